@@ -0,0 +1,80 @@
+# tests/test_analysis_report_write.py
+"""
+Tests for when AnalysisStep writes the analysis report.
+
+Validates:
+1. Without analysis_write_report nothing is written, whatever the logging
+   options (log_show_options_json is a log toggle only)
+2. With it, the JSON + CSV report goes into the job's logs dir
+3. The delay sidecar is written with the report, into the output folder;
+   log_show_options_json alone never puts one next to the outputs
+"""
+
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+# The steps package pulls in the analysis stack
+pytest.importorskip("scipy")
+
+from vsg_core.analysis.delay_sidecar import DelaySidecar, SidecarDelay  # noqa: E402
+from vsg_core.analysis.report import AnalysisReport  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+from vsg_core.orchestrator.steps.analysis_step import AnalysisStep  # noqa: E402
+
+
+def _ctx(tmp_path: Path, **settings):
+    report = AnalysisReport(job_name="ep01")
+    report.add_source("Source 2", -120, -120.4, "mode", [])
+    return SimpleNamespace(
+        settings=AppSettings(**settings),
+        analysis_report=report,
+        work_dir=SimpleNamespace(logs=tmp_path / "work" / "logs"),
+        output_dir=str(tmp_path / "out"),
+        source_inputs={"Source 1": "/in/ep01.mkv", "Source 2": "/in/b/ep01.mkv"},
+    )
+
+
+def _written(tmp_path: Path) -> list[str]:
+    return sorted(p.name for p in tmp_path.rglob("*") if p.is_file())
+
+
+def test_log_options_write_nothing(tmp_path: Path):
+    lines: list[str] = []
+    ctx = _ctx(tmp_path, log_show_options_json=True)
+    AnalysisStep()._write_analysis_report(ctx, lines.append)
+
+    assert _written(tmp_path) == []
+    assert lines == []
+
+
+def test_report_goes_to_the_logs_dir(tmp_path: Path):
+    lines: list[str] = []
+    ctx = _ctx(tmp_path, analysis_write_report=True)
+    AnalysisStep()._write_analysis_report(ctx, lines.append)
+
+    logs = tmp_path / "work" / "logs"
+    assert (logs / "ep01_analysis_report.json").is_file()
+    assert (logs / "ep01_analysis_report.csv").is_file()
+    assert lines[0].startswith("[Report] Analysis report written:")
+
+
+def test_sidecar_goes_to_the_output_folder(tmp_path: Path):
+    ctx = _ctx(tmp_path, analysis_write_report=True)
+    AnalysisStep()._write_analysis_report(ctx, print)
+
+    sidecar = DelaySidecar.load(tmp_path / "out" / "ep01.delays.json")
+    assert sidecar.sources == {
+        "Source 2": SidecarDelay(-120, -120.4, "analyzed", "ep01.mkv")
+    }
+
+
+def test_log_options_write_no_sidecar(tmp_path: Path):
+    ctx = _ctx(tmp_path, log_show_options_json=True, analysis_use_sidecar=True)
+    AnalysisStep()._write_analysis_report(ctx, print)
+    assert not (tmp_path / "out").exists()
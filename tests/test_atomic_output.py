@@ -0,0 +1,79 @@
+# tests/test_atomic_output.py
+"""
+Tests for moving the merged file into place (vsg_core.postprocess.atomic_output).
+
+Validates:
+1. On one filesystem the file is renamed into place
+2. Across filesystems it is copied to <name>.part and renamed once complete
+3. A failed copy leaves neither the final name nor the .part behind
+"""
+
+import errno
+import os
+import shutil
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+import pytest  # noqa: E402
+
+from vsg_core.postprocess.atomic_output import part_path, place_output  # noqa: E402
+
+_real_replace = os.replace
+
+
+def _cross_device_replace(src, dst):
+    """os.replace that fails like a rename across mounts for work-dir files."""
+    if Path(src).parent.name == "work":
+        raise OSError(errno.EXDEV, "Invalid cross-device link")
+    _real_replace(src, dst)
+
+
+def _setup(tmp_path):
+    (tmp_path / "work").mkdir()
+    (tmp_path / "out").mkdir()
+    temp = tmp_path / "work" / "temp_movie.mkv"
+    temp.write_bytes(b"mkv" * 1000)
+    return temp, tmp_path / "out" / "movie.mkv"
+
+
+def test_same_filesystem_rename(tmp_path):
+    temp, final = _setup(tmp_path)
+
+    place_output(temp, final)
+
+    assert final.read_bytes() == b"mkv" * 1000
+    assert not temp.exists()
+    assert not part_path(final).exists()
+
+
+def test_cross_filesystem_goes_through_part(tmp_path, monkeypatch):
+    temp, final = _setup(tmp_path)
+    logged = []
+    monkeypatch.setattr(os, "replace", _cross_device_replace)
+
+    place_output(temp, final, log=logged.append)
+
+    assert final.read_bytes() == b"mkv" * 1000
+    assert not temp.exists()
+    assert not part_path(final).exists()
+    assert "movie.mkv.part" in logged[0]
+
+
+def test_failed_copy_leaves_nothing(tmp_path, monkeypatch):
+    temp, final = _setup(tmp_path)
+
+    def failing_copy(src, dst):
+        Path(dst).write_bytes(b"mk")
+        raise OSError(errno.ENOSPC, "No space left on device")
+
+    monkeypatch.setattr(os, "replace", _cross_device_replace)
+    monkeypatch.setattr(shutil, "copy2", failing_copy)
+
+    with pytest.raises(OSError):
+        place_output(temp, final)
+    assert not final.exists()
+    assert not part_path(final).exists()
+    assert temp.exists()
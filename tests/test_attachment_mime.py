@@ -0,0 +1,127 @@
+# tests/test_attachment_mime.py
+"""
+Tests for attachment MIME-type correction (vsg_core.mux.attachment_mime)
+and its use in MuxStep.
+
+Validates:
+1. Fonts and images are identified by magic bytes, not the extension
+2. Only attachments with a declared type that doesn't fit are corrected
+3. With fix_attachment_mime on, MuxStep passes the corrected type to
+   mkvmerge right before the attachment
+"""
+
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.settings import AppSettings  # noqa: E402
+from vsg_core.mux.attachment_mime import fix_attachment_mime, sniff_mime  # noqa: E402
+
+_MAGIC = {
+    "font.ttf": b"\x00\x01\x00\x00\x00\x10\x01\x00\x00\x04\x00\x00",
+    "font.otf": b"OTTO\x00\x0b\x00\x80\x00\x03\x00\x30",
+    "fonts.ttc": b"ttcf\x00\x01\x00\x00\x00\x00\x00\x02",
+    "cover.png": b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR",
+    "cover.jpg": b"\xff\xd8\xff\xe0\x00\x10JFIF\x00\x01",
+    "cover.webp": b"RIFF\x24\x00\x00\x00WEBPVP8 ",
+    "notes.txt": b"Release notes",
+}
+
+
+@pytest.fixture
+def files(tmp_path: Path) -> dict[str, Path]:
+    out = {}
+    for name, head in _MAGIC.items():
+        out[name] = tmp_path / name
+        out[name].write_bytes(head + b"\x00" * 64)
+    return out
+
+
+@pytest.mark.parametrize(
+    ("name", "mime"),
+    [
+        ("font.ttf", "application/x-truetype-font"),
+        ("font.otf", "application/vnd.ms-opentype"),
+        ("fonts.ttc", "application/x-truetype-font"),
+        ("cover.png", "image/png"),
+        ("cover.jpg", "image/jpeg"),
+        ("cover.webp", "image/webp"),
+        ("notes.txt", None),
+    ],
+)
+def test_sniff(files, name, mime):
+    assert sniff_mime(files[name]) == mime
+
+
+def test_sniff_ignores_extension(tmp_path: Path):
+    misnamed = tmp_path / "font.ttf"
+    misnamed.write_bytes(_MAGIC["font.otf"])
+    assert sniff_mime(misnamed) == "application/vnd.ms-opentype"
+    assert sniff_mime(tmp_path / "missing.ttf") is None
+
+
+def test_only_wrong_types_are_corrected(files):
+    corrections = fix_attachment_mime(
+        {
+            files["font.ttf"]: "application/octet-stream",
+            files["font.otf"]: "font/otf",  # Accepted font type
+            files["cover.png"]: "",  # No declared type
+            files["cover.jpg"]: "IMAGE/JPEG",
+            files["notes.txt"]: "application/octet-stream",  # Unknown content
+        }
+    )
+    assert [(c.path.name, c.mime) for c in corrections] == [
+        ("font.ttf", "application/x-truetype-font"),
+        ("cover.png", "image/png"),
+    ]
+    assert corrections[1].describe() == "cover.png: (none) -> image/png"
+
+
+class _Runner:
+    def __init__(self):
+        self.lines: list[str] = []
+
+    def run(self, cmd, tool_paths, **kwargs):
+        return None
+
+    def _log_message(self, message: str) -> None:
+        self.lines.append(message)
+
+
+def test_mux_step_forces_corrected_type(files, tmp_path: Path):
+    # The steps package pulls in the analysis stack
+    pytest.importorskip("scipy")
+    from vsg_core.orchestrator.steps.mux_step import MuxStep
+
+    font, notes = files["font.ttf"], files["notes.txt"]
+    ctx = SimpleNamespace(
+        settings=AppSettings(fix_attachment_mime=True),
+        extracted_items=[],
+        delays=None,
+        chapters_xml=None,
+        attachments=[str(font), str(notes)],
+        attachment_mime_types={str(font): "application/octet-stream"},
+        subtitle_delays_ms={},
+        sources={"Source 1": str(tmp_path / "ref.mkv")},
+        temp_dir=tmp_path,
+        tool_paths={},
+        audit=None,
+    )
+    runner = _Runner()
+    MuxStep().run(ctx, runner)
+
+    tokens = ctx.tokens
+    at = tokens.index(str(font))
+    assert tokens[at - 3 : at] == [
+        "--attachment-mime-type",
+        "application/x-truetype-font",
+        "--attach-file",
+    ]
+    # Unrecognized files are attached without a forced type
+    assert tokens[tokens.index(str(notes)) - 2] != "--attachment-mime-type"
+    assert any("MIME type corrected" in line for line in runner.lines)
@@ -0,0 +1,176 @@
+# tests/test_audio_cache.py
+"""
+Tests for the decoded-audio cache (vsg_core.analysis.correlation.audio_cache).
+
+Validates:
+1. The key changes with every input that changes the samples (stream,
+   sample rate, resampler, downmix) and not with how the path is written
+2. A second decode of the same stream is read back instead of decoded,
+   also by a new cache on the same folder (index.json)
+3. A source file that changed since it was decoded is decoded again
+4. An unreadable index starts an empty cache
+5. decode_for_job() only uses the cache with analysis_audio_cache on
+
+Needs numpy; ffmpeg is replaced by a fake decoder.
+"""
+
+import os
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation import audio_cache  # noqa: E402
+from vsg_core.analysis.correlation.audio_cache import (  # noqa: E402
+    DecodedAudioCache,
+    decode_for_job,
+)
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+SR = 48000
+
+
+class _Runner:
+    def __init__(self):
+        self.lines: list[str] = []
+
+    def _log_message(self, message: str) -> None:
+        self.lines.append(message)
+
+
+@pytest.fixture
+def decodes(monkeypatch) -> list[tuple]:
+    """Calls of the fake decode_audio; each returns different samples."""
+    calls: list[tuple] = []
+
+    def fake_decode(file_path, stream_index, sr, use_soxr, runner, tool_paths, **kw):
+        calls.append((Path(file_path).name, stream_index, kw.get("downmix")))
+        return np.full(sr // 100, len(calls), dtype=np.float32)
+
+    monkeypatch.setattr(audio_cache, "decode_audio", fake_decode)
+    return calls
+
+
+@pytest.fixture
+def source(tmp_path: Path) -> Path:
+    path = tmp_path / "ref.mkv"
+    path.write_bytes(b"\0" * 100)
+    return path
+
+
+def _decode(cache: DecodedAudioCache, path: Path, runner=None, stream: int = 0):
+    return cache.decode(str(path), stream, SR, False, runner or _Runner(), {})
+
+
+def test_key_covers_every_decode_input(source: Path, monkeypatch):
+    key = DecodedAudioCache.key
+    base = key(str(source), 0, SR, False, "mono_sum")
+    variants = [
+        key(str(source), 1, SR, False, "mono_sum"),
+        key(str(source), 0, 44100, False, "mono_sum"),
+        key(str(source), 0, SR, True, "mono_sum"),
+        key(str(source), 0, SR, False, "center_only"),
+        key(str(source.with_name("other.mkv")), 0, SR, False, "mono_sum"),
+    ]
+    assert len({base, *variants}) == 6
+
+    monkeypatch.chdir(source.parent)
+    assert DecodedAudioCache.key("ref.mkv", 0, SR, False, "mono_sum") == base
+
+
+def test_second_decode_is_reused(tmp_path: Path, source: Path, decodes):
+    cache = DecodedAudioCache(tmp_path / "decoded")
+    runner = _Runner()
+    first = _decode(cache, source, runner)
+    second = _decode(cache, source, runner)
+
+    assert decodes == [("ref.mkv", 0, "mono_sum")]
+    assert np.array_equal(first, second)
+    assert runner.lines == ["[Audio Cache] Reusing decoded ref.mkv (a:0)"]
+
+
+def test_other_stream_is_decoded(tmp_path: Path, source: Path, decodes):
+    cache = DecodedAudioCache(tmp_path / "decoded")
+    _decode(cache, source)
+    other = _decode(cache, source, stream=1)
+    assert [c[1] for c in decodes] == [0, 1]
+    assert other[0] == 2
+
+
+def test_index_survives_a_new_cache(tmp_path: Path, source: Path, decodes):
+    _decode(DecodedAudioCache(tmp_path / "decoded"), source)
+    again = _decode(DecodedAudioCache(tmp_path / "decoded"), source)
+    assert len(decodes) == 1
+    assert again[0] == 1
+
+
+def test_changed_source_is_decoded_again(tmp_path: Path, source: Path, decodes):
+    cache = DecodedAudioCache(tmp_path / "decoded")
+    _decode(cache, source)
+
+    source.write_bytes(b"\0" * 200)  # Remuxed in place: new size and mtime
+    runner = _Runner()
+    fresh = _decode(cache, source, runner)
+    assert len(decodes) == 2
+    assert fresh[0] == 2
+    assert runner.lines == [
+        "[Audio Cache] ref.mkv changed since it was decoded; decoding again"
+    ]
+    # The new decode replaced the old entry
+    assert _decode(cache, source)[0] == 2
+    assert len(decodes) == 2
+
+
+def test_touched_source_is_decoded_again(tmp_path: Path, source: Path, decodes):
+    cache = DecodedAudioCache(tmp_path / "decoded")
+    _decode(cache, source)
+    stat = source.stat()
+    os.utime(source, ns=(stat.st_atime_ns, stat.st_mtime_ns + 1_000_000_000))
+    _decode(cache, source)
+    assert len(decodes) == 2
+
+
+def test_unreadable_index_starts_empty(tmp_path: Path, source: Path, decodes):
+    root = tmp_path / "decoded"
+    root.mkdir()
+    (root / "index.json").write_text("{not json", encoding="utf-8")
+    cache = DecodedAudioCache(root)
+    _decode(cache, source)
+    assert len(decodes) == 1
+
+
+def _ctx(tmp_path: Path, enabled: bool) -> SimpleNamespace:
+    return SimpleNamespace(
+        settings=AppSettings(analysis_audio_cache=enabled),
+        tool_paths={},
+        work_dir=SimpleNamespace(audio=tmp_path / "audio"),
+        audio_cache=None,
+    )
+
+
+def test_decode_for_job_without_cache(tmp_path: Path, source: Path, decodes):
+    ctx = _ctx(tmp_path, enabled=False)
+    for _ in range(2):
+        decode_for_job(ctx, str(source), 0, SR, _Runner())
+    assert len(decodes) == 2
+    assert ctx.audio_cache is None
+    assert not (tmp_path / "audio").exists()
+
+
+def test_decode_for_job_with_cache(tmp_path: Path, source: Path, decodes):
+    ctx = _ctx(tmp_path, enabled=True)
+    for _ in range(2):
+        decode_for_job(ctx, str(source), 0, SR, _Runner(), downmix="left_only")
+    assert decodes == [("ref.mkv", 0, "left_only")]
+    assert ctx.audio_cache.root == tmp_path / "audio" / "decoded"
+    assert (tmp_path / "audio" / "decoded" / "index.json").exists()
+
+
+def test_cache_is_off_by_default():
+    assert AppSettings().analysis_audio_cache is False
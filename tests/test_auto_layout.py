@@ -0,0 +1,81 @@
+# tests/test_auto_layout.py
+"""
+Tests for generated starting layouts (vsg_core.job_layouts.auto_layout).
+
+Validates:
+1. The chosen source's tracks come first, in file order
+2. Tracks in the wanted languages are added from the other sources next to
+   the tracks of the same type, with 639-1 and 639-2 codes treated alike
+3. Video only comes from Source 1, whichever source sets the order
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.job_layouts.auto_layout import (  # noqa: E402
+    AutoLayoutPolicy,
+    auto_layout,
+)
+
+
+def _track(source, track_id, ttype, lang):
+    return {"source": source, "id": track_id, "type": ttype, "lang": lang}
+
+
+TRACK_INFO = {
+    "Source 1": [
+        _track("Source 1", 0, "video", "und"),
+        _track("Source 1", 1, "audio", "eng"),
+        _track("Source 1", 2, "subtitles", "eng"),
+    ],
+    "Source 2": [
+        _track("Source 2", 0, "video", "und"),
+        _track("Source 2", 1, "audio", "jpn"),
+        _track("Source 2", 2, "audio", "eng"),
+        _track("Source 2", 3, "subtitles", "jpn"),
+    ],
+}
+
+
+def _ids(layout):
+    return [(t["source"][-1], t["id"]) for t in layout]
+
+
+def test_reference_order_only():
+    assert _ids(auto_layout(TRACK_INFO, AutoLayoutPolicy())) == [
+        ("1", 0),
+        ("1", 1),
+        ("1", 2),
+    ]
+
+
+def test_languages_are_added_next_to_their_type():
+    policy = AutoLayoutPolicy(languages=AutoLayoutPolicy.parse_languages("ja"))
+
+    assert _ids(auto_layout(TRACK_INFO, policy)) == [
+        ("1", 0),
+        ("1", 1),
+        ("2", 1),
+        ("1", 2),
+        ("2", 3),
+    ]
+
+    audio_only = AutoLayoutPolicy(languages=("jpn",), track_types=("audio",))
+    assert ("2", 3) not in _ids(auto_layout(TRACK_INFO, audio_only))
+
+
+def test_other_order_source_keeps_reference_video():
+    policy = AutoLayoutPolicy(order_source="Source 2", languages=("eng",))
+
+    assert _ids(auto_layout(TRACK_INFO, policy)) == [
+        ("1", 0),
+        ("2", 1),
+        ("2", 2),
+        ("1", 1),
+        ("2", 3),
+        ("1", 2),
+    ]
+    assert AutoLayoutPolicy.parse_languages(" jpn; ENG, jpn ,") == ("jpn", "eng")
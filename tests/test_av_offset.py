@@ -0,0 +1,93 @@
+# tests/test_av_offset.py
+"""
+A/V offset within one file (vsg_core.analysis.av_offset), on synthetic
+signals.
+
+Validates:
+1. Audio onsets a few frames after the cuts give a positive offset
+   (audio lags), onsets before them a negative one
+2. The audio stream's start offset is added to the result
+3. Onsets unrelated to the cuts give an unreliable result
+4. A video without cuts raises
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.av_offset import (  # noqa: E402
+    audio_onsets,
+    av_offset_from_signals,
+)
+
+FPS = 24000 / 1001
+SR = 16000
+N_FRAMES = 6000
+
+
+def _video(rng: np.random.Generator) -> tuple[np.ndarray, np.ndarray]:
+    """Luma change per frame with a cut every 40-120 frames."""
+    cuts = np.cumsum(rng.integers(40, 120, size=80))
+    cuts = cuts[cuts < N_FRAMES - 10]
+    luma_diff = rng.uniform(0.5, 2.0, size=N_FRAMES - 1)
+    luma_diff[cuts] = rng.uniform(30.0, 60.0, size=len(cuts))
+    return luma_diff, cuts
+
+
+def _audio(rng: np.random.Generator, onset_frames: np.ndarray) -> np.ndarray:
+    """Quiet noise with a loud burst starting at each onset frame."""
+    pcm = rng.normal(0.0, 0.01, size=int(N_FRAMES * SR / FPS)).astype(np.float32)
+    burst = int(0.3 * SR)
+    for frame in onset_frames:
+        start = int(round((frame + 1) * SR / FPS))
+        segment = pcm[start : start + burst]
+        segment += rng.normal(0.0, 0.5, size=len(segment)).astype(np.float32)
+    return pcm
+
+
+@pytest.mark.parametrize("shift_frames", [3, -2, 0])
+def test_offset_follows_onset_shift(shift_frames):
+    rng = np.random.default_rng(11)
+    luma_diff, cuts = _video(rng)
+    onsets = audio_onsets(_audio(rng, cuts + shift_frames), SR, FPS, N_FRAMES)
+
+    result = av_offset_from_signals(luma_diff, onsets, FPS)
+
+    assert result.reliable
+    assert result.offset_ms == pytest.approx(shift_frames * 1000.0 / FPS, abs=15.0)
+
+
+def test_start_offset_is_added():
+    rng = np.random.default_rng(5)
+    luma_diff, cuts = _video(rng)
+    onsets = audio_onsets(_audio(rng, cuts), SR, FPS, N_FRAMES)
+
+    result = av_offset_from_signals(luma_diff, onsets, FPS, start_offset_ms=-80.0)
+
+    assert result.offset_ms == pytest.approx(-80.0, abs=15.0)
+
+
+def test_unrelated_onsets_are_unreliable():
+    rng = np.random.default_rng(3)
+    luma_diff, cuts = _video(rng)
+    unrelated = rng.integers(0, N_FRAMES - 10, size=len(cuts))
+    onsets = audio_onsets(_audio(rng, unrelated), SR, FPS, N_FRAMES)
+
+    result = av_offset_from_signals(luma_diff, onsets, FPS)
+
+    assert not result.reliable
+
+
+def test_no_cuts_raises():
+    rng = np.random.default_rng(1)
+    luma_diff = rng.uniform(0.5, 2.0, size=N_FRAMES - 1)
+    onsets = audio_onsets(_audio(rng, np.arange(0, N_FRAMES, 100)), SR, FPS, N_FRAMES)
+
+    with pytest.raises(ValueError):
+        av_offset_from_signals(luma_diff, onsets, FPS)
@@ -0,0 +1,102 @@
+# tests/test_bake_shift.py
+"""Frame-accurate baked shift (vsg_core.subtitles.operations.bake_shift).
+
+At 24000/1001 fps a frame lasts 41.7083ms: frame 24 starts at 1001.0ms,
+frame 25 at 1042.708ms. Every baked boundary must be such a frame start.
+"""
+
+import math
+
+from vsg_core.models.settings import AppSettings
+from vsg_core.subtitles.data import SubtitleData, SubtitleEvent
+from vsg_core.subtitles.operations.bake_shift import bake_shift, snap_frame
+from vsg_core.subtitles.sync_mode_plugins.time_based import TimeBasedSync
+
+FILM = 24000 / 1001
+
+
+def _data(*spans: tuple[float, float]) -> SubtitleData:
+    data = SubtitleData()
+    data.events = [SubtitleEvent(start_ms=s, end_ms=e, text="Cue") for s, e in spans]
+    return data
+
+
+def _on_grid(time_ms: float, fps: float) -> bool:
+    frames = time_ms * fps / 1000.0
+    return math.isclose(frames, round(frames), abs_tol=1e-6)
+
+
+def test_boundaries_land_on_frame_starts():
+    data = _data((1000.0, 2500.0), (12345.6, 13000.4), (60000.0, 61234.5))
+    result = bake_shift(data, 333.3, FILM, "round")
+    assert result.success
+    assert result.events_affected == 3
+    for event in data.events:
+        assert _on_grid(event.start_ms, FILM)
+        assert _on_grid(event.end_ms, FILM)
+        assert event.sync.snapped_to_frame
+        assert event.end_ms > event.start_ms
+
+
+def test_rounding_modes_pick_expected_frame():
+    # 1000ms + 20ms = 1020ms sits inside frame 24 (1001.0 - 1042.7ms)
+    expected = {"floor": 1001.0, "round": 1001.0, "ceil": 1042.708}
+    for mode, start in expected.items():
+        data = _data((1000.0, 2000.0))
+        bake_shift(data, 20.0, FILM, mode)
+        assert abs(data.events[0].start_ms - start) < 1e-3, mode
+
+
+def test_exact_frame_time_is_stable():
+    # A time already on a frame stays there whatever the rounding
+    on_frame = 1001.0
+    for mode in ("floor", "round", "ceil"):
+        assert snap_frame(on_frame, FILM, mode) == 24
+
+
+def test_negative_delay():
+    data = _data((5000.0, 6000.0))
+    bake_shift(data, -1234.5, FILM, "floor")
+    event = data.events[0]
+    assert _on_grid(event.start_ms, FILM)
+    assert event.start_ms <= 5000.0 - 1234.5
+    assert 5000.0 - 1234.5 - event.start_ms < 1000 / FILM
+
+
+def test_short_event_keeps_one_frame():
+    data = _data((1001.0, 1010.0))
+    result = bake_shift(data, 0.0, FILM, "floor")
+    event = data.events[0]
+    assert snap_frame(event.end_ms, FILM) == snap_frame(event.start_ms, FILM) + 1
+    assert result.details["events_extended"] == 1
+
+
+def test_comments_untouched():
+    data = _data((1000.0, 2000.0))
+    comment = SubtitleEvent(start_ms=1234.0, end_ms=2345.0, text="note")
+    comment.is_comment = True
+    data.events.append(comment)
+    result = bake_shift(data, 100.0, FILM)
+    assert result.events_affected == 1
+    assert comment.start_ms == 1234.0
+
+
+def test_invalid_fps():
+    assert not bake_shift(_data((0.0, 1.0)), 10.0, 0.0).success
+
+
+def test_time_based_frame_accurate_bakes_delay():
+    settings = AppSettings(time_based_frame_accurate=True, subtitle_rounding="round")
+    data = _data((1000.0, 2000.0))
+    result = TimeBasedSync().apply(data, 500.0, 0.0, target_fps=FILM, settings=settings)
+    # events_affected > 0 marks the track frame_adjusted -> mkvmerge --sync 0
+    assert result.events_affected == 1
+    assert _on_grid(data.events[0].start_ms, FILM)
+
+
+def test_time_based_frame_accurate_without_fps_falls_back():
+    settings = AppSettings(time_based_frame_accurate=True)
+    data = _data((1000.0, 2000.0))
+    result = TimeBasedSync().apply(data, 500.0, 0.0, target_fps=None, settings=settings)
+    assert result.events_affected == 0
+    assert data.events[0].start_ms == 1000.0
@@ -0,0 +1,74 @@
+"""Block-mean perceptual hash (``compute_block_hash``).
+
+A textured 1920x1080 frame is hashed next to a re-encoded copy (downscaled
+to 1280x720 and saved as JPEG) and an unrelated frame. The copy must stay
+within the default hash threshold (Hamming distance <= 5 at 64 bits); the
+unrelated frame must not.
+
+Needs numpy, Pillow and imagehash.
+"""
+
+import io
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+PILImage = pytest.importorskip("PIL.Image")
+pytest.importorskip("imagehash")
+
+from vsg_core.subtitles.frame_utils.frame_hashing import (  # noqa: E402
+    compare_frames,
+    compute_block_hash,
+)
+
+HASH_THRESHOLD = 5  # compare_frames default for hashes
+
+
+def _frame(seed: int) -> "PILImage.Image":
+    rng = np.random.default_rng(seed)
+    coarse = rng.integers(0, 256, size=(34, 60), dtype=np.uint8)
+    return PILImage.fromarray(coarse, "L").resize(
+        (1920, 1080), PILImage.Resampling.BICUBIC
+    )
+
+
+def _reencode(frame: "PILImage.Image") -> "PILImage.Image":
+    buf = io.BytesIO()
+    frame.resize((1280, 720), PILImage.Resampling.BILINEAR).save(
+        buf, format="JPEG", quality=75
+    )
+    buf.seek(0)
+    return PILImage.open(buf).convert("L")
+
+
+def test_block_hash_size():
+    h = compute_block_hash(_frame(821), hash_size=16)
+    assert h.hash.shape == (16, 16)
+
+
+def test_reencoded_copy_within_threshold():
+    frame = _frame(821)
+    distance = compute_block_hash(frame) - compute_block_hash(_reencode(frame))
+    assert distance <= HASH_THRESHOLD
+
+
+def test_unrelated_frame_exceeds_threshold():
+    distance = compute_block_hash(_frame(821)) - compute_block_hash(_frame(822))
+    assert distance > HASH_THRESHOLD
+
+
+def test_compare_frames_blockhash():
+    frame = _frame(821)
+    _, is_match = compare_frames(
+        frame, _reencode(frame), method="hash", hash_algorithm="blockhash"
+    )
+    _, other_match = compare_frames(
+        frame, _frame(822), method="hash", hash_algorithm="blockhash"
+    )
+    assert is_match
+    assert not other_match
@@ -0,0 +1,102 @@
+# tests/test_chapter_exclusion.py
+"""
+Skipping intro/outro chapters in window placement (chapter_exclusion.py).
+
+Validates:
+1. Chapters matching the patterns become ranges that end at their end
+   time, the next chapter or the end of the file
+2. Chapter names and times are read from mkvextract XML
+3. Windows overlapping a range are dropped, and all are kept when
+   nothing would be left
+"""
+
+import math
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")
+pytest.importorskip("lxml")
+
+from vsg_core.analysis.chapter_exclusion import find_excluded_ranges  # noqa: E402
+from vsg_core.analysis.correlation.dense import ChunkPlacement  # noqa: E402
+from vsg_core.chapters.process import read_chapter_timings  # noqa: E402
+from vsg_core.chapters.validate import ChapterTiming  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+S = 1_000_000_000
+PATTERNS = AppSettings().analysis_exclude_chapter_patterns
+
+CHAPTERS_XML = """<?xml version="1.0"?>
+<Chapters>
+  <EditionEntry>
+    <ChapterAtom>
+      <ChapterTimeStart>00:00:00.000000000</ChapterTimeStart>
+      <ChapterDisplay><ChapterString>Prologue</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+    <ChapterAtom>
+      <ChapterTimeStart>00:01:30.000000000</ChapterTimeStart>
+      <ChapterTimeEnd>00:03:00.000000000</ChapterTimeEnd>
+      <ChapterDisplay><ChapterString>Opening</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+    <ChapterAtom>
+      <ChapterTimeStart>00:03:00.000000000</ChapterTimeStart>
+      <ChapterDisplay><ChapterString>Part A</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+    <ChapterAtom>
+      <ChapterTimeStart>00:21:00.000000000</ChapterTimeStart>
+      <ChapterDisplay><ChapterString>ED</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+    <ChapterAtom>
+      <ChapterTimeStart>00:22:30.000000000</ChapterTimeStart>
+      <ChapterDisplay><ChapterString>Preview</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+  </EditionEntry>
+</Chapters>
+"""
+
+
+def test_matching_chapters_become_ranges():
+    ranges = find_excluded_ranges(read_chapter_timings(CHAPTERS_XML), PATTERNS)
+
+    assert [(r.name, r.start_s, r.end_s) for r in ranges] == [
+        ("Opening", 90.0, 180.0),  # Own end time
+        ("ED", 1260.0, 1350.0),  # Until the next chapter
+    ]
+    assert ranges[1].describe() == "'ED' (1260.0s - 1350.0s)"
+
+
+def test_last_chapter_runs_to_end_and_names_are_matched_loosely():
+    timings = [
+        ChapterTiming("Part B", 0),
+        ChapterTiming("OP2", 10 * S),
+        ChapterTiming("Episode Part", 20 * S),
+        ChapterTiming("End Credits", 30 * S),
+    ]
+
+    ranges = find_excluded_ranges(timings, PATTERNS)
+
+    assert [r.name for r in ranges] == ["OP2", "End Credits"]
+    assert math.isinf(ranges[1].end_s)
+    assert "end)" in ranges[1].describe()
+    assert find_excluded_ranges(timings, ["^part"])[0].name == "Part B"
+    with pytest.raises(ValueError):
+        find_excluded_ranges(timings, ["("])
+
+
+def test_placement_skips_excluded_windows():
+    sr = 10
+    positions = list(range(0, 100 * sr, 10 * sr))  # 10 s windows every 10 s
+    placement = ChunkPlacement(excluded_s=((15.0, 35.0), (90.0, math.inf)))
+
+    kept = placement.select(positions, 10 * sr, 10 * sr, sr)
+
+    # 10-20, 20-30 and 30-40 overlap the first range, 90-100 the second
+    assert kept == [0, 40 * sr, 50 * sr, 60 * sr, 70 * sr, 80 * sr]
+    assert "skipping 2 excluded range(s)" in placement.describe()
+    everything = ChunkPlacement(excluded_s=((0.0, math.inf),))
+    assert everything.select(positions, 10 * sr, 10 * sr, sr) == positions
@@ -0,0 +1,217 @@
+# tests/test_chapter_validation.py
+"""
+Tests for chapter timestamp validation/repair (vsg_core.chapters.validate)
+and per-edition processing (vsg_core.chapters.process / editions).
+
+Validates:
+1. validate() reports duplicate starts, out-of-order starts, zero-length
+   chapters and starts past the end of the file
+2. repair() restores a strictly increasing order, nudging collisions by
+   the epsilon and keeping document order among them
+3. process_chapters() re-orders a plain edition, passes an ordered edition
+   through with shifted timestamps only and leaves linked atoms alone,
+   however many of them are interleaved with plain ones (needs lxml)
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.chapters.validate import (  # noqa: E402
+    CHAPTER_EPSILON_NS,
+    ChapterTiming,
+    repair,
+    validate,
+)
+
+S = 1_000_000_000
+
+
+def _ch(name: str, start_s: float, end_s: float | None = None, pos: int = 0):
+    end = int(end_s * S) if end_s is not None else None
+    return ChapterTiming(name, int(start_s * S), end, position=pos)
+
+
+def test_clean_chapters_have_no_issues():
+    chapters = [_ch("A", 0, 10), _ch("B", 10, 20), _ch("C", 20)]
+    assert validate(chapters, duration_ns=30 * S) == []
+
+
+def test_validate_reports_each_kind():
+    chapters = [
+        _ch("A", 0, 10),
+        _ch("B", 0, 5),  # Same start as A
+        _ch("C", 30, 30),  # Zero length
+        _ch("D", 20),  # Before C
+        _ch("E", 60),  # Past the end
+    ]
+    kinds = [(i.kind, i.name) for i in validate(chapters, duration_ns=45 * S)]
+    assert kinds == [
+        ("duplicate_start", "B"),
+        ("zero_length", "C"),
+        ("out_of_order", "D"),
+        ("beyond_duration", "E"),
+    ]
+
+
+def test_duration_check_needs_a_duration():
+    assert validate([_ch("A", 3600)]) == []
+
+
+def test_repair_sorts_and_nudges_collisions():
+    chapters = [
+        _ch("Intro", 0, pos=0),
+        _ch("Part B", 300, pos=1),
+        _ch("Part A", 120, pos=2),
+        _ch("Part A2", 120, pos=3),  # Snapped onto Part A's keyframe
+    ]
+    repaired = repair(chapters)
+    assert [c.name for c in repaired] == ["Intro", "Part A", "Part A2", "Part B"]
+    assert repaired[2].start_ns == 120 * S + CHAPTER_EPSILON_NS
+    assert [c.position for c in repaired] == [0, 2, 3, 1]
+    assert validate(repaired) == []
+
+
+def test_repair_keeps_ends_after_nudged_start():
+    repaired = repair([_ch("A", 5, 6), _ch("B", 5, 5)])
+    b = repaired[1]
+    assert b.start_ns == 5 * S + CHAPTER_EPSILON_NS
+    assert b.end_ns == b.start_ns + CHAPTER_EPSILON_NS
+
+
+def test_repair_leaves_exact_duplicates_for_dedupe():
+    repaired = repair([_ch("A", 5), _ch("A", 5)])
+    assert [c.start_ns for c in repaired] == [5 * S, 5 * S]
+
+
+# --- process_chapters (lxml) ---
+
+_XML = """<?xml version="1.0"?>
+<Chapters>
+  <EditionEntry>
+    <EditionUID>1</EditionUID>
+    <ChapterAtom>
+      <ChapterTimeStart>00:00:00.000000000</ChapterTimeStart>
+      <ChapterDisplay><ChapterString>Intro</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+    <ChapterAtom>
+      <ChapterTimeStart>00:05:00.000000000</ChapterTimeStart>
+      <ChapterDisplay><ChapterString>Part B</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+    <ChapterAtom>
+      <ChapterTimeStart>00:02:00.000000000</ChapterTimeStart>
+      <ChapterDisplay><ChapterString>Part A</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+  </EditionEntry>
+  <EditionEntry>
+    <EditionUID>2</EditionUID>
+    <EditionFlagOrdered>1</EditionFlagOrdered>
+    <ChapterAtom>
+      <ChapterTimeStart>00:00:00.000000000</ChapterTimeStart>
+      <ChapterTimeEnd>00:10:00.000000000</ChapterTimeEnd>
+      <ChapterDisplay><ChapterString>Main</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+    <ChapterAtom>
+      <ChapterTimeStart>00:00:00.000000000</ChapterTimeStart>
+      <ChapterTimeEnd>00:01:00.000000000</ChapterTimeEnd>
+      <ChapterDisplay><ChapterString>Recap</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+    <ChapterAtom>
+      <ChapterSegmentUID format="hex">0102030405060708</ChapterSegmentUID>
+      <ChapterTimeStart>00:00:30.000000000</ChapterTimeStart>
+      <ChapterTimeEnd>00:00:40.000000000</ChapterTimeEnd>
+      <ChapterDisplay><ChapterString>Linked</ChapterString></ChapterDisplay>
+    </ChapterAtom>
+  </EditionEntry>
+</Chapters>
+"""
+
+
+class _Runner:
+    """mkvextract prints the chapter XML; ffprobe (duration) gives nothing."""
+
+    def __init__(self, xml: str = _XML):
+        self.xml = xml
+        self.lines: list[str] = []
+
+    def run(self, cmd, tool_paths):
+        return self.xml if cmd[0] == "mkvextract" else None
+
+    def _log_message(self, message: str) -> None:
+        self.lines.append(message)
+
+
+def _process(tmp_path: Path, shift_ms: int, xml: str = _XML):
+    etree = pytest.importorskip("lxml.etree")
+    from vsg_core.chapters.process import process_chapters
+    from vsg_core.models.settings import AppSettings
+
+    runner = _Runner(xml)
+    out = process_chapters("ref.mkv", tmp_path, runner, {}, AppSettings(), shift_ms)
+    assert out is not None, runner.lines
+    editions = etree.parse(out).getroot().findall("EditionEntry")
+    return [
+        [
+            (
+                atom.findtext("ChapterDisplay/ChapterString"),
+                atom.findtext("ChapterTimeStart"),
+                atom.findtext("ChapterTimeEnd"),
+            )
+            for atom in edition.findall("ChapterAtom")
+        ]
+        for edition in editions
+    ], runner.lines
+
+
+def test_plain_edition_is_reordered(tmp_path: Path):
+    (plain, _), lines = _process(tmp_path, 0)
+    assert [name for name, _, _ in plain] == ["Intro", "Part A", "Part B"]
+    assert any("out_of_order" in line for line in lines)
+
+
+def test_ordered_edition_is_only_shifted(tmp_path: Path):
+    (_, ordered), lines = _process(tmp_path, 100)
+    assert ordered == [
+        ("Main", "00:00:00.100000000", "00:10:00.100000000"),
+        ("Recap", "00:00:00.100000000", "00:01:00.100000000"),
+        # Plays a range of another file: never shifted
+        ("Linked", "00:00:30.000000000", "00:00:40.000000000"),
+    ]
+    assert any("ordered chapters" in line for line in lines)
+
+
+def _ordered_xml(count: int) -> str:
+    """Ordered edition alternating local and linked atoms."""
+    atoms = []
+    for i in range(count):
+        uid = (
+            f'<ChapterSegmentUID format="hex">{i + 1:016x}</ChapterSegmentUID>'
+            if i % 2
+            else ""
+        )
+        atoms.append(
+            f"<ChapterAtom>{uid}"
+            f"<ChapterTimeStart>00:00:{i:02d}.000000000</ChapterTimeStart>"
+            f"<ChapterDisplay><ChapterString>C{i}</ChapterString></ChapterDisplay>"
+            "</ChapterAtom>"
+        )
+    return (
+        '<?xml version="1.0"?><Chapters><EditionEntry>'
+        "<EditionUID>1</EditionUID><EditionFlagOrdered>1</EditionFlagOrdered>"
+        + "".join(atoms)
+        + "</EditionEntry></Chapters>"
+    )
+
+
+def test_interleaved_linked_atoms_stay_unshifted(tmp_path: Path):
+    # Linked atoms are matched by element, not by the id() of a proxy that
+    # may be freed and reused for a local atom
+    (ordered,), _ = _process(tmp_path, 500, _ordered_xml(40))
+    for i, (name, start, _) in enumerate(ordered):
+        assert name == f"C{i}"
+        expected = f"00:00:{i:02d}.000000000" if i % 2 else f"00:00:{i:02d}.500000000"
+        assert start == expected, name
@@ -0,0 +1,77 @@
+# tests/test_chunk_spacing.py
+"""
+Minimum spacing between correlation windows (ChunkPlacement.min_spacing_s),
+on a deliberately short input.
+
+Validates:
+1. Without a minimum every hop position is used (windows overlap)
+2. Uniform placement keeps window centres at least the spacing apart
+3. Endpoint placement uses fewer windows when the range can't fit the
+   requested count at the spacing
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation.dense import (  # noqa: E402
+    ChunkPlacement,
+    window_positions,
+)
+
+SR = 1000
+LENGTH = 60 * SR  # A one-minute file
+WINDOW_S = 10.0
+HOP_S = 2.0
+
+
+def _positions(placement: ChunkPlacement) -> list[int]:
+    positions, _, _ = window_positions(
+        LENGTH, SR, WINDOW_S, HOP_S, 0.0, 100.0, placement=placement
+    )
+    return positions
+
+
+def test_no_minimum_uses_every_hop():
+    positions = _positions(ChunkPlacement())
+
+    assert len(positions) == 26
+    assert positions[1] - positions[0] == HOP_S * SR
+
+
+def test_uniform_spacing_spreads_window_centres():
+    positions = _positions(ChunkPlacement(min_spacing_s=15.0))
+
+    assert positions == [0, 16 * SR, 32 * SR, 48 * SR]
+    gaps = [b - a for a, b in zip(positions, positions[1:])]
+    assert min(gaps) >= 15.0 * SR
+
+
+def test_endpoint_count_is_reduced_to_fit():
+    placement = ChunkPlacement(
+        strategy="endpoints", start_chunks=5, end_chunks=5, min_spacing_s=15.0
+    )
+
+    positions = _positions(placement)
+
+    assert len(positions) == 4  # 10 requested, only 4 fit 15s apart
+    gaps = [b - a for a, b in zip(positions, positions[1:])]
+    assert min(gaps) >= 15.0 * SR
+
+
+def test_endpoint_windows_never_overlap():
+    # A spacing below the window length still keeps endpoint windows apart
+    placement = ChunkPlacement(
+        strategy="endpoints", start_chunks=2, end_chunks=2, min_spacing_s=3.0
+    )
+
+    positions = _positions(placement)
+
+    gaps = [b - a for a, b in zip(positions, positions[1:])]
+    assert min(gaps) >= WINDOW_S * SR
@@ -0,0 +1,127 @@
+# tests/test_cli.py
+"""
+Tests for the headless command line (vsg_core.cli) parts that don't run
+external tools.
+
+Validates:
+1. Positional sources map to Source 1, Source 2, ...
+2. --set values are parsed as JSON, falling back to strings
+3. Layout files load as a saved job layout or a plain list of tracks,
+   with positions filled in; malformed layouts are rejected
+4. A layout that doesn't fit a job's tracks leaves the job untouched
+5. Bad arguments exit with code 2
+"""
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.cli import (  # noqa: E402
+    CliError,
+    apply_layout,
+    build_parser,
+    load_layout,
+    main,
+    parse_overrides,
+    source_map,
+)
+
+
+def _item(source, ttype, track_id, **extra):
+    return {"source": source, "type": ttype, "id": track_id, **extra}
+
+
+def test_source_map():
+    assert source_map(["a.mkv", "b.mkv"]) == {"Source 1": "a.mkv", "Source 2": "b.mkv"}
+
+
+def test_parse_overrides():
+    overrides = parse_overrides(["min_chunk_spacing_s=2.5", "output_folder=/out"])
+
+    assert overrides == {"min_chunk_spacing_s": 2.5, "output_folder": "/out"}
+    with pytest.raises(CliError):
+        parse_overrides(["no_equals_sign"])
+
+
+def test_load_plain_layout_list(tmp_path):
+    path = tmp_path / "layout.json"
+    path.write_text(
+        json.dumps(
+            [
+                _item("Source 1", "video", 0),
+                _item("Source 2", "audio", 1),
+                _item("Source 2", "audio", 2),
+            ]
+        )
+    )
+
+    layout = load_layout(path)
+
+    positions = [t["position_in_source_type"] for t in layout["enhanced_layout"]]
+    assert positions == [0, 0, 1]
+    assert layout["chapter_source"] == "Source 1"
+    assert layout["attachment_sources"] == []
+
+
+def test_load_saved_job_layout_keeps_order_and_fields(tmp_path):
+    path = tmp_path / "job.json"
+    saved = {
+        "enhanced_layout": [
+            _item("Source 2", "audio", 1, user_order_index=1),
+            _item("Source 1", "video", 0, user_order_index=0),
+        ],
+        "attachment_sources": ["Source 2"],
+        "chapter_source": "Source 2",
+    }
+    path.write_text(json.dumps(saved))
+
+    layout = load_layout(path)
+
+    assert [t["source"] for t in layout["enhanced_layout"]] == ["Source 1", "Source 2"]
+    assert layout["attachment_sources"] == ["Source 2"]
+    assert layout["chapter_source"] == "Source 2"
+
+
+@pytest.mark.parametrize(
+    "content", ['{"tracks": []}', '[{"source": "Source 1"}]', "not json"]
+)
+def test_malformed_layouts_are_rejected(tmp_path, content):
+    path = tmp_path / "bad.json"
+    path.write_text(content)
+
+    with pytest.raises(CliError):
+        load_layout(path)
+
+
+def test_layout_that_does_not_fit_leaves_job_untouched():
+    layout = {
+        "enhanced_layout": [
+            _item("Source 1", "video", 0, codec_id="V_MPEG4/ISO/AVC"),
+            _item("Source 2", "audio", 3, codec_id="A_AC3", position_in_source_type=2),
+        ],
+        "attachment_sources": [],
+        "source_settings": {},
+        "chapter_source": "Source 1",
+    }
+    track_info = {
+        "Source 1": [_item("Source 1", "video", 0, codec_id="V_MPEG4/ISO/AVC")],
+        "Source 2": [_item("Source 2", "audio", 1, codec_id="A_AC3")],
+    }
+    job = {"sources": {"Source 1": "/a.mkv", "Source 2": "/b.mkv"}}
+
+    problems = apply_layout(job, layout, track_info, "id_then_position")
+
+    assert problems == ["Source 2 audio track 3: not found in the new file"]
+    assert "manual_layout" not in job
+
+
+def test_bad_arguments_exit_with_2(tmp_path):
+    assert main(["scan", "a.mkv", "--settings", str(tmp_path / "missing.json")]) == 2
+    with pytest.raises(SystemExit) as exc:
+        build_parser().parse_args(["run", "a.mkv"])  # --layout is required
+    assert exc.value.code == 2
@@ -0,0 +1,197 @@
+# tests/test_coarse_offset.py
+"""
+Tests for the coarse whole-file pre-alignment
+(vsg_core.analysis.correlation.coarse).
+
+Validates:
+1. Sign convention (delay = ref_time - tgt_time): a target with an extra
+   intro gets a negative offset, a target missing the reference's head a
+   positive one
+2. shift_target() by that offset lines the target up with the reference
+3. Lags where the tracks overlap less than a quarter of the shorter one
+   are not considered, even when they would match
+4. Unrelated audio gives a low peak-to-sidelobe ratio (not reliable);
+   tracks shorter than two frames give None
+5. add_offset() moves every window's delay by the coarse offset
+6. The analysis step only shifts the target by a reliable coarse offset
+   (needs scipy for the steps package)
+
+Needs numpy. Offsets are whole 10 ms frames, so they are found exactly.
+"""
+
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation import coarse  # noqa: E402
+from vsg_core.analysis.correlation.coarse import (  # noqa: E402
+    MIN_COARSE_PSR,
+    CoarseAlignment,
+    add_offset,
+    coarse_offset,
+    shift_target,
+)
+from vsg_core.analysis.correlation.decode import DEFAULT_SR  # noqa: E402
+from vsg_core.analysis.types import ChunkResult  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+SR = 8000
+
+
+def _program(seconds: float, seed: int, sr: int = SR) -> "np.ndarray":
+    """Noise whose level jumps every 50-300 ms, like speech and cuts."""
+    rng = np.random.default_rng(seed)
+    n = int(seconds * sr)
+    gain = np.empty(n)
+    pos = 0
+    while pos < n:
+        seg = int(rng.integers(sr // 20, sr * 3 // 10))
+        gain[pos : pos + seg] = rng.uniform(0.01, 1.0)
+        pos += seg
+    return (rng.standard_normal(n) * gain).astype(np.float32)
+
+
+def _samples(ms: float) -> int:
+    return int(ms * SR / 1000)
+
+
+@pytest.fixture(scope="module")
+def ref() -> "np.ndarray":
+    return _program(60, seed=843)
+
+
+def test_extra_intro_in_target_is_negative(ref):
+    tgt = np.concatenate([np.zeros(_samples(3000), np.float32), ref])
+    result = coarse_offset(ref, tgt, SR, max_offset_s=10)
+    assert result.offset_ms == -3000.0
+    assert result.reliable
+    assert result.psr >= MIN_COARSE_PSR
+
+    aligned = shift_target(tgt, result.offset_ms, SR)
+    assert np.array_equal(aligned, ref)
+
+
+def test_missing_head_in_target_is_positive(ref):
+    tgt = ref[_samples(2500) :]
+    result = coarse_offset(ref, tgt, SR, max_offset_s=10)
+    assert result.offset_ms == 2500.0
+    assert result.reliable
+
+    aligned = shift_target(tgt, result.offset_ms, SR)
+    assert len(aligned) == len(ref)
+    assert not aligned[: _samples(2500)].any()  # Padded with silence
+    assert np.array_equal(aligned[_samples(2500) :], ref[_samples(2500) :])
+
+
+def test_offset_beyond_the_search_range_is_not_found(ref):
+    tgt = np.concatenate([np.zeros(_samples(3000), np.float32), ref])
+    result = coarse_offset(ref, tgt, SR, max_offset_s=2)
+    assert result.offset_ms != -3000.0
+    assert abs(result.offset_ms) <= 2000.0
+
+
+def test_low_overlap_lags_are_masked(ref, monkeypatch):
+    # The target's last 4 s are the reference's first 4 s: at that lag the
+    # 20 s tracks overlap by a fifth of their length
+    ref20 = ref[: _samples(20000)]
+    tgt = np.concatenate([_program(16, seed=844), ref[: _samples(4000)]])
+
+    result = coarse_offset(ref20, tgt, SR, max_offset_s=30)
+    assert result.offset_ms != -16000.0
+
+    monkeypatch.setattr(coarse, "_MIN_OVERLAP", 0.1)
+    result = coarse_offset(ref20, tgt, SR, max_offset_s=30)
+    assert result.offset_ms == -16000.0
+
+
+def test_unrelated_audio_is_not_reliable(ref):
+    result = coarse_offset(ref, _program(60, seed=845), SR, max_offset_s=3)
+    assert result is not None
+    assert not result.reliable
+    assert result.to_dict()["reliable"] is False
+
+
+def test_too_short_gives_none():
+    short = np.zeros(100, np.float32)  # One 10 ms frame
+    assert coarse_offset(short, short, SR, max_offset_s=1) is None
+
+
+def test_reliability_threshold():
+    assert CoarseAlignment(0.0, MIN_COARSE_PSR, 10.0, 30.0).reliable
+    assert not CoarseAlignment(0.0, MIN_COARSE_PSR - 0.1, 10.0, 30.0).reliable
+
+
+def test_shift_target_rounds_to_samples():
+    tgt = np.arange(1, 11, dtype=np.float32)
+    # 0.25 ms is 2 samples at 8 kHz
+    assert np.array_equal(shift_target(tgt, 0.25, SR)[:3], [0, 0, 1])
+    assert np.array_equal(shift_target(tgt, -0.25, SR), np.arange(3, 11))
+    assert np.array_equal(shift_target(tgt, 0.0, SR), tgt)
+
+
+def test_add_offset_moves_every_window():
+    results = [
+        ChunkResult(12, 12.4, 91.0, 30.0, True),
+        ChunkResult(-3, -2.6, 4.0, 60.0, False),
+    ]
+    moved = add_offset(results, -3000.0)
+    assert [r.raw_delay_ms for r in moved] == pytest.approx([-2987.6, -3002.6])
+    assert [r.delay_ms for r in moved] == [-2988, -3003]
+    # Everything else is kept
+    assert [(r.match_pct, r.start_s, r.accepted) for r in moved] == [
+        (91.0, 30.0, True),
+        (4.0, 60.0, False),
+    ]
+    assert results[0].raw_delay_ms == 12.4  # Inputs untouched
+
+
+# --- Analysis step ---
+
+
+def _prealign(tgt_seconds_extra: float | None, secondary: bool = False):
+    pytest.importorskip("scipy")  # The steps package pulls in the analysis stack
+    from vsg_core.orchestrator.steps.analysis_step import AnalysisStep
+
+    ref = _program(20, seed=846, sr=DEFAULT_SR)
+    if tgt_seconds_extra is None:
+        tgt = _program(20, seed=847, sr=DEFAULT_SR)  # Unrelated
+    else:
+        pad = np.zeros(int(tgt_seconds_extra * DEFAULT_SR), np.float32)
+        tgt = np.concatenate([pad, ref])
+    ctx = SimpleNamespace(
+        settings=AppSettings(coarse_prealign_max_offset_s=5.0),
+        coarse_alignments={},
+    )
+    lines: list[str] = []
+    shift = AnalysisStep()._coarse_prealign(
+        ctx, "Source 2", ref, tgt, lines.append, secondary
+    )
+    return shift, ctx.coarse_alignments, lines
+
+
+def test_step_shifts_by_a_reliable_offset():
+    shift, alignments, lines = _prealign(2.0)
+    assert shift == -2000.0
+    assert alignments["Source 2"].offset_ms == -2000.0
+    assert "windows are correlated around it" in lines[-1]
+
+
+def test_step_ignores_an_unreliable_offset():
+    shift, alignments, lines = _prealign(None)
+    assert shift == 0.0
+    # Still reported, but not applied
+    assert not alignments["Source 2"].reliable
+    assert "not distinct enough" in lines[-1]
+
+
+def test_step_secondary_tracks_are_not_recorded():
+    shift, alignments, _ = _prealign(2.0, secondary=True)
+    assert shift == -2000.0
+    assert alignments == {}
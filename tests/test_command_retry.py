@@ -0,0 +1,137 @@
+# tests/test_command_retry.py
+"""
+Tests for retrying external tool runs (CommandRunner.run_with_retry).
+
+subprocess.Popen is replaced by a fake that plays back scripted exits;
+time.sleep is replaced so backoff pauses are recorded, not waited out.
+
+Validates:
+1. A failure that looks transient (busy/locked file, I/O error, killed by
+   a signal) is retried and the later success is returned
+2. A deterministic failure is returned at once, also when its output
+   mentions a transient cause too, and so is a tool that can't start
+3. Retries stop at ``command_retries`` (or ``retries=``) with exponential
+   backoff, and the last failure is kept in ``last_failure``
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.io import runner as runner_module  # noqa: E402
+from vsg_core.io.runner import CommandRunner  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+
+class _Popen:
+    """Plays back ``exits`` ((returncode, output) pairs), one per process."""
+
+    exits: list[tuple[int, str]] = []
+    calls: list[list[str]] = []
+
+    def __init__(self, cmd, **kwargs):
+        _Popen.calls.append(cmd)
+        self.returncode, self._output = _Popen.exits.pop(0)
+
+    def communicate(self, input=None):
+        return self._output, None
+
+
+@pytest.fixture
+def sleeps(monkeypatch):
+    _Popen.calls = []
+    recorded: list[float] = []
+    monkeypatch.setattr(runner_module.subprocess, "Popen", _Popen)
+    monkeypatch.setattr(runner_module.time, "sleep", recorded.append)
+    return recorded
+
+
+def _runner(retries: int = 3) -> CommandRunner:
+    return CommandRunner(AppSettings(command_retries=retries), lambda line: None)
+
+
+def _run(runner: CommandRunner, *exits: tuple[int, str], **kwargs):
+    _Popen.exits = list(exits)
+    return runner.run_with_retry(["mkvmerge", "-o", "out.mkv"], {}, **kwargs)
+
+
+@pytest.mark.parametrize(
+    "output",
+    [
+        "Error: Device or resource busy\n",
+        "The process cannot access the file because it is being used by "
+        "another process.\n",
+        "av_interleaved_write_frame(): Input/output error\n",
+    ],
+)
+def test_transient_failure_is_retried(sleeps, output):
+    runner = _runner()
+    assert _run(runner, (2, output), (0, "done\n")) == "done\n"
+    assert len(_Popen.calls) == 2
+    assert sleeps == [2.0]
+    assert runner.last_failure is None
+
+
+def test_killed_process_is_retried(sleeps):
+    assert _run(_runner(), (-9, ""), (0, "done\n")) == "done\n"
+    assert len(_Popen.calls) == 2
+
+
+@pytest.mark.parametrize(
+    "output",
+    [
+        "Error: unrecognized option '--bogus'\n",
+        "Error: The file 'a.mkv' could not be opened: No such file or directory\n",
+        # A deterministic cause wins over a transient one
+        "I/O error while probing; Invalid argument\n",
+        # Neither: an unknown error is not retried either
+        "Error: something went wrong\n",
+    ],
+)
+def test_deterministic_failure_is_not_retried(sleeps, output):
+    runner = _runner()
+    assert _run(runner, (2, output), (0, "done\n")) is None
+    assert len(_Popen.calls) == 1
+    assert sleeps == []
+    assert runner.last_failure.returncode == 2
+    assert runner.last_failure.output == output
+
+
+def test_tool_that_cannot_start_is_not_retried(monkeypatch, sleeps):
+    def missing(cmd, **kwargs):
+        _Popen.calls.append(cmd)
+        raise FileNotFoundError(2, "No such file or directory", cmd[0])
+
+    monkeypatch.setattr(runner_module.subprocess, "Popen", missing)
+    runner = _runner()
+    assert runner.run_with_retry(["mkvmerge"], {}) is None
+    assert len(_Popen.calls) == 1
+    assert runner.last_failure.returncode is None
+
+
+def test_retries_stop_at_the_setting(sleeps):
+    runner = _runner(retries=2)
+    busy = (2, "Error: Device or resource busy\n")
+    assert _run(runner, busy, busy, busy, (0, "done\n")) is None
+
+    assert len(_Popen.calls) == 3  # First run + 2 retries
+    assert sleeps == [2.0, 4.0]
+    assert runner.last_failure.describe() == "mkvmerge (exit 2)"
+
+
+def test_retries_argument_overrides_the_setting(sleeps):
+    busy = (2, "Error: Device or resource busy\n")
+    assert _run(_runner(retries=3), busy, (0, "done\n"), retries=0) is None
+    assert len(_Popen.calls) == 1
+    assert sleeps == []
+
+
+def test_no_retries_by_default(sleeps):
+    runner = CommandRunner(AppSettings(), lambda line: None)
+    _Popen.exits = [(2, "Error: Device or resource busy\n"), (0, "done\n")]
+    assert runner.run_with_retry(["mkvmerge"], {}) is None
+    assert len(_Popen.calls) == 1
@@ -0,0 +1,91 @@
+# tests/test_confidence_metric.py
+"""
+Confidence metrics (vsg_core.analysis.correlation.confidence).
+
+Validates:
+1. A spurious, near-uniform correlation (cyclic/ambient audio) scores high
+   on normalized peak height but is rejected by peak-to-sidelobe ratio
+2. A sharp, isolated peak passes every metric
+3. The PSR scale maps 10 -> 0%, 15 -> 50%, 20 -> 100%
+4. configure_method sets the metric on waveform methods only
+
+Needs numpy only.
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation.confidence import (  # noqa: E402
+    psr_to_pct,
+    score_confidence,
+)
+from vsg_core.analysis.correlation.methods.gcc_phat import GccPhat  # noqa: E402
+from vsg_core.analysis.correlation.methods.spectrogram import (  # noqa: E402
+    SpectrogramCorrelation,
+)
+from vsg_core.analysis.correlation.run import configure_method  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+N = 20_000
+PEAK_IDX = 7_000
+MIN_MATCH_PCT = 50.0
+
+
+@pytest.fixture
+def near_uniform() -> np.ndarray:
+    """Correlation high at every lag, with the maximum barely above the rest."""
+    rng = np.random.default_rng(833)
+    corr = rng.uniform(0.85, 0.95, N)
+    corr[PEAK_IDX] = 0.96
+    return corr
+
+
+@pytest.fixture
+def sharp() -> np.ndarray:
+    """Low noise floor with one clear peak."""
+    rng = np.random.default_rng(834)
+    corr = rng.normal(0.0, 0.01, N)
+    corr[PEAK_IDX] = 0.8
+    return corr
+
+
+def test_psr_rejects_near_uniform_correlation_that_raw_peak_accepts(near_uniform):
+    raw = score_confidence("normalized_peak", near_uniform, PEAK_IDX, 1.0)
+    psr = score_confidence("peak_to_sidelobe", near_uniform, PEAK_IDX, 1.0)
+    assert raw >= MIN_MATCH_PCT
+    assert psr < MIN_MATCH_PCT
+    assert psr == 0.0
+
+
+@pytest.mark.parametrize(
+    "metric", ["normalized_peak", "peak_to_sidelobe", "peak_to_mean"]
+)
+def test_sharp_peak_passes_every_metric(sharp, metric):
+    assert score_confidence(metric, sharp, PEAK_IDX, 1.0) >= MIN_MATCH_PCT
+
+
+@pytest.mark.parametrize(
+    ("psr", "pct"),
+    [(5.0, 0.0), (10.0, 0.0), (15.0, 50.0), (20.0, 100.0), (40.0, 100.0)],
+)
+def test_psr_scale(psr, pct):
+    assert psr_to_pct(psr) == pytest.approx(pct)
+
+
+def test_native_is_not_scored_here(sharp):
+    with pytest.raises(ValueError):
+        score_confidence("native", sharp, PEAK_IDX, 1.0)
+
+
+def test_configure_method_sets_the_metric():
+    settings = AppSettings(correlation_confidence_metric="peak_to_mean")
+    assert configure_method(GccPhat(), settings).confidence_metric == "peak_to_mean"
+    spectrogram = SpectrogramCorrelation()
+    assert configure_method(spectrogram, settings) is spectrogram
@@ -0,0 +1,72 @@
+# tests/test_correlation_linear.py
+"""
+Linear (not circular) FFT correlation in the waveform methods.
+
+Validates:
+1. circular_to_lag maps the indices of a zero-padded correlation back to
+   their signed lags, also for windows of different lengths
+2. A delay longer than half the FFT size (a short window found late in a
+   long one) keeps its sign and size instead of wrapping to a negative lag
+
+The correlation tests need torch; skipped otherwise.
+"""
+
+import importlib.util
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation.lag_window import (  # noqa: E402
+    circular_to_lag,
+    linear_fft_size,
+)
+from vsg_core.analysis.correlation.methods.gcc_phat import GccPhat  # noqa: E402
+from vsg_core.analysis.correlation.methods.scc import Scc  # noqa: E402
+
+needs_torch = pytest.mark.skipif(
+    importlib.util.find_spec("torch") is None, reason="torch not available"
+)
+
+SR = 48000
+REF_S = 4.0
+TGT_S = 1.0
+DELAY_MS = 3000.0  # Beyond n_fft / 2 (about 2731 ms here)
+
+
+def test_circular_to_lag():
+    ref_len, tgt_len = 10, 4
+    n_fft = linear_fft_size(ref_len, tgt_len)
+
+    assert n_fft == 16
+    lags = [circular_to_lag(k, n_fft, ref_len) for k in range(n_fft)]
+    assert lags[:10] == list(range(10))  # 9 > n_fft // 2, still positive
+    assert lags[-3:] == [-3, -2, -1]
+    # Equal windows: split in the middle, as before
+    assert circular_to_lag(8, 16) == 8
+    assert circular_to_lag(9, 16) == -7
+
+
+def _late_window() -> tuple[np.ndarray, np.ndarray]:
+    """Noise and the 1 s of it starting ``DELAY_MS`` in."""
+    rng = np.random.default_rng(3)
+    ref = rng.standard_normal(int(REF_S * SR)).astype(np.float32)
+    start = int(DELAY_MS * SR / 1000)
+    return ref, ref[start : start + int(TGT_S * SR)].copy()
+
+
+@needs_torch
+@pytest.mark.parametrize("method_cls", [Scc, GccPhat])
+def test_long_lag_does_not_alias(method_cls):
+    ref, tgt = _late_window()
+    assert DELAY_MS * SR / 1000 > linear_fft_size(len(ref), len(tgt)) // 2
+
+    delay_ms, confidence = method_cls().find_delay(ref, tgt, SR)
+
+    assert delay_ms == pytest.approx(DELAY_MS, abs=1.0)
+    assert confidence > 0
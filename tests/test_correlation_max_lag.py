@@ -0,0 +1,84 @@
+# tests/test_correlation_max_lag.py
+"""
+Bounded lag search (correlation_max_lag_ms) for the waveform methods.
+
+Validates:
+1. A delay inside the bound is found as without a bound
+2. A delay beyond the bound is rejected (confidence 0) instead of being
+   reported as the best, wrong, peak inside the bound
+3. configure_method bounds SCC/GCC methods and leaves feature-domain
+   methods alone
+
+The correlation tests need torch; skipped otherwise.
+"""
+
+import importlib.util
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation.lag_window import lag_limit_samples  # noqa: E402
+from vsg_core.analysis.correlation.methods.gcc_phat import GccPhat  # noqa: E402
+from vsg_core.analysis.correlation.methods.scc import Scc  # noqa: E402
+from vsg_core.analysis.correlation.methods.spectrogram import (  # noqa: E402
+    SpectrogramCorrelation,
+)
+from vsg_core.analysis.correlation.run import configure_method  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+needs_torch = pytest.mark.skipif(
+    importlib.util.find_spec("torch") is None, reason="torch not available"
+)
+
+SR = 48000
+CHUNK_S = 4.0
+DELAY_MS = 300.0
+
+
+def _noise_pair() -> tuple[np.ndarray, np.ndarray]:
+    """White noise and a copy that starts ``DELAY_MS`` later."""
+    rng = np.random.default_rng(7)
+    n = int(CHUNK_S * SR)
+    lag = int(DELAY_MS * SR / 1000)
+    signal = rng.standard_normal(n + lag).astype(np.float32)
+    return signal[lag:], signal[:n]
+
+
+@needs_torch
+@pytest.mark.parametrize("method_cls", [Scc, GccPhat])
+def test_delay_inside_bound_is_found(method_cls):
+    ref, tgt = _noise_pair()
+    unbounded_ms, unbounded_conf = method_cls().find_delay(ref, tgt, SR)
+    bounded_ms, bounded_conf = method_cls(max_lag_ms=500.0).find_delay(ref, tgt, SR)
+
+    assert abs(abs(unbounded_ms) - DELAY_MS) < 1.0
+    assert bounded_ms == pytest.approx(unbounded_ms)
+    assert bounded_conf == pytest.approx(unbounded_conf)
+    assert bounded_conf > 0
+
+
+@needs_torch
+@pytest.mark.parametrize("method_cls", [Scc, GccPhat])
+def test_delay_beyond_bound_is_rejected(method_cls):
+    ref, tgt = _noise_pair()
+    delay_ms, confidence = method_cls(max_lag_ms=100.0).find_delay(ref, tgt, SR)
+
+    assert confidence == 0.0
+    assert abs(delay_ms) <= 100.0 + 1.0
+
+
+def test_configure_max_lag():
+    settings = AppSettings(correlation_max_lag_ms=250.0)
+
+    assert configure_method(GccPhat(), settings).max_lag_ms == 250.0
+    assert configure_method(Scc(), settings).max_lag_ms == 250.0
+    spectrogram = SpectrogramCorrelation()
+    assert configure_method(spectrogram, settings) is spectrogram
+    assert lag_limit_samples(0.0, SR) == 0
+    assert lag_limit_samples(250.0, SR) == 12000
@@ -0,0 +1,66 @@
+# tests/test_delay_rounding.py
+"""
+Tests for whole-ms delay rounding (vsg_core.models.rounding).
+
+Validates:
+1. nearest rounds exact halves to the even neighbour, on both signs
+2. toward_zero truncates, so negative delays never grow
+3. away_from_zero rounds exact halves outward, on both signs
+4. Zero stays zero and the sign never flips in any mode
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.rounding import round_delay_ms
+
+_HALVES = [0.5, -0.5, 1.5, -1.5, 2.5, -2.5, 0.0]
+
+
+@pytest.mark.parametrize(
+    ("mode", "expected"),
+    [
+        ("nearest", [0, 0, 2, -2, 2, -2, 0]),
+        ("toward_zero", [0, 0, 1, -1, 2, -2, 0]),
+        ("away_from_zero", [1, -1, 2, -2, 3, -3, 0]),
+    ],
+)
+def test_exact_halves(mode, expected):
+    assert [round_delay_ms(d, mode) for d in _HALVES] == expected
+
+
+@pytest.mark.parametrize(
+    ("mode", "positive", "negative"),
+    [
+        ("nearest", 2, -2),
+        ("toward_zero", 1, -1),
+        ("away_from_zero", 2, -2),
+    ],
+)
+def test_fractions_are_symmetric(mode, positive, negative):
+    assert round_delay_ms(1.7, mode) == positive
+    assert round_delay_ms(-1.7, mode) == negative
+
+
+@pytest.mark.parametrize("mode", ["nearest", "toward_zero", "away_from_zero"])
+def test_zero_and_sign(mode):
+    assert round_delay_ms(0.0, mode) == 0
+    assert round_delay_ms(-0.0, mode) == 0
+    assert round_delay_ms(-0.4, mode) == 0
+    assert round_delay_ms(-1001.825, mode) < 0
+
+
+def test_away_from_zero_below_half_rounds_down():
+    # Largest double below 0.5: floor(x + 0.5) would wrongly give 1
+    assert round_delay_ms(0.49999999999999994, "away_from_zero") == 0
+    assert round_delay_ms(-0.49999999999999994, "away_from_zero") == 0
+
+
+def test_default_matches_builtin_round():
+    for delay in (-1001.825, -0.5, 0.5, 2.5, 999.5):
+        assert round_delay_ms(delay) == round(delay)
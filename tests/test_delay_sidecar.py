@@ -0,0 +1,140 @@
+# tests/test_delay_sidecar.py
+"""
+Tests for per-job delay sidecars (vsg_core.analysis.delay_sidecar).
+
+Validates:
+1. A sidecar written from an analysis report loads back unchanged
+2. Files that are not JSON, not a version 1 sidecar or have malformed
+   entries raise ValueError
+3. check_sources() accepts exactly the job's sources and names the
+   missing and extra ones otherwise
+4. sidecar_path() puts the sidecar next to Source 1
+
+Needs numpy (the analysis package).
+"""
+
+import json
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")
+
+from vsg_core.analysis.delay_sidecar import (  # noqa: E402
+    SIDECAR_VERSION,
+    DelaySidecar,
+    SidecarDelay,
+    sidecar_path,
+)
+
+
+def _report():
+    """The parts of an AnalysisReport a sidecar is built from."""
+    return SimpleNamespace(
+        job_name="Episode 01",
+        sources=[
+            SimpleNamespace(
+                source_key="Source 2",
+                delay_ms=-120,
+                raw_delay_ms=-120.4123456789,
+                provenance="analyzed",
+            ),
+            SimpleNamespace(
+                source_key="Source 3",
+                delay_ms=40,
+                raw_delay_ms=40.0,
+                provenance="sidecar",
+            ),
+        ],
+    )
+
+
+def _write(tmp_path: Path, data) -> Path:
+    path = tmp_path / "ep01.delays.json"
+    text = data if isinstance(data, str) else json.dumps(data)
+    path.write_text(text, encoding="utf-8")
+    return path
+
+
+def test_sidecar_path():
+    assert sidecar_path("/media/show/ep01.mkv") == Path(
+        "/media/show/ep01.delays.json"
+    )
+
+
+def test_write_then_load_round_trip(tmp_path: Path):
+    sidecar = DelaySidecar.from_report(
+        _report(), {"Source 2": "/in/b/ep01.mkv", "Source 3": "/in/c/ep01.mka"}
+    )
+    path = sidecar.to_json(tmp_path / "ep01.delays.json")
+
+    loaded = DelaySidecar.load(path)
+    assert loaded.job_name == "Episode 01"
+    assert loaded.sources == {
+        "Source 2": SidecarDelay(-120, -120.412346, "analyzed", "ep01.mkv"),
+        "Source 3": SidecarDelay(40, 40.0, "sidecar", "ep01.mka"),
+    }
+    assert json.loads(path.read_text(encoding="utf-8"))["version"] == SIDECAR_VERSION
+
+
+def test_minimal_entry_gets_defaults(tmp_path: Path):
+    path = _write(
+        tmp_path, {"version": 1, "sources": {"Source 2": {"delay_ms": "-8"}}}
+    )
+    loaded = DelaySidecar.load(path)
+    assert loaded.job_name == ""
+    assert loaded.sources == {"Source 2": SidecarDelay(-8, -8.0, "analyzed", "")}
+
+
+@pytest.mark.parametrize(
+    ("content", "message"),
+    [
+        ("{not json", "Could not read"),
+        ("[1, 2]", "not a version 1"),
+        ({"sources": {}}, "not a version 1"),
+        ({"version": 2, "sources": {}}, "not a version 1"),
+        ({"version": 1, "sources": [{"delay_ms": 5}]}, "'sources'"),
+        ({"version": 1, "sources": {"Source 2": {}}}, "invalid entry for Source 2"),
+        (
+            {"version": 1, "sources": {"Source 2": {"delay_ms": "soon"}}},
+            "invalid entry for Source 2",
+        ),
+        ({"version": 1, "sources": {"Source 2": 120}}, "invalid entry"),
+    ],
+)
+def test_malformed_sidecars_are_rejected(tmp_path: Path, content, message):
+    path = _write(tmp_path, content)
+    with pytest.raises(ValueError, match=message):
+        DelaySidecar.load(path)
+
+
+def test_missing_file_is_rejected(tmp_path: Path):
+    with pytest.raises(ValueError, match="Could not read"):
+        DelaySidecar.load(tmp_path / "missing.delays.json")
+
+
+def test_check_sources():
+    sidecar = DelaySidecar(
+        "Episode 01",
+        {
+            "Source 2": SidecarDelay(-120, -120.4),
+            "Source 3": SidecarDelay(40, 40.0),
+        },
+    )
+    sidecar.check_sources(["Source 3", "Source 2"])
+
+    with pytest.raises(ValueError, match="missing Source 4") as exc:
+        sidecar.check_sources(["Source 2", "Source 3", "Source 4"])
+    assert "has" not in str(exc.value)
+
+    with pytest.raises(ValueError, match="has Source 3 which the job doesn't"):
+        sidecar.check_sources(["Source 2"])
+
+    with pytest.raises(ValueError) as exc:
+        sidecar.check_sources(["Source 2", "Source 4"])
+    assert "missing Source 4; has Source 3" in str(exc.value)
@@ -0,0 +1,84 @@
+# tests/test_dense_settings.py
+"""
+Tests for run_dense_from_settings(), the one place the dense correlation
+parameters are read from AppSettings.
+
+run_dense_correlation is replaced by a fake that records its arguments.
+
+Validates:
+1. Window, scan range, silence and detection settings reach
+   run_dense_correlation unchanged
+2. Without an explicit placement the settings' chunk placement is used;
+   an explicit one (e.g. with excluded chapters) wins
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation import dense  # noqa: E402
+from vsg_core.analysis.correlation.dense import (  # noqa: E402
+    ChunkPlacement,
+    run_dense_from_settings,
+)
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+
+@pytest.fixture
+def calls(monkeypatch):
+    recorded: list[dict] = []
+
+    def fake(**kwargs):
+        recorded.append(kwargs)
+        return []
+
+    monkeypatch.setattr(dense, "run_dense_correlation", fake)
+    return recorded
+
+
+def test_settings_reach_the_correlation(calls):
+    settings = AppSettings(
+        dense_window_s=15.0,
+        dense_hop_s=3.0,
+        dense_silence_threshold_db=-50.0,
+        dense_outlier_threshold_ms=30.0,
+        scan_start_ms=60000,
+        scan_end_ms=1200000,
+        detection_dbscan_epsilon_ms=10.0,
+        avoid_silence=True,
+        min_chunk_energy_db=-40.0,
+    )
+    run_dense_from_settings("ref", "tgt", 48000, "method", settings, 25.0)
+
+    (kwargs,) = calls
+    assert kwargs["ref_pcm"] == "ref"
+    assert kwargs["sr"] == 48000
+    assert kwargs["method"] == "method"
+    assert kwargs["min_match"] == 25.0
+    assert kwargs["window_s"] == 15.0
+    assert kwargs["hop_s"] == 3.0
+    assert kwargs["silence_threshold_db"] == -50.0
+    assert kwargs["outlier_threshold_ms"] == 30.0
+    assert (kwargs["start_ms"], kwargs["end_ms"]) == (60000, 1200000)
+    assert kwargs["start_pct"] == settings.scan_start_percentage
+    assert kwargs["dbscan_epsilon_ms"] == 10.0
+    assert kwargs["avoid_silence"] is True
+    assert kwargs["min_chunk_energy_db"] == -40.0
+
+
+def test_placement_defaults_to_the_settings(calls):
+    settings = AppSettings(chunk_strategy="endpoints", endpoint_chunks_start=3)
+    run_dense_from_settings("ref", "tgt", 48000, "method", settings, 25.0)
+    assert calls[0]["placement"] == ChunkPlacement.from_settings(settings)
+
+    excluded = ChunkPlacement(excluded_s=((0.0, 90.0),))
+    run_dense_from_settings(
+        "ref", "tgt", 48000, "method", settings, 25.0, placement=excluded
+    )
+    assert calls[1]["placement"] is excluded
@@ -0,0 +1,135 @@
+# tests/test_deterministic_uids.py
+"""
+Tests for deterministic track UIDs and reproducible output
+(vsg_core.mux.uids, vsg_core.mux.reproducible).
+
+Validates:
+1. The seed depends only on source filenames, track ids/types and order
+2. The builder passes it as mkvmerge --deterministic
+3. Reproducible plans leave out the date and statistics tags
+4. Two mkvmerge runs with the same seed give identical track UIDs, and
+   reproducible runs identical bytes (need mkvmerge on PATH)
+"""
+
+import json
+import shutil
+import subprocess
+import sys
+from dataclasses import replace
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.jobs import Delays, MergePlan, PlanItem
+from vsg_core.models.media import StreamProps, Track
+from vsg_core.models.settings import AppSettings
+from vsg_core.mux.options_builder import MkvmergeOptionsBuilder
+from vsg_core.mux.uids import track_key, uid_seed
+
+_SOURCES = {"Source 1": "/a/Show - 05.mkv", "Source 2": "/b/Show - 05 [JP].mkv"}
+
+
+def _item(source, track_id, type_, path="/tmp/t.bin"):
+    track = Track(
+        source=source,
+        id=track_id,
+        type=type_,
+        props=StreamProps(codec_id="S_TEXT/UTF8"),
+    )
+    return PlanItem(track=track, extracted_path=Path(path))
+
+
+def _items():
+    return [
+        _item("Source 1", 0, "video"),
+        _item("Source 2", 1, "audio"),
+        _item("Source 1", 2, "subtitles"),
+    ]
+
+
+def test_same_job_same_seed():
+    assert uid_seed(_items(), _SOURCES) == uid_seed(_items(), _SOURCES)
+    assert 0 < uid_seed(_items(), _SOURCES) < 2**31
+
+
+def test_seed_ignores_folders():
+    moved = {role: "/elsewhere/" + Path(p).name for role, p in _SOURCES.items()}
+    assert uid_seed(_items(), moved) == uid_seed(_items(), _SOURCES)
+
+
+def test_seed_changes_with_tracks():
+    base = uid_seed(_items(), _SOURCES)
+    assert uid_seed(_items()[::-1], _SOURCES) != base
+    assert uid_seed(_items()[:2], _SOURCES) != base
+    other = {**_SOURCES, "Source 1": "/a/Show - 06.mkv"}
+    assert uid_seed(_items(), other) != base
+
+
+def test_track_key():
+    item = _item("Source 2", 1, "audio")
+    assert track_key(item, _SOURCES) == "Show - 05 [JP].mkv:1:audio"
+    external = _item("External", 0, "subtitles")
+    assert track_key(external, _SOURCES) == "External:0:subtitles"
+
+
+def test_builder_passes_seed():
+    plan = MergePlan(items=[_item("Source 1", 0, "subtitles")], delays=Delays())
+    tokens = MkvmergeOptionsBuilder().build(plan, AppSettings())
+    assert "--deterministic" not in tokens
+    plan = replace(plan, uid_seed=12345)
+    tokens = MkvmergeOptionsBuilder().build(plan, AppSettings())
+    assert tokens[tokens.index("--deterministic") + 1] == "12345"
+
+
+def test_reproducible_tokens():
+    plan = MergePlan(
+        items=[_item("Source 1", 0, "subtitles")], delays=Delays(), reproducible=True
+    )
+    settings = AppSettings(disable_track_statistics_tags=False)
+    tokens = MkvmergeOptionsBuilder().build(plan, settings)
+    assert "--no-date" in tokens
+    assert "--disable-track-statistics-tags" in tokens
+
+
+needs_mkvmerge = pytest.mark.skipif(
+    shutil.which("mkvmerge") is None, reason="mkvmerge not available"
+)
+
+
+def _mux(tmp_path: Path, name: str, **plan_fields) -> Path:
+    srt = tmp_path / "subs.srt"
+    srt.write_text("1\n00:00:01,000 --> 00:00:02,000\nHello\n", encoding="utf-8")
+    items = [_item("Source 1", 0, "subtitles", str(srt))]
+    plan = MergePlan(
+        items=items, delays=Delays(), uid_seed=uid_seed(items, _SOURCES), **plan_fields
+    )
+    tokens = MkvmergeOptionsBuilder().build(plan, AppSettings())
+    out = tmp_path / name
+    subprocess.run(["mkvmerge", "-q", "-o", str(out), *tokens], check=True)
+    return out
+
+
+def _track_uids(path: Path) -> list[int]:
+    info = json.loads(
+        subprocess.run(
+            ["mkvmerge", "-J", str(path)], capture_output=True, check=True
+        ).stdout
+    )
+    return [t["properties"]["uid"] for t in info["tracks"]]
+
+
+@needs_mkvmerge
+def test_two_runs_give_identical_uids(tmp_path):
+    first = _track_uids(_mux(tmp_path, "run1.mkv"))
+    assert first
+    assert _track_uids(_mux(tmp_path, "run2.mkv")) == first
+
+
+@needs_mkvmerge
+def test_reproducible_runs_are_byte_identical(tmp_path):
+    first = _mux(tmp_path, "run1.mkv", reproducible=True)
+    second = _mux(tmp_path, "run2.mkv", reproducible=True)
+    assert first.read_bytes() == second.read_bytes()
@@ -0,0 +1,81 @@
+"""AC3/E-AC3 dialnorm parsing (vsg_core.mux.dialnorm).
+
+Frames are built bit by bit from the bit stream info layout, so every
+optional AC3 mix-level field (by acmod) is exercised.
+"""
+
+import pytest
+
+from vsg_core.mux.dialnorm import (
+    _packet_bytes,
+    dialnorm_gain_db,
+    parse_dialnorm,
+)
+
+
+def _frame(fields: list[tuple[int, int]]) -> bytes:
+    """Syncword followed by ``(value, bits)`` fields, zero-padded."""
+    bits = "".join(format(value, f"0{n}b") for value, n in fields)
+    bits += "0" * (-len(bits) % 8 + 64)
+    return b"\x0b\x77" + int(bits, 2).to_bytes(len(bits) // 8, "big")
+
+
+def _ac3(acmod: int, dialnorm: int, bsid: int = 8) -> bytes:
+    fields = [(0, 16), (0, 2), (0, 6), (bsid, 5), (0, 3), (acmod, 3)]
+    if acmod & 1 and acmod != 1:
+        fields.append((0b11, 2))  # cmixlev
+    if acmod & 4:
+        fields.append((0b11, 2))  # surmixlev
+    if acmod == 2:
+        fields.append((0b11, 2))  # dsurmod
+    fields += [(1, 1), (dialnorm, 5)]  # lfeon
+    return _frame(fields)
+
+
+def _eac3(dialnorm: int) -> bytes:
+    fields = [(0, 2), (0, 3), (0x7FF, 11), (0, 4), (0b1111, 4), (16, 5)]
+    return _frame(fields + [(dialnorm, 5)])
+
+
+@pytest.mark.parametrize("acmod", range(8))
+def test_ac3_every_channel_mode(acmod):
+    assert parse_dialnorm(_ac3(acmod, 27)) == -27
+
+
+def test_eac3():
+    assert parse_dialnorm(_eac3(24)) == -24
+
+
+def test_reserved_zero_means_neutral():
+    assert parse_dialnorm(_ac3(7, 0)) == -31
+
+
+def test_leading_garbage_is_skipped():
+    assert parse_dialnorm(b"\x00\xff" + _ac3(2, 20)) == -20
+
+
+@pytest.mark.parametrize(
+    "frame",
+    [
+        b"",
+        b"\x00" * 32,  # No syncword
+        b"\x0b\x77\x00\x00",  # Too short
+        _ac3(7, 27, bsid=20),  # Unknown bsid
+    ],
+)
+def test_unreadable_frames(frame):
+    assert parse_dialnorm(frame) is None
+
+
+def test_truncated_header():
+    assert parse_dialnorm(_ac3(7, 27)[:7]) is None
+
+
+def test_packet_hexdump():
+    dump = "00000000: 0b77 1234 5678                           .w.4Vx\n"
+    assert _packet_bytes(dump) == bytes.fromhex("0b7712345678")
+
+
+def test_gain_to_target():
+    assert dialnorm_gain_db(-27, -31) == -4
+    assert dialnorm_gain_db(-31, -24) == 7
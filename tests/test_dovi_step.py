@@ -0,0 +1,156 @@
+# tests/test_dovi_step.py
+"""
+Tests for Dolby Vision RPU extraction and injection (DoviStep).
+
+ffmpeg and dovi_tool are replaced by a fake runner.
+
+Validates:
+1. Extraction copies Source 1's first video stream to an Annex B file and
+   runs dovi_tool extract-rpu on it (with -m 2 when converting to 8.1);
+   the intermediate file is always removed
+2. An ffmpeg failure fails the job with the runner's failure instead of
+   passing a truncated or empty RPU on
+3. dovi_tool failing or writing an empty RPU means "no RPU" (skipped)
+4. Injection writes <stem>_dovi.hevc and points the video item at it; a
+   failed injection raises ProcessFailed
+"""
+
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+# The steps package pulls in the analysis stack
+pytest.importorskip("scipy")
+
+from vsg_core.errors import ProcessFailed  # noqa: E402
+from vsg_core.io.retry import CommandFailure  # noqa: E402
+from vsg_core.models.jobs import PlanItem  # noqa: E402
+from vsg_core.models.media import StreamProps, Track  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+from vsg_core.mux.dovi import DoviConfig  # noqa: E402
+from vsg_core.orchestrator.steps.dovi_step import DoviStep, _extract_rpu  # noqa: E402
+
+
+class _Runner:
+    """Writes each command's output file; ``fail`` names tools that fail."""
+
+    def __init__(self, fail: tuple[str, ...] = (), rpu: bytes = b"RPU"):
+        self.fail = fail
+        self.rpu = rpu
+        self.calls: list[list[str]] = []
+        self.last_failure: CommandFailure | None = None
+        self.lines: list[str] = []
+
+    def _log_message(self, message: str) -> None:
+        self.lines.append(message)
+
+    def run(self, cmd, tool_paths):
+        self.calls.append(cmd)
+        self.last_failure = None
+        if cmd[0] in self.fail or ("inject-rpu" in cmd and "inject" in self.fail):
+            self.last_failure = CommandFailure(cmd[0], 1, "boom")
+            return None
+        if "info" in cmd:
+            return "Summary:\n  Profile: 8.1\n"
+        out = Path(cmd[cmd.index("-o") + 1] if "-o" in cmd else cmd[-1])
+        out.write_bytes(self.rpu if "extract-rpu" in cmd else b"\0\0\1")
+        return ""
+
+    run_with_retry = run
+
+
+def _ctx(tmp_path: Path, target_profile: int = 0):
+    extracted = tmp_path / "extracted"
+    extracted.mkdir(exist_ok=True)
+    video = extracted / "video.hevc"
+    video.write_bytes(b"\0\0\1")
+    item = PlanItem(
+        track=Track("Source 1", 0, "video", StreamProps("V_MPEGH/ISO/HEVC")),
+        extracted_path=video,
+    )
+    return SimpleNamespace(
+        settings=AppSettings(dovi_inject=True, dovi_target_profile=target_profile),
+        tool_paths={"dovi_tool": "/usr/bin/dovi_tool"},
+        sources={"Source 1": "/in/ep01.mkv"},
+        extracted_items=[item],
+        delays=None,
+        work_dir=SimpleNamespace(extracted=extracted),
+    )
+
+
+def _extract(tmp_path: Path, runner: _Runner, target_profile: int = 0) -> bool:
+    ctx = _ctx(tmp_path, target_profile)
+    config = DoviConfig.from_settings(ctx.settings)
+    rpu = tmp_path / "extracted" / "rpu.bin"
+    return _extract_rpu("/in/ep01.mkv", rpu, config, ctx, runner)
+
+
+def test_extract_commands(tmp_path: Path):
+    runner = _Runner()
+    assert _extract(tmp_path, runner, target_profile=8)
+
+    hevc = str(tmp_path / "extracted" / "dovi_source1.hevc")
+    ffmpeg, dovi = runner.calls
+    assert ffmpeg[0] == "ffmpeg"
+    assert ffmpeg[ffmpeg.index("-i") + 1] == "/in/ep01.mkv"
+    assert ffmpeg[ffmpeg.index("-map") + 1] == "0:v:0"
+    assert ffmpeg[ffmpeg.index("-bsf:v") + 1] == "hevc_mp4toannexb"
+    assert ffmpeg[-3:] == ["-f", "hevc", hevc]
+    assert dovi == [
+        "dovi_tool",
+        "-m",
+        "2",
+        "extract-rpu",
+        "-i",
+        hevc,
+        "-o",
+        str(tmp_path / "extracted" / "rpu.bin"),
+    ]
+    assert not Path(hevc).exists()
+
+
+def test_extract_keeps_the_profile_by_default(tmp_path: Path):
+    runner = _Runner()
+    assert _extract(tmp_path, runner)
+    assert "-m" not in runner.calls[1]
+
+
+def test_ffmpeg_failure_fails_the_job(tmp_path: Path):
+    runner = _Runner(fail=("ffmpeg",))
+    with pytest.raises(ProcessFailed) as exc:
+        _extract(tmp_path, runner)
+    assert exc.value.failure.tool == "ffmpeg"
+    assert [c[0] for c in runner.calls] == ["ffmpeg"]  # dovi_tool never ran
+
+
+def test_no_rpu(tmp_path: Path):
+    assert not _extract(tmp_path, _Runner(fail=("dovi_tool",)))
+    assert not (tmp_path / "extracted" / "dovi_source1.hevc").exists()
+    assert not _extract(tmp_path, _Runner(rpu=b""))
+
+
+def test_inject(tmp_path: Path):
+    ctx = _ctx(tmp_path)
+    runner = _Runner()
+    DoviStep().run(ctx, runner)
+
+    item = ctx.extracted_items[0]
+    assert item.extracted_path == tmp_path / "extracted" / "video_dovi.hevc"
+    inject = runner.calls[-1]
+    assert inject[:2] == ["dovi_tool", "inject-rpu"]
+    assert inject[inject.index("--rpu-in") + 1] == str(
+        tmp_path / "extracted" / "dovi_source1_rpu.bin"
+    )
+    assert any("profile 8.1" in line for line in runner.lines)
+
+
+def test_failed_injection_raises(tmp_path: Path):
+    ctx = _ctx(tmp_path)
+    with pytest.raises(ProcessFailed, match="inject-rpu"):
+        DoviStep().run(ctx, _Runner(fail=("inject",)))
+    assert ctx.extracted_items[0].extracted_path.name == "video.hevc"
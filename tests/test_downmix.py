@@ -0,0 +1,130 @@
+# tests/test_downmix.py
+"""
+Channel selection for correlation decoding (downmix_args / resolve_downmix
+in vsg_core.analysis.correlation.decode).
+
+Validates:
+1. Each downmix mode maps to its ffmpeg options (-ac 1 or a pan filter)
+2. "mono_sum" and "none" never probe the stream
+3. ffprobe's JSON is read for the channel layout of the selected stream
+4. "center_only" is kept only for layouts with an FC channel (mono, 3.0,
+   3.1, 4.0, 4.1 and 5+ channels, not the "(back)" variants)
+5. "left_only" is kept for everything but mono
+6. Unknown layouts, failed probes and unreadable output fall back to
+   "mono_sum"
+
+Needs numpy.
+"""
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation.decode import (  # noqa: E402
+    downmix_args,
+    resolve_downmix,
+)
+
+
+def _ffprobe_json(channels: int, layout: str | None) -> str:
+    """ffprobe -show_entries stream=channels,channel_layout -of json output."""
+    stream: dict = {"channels": channels}
+    if layout is not None:  # ffprobe leaves it out when unknown
+        stream["channel_layout"] = layout
+    return json.dumps({"programs": [], "streams": [stream]}, indent=4)
+
+
+class _Runner:
+    def __init__(self, output):
+        self.output = output
+        self.commands: list[list[str]] = []
+
+    def run(self, cmd, tool_paths):
+        self.commands.append(cmd)
+        return self.output
+
+
+def _resolve(downmix: str, output, stream_index: int = 0):
+    runner = _Runner(output)
+    result = resolve_downmix(downmix, "ep01.mkv", stream_index, runner, {})
+    return result, runner.commands
+
+
+@pytest.mark.parametrize(
+    ("downmix", "args"),
+    [
+        ("mono_sum", ["-ac", "1"]),
+        ("none", ["-af", "pan=mono|c0=c0"]),
+        ("left_only", ["-af", "pan=mono|c0=FL"]),
+        ("center_only", ["-af", "pan=mono|c0=FC"]),
+    ],
+)
+def test_downmix_args(downmix, args):
+    assert downmix_args(downmix) == args
+
+
+@pytest.mark.parametrize("downmix", ["mono_sum", "none"])
+def test_modes_without_a_named_channel_do_not_probe(downmix):
+    result, commands = _resolve(downmix, _ffprobe_json(6, "5.1(side)"))
+    assert result == (downmix, "")
+    assert commands == []
+
+
+def test_probe_selects_the_audio_stream():
+    result, commands = _resolve("center_only", _ffprobe_json(6, "5.1(side)"), 2)
+    assert result == ("center_only", "5.1(side)")
+    (cmd,) = commands
+    assert cmd[0] == "ffprobe"
+    assert cmd[cmd.index("-select_streams") + 1] == "a:2"
+    assert cmd[-1] == "ep01.mkv"
+
+
+@pytest.mark.parametrize(
+    ("channels", "layout", "expected"),
+    [
+        (1, "mono", "center_only"),
+        (2, "stereo", "mono_sum"),
+        (3, "3.0", "center_only"),
+        (3, "3.0(back)", "mono_sum"),
+        (4, "3.1", "center_only"),
+        (4, "4.0", "center_only"),
+        (4, "quad", "mono_sum"),
+        (5, "4.1", "center_only"),
+        (6, "5.1", "center_only"),
+        (8, "7.1", "center_only"),
+    ],
+)
+def test_center_only_needs_a_front_centre(channels, layout, expected):
+    result, _ = _resolve("center_only", _ffprobe_json(channels, layout))
+    assert result == (expected, layout)
+
+
+@pytest.mark.parametrize(
+    ("channels", "layout", "expected"),
+    [
+        (1, "mono", "mono_sum"),
+        (2, "stereo", "left_only"),
+        (6, "5.1(side)", "left_only"),
+    ],
+)
+def test_left_only_needs_more_than_mono(channels, layout, expected):
+    result, _ = _resolve("left_only", _ffprobe_json(channels, layout))
+    assert result == (expected, layout)
+
+
+def test_unknown_layout_falls_back():
+    result, _ = _resolve("center_only", _ffprobe_json(6, None))
+    assert result == ("mono_sum", "6 channels")
+
+
+@pytest.mark.parametrize("output", [None, "", "not json", '{"streams": []}'])
+def test_failed_probe_falls_back(output):
+    (downmix, _), _ = _resolve("left_only", output)
+    assert downmix == "mono_sum"
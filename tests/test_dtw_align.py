@@ -0,0 +1,129 @@
+# tests/test_dtw_align.py
+"""
+Tests for band-limited DTW alignment (vsg_core.analysis.correlation.dtw).
+
+The target is built from the reference with whole-frame edits: a lead-in,
+300 ms of unrelated audio inserted at 20 s and 200 ms cut at 40 s. Away
+from the edits its frames are the reference's frames, so the warping path
+is known exactly.
+
+Validates:
+1. A constant offset is found exactly, with no stretch (delay sign:
+   ref_time - tgt_time, so a target with an extra lead-in is negative)
+2. The delay curve follows the piecewise offsets of the edited target,
+   both where audio was inserted and where it was cut
+3. The search is limited to the band around center_delay_ms
+4. A scan range that doesn't overlap the target gives None
+
+Needs numpy.
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation.dtw import DTW_FRAME_MS, dtw_align  # noqa: E402
+
+SR = 8000  # 50 ms frames are 400 samples
+
+
+def _program(seconds: float, seed: int) -> "np.ndarray":
+    """Noise whose level jumps every 50-300 ms, like speech and cuts."""
+    rng = np.random.default_rng(seed)
+    n = int(seconds * SR)
+    gain = np.empty(n)
+    pos = 0
+    while pos < n:
+        seg = int(rng.integers(SR // 20, SR * 3 // 10))
+        gain[pos : pos + seg] = rng.uniform(0.01, 1.0)
+        pos += seg
+    return (rng.standard_normal(n) * gain).astype(np.float32)
+
+
+def _samples(ms: float) -> int:
+    return int(ms * SR / 1000)
+
+
+@pytest.fixture(scope="module")
+def ref() -> "np.ndarray":
+    return _program(60, seed=789)
+
+
+def _lead_in(ref, ms: float = 500.0) -> "np.ndarray":
+    return np.concatenate([np.zeros(_samples(ms), np.float32), ref])
+
+
+def _edited(ref) -> "np.ndarray":
+    """500 ms lead-in, +300 ms at 20 s, -200 ms at 40 s."""
+    return np.concatenate(
+        [
+            np.zeros(_samples(500), np.float32),
+            ref[: _samples(20000)],
+            _program(0.3, seed=790),
+            ref[_samples(20000) : _samples(40000)],
+            ref[_samples(40200) :],
+        ]
+    )
+
+
+def _expected_delay_ms(ref_s: float) -> float:
+    if ref_s < 20.0:
+        return -500.0
+    if ref_s < 40.0:
+        return -800.0
+    return -600.0
+
+
+def test_constant_offset(ref):
+    result = dtw_align(ref, _lead_in(ref), SR, band_ms=1000.0)
+
+    assert result is not None
+    assert result.offset_ms == pytest.approx(-500.0)
+    assert result.slope == pytest.approx(1.0, abs=1e-6)
+    assert result.mean_cost < 0.01  # Same frames all along the path
+    assert result.frame_ms == DTW_FRAME_MS
+    # Scan range: 5-95 % of the reference by default
+    assert result.path[0][0] == pytest.approx(3.0, abs=0.1)
+    assert result.path[-1][0] == pytest.approx(57.0, abs=0.1)
+
+
+def test_warped_offset_curve_is_recovered(ref):
+    result = dtw_align(ref, _edited(ref), SR, band_ms=1000.0)
+    assert result is not None
+
+    curve = result.delay_curve(step_s=0.5)
+    # Away from the edits (the path crosses an edit within a few frames)
+    checked = [
+        (t, delay)
+        for t, delay in curve
+        if abs(t - 20.0) > 1.0 and abs(t - 40.2) > 1.0
+    ]
+    assert len(checked) > 90
+    for t, delay in checked:
+        assert delay == pytest.approx(_expected_delay_ms(t), abs=1e-6), t
+
+    # The path never goes backwards in either track
+    ref_t = [p[0] for p in result.path]
+    tgt_t = [p[1] for p in result.path]
+    assert ref_t == sorted(ref_t)
+    assert tgt_t == sorted(tgt_t)
+
+
+def test_search_stays_within_the_band(ref):
+    tgt = _lead_in(ref)
+    narrow = dtw_align(ref, tgt, SR, band_ms=200.0)
+    assert abs(narrow.offset_ms) < 250.0  # Within the 4-frame band, not -500
+
+    centred = dtw_align(ref, tgt, SR, band_ms=200.0, center_delay_ms=-500.0)
+    assert centred.offset_ms == pytest.approx(-500.0)
+
+
+def test_no_overlap_gives_none(ref):
+    # The band centre (tgt = ref + 2 min) lies past the end of the target
+    assert dtw_align(ref, ref, SR, band_ms=500.0, center_delay_ms=-120000.0) is None
@@ -0,0 +1,86 @@
+# tests/test_duplicate_tracks.py
+"""
+Tests for duplicate track detection (vsg_core.job_layouts.duplicates).
+
+Validates:
+1. Tracks from different sources with the same language, codec, channels
+   and near-identical duration are reported, with details
+2. Any mismatch (language, codec, channels, duration beyond the
+   tolerance) or the same source is not a duplicate
+3. Generated tracks are skipped and unknown durations are reported as such
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.job_layouts.duplicates import find_duplicate_tracks  # noqa: E402
+
+
+def _track(source, track_id, ttype, lang, codec, channels=0, duration_ms=None):
+    return {
+        "source": source,
+        "id": track_id,
+        "type": ttype,
+        "lang": lang,
+        "codec_id": codec,
+        "audio_channels": channels,
+        "duration_ms": duration_ms,
+    }
+
+
+TRACK_INFO = {
+    "Source 1": [
+        _track("Source 1", 1, "audio", "jpn", "A_FLAC", 2, 1_420_123),
+        _track("Source 1", 2, "audio", "jpn", "A_AC3", 6, 1_420_123),
+        _track("Source 1", 3, "subtitles", "eng", "S_TEXT/ASS"),
+    ],
+    "Source 2": [
+        _track("Source 2", 1, "audio", "jpn", "A_FLAC", 2, 1_420_135),
+        _track("Source 2", 2, "audio", "jpn", "A_AC3", 6, 1_390_000),
+        _track("Source 2", 3, "subtitles", "eng", "S_TEXT/ASS"),
+        _track("Source 2", 4, "audio", "eng", "A_FLAC", 2, 1_420_123),
+    ],
+}
+
+
+def _layout(*keys):
+    return [
+        {"source": f"Source {s}", "id": i, "type": t["type"]}
+        for s, i in keys
+        for t in TRACK_INFO[f"Source {s}"]
+        if t["id"] == i
+    ]
+
+
+def test_same_audio_from_two_sources_is_reported():
+    found = find_duplicate_tracks(_layout((1, 1), (2, 1), (2, 4)), TRACK_INFO)
+
+    assert len(found) == 1
+    assert (found[0].first, found[0].second) == (("Source 1", 1), ("Source 2", 1))
+    assert found[0].duration_diff_ms == 12
+    assert found[0].describe() == (
+        "Source 1 audio track 1 and Source 2 audio track 1 look identical: "
+        "jpn, A_FLAC, 2 ch, 23:40.1 (differ by 12 ms)"
+    )
+
+
+def test_mismatches_are_not_duplicates():
+    # AC3 pair: 30 s apart
+    assert find_duplicate_tracks(_layout((1, 2), (2, 2)), TRACK_INFO) == []
+    # Different codec/channels, and different language
+    assert find_duplicate_tracks(_layout((1, 2), (2, 1)), TRACK_INFO) == []
+    assert find_duplicate_tracks(_layout((1, 1), (2, 4)), TRACK_INFO) == []
+    # Same source twice
+    assert find_duplicate_tracks(_layout((1, 1), (1, 1)), TRACK_INFO) == []
+
+
+def test_generated_and_unknown_duration():
+    layout = _layout((1, 3), (2, 3))
+    generated = {**layout[1], "id": 9, "is_generated": True}
+
+    found = find_duplicate_tracks(layout + [generated], TRACK_INFO)
+    assert len(found) == 1
+    assert found[0].describe().endswith("S_TEXT/ASS, duration not known")
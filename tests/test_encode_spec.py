@@ -0,0 +1,58 @@
+"""Audio re-encode specs (vsg_core.mux.encode.EncodeSpec).
+
+Layout entries are parsed with ``from_dict``; unsupported codecs, channel
+counts and bitrates are rejected so a bad layout fails before ffmpeg runs.
+"""
+
+import pytest
+
+from vsg_core.mux.encode import EncodeSpec
+
+
+def test_from_dict_normalises_fields():
+    spec = EncodeSpec.from_dict(
+        {"codec": "AC3", "bitrate_kbps": "448", "channels": 2, "gain_db": "-3"}
+    )
+    assert spec == EncodeSpec("ac3", bitrate_kbps=448, channels=2, gain_db=-3.0)
+
+
+def test_from_dict_empty_values_mean_defaults():
+    spec = EncodeSpec.from_dict(
+        {"codec": "eac3", "bitrate_kbps": None, "channels": 0, "gain_db": None}
+    )
+    assert spec == EncodeSpec("eac3")
+
+
+def test_round_trip_through_dict():
+    spec = EncodeSpec("opus", bitrate_kbps=160, channels=2, gain_db=1.5)
+    assert EncodeSpec.from_dict(spec.to_dict()) == spec
+
+
+@pytest.mark.parametrize(
+    "data",
+    [
+        {"codec": "mp3"},
+        {},
+        {"codec": "ac3", "channels": 8},
+        {"codec": "ac3", "bitrate_kbps": -64},
+        {"codec": "ac3", "channels": "stereo"},
+    ],
+)
+def test_invalid_entries_are_rejected(data):
+    with pytest.raises(ValueError):
+        EncodeSpec.from_dict(data)
+
+
+def test_ffmpeg_args_use_default_bitrate():
+    assert EncodeSpec("ac3").ffmpeg_args() == ["-c:a", "ac3", "-b:a", "640k"]
+
+
+def test_lossless_ignores_bitrate():
+    args = EncodeSpec("flac", bitrate_kbps=320, channels=2).ffmpeg_args()
+    assert args == ["-c:a", "flac", "-ac", "2"]
+
+
+def test_gain_runs_before_extra_filters():
+    spec = EncodeSpec("aac", gain_db=-2.5, filters=("loudnorm=I=-23",))
+    assert spec.ffmpeg_args()[-2:] == ["-af", "volume=-2.5dB,loudnorm=I=-23"]
+    assert spec.describe() == "AAC, 256 kbps, -2.5 dB, loudnorm"
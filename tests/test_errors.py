@@ -0,0 +1,82 @@
+# tests/test_errors.py
+"""
+Tests for the typed pipeline errors (vsg_core.errors).
+
+Validates:
+1. error_kind finds the original error behind the orchestrator's
+   "... phase failed" wrappers, and classifies plain OSErrors as I/O
+2. The typed errors are still caught by the built-in types they replaced,
+   with the same messages
+3. ProcessFailed exposes the tool, exit code and output of the failure
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.errors import (  # noqa: E402
+    ErrorKind,
+    InvalidLayout,
+    ProcessFailed,
+    ToolMissing,
+    error_kind,
+)
+from vsg_core.io.retry import CommandFailure  # noqa: E402
+from vsg_core.mux.sync_targets import check_sync_targets  # noqa: E402
+
+
+def _wrapped(error):
+    try:
+        try:
+            raise error
+        except Exception as e:
+            raise RuntimeError(f"Extraction phase failed: {e}") from e
+    except RuntimeError as outer:
+        return outer
+
+
+def test_error_kind_follows_the_cause_chain():
+    wrapped = _wrapped(ToolMissing("Required tool 'mkvmerge' not found", "mkvmerge"))
+
+    assert error_kind(wrapped) == ErrorKind.TOOL_MISSING
+    assert str(wrapped) == (
+        "Extraction phase failed: Required tool 'mkvmerge' not found"
+    )
+
+
+def test_plain_errors_are_io_or_internal():
+    assert error_kind(_wrapped(PermissionError("denied"))) == ErrorKind.IO_ERROR
+    assert error_kind(KeyError("x")) == ErrorKind.INTERNAL
+
+
+def test_typed_errors_keep_their_builtin_bases():
+    layout = [
+        {"source": "External", "type": "subtitles", "id": 0, "sync_to": "Source 3"}
+    ]
+
+    try:
+        check_sync_targets(layout, {"Source 1", "Source 2"})
+    except ValueError as e:
+        assert isinstance(e, InvalidLayout)
+        assert error_kind(e) == ErrorKind.INVALID_LAYOUT
+        assert str(e).startswith("Invalid 'Sync to Source' in the layout")
+    else:
+        raise AssertionError("check_sync_targets did not raise")
+
+    assert isinstance(ToolMissing("x"), FileNotFoundError)
+    assert isinstance(ProcessFailed("x"), RuntimeError)
+
+
+def test_process_failed_details():
+    failure = CommandFailure("mkvextract", 2, "Error: no space left")
+    error = ProcessFailed("mkvextract failed", failure)
+
+    assert (error.tool, error.code, error.stderr) == (
+        "mkvextract",
+        2,
+        "Error: no space left",
+    )
+    assert ProcessFailed("x").code is None
+    assert ErrorKind.IO_ERROR.label == "I/O error"
@@ -0,0 +1,102 @@
+# tests/test_ffprobe_info.py
+"""
+Tests for the ffprobe probing fallback (vsg_core.extraction.ffprobe_info).
+
+Validates:
+1. Streams map to mkvmerge -J-style tracks (ids, types, codec ids, props)
+2. Data streams are skipped and attachments listed separately
+3. The info is marked as needing a remux; mkvmerge info is not
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.extraction.ffprobe_info import info_from_ffprobe, needs_remux
+
+_FFPROBE_TS = {
+    "streams": [
+        {
+            "index": 0,
+            "codec_type": "video",
+            "codec_name": "h264",
+            "width": 1920,
+            "height": 1080,
+            "start_time": "1.400000",
+        },
+        {
+            "index": 1,
+            "codec_type": "audio",
+            "codec_name": "ac3",
+            "channels": 6,
+            "sample_rate": "48000",
+            "start_time": "1.433000",
+            "tags": {"language": "jpn", "title": "Main"},
+            "disposition": {"default": 1, "forced": 0},
+        },
+        {"index": 2, "codec_type": "data", "codec_name": "bin_data"},
+        {
+            "index": 3,
+            "codec_type": "audio",
+            "codec_name": "pcm_s24le",
+            "channels": 2,
+            "sample_rate": "48000",
+            "bits_per_raw_sample": "24",
+        },
+        {"index": 4, "codec_type": "subtitle", "codec_name": "hdmv_pgs_subtitle"},
+        {
+            "index": 5,
+            "codec_type": "attachment",
+            "codec_name": "ttf",
+            "extradata_size": 1234,
+            "tags": {"filename": "font.ttf", "mimetype": "font/ttf"},
+        },
+    ],
+    "format": {"format_name": "mpegts", "duration": "1440.5"},
+}
+
+
+def test_streams_map_to_tracks():
+    info = info_from_ffprobe(_FFPROBE_TS)
+    tracks = info["tracks"]
+
+    assert [(t["id"], t["type"]) for t in tracks] == [
+        (0, "video"),
+        (1, "audio"),
+        (2, "audio"),
+        (3, "subtitles"),
+    ]
+    video, ac3, pcm, pgs = (t["properties"] for t in tracks)
+    assert video["codec_id"] == "V_MPEG4/ISO/AVC"
+    assert video["pixel_dimensions"] == "1920x1080"
+    assert video["minimum_timestamp"] == 1_400_000_000
+    assert ac3["codec_id"] == "A_AC3"
+    assert ac3["language"] == "jpn"
+    assert ac3["track_name"] == "Main"
+    assert ac3["audio_channels"] == 6
+    assert ac3["audio_sampling_frequency"] == 48000
+    assert ac3["default_track"] is True
+    assert pcm["codec_id"] == "A_PCM/INT/LIT"
+    assert pcm["audio_bits_per_sample"] == 24
+    assert pcm["language"] == "und"
+    assert "minimum_timestamp" not in pcm
+    assert pgs["codec_id"] == "S_HDMV/PGS"
+
+
+def test_attachments_and_container():
+    info = info_from_ffprobe(_FFPROBE_TS)
+
+    assert info["attachments"] == [
+        {"id": 1, "file_name": "font.ttf", "content_type": "font/ttf", "size": 1234}
+    ]
+    assert info["container"]["type"] == "mpegts"
+    assert info["container"]["supported"] is False
+    assert info["container"]["properties"]["duration"] == 1_440_500_000_000
+
+
+def test_needs_remux_marks_ffprobe_info_only():
+    assert needs_remux(info_from_ffprobe(_FFPROBE_TS))
+    assert not needs_remux({"container": {"recognized": True}, "tracks": []})
+    assert not needs_remux(None)
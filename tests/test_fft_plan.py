@@ -0,0 +1,118 @@
+# tests/test_fft_plan.py
+"""
+Cached FFT plans and the real/complex FFT paths (fft_plan.py).
+
+Validates:
+1. Same-size windows build one plan and reuse it
+2. The complex path finds the same delay as the real one
+3. configure_method sets the FFT path of SCC/GCC methods and leaves
+   feature-domain methods alone
+4. Plan counters are per thread, so concurrent jobs don't reset or count
+   into each other's
+
+The correlation tests need torch; skipped otherwise.
+"""
+
+import importlib.util
+import sys
+import threading
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation import gpu_backend  # noqa: E402
+from vsg_core.analysis.correlation.fft_plan import (  # noqa: E402
+    PlanStats,
+    get_fft_plan,
+    plan_stats,
+    reset_plan_stats,
+)
+from vsg_core.analysis.correlation.methods.gcc_phat import GccPhat  # noqa: E402
+from vsg_core.analysis.correlation.methods.scc import Scc  # noqa: E402
+from vsg_core.analysis.correlation.methods.spectrogram import (  # noqa: E402
+    SpectrogramCorrelation,
+)
+from vsg_core.analysis.correlation.run import configure_method  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+needs_torch = pytest.mark.skipif(
+    importlib.util.find_spec("torch") is None, reason="torch not available"
+)
+
+SR = 48000
+CHUNK_S = 1.0
+DELAY_MS = 120.0
+
+
+def _noise_pair(seed: int) -> tuple[np.ndarray, np.ndarray]:
+    """White noise and a copy that starts ``DELAY_MS`` later."""
+    rng = np.random.default_rng(seed)
+    n = int(CHUNK_S * SR)
+    lag = int(DELAY_MS * SR / 1000)
+    signal = rng.standard_normal(n + lag).astype(np.float32)
+    return signal[lag:], signal[:n]
+
+
+@needs_torch
+def test_same_size_windows_plan_once():
+    from vsg_core.analysis.correlation.gpu_backend import cleanup_gpu
+
+    cleanup_gpu()
+    reset_plan_stats()
+    method = GccPhat()
+    for seed in range(20):
+        delay_ms, _ = method.find_delay(*_noise_pair(seed), SR)
+        assert delay_ms == pytest.approx(DELAY_MS, abs=1.0)
+
+    stats = plan_stats()
+    assert (stats.built, stats.reused) == (1, 19)
+    assert "reused 19x" in stats.describe()
+
+
+@needs_torch
+@pytest.mark.parametrize("method_cls", [Scc, GccPhat])
+def test_complex_path_matches_real(method_cls):
+    ref, tgt = _noise_pair(1)
+    real_ms, real_conf = method_cls(fft_mode="real").find_delay(ref, tgt, SR)
+    complex_ms, complex_conf = method_cls(fft_mode="complex").find_delay(
+        ref, tgt, SR
+    )
+
+    assert complex_ms == pytest.approx(real_ms, abs=0.01)
+    assert complex_conf == pytest.approx(real_conf, rel=0.01)
+
+
+def test_configure_fft_mode_and_stats():
+    settings = AppSettings(correlation_fft_mode="complex")
+
+    assert configure_method(Scc(), settings).fft_mode == "complex"
+    assert configure_method(GccPhat(), settings).fft_mode == "complex"
+    spectrogram = SpectrogramCorrelation()
+    assert configure_method(spectrogram, settings) is spectrogram
+    assert PlanStats(1, 4, 0.002).describe() == (
+        "FFT plans: 1 built in 2.0 ms, reused 4x"
+    )
+
+
+def test_plan_stats_are_per_thread(monkeypatch):
+    # Every lookup hits the cache; no plan (or torch) is needed
+    monkeypatch.setattr(gpu_backend, "get_device", lambda: "cpu")
+    monkeypatch.setattr(gpu_backend, "get_cached", lambda key, build: "plan")
+
+    reset_plan_stats()
+    get_fft_plan(1024, SR)
+
+    def other_job():
+        reset_plan_stats()
+        for _ in range(3):
+            get_fft_plan(2048, SR)
+
+    job = threading.Thread(target=other_job)
+    job.start()
+    job.join()
+    assert (plan_stats().built, plan_stats().reused) == (0, 1)
@@ -0,0 +1,129 @@
+# tests/test_flag_policy.py
+"""
+Tests for the default/forced flag policy (vsg_core.mux.flags.FlagPolicy).
+
+Validates:
+1. Exactly one default audio and one default subtitle track, preferring
+   the configured language and keeping a default that already fits
+2. At most one forced subtitle track, and it is never the default
+3. Preserved originals lose their flags; types without tracks are skipped
+4. A default picked by hand (manual_default) beats the preferred language,
+   also after DefaultLanguage has run
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.jobs import PlanItem  # noqa: E402
+from vsg_core.models.media import StreamProps, Track  # noqa: E402
+from vsg_core.mux.flags import DefaultLanguage, FlagPolicy  # noqa: E402
+
+_ids = iter(range(1000))
+
+
+def _item(kind: str, lang: str, **flags) -> PlanItem:
+    track = Track("Source 1", next(_ids), kind, StreamProps("X", lang=lang))
+    return PlanItem(track=track, **flags)
+
+
+def _defaults(items: list[PlanItem], kind: str) -> list[str]:
+    return [
+        it.track.props.lang
+        for it in items
+        if it.track.type == kind and it.is_default
+    ]
+
+
+def test_one_default_per_type():
+    items = [
+        _item("video", "und", is_default=True),
+        _item("audio", "jpn", is_default=True),
+        _item("audio", "eng", is_default=True),
+        _item("subtitles", "eng"),
+        _item("subtitles", "spa"),
+    ]
+    changes = FlagPolicy(enabled=True).apply(items)
+    assert _defaults(items, "audio") == ["jpn"]
+    assert _defaults(items, "subtitles") == ["eng"]
+    assert items[0].is_default  # Video is left alone
+    assert len(changes) == 2
+
+
+def test_preferred_language_wins_over_existing_default():
+    items = [
+        _item("audio", "eng", is_default=True),
+        _item("audio", "jpn"),
+        _item("subtitles", "eng", is_default=True),
+        _item("subtitles", "spa"),
+    ]
+    FlagPolicy(enabled=True, audio_lang="jpn", subtitle_lang="spa").apply(items)
+    assert _defaults(items, "audio") == ["jpn"]
+    assert _defaults(items, "subtitles") == ["spa"]
+
+
+def test_existing_default_kept_when_language_missing():
+    items = [_item("audio", "eng"), _item("audio", "fre", is_default=True)]
+    changes = FlagPolicy(enabled=True, audio_lang="jpn").apply(items)
+    assert _defaults(items, "audio") == ["fre"]
+    assert changes == []
+
+
+def test_one_forced_track_and_never_default():
+    items = [
+        _item("subtitles", "eng", is_forced_display=True, is_default=True),
+        _item("subtitles", "jpn", is_forced_display=True),
+        _item("subtitles", "eng"),
+    ]
+    FlagPolicy(enabled=True, subtitle_lang="jpn").apply(items)
+    assert [it.is_forced_display for it in items] == [False, True, False]
+    # The track that lost its forced flag is regular again and can be default
+    assert [it.is_default for it in items] == [True, False, False]
+
+
+def test_preserved_original_loses_flags():
+    preserved = _item("audio", "jpn", is_default=True, is_preserved=True)
+    main = _item("audio", "jpn")
+    changes = FlagPolicy(enabled=True).apply([preserved, main])
+    assert not preserved.is_default
+    assert main.is_default
+    assert any("preserved original" in c for c in changes)
+
+
+def test_no_tracks_of_a_type():
+    items = [_item("audio", "jpn")]
+    assert FlagPolicy(enabled=True, subtitle_lang="eng").apply(items) != []
+    assert items[0].is_default
+    assert FlagPolicy(enabled=True).apply([]) == []
+
+
+def test_manual_default_beats_preferred_language():
+    items = [
+        # The layout marks every track of the type
+        _item("audio", "jpn", manual_default=True),
+        _item("audio", "eng", is_default=True, manual_default=True),
+    ]
+    changes = FlagPolicy(enabled=True, audio_lang="jpn").apply(items)
+    assert _defaults(items, "audio") == ["eng"]
+    assert changes == []
+
+
+def test_default_language_then_policy_keeps_manual_default():
+    items = [
+        _item("subtitles", "eng", manual_default=True),
+        _item("subtitles", "spa", is_default=True, manual_default=True),
+    ]
+    DefaultLanguage(subtitle_lang="eng").apply(items)
+    FlagPolicy(enabled=True, subtitle_lang="eng").apply(items)
+    assert _defaults(items, "subtitles") == ["spa"]
+
+
+def test_manual_type_without_default_gets_one():
+    items = [
+        _item("audio", "eng", manual_default=True),
+        _item("audio", "jpn", manual_default=True),
+    ]
+    FlagPolicy(enabled=True, audio_lang="jpn").apply(items)
+    assert _defaults(items, "audio") == ["jpn"]
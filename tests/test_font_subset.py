@@ -0,0 +1,121 @@
+# tests/test_font_subset.py
+"""
+Tests for font subsetting (vsg_core.subtitles.ass_fonts.subset_font).
+
+fontTools is replaced by a stub that copies the font, so the naming and
+fallback rules are checked without the real dependency; one test runs the
+real subsetter when fontTools is installed.
+
+Validates:
+1. Same-named fonts from different sources get different subset paths
+2. The subset keeps the source file name (the attachment name)
+3. Unsupported formats and empty character sets keep the full font
+"""
+
+import sys
+import types
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.subtitles.ass_fonts import subset_font  # noqa: E402
+
+
+@pytest.fixture
+def fake_fonttools(monkeypatch):
+    """fontTools.subset stand-in: 'subsetting' copies the source bytes."""
+    subset = types.ModuleType("fontTools.subset")
+
+    class Options:
+        pass
+
+    class Subsetter:
+        def __init__(self, options):
+            pass
+
+        def populate(self, text):
+            pass
+
+        def subset(self, font):
+            pass
+
+    subset.Options = Options
+    subset.Subsetter = Subsetter
+    subset.load_font = lambda path, options: Path(path).read_bytes()
+    subset.save_font = lambda font, path, options: Path(path).write_bytes(font)
+    package = types.ModuleType("fontTools")
+    package.subset = subset
+    monkeypatch.setitem(sys.modules, "fontTools", package)
+    monkeypatch.setitem(sys.modules, "fontTools.subset", subset)
+
+
+def _font(path: Path, content: bytes) -> Path:
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_bytes(content)
+    return path
+
+
+def test_same_name_from_two_sources_does_not_collide(tmp_path, fake_fonttools):
+    a = _font(tmp_path / "src2" / "Main.ttf", b"font from source 2")
+    b = _font(tmp_path / "src3" / "Main.ttf", b"font from source 3")
+    out_dir = tmp_path / "font_subsets"
+
+    out_a = subset_font(a, {"x"}, out_dir)
+    out_b = subset_font(b, {"y"}, out_dir)
+
+    assert out_a is not None and out_b is not None
+    assert out_a != out_b
+    assert out_a.name == out_b.name == "Main.ttf"
+    assert out_a.read_bytes() == b"font from source 2"
+    assert out_b.read_bytes() == b"font from source 3"
+
+
+def test_same_source_maps_to_same_path(tmp_path, fake_fonttools):
+    font = _font(tmp_path / "Main.otf", b"font")
+    out_dir = tmp_path / "font_subsets"
+    assert subset_font(font, {"a"}, out_dir) == subset_font(font, {"b"}, out_dir)
+
+
+def test_unsupported_or_empty_keeps_full_font(tmp_path, fake_fonttools):
+    out_dir = tmp_path / "font_subsets"
+    assert subset_font(_font(tmp_path / "a.ttc", b"c"), {"a"}, out_dir) is None
+    assert subset_font(_font(tmp_path / "a.ttf", b"t"), set(), out_dir) is None
+    assert not out_dir.exists()
+
+
+def test_real_subset_keeps_family_name(tmp_path):
+    pytest.importorskip("fontTools")
+    from fontTools.fontBuilder import FontBuilder
+    from fontTools.pens.ttGlyphPen import TTGlyphPen
+    from fontTools.ttLib import TTFont
+
+    def box():
+        pen = TTGlyphPen(None)
+        pen.moveTo((0, 0))
+        pen.lineTo((0, 500))
+        pen.lineTo((500, 500))
+        pen.closePath()
+        return pen.glyph()
+
+    names = [".notdef", "A", "B", "C"]
+    fb = FontBuilder(1000, isTTF=True)
+    fb.setupGlyphOrder(names)
+    fb.setupCharacterMap({ord("A"): "A", ord("B"): "B", ord("C"): "C"})
+    fb.setupGlyf({n: box() for n in names})
+    fb.setupHorizontalMetrics({n: (600, 0) for n in names})
+    fb.setupHorizontalHeader(ascent=800, descent=-200)
+    fb.setupNameTable({"familyName": "Test Sans", "styleName": "Regular"})
+    fb.setupOS2()
+    fb.setupPost()
+    source = tmp_path / "TestSans.ttf"
+    fb.save(str(source))
+
+    out = subset_font(source, {"A"}, tmp_path / "font_subsets")
+
+    assert out is not None and out.name == "TestSans.ttf"
+    font = TTFont(str(out))
+    assert set(font.getBestCmap()) == {ord("A")}
+    assert font["name"].getDebugName(1) == "Test Sans"
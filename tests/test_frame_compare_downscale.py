@@ -0,0 +1,67 @@
+"""Downscaled SSIM/MSE frame comparison (``downscale_width``).
+
+A 1920x1080 textured frame is compared against a re-encoded copy of itself
+(mild noise) and against a different frame. At 256 px wide SSIM must still
+put the copy inside the default match threshold (distance <= 10) and the
+other frame well outside it.
+
+Needs numpy and Pillow.
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+PILImage = pytest.importorskip("PIL.Image")
+
+from vsg_core.subtitles.frame_utils.frame_hashing import (  # noqa: E402
+    compare_frames,
+)
+
+SIZE = (1920, 1080)
+
+
+def _frame(seed: int) -> np.ndarray:
+    """Smooth texture: coarse noise upscaled, like real picture content."""
+    rng = np.random.default_rng(seed)
+    coarse = rng.integers(0, 256, size=(34, 60), dtype=np.uint8)
+    img = PILImage.fromarray(coarse, "L").resize(SIZE, PILImage.Resampling.BICUBIC)
+    return np.asarray(img, dtype=np.float64)
+
+
+def _image(arr: np.ndarray) -> "PILImage.Image":
+    return PILImage.fromarray(np.clip(arr, 0, 255).astype(np.uint8), "L")
+
+
+@pytest.fixture(scope="module")
+def frames():
+    base = _frame(819)
+    noise = np.random.default_rng(1).normal(0.0, 2.0, base.shape)
+    return _image(base), _image(base + noise), _image(_frame(820))
+
+
+@pytest.mark.parametrize("method", ["ssim", "mse"])
+def test_downscaled_compare_separates_match_from_mismatch(frames, method):
+    frame, copy, other = frames
+
+    match_dist, is_match = compare_frames(frame, copy, method, downscale_width=256)
+    other_dist, other_match = compare_frames(
+        frame, other, method, downscale_width=256
+    )
+
+    assert is_match
+    assert not other_match
+    assert other_dist > 2 * match_dist
+
+
+def test_downscale_agrees_with_full_resolution(frames):
+    frame, copy, other = frames
+    for candidate in (copy, other):
+        _, full = compare_frames(frame, candidate, "ssim")
+        _, small = compare_frames(frame, candidate, "ssim", downscale_width=256)
+        assert full == small
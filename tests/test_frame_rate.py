@@ -0,0 +1,72 @@
+# tests/test_frame_rate.py
+"""
+Tests for CFR/VFR detection from packet timestamps
+(vsg_core.extraction.frame_rate.classify_frame_timestamps).
+
+Timestamps are rounded to whole milliseconds like Matroska stores them,
+and given in decode order (B-frames) where that matters.
+
+Validates:
+1. CFR 23.976 (alternating 41/42 ms frames) snaps to 24000/1001
+2. Mixed 23.976/29.97 content is VFR with the dominant rate reported
+3. A handful of odd frames stays CFR; too few frames give no verdict
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.extraction.frame_rate import (  # noqa: E402
+    MIN_FRAMES,
+    classify_frame_timestamps,
+)
+
+
+def _cfr(fps: float, frames: int, start_ms: float = 0.0) -> list[float]:
+    return [float(round(start_ms + i * 1000.0 / fps)) for i in range(frames)]
+
+
+def test_cfr_film_snaps_to_standard_rate():
+    pts = _cfr(24000 / 1001, 2000)
+    # Decode order: each P-frame comes before the two B-frames it anchors
+    decode = []
+    for i in range(0, len(pts) - 2, 3):
+        decode += [pts[i + 2], pts[i], pts[i + 1]]
+    info = classify_frame_timestamps(decode)
+    assert info is not None
+    assert not info.is_vfr
+    assert info.fps_fraction == (24000, 1001)
+    assert info.fps == 24000 / 1001
+    assert info.describe().startswith("CFR 23.976 fps")
+
+
+def test_pal_is_cfr():
+    info = classify_frame_timestamps(_cfr(25.0, 500))
+    assert (info.is_vfr, info.fps_fraction) == (False, (25, 1))
+
+
+def test_mixed_rates_are_vfr():
+    film = _cfr(24000 / 1001, 1500)
+    video = _cfr(30000 / 1001, 500, start_ms=film[-1] + 1001 / 24)
+    info = classify_frame_timestamps(film + video)
+    assert info.is_vfr
+    assert info.fps_fraction == (24000, 1001)
+    assert 0.74 < info.dominant_share < 0.76
+    assert "VFR, dominant 23.976 fps" in info.describe()
+
+
+def test_few_odd_frames_stay_cfr():
+    pts = _cfr(25.0, 1000)
+    pts[500:] = [p + 20 for p in pts[500:]]  # One 60 ms gap
+    info = classify_frame_timestamps(pts)
+    assert not info.is_vfr
+    assert info.dominant_share > 0.99
+
+
+def test_duplicates_and_too_few_frames():
+    assert classify_frame_timestamps(_cfr(25.0, MIN_FRAMES - 1)) is None
+    # Duplicated timestamps don't count as extra frames
+    assert classify_frame_timestamps(_cfr(25.0, MIN_FRAMES - 1) * 2) is None
+    assert classify_frame_timestamps([]) is None
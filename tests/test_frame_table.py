@@ -0,0 +1,60 @@
+"""Timestamps-v2 parsing and the explicit frame grid (:class:`FrameTable`).
+
+Fixture ``tests/fixtures/cfr_23976_timestamps_v2.txt`` holds the first 720
+frames (30 s) of a 24000/1001 CFR track, as ``mkvextract timestamps_v2``
+writes them: a header, then one integer millisecond time per frame.
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.extraction.timestamps import parse_timestamps_v2  # noqa: E402
+from vsg_core.subtitles.frame_utils.frame_clock import FrameClock  # noqa: E402
+from vsg_core.subtitles.frame_utils.frame_table import FrameTable  # noqa: E402
+
+FIXTURE = PROJECT_ROOT / "tests" / "fixtures" / "cfr_23976_timestamps_v2.txt"
+CLOCK = FrameClock(24000, 1001)
+
+
+@pytest.fixture(scope="module")
+def table() -> FrameTable:
+    return FrameTable.from_timestamps(parse_timestamps_v2(FIXTURE.read_text()))
+
+
+def test_cfr_table_fps_matches_nominal(table):
+    assert len(table.times_ms) == 720
+    assert table.fps == pytest.approx(24000 / 1001, rel=1e-5)
+    assert (table.num, table.den) == (24000, 1001)
+
+
+def test_cfr_table_agrees_with_frame_clock(table):
+    for n in (0, 1, 12, 36, 500, 719):
+        assert table.frame_ms(n) == CLOCK.frame_ms(n)
+    for t in (0.0, 41.0, 42.0, 500.5, 1502.0, 20000.0, 29987.3):
+        assert table.frame_of(t) == CLOCK.frame_of(t)
+        assert table.is_on_frame(t) == CLOCK.is_on_frame(t)
+
+
+def test_vfr_table_maps_across_rate_switch():
+    # 10 frames at 24 fps, then 10 frames at 30 fps
+    times = [n * 1000 / 24 for n in range(10)]
+    times += [times[-1] + (n + 1) * 1000 / 30 for n in range(10)]
+    table = FrameTable.from_timestamps(times)
+
+    assert table.frame_ms(9) == 375
+    assert table.frame_ms(10) == 408
+    assert table.frame_of(400.0) == 10
+    assert table.is_on_frame(408.0)
+    assert not table.is_on_frame(400.0)
+    # Past the end: continues at the last (30 fps) frame duration
+    assert table.frame_ms(20) == table.frame_ms(19) + 33
+
+
+def test_missing_header_rejected():
+    with pytest.raises(ValueError):
+        parse_timestamps_v2("0\n42\n83\n")
@@ -0,0 +1,81 @@
+# tests/test_gpu_backend.py
+"""
+Tests for the shared GPU resource cache (gpu_backend) under concurrent jobs.
+
+Validates:
+1. Concurrent get_cached() calls for one key build it once
+2. cleanup_gpu() keeps the cache while another job is inside gpu_in_use()
+   and releases it once none is
+
+Needs numpy (the analysis package); torch is not needed.
+"""
+
+import sys
+import threading
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation import gpu_backend  # noqa: E402
+from vsg_core.analysis.correlation.gpu_backend import (  # noqa: E402
+    cleanup_gpu,
+    get_cached,
+    gpu_in_use,
+)
+
+
+@pytest.fixture(autouse=True)
+def empty_cache(monkeypatch):
+    monkeypatch.setattr(gpu_backend, "_transform_cache", {})
+
+
+def test_concurrent_lookups_build_once():
+    builds: list[int] = []
+    start = threading.Barrier(8)
+
+    def build():
+        builds.append(1)
+        return object()
+
+    found: list[object] = []
+
+    def lookup():
+        start.wait()
+        found.append(get_cached(("plan", 1024), build))
+
+    threads = [threading.Thread(target=lookup) for _ in range(8)]
+    for t in threads:
+        t.start()
+    for t in threads:
+        t.join()
+
+    assert len(builds) == 1
+    assert len({id(f) for f in found}) == 1
+
+
+def test_cleanup_waits_for_other_jobs():
+    plan = get_cached(("plan", 1024), object)
+    inside = threading.Event()
+    done = threading.Event()
+
+    def other_job():
+        with gpu_in_use():
+            inside.set()
+            done.wait(5)
+
+    job = threading.Thread(target=other_job)
+    job.start()
+    assert inside.wait(5)
+
+    assert cleanup_gpu() is False  # The other job is still correlating
+    assert get_cached(("plan", 1024), object) is plan
+
+    done.set()
+    job.join()
+    assert cleanup_gpu() is True
+    assert gpu_backend._transform_cache == {}
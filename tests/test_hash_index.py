@@ -0,0 +1,166 @@
+"""Precomputed perceptual-hash index (``vsg_core.subtitles.frame_utils.hash_index``).
+
+Validates:
+1. save() / load() round-trips the packed bits and the key fields
+2. nearest() finds the closest frame by Hamming distance, over the whole
+   video or a candidate list
+3. descriptors() score like a Hamming search (cosine = 1 - 2 * d / dim)
+4. get_hash_index() reuses a cached index, and rebuilds it when the source
+   changes or the file is unreadable
+
+Needs numpy. Building a real index (VapourSynth + torch) is not covered.
+"""
+
+import os
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.subtitles.frame_utils import hash_index  # noqa: E402
+from vsg_core.subtitles.frame_utils.hash_index import HashIndex  # noqa: E402
+
+HASH_SIZE = 8  # 64-bit hashes
+
+
+def _random_bits(num_frames: int, seed: int = 820) -> "np.ndarray":
+    rng = np.random.default_rng(seed)
+    shape = (num_frames, HASH_SIZE * HASH_SIZE)
+    return rng.integers(0, 2, size=shape, dtype=np.uint8)
+
+
+def _index(bits01: "np.ndarray", size: int = 1000, mtime_ns: int = 5) -> HashIndex:
+    return HashIndex(
+        algo="phash",
+        hash_size=HASH_SIZE,
+        bits=np.packbits(bits01, axis=1),
+        source_size=size,
+        source_mtime_ns=mtime_ns,
+    )
+
+
+def _flip(bits: "np.ndarray", n: int) -> "np.ndarray":
+    out = bits.copy()
+    out[:n] ^= 1
+    return out
+
+
+def test_save_load_round_trip(tmp_path: Path):
+    index = _index(_random_bits(50))
+    path = tmp_path / "nested" / "ref.phash8.hashidx.npz"
+    index.save(path)
+
+    loaded = HashIndex.load(path)
+    assert (loaded.algo, loaded.hash_size) == ("phash", HASH_SIZE)
+    assert (loaded.source_size, loaded.source_mtime_ns) == (1000, 5)
+    assert loaded.num_frames == 50
+    assert np.array_equal(loaded.bits, index.bits)
+    # Written via a temp file that is renamed into place
+    assert sorted(p.name for p in path.parent.iterdir()) == [path.name]
+
+
+def test_nearest_over_whole_video():
+    bits = _random_bits(40)
+    index = _index(bits)
+    assert index.nearest(bits[17]) == (17, 0)
+    assert index.nearest(_flip(bits[23], 3)) == (23, 3)
+
+
+def test_nearest_within_candidates():
+    bits = _random_bits(40)
+    index = _index(bits)
+    # Frame 23 is the true match but is not a candidate
+    frame, dist = index.nearest(_flip(bits[23], 3), frame_nums=[5, 6, 7])
+    assert frame in (5, 6, 7)
+    assert dist == min(int((bits[f] != _flip(bits[23], 3)).sum()) for f in (5, 6, 7))
+
+
+def test_descriptors_rank_like_hamming():
+    bits = _random_bits(4)
+    index = _index(bits)
+    desc = index.descriptors([0, 1, 2, 3])
+    assert np.allclose(np.linalg.norm(desc, axis=1), 1.0)
+
+    dim = HASH_SIZE * HASH_SIZE
+    for j in range(1, 4):
+        hamming = int((bits[0] != bits[j]).sum())
+        assert float(desc[0] @ desc[j]) == pytest.approx(1 - 2 * hamming / dim)
+
+
+def test_unpacked_clamps_frame_numbers():
+    bits = _random_bits(10)
+    index = _index(bits)
+    rows = index.unpacked([-3, 4, 99])
+    assert np.array_equal(rows, bits[[0, 4, 9]])
+
+
+# --- get_hash_index caching ---
+
+
+@pytest.fixture
+def cached(tmp_path: Path, monkeypatch):
+    video = tmp_path / "ref.mkv"
+    video.write_bytes(b"\0" * 64)
+    cache = tmp_path / "cache" / "ref.phash8.hashidx.npz"
+    builds: list[str] = []
+
+    def fake_build(video_path, algo, hash_size, **kwargs):
+        builds.append(algo)
+        stat = os.stat(video_path)
+        return HashIndex(
+            algo=algo,
+            hash_size=hash_size,
+            bits=np.packbits(_random_bits(3), axis=1),
+            source_size=stat.st_size,
+            source_mtime_ns=stat.st_mtime_ns,
+        )
+
+    monkeypatch.setattr(hash_index, "build_hash_index", fake_build)
+    monkeypatch.setattr(hash_index, "hash_index_cache_path", lambda *a: cache)
+    return video, cache, builds
+
+
+def test_index_is_built_once_then_reused(cached):
+    video, cache, builds = cached
+    first = hash_index.get_hash_index(str(video), "phash", HASH_SIZE)
+    assert cache.exists()
+    second = hash_index.get_hash_index(str(video), "phash", HASH_SIZE)
+    assert builds == ["phash"]
+    assert np.array_equal(first.bits, second.bits)
+
+
+def test_changed_source_rebuilds(cached):
+    video, cache, builds = cached
+    hash_index.get_hash_index(str(video), "phash", HASH_SIZE)
+    video.write_bytes(b"\0" * 128)  # New size
+    index = hash_index.get_hash_index(str(video), "phash", HASH_SIZE)
+    assert builds == ["phash", "phash"]
+    assert index.matches_source(video)
+
+
+def test_other_algorithm_in_file_rebuilds(cached):
+    video, cache, builds = cached
+    hash_index.get_hash_index(str(video), "phash", HASH_SIZE)
+    # Same file but asked for dHash: the stored key does not match
+    hash_index.get_hash_index(str(video), "dhash", HASH_SIZE)
+    assert builds == ["phash", "dhash"]
+    assert HashIndex.load(cache).algo == "dhash"
+
+
+def test_unreadable_cache_rebuilds(cached):
+    video, cache, builds = cached
+    cache.parent.mkdir(parents=True)
+    cache.write_bytes(b"not an npz")
+    hash_index.get_hash_index(str(video), "phash", HASH_SIZE)
+    assert builds == ["phash"]
+    assert HashIndex.load(cache).matches_source(video)
+
+
+def test_unsupported_algorithm_is_rejected():
+    with pytest.raises(ValueError, match="ssim"):
+        hash_index.build_hash_index("ref.mkv", "ssim", HASH_SIZE)
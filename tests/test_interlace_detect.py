@@ -0,0 +1,98 @@
+"""ffmpeg ``idet`` interlace detection (vsg_core.extraction.interlace).
+
+Fixtures ``tests/fixtures/idet_tff.log`` and ``idet_progressive.log`` are
+the stderr of ``ffmpeg -hide_banner -nostats ... -vf idet -f null -`` on a
+250-frame window of a telecined NTSC DVD and a progressive Blu-ray flagged
+"top first". Only the multi-frame counts may be read; the single-frame
+line above them must be ignored.
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.extraction.interlace import (  # noqa: E402
+    WINDOW_POSITIONS,
+    InterlaceInfo,
+    detect_interlaced,
+    parse_idet_output,
+)
+
+FIXTURES = PROJECT_ROOT / "tests" / "fixtures"
+TFF_LOG = (FIXTURES / "idet_tff.log").read_text()
+PROGRESSIVE_LOG = (FIXTURES / "idet_progressive.log").read_text()
+
+
+def test_parse_interlaced_window():
+    info = parse_idet_output(TFF_LOG)
+    assert info == InterlaceInfo(tff=229, bff=0, progressive=21, undetermined=1)
+    assert info.is_interlaced
+    assert info.field_order == "tff"
+    assert info.confidence == pytest.approx(229 / 250)
+
+
+def test_parse_progressive_window():
+    info = parse_idet_output(PROGRESSIVE_LOG)
+    assert info == InterlaceInfo(tff=0, bff=0, progressive=243, undetermined=8)
+    assert not info.is_interlaced
+    assert info.field_order == "progressive"
+    assert info.confidence == 1.0
+
+
+def test_parse_sums_repeated_reports():
+    info = parse_idet_output(TFF_LOG + PROGRESSIVE_LOG)
+    assert info == InterlaceInfo(tff=229, bff=0, progressive=264, undetermined=9)
+
+
+def test_parse_without_idet_lines():
+    assert parse_idet_output("ep01.mkv: No such file or directory\n") is None
+    assert parse_idet_output("") is None
+
+
+def test_verdict_edges():
+    assert InterlaceInfo(0, 0, 0, 250).field_order == "undetermined"
+    # Exactly the minimum interlaced share counts as interlaced
+    assert InterlaceInfo(10, 15, 75, 0).field_order == "bff"
+    assert InterlaceInfo(10, 14, 76, 0).field_order == "progressive"
+
+
+class _Runner:
+    """Hands out one canned ffmpeg log per call and records the commands."""
+
+    def __init__(self, outputs):
+        self.outputs = list(outputs)
+        self.commands: list[list[str]] = []
+
+    def run(self, cmd, tool_paths):
+        self.commands.append(cmd)
+        return self.outputs.pop(0)
+
+
+def _start(cmd: list[str]) -> str:
+    return cmd[cmd.index("-ss") + 1]
+
+
+def test_detect_sums_windows_and_skips_failures():
+    runner = _Runner([TFF_LOG, None, PROGRESSIVE_LOG])
+    info = detect_interlaced("ep01.mkv", runner, {}, duration_s=1000.0)
+    assert [_start(c) for c in runner.commands] == [
+        f"{1000.0 * pos:.3f}" for pos in WINDOW_POSITIONS
+    ]
+    assert info == InterlaceInfo(tff=229, bff=0, progressive=264, undetermined=9)
+    assert info.field_order == "tff"
+
+
+def test_detect_without_duration_reads_one_window():
+    runner = _Runner([PROGRESSIVE_LOG])
+    info = detect_interlaced("movie.mkv", runner, {})
+    assert [_start(c) for c in runner.commands] == ["0.000"]
+    assert info.field_order == "progressive"
+
+
+def test_detect_returns_none_when_every_window_fails():
+    runner = _Runner([None, "", "Conversion failed!\n"])
+    assert detect_interlaced("ep01.mkv", runner, {}, duration_s=60.0) is None
@@ -0,0 +1,191 @@
+"""Unit tests for episode extraction and cross-source pairing in
+vsg_core.job_discovery.
+
+Fixture ``tests/fixtures/episode_names.json`` collects real-world release
+naming schemes (fansub groups, scene WEB-DL/BluRay names, double episodes,
+specials) with their expected episode keys and Source 1 ↔ Source 2 pairs.
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.job_discovery import (  # noqa: E402
+    EpisodeKey,
+    discover_jobs,
+    extract_episode,
+    low_confidence_jobs,
+    normalize_name,
+    release_title,
+)
+
+FIXTURE = json.loads(
+    (PROJECT_ROOT / "tests" / "fixtures" / "episode_names.json").read_text(
+        encoding="utf-8"
+    )
+)
+
+
+@pytest.mark.parametrize(("filename", "expected"), FIXTURE["episodes"].items())
+def test_extract_episode(filename: str, expected: list) -> None:
+    season, episode, end_episode, special = expected
+    assert extract_episode(filename) == EpisodeKey(
+        episode=episode, season=season, end_episode=end_episode, special=special
+    )
+
+
+@pytest.mark.parametrize("filename", FIXTURE["no_episode"])
+def test_no_episode_in_movie_names(filename: str) -> None:
+    assert extract_episode(filename) is None
+
+
+def test_absolute_and_season_one_share_match_key() -> None:
+    absolute = extract_episode("Show - 05 [BD].mkv")
+    seasonal = extract_episode("Show.S01E05.WEB.mkv")
+    assert absolute is not None and seasonal is not None
+    assert absolute.match_key() == seasonal.match_key()
+
+
+def test_special_never_matches_regular_episode() -> None:
+    special = extract_episode("Show.S00E02.mkv")
+    regular = extract_episode("Show - 02.mkv")
+    assert special is not None and regular is not None
+    assert special.match_key() != regular.match_key()
+
+
+def test_special_word_in_title_is_not_a_special() -> None:
+    # Every episode would otherwise collide on "special 1"
+    keys = {extract_episode(f"Special Forces - {n:02d}.mkv") for n in (1, 2, 3)}
+    assert len(keys) == 3
+    assert release_title("[Group] Special Forces - 05 [BD].mkv") == "Special Forces"
+
+
+def test_normalize_strips_release_noise() -> None:
+    assert normalize_name("[Group] Show Name - 05 (1080p BD x264) [ABCD1234].mkv") == (
+        "show name 05"
+    )
+    assert normalize_name("Show.Name.05.1080p.WEB-DL.AAC2.0.H.264.mkv") == (
+        "show name 05"
+    )
+
+
+def _make_folders(tmp_path: Path, names1: list[str], names2: list[str]):
+    src1 = tmp_path / "src1"
+    src2 = tmp_path / "src2"
+    src1.mkdir()
+    src2.mkdir()
+    for name in names1:
+        (src1 / name).touch()
+    for name in names2:
+        (src2 / name).touch()
+    return src1, src2
+
+
+def test_fixture_pairs_are_discovered(tmp_path: Path) -> None:
+    pairs = FIXTURE["pairs"]
+    src1, src2 = _make_folders(
+        tmp_path, [a for a, _ in pairs], [b for _, b in pairs]
+    )
+    jobs = discover_jobs(
+        {"Source 1": str(src1), "Source 2": str(src2)}, strategy="normalized"
+    )
+    found = {
+        Path(j["sources"]["Source 1"]).name: Path(j["sources"]["Source 2"]).name
+        for j in jobs
+        if "Source 2" in j["sources"]
+    }
+    assert found == dict(pairs)
+    assert not low_confidence_jobs(jobs, 0.8)
+
+
+def test_exact_strategy_keeps_legacy_behavior(tmp_path: Path) -> None:
+    src1, src2 = _make_folders(
+        tmp_path,
+        ["Show - 05 [BD].mkv", "Same.mkv"],
+        ["Show.S01E05.WEB.mkv", "Same.mkv"],
+    )
+    jobs = discover_jobs({"Source 1": str(src1), "Source 2": str(src2)})
+    by_ref = {Path(j["sources"]["Source 1"]).name: j for j in jobs}
+    assert "Source 2" not in by_ref["Show - 05 [BD].mkv"]["sources"]
+    assert by_ref["Same.mkv"]["match_confidence"] == {"Source 2": 1.0}
+
+
+def test_ambiguous_episode_is_low_confidence(tmp_path: Path) -> None:
+    src1, src2 = _make_folders(
+        tmp_path,
+        ["Show - 05.mkv"],
+        ["Show.S01E05.WEB.mkv", "Show.S01E05.BluRay.mkv"],
+    )
+    jobs = discover_jobs(
+        {"Source 1": str(src1), "Source 2": str(src2)}, strategy="normalized"
+    )
+    assert low_confidence_jobs(jobs, 0.8)
+
+
+def test_regex_strategy(tmp_path: Path) -> None:
+    src1, src2 = _make_folders(
+        tmp_path, ["Disc1 Title 07.mkv"], ["Other Release - ep007.mkv"]
+    )
+    jobs = discover_jobs(
+        {"Source 1": str(src1), "Source 2": str(src2)},
+        strategy="regex",
+        episode_regex=r"(\d+)\D*$",
+    )
+    assert Path(jobs[0]["sources"]["Source 2"]).name == "Other Release - ep007.mkv"
+
+
+def test_invalid_regex_raises(tmp_path: Path) -> None:
+    src1, src2 = _make_folders(tmp_path, ["a.mkv"], ["b.mkv"])
+    with pytest.raises(ValueError):
+        discover_jobs(
+            {"Source 1": str(src1), "Source 2": str(src2)},
+            strategy="regex",
+            episode_regex="(",
+        )
+
+
+def test_multipart_source_is_kept_as_list(tmp_path: Path) -> None:
+    parts = [tmp_path / "Movie Disc1.mkv", tmp_path / "Movie Disc2.mkv"]
+    other = tmp_path / "Movie [BD].mkv"
+    for f in (*parts, other):
+        f.touch()
+    jobs = discover_jobs(
+        {"Source 1": str(other), "Source 2": [str(p) for p in parts]}
+    )
+    assert jobs == [
+        {
+            "sources": {
+                "Source 1": str(other),
+                "Source 2": [str(p) for p in parts],
+            },
+            "match_confidence": {"Source 2": 1.0},
+        }
+    ]
+
+
+def test_multipart_source_rejects_folders_and_missing_parts(tmp_path: Path) -> None:
+    ref = tmp_path / "ref.mkv"
+    ref.touch()
+    with pytest.raises(ValueError):
+        discover_jobs({"Source 1": str(ref), "Source 2": [str(tmp_path), "x.mkv"]})
+    with pytest.raises(FileNotFoundError):
+        discover_jobs(
+            {"Source 1": str(ref), "Source 2": [str(ref), str(tmp_path / "no.mkv")]}
+        )
+
+
+def test_other_episode_is_never_paired(tmp_path: Path) -> None:
+    src1, src2 = _make_folders(
+        tmp_path, ["Show - 05 [BD].mkv"], ["Show.S01E06.WEB.mkv"]
+    )
+    jobs = discover_jobs(
+        {"Source 1": str(src1), "Source 2": str(src2)}, strategy="normalized"
+    )
+    assert "Source 2" not in jobs[0]["sources"]
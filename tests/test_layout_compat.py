@@ -0,0 +1,175 @@
+# tests/test_layout_compat.py
+"""
+Tests for checking a layout against other files' tracks
+(vsg_core.job_layouts.validation.validate_against / rebind_layout), as
+used when pasting layouts in the job queue.
+
+Validates:
+1. Tracks match by id; a layout on identical files has no mismatches
+2. When ids moved, tracks match by type + position and are rebound
+3. Missing tracks, changed codecs and missing sources are reported
+4. Generated tracks only match through the id of their source track, and
+   follow it when it is rebound; external subtitles are never checked
+5. The "id" and "position" strategies use only one of the two, and the
+   match of each track is logged
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.job_layouts.validation import (  # noqa: E402
+    rebind_layout,
+    validate_against,
+)
+
+
+def _track(track_id, ttype, codec_id):
+    return {"source": "Source 1", "id": track_id, "type": ttype, "codec_id": codec_id}
+
+
+def _item(track_id, ttype, codec_id, position, **extra):
+    return {
+        "source": "Source 1",
+        "id": track_id,
+        "type": ttype,
+        "codec_id": codec_id,
+        "position_in_source_type": position,
+        **extra,
+    }
+
+
+LAYOUT = [
+    _item(0, "video", "V_MPEGH/ISO/HEVC", 0),
+    _item(1, "audio", "A_FLAC", 0),
+    _item(2, "subtitles", "S_TEXT/ASS", 0),
+    {"source": "External", "id": 0, "type": "subtitles", "codec_id": "S_TEXT/UTF8"},
+]
+
+
+def test_identical_tracks_match():
+    info = {
+        "Source 1": [
+            _track(0, "video", "V_MPEGH/ISO/HEVC"),
+            _track(1, "audio", "A_FLAC"),
+            _track(2, "subtitles", "S_TEXT/ASS"),
+        ]
+    }
+
+    assert validate_against(LAYOUT, info) == []
+    assert [t["id"] for t in rebind_layout(LAYOUT, info)] == [0, 1, 2, 0]
+
+
+def test_moved_ids_fall_back_to_type_and_position():
+    # An extra audio track at the front shifts every id by one
+    info = {
+        "Source 1": [
+            _track(0, "audio", "A_AC3"),
+            _track(1, "video", "V_MPEGH/ISO/HEVC"),
+            _track(2, "audio", "A_FLAC"),
+            _track(3, "subtitles", "S_TEXT/ASS"),
+        ]
+    }
+    layout = [LAYOUT[0], _item(2, "audio", "A_FLAC", 1), LAYOUT[2]]
+
+    assert validate_against(layout, info) == []
+    assert [t["id"] for t in rebind_layout(layout, info)] == [1, 2, 3]
+
+
+def test_mismatches_are_reported():
+    info = {
+        "Source 1": [
+            _track(0, "video", "V_MPEGH/ISO/HEVC"),
+            _track(1, "audio", "A_AAC"),
+        ]
+    }
+
+    mismatches = validate_against(LAYOUT, info)
+
+    assert [m.describe() for m in mismatches] == [
+        "Source 1 audio track 1: codec changed (A_FLAC -> A_AAC)",
+        "Source 1 subtitles track 2: not found in the new file",
+    ]
+    assert validate_against(LAYOUT, {})[0].reason == "Source 1 is not part of the job"
+
+
+def test_generated_tracks_match_by_source_track_only():
+    info = {"Source 1": [_track(0, "subtitles", "S_TEXT/ASS")]}
+    generated = _item(
+        2, "subtitles", "S_TEXT/ASS", 1, is_generated=True, source_track_id=2
+    )
+
+    assert [m.track_id for m in validate_against([generated], info)] == [2]
+    generated["source_track_id"] = 0
+    assert validate_against([generated], info) == []
+
+
+def test_generated_tracks_follow_their_rebound_source_track():
+    # Subtitle track 2 is now track 3; track 2 is an audio track
+    info = {
+        "Source 1": [
+            _track(0, "video", "V_MPEGH/ISO/HEVC"),
+            _track(1, "audio", "A_FLAC"),
+            _track(2, "audio", "A_AC3"),
+            _track(3, "subtitles", "S_TEXT/ASS"),
+        ]
+    }
+    generated = _item(
+        2, "subtitles", "S_TEXT/ASS", 0, is_generated=True, source_track_id=2
+    )
+    layout = [LAYOUT[2], generated]
+    lines = []
+
+    assert validate_against(layout, info) == []
+    rebound = rebind_layout(layout, info, log=lines.append)
+
+    assert [(t["id"], t.get("source_track_id")) for t in rebound] == [
+        (3, None),
+        (3, 3),
+    ]
+    assert generated["source_track_id"] == 2  # The layout itself is unchanged
+    assert lines[1] == (
+        "[Layout] Source 1 subtitles track 2 (generated): from track 3, by id"
+    )
+
+
+SHIFTED = {
+    "Source 1": [
+        _track(0, "audio", "A_AC3"),
+        _track(1, "audio", "A_FLAC"),
+    ]
+}
+
+
+def test_id_strategy_does_not_fall_back():
+    layout = [_item(5, "audio", "A_FLAC", 1)]
+
+    assert validate_against(layout, SHIFTED, "id")[0].reason == (
+        "not found in the new file"
+    )
+    assert validate_against(layout, SHIFTED, "id_then_position") == []
+
+
+def test_position_strategy_ignores_ids():
+    # Id 0 exists (A_AC3) but is the first audio track, not the second
+    layout = [_item(0, "audio", "A_FLAC", 1)]
+
+    assert validate_against(layout, SHIFTED, "position") == []
+    assert [t["id"] for t in rebind_layout(layout, SHIFTED, "position")] == [1]
+    assert validate_against(layout, SHIFTED, "id_then_position")[0].reason == (
+        "codec changed (A_FLAC -> A_AC3)"
+    )
+
+
+def test_rebind_logs_each_match():
+    lines = []
+    layout = [_item(5, "audio", "A_FLAC", 1), _item(0, "audio", "A_AC3", 0)]
+
+    rebind_layout(layout, SHIFTED, "id_then_position", lines.append)
+
+    assert lines == [
+        "[Layout] Source 1 audio track 5 -> track 1 (by position)",
+        "[Layout] Source 1 audio track 0 -> track 0 (by id)",
+    ]
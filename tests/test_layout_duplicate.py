@@ -0,0 +1,119 @@
+# tests/test_layout_duplicate.py
+"""
+Tests for duplicating a job layout onto other sources
+(JobLayoutManager.duplicate_layout).
+
+Validates:
+1. A layout whose tracks all exist in the new files has no problems and
+   is re-pointed at the new files
+2. Missing tracks and changed codecs are reported and stored as
+   needs_review
+3. A missing source layout saves nothing
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.job_layouts import JobLayoutManager  # noqa: E402
+
+
+def _track(source, track_id, ttype, codec_id):
+    return {
+        "source": source,
+        "id": track_id,
+        "type": ttype,
+        "codec_id": codec_id,
+        "lang": "eng",
+    }
+
+
+def _track_info(audio_codec="A_AC3", subs=True):
+    info = {
+        "Source 1": [
+            _track("Source 1", 0, "video", "V_MPEG4/ISO/AVC"),
+            _track("Source 1", 1, "audio", "A_AC3"),
+        ],
+        "Source 2": [_track("Source 2", 0, "audio", audio_codec)],
+    }
+    if subs:
+        info["Source 2"].append(_track("Source 2", 1, "subtitles", "S_TEXT/ASS"))
+    return info
+
+
+def _layout(old_dir):
+    def item(source, track_id, ttype, codec_id, **extra):
+        return {
+            "source": source,
+            "id": track_id,
+            "type": ttype,
+            "codec_id": codec_id,
+            "original_path": f"{old_dir}/{source.replace(' ', '')}.mkv",
+            **extra,
+        }
+
+    return [
+        item("Source 1", 0, "video", "V_MPEG4/ISO/AVC"),
+        item("Source 2", 0, "audio", "A_AC3"),
+        item("Source 2", 1, "subtitles", "S_TEXT/ASS"),
+        item(
+            "Source 2",
+            1,
+            "subtitles",
+            "S_TEXT/ASS",
+            is_generated=True,
+            source_track_id=1,
+            filter_config={"mode": "include", "kept_indices": [1, 2]},
+        ),
+        {"source": "External", "id": 0, "type": "subtitles", "codec_id": "S_TEXT/UTF8"},
+    ]
+
+
+def _manager(tmp_path):
+    manager = JobLayoutManager(str(tmp_path), log_callback=lambda msg: None)
+    old_sources = {"Source 1": "/old/Source1.mkv", "Source 2": "/old/Source2.mkv"}
+    old_id = manager.generate_job_id(old_sources)
+    manager.save_job_layout(old_id, _layout("/old"), [], old_sources, _track_info())
+    return manager, old_id
+
+
+def test_matching_tracks_have_no_problems(tmp_path):
+    manager, old_id = _manager(tmp_path)
+    new_sources = {"Source 1": "/new/ep2.mkv", "Source 2": "/new/ep2_jp.mkv"}
+    new_id = manager.generate_job_id(new_sources)
+
+    problems = manager.duplicate_layout(old_id, new_id, new_sources, _track_info())
+
+    assert problems == []
+    data = manager.load_job_layout(new_id)
+    assert "needs_review" not in data
+    assert data["sources"] == new_sources
+    assert data["copied_from"] == old_id
+    paths = {t["source"]: t.get("original_path") for t in data["enhanced_layout"]}
+    assert paths["Source 1"] == "/new/ep2.mkv"
+    assert paths["Source 2"] == "/new/ep2_jp.mkv"
+    generated = next(t for t in data["enhanced_layout"] if t.get("is_generated"))
+    assert "kept_indices" not in generated["filter_config"]
+
+
+def test_incompatible_tracks_need_review(tmp_path):
+    manager, old_id = _manager(tmp_path)
+    new_sources = {"Source 1": "/new/ep2.mkv", "Source 2": "/new/ep2_jp.mkv"}
+    new_id = manager.generate_job_id(new_sources)
+    track_info = _track_info(audio_codec="A_EAC3", subs=False)
+
+    problems = manager.duplicate_layout(old_id, new_id, new_sources, track_info)
+
+    assert len(problems) == 3
+    assert any("A_AC3 -> A_EAC3" in p for p in problems)
+    assert sum("not found" in p for p in problems) == 2  # subs + generated
+    assert manager.load_job_layout(new_id)["needs_review"] == problems
+
+
+def test_missing_source_layout_saves_nothing(tmp_path):
+    manager = JobLayoutManager(str(tmp_path), log_callback=lambda msg: None)
+
+    assert manager.duplicate_layout("nope", "new", {"Source 1": "/a.mkv"}, {}) is None
+    assert not manager.layout_exists("new")
@@ -0,0 +1,117 @@
+# tests/test_layout_history.py
+"""
+Tests for the manual selection's undo/redo history
+(vsg_qt/manual_selection_dialog/history.py).
+
+history.py is loaded on its own: the dialog package imports Qt.
+
+Validates:
+1. Undo and redo step through the committed snapshots, and return None
+   at either end
+2. Committing an unchanged list records nothing; committing after an undo
+   drops the states that could have been redone
+3. At most ``limit`` undo steps are kept, the oldest going first
+4. reset() (the dialog's reset_history) makes one state the bottom of the
+   history, and clear() forgets everything
+5. Snapshots are copies: editing a committed or returned list doesn't
+   change the history
+"""
+
+import importlib.util
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+_spec = importlib.util.spec_from_file_location(
+    "layout_history",
+    PROJECT_ROOT / "vsg_qt" / "manual_selection_dialog" / "history.py",
+)
+history = importlib.util.module_from_spec(_spec)
+_spec.loader.exec_module(history)
+LayoutHistory = history.LayoutHistory
+
+
+def _state(*track_ids: int) -> list[dict]:
+    return [{"source": "Source 1", "id": i} for i in track_ids]
+
+
+def _history(*states: list[dict], limit: int = 50) -> LayoutHistory:
+    h = LayoutHistory(limit)
+    h.reset(states[0])
+    for state in states[1:]:
+        assert h.commit(state)
+    return h
+
+
+def test_undo_and_redo():
+    h = _history(_state(0), _state(0, 1), _state(0, 1, 2))
+
+    assert h.undo() == _state(0, 1)
+    assert h.undo() == _state(0)
+    assert not h.can_undo
+    assert h.undo() is None
+
+    assert h.redo() == _state(0, 1)
+    assert h.redo() == _state(0, 1, 2)
+    assert not h.can_redo
+    assert h.redo() is None
+
+
+def test_unchanged_commit_is_not_recorded():
+    h = _history(_state(0), _state(0, 1))
+    assert not h.commit(_state(0, 1))
+    assert h.undo() == _state(0)
+    assert not h.can_undo
+
+
+def test_commit_after_undo_drops_redo():
+    h = _history(_state(0), _state(0, 1), _state(0, 1, 2))
+    h.undo()
+    h.undo()
+
+    assert h.commit(_state(3))
+    assert not h.can_redo
+    assert h.undo() == _state(0)
+    assert h.redo() == _state(3)
+
+
+def test_limit_drops_the_oldest_states():
+    h = _history(*(_state(i) for i in range(6)), limit=3)
+
+    undone = []
+    while (state := h.undo()) is not None:
+        undone.append(state)
+    assert undone == [_state(4), _state(3), _state(2)]  # 3 undo steps
+
+
+def test_limit_is_at_least_one():
+    h = _history(_state(0), _state(1), _state(2), limit=0)
+    assert h.undo() == _state(1)
+    assert h.undo() is None
+
+
+def test_reset_and_clear():
+    h = _history(_state(0), _state(0, 1))
+    h.reset(_state(5))
+    assert not h.can_undo and not h.can_redo
+    assert h.commit(_state(5, 6))
+    assert h.undo() == _state(5)
+
+    h.clear()
+    assert not h.can_undo and not h.can_redo
+    assert h.commit(_state(7))  # An empty history takes any state
+    assert not h.can_undo
+
+
+def test_snapshots_are_copies():
+    first = _state(0)
+    h = _history(first, _state(0, 1))
+    first[0]["id"] = 99
+
+    undone = h.undo()
+    assert undone == _state(0)
+    undone[0]["id"] = 42
+    h.redo()
+    assert h.undo() == _state(0)
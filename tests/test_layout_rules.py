@@ -0,0 +1,107 @@
+# tests/test_layout_rules.py
+"""
+Tests for declarative layout rules (LayoutRuleSet in
+vsg_core.job_layouts.auto_layout).
+
+Validates:
+1. A rule set resolves against a track set in rule order, one track per
+   language from the preferred source unless 'all' is given
+2. Full and forced subtitles are told apart (flag or name) and forced ones
+   are marked is_forced_display
+3. Missing languages are logged and skipped
+4. Rules round-trip through their text and settings (dict) forms
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+import pytest  # noqa: E402
+
+from vsg_core.job_layouts.auto_layout import LayoutRule, LayoutRuleSet  # noqa: E402
+
+
+def _track(source, track_id, ttype, lang, name="", forced=False):
+    return {
+        "source": source,
+        "id": track_id,
+        "type": ttype,
+        "lang": lang,
+        "name": name,
+        "forced": forced,
+    }
+
+
+TRACK_INFO = {
+    "Source 1": [
+        _track("Source 1", 0, "video", "und"),
+        _track("Source 1", 1, "audio", "eng"),
+        _track("Source 1", 2, "subtitles", "eng", "Signs & Songs", forced=True),
+    ],
+    "Source 2": [
+        _track("Source 2", 0, "video", "und"),
+        _track("Source 2", 1, "audio", "jpn"),
+        _track("Source 2", 2, "audio", "eng"),
+        _track("Source 2", 3, "subtitles", "eng", "Full Subtitles"),
+        _track("Source 2", 4, "subtitles", "eng", "Forced"),
+    ],
+}
+
+SERIES_RULES = """
+# Whole series
+video any all from Source 1
+audio jpn,eng
+subtitles eng full
+subtitles en forced
+"""
+
+
+def _ids(layout):
+    return [(t["source"][-1], t["id"]) for t in layout]
+
+
+def test_series_rules_resolve_in_order():
+    logged = []
+    layout = LayoutRuleSet.parse(SERIES_RULES).resolve(TRACK_INFO, logged.append)
+
+    # jpn only exists in Source 2; eng audio prefers Source 1
+    assert _ids(layout) == [("1", 0), ("2", 1), ("1", 1), ("2", 3), ("1", 2)]
+    forced = {(t["source"][-1], t["id"]): t["is_forced_display"] for t in layout}
+    assert forced[("2", 3)] is False
+    assert forced[("1", 2)] is True
+    assert not any(t["is_default"] for t in layout)
+    assert logged == []
+
+
+def test_source_preference_and_all():
+    rules = LayoutRuleSet.parse(
+        "audio eng from Source 2, Source 1\nsubtitles eng forced all"
+    )
+
+    assert _ids(rules.resolve(TRACK_INFO)) == [("2", 2), ("1", 2), ("2", 4)]
+
+
+def test_missing_language_is_logged_and_skipped():
+    logged = []
+    rules = LayoutRuleSet.parse("audio fre,jpn\nvideo any from Source 2")
+
+    assert _ids(rules.resolve(TRACK_INFO, logged.append)) == [("2", 1)]
+    assert len(logged) == 2
+    assert "no 'fre' track" in logged[0]
+    assert "no matching track" in logged[1]  # Video only comes from Source 1
+
+
+def test_round_trips_and_errors():
+    rules = LayoutRuleSet.parse(SERIES_RULES)
+
+    assert LayoutRuleSet.parse(rules.describe()) == rules
+    assert LayoutRuleSet.from_list(rules.to_list()) == rules
+    assert LayoutRule.parse("subs eng forced from source 2").describe() == (
+        "subtitles eng forced from Source 2"
+    )
+    with pytest.raises(ValueError):
+        LayoutRuleSet.parse("audio jpn\nchapters any")
+    with pytest.raises(ValueError):
+        LayoutRule.parse("audio jpn forced")
@@ -0,0 +1,91 @@
+"""Linear-stretch subtitle sync (new = a * old + b between two anchors).
+
+Anchors at 0ms (analysed delay) and 1,000,000ms (configured end delay); both
+delays take the global shift, so a +100ms shift moves the whole line.
+"""
+
+from vsg_core.models.settings import AppSettings
+from vsg_core.subtitles.data import SubtitleData, SubtitleEvent
+from vsg_core.subtitles.sync_mode_plugins.linear_stretch import (
+    LinearStretchSync,
+    stretch_from_anchors,
+)
+
+
+def _data(*starts: float) -> SubtitleData:
+    data = SubtitleData()
+    data.events = [
+        SubtitleEvent(start_ms=s, end_ms=s + 1000.0, text="Cue") for s in starts
+    ]
+    return data
+
+
+def _settings(**kw) -> AppSettings:
+    kw.setdefault("linear_stretch_end_ms", 1_000_000.0)
+    return AppSettings(**kw)
+
+
+def test_anchors_give_slope_and_offset():
+    a, b = stretch_from_anchors((0.0, 100.0), (1000.0, 1200.0))
+    assert abs(a - 1.1) < 1e-12
+    assert abs(b - 100.0) < 1e-9
+
+
+def test_end_delay_takes_global_shift():
+    # 200ms at the start, 1200ms at the end, plus a 100ms global shift
+    data = _data(0.0, 500_000.0, 1_000_000.0)
+    result = LinearStretchSync().apply(
+        data,
+        total_delay_ms=300.0,
+        global_shift_ms=100.0,
+        settings=_settings(linear_stretch_end_delay_ms=1200.0),
+    )
+    assert result.success
+    assert [e.start_ms for e in data.events] == [300.0, 500_800.0, 1_001_300.0]
+
+
+def test_constant_delay_is_a_plain_shift():
+    data = _data(1234.0)
+    LinearStretchSync().apply(
+        data,
+        total_delay_ms=250.0,
+        global_shift_ms=0.0,
+        settings=_settings(linear_stretch_end_delay_ms=250.0),
+    )
+    assert (data.events[0].start_ms, data.events[0].end_ms) == (1484.0, 2484.0)
+
+
+def test_mapped_times_take_rounding_mode():
+    # 0 -> 0, 3 -> 4: old 1ms maps to 1.333ms
+    for mode, expected in (("floor", 1.0), ("round", 1.0), ("ceil", 2.0)):
+        data = _data(1.0)
+        LinearStretchSync().apply(
+            data,
+            total_delay_ms=0.0,
+            global_shift_ms=0.0,
+            settings=AppSettings(subtitle_rounding=mode),
+            anchors=((0.0, 0.0), (3.0, 4.0)),
+        )
+        assert data.events[0].start_ms == expected
+
+
+def test_unset_end_anchor_is_rejected():
+    data = _data(1000.0)
+    result = LinearStretchSync().apply(
+        data, total_delay_ms=100.0, global_shift_ms=0.0, settings=AppSettings()
+    )
+    assert not result.success
+    assert "linear_stretch_end_ms" in result.error
+    assert data.events[0].start_ms == 1000.0
+
+
+def test_negative_times_are_clamped():
+    data = _data(0.0)
+    result = LinearStretchSync().apply(
+        data,
+        total_delay_ms=-500.0,
+        global_shift_ms=0.0,
+        settings=_settings(linear_stretch_end_delay_ms=-500.0),
+    )
+    assert result.details["events_clamped"] == 1
+    assert (data.events[0].start_ms, data.events[0].end_ms) == (0.0, 500.0)
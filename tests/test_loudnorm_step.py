@@ -0,0 +1,84 @@
+# tests/test_loudnorm_step.py
+"""
+Tests for the loudnorm pass of AudioEncodeStep (_normalize_loudness).
+
+ffmpeg/ffprobe are stubbed at the module level.
+
+Validates:
+1. A track loudnorm can't measure (silent) is skipped with a warning
+2. Lossless tracks are skipped unless allowed, or explicitly re-encoded
+3. A measured track gets the loudnorm filter and loses its gain
+"""
+
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.jobs import PlanItem  # noqa: E402
+from vsg_core.models.media import StreamProps, Track  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+from vsg_core.mux.encode import EncodeSpec  # noqa: E402
+from vsg_core.mux.loudnorm import LoudnessMeasurement  # noqa: E402
+
+_MEASURED = LoudnessMeasurement(-18.0, 6.0, -2.0, -28.0, 0.1)
+
+
+class _Runner:
+    def __init__(self):
+        self.lines: list[str] = []
+
+    def _log_message(self, message: str) -> None:
+        self.lines.append(message)
+
+
+def _item(codec_id: str, encode: EncodeSpec | None = None) -> PlanItem:
+    track = Track("Source 2", 1, "audio", StreamProps(codec_id))
+    return PlanItem(
+        track=track, extracted_path=Path("a.mka"), loudnorm=True, encode=encode
+    )
+
+
+def _normalize(monkeypatch, items, measured=_MEASURED, **settings):
+    # The steps package pulls in the analysis stack
+    pytest.importorskip("scipy")
+    from vsg_core.orchestrator.steps import audio_encode_step as step
+
+    monkeypatch.setattr(step, "measure_loudness", lambda *a, **kw: measured)
+    monkeypatch.setattr(step, "probe_sample_rate", lambda *a: 48000)
+    ctx = SimpleNamespace(
+        settings=AppSettings(loudnorm_enabled=True, **settings), tool_paths={}
+    )
+    runner = _Runner()
+    step.AudioEncodeStep()._normalize_loudness(ctx, runner, items)
+    return runner.lines
+
+
+def test_unmeasurable_track_is_skipped(monkeypatch):
+    item = _item("A_AC3")
+    lines = _normalize(monkeypatch, [item], measured=None)
+    assert item.encode is None
+    assert any("could not be measured" in line for line in lines)
+
+
+def test_lossless_track_needs_opt_in(monkeypatch):
+    item = _item("A_TRUEHD")
+    _normalize(monkeypatch, [item])
+    assert item.encode is None
+
+    item = _item("A_TRUEHD")
+    _normalize(monkeypatch, [item], loudnorm_allow_lossless=True)
+    assert item.encode.codec == "flac"
+
+
+def test_explicit_lossy_encode_of_lossless_track(monkeypatch):
+    item = _item("A_TRUEHD", EncodeSpec("eac3", gain_db=3.0))
+    _normalize(monkeypatch, [item])
+    assert item.encode.codec == "eac3"
+    assert item.encode.gain_db == 0.0
+    assert item.encode.filters[-1].startswith("loudnorm=I=-23:")
+    assert "measured_I=-18.00" in item.encode.filters[-1]
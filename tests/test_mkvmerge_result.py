@@ -0,0 +1,79 @@
+# tests/test_mkvmerge_result.py
+"""
+Tests for mkvmerge exit code / message parsing (vsg_core.mux.mkvmerge_result).
+
+Validates:
+1. Exit 0 succeeds without warnings
+2. Exit 1 succeeds and collects the warnings (incl. continuation lines)
+3. Exit 2 fails and reports the error lines as the reason
+4. Unknown exit codes and a process that never started fail
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.mux.mkvmerge_result import parse_mkvmerge_output
+
+_WARNING_OUTPUT = """\
+mkvmerge v80.0 ('Roundabout') 64-bit
+'in.mkv': Using the demultiplexer for the format 'Matroska'.
+Warning: 'in.mkv' track 2: The timestamps are not monotonic. The affected
+  frames were re-ordered, which is fine for most players.
+Progress: 100%
+Warning: The chapter language 'xx' is not valid and was replaced by 'und'.
+Multiplexing took 3 seconds.
+"""
+
+
+def test_exit_zero_is_clean_success():
+    outcome = parse_mkvmerge_output(0, "Progress: 100%\nMultiplexing took 1 second.\n")
+    assert outcome.ok
+    assert outcome.warnings == ()
+    assert outcome.errors == ()
+    assert outcome.describe() == "mkvmerge finished"
+
+
+def test_exit_one_succeeds_with_warnings():
+    outcome = parse_mkvmerge_output(1, _WARNING_OUTPUT)
+    assert outcome.ok
+    assert outcome.warnings == (
+        "'in.mkv' track 2: The timestamps are not monotonic. The affected "
+        "frames were re-ordered, which is fine for most players.",
+        "The chapter language 'xx' is not valid and was replaced by 'und'.",
+    )
+    assert outcome.errors == ()
+    assert "2 warning(s)" in outcome.describe()
+
+
+def test_exit_two_fails_with_errors():
+    output = (
+        "Warning: something minor.\n"
+        "Error: The file 'missing.mkv' could not be opened for reading.\n"
+    )
+    outcome = parse_mkvmerge_output(2, output)
+    assert not outcome.ok
+    assert outcome.warnings == ("something minor.",)
+    assert outcome.errors == (
+        "The file 'missing.mkv' could not be opened for reading.",
+    )
+    assert outcome.describe() == (
+        "mkvmerge failed: The file 'missing.mkv' could not be opened for reading."
+    )
+
+
+def test_timestamped_log_lines_are_parsed():
+    outcome = parse_mkvmerge_output(1, "[12:00:01] Warning: late frame dropped.\n")
+    assert outcome.warnings == ("late frame dropped.",)
+
+
+def test_other_exit_codes_fail():
+    outcome = parse_mkvmerge_output(-9, "")
+    assert not outcome.ok
+    assert outcome.describe() == "mkvmerge failed: exit code -9"
+
+    not_started = parse_mkvmerge_output(None, "")
+    assert not not_started.ok
+    assert not_started.describe() == "mkvmerge could not be started"
@@ -0,0 +1,141 @@
+# tests/test_normalize.py
+"""
+Tests for remuxing non-MKV sources (vsg_core.extraction.normalize).
+
+ffmpeg is replaced by a fake runner.
+
+Validates:
+1. is_matroska() goes by the suffix, in any case
+2. remux_to_mkv() stream-copies every stream but data streams into
+   <stem>.mkv, and names a second source with the same stem after its key
+3. A failed remux names the ffmpeg lines that say why (the last three),
+   or the exit code when none do, and leaves no partial file behind
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.errors import ProcessFailed  # noqa: E402
+from vsg_core.extraction.normalize import is_matroska, remux_to_mkv  # noqa: E402
+from vsg_core.io.retry import CommandFailure  # noqa: E402
+
+
+class _Runner:
+    """Records ffmpeg calls; writes the output file unless ``failure``."""
+
+    def __init__(self, failure: CommandFailure | None = None):
+        self.failure = failure
+        self.last_failure: CommandFailure | None = None
+        self.calls: list[list[str]] = []
+        self.lines: list[str] = []
+
+    def _log_message(self, message: str) -> None:
+        self.lines.append(message)
+
+    def run(self, cmd, tool_paths):
+        self.calls.append(cmd)
+        out = Path(cmd[-1])
+        out.write_bytes(b"")  # ffmpeg creates the file before it fails
+        if self.failure is not None:
+            self.last_failure = self.failure
+            return None
+        return ""
+
+
+@pytest.mark.parametrize(
+    ("path", "expected"),
+    [
+        ("ep01.mkv", True),
+        ("EP01.MKV", True),
+        ("audio.mka", True),
+        ("subs.mks", True),
+        ("clip.webm", True),
+        ("ep01.ts", False),
+        ("ep01.m2ts", False),
+        ("ep01.mp4", False),
+        ("ep01", False),
+        ("ep01.mkv.part", False),
+    ],
+)
+def test_is_matroska(path, expected):
+    assert is_matroska(path) is expected
+    assert is_matroska(Path(path)) is expected
+
+
+def test_remux_command(tmp_path: Path):
+    runner = _Runner()
+    dest = tmp_path / "normalized"
+    out = remux_to_mkv("Source 2", "/in/ep01.ts", dest, runner, {})
+
+    assert out == dest / "ep01.mkv"
+    assert runner.calls == [
+        [
+            "ffmpeg",
+            "-hide_banner",
+            "-nostdin",
+            "-y",
+            "-i",
+            "/in/ep01.ts",
+            "-map",
+            "0",
+            "-map",
+            "-0:d?",
+            "-c",
+            "copy",
+            "-max_interleave_delta",
+            "0",
+            str(dest / "ep01.mkv"),
+        ]
+    ]
+    assert runner.lines[-1] == "[Normalize] Source 2 -> ep01.mkv"
+
+
+def test_same_stem_gets_the_source_key(tmp_path: Path):
+    runner = _Runner()
+    first = remux_to_mkv("Source 1", "/in/ep01.mp4", tmp_path, runner, {})
+    second = remux_to_mkv("Source 2", "/in/ep01.ts", tmp_path, runner, {})
+    assert first.name == "ep01.mkv"
+    assert second.name == "ep01.Source_2.mkv"
+
+
+def test_failure_names_the_ffmpeg_error_lines(tmp_path: Path):
+    output = "\n".join(
+        [
+            "Input #0, mpegts, from 'ep01.ts':",
+            "  Stream #0:2: Subtitle: dvb_teletext",
+            "[matroska @ 0x1] Subtitle codec 94215 is not supported.",
+            "[out#0/matroska @ 0x2] Could not write header: Invalid argument",
+            "Error opening output file out.mkv.",
+            "Error opening output files: Invalid argument",
+        ]
+    )
+    failure = CommandFailure("ffmpeg", 234, output)
+    runner = _Runner(failure)
+
+    with pytest.raises(ProcessFailed) as exc:
+        remux_to_mkv("Source 2", "/in/ep01.ts", tmp_path, runner, {})
+
+    message = str(exc.value)
+    assert message.startswith("Source 2: ep01.ts could not be remuxed to MKV")
+    # The last three lines that say why, not the stream listing
+    assert (
+        "([out#0/matroska @ 0x2] Could not write header: Invalid argument; "
+        "Error opening output file out.mkv.; "
+        "Error opening output files: Invalid argument)"
+    ) in message
+    assert "dvb_teletext" not in message
+    assert exc.value.failure is failure
+    assert exc.value.code == 234
+    assert not (tmp_path / "ep01.mkv").exists()
+
+
+def test_failure_without_error_lines_names_the_exit_code(tmp_path: Path):
+    runner = _Runner(CommandFailure("ffmpeg", 1, "frame=  100 fps=0.0"))
+    with pytest.raises(ProcessFailed, match=r"stream copy \(ffmpeg \(exit 1\)\)"):
+        remux_to_mkv("Source 2", "/in/ep01.mov", tmp_path, runner, {})
+    assert not (tmp_path / "ep01.mkv").exists()
@@ -0,0 +1,36 @@
+# tests/test_output_checksum.py
+"""
+Tests for output checksums (vsg_core.postprocess.checksum).
+
+Validates:
+1. sha256_file matches hashlib over the whole file, across chunk borders
+2. The size is recorded and shown with the hash
+"""
+
+import hashlib
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.postprocess.checksum import sha256_file  # noqa: E402
+
+
+def test_sha256_matches_whole_file(tmp_path):
+    data = bytes(range(256)) * 9000  # > 2 chunks, not a multiple of one
+    path = tmp_path / "movie.mkv"
+    path.write_bytes(data)
+
+    checksum = sha256_file(path)
+
+    assert checksum.sha256 == hashlib.sha256(data).hexdigest()
+    assert checksum.size_bytes == len(data)
+    assert checksum.describe().startswith("movie.mkv: 2,304,000 bytes (2.20 MiB)")
+
+
+def test_empty_file(tmp_path):
+    path = tmp_path / "empty.mkv"
+    path.write_bytes(b"")
+
+    assert sha256_file(path).sha256 == hashlib.sha256(b"").hexdigest()
@@ -0,0 +1,139 @@
+"""Templated output filenames (vsg_core.mux.output_name).
+
+Validates:
+1. output_name_fields() pulls title, episode, season, resolution and the
+   per-source release groups from the source filenames
+2. sanitize_filename() replaces illegal characters and tidies up what
+   empty fields leave behind
+3. render_output_name() always ends in .mkv, keeps unknown placeholders
+   (logging them once) and falls back when the name renders empty
+4. claim_output_path() never hands the same path out twice, and
+   release_output_path() frees a claim again
+5. The mux step names the output after the user's Source 1, not the
+   joined file in the work dir (needs scipy for the steps package)
+"""
+
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.mux import output_name  # noqa: E402
+from vsg_core.mux.output_name import (  # noqa: E402
+    claim_output_path,
+    output_name_fields,
+    release_output_path,
+    render_output_name,
+    sanitize_filename,
+)
+
+SOURCES = {
+    "Source 1": "/in/[SubsPlease] Frieren - 05 (1080p) [ABCD1234].mkv",
+    "Source 2": "/in/[Judas] Sousou no Frieren - S01E05.mkv",
+}
+
+
+@pytest.fixture(autouse=True)
+def _fresh_state(monkeypatch):
+    monkeypatch.setattr(output_name, "_warned_placeholders", set())
+    monkeypatch.setattr(output_name, "_claimed_paths", set())
+
+
+def test_fields_from_filenames():
+    fields = output_name_fields(SOURCES, resolution_height=1080)
+    assert fields == {
+        "title": "Frieren",
+        "episode": "05",
+        "season": "",
+        "source1": "[SubsPlease] Frieren - 05 (1080p) [ABCD1234]",
+        "resolution": "1080p",
+        "source1_group": "SubsPlease",
+        "source2_group": "Judas",
+    }
+
+
+def test_fields_for_double_episode_with_season():
+    fields = output_name_fields({"Source 1": "/in/Show.S02E03E04.1080p.WEB.mkv"})
+    assert (fields["title"], fields["season"], fields["episode"]) == (
+        "Show",
+        "02",
+        "03-04",
+    )
+    assert fields["resolution"] == ""
+
+
+def test_sanitize_replaces_illegal_characters():
+    assert sanitize_filename('Re:Zero <Director\'s Cut> "A/B"?') == (
+        "Re_Zero _Director's Cut_ _A_B__"
+    )
+
+
+def test_sanitize_drops_empty_brackets_and_trailing_dots():
+    assert sanitize_filename("Show - 05 [ ] ()  [Synced]. ") == "Show - 05 [Synced]"
+
+
+def test_render_template():
+    fields = output_name_fields(SOURCES, resolution_height=1080)
+    name = render_output_name(
+        "{title} - {episode} [{source2_group}] [{resolution}]", fields
+    )
+    assert name == "Frieren - 05 [Judas] [1080p].mkv"
+
+
+def test_render_missing_source_group_is_empty():
+    fields = output_name_fields(SOURCES)
+    name = render_output_name("{title} [{source3_group}] [Synced].mkv", fields)
+    assert name == "Frieren [Synced].mkv"
+
+
+def test_render_keeps_unknown_placeholder_and_logs_once():
+    lines: list[str] = []
+    fields = output_name_fields(SOURCES)
+    for _ in range(2):
+        name = render_output_name("{title} {crc}", fields, lines.append)
+        assert name == "Frieren {crc}.mkv"
+    assert lines == ["[Output Name] Unknown placeholder {crc} left as written."]
+
+
+def test_render_empty_name_falls_back():
+    assert render_output_name("{season}", {"season": "", "source1": "ep05"}) == (
+        "ep05.mkv"
+    )
+    assert render_output_name("...", {}) == "output.mkv"
+
+
+def test_claim_skips_existing_files(tmp_path: Path):
+    (tmp_path / "Show - 05.mkv").write_bytes(b"")
+    assert claim_output_path(tmp_path, "Show - 05.mkv").name == "Show - 05 (2).mkv"
+
+
+def test_claim_skips_names_claimed_by_other_jobs(tmp_path: Path):
+    first = claim_output_path(tmp_path, "Show - 05.mkv")
+    second = claim_output_path(tmp_path, "show - 05.mkv")  # Same name, any case
+    assert first.name == "Show - 05.mkv"
+    assert second.name == "show - 05 (2).mkv"
+
+
+def test_release_frees_the_claim(tmp_path: Path):
+    first = claim_output_path(tmp_path, "Show - 05.mkv")
+    release_output_path(first)
+    assert claim_output_path(tmp_path, "Show - 05.mkv") == first
+
+
+def test_mux_step_names_output_after_the_users_source():
+    pytest.importorskip("scipy")  # The steps package pulls in the analysis stack
+    from vsg_core.models.settings import AppSettings
+    from vsg_core.orchestrator.steps.mux_step import MuxStep
+
+    lines: list[str] = []
+    ctx = SimpleNamespace(
+        settings=AppSettings(output_template="{title} - {episode}"),
+        sources={"Source 1": "/tmp/work/joined/Source 1.mkv"},
+        source_inputs={"Source 1": "/media/[Grp] Frieren - 05 (part 1).mkv"},
+    )
+    runner = SimpleNamespace(_log_message=lines.append)
+    assert MuxStep()._render_output_name(ctx, runner) == "Frieren - 05.mkv"
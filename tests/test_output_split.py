@@ -0,0 +1,94 @@
+# tests/test_output_split.py
+"""
+Tests for output splitting (vsg_core.mux.split).
+
+Validates:
+1. SplitSpec from settings, validation and mkvmerge --split tokens
+2. Chapter splitting lists the chapters that start each new part
+3. find_parts picks up numbered parts, including names with brackets
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.settings import AppSettings  # noqa: E402
+from vsg_core.mux.split import SplitSpec, count_chapters, find_parts  # noqa: E402
+
+
+def test_from_settings_converts_units():
+    size = SplitSpec.from_settings(
+        AppSettings(output_split_mode="size", output_split_size_mb=700)
+    )
+    assert size == SplitSpec("size", 700 * 1024 * 1024)
+    duration = SplitSpec.from_settings(
+        AppSettings(output_split_mode="duration", output_split_duration_min=25)
+    )
+    assert duration == SplitSpec("duration", 25 * 60_000)
+    assert not SplitSpec.from_settings(AppSettings()).enabled
+
+
+def test_enabled_mode_needs_positive_value():
+    with pytest.raises(ValueError):
+        SplitSpec("size", 0)
+    assert SplitSpec("none", 0).mkvmerge_args() == []
+
+
+def test_size_and_duration_tokens():
+    assert SplitSpec("size", 1024).mkvmerge_args() == ["--split", "size:1024"]
+    assert SplitSpec("duration", 5_430_250).mkvmerge_args() == [
+        "--split",
+        "duration:01:30:30.250",
+    ]
+    assert SplitSpec("duration", 5_430_250).describe() == "every 01:30:30.250"
+
+
+@pytest.mark.parametrize(
+    ("every", "count", "expected"),
+    [
+        (2, 6, ["--split", "chapters:3,5"]),
+        (3, 7, ["--split", "chapters:4,7"]),
+        (3, 3, []),  # Never reaches a second part
+        (1, 4, ["--split", "chapters:all"]),
+        (1, 1, []),
+    ],
+)
+def test_chapter_split_points(every, count, expected):
+    assert SplitSpec("chapters", every).mkvmerge_args(count) == expected
+
+
+def test_count_chapters_first_edition(tmp_path: Path):
+    xml = tmp_path / "chapters.xml"
+    xml.write_text(
+        "<Chapters><EditionEntry>"
+        "<ChapterAtom/><ChapterAtom><ChapterAtom/></ChapterAtom>"
+        "</EditionEntry><EditionEntry><ChapterAtom/></EditionEntry></Chapters>"
+    )
+    assert count_chapters(xml) == 2
+    assert count_chapters(tmp_path / "missing.xml") == 0
+    assert count_chapters(None) == 0
+
+
+def test_find_parts(tmp_path: Path):
+    out = tmp_path / "Show [1080p] - 01.mkv"
+    for name in (
+        "Show [1080p] - 01-002.mkv",
+        "Show [1080p] - 01-001.mkv",
+        "Show [1080p] - 01-001.mkv.tmp",
+        "Show [1080p] - 02-001.mkv",
+    ):
+        (tmp_path / name).touch()
+    assert [p.name for p in find_parts(out)] == [
+        "Show [1080p] - 01-001.mkv",
+        "Show [1080p] - 01-002.mkv",
+    ]
+
+
+def test_find_parts_unsplit(tmp_path: Path):
+    out = tmp_path / "Movie.mkv"
+    out.touch()
+    assert find_parts(out) == [out]
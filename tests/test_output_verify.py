@@ -0,0 +1,128 @@
+# tests/test_output_verify.py
+"""
+Tests for post-mux verification (vsg_core.extraction.stats.find_anomalies
+and VerifyStep._check_delays).
+
+Validates:
+1. Empty tracks, subtitle tracks without events and short audio are flagged
+2. Planned and actual container delays are compared within the tolerance;
+   subtitles and negative planned delays are skipped
+3. A mismatch warns or fails per verify_delay_mismatch_action
+"""
+
+import sys
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.extraction.stats import TrackStats, find_anomalies  # noqa: E402
+from vsg_core.models.jobs import Delays, PlanItem  # noqa: E402
+from vsg_core.models.media import StreamProps, Track  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+
+def _stats(index, kind, duration_s=100.0, size=1000, packets=10):
+    return TrackStats(index, kind, "codec", "und", duration_s, size, packets)
+
+
+def test_healthy_file_has_no_anomalies():
+    stats = [_stats(0, "video"), _stats(1, "audio", 99.5), _stats(2, "subtitles")]
+    assert find_anomalies(stats) == []
+
+
+def test_anomalies():
+    stats = [
+        _stats(0, "video"),
+        _stats(1, "audio", duration_s=90.0),  # Below 95% of the video
+        _stats(2, "audio", size=0),
+        _stats(3, "subtitles", packets=0),
+    ]
+    found = find_anomalies(stats)
+    assert len(found) == 3
+    assert "audio track #1" in found[0] and "90.0s" in found[0]
+    assert found[1].startswith("audio track #2") and found[1].endswith("is empty.")
+    assert found[2].startswith("subtitles track #3") and "no events" in found[2]
+
+
+def test_audio_only_file_is_not_short():
+    assert find_anomalies([_stats(0, "audio", duration_s=5.0)]) == []
+
+
+# --- delay check ---
+
+
+class _Runner:
+    def __init__(self):
+        self.lines: list[str] = []
+
+    def _log_message(self, message: str) -> None:
+        self.lines.append(message)
+
+
+def _item(source: str, kind: str, tid: int) -> PlanItem:
+    return PlanItem(track=Track(source, tid, kind, StreamProps("X")))
+
+
+def _check(monkeypatch, actual: list[int], action: str = "warn", **delays):
+    # The steps package pulls in the analysis stack
+    pytest.importorskip("scipy")
+    from vsg_core.orchestrator.steps import verify_step
+
+    tracks = [{"id": n, "container_delay_ms": d} for n, d in enumerate(actual)]
+    monkeypatch.setattr(
+        verify_step,
+        "get_stream_info_with_delays",
+        lambda path, runner, tool_paths: {"tracks": tracks},
+    )
+    ctx = SimpleNamespace(
+        out_file="out.mkv",
+        tool_paths={},
+        extracted_items=[
+            _item("Source 1", "video", 0),
+            _item("Source 2", "audio", 1),
+            _item("Source 3", "audio", 1),
+            _item("Source 2", "subtitles", 2),
+        ],
+        delays=Delays(
+            source_delays_ms={"Source 2": 250, "Source 3": -40},
+            global_shift_ms=0,
+        ),
+        subtitle_delays_ms={},
+        settings=AppSettings(
+            verify_delay_tolerance_ms=1, verify_delay_mismatch_action=action
+        ),
+        verify_issues=[],
+    )
+    runner = _Runner()
+    verify_step.VerifyStep()._check_delays(ctx, runner)
+    return ctx, runner.lines
+
+
+def test_matching_delays(monkeypatch):
+    ctx, lines = _check(monkeypatch, [0, 251, 0, 0])
+    assert ctx.verify_issues == []
+    rows = [line for line in lines if line.endswith(("ok", "MISMATCH"))]
+    # Source 3 (negative planned delay) and the subtitle track are skipped
+    assert len(rows) == 2
+    assert all(row.endswith("ok") for row in rows)
+
+
+def test_mismatch_warns(monkeypatch):
+    ctx, lines = _check(monkeypatch, [0, 300, 0, 0])
+    assert any(line.endswith("MISMATCH") for line in lines)
+    assert len(ctx.verify_issues) == 1
+
+
+def test_mismatch_can_fail(monkeypatch):
+    with pytest.raises(RuntimeError):
+        _check(monkeypatch, [0, 300, 0, 0], action="fail")
+
+
+def test_track_count_mismatch_skips_check(monkeypatch):
+    ctx, lines = _check(monkeypatch, [0, 250])
+    assert ctx.verify_issues == []
+    assert any("skipping delay check" in line for line in lines)
@@ -0,0 +1,110 @@
+"""Accuracy tests for sub-sample correlation peak interpolation.
+
+Signals are periodic and exactly band-limited (a sum of cosines on FFT
+bins), so a delay of a fractional number of samples is exact and the
+circular cross-correlation has its true maximum at that delay. A narrow
+band gives the broad, smooth peak of plain SCC on music/speech; a wide
+band gives the sharp peak of the whitened GCC methods.
+"""
+
+from __future__ import annotations
+
+import math
+import random
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.analysis.correlation.peak_interp import refine_peak  # noqa: E402
+
+N = 512
+RADIUS = 12
+DELAYS = [0.23, -0.41, 0.07, 0.5, 3.3, -7.75]
+_rng = random.Random(7)
+_PHASES = [_rng.uniform(0.0, 2.0 * math.pi) for _ in range(N)]
+
+
+def _signal(delay: float, lo: int, hi: int) -> list[float]:
+    """Hann-shaped spectrum over bins lo..hi, delayed by ``delay`` samples."""
+    amps = {
+        k: math.sin(math.pi * (k - lo + 1) / (hi - lo + 2)) for k in range(lo, hi + 1)
+    }
+    return [
+        sum(
+            a * math.cos(2.0 * math.pi * k * (n - delay) / N + _PHASES[k])
+            for k, a in amps.items()
+        )
+        for n in range(N)
+    ]
+
+
+def _estimate(delay: float, band: tuple[int, int], method: str) -> float:
+    ref = _signal(0.0, *band)
+    tgt = _signal(delay, *band)
+    corr = [
+        sum(ref[n] * tgt[(n + lag) % N] for n in range(N))
+        for lag in range(-RADIUS, RADIUS + 1)
+    ]
+    k = max(range(len(corr)), key=corr.__getitem__)
+    return (k - RADIUS) + refine_peak(corr, k, method, sinc_taps=8)
+
+
+BROAD = (2, 30)
+SHARP = (8, 200)
+
+
+@pytest.mark.parametrize("delay", DELAYS)
+@pytest.mark.parametrize("method", ["quadratic", "gaussian"])
+def test_parabolic_methods_on_broad_peak(method, delay):
+    assert abs(_estimate(delay, BROAD, method) - delay) < 0.005
+
+
+@pytest.mark.parametrize("delay", DELAYS)
+def test_sinc_on_sharp_peak(delay):
+    assert abs(_estimate(delay, SHARP, "sinc") - delay) < 0.002
+
+
+def test_sinc_beats_quadratic_on_sharp_peak():
+    quad = max(abs(_estimate(d, SHARP, "quadratic") - d) for d in DELAYS)
+    sinc = max(abs(_estimate(d, SHARP, "sinc") - d) for d in DELAYS)
+    assert sinc < quad / 10
+
+
+@pytest.mark.parametrize("delay", DELAYS)
+def test_none_stays_on_integer_sample(delay):
+    assert _estimate(delay, BROAD, "none") == round(delay)
+
+
+def test_peak_at_edge_is_not_refined():
+    values = [3.0, 2.0, 1.0]
+    for method in ("quadratic", "gaussian", "sinc"):
+        assert refine_peak(values, 0, method) == 0.0
+
+
+def test_gaussian_falls_back_to_quadratic_for_non_positive_values():
+    values = [-0.5, 1.0, 0.25]
+    assert refine_peak(values, 1, "gaussian") == refine_peak(values, 1, "quadratic")
+
+
+def test_legacy_peak_fit_only_affects_scc():
+    pytest.importorskip("numpy")
+    from vsg_core.analysis.correlation.methods.gcc_phat import GccPhat
+    from vsg_core.analysis.correlation.methods.scc import Scc
+    from vsg_core.analysis.correlation.run import configure_method
+    from vsg_core.models.settings import AppSettings
+
+    legacy = AppSettings(audio_peak_fit=True)
+    assert configure_method(GccPhat(), legacy).peak_interp == "none"
+    scc = configure_method(Scc(peak_fit=True), legacy)
+    assert scc.peak_fit
+    assert scc.peak_interp == "none"
+    chosen = AppSettings(
+        audio_peak_fit=True, peak_interpolation="sinc", peak_sinc_taps=4
+    )
+    gcc = configure_method(GccPhat(), chosen)
+    assert gcc.peak_interp == "sinc"
+    assert gcc.sinc_taps == 4
@@ -0,0 +1,75 @@
+# tests/test_preflight_temp_space.py
+"""
+Tests for the preflight's temp space check (vsg_core.orchestrator.preflight).
+
+Validates:
+1. The estimate counts the selected tracks' tagged sizes, falls back to the
+   whole source when a size is unknown, and adds the normalized copy of
+   non-MKV sources
+2. A batch is checked for its largest concurrent jobs plus the margin, and
+   the error gives the needed and available space
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.settings import AppSettings  # noqa: E402
+from vsg_core.orchestrator.preflight import (  # noqa: E402
+    _check_temp_space,
+    _temp_bytes_needed,
+)
+
+GiB = 1024**3
+
+
+def _source(tmp_path, name, size, track_bytes):
+    path = tmp_path / name
+    path.write_bytes(b"\0" * size)
+    tracks = [
+        {"id": i, "properties": {"tag_number_of_bytes": str(n)} if n else {}}
+        for i, n in enumerate(track_bytes)
+    ]
+    return [(str(path), {"tracks": tracks})]
+
+
+def test_estimate_from_selected_tracks(tmp_path):
+    parts = {
+        "Source 1": _source(tmp_path, "a.mkv", 1000, [600, 300, 50]),
+        "Source 2": _source(tmp_path, "b.mp4", 800, [500, None]),
+    }
+    job = {
+        "manual_layout": [
+            {"source": "Source 1", "id": 0},
+            {"source": "Source 1", "id": 2},
+            {"source": "Source 2", "id": 0},
+            {"source": "Source 2", "id": 5, "is_generated": True},
+        ]
+    }
+
+    assert _temp_bytes_needed(job, parts, normalize=False) == 650 + 500
+    # Normalized copy of the MP4
+    assert _temp_bytes_needed(job, parts, normalize=True) == 650 + 500 + 800
+
+    # Untagged track: the whole source
+    job["manual_layout"].append({"source": "Source 2", "id": 1})
+    assert _temp_bytes_needed(job, parts, normalize=False) == 650 + 800
+
+
+def test_batch_needs_room_for_concurrent_jobs(tmp_path):
+    settings = AppSettings(
+        temp_root=str(tmp_path), batch_max_concurrent_jobs=2, temp_space_margin_pct=50
+    )
+
+    assert _check_temp_space([0, 0], settings) == []
+    assert _check_temp_space([1024], settings) == []
+
+    issues = _check_temp_space([10**8 * GiB, 10**8 * GiB, 1024], settings)
+    assert len(issues) == 1
+    assert issues[0].is_error
+    assert "about 300000000.0 GiB needed (2 job(s) at once, +50% margin)" in (
+        issues[0].message
+    )
+    assert "available" in issues[0].message
@@ -0,0 +1,152 @@
+# tests/test_queue_runner.py
+"""
+Tests for batch-parallel queue execution (vsg_core.queue_runner).
+
+JobPipeline is replaced by a fake that acts on Source 1's file name.
+
+Validates:
+1. assign_output_names() keeps the first name and numbers later jobs
+   with the same (case-insensitive) Source 1 name; multi-part sources
+   are named after their first part
+2. run_queue() returns results in job order whatever order jobs finish in,
+   and passes each job its unique output name
+3. A job that raises fails on its own; the other jobs still run
+4. stop_on_error skips the jobs that have not started; a set
+   cancel_event skips every job
+"""
+
+import sys
+import threading
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+# The steps package pulls in the analysis stack
+pytest.importorskip("scipy")
+
+from vsg_core import queue_runner  # noqa: E402
+from vsg_core.errors import ErrorKind  # noqa: E402
+from vsg_core.models.jobs import PipelineResult  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+from vsg_core.queue_runner import assign_output_names, run_queue  # noqa: E402
+
+
+class _Pipeline:
+    """Fake JobPipeline: "fail*" raises, "slow*" waits for ``release``."""
+
+    release = threading.Event()
+    calls: list[tuple[str, str]] = []
+
+    def __init__(self, config, log_callback, progress_callback):
+        self.log = log_callback
+        self.progress = progress_callback
+
+    def run_job(self, *, sources, output_name, **kwargs):
+        stem = Path(sources["Source 1"]).stem
+        _Pipeline.calls.append((stem, output_name))
+        self.log(f"running {stem}")
+        if stem.startswith("slow"):
+            assert _Pipeline.release.wait(5)
+        if stem.startswith("fail"):
+            raise RuntimeError(f"{stem} broke")
+        _Pipeline.release.set()
+        self.progress(0.5)
+        return PipelineResult(status="Analyzed", name=output_name)
+
+
+@pytest.fixture
+def pipeline(monkeypatch):
+    _Pipeline.release = threading.Event()
+    _Pipeline.calls = []
+    monkeypatch.setattr(queue_runner, "JobPipeline", _Pipeline)
+    return _Pipeline
+
+
+def _jobs(*names: str) -> list[dict]:
+    return [{"sources": {"Source 1": f"/in/{name}.mkv"}} for name in names]
+
+
+def _run(jobs, tmp_path: Path, max_concurrent: int = 2, **kwargs):
+    lines: list[str] = []
+    finished: list[int] = []
+    results = run_queue(
+        jobs,
+        AppSettings(),
+        and_merge=False,
+        output_dir=str(tmp_path),
+        max_concurrent=max_concurrent,
+        log_callback=lines.append,
+        progress_callback=lambda *_: None,
+        job_finished_callback=lambda index, _result: finished.append(index),
+        **kwargs,
+    )
+    return results, lines, finished
+
+
+def test_output_names_are_unique():
+    jobs = [
+        {"sources": {"Source 1": "/a/Ep01.mkv"}},
+        {"sources": {"Source 1": "/b/ep01.mkv"}},
+        {"sources": {"Source 1": "/c/Ep01.mkv"}},
+        {"sources": {"Source 1": ["/d/Ep02.mkv", "/d/Ep02-part2.mkv"]}},
+    ]
+    assert assign_output_names(jobs) == [
+        "Ep01.mkv",
+        "ep01 (2).mkv",
+        "Ep01 (3).mkv",
+        "Ep02.mkv",
+    ]
+
+
+def test_results_keep_job_order(pipeline, tmp_path: Path):
+    # "slow" starts first but only finishes once "fast" has
+    results, lines, finished = _run(_jobs("slow", "fast"), tmp_path)
+
+    assert [r.name for r in results] == ["slow.mkv", "fast.mkv"]
+    assert finished == [1, 0]
+    assert "[Job 2] running fast" in lines
+
+
+def test_each_job_gets_its_output_name(pipeline, tmp_path: Path):
+    jobs = [
+        {"sources": {"Source 1": "/a/ep01.mkv"}},
+        {"sources": {"Source 1": "/b/ep01.mkv"}},
+    ]
+    _run(jobs, tmp_path, max_concurrent=1)
+    assert pipeline.calls == [("ep01", "ep01.mkv"), ("ep01", "ep01 (2).mkv")]
+
+
+def test_failing_job_does_not_stop_the_others(pipeline, tmp_path: Path):
+    results, _, _ = _run(_jobs("fail", "ok1", "ok2"), tmp_path)
+
+    assert [r.status for r in results] == ["Failed", "Analyzed", "Analyzed"]
+    assert results[0].error == "fail broke"
+    assert results[0].name == "fail.mkv"
+
+
+def test_stop_on_error_skips_the_rest(pipeline, tmp_path: Path):
+    results, lines, _ = _run(
+        _jobs("ok", "fail", "later"), tmp_path, max_concurrent=1, stop_on_error=True
+    )
+
+    assert [r.status for r in results] == ["Analyzed", "Failed", "Failed"]
+    assert results[2].error == "Skipped"
+    assert results[2].error_kind == ErrorKind.CANCELLED
+    assert [stem for stem, _ in pipeline.calls] == ["ok", "fail"]
+    assert any("stopping remaining jobs" in line for line in lines)
+
+
+def test_cancelled_queue_runs_nothing(pipeline, tmp_path: Path):
+    cancel = threading.Event()
+    cancel.set()
+    results, _, _ = _run(_jobs("a", "b"), tmp_path, cancel_event=cancel)
+
+    assert [r.error for r in results] == ["Skipped", "Skipped"]
+    assert pipeline.calls == []
+
+
+def test_empty_queue():
+    assert run_queue([], AppSettings(), False, "", 2, print, print) == []
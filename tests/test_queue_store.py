@@ -0,0 +1,71 @@
+# tests/test_queue_store.py
+"""
+Tests for restoring the saved job queue (vsg_core.job_layouts.queue_store).
+
+Validates:
+1. An unchanged queue restores without stale layouts
+2. A source replaced under the same name flags the layout stale
+3. Missing sources (including a missing part) mark the job unavailable
+"""
+
+import os
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.job_layouts.queue_store import JobQueueStore  # noqa: E402
+
+
+class _Layouts:
+    """Stands in for JobLayoutManager: fixed job ID, no stored layout."""
+
+    def generate_job_id(self, sources):
+        return "job"
+
+    def load_job_layout(self, job_id):
+        return None
+
+
+def _restore(tmp_path: Path, jobs: list[dict], change=None) -> list[dict]:
+    store = JobQueueStore(tmp_path / "layouts", lambda _msg: None)
+    assert store.save(jobs, _Layouts())
+    if change:
+        change()
+    return store.load_or_empty(_Layouts())
+
+
+def _files(tmp_path: Path, *names: str) -> list[Path]:
+    paths = [tmp_path / n for n in names]
+    for p in paths:
+        p.write_bytes(b"original")
+    return paths
+
+
+def test_unchanged_queue_is_not_stale(tmp_path: Path):
+    ref, other = _files(tmp_path, "ref.mkv", "other.mkv")
+    sources = {"Source 1": str(ref), "Source 2": str(other)}
+    jobs = _restore(tmp_path, [{"sources": sources}])
+    assert jobs[0]["status"] == "Needs Configuration"
+    assert not jobs[0].get("stale_layout")
+
+
+def test_replaced_file_is_stale(tmp_path: Path):
+    ref, other = _files(tmp_path, "ref.mkv", "other.mkv")
+
+    def replace_other():
+        other.write_bytes(b"a different file")
+        os.utime(other, ns=(0, 0))
+
+    sources = {"Source 1": str(ref), "Source 2": str(other)}
+    jobs = _restore(tmp_path, [{"sources": sources}], replace_other)
+    assert jobs[0]["stale_layout"]
+
+
+def test_missing_part_is_unavailable(tmp_path: Path):
+    ref, part1, part2 = _files(tmp_path, "ref.mkv", "part1.mkv", "part2.mkv")
+    sources = {"Source 1": str(ref), "Source 2": [str(part1), str(part2)]}
+    jobs = _restore(tmp_path, [{"sources": sources}], part2.unlink)
+    assert jobs[0]["status"] == "Unavailable"
+    assert jobs[0]["missing_sources"] == ["Source 2"]
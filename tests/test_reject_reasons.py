@@ -0,0 +1,115 @@
+# tests/test_reject_reasons.py
+"""
+Per-window rejection reasons (RejectReason on ChunkResult).
+
+Validates:
+1. Dense correlation tags every rejected window: out of range (a bounded
+   method whose peak lies beyond the bound), low energy, below min match;
+   silence is counted without being correlated
+2. The counts reach the log, the JSON report and the CSV
+"""
+
+import csv
+import json
+import sys
+from dataclasses import dataclass
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation.dense import run_dense_correlation  # noqa: E402
+from vsg_core.analysis.report import AnalysisReport  # noqa: E402
+from vsg_core.analysis.types import (  # noqa: E402
+    RejectReason,
+    count_rejections,
+    describe_rejections,
+)
+
+SR = 1000
+# Window amplitude -> what the fake method makes of it
+MATCH, FAR, QUIET, SILENT, NOISY = 0.5, 0.6, 0.004, 0.0, 0.7
+
+
+@dataclass(frozen=True, slots=True)
+class _FakeBounded:
+    """Answers by window amplitude; FAR peaks at 900 ms, beyond a bound."""
+
+    name: str = "Fake"
+    config_key: str = "multi_corr_fake"
+    max_lag_ms: float = 0.0
+
+    def find_delay(self, ref_chunk, tgt_chunk, sr):
+        level = round(float(ref_chunk[0]), 3)
+        if level == FAR:
+            if 0 < self.max_lag_ms < 900:
+                return 40.0, 0.0
+            return 900.0, 80.0
+        if level == MATCH:
+            return 40.0, 90.0
+        return 40.0, 3.0
+
+
+def _run(log):
+    levels = [MATCH, FAR, QUIET, SILENT, NOISY]
+    pcm = np.concatenate([np.full(SR, v, dtype=np.float32) for v in levels])
+    return run_dense_correlation(
+        pcm,
+        pcm,
+        SR,
+        _FakeBounded(max_lag_ms=500.0),
+        window_s=1.0,
+        hop_s=1.0,
+        min_match=10.0,
+        start_pct=0.0,
+        end_pct=100.0,
+        log=log,
+    )
+
+
+def test_dense_tags_rejected_windows():
+    logged = []
+    results = _run(logged.append)
+
+    assert [r.reject_reason for r in results] == [
+        None,
+        RejectReason.OUT_OF_RANGE,
+        RejectReason.LOW_ENERGY,  # About -48 dB, under the -45 dB default
+        RejectReason.BELOW_MIN_MATCH,
+    ]
+    assert (
+        "  Rejected: 1 out of range, 1 low energy, 1 below min match, 1 silence"
+        in logged
+    )
+
+
+def test_counts_reach_report(tmp_path):
+    results = _run(lambda msg: None)
+    report = AnalysisReport(job_name="job")
+    report.add_source("Source 2", 40, 40.0, "Mode (Most Common)", results)
+
+    data = json.loads(json.dumps(report.to_dict()))
+    source = data["sources"][0]
+    assert source["rejections"] == {
+        "out_of_range": 1,
+        "low_energy": 1,
+        "below_min_match": 1,
+    }
+    assert source["chunks"][1]["reject_reason"] == "out_of_range"
+
+    with report.to_csv(tmp_path / "report.csv").open(newline="") as f:
+        rows = list(csv.DictReader(f))
+    assert [r["reject_reason"] for r in rows] == [
+        "",
+        "out_of_range",
+        "low_energy",
+        "below_min_match",
+    ]
+
+    counts = count_rejections(results, silence_windows=2)
+    assert counts[RejectReason.SILENCE] == 2
+    assert describe_rejections({}) == "none"
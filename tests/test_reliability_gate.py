@@ -0,0 +1,132 @@
+# tests/test_reliability_gate.py
+"""
+Tests for the analysis confidence gate (vsg_core.analysis.reliability).
+
+Validates:
+1. With both thresholds at 0 (the default) every chunk set passes, even
+   one with no accepted windows
+2. Confidence is the mean match % of the accepted windows only, and the
+   accepted fraction counts rejected windows; a source is held back when
+   it misses either threshold, with a reason per threshold missed
+3. Sources at exactly a threshold pass
+4. enforce() does nothing without unreliable sources; otherwise
+   "needs_review" stops the job as Needs Review keeping the measured
+   delays, and "fail" stops it as a failed analysis
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")
+
+from vsg_core.analysis.reliability import (  # noqa: E402
+    AnalysisNeedsReview,
+    ReliabilityGate,
+    UnreliableSource,
+)
+from vsg_core.analysis.types import ChunkResult  # noqa: E402
+from vsg_core.errors import AnalysisUnreliable, ErrorKind  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+
+def _chunks(accepted: list[float], rejected: int = 0) -> list[ChunkResult]:
+    """Accepted windows with the given match %, plus rejected ones."""
+    chunks = [
+        ChunkResult(-120, -120.0, pct, float(i), True)
+        for i, pct in enumerate(accepted)
+    ]
+    chunks += [ChunkResult(0, 0.0, 2.0, 100.0 + i, False) for i in range(rejected)]
+    return chunks
+
+
+def test_off_by_default():
+    gate = ReliabilityGate.from_settings(AppSettings())
+    assert not gate.enabled
+    assert gate.check("Source 2", _chunks([], rejected=10)) is None
+    assert gate.check("Source 2", []) is None
+
+
+@pytest.mark.parametrize(
+    ("chunks", "expected"),
+    [
+        # Rejected windows don't pull the confidence down
+        (_chunks([80.0, 90.0], rejected=2), None),
+        (_chunks([80.0, 90.0], rejected=8), ["accepted fraction 0.20 < 0.5"]),
+        (_chunks([40.0, 50.0, 60.0]), ["confidence 50.0% < 70%"]),
+        (
+            _chunks([40.0], rejected=3),
+            ["confidence 40.0% < 70%", "accepted fraction 0.25 < 0.5"],
+        ),
+        # Nothing accepted: 0% confidence, 0 fraction
+        (
+            _chunks([], rejected=5),
+            ["confidence 0.0% < 70%", "accepted fraction 0.00 < 0.5"],
+        ),
+        # Exactly at both thresholds
+        (_chunks([70.0, 70.0], rejected=2), None),
+    ],
+)
+def test_which_chunk_sets_pass(chunks, expected):
+    gate = ReliabilityGate(min_confidence=70.0, min_accepted_fraction=0.5)
+    result = gate.check("Source 2", chunks)
+    if expected is None:
+        assert result is None
+    else:
+        assert list(result.reasons) == expected
+        assert result.total == len(chunks)
+        assert result.accepted == sum(1 for c in chunks if c.accepted)
+
+
+def test_single_threshold():
+    by_confidence = ReliabilityGate(min_confidence=70.0)
+    assert by_confidence.check("Source 2", _chunks([90.0], rejected=99)) is None
+
+    by_fraction = ReliabilityGate(min_accepted_fraction=0.5)
+    assert by_fraction.check("Source 2", _chunks([10.0, 10.0])) is None
+
+
+def test_unreliable_source_describe():
+    source = ReliabilityGate(min_confidence=70.0).check(
+        "Source 3", _chunks([50.0], rejected=3)
+    )
+    assert source.accepted_fraction == 0.25
+    assert source.describe() == (
+        "Source 3: confidence 50.0% < 70% (1/4 windows accepted)"
+    )
+
+
+def _unreliable() -> list[UnreliableSource]:
+    return [UnreliableSource("Source 2", 40.0, 1, 4, ("confidence 40.0% < 70%",))]
+
+
+def test_enforce_without_unreliable_sources():
+    ReliabilityGate(min_confidence=70.0, action="fail").enforce([], {})
+
+
+def test_needs_review_keeps_the_measured_delays():
+    gate = ReliabilityGate.from_settings(AppSettings(abort_below_confidence=70.0))
+    assert gate.action == "needs_review"
+    with pytest.raises(AnalysisNeedsReview) as exc:
+        gate.enforce(_unreliable(), {"Source 2": -120})
+
+    assert exc.value.delays == {"Source 2": -120}
+    assert exc.value.sources == _unreliable()
+    assert "needs manual review" in str(exc.value)
+    assert "Source 2: confidence 40.0% < 70%" in str(exc.value)
+
+
+def test_fail_action_fails_the_job():
+    gate = ReliabilityGate.from_settings(
+        AppSettings(abort_below_confidence=70.0, unreliable_analysis_action="fail")
+    )
+    with pytest.raises(AnalysisUnreliable) as exc:
+        gate.enforce(_unreliable(), {"Source 2": -120})
+
+    assert not isinstance(exc.value, AnalysisNeedsReview)
+    assert exc.value.kind == ErrorKind.ANALYSIS_UNRELIABLE
+    assert str(exc.value).startswith("Analysis result is unreliable. Source 2:")
@@ -0,0 +1,92 @@
+# tests/test_report_diff.py
+"""
+Tests for diffing analysis reports between runs (vsg_core.analysis.report_diff).
+
+Validates:
+1. Unchanged sources are not reported as changed or flagged
+2. Delay moves beyond the threshold are flagged; smaller moves are only changed
+3. Sources missing from the current run are flagged, new ones are not
+4. Text and JSON renderings carry the deltas
+"""
+
+import json
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.analysis.report_diff import diff_reports, load_report_set
+
+
+def _report(job, *sources):
+    return {
+        "job_name": job,
+        "global_shift_ms": 0,
+        "sources": [
+            {
+                "source": key,
+                "delay_ms": delay,
+                "confidence": conf,
+                "accepted_chunks": accepted,
+                "total_chunks": 10,
+            }
+            for key, delay, conf, accepted in sources
+        ],
+    }
+
+
+def test_unchanged_source_is_same():
+    prev = [_report("ep01", ("Source 2", -120, 80.0, 9))]
+    diff = diff_reports(prev, prev)
+    assert [s.status for s in diff.sources] == ["same"]
+    assert diff.changed == []
+    assert diff.flagged == []
+
+
+def test_delay_move_beyond_threshold_is_flagged():
+    prev = [_report("ep01", ("Source 2", -120, 80.0, 9), ("Source 3", 40, 70.0, 8))]
+    curr = [_report("ep01", ("Source 2", -155, 75.5, 7), ("Source 3", 41, 72.0, 8))]
+    diff = diff_reports(prev, curr, threshold_ms=5.0)
+
+    s2, s3 = diff.sources
+    assert s2.delay_delta_ms == -35
+    assert s2.confidence_delta == -4.5
+    assert s2.accepted_delta == -2
+    assert [s.source_key for s in diff.flagged] == ["Source 2"]
+    assert s3.status == "changed"
+    assert not s3.is_flagged(diff.threshold_ms)
+
+
+def test_missing_sources_are_flagged_and_new_ones_listed():
+    prev = [_report("ep01", ("Source 2", 0, 80.0, 9)), _report("ep02")]
+    curr = [_report("ep01", ("Source 3", 5, 60.0, 6))]
+    diff = diff_reports(prev, curr)
+
+    by_key = {s.source_key: s for s in diff.sources}
+    assert by_key["Source 3"].status == "added"
+    assert by_key["Source 2"].status == "removed"
+    assert [s.source_key for s in diff.flagged] == ["Source 2"]
+
+
+def test_text_and_json_rendering(tmp_path):
+    prev_dir = tmp_path / "prev"
+    curr_dir = tmp_path / "curr"
+    prev_dir.mkdir()
+    curr_dir.mkdir()
+    (prev_dir / "ep01_analysis_report.json").write_text(
+        json.dumps(_report("ep01", ("Source 2", -120, 80.0, 9)))
+    )
+    (curr_dir / "ep01_analysis_report.json").write_text(
+        json.dumps(_report("ep01", ("Source 2", -100, 80.0, 9)))
+    )
+
+    diff = diff_reports(load_report_set(prev_dir), load_report_set(curr_dir))
+    text = diff.to_text()
+    assert "1 flagged" in text
+    assert "ep01 / Source 2: delay -120 -> -100ms (+20)" in text
+
+    data = json.loads(diff.to_json(tmp_path / "diff.json").read_text())
+    assert data["flagged"] == 1
+    assert data["sources"][0]["delay_delta_ms"] == 20
+    assert data["sources"][0]["previous"]["delay_ms"] == -120
@@ -0,0 +1,55 @@
+"""Framerate retime (PAL 25fps -> film 24000/1001).
+
+Scaling by 25 / (24000/1001) = 1.0427083... maps source frame N onto target
+frame N: one minute of PAL time (frame 1500) becomes 62562.5ms of film time.
+"""
+
+from vsg_core.subtitles.data import SubtitleData, SubtitleEvent
+from vsg_core.subtitles.operations.retime import retime_fps, retime_time_ms
+
+PAL = 25.0
+FILM = 24000 / 1001
+
+
+def _data(start_ms: float, end_ms: float) -> SubtitleData:
+    data = SubtitleData()
+    data.events = [SubtitleEvent(start_ms=start_ms, end_ms=end_ms, text="Cue")]
+    return data
+
+
+def test_frame_lands_on_same_target_frame():
+    assert abs(retime_time_ms(60000.0, PAL, FILM) - 62562.5) < 1e-6
+    assert abs(retime_time_ms(40.0, PAL, FILM) - 1001 / 24) < 1e-6
+
+
+def test_sample_cue_lands_on_expected_millisecond():
+    # 00:01:00.040 -> 00:01:02.604 (frame 1501 at 24000/1001 = 62604.208ms),
+    # 00:01:02.000 -> 00:01:04.648 (64647.917ms)
+    data = _data(60040.0, 62000.0)
+    result = retime_fps(data, PAL, FILM, rounding="round")
+    assert result.success
+    assert result.events_affected == 1
+    assert data.events[0].start_ms == 62604.0
+    assert data.events[0].end_ms == 64648.0
+
+
+def test_rounding_mode_applies_to_half_millisecond():
+    for mode, expected in (("floor", 62562.0), ("ceil", 62563.0)):
+        data = _data(60000.0, 61000.0)
+        retime_fps(data, PAL, FILM, rounding=mode)
+        assert data.events[0].start_ms == expected
+
+
+def test_round_trip_is_identity_on_frame_grid():
+    data = _data(60040.0, 62000.0)
+    retime_fps(data, PAL, FILM)
+    retime_fps(data, FILM, PAL)
+    assert data.events[0].start_ms == 60040.0
+    assert data.events[0].end_ms == 62000.0
+
+
+def test_invalid_framerate_is_rejected():
+    data = _data(1000.0, 2000.0)
+    result = retime_fps(data, 0.0, FILM)
+    assert not result.success
+    assert data.events[0].start_ms == 1000.0
@@ -0,0 +1,58 @@
+# tests/test_scan_sources.py
+"""
+Tests for parallel source scanning (vsg_core.extraction.tracks.scan_sources).
+
+Validates:
+1. Every worker scans with its own runner, never the caller's
+2. Worker log lines reach the caller's callback on the calling thread,
+   grouped per source in source order
+3. A failing source comes back with ``error`` set
+"""
+
+import sys
+import threading
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.extraction import tracks  # noqa: E402
+from vsg_core.io.runner import CommandRunner  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+
+def test_workers_log_through_their_own_runner(monkeypatch):
+    caller_thread = threading.current_thread()
+    seen_runners = []
+    log: list[tuple[str, threading.Thread]] = []
+
+    def fake_scan(source_key, filepath, runner, tool_paths):
+        seen_runners.append(runner)
+        runner._log_message(f"{source_key}: first")
+        runner._log_message(f"{source_key}: second")
+        if source_key == "Source 3":
+            raise RuntimeError("mkvmerge crashed")
+        return tracks.SourceScan(source_key=source_key, path=filepath)
+
+    monkeypatch.setattr(tracks, "scan_source", fake_scan)
+    runner = CommandRunner(
+        AppSettings(), lambda line: log.append((line, threading.current_thread()))
+    )
+    sources = {"Source 1": "a.mkv", "Source 2": "b.mkv", "Source 3": "c.mkv"}
+
+    scans = tracks.scan_sources(sources, runner, {})
+
+    assert list(scans) == list(sources)
+    assert scans["Source 3"].error == "mkvmerge crashed"
+    assert scans["Source 1"].error is None
+    assert runner not in seen_runners
+    assert len({id(r) for r in seen_runners}) == 3
+    assert all(thread is caller_thread for _, thread in log)
+    assert [line.split("] ", 1)[1] for line, _ in log] == [
+        "Source 1: first",
+        "Source 1: second",
+        "Source 2: first",
+        "Source 2: second",
+        "Source 3: first",
+        "Source 3: second",
+    ]
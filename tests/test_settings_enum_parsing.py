@@ -0,0 +1,88 @@
+"""Unit tests for vsg_core.models.parsing and the AppSettings round-trip.
+
+Every Literal value of analysis_mode / correlation_method / sync_mode must
+survive being lowercased (the form some UI paths hand back) and assigned
+back onto AppSettings. Unknown strings must leave the previous value alone.
+"""
+
+from __future__ import annotations
+
+import sys
+import warnings
+from pathlib import Path
+from typing import get_args
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.parsing import (  # noqa: E402
+    parse_analysis_mode,
+    parse_correlation_method,
+    parse_sync_mode,
+)
+from vsg_core.models.settings import AppSettings  # noqa: E402
+from vsg_core.models.types import (  # noqa: E402
+    AnalysisModeStr,
+    CorrelationMethodStr,
+    SyncModeStr,
+)
+
+CASES = [
+    ("analysis_mode", parse_analysis_mode, get_args(AnalysisModeStr)),
+    ("correlation_method", parse_correlation_method, get_args(CorrelationMethodStr)),
+    ("sync_mode", parse_sync_mode, get_args(SyncModeStr)),
+]
+
+
+@pytest.mark.parametrize(
+    ("parser", "value"),
+    [(parser, value) for _, parser, values in CASES for value in values],
+)
+def test_parse_round_trips_every_variant(parser, value) -> None:
+    assert parser(value) == value
+    assert parser(value.lower()) == value
+    assert parser(value.upper()) == value
+
+
+@pytest.mark.parametrize(
+    ("key", "value"),
+    [(key, value) for key, _, values in CASES for value in values],
+)
+def test_settings_accept_lowercased_variant(key: str, value: str) -> None:
+    settings = AppSettings()
+    setattr(settings, key, value.lower())
+    assert getattr(settings, key) == value
+    assert AppSettings.model_validate(settings.to_dict()) == settings
+
+
+def test_sync_mode_accepts_dashed_spelling() -> None:
+    assert parse_sync_mode("allow-negative") == "allow_negative"
+
+
+def test_correlation_short_keys() -> None:
+    assert parse_correlation_method("scc") == "Standard Correlation (SCC)"
+    assert parse_correlation_method("gcc_phat") == "Phase Correlation (GCC-PHAT)"
+    assert parse_correlation_method("gcc_whiten") == "Whitened Cross-Correlation"
+
+
+@pytest.mark.parametrize("parser", [parser for _, parser, _ in CASES])
+def test_unknown_strings_return_none(parser) -> None:
+    assert parser("definitely not a mode") is None
+    assert parser("") is None
+    assert parser(None) is None
+
+
+@pytest.mark.parametrize("key", [key for key, _, _ in CASES])
+def test_unknown_value_keeps_previous(key: str) -> None:
+    from vsg_core.config import AppConfig
+
+    config = AppConfig.__new__(AppConfig)
+    config.settings = AppSettings()
+    previous = getattr(config.settings, key)
+    with warnings.catch_warnings(record=True) as caught:
+        warnings.simplefilter("always")
+        assert config.set(key, "bogus value") is False
+    assert getattr(config.settings, key) == previous
+    assert any("Keeping previous value" in str(w.message) for w in caught)
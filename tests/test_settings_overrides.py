@@ -0,0 +1,106 @@
+# tests/test_settings_overrides.py
+"""
+Tests for per-job settings overrides (vsg_core.models.overrides).
+
+Validates:
+1. Overrides are merged over the global settings, coerced like the
+   settings file, and logged as before -> after
+2. The shared global settings object is never modified
+3. Unknown keys are reported by validate_overrides() and skipped (with a
+   log line) by apply_overrides()
+4. Values a setting does not accept (wrong Literal, wrong type) are
+   reported, and apply_overrides() raises ValueError for them
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.overrides import (  # noqa: E402
+    apply_overrides,
+    unknown_override_keys,
+    validate_overrides,
+)
+from vsg_core.models.settings import AppSettings  # noqa: E402
+
+
+@pytest.fixture
+def global_settings() -> AppSettings:
+    return AppSettings(scan_end_percentage=90.0, subtitle_sync_mode="time-based")
+
+
+def test_no_overrides_returns_the_same_settings(global_settings):
+    assert apply_overrides(global_settings, None) == (global_settings, [])
+    effective, lines = apply_overrides(global_settings, {})
+    assert effective is global_settings
+    assert lines == []
+
+
+def test_overrides_are_merged_and_logged(global_settings):
+    effective, lines = apply_overrides(
+        global_settings,
+        {"scan_end_percentage": "95", "subtitle_sync_mode": "time-based"},
+    )
+    assert effective.scan_end_percentage == 95.0  # Coerced from the string
+    assert effective.subtitle_sync_mode == "time-based"
+    assert lines == [
+        "[Overrides] scan_end_percentage: 90.0 -> 95.0",
+        "[Overrides] subtitle_sync_mode: 'time-based' (same as global)",
+    ]
+
+
+def test_global_settings_stay_unchanged(global_settings):
+    before = global_settings.model_dump()
+    effective, _ = apply_overrides(
+        global_settings,
+        {"scan_end_percentage": 95.0, "subtitle_sync_mode": "video-verified"},
+    )
+    assert effective is not global_settings
+    assert global_settings.model_dump() == before
+    # Every setting not overridden keeps its global value
+    others = effective.model_dump()
+    others.update(scan_end_percentage=90.0, subtitle_sync_mode="time-based")
+    assert others == before
+
+
+def test_unknown_keys_are_reported_and_skipped(global_settings):
+    overrides = {"scan_end_percentage": 95.0, "zz_future": 1, "aa_old": True}
+    assert unknown_override_keys(overrides) == ["aa_old", "zz_future"]
+    assert validate_overrides(overrides) == [
+        "Unknown setting 'aa_old'",
+        "Unknown setting 'zz_future'",
+    ]
+
+    effective, lines = apply_overrides(global_settings, overrides)
+    assert effective.scan_end_percentage == 95.0
+    assert not hasattr(effective, "zz_future")
+    assert lines[:2] == [
+        "[Overrides] Ignored unknown setting 'aa_old'.",
+        "[Overrides] Ignored unknown setting 'zz_future'.",
+    ]
+
+
+def test_invalid_literal_value(global_settings):
+    problems = validate_overrides({"subtitle_sync_mode": "frame-perfect"})
+    assert len(problems) == 1
+    assert problems[0].startswith("subtitle_sync_mode: ")
+
+    with pytest.raises(ValueError, match="subtitle_sync_mode"):
+        apply_overrides(global_settings, {"subtitle_sync_mode": "frame-perfect"})
+    assert global_settings.subtitle_sync_mode == "time-based"
+
+
+def test_invalid_type(global_settings):
+    problems = validate_overrides({"scan_end_percentage": "most of it"})
+    assert [p.split(":")[0] for p in problems] == ["scan_end_percentage"]
+    with pytest.raises(ValueError):
+        apply_overrides(global_settings, {"scan_end_percentage": "most of it"})
+
+
+def test_valid_overrides_have_no_problems():
+    assert validate_overrides({}) == []
+    assert validate_overrides({"subtitle_sync_mode": "linear-stretch"}) == []
@@ -0,0 +1,58 @@
+# tests/test_silence_avoidance.py
+"""
+Window placement off silent regions
+(vsg_core.analysis.correlation.dense._place_avoiding_silence), with dense
+silence: 250-sample blocks, one second of signal in five.
+
+Validates:
+1. Two quiet windows nudged onto the same loud start give one window
+2. A nudged window never ends past the scan range
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+from vsg_core.analysis.correlation.dense import (  # noqa: E402
+    _place_avoiding_silence,
+)
+
+SR = 1000
+SCAN_END = 5000
+HOP = 1000
+
+
+def _place(loud: tuple[int, int], window: int, positions: list[int]) -> list[int]:
+    pcm = np.zeros(SCAN_END, dtype=np.float32)
+    pcm[loud[0] : loud[1]] = 1.0  # 0 dB; everything else silent
+    return _place_avoiding_silence(
+        pcm,
+        pcm,
+        positions,
+        0,
+        SCAN_END,
+        window,
+        HOP,
+        -1.0,  # Only windows fully inside the signal count as loud
+        SR,
+        lambda _msg: None,
+    )
+
+
+def test_windows_nudged_onto_one_start_are_deduplicated():
+    # Only the window starting at 1500 is loud; 1000 and 2000 both move there
+    placed = _place((1500, 2500), 1000, [0, 1000, 2000, 3000, 4000])
+    assert placed == [0, 1500, 3000, 4000]
+
+
+def test_nudged_window_stays_inside_scan_range():
+    # The window at 3800 moves to the loud start at 4000, which would end at
+    # 5100 with an 1100-sample window; it is held back to 3900
+    placed = _place((4000, 5000), 1100, [0, 1000, 2000, 3000, 3800])
+    assert placed == [0, 1000, 2000, 3000, 3900]
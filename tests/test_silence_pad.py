@@ -0,0 +1,68 @@
+# tests/test_silence_pad.py
+"""
+Tests for the pad_silence sync mode (vsg_core.mux.pad.plan_silence_pad).
+
+Validates:
+1. A negative delay trims that much audio and pads the tail to the video
+2. Audio running past the video is cut; positive/zero delays are refused
+3. The loudnorm analysis pass runs through the trim/pad filter
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.mux.loudnorm import LoudnormSpec, measure_loudness  # noqa: E402
+from vsg_core.mux.pad import plan_silence_pad  # noqa: E402
+
+
+def test_negative_delay_trims_and_pads():
+    pad = plan_silence_pad(-1500, audio_duration_s=100.0, video_duration_s=100.0)
+    assert pad.trim_ms == 1500
+    assert pad.silence_s == pytest.approx(1.5)
+    assert pad.duration_s == 100.0
+    assert pad.ffmpeg_filter() == (
+        "atrim=start=1.500,asetpts=PTS-STARTPTS,"
+        "apad=whole_dur=100.000,atrim=duration=100.000"
+    )
+
+
+def test_long_audio_is_cut_to_video():
+    pad = plan_silence_pad(-200, audio_duration_s=130.0, video_duration_s=120.0)
+    assert pad.silence_s == 0.0
+    assert pad.duration_s == 120.0
+
+
+def test_delay_longer_than_audio():
+    pad = plan_silence_pad(-5000, audio_duration_s=3.0, video_duration_s=10.0)
+    assert pad.silence_s == 10.0
+
+
+@pytest.mark.parametrize("delay_ms", [0, 1, 250])
+def test_non_negative_delay_is_refused(delay_ms):
+    with pytest.raises(ValueError):
+        plan_silence_pad(delay_ms, 100.0, 100.0)
+
+
+def test_loudness_is_measured_after_padding():
+    class Runner:
+        cmd: list[str] = []
+
+        def run(self, cmd, tool_paths):
+            Runner.cmd = cmd
+            return (
+                '[Parsed_loudnorm_3 @ 0x1]\n{"input_i": "-20.5", "input_lra": '
+                '"5.0", "input_tp": "-3.1", "input_thresh": "-31.0", '
+                '"target_offset": "0.2"}'
+            )
+
+    pad = plan_silence_pad(-1000, 60.0, 60.0).ffmpeg_filter()
+    spec = LoudnormSpec()
+    measured = measure_loudness("a.mka", spec, Runner(), {}, pre_filters=(pad,))
+    assert measured is not None and measured.integrated_lufs == -20.5
+    af = Runner.cmd[Runner.cmd.index("-af") + 1]
+    assert af == f"{pad},{spec.measure_filter()}"
@@ -0,0 +1,58 @@
+"""Unit tests for the subtitle event timing sanity check (lint)."""
+
+from vsg_core.subtitles.data import SubtitleEvent
+from vsg_core.subtitles.operations.lint import (
+    LARGE_GAP_MS,
+    MIN_DURATION_MS,
+    SubtitleLintResult,
+    fix_subtitle_timing,
+    lint_subtitles,
+)
+
+
+def _ev(start_ms: float, end_ms: float, *, comment: bool = False) -> SubtitleEvent:
+    return SubtitleEvent(start_ms=start_ms, end_ms=end_ms, text="x", is_comment=comment)
+
+
+def test_clean_track_has_no_issues() -> None:
+    # Overlaps are legitimate in ASS and not flagged
+    events = [_ev(0, 2000), _ev(1000, 3000), _ev(0, 0, comment=True)]
+    assert lint_subtitles(events) == []
+
+
+def test_broken_events_are_flagged() -> None:
+    events = [
+        _ev(-500, 1000),
+        _ev(2000, 2000),
+        _ev(5000, 4000),
+        _ev(3000, 3500),
+        _ev(5000 + LARGE_GAP_MS + 1, 5000 + LARGE_GAP_MS + 1000),
+    ]
+
+    issues = lint_subtitles(events)
+
+    assert [(i.kind, i.index) for i in issues] == [
+        ("negative_start", 0),
+        ("zero_duration", 1),
+        ("ends_before_start", 2),
+        ("out_of_order", 3),
+        ("large_gap", 4),
+    ]
+    assert issues[1].describe() == "Event 2 (2.000s -> 2.000s): zero duration"
+    result = SubtitleLintResult("Signs (External)", True, tuple(issues))
+    assert result.summary().startswith("1 start before 0, 1 zero duration")
+
+
+def test_fix_clamps_and_reorders() -> None:
+    events = [_ev(-500, 1000), _ev(5000, 4000), _ev(2000, 2000), _ev(3000, 3500)]
+
+    fixed = fix_subtitle_timing(events)
+
+    assert [(e.start_ms, e.end_ms) for e in events] == [
+        (0.0, 1000),
+        (2000, 2000 + MIN_DURATION_MS),
+        (3000, 3500),
+        (4000, 5000),
+    ]
+    assert fixed == 4
+    assert lint_subtitles(events) == []
@@ -0,0 +1,81 @@
+"""Verbatim ASS round-trip and tolerant SRT parsing.
+
+After a shift, only the Dialogue timestamps may differ from the source file:
+Script Info comments and spacing, style lines and event fields (e.g. the
+zero-padded margins of older files) must survive byte-for-byte.
+"""
+
+import codecs
+
+from vsg_core.subtitles.data import SubtitleData
+from vsg_core.subtitles.sync_utils import apply_delay_to_events
+
+ASS = """[Script Info]
+; Script generated by Aegisub 3.2.2
+Title: Sample
+ScriptType:   v4.00+
+
+PlayResX: 1920
+PlayResY: 1080
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,Arial,48.0,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,2,2,10,10,10,1
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:01.00,0:00:02.50,Default,,0000,0000,0000,,Hello, world
+Comment: 0,0:00:03.00,0:00:04.00,Default,,0000,0000,0000,,note
+"""
+
+
+def _load(tmp_path, name: str, raw: bytes) -> SubtitleData:
+    path = tmp_path / name
+    path.write_bytes(raw)
+    return SubtitleData.from_file(path)
+
+
+def test_ass_roundtrip_changes_only_timestamps(tmp_path):
+    data = _load(tmp_path, "in.ass", ASS.encode("utf-8"))
+    apply_delay_to_events(data, 1000.0)
+    out = tmp_path / "out.ass"
+    data.save_ass(out)
+
+    written = out.read_text(encoding="utf-8").splitlines()
+    expected = (
+        ASS.replace("0:00:01.00,0:00:02.50", "0:00:02.00,0:00:03.50")
+        .strip()
+        .splitlines()
+    )
+    assert [line for line in written if line] == [line for line in expected if line]
+    assert "; Script generated by Aegisub 3.2.2" in written
+    assert "ScriptType:   v4.00+" in written
+
+
+def test_ass_modified_fields_are_rewritten(tmp_path):
+    data = _load(tmp_path, "in.ass", ASS.encode("utf-8"))
+    data.script_info["PlayResX"] = "1280"
+    data.events[0].text = "Changed"
+    out = tmp_path / "out.ass"
+    data.save_ass(out)
+
+    written = out.read_text(encoding="utf-8")
+    assert "PlayResX: 1280" in written
+    assert "Dialogue: 0,0:00:01.00,0:00:02.50,Default,,0,0,0,,Changed" in written
+
+
+def test_srt_tolerates_bom_crlf_and_missing_trailing_blank(tmp_path):
+    srt = (
+        "1\r\n00:00:01,000 --> 00:00:02,000\r\nFirst\r\n\r\n"
+        "2\r\n00:00:03,000 --> 00:00:04,500\r\nSecond"
+    )
+    for name, raw in (
+        ("utf8.srt", codecs.BOM_UTF8 + srt.encode("utf-8")),
+        ("utf16le.srt", codecs.BOM_UTF16_LE + srt.encode("utf-16-le")),
+        ("utf16be.srt", codecs.BOM_UTF16_BE + srt.encode("utf-16-be")),
+    ):
+        data = _load(tmp_path, name, raw)
+        assert [(e.start_ms, e.end_ms, e.text) for e in data.events] == [
+            (1000.0, 2000.0, "First"),
+            (3000.0, 4500.0, "Second"),
+        ], name
@@ -0,0 +1,143 @@
+# tests/test_sync_subs.py
+"""
+Tests for subtitle-only sync (vsg_core.subtitle_sync) through
+``vsg-cli sync-subs``.
+
+Validates:
+1. A manual offset shifts every SRT and ASS event by that much, in the
+   default ``<name>.synced.<ext>`` output next to the subtitle
+2. -o and --format write elsewhere and convert ASS to SRT
+3. --json prints the result document and nothing else on stdout
+4. Missing inputs, unsupported formats and an offset-less call without a
+   source video exit with code 2; the work directory is always removed
+"""
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+# The steps package pulls in the analysis stack
+pytest.importorskip("scipy")
+
+from vsg_core import cli  # noqa: E402
+from vsg_core.models.settings import AppSettings  # noqa: E402
+from vsg_core.pipeline_components import ToolValidator  # noqa: E402
+from vsg_core.subtitles.data import SubtitleData  # noqa: E402
+
+SRT = """1
+00:00:01,000 --> 00:00:02,500
+Hello
+
+2
+00:00:10,000 --> 00:00:12,000
+World
+"""
+
+ASS = """[Script Info]
+ScriptType: v4.00+
+PlayResX: 1920
+PlayResY: 1080
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,Arial,48,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:01.00,0:00:02.50,Default,,0,0,0,,Hello
+Dialogue: 0,0:00:10.00,0:00:12.00,Default,,0,0,0,,World
+"""
+
+
+@pytest.fixture
+def job(tmp_path: Path, monkeypatch):
+    """A video (never read with a manual offset), settings and no tools."""
+    video = tmp_path / "ep01.mkv"
+    video.write_bytes(b"")
+    temp_root = tmp_path / "work"
+    settings = AppSettings(temp_root=str(temp_root), subtitle_sync_mode="time-based")
+    monkeypatch.setattr(cli, "load_settings", lambda args: (settings, []))
+    monkeypatch.setattr(ToolValidator, "validate_tools", staticmethod(dict))
+    return video, temp_root
+
+
+def _times(path: Path) -> list[tuple[float, float]]:
+    return [(e.start_ms, e.end_ms) for e in SubtitleData.from_file(path).events]
+
+
+def test_offset_shifts_srt(job, tmp_path: Path):
+    video, temp_root = job
+    sub = tmp_path / "ep01.srt"
+    sub.write_text(SRT, encoding="utf-8")
+
+    assert cli.main(["sync-subs", str(video), str(sub), "--offset", "1500"]) == 0
+
+    out = tmp_path / "ep01.synced.srt"
+    assert _times(out) == [(2500, 4000), (11500, 13500)]
+    assert _times(sub) == [(1000, 2500), (10000, 12000)]  # Input untouched
+    assert not any(temp_root.iterdir())
+
+
+def test_negative_offset_shifts_ass(job, tmp_path: Path):
+    video, _ = job
+    sub = tmp_path / "ep01.ass"
+    sub.write_text(ASS, encoding="utf-8")
+
+    assert cli.main(["sync-subs", str(video), str(sub), "--offset", "-500"]) == 0
+
+    out = tmp_path / "ep01.synced.ass"
+    assert _times(out) == [(500, 2000), (9500, 11500)]
+    assert "Style: Default,Arial,48" in out.read_text(encoding="utf-8")
+
+
+def test_output_path_and_format(job, tmp_path: Path):
+    video, _ = job
+    sub = tmp_path / "ep01.ass"
+    sub.write_text(ASS, encoding="utf-8")
+    target = tmp_path / "out" / "episode"
+    target.parent.mkdir()
+
+    argv = ["sync-subs", str(video), str(sub), "--offset", "250"]
+    argv += ["-o", str(target), "--format", "srt"]
+    assert cli.main(argv) == 0
+
+    assert _times(tmp_path / "out" / "episode.srt") == [(1250, 2750), (10250, 12250)]
+
+
+def test_json_result(job, tmp_path: Path, capsys):
+    video, _ = job
+    sub = tmp_path / "ep01.srt"
+    sub.write_text(SRT, encoding="utf-8")
+
+    argv = ["sync-subs", str(video), str(sub), "--offset", "40", "--json"]
+    assert cli.main(argv) == 0
+
+    doc = json.loads(capsys.readouterr().out)
+    assert doc["command"] == "sync-subs"
+    assert doc["result"]["output_path"] == str(tmp_path / "ep01.synced.srt")
+    assert doc["result"]["delay_ms"] == 40
+    assert doc["result"]["sync_mode"] == "time-based"
+    assert doc["result"]["events"] == 2
+
+
+def test_bad_inputs_exit_with_2(job, tmp_path: Path):
+    video, temp_root = job
+    srt = tmp_path / "ep01.srt"
+    srt.write_text(SRT, encoding="utf-8")
+    vtt = tmp_path / "ep01.vtt"
+    vtt.write_text("WEBVTT\n", encoding="utf-8")
+
+    missing = str(tmp_path / "missing.srt")
+    assert cli.main(["sync-subs", str(video), missing, "--offset", "1"]) == 2
+    assert cli.main(["sync-subs", str(video), str(vtt), "--offset", "1"]) == 2
+    # Neither an offset nor a source video to measure one against
+    assert cli.main(["sync-subs", str(video), str(srt)]) == 2
+    # Video-verified needs the subtitle's source video
+    argv = ["sync-subs", str(video), str(srt), "--offset", "1"]
+    assert cli.main([*argv, "--mode", "video-verified"]) == 2
+    assert not temp_root.exists() or not any(temp_root.iterdir())
@@ -0,0 +1,86 @@
+# tests/test_sync_targets.py
+"""
+Tests for 'Sync to Source' resolution (vsg_core.mux.sync_targets).
+
+Validates:
+1. External tracks resolve to their sync_to; source tracks to their source
+2. A reference to a source the job doesn't have is reported
+3. An external track syncing to 'External' is reported as a self-reference
+4. check_sync_targets lists every offending track in one error
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.mux.sync_targets import (  # noqa: E402
+    check_sync_targets,
+    resolve_sync_key,
+    sync_target_problems,
+)
+
+SOURCES = ["Source 1", "Source 2"]
+
+
+def _external(track_id, sync_to, name="signs.ass"):
+    return {
+        "source": "External",
+        "id": track_id,
+        "type": "subtitles",
+        "original_path": f"/subs/{name}",
+        "sync_to": sync_to,
+    }
+
+
+def test_resolve_sync_key():
+    assert resolve_sync_key("External", "Source 2") == "Source 2"
+    assert resolve_sync_key("External", None) is None
+    # sync_to is ignored for tracks of a real source
+    assert resolve_sync_key("Source 2", "Source 1") == "Source 2"
+
+
+def test_valid_layout_has_no_problems():
+    layout = [
+        {"source": "Source 1", "id": 0, "type": "video"},
+        {"source": "Source 2", "id": 1, "type": "audio", "sync_to": "Source 9"},
+        _external(0, "Source 2"),
+        _external(1, None),
+    ]
+    assert sync_target_problems(layout, SOURCES) == []
+    check_sync_targets(layout, SOURCES)
+
+
+def test_missing_source_is_reported():
+    problems = sync_target_problems([_external(0, "Source 3")], SOURCES)
+
+    assert len(problems) == 1
+    assert "'Source 3'" in problems[0]
+    assert "signs.ass" in problems[0]
+    assert "not a source of this job" in problems[0]
+
+
+def test_self_reference_is_reported():
+    problems = sync_target_problems([_external(0, "External")], SOURCES)
+
+    assert len(problems) == 1
+    assert "syncs to itself" in problems[0]
+
+
+def test_check_lists_every_offending_track():
+    layout = [
+        _external(0, "Source 3", name="a.srt"),
+        _external(1, "Source 2", name="b.srt"),
+        _external(2, "External", name="c.srt"),
+    ]
+    with pytest.raises(ValueError) as exc:
+        check_sync_targets(layout, SOURCES)
+
+    message = str(exc.value)
+    assert "a.srt" in message
+    assert "c.srt" in message
+    assert "b.srt" not in message
+    assert "Source 1, Source 2" in message
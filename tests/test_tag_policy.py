@@ -0,0 +1,127 @@
+# tests/test_tag_policy.py
+"""
+Tests for the output tag policy and title (vsg_core.mux.tags).
+
+Validates:
+1. Source 1's tags split into global tags (carried) and track tags (not)
+2. Each policy keeps or drops Source 1's title; output_title always wins
+3. output_title placeholders, including {source1_title}
+4. mkvmerge tokens: --title, --global-tags and per-input tag stripping
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.jobs import Delays, MergePlan, PlanItem
+from vsg_core.models.media import StreamProps, Track
+from vsg_core.models.settings import AppSettings
+from vsg_core.mux.options_builder import MkvmergeOptionsBuilder
+from vsg_core.mux.tags import SourceTags, TagPolicy
+
+_TAGS_XML = """<?xml version="1.0"?>
+<Tags>
+  <Tag>
+    <Targets><TargetTypeValue>50</TargetTypeValue></Targets>
+    <SimpleTag><Name>COMMENT</Name><String>Release notes</String></SimpleTag>
+    <SimpleTag><Name>DATE_RELEASED</Name><String>2020</String></SimpleTag>
+  </Tag>
+  <Tag>
+    <Targets><TrackUID>123456</TrackUID></Targets>
+    <SimpleTag><Name>BPS</Name><String>4000000</String></SimpleTag>
+  </Tag>
+</Tags>
+"""
+
+
+def test_parse_keeps_only_global_tags():
+    source = SourceTags.parse("Show 05", _TAGS_XML)
+    assert source.global_tag_names == ("COMMENT", "DATE_RELEASED")
+    assert source.track_tag_count == 1
+    assert "COMMENT" in source.global_tags_xml
+    assert "TrackUID" not in source.global_tags_xml
+    assert "2 global tag(s)" in source.describe()
+
+
+def test_parse_without_tags():
+    assert SourceTags.parse("T", None).global_tags_xml is None
+    assert SourceTags.parse("T", "not xml").global_tags_xml is None
+    only_track = SourceTags.parse(
+        "", "<Tags><Tag><Targets><TrackUID>1</TrackUID></Targets></Tag></Tags>"
+    )
+    assert only_track.global_tags_xml is None
+    assert only_track.track_tag_count == 1
+
+
+def test_title_per_policy():
+    source = SourceTags("Original Title")
+    assert TagPolicy("keep").title(source, {}) == "Original Title"
+    assert TagPolicy("strip_except_title").title(source, {}) == "Original Title"
+    assert TagPolicy("strip_all").title(source, {}) is None
+    assert TagPolicy("keep").title(SourceTags(), {}) is None
+
+
+def test_output_title_overrides_policy():
+    source = SourceTags("Original")
+    fields = {"title": "Show", "episode": "05"}
+    policy = TagPolicy("strip_all", "{title} - {episode} ({source1_title})")
+    assert policy.title(source, fields) == "Show - 05 (Original)"
+    # Empty fields leave no empty brackets behind
+    assert policy.title(SourceTags(), fields) == "Show - 05"
+
+
+def test_unknown_placeholder_is_logged():
+    logs = []
+    title = TagPolicy("keep", "{title} {nope_tag}").title(
+        SourceTags(), {"title": "Show"}, logs.append
+    )
+    assert title == "Show {nope_tag}"
+    assert any("{nope_tag}" in line for line in logs)
+
+
+def test_from_settings():
+    settings = AppSettings(tag_policy="keep", output_title="  {title}  ")
+    assert TagPolicy.from_settings(settings) == TagPolicy("keep", "{title}")
+    assert TagPolicy.from_settings(AppSettings()).mode == "strip_all"
+
+
+def _tokens(**plan_fields) -> list[str]:
+    track = Track(
+        source="Source 1",
+        id=0,
+        type="video",
+        props=StreamProps(codec_id="V_MPEG4/ISO/AVC"),
+    )
+    plan = MergePlan(
+        items=[PlanItem(track=track, extracted_path=Path("/tmp/v.h264"))],
+        delays=Delays(),
+        **plan_fields,
+    )
+    return MkvmergeOptionsBuilder().build(plan, AppSettings())
+
+
+def test_tokens_strip_inputs():
+    tokens = _tokens(strip_input_tags=True)
+    i = tokens.index("(")
+    assert tokens[i - 2 : i] == ["--no-global-tags", "--no-track-tags"]
+    assert "--title" not in tokens
+    assert "--global-tags" not in tokens
+
+
+def test_tokens_title_and_global_tags():
+    tokens = _tokens(title="Show - 05", global_tags_xml=Path("/tmp/tags.xml"))
+    assert tokens[tokens.index("--title") + 1] == "Show - 05"
+    assert tokens[tokens.index("--global-tags") + 1] == "/tmp/tags.xml"
+    assert "--no-global-tags" not in tokens
+
+
+def test_describe_reports_kept_and_stripped():
+    source = SourceTags.parse("Original", _TAGS_XML)
+    keep = " ".join(TagPolicy("keep").describe(source))
+    assert "Keeping Source 1's global tags" in keep
+    assert "Track tags are not carried" in keep
+    strip = " ".join(TagPolicy("strip_all").describe(source))
+    assert "Stripping tags" in strip
+    assert "Stripping the title" in strip
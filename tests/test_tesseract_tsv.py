@@ -0,0 +1,76 @@
+# tests/test_tesseract_tsv.py
+"""
+Tests for reading Tesseract's TSV output
+(vsg_core.subtitles.ocr.vlm_backends.tesseract._parse_tsv).
+
+Validates:
+1. Words are joined per Tesseract line, lines by newlines
+2. Confidence is the mean over words; -1 rows (non-words) are ignored
+3. Output without words gives empty text and no confidence
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")
+pytest.importorskip("PIL")
+
+from vsg_core.subtitles.ocr.vlm_backends.tesseract import _parse_tsv  # noqa: E402
+
+_HEADER = (
+    "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\t"
+    "left\ttop\twidth\theight\tconf\ttext"
+)
+
+# ``tesseract crop.png - --psm 6 tsv`` on a two-line subtitle
+_TWO_LINES = "\n".join(
+    [
+        _HEADER,
+        "1\t1\t0\t0\t0\t0\t0\t0\t960\t180\t-1\t",
+        "2\t1\t1\t0\t0\t0\t30\t30\t900\t120\t-1\t",
+        "3\t1\t1\t1\t0\t0\t30\t30\t900\t120\t-1\t",
+        "4\t1\t1\t1\t1\t0\t30\t30\t600\t50\t-1\t",
+        "5\t1\t1\t1\t1\t1\t30\t30\t120\t50\t96.5\tWhere",
+        "5\t1\t1\t1\t1\t2\t160\t30\t80\t50\t91.0\tare",
+        "5\t1\t1\t1\t1\t3\t250\t30\t90\t50\t89.5\tyou?",
+        "4\t1\t1\t1\t2\t0\t30\t100\t500\t50\t-1\t",
+        "5\t1\t1\t1\t2\t1\t30\t100\t100\t50\t71.0\tRight",
+        "5\t1\t1\t1\t2\t2\t140\t100\t100\t50\t92.0\there.",
+        "",
+    ]
+)
+
+
+def test_words_are_grouped_by_line():
+    text, _ = _parse_tsv(_TWO_LINES)
+    assert text == "Where are you?\nRight here."
+
+
+def test_confidence_is_mean_of_words():
+    _, conf = _parse_tsv(_TWO_LINES)
+    assert conf == pytest.approx((96.5 + 91.0 + 89.5 + 71.0 + 92.0) / 5)
+
+
+def test_blank_words_and_bad_confidence_are_skipped():
+    tsv = "\n".join(
+        [
+            _HEADER,
+            "5\t1\t1\t1\t1\t1\t30\t30\t120\t50\t95\tHi",
+            "5\t1\t1\t1\t1\t2\t160\t30\t10\t50\t-1\t ",
+            "5\t1\t1\t1\t1\t3\t180\t30\t60\t50\tnan?\tthere",
+        ]
+    )
+    text, conf = _parse_tsv(tsv)
+    assert text == "Hi there"
+    assert conf == pytest.approx(95.0)
+
+
+def test_no_words():
+    tsv = _HEADER + "\n1\t1\t0\t0\t0\t0\t0\t0\t960\t180\t-1\t\n"
+    assert _parse_tsv(tsv) == ("", None)
+    assert _parse_tsv("") == ("", None)
@@ -0,0 +1,124 @@
+# tests/test_track_consensus.py
+"""
+Tests for the multi-track delay consensus
+(vsg_core.analysis.track_consensus).
+
+Validates:
+1. majority_ms picks the delay with the most confidence within the
+   threshold of it; a tie goes to the first (selected) track
+2. included / excluded split the tracks around that majority, and failed
+   tracks are always excluded
+3. consensus_ms is the confidence-weighted mean of the included delays,
+   or the plain mean when none of them has any confidence
+4. has_majority needs a strict majority of the confidence (of the track
+   count when no track has any)
+
+Needs numpy (the analysis package).
+"""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")
+
+from vsg_core.analysis.track_consensus import (  # noqa: E402
+    TrackAgreement,
+    TrackConsensusReport,
+)
+
+
+def _report(*tracks: tuple[float | None, float], threshold_ms: float = 20.0):
+    """Report of (delay_ms, confidence) tracks; the first is the selected one."""
+    return TrackConsensusReport(
+        source_key="Source 2",
+        threshold_ms=threshold_ms,
+        tracks=[
+            TrackAgreement(i, f"Track {i}", delay, conf, 10, 10)
+            for i, (delay, conf) in enumerate(tracks)
+        ],
+    )
+
+
+def _indexes(pairs) -> list[int]:
+    return [t.track_index for t, _ in pairs]
+
+
+def test_agreeing_tracks():
+    report = _report((100.0, 90.0), (104.0, 60.0), (98.0, 30.0))
+    assert report.majority_ms == 100.0
+    assert _indexes(report.included) == [0, 1, 2]
+    assert report.excluded == []
+    expected = (100.0 * 90 + 104.0 * 60 + 98.0 * 30) / 180
+    assert report.consensus_ms == pytest.approx(expected)
+    assert report.has_majority
+
+
+def test_different_content_is_outvoted():
+    # Stereo and 5.1 agree; the commentary track lands far away
+    report = _report((100.0, 80.0), (102.0, 80.0), (640.0, 95.0))
+    assert report.majority_ms == 100.0
+    assert _indexes(report.included) == [0, 1]
+    assert [t.track_index for t in report.excluded] == [2]
+    assert report.consensus_ms == pytest.approx(101.0)
+    assert report.has_majority
+
+
+def test_majority_anchor_prefers_the_more_confident_track():
+    # Same support from both anchors: the more confident one is the majority
+    report = _report((100.0, 40.0), (110.0, 60.0))
+    assert report.majority_ms == 110.0
+    assert _indexes(report.included) == [0, 1]
+
+
+def test_tie_keeps_the_selected_track_without_a_majority():
+    report = _report((100.0, 70.0), (400.0, 70.0))
+    assert report.majority_ms == 100.0  # First of equals
+    assert _indexes(report.included) == [0]
+    assert not report.has_majority
+
+
+def test_half_of_the_weight_is_not_a_majority():
+    report = _report((100.0, 50.0), (400.0, 30.0), (700.0, 20.0))
+    assert _indexes(report.included) == [0]
+    assert not report.has_majority
+
+
+def test_selected_track_can_be_excluded():
+    report = _report((400.0, 60.0), (100.0, 70.0), (101.0, 70.0))
+    assert report.majority_ms in (100.0, 101.0)
+    assert _indexes(report.included) == [1, 2]
+    assert [t.track_index for t in report.excluded] == [0]
+    assert report.has_majority
+
+
+def test_failed_tracks_are_excluded():
+    report = _report((100.0, 80.0), (None, 0.0))
+    assert _indexes(report.included) == [0]
+    assert [t.track_index for t in report.excluded] == [1]
+    assert report.consensus_ms == 100.0
+    assert report.has_majority
+
+
+def test_zero_weight_uses_plain_mean_and_count_majority():
+    report = _report((100.0, 0.0), (104.0, 0.0), (500.0, 0.0))
+    assert _indexes(report.included) == [0, 1]
+    assert report.consensus_ms == pytest.approx(102.0)
+    assert report.has_majority
+
+    split = _report((100.0, 0.0), (500.0, 0.0))
+    assert _indexes(split.included) == [0]
+    assert not split.has_majority
+
+
+def test_nothing_measured():
+    report = _report((None, 0.0), (None, 0.0))
+    assert report.majority_ms is None
+    assert report.included == []
+    assert report.consensus_ms is None
+    assert not report.has_majority
+    assert report.to_dict()["consensus_ms"] is None
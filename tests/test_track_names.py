@@ -0,0 +1,76 @@
+# tests/test_track_names.py
+"""
+Tests for generated track names (vsg_core.mux.track_names).
+
+Validates:
+1. Common audio codec/language/channel combinations
+2. Subtitle tracks get language + format (no channel layout)
+3. Unknown/undetermined parts are left out
+4. Codec and channel maps can be overridden per call
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.media import StreamProps, Track
+from vsg_core.mux.track_names import suggest_track_name
+
+
+def _track(type_, codec_id, lang="und", channels=None):
+    return Track(
+        source="Source 1",
+        id=1,
+        type=type_,
+        props=StreamProps(codec_id=codec_id, lang=lang, audio_channels=channels),
+    )
+
+
+def test_common_audio_tracks():
+    cases = [
+        (("A_DTS/LOSSLESS", "eng", 6), "English DTS-HD MA 5.1"),
+        (("A_TRUEHD", "eng", 8), "English TrueHD 7.1"),
+        (("A_AC3", "jpn", 2), "Japanese AC-3 Stereo"),
+        (("A_EAC3", "ger", 6), "German E-AC-3 5.1"),
+        (("A_FLAC", "jpn", 1), "Japanese FLAC Mono"),
+        (("A_AAC", "spa", 2), "Spanish AAC Stereo"),
+        (("A_OPUS", "fre", 6), "French Opus 5.1"),
+    ]
+    for (codec, lang, channels), expected in cases:
+        assert suggest_track_name(_track("audio", codec, lang, channels)) == expected
+
+
+def test_unmapped_channel_count_falls_back_to_count():
+    assert suggest_track_name(_track("audio", "A_AC3", "eng", 4)) == "English AC-3 4ch"
+
+
+def test_subtitle_tracks():
+    ass = _track("subtitles", "S_TEXT/ASS", "eng")
+    pgs = _track("subtitles", "S_HDMV/PGS", "jpn")
+    assert suggest_track_name(ass) == "English ASS"
+    assert suggest_track_name(pgs) == "Japanese PGS"
+    assert suggest_track_name(_track("subtitles", "S_TEXT/UTF8", "pt-BR")) == (
+        "Portuguese SRT"
+    )
+
+
+def test_unknown_parts_are_omitted():
+    assert suggest_track_name(_track("audio", "A_AC3", "und", 6)) == "AC-3 5.1"
+    assert suggest_track_name(_track("audio", "A_UNKNOWN", "und")) == ""
+    assert suggest_track_name(_track("audio", "A_PCM/INT/LIT", "xho", 2)) == (
+        "xho PCM Stereo"
+    )
+
+
+def test_maps_are_overridable():
+    track = _track("audio", "A_AC3", "eng", 6)
+    name = suggest_track_name(
+        track,
+        codec_names={"A_AC3": "Dolby Digital"},
+        channel_layouts={6: "5.1 Surround"},
+    )
+    assert name == "English Dolby Digital 5.1 Surround"
+    # Overrides don't leak into later calls
+    assert suggest_track_name(track) == "English AC-3 5.1"
@@ -0,0 +1,81 @@
+# tests/test_video_verified_checkpoints.py
+"""
+Tests for the per-checkpoint VideoVerified result
+(vsg_core.subtitles.sync_mode_plugins.video_verified.checkpoints).
+
+Validates:
+1. Confirmed / disagreeing / skipped checkpoints and the strip
+2. Hamming distance for hash backends, SSIM label for SSIM
+3. The report survives a JSON round trip through the details dict
+4. Fallback details without checkpoints give no report
+"""
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+pytest.importorskip("numpy")  # the video_verified package imports it
+
+from vsg_core.subtitles.sync_mode_plugins.video_verified import (  # noqa: E402
+    CheckpointReport,
+    CheckpointResult,
+    checkpoint_report,
+)
+
+
+def _report() -> CheckpointReport:
+    return CheckpointReport(
+        backend="phash",
+        applied_offset_frames=3,
+        audio_offset_frames=2,
+        checkpoints=(
+            CheckpointResult(14.4, 1000, 1002, 1003, 0.98, 230, 240, confirmed=True),
+            CheckpointResult(23.3, 2000, 2002, 2003, 0.97, 228, 240, confirmed=True),
+            CheckpointResult(32.2, 3000, 3002, 3010, 0.61, 90, 240),
+            CheckpointResult(41.1, 4000, 4002, None, skip_reason="edge"),
+        ),
+    )
+
+
+def test_counts_and_strip():
+    report = _report()
+    assert report.strip() == "✓✓✗·"
+    assert report.confirmed_count == 2
+    assert len(report.tested) == 3
+    assert not report.all_confirmed
+    assert report.describe() == "2/3 checkpoints confirmed +3f [✓✓✗·]"
+
+
+def test_checkpoint_offsets():
+    confirmed, _, disagreeing, skipped = _report().checkpoints
+    assert confirmed.offset_from_audio == 1
+    assert disagreeing.offset_from_audio == 8
+    assert skipped.skipped
+    assert skipped.offset_from_audio is None
+    assert "skipped (edge)" in skipped.describe()
+    assert "disagrees" in disagreeing.describe()
+
+
+def test_metric_per_backend():
+    checkpoint = _report().checkpoints[0]
+    assert checkpoint.hamming("phash") == pytest.approx(0.01)
+    assert "hamming=0.010" in checkpoint.describe("phash")
+    assert checkpoint.hamming("isc") is None
+    assert "SSIM=0.9800" in checkpoint.describe("ssim")
+
+
+def test_json_round_trip():
+    report = _report()
+    details = json.loads(json.dumps({"checkpoints": report.to_dict()}))
+    assert checkpoint_report(details) == report
+
+
+def test_no_report_for_fallbacks():
+    assert checkpoint_report(None) is None
+    assert checkpoint_report({"reason": "skipped-VFR-content"}) is None
+    assert checkpoint_report({"checkpoints": {"backend": "isc"}}) is None
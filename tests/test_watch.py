@@ -0,0 +1,155 @@
+# tests/test_watch.py
+"""
+Tests for the watch mode loop (vsg_core.watch).
+
+Validates:
+1. A job runs only once every folder has its file and the files have
+   stopped changing for the settle time
+2. Finished jobs are not run again after a restart; interrupted ones are
+3. Files whose output already exists are skipped, looked up under the
+   name the job's output gets (e.g. from output_template)
+"""
+
+import sys
+from pathlib import Path
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+from vsg_core.models.jobs import PipelineResult  # noqa: E402
+from vsg_core.mux.output_name import (  # noqa: E402
+    output_name_fields,
+    render_output_name,
+)
+from vsg_core.watch import STATE_FILENAME, Watcher, WatchState, job_key  # noqa: E402
+
+
+class _Clock:
+    def __init__(self):
+        self.now = 0.0
+
+    def __call__(self):
+        return self.now
+
+
+def _setup(tmp_path):
+    folders = {"Source 1": tmp_path / "a", "Source 2": tmp_path / "b"}
+    for folder in folders.values():
+        folder.mkdir()
+    return {k: str(v) for k, v in folders.items()}, tmp_path / "out"
+
+
+def _watcher(folders, output, clock, ran, output_name=None):
+    def run_job(job):
+        ran.append(job["sources"]["Source 1"])
+        return PipelineResult(status="Merged", name=Path(ran[-1]).name)
+
+    return Watcher(
+        folders,
+        str(output),
+        run_job,
+        lambda msg: None,
+        settle_s=5,
+        clock=clock,
+        output_name=output_name,
+    )
+
+
+def test_waits_for_pair_and_settled_files(tmp_path):
+    folders, output = _setup(tmp_path)
+    clock, ran = _Clock(), []
+    watcher = _watcher(folders, output, clock, ran)
+
+    (Path(folders["Source 1"]) / "ep01.mkv").write_bytes(b"x" * 10)
+    assert watcher.run_pending() is False  # No Source 2 file yet
+    (Path(folders["Source 2"]) / "ep01.mkv").write_bytes(b"y" * 10)
+    assert watcher.run_pending() is True  # First sighting
+    clock.now = 3
+    assert watcher.run_pending() is True  # Not settled yet
+    assert ran == []
+
+    clock.now = 6
+    assert watcher.run_pending() is False
+    assert [Path(p).name for p in ran] == ["ep01.mkv"]
+    clock.now = 20
+    watcher.run_pending()
+    assert len(ran) == 1  # Not run twice
+
+
+def test_growing_file_restarts_settle_time(tmp_path):
+    folders, output = _setup(tmp_path)
+    clock, ran = _Clock(), []
+    watcher = _watcher(folders, output, clock, ran)
+    ref = Path(folders["Source 1"]) / "ep01.mkv"
+    ref.write_bytes(b"x")
+    (Path(folders["Source 2"]) / "ep01.mkv").write_bytes(b"y")
+
+    watcher.run_pending()
+    clock.now = 4
+    ref.write_bytes(b"x" * 100)  # Still being copied
+    watcher.run_pending()
+    clock.now = 8
+    watcher.run_pending()
+    assert ran == []
+    clock.now = 10
+    watcher.run_pending()
+    assert len(ran) == 1
+
+
+def test_restart_skips_finished_and_reruns_interrupted(tmp_path):
+    folders, output = _setup(tmp_path)
+    for name in ("ep01.mkv", "ep02.mkv"):
+        (Path(folders["Source 1"]) / name).write_bytes(b"x")
+        (Path(folders["Source 2"]) / name).write_bytes(b"y")
+    sources = lambda name: {k: str(Path(v) / name) for k, v in folders.items()}
+    state = WatchState(output / STATE_FILENAME, lambda msg: None)
+    state.record(job_key(sources("ep01.mkv")), "Failed")
+    state.record(job_key(sources("ep02.mkv")), WatchState.RUNNING)
+
+    clock, ran = _Clock(), []
+    watcher = _watcher(folders, output, clock, ran)
+    watcher.run_pending()
+    clock.now = 6
+    watcher.run_pending()
+
+    assert [Path(p).name for p in ran] == ["ep02.mkv"]
+    reloaded = WatchState(output / STATE_FILENAME, lambda msg: None)
+    assert reloaded.status(job_key(sources("ep02.mkv"))) == "Merged"
+
+
+def test_existing_output_is_skipped(tmp_path):
+    folders, output = _setup(tmp_path)
+    (Path(folders["Source 1"]) / "ep01.mkv").write_bytes(b"x")
+    (Path(folders["Source 2"]) / "ep01.mkv").write_bytes(b"y")
+    output.mkdir()
+    (output / "ep01.mkv").write_bytes(b"done")
+
+    clock, ran = _Clock(), []
+    watcher = _watcher(folders, output, clock, ran)
+    watcher.run_pending()
+    clock.now = 6
+    watcher.run_pending()
+
+    assert ran == []
+
+
+def test_existing_output_is_found_under_the_template_name(tmp_path):
+    folders, output = _setup(tmp_path)
+    for name in ("Show - 01.mkv", "Show - 02.mkv"):
+        (Path(folders["Source 1"]) / name).write_bytes(b"x")
+        (Path(folders["Source 2"]) / name).write_bytes(b"y")
+    output.mkdir()
+    (output / "Show - 01 [Synced].mkv").write_bytes(b"done")
+    (output / "Show - 02.mkv").write_bytes(b"someone else's file")
+
+    def output_name(sources):
+        fields = output_name_fields(sources)
+        return render_output_name("{title} - {episode} [Synced].mkv", fields)
+
+    clock, ran = _Clock(), []
+    watcher = _watcher(folders, output, clock, ran, output_name)
+    watcher.run_pending()
+    clock.now = 6
+    watcher.run_pending()
+
+    assert [Path(p).name for p in ran] == ["Show - 02.mkv"]
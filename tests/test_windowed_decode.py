@@ -0,0 +1,134 @@
+# tests/test_windowed_decode.py
+"""
+Seek-based window decoding vs full decoding.
+
+Validates:
+1. Windows of a 48 kHz file match the full decode sample for sample
+2. With resampling (44.1 kHz source) the offset stays below one sample
+3. Windows near the file start (guard clamped at 0) still line up
+4. The full-track slice interface used by the dense runner
+
+Needs ffmpeg on PATH and numpy; skipped otherwise.
+"""
+
+import shutil
+import subprocess
+import sys
+from pathlib import Path
+
+import pytest
+
+PROJECT_ROOT = Path(__file__).parent.parent
+sys.path.insert(0, str(PROJECT_ROOT))
+
+np = pytest.importorskip("numpy")
+
+if shutil.which("ffmpeg") is None or shutil.which("ffprobe") is None:
+    pytest.skip("ffmpeg/ffprobe not available", allow_module_level=True)
+
+from vsg_core.analysis.correlation.decode import (  # noqa: E402
+    decode_audio,
+    probe_audio_timing,
+)
+from vsg_core.analysis.correlation.dense import window_positions  # noqa: E402
+from vsg_core.analysis.correlation.windowed import decode_windows  # noqa: E402
+
+SR = 48000
+WINDOW_S = 2.0
+HOP_S = 7.0
+
+
+class _Runner:
+    """Minimal CommandRunner stand-in."""
+
+    def _log_message(self, message: str) -> None:
+        pass
+
+    def run(self, cmd, tool_paths, is_binary=False, input_data=None):
+        proc = subprocess.run(cmd, capture_output=True, check=False)
+        if proc.returncode != 0:
+            return None
+        return proc.stdout if is_binary else proc.stdout.decode()
+
+
+def _make_file(tmp_path: Path, rate: int, ext: str, codec: str) -> str:
+    out = tmp_path / f"noise_{rate}.{ext}"
+    subprocess.run(
+        [
+            "ffmpeg",
+            "-v",
+            "error",
+            "-f",
+            "lavfi",
+            "-i",
+            f"anoisesrc=d=30:c=pink:r={rate}:seed=7",
+            "-c:a",
+            codec,
+            str(out),
+        ],
+        check=True,
+    )
+    return str(out)
+
+
+def _lag(a, b, radius: int = 4) -> float:
+    """Sub-sample lag of ``b`` relative to ``a`` (parabolic peak fit)."""
+    n = len(a) - 2 * radius
+    core = a[radius : radius + n]
+    lags = range(-radius, radius + 1)
+    corr = np.array([np.dot(core, b[radius + k : radius + k + n]) for k in lags])
+    i = int(np.argmax(corr))
+    assert 0 < i < len(corr) - 1, "correlation peak at the edge of the search"
+    y0, y1, y2 = corr[i - 1], corr[i], corr[i + 1]
+    return (i - radius) + 0.5 * (y0 - y2) / (y0 - 2 * y1 + y2)
+
+
+def _decode_both(path: str):
+    runner = _Runner()
+    full = decode_audio(path, 0, SR, False, runner, {})
+    timing = probe_audio_timing(path, 0, runner, {})
+    assert timing is not None
+    length = int(timing[0] * SR)
+    positions, _, _ = window_positions(length, SR, WINDOW_S, HOP_S, 0.0, 100.0)
+    window = int(WINDOW_S * SR)
+    windowed = decode_windows(
+        path,
+        0,
+        positions,
+        window,
+        length,
+        SR,
+        False,
+        runner,
+        {},
+        start_offset_s=timing[1],
+    )
+    return full, windowed, positions, window
+
+
+def test_native_rate_is_sample_exact(tmp_path):
+    path = _make_file(tmp_path, SR, "mka", "flac")
+    full, windowed, positions, window = _decode_both(path)
+    assert positions[0] == 0  # guard clamped at the file start
+    for pos in positions:
+        np.testing.assert_allclose(
+            windowed[pos : pos + window], full[pos : pos + window], atol=1e-6
+        )
+
+
+def test_resampled_offset_is_sub_sample(tmp_path):
+    path = _make_file(tmp_path, 44100, "wav", "pcm_s16le")
+    full, windowed, positions, window = _decode_both(path)
+    for pos in positions:
+        lag = _lag(full[pos : pos + window], windowed[pos : pos + window])
+        assert abs(lag) < 1.0, f"window at {pos / SR:.1f}s is off by {lag:.3f}"
+
+
+def test_slices_outside_windows_are_rejected(tmp_path):
+    path = _make_file(tmp_path, SR, "wav", "pcm_s16le")
+    _, windowed, positions, window = _decode_both(path)
+    assert len(windowed) >= positions[-1] + window
+    with pytest.raises(KeyError):
+        windowed[positions[0] + 1 : positions[0] + 1 + window]
+    with pytest.raises(KeyError):
+        windowed[positions[0] : positions[0] + 2 * window]
@@ -0,0 +1,53 @@
+#!/usr/bin/env python3
+"""
+Diff two runs of analysis reports (``{job}_analysis_report.json``).
+
+Point it at the folder (or single report) of a previous run and of the
+current one to see which sources' delays, confidence or accepted chunks
+changed, e.g. after tweaking analysis settings and re-analysing a season.
+Sources whose delay moved by more than the threshold, or that are missing
+from the current run, are flagged. Reads only; nothing is re-muxed.
+
+Usage:
+    python3 tools/report_diff.py PREVIOUS CURRENT [--threshold MS] [--json OUT]
+
+Exits 1 when any source is flagged, so it can gate a batch re-mux.
+"""
+
+from __future__ import annotations
+
+import argparse
+import sys
+from pathlib import Path
+
+sys.path.insert(0, str(Path(__file__).resolve().parent.parent))
+
+from vsg_core.analysis.report_diff import diff_reports, load_report_set
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[1])
+    parser.add_argument("previous", type=Path)
+    parser.add_argument("current", type=Path)
+    parser.add_argument(
+        "--threshold",
+        type=float,
+        default=1.0,
+        help="flag sources whose delay moved by more than this (ms)",
+    )
+    parser.add_argument("--json", type=Path, help="also write the diff as JSON")
+    args = parser.parse_args()
+
+    diff = diff_reports(
+        load_report_set(args.previous),
+        load_report_set(args.current),
+        threshold_ms=args.threshold,
+    )
+    print(diff.to_text())
+    if args.json:
+        diff.to_json(args.json)
+    return 1 if diff.flagged else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
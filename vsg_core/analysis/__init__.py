@@ -1,4 +1,5 @@
 # vsg_core/analysis/__init__.py
+from .av_offset import AvOffset, measure_av_offset
 from .container_delays import (
     calculate_delay_chain,
     find_actual_correlation_track_delay,
@@ -14,6 +15,8 @@ from .correlation import (
 from .delay_selection import calculate_delay, find_first_stable_segment_delay
 from .drift_detection import diagnose_audio_issue
 from .global_shift import apply_global_shift_to_delays, calculate_global_shift
+from .pair import PairDelay, correlate_pair
+from .report_diff import ReportDiff, diff_reports
 from .source_separation import (
     SEPARATION_MODES,
     is_audio_separator_available,
@@ -28,6 +31,7 @@ from .types import (
     DelayCalculation,
     GlobalShiftCalculation,
     QualityThresholds,
+    RejectReason,
     TrackSelection,
     ValidationCheck,
 )
@@ -35,6 +39,7 @@ from .videodiff import VideoDiffResult, run_native_videodiff, run_videodiff
 
 __all__ = [
     "SEPARATION_MODES",
+    "AvOffset",
     "ChunkResult",
     "ClusterDiagnostic",
     "ClusterValidation",
@@ -42,7 +47,10 @@ __all__ = [
     "CorrelationMethod",
     "DelayCalculation",
     "GlobalShiftCalculation",
+    "PairDelay",
     "QualityThresholds",
+    "RejectReason",
+    "ReportDiff",
     "TrackSelection",
     "ValidationCheck",
     "VideoDiffResult",
@@ -50,8 +58,10 @@ __all__ = [
     "calculate_delay",
     "calculate_delay_chain",
     "calculate_global_shift",
+    "correlate_pair",
     "decode_audio",
     "diagnose_audio_issue",
+    "diff_reports",
     "find_actual_correlation_track_delay",
     "find_first_stable_segment_delay",
     "format_track_details",
@@ -61,6 +71,7 @@ __all__ = [
     "is_audio_separator_available",
     "list_available_models",
     "list_methods",
+    "measure_av_offset",
     "run_native_videodiff",
     "run_videodiff",
     "select_audio_track",
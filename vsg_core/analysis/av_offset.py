@@ -0,0 +1,323 @@
+# vsg_core/analysis/av_offset.py
+"""
+Audio/video sync within one file (A/V offset).
+
+Cross-source analysis assumes each source's own audio matches its video;
+a reference whose audio is already off drags every other source with it.
+``measure_av_offset`` checks a single file by lining scene cuts in the
+video up with sound onsets in the audio: a cut usually comes with a new
+shot's sound (a door, a line of dialogue, a music hit), so over hundreds
+of cuts the audio onsets pile up at a fixed distance from the cuts. That
+distance is the A/V offset.
+
+    video  downscaled luma (64x36) at the video frame rate; the mean
+           absolute change between consecutive frames, with a cut wherever
+           it jumps well above its local median
+    audio  log energy per video frame, differenced and half-wave rectified
+           (an onset envelope on the video's frame grid), z-normalised
+    score  for each lag within ``max_offset_ms``, the mean audio onset at
+           cut + lag; the best lag is refined to a fraction of a frame
+           with a parabola through its neighbours
+
+Sign convention:
+
+    offset_ms > 0  audio lags the video (sound comes after the picture);
+                   the audio must be moved earlier by ``offset_ms``.
+    offset_ms < 0  audio leads the video.
+
+Both streams' start times are taken into account, so a container delay
+on the audio shows up in the offset.
+
+Limitations. The measurement only works when cuts and sounds are
+correlated: edited live action, anime and series with dialogue and
+effects on the cuts. Long takes, music videos cut off the beat, concerts,
+static slides and content with a continuous score give few or unrelated
+onsets, and the result is noise. Resolution is one video frame before
+refinement (about 42ms at 23.976fps); treat anything within a frame of
+zero as in sync. Only the first ``duration_s`` seconds are measured.
+
+Confidence. ``psr`` is the peak-to-sidelobe ratio of the score over all
+lags; ``reliable`` needs ``MIN_AV_PSR`` and at least ``MIN_CUTS`` cuts.
+An unreliable result is still returned with its offset (so it can be
+logged and compared), but it should not be acted on; a file with fewer
+than two cuts raises instead.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+import numpy as np
+
+from .correlation.decode import (
+    decode_window,
+    get_audio_stream_info,
+    normalize_lang,
+    resolve_downmix,
+)
+from .correlation.peak_interp import refine_peak
+
+if TYPE_CHECKING:
+    from ..io.runner import CommandRunner
+    from ..models.settings import AppSettings
+
+AV_SR = 16000
+DEFAULT_AV_DURATION_S = 900.0
+DEFAULT_MAX_AV_OFFSET_MS = 1000.0
+# Peak-to-sidelobe ratio and cut count below which the offset is not trusted
+MIN_AV_PSR = 5.0
+MIN_CUTS = 20
+
+_FRAME_W, _FRAME_H = 64, 36
+# A cut is a frame change this many times the local median change ...
+_CUT_RATIO = 4.0
+# ... and at least this large (mean luma levels), so noise in static
+# shots doesn't count
+_MIN_CUT_CHANGE = 6.0
+_MEDIAN_FRAMES = 25
+# Lags this close to the peak are part of it, not sidelobes
+_PEAK_HALF_WIDTH = 2
+
+
+@dataclass(frozen=True, slots=True)
+class AvOffset:
+    """A/V offset of one file (see module docstring for the sign)."""
+
+    offset_ms: float
+    psr: float  # Peak-to-sidelobe ratio of the cut/onset score
+    cuts: int  # Scene cuts the score was built from
+    fps: float
+    max_offset_ms: float
+
+    @property
+    def reliable(self) -> bool:
+        return self.psr >= MIN_AV_PSR and self.cuts >= MIN_CUTS
+
+    def describe(self) -> str:
+        if abs(self.offset_ms) < 0.5:
+            where = "in sync with the video"
+        elif self.offset_ms > 0:
+            where = f"lags the video by {self.offset_ms:.1f}ms"
+        else:
+            where = f"leads the video by {-self.offset_ms:.1f}ms"
+        trust = "" if self.reliable else " - low confidence, not usable"
+        return (
+            f"Audio {where} (PSR {self.psr:.1f}, {self.cuts} cuts, "
+            f"±{1000.0 / self.fps:.0f}ms per frame){trust}"
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "offset_ms": round(self.offset_ms, 3),
+            "psr": round(self.psr, 2),
+            "cuts": self.cuts,
+            "fps": round(self.fps, 6),
+            "max_offset_ms": self.max_offset_ms,
+            "reliable": self.reliable,
+        }
+
+
+def detect_cuts(luma_diff: np.ndarray) -> np.ndarray:
+    """Indices into ``luma_diff`` (change from frame i to i + 1) that are cuts."""
+    diff = np.asarray(luma_diff, dtype=np.float64)
+    if len(diff) < _MEDIAN_FRAMES:
+        return np.zeros(0, dtype=np.int64)
+    half = _MEDIAN_FRAMES // 2
+    padded = np.pad(diff, half, mode="edge")
+    windows = np.lib.stride_tricks.sliding_window_view(padded, _MEDIAN_FRAMES)
+    local = np.median(windows, axis=1)
+    is_cut = (diff > _CUT_RATIO * local) & (diff >= _MIN_CUT_CHANGE)
+    return np.flatnonzero(is_cut)
+
+
+def audio_onsets(pcm: np.ndarray, sr: int, fps: float, n_frames: int) -> np.ndarray:
+    """
+    Onset envelope on the video frame grid: entry i is the energy rise from
+    frame i to frame i + 1 (so it lines up with ``luma_diff``).
+    """
+    bounds = np.round(np.arange(n_frames + 1) * sr / fps).astype(np.int64)
+    bounds = bounds[bounds <= len(pcm)]
+    if len(bounds) < 3:
+        return np.zeros(0, dtype=np.float64)
+    power = np.asarray(pcm, dtype=np.float64) ** 2
+    sums = np.add.reduceat(power, bounds[:-1])
+    energy = sums / np.maximum(np.diff(bounds), 1)
+    onset = np.maximum(np.diff(np.log10(energy + 1e-10)), 0.0)
+    onset -= onset.mean()
+    std = onset.std()
+    return onset / std if std > 0 else onset
+
+
+def av_offset_from_signals(
+    luma_diff: np.ndarray,
+    onsets: np.ndarray,
+    fps: float,
+    max_offset_ms: float = DEFAULT_MAX_AV_OFFSET_MS,
+    start_offset_ms: float = 0.0,
+) -> AvOffset:
+    """
+    A/V offset from a video change signal and an audio onset envelope on
+    the same frame grid. ``start_offset_ms`` is the audio stream's start
+    minus the video stream's (added to the result).
+
+    Raises ValueError when the video has fewer than two cuts.
+    """
+    cuts = detect_cuts(luma_diff)
+    if len(cuts) < 2:
+        raise ValueError(
+            f"Only {len(cuts)} scene cut(s) found; the A/V offset can't be measured"
+        )
+    frame_ms = 1000.0 / fps
+    max_lag = max(_PEAK_HALF_WIDTH + 2, int(max_offset_ms / frame_ms))
+    lags = np.arange(-max_lag, max_lag + 1)
+    scores = np.empty(len(lags), dtype=np.float64)
+    for i, lag in enumerate(lags):
+        at = cuts + lag
+        at = at[(at >= 0) & (at < len(onsets))]
+        scores[i] = onsets[at].mean() if len(at) else 0.0
+
+    peak = int(np.argmax(scores))
+    near = np.arange(
+        max(0, peak - _PEAK_HALF_WIDTH), min(len(scores), peak + _PEAK_HALF_WIDTH + 1)
+    )
+    sidelobes = np.delete(scores, near)
+    if len(sidelobes) < 2 or sidelobes.std() == 0:
+        psr = 0.0
+    else:
+        psr = float((scores[peak] - sidelobes.mean()) / sidelobes.std())
+    lag = lags[peak] + refine_peak(scores.tolist(), peak, "quadratic")
+
+    return AvOffset(
+        offset_ms=float(lag * frame_ms + start_offset_ms),
+        psr=psr,
+        cuts=len(cuts),
+        fps=fps,
+        max_offset_ms=max_offset_ms,
+    )
+
+
+def _probe_streams(
+    path: str, audio_index: int, runner: CommandRunner, tool_paths: dict
+) -> tuple[float, float]:
+    """(video fps, audio start - video start in ms) of ``path``."""
+    out = runner.run(
+        [
+            "ffprobe",
+            "-v",
+            "error",
+            "-show_entries",
+            "stream=codec_type,start_time,avg_frame_rate,r_frame_rate",
+            "-of",
+            "json",
+            str(path),
+        ],
+        tool_paths,
+    )
+    try:
+        streams = json.loads(out or "")["streams"]
+        video = next(s for s in streams if s.get("codec_type") == "video")
+        audio = [s for s in streams if s.get("codec_type") == "audio"][audio_index]
+        num, den = (video.get("avg_frame_rate") or video["r_frame_rate"]).split("/")
+        fps = int(num) / int(den)
+        start_ms = (
+            float(audio.get("start_time") or 0) - float(video.get("start_time") or 0)
+        ) * 1000.0
+    except (ValueError, KeyError, IndexError, StopIteration, ZeroDivisionError) as e:
+        raise RuntimeError(
+            f"Could not read the video/audio streams of {Path(path).name}: {e}"
+        ) from e
+    if fps <= 0:
+        raise RuntimeError(f"{Path(path).name} has no usable video frame rate")
+    return fps, start_ms
+
+
+def _decode_luma_diff(
+    path: str,
+    fps: float,
+    duration_s: float,
+    runner: CommandRunner,
+    tool_paths: dict,
+) -> np.ndarray:
+    """Mean absolute luma change between consecutive downscaled frames."""
+    cmd = [
+        "ffmpeg",
+        "-nostdin",
+        "-v",
+        "error",
+        "-i",
+        str(path),
+        "-map",
+        "0:v:0",
+        "-t",
+        f"{duration_s:.3f}",
+        "-vf",
+        f"fps={fps:.6f},scale={_FRAME_W}:{_FRAME_H},format=gray",
+        "-f",
+        "rawvideo",
+        "-",
+    ]
+    raw = runner.run(cmd, tool_paths, is_binary=True)
+    if not raw or not isinstance(raw, bytes):
+        raise RuntimeError(f"ffmpeg could not decode the video of {Path(path).name}")
+    size = _FRAME_W * _FRAME_H
+    frames = np.frombuffer(raw[: len(raw) // size * size], dtype=np.uint8)
+    frames = frames.reshape(-1, size).astype(np.float32)
+    return np.abs(np.diff(frames, axis=0)).mean(axis=1)
+
+
+def measure_av_offset(
+    path: str,
+    settings: AppSettings,
+    runner: CommandRunner,
+    tool_paths: dict[str, str | None],
+    *,
+    audio_track: int | None = None,
+    duration_s: float = DEFAULT_AV_DURATION_S,
+    max_offset_ms: float = DEFAULT_MAX_AV_OFFSET_MS,
+) -> AvOffset:
+    """
+    A/V offset of the file at ``path`` (see module docstring).
+
+    ``audio_track`` is a 0-based audio stream index; when omitted the
+    stream is picked by Source 1's Analysis Language. Raises RuntimeError
+    when a stream can't be read and ValueError when there are too few cuts.
+    """
+    log = runner._log_message
+    name = Path(path).name
+    if audio_track is None:
+        lang = normalize_lang(settings.analysis_lang_source1)
+        audio_track, _ = get_audio_stream_info(path, lang, runner, tool_paths)
+    if audio_track is None:
+        raise RuntimeError(f"No audio stream found in {name}")
+
+    fps, start_offset_ms = _probe_streams(path, audio_track, runner, tool_paths)
+    log(
+        f"[A/V Offset] {name}: video at {fps:.3f}fps, audio stream {audio_track} "
+        f"starts {start_offset_ms:+.1f}ms from the video"
+    )
+    luma_diff = _decode_luma_diff(path, fps, duration_s, runner, tool_paths)
+    downmix, _ = resolve_downmix(
+        settings.analysis_downmix_mode, path, audio_track, runner, tool_paths
+    )
+    pcm, lead = decode_window(
+        path,
+        audio_track,
+        0.0,
+        duration_s,
+        AV_SR,
+        settings.use_soxr,
+        runner,
+        tool_paths,
+        guard_s=0.0,
+        downmix=downmix,
+    )
+    onsets = audio_onsets(pcm[lead:], AV_SR, fps, len(luma_diff) + 1)
+
+    result = av_offset_from_signals(
+        luma_diff, onsets, fps, max_offset_ms, start_offset_ms
+    )
+    log(f"[A/V Offset] {name}: {result.describe()}")
+    return result
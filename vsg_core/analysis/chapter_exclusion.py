@@ -0,0 +1,90 @@
+# vsg_core/analysis/chapter_exclusion.py
+"""
+Keep correlation windows off intro/outro chapters.
+
+Episodic sources often differ only in their opening and ending (a
+creditless OP on one release, a different ED song on another), and windows
+over them drag the delay votes apart. With ``analysis_exclude_chapters``,
+Source 1's chapters whose names match one of
+``analysis_exclude_chapter_patterns`` (regexes, case-insensitive, searched
+anywhere in the name) are skipped by the window placement
+(``ChunkPlacement.excluded_s``). A chapter runs to its end time, else to
+the next chapter's start, else to the end of the file.
+
+The ranges are in Source 1's timeline and windows sit at the same
+position in both files, so a large delay shifts what is skipped in the
+other source by that much. When no chapter matches (or the file has no
+chapters), the scan range is used as configured.
+"""
+
+from __future__ import annotations
+
+import math
+import re
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+from ..chapters.process import read_chapter_timings
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from ..chapters.validate import ChapterTiming
+    from ..io.runner import CommandRunner
+
+
+@dataclass(frozen=True, slots=True)
+class ExcludedRange:
+    """One chapter skipped by the window placement."""
+
+    name: str
+    start_s: float
+    end_s: float  # math.inf = to the end of the file
+
+    def describe(self) -> str:
+        end = "end" if math.isinf(self.end_s) else f"{self.end_s:.1f}s"
+        return f"'{self.name}' ({self.start_s:.1f}s - {end})"
+
+
+def compile_patterns(patterns: Sequence[str]) -> list[re.Pattern[str]]:
+    """Case-insensitive regexes; raises ValueError on an invalid one."""
+    compiled = []
+    for pattern in patterns:
+        try:
+            compiled.append(re.compile(pattern, re.IGNORECASE))
+        except re.error as e:
+            raise ValueError(f"Invalid chapter pattern {pattern!r}: {e}") from e
+    return compiled
+
+
+def find_excluded_ranges(
+    timings: Sequence[ChapterTiming], patterns: Sequence[str]
+) -> list[ExcludedRange]:
+    """Chapters whose names match any pattern, in time order."""
+    regexes = compile_patterns(patterns)
+    ordered = sorted(timings, key=lambda t: t.start_ns)
+    out = []
+    for i, timing in enumerate(ordered):
+        if not any(r.search(timing.name.strip()) for r in regexes):
+            continue
+        if timing.end_ns is not None and timing.end_ns > timing.start_ns:
+            end_s = timing.end_ns / 1e9
+        elif i + 1 < len(ordered):
+            end_s = ordered[i + 1].start_ns / 1e9
+        else:
+            end_s = math.inf
+        out.append(ExcludedRange(timing.name, timing.start_ns / 1e9, end_s))
+    return out
+
+
+def read_excluded_ranges(
+    ref_file: str,
+    patterns: Sequence[str],
+    runner: CommandRunner,
+    tool_paths: dict,
+) -> list[ExcludedRange]:
+    """The matching chapter ranges of ``ref_file`` ([] without chapters)."""
+    xml_content = runner.run(["mkvextract", str(ref_file), "chapters", "-"], tool_paths)
+    if not xml_content or not xml_content.strip():
+        return []
+    return find_excluded_ranges(read_chapter_timings(xml_content), patterns)
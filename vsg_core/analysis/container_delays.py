@@ -11,6 +11,7 @@ from __future__ import annotations
 from typing import TYPE_CHECKING, Any
 
 from vsg_core.extraction.tracks import get_stream_info_with_delays
+from vsg_core.models.rounding import round_delay_ms
 
 from .types import ContainerDelayInfo
 
@@ -18,6 +19,7 @@ if TYPE_CHECKING:
     from collections.abc import Callable
 
     from vsg_core.io.runner import CommandRunner
+    from vsg_core.models.types import DelayRoundingStr
 
 
 def get_container_delay_info(
@@ -43,7 +45,9 @@ def get_container_delay_info(
     Returns:
         ContainerDelayInfo with video and audio delays, or None if extraction fails
     """
-    stream_info = get_stream_info_with_delays(source_file, runner, tool_paths)
+    stream_info = get_stream_info_with_delays(
+        source_file, runner, tool_paths, ffprobe_fallback=True
+    )
     if not stream_info:
         log(f"[WARN] Could not extract stream info from {source_file}")
         return None
@@ -95,6 +99,7 @@ def calculate_delay_chain(
     container_delay_ms: float,
     log: Callable[[str], None],
     source_key: str,
+    rounding: DelayRoundingStr = "nearest",
 ) -> tuple[int, float]:
     """
     Calculate final delay by combining correlation and container delays.
@@ -105,11 +110,14 @@ def calculate_delay_chain(
         container_delay_ms: Container delay for the audio track
         log: Logging function for messages
         source_key: Source identifier for logging
+        rounding: Whole-ms rounding of the final delay (``delay_rounding``)
 
     Returns:
         Tuple of (final_rounded_ms, final_raw_ms)
     """
-    final_delay_ms = round(correlation_delay_ms + container_delay_ms)
+    final_delay_ms = round_delay_ms(
+        correlation_delay_ms + container_delay_ms, rounding
+    )
     final_delay_raw = correlation_delay_raw + container_delay_ms
 
     # Log the delay calculation chain for transparency
@@ -0,0 +1,166 @@
+# vsg_core/analysis/correlation/audio_cache.py
+"""
+Decoded-audio cache in the job's work directory.
+
+Decoding a long lossless track (TrueHD, DTS-HD MA) to PCM is one of the
+slowest parts of a job, and a job decodes the same stream several times:
+Source 1's correlation track once per compared source, again for every
+extra track of a multi-track consensus, and once more for the stepping
+correction's splice verification and QA. With the cache each distinct
+decode runs once; later requests read the samples back from
+``audio/decoded/`` in the work dir.
+
+Entries are keyed by everything that changes the samples (file, audio
+stream, sample rate, resampler, downmix). Each entry records the source
+file's size and modification time when it was decoded; an entry whose
+file has changed since is decoded again instead of reused. The cache
+lives in the work dir, so it is removed with it when the job finishes
+(or kept with it by ``keep_temp_on_failure``).
+
+The cache serves analysis only. Entries are mono at the analysis sample
+rate, so they can't feed the audio re-encode before muxing, which needs
+every channel at the source rate; that path reads the track extracted
+into the work dir, which is extracted once per job anyway.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import threading
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+import numpy as np
+
+from .decode import decode_audio
+
+if TYPE_CHECKING:
+    from vsg_core.io.runner import CommandRunner
+    from vsg_core.models.types import DownmixModeStr
+    from vsg_core.orchestrator.steps.context import Context
+
+_INDEX = "index.json"
+_create_lock = threading.Lock()
+
+
+def _provenance(file_path: str) -> dict[str, Any]:
+    stat = Path(file_path).stat()
+    return {
+        "file": str(Path(file_path).resolve()),
+        "size": stat.st_size,
+        "mtime_ns": stat.st_mtime_ns,
+    }
+
+
+class DecodedAudioCache:
+    """Decoded mono float32 PCM, one file per distinct decode."""
+
+    def __init__(self, root: Path):
+        self.root = root
+        self._lock = threading.Lock()
+        self._index: dict[str, dict[str, Any]] = {}
+        index_path = root / _INDEX
+        if index_path.exists():
+            try:
+                self._index = json.loads(index_path.read_text(encoding="utf-8"))
+            except (OSError, ValueError):
+                self._index = {}
+
+    @staticmethod
+    def key(
+        file_path: str,
+        stream_index: int,
+        sr: int,
+        use_soxr: bool,
+        downmix: DownmixModeStr,
+    ) -> str:
+        resolved = str(Path(file_path).resolve())
+        raw = f"{resolved}|a:{stream_index}|{sr}|soxr={use_soxr}|{downmix}"
+        return hashlib.sha1(raw.encode("utf-8")).hexdigest()[:20]
+
+    def decode(
+        self,
+        file_path: str,
+        stream_index: int,
+        sr: int,
+        use_soxr: bool,
+        runner: CommandRunner,
+        tool_paths: dict[str, str | None],
+        downmix: DownmixModeStr = "mono_sum",
+    ) -> np.ndarray:
+        """``decode_audio``, reusing an earlier decode of the same stream."""
+        key = self.key(file_path, stream_index, sr, use_soxr, downmix)
+        pcm_path = self.root / f"{key}.f32"
+        provenance = _provenance(file_path)
+        log = runner._log_message
+        name = Path(file_path).name
+
+        with self._lock:
+            entry = self._index.get(key)
+        if entry is not None and pcm_path.exists():
+            if entry.get("provenance") == provenance:
+                log(f"[Audio Cache] Reusing decoded {name} (a:{stream_index})")
+                return np.fromfile(pcm_path, dtype=np.float32)
+            log(f"[Audio Cache] {name} changed since it was decoded; decoding again")
+
+        pcm = decode_audio(
+            file_path, stream_index, sr, use_soxr, runner, tool_paths, downmix=downmix
+        )
+        try:
+            self.root.mkdir(parents=True, exist_ok=True)
+            pcm.astype(np.float32, copy=False).tofile(pcm_path)
+            with self._lock:
+                self._index[key] = {
+                    "provenance": provenance,
+                    "stream_index": stream_index,
+                    "sample_rate": sr,
+                    "use_soxr": use_soxr,
+                    "downmix": downmix,
+                    "samples": int(pcm.size),
+                }
+                (self.root / _INDEX).write_text(
+                    json.dumps(self._index, indent=2), encoding="utf-8"
+                )
+        except OSError as e:
+            # A full disk only costs the reuse, not the job
+            log(f"[Audio Cache] Could not store decoded {name}: {e}")
+            pcm_path.unlink(missing_ok=True)
+        return pcm
+
+
+def decode_for_job(
+    ctx: Context,
+    file_path: str,
+    stream_index: int,
+    sr: int,
+    runner: CommandRunner,
+    downmix: DownmixModeStr = "mono_sum",
+) -> np.ndarray:
+    """
+    Decode a full stream for ``ctx``'s job, through its cache when
+    ``analysis_audio_cache`` is on.
+    """
+    settings = ctx.settings
+    if not settings.analysis_audio_cache:
+        return decode_audio(
+            file_path,
+            stream_index,
+            sr,
+            settings.use_soxr,
+            runner,
+            ctx.tool_paths,
+            downmix=downmix,
+        )
+    with _create_lock:
+        if ctx.audio_cache is None:
+            ctx.audio_cache = DecodedAudioCache(ctx.work_dir.audio / "decoded")
+    return ctx.audio_cache.decode(
+        file_path,
+        stream_index,
+        sr,
+        settings.use_soxr,
+        runner,
+        ctx.tool_paths,
+        downmix=downmix,
+    )
@@ -0,0 +1,75 @@
+# vsg_core/analysis/correlation/chunk_dump.py
+"""
+Debug dump of the audio the correlator saw.
+
+With ``analysis_dump_chunks`` on, every window handed to the correlation
+method (decoded, resampled to 48 kHz mono and filtered) is written as a
+pair of float WAVs, reference and target, plus a CSV with each window's
+outcome. The buffers are the ones already in memory; nothing is decoded
+again.
+"""
+
+from __future__ import annotations
+
+import csv
+import re
+from typing import TYPE_CHECKING
+
+import numpy as np
+from scipy.io import wavfile
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from ..types import ChunkResult
+
+
+class ChunkDumper:
+    """``on_window`` callback for ``run_dense_correlation``."""
+
+    def __init__(self, out_dir: Path, source_key: str, sr: int):
+        self.out_dir = out_dir
+        self.prefix = re.sub(r"\W+", "_", source_key.lower()).strip("_")
+        self.sr = sr
+        self._rows: list[dict[str, object]] = []
+        out_dir.mkdir(parents=True, exist_ok=True)
+
+    def __call__(
+        self,
+        index: int,
+        start_sample: int,
+        ref: np.ndarray,
+        tgt: np.ndarray,
+        result: ChunkResult | None,
+    ) -> None:
+        stem = f"{self.prefix}_chunk{index:04d}"
+        for side, pcm in (("ref", ref), ("tgt", tgt)):
+            path = self.out_dir / f"{stem}_{side}.wav"
+            wavfile.write(path, self.sr, np.asarray(pcm, dtype=np.float32))
+        if result is None:
+            status = "silence"
+        elif result.accepted:
+            status = "accepted"
+        else:
+            status = f"rejected ({result.reject_reason or 'below_min_match'})"
+        self._rows.append(
+            {
+                "chunk": index,
+                "start_s": f"{start_sample / self.sr:.3f}",
+                "status": status,
+                "delay_ms": f"{result.raw_delay_ms:.3f}" if result else "",
+                "match_pct": f"{result.match_pct:.2f}" if result else "",
+                "files": f"{stem}_ref.wav / {stem}_tgt.wav",
+            }
+        )
+
+    def close(self) -> Path | None:
+        """Write the CSV index; returns its path (None if nothing dumped)."""
+        if not self._rows:
+            return None
+        path = self.out_dir / f"{self.prefix}_chunks.csv"
+        with path.open("w", newline="", encoding="utf-8") as f:
+            writer = csv.DictWriter(f, fieldnames=list(self._rows[0]))
+            writer.writeheader()
+            writer.writerows(self._rows)
+        return path
@@ -0,0 +1,162 @@
+# vsg_core/analysis/correlation/coarse.py
+"""
+Coarse whole-file pre-alignment from onset envelopes.
+
+Window correlation can only find a delay that is small compared to the
+window: when the target has, say, three extra minutes of intro, a window
+of the reference and the window at the same time in the target share no
+audio and every window is rejected. The coarse aligner looks at both whole
+tracks at low resolution first.
+
+Each track is reduced to an onset envelope: log energy per
+``COARSE_FRAME_MS`` frame, differenced and half-wave rectified, so level
+differences between masters cancel and only "something starts here"
+remains. The two envelopes are cross-correlated over lags up to
+``max_offset_s`` (normalised by the overlap, so large lags are not
+penalised for overlapping less). The strongest lag is the coarse offset,
+with a frame's resolution; its peak-to-sidelobe ratio says how clearly it
+stands out.
+
+The target is then shifted by the coarse offset (padded with silence or
+trimmed at the head) and window correlation runs on the shifted track, so
+the windows only have to find the residual. The coarse offset is added
+back to every window's delay afterwards. Correlation curves and DTW, which
+run on the shifted track, are relative to the coarse offset.
+
+Delay sign follows the rest of the analysis: ``delay = ref_time -
+tgt_time`` is the delay to apply to the target.
+"""
+
+from __future__ import annotations
+
+import dataclasses
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any
+
+import numpy as np
+
+if TYPE_CHECKING:
+    from ..types import ChunkResult
+
+COARSE_FRAME_MS = 10.0
+# Peak-to-sidelobe ratio below which the coarse offset is not trusted
+MIN_COARSE_PSR = 6.0
+# Lags where the envelopes overlap less than this share of the shorter
+# track are not considered (the normalisation would amplify noise)
+_MIN_OVERLAP = 0.25
+
+
+@dataclass(frozen=True, slots=True)
+class CoarseAlignment:
+    """Whole-file offset found from the onset envelopes."""
+
+    offset_ms: float  # Coarse delay (multiple of frame_ms)
+    psr: float  # Peak-to-sidelobe ratio of the envelope correlation
+    frame_ms: float
+    max_offset_s: float
+
+    @property
+    def reliable(self) -> bool:
+        return self.psr >= MIN_COARSE_PSR
+
+    def describe(self) -> str:
+        return (
+            f"{self.offset_ms:+.0f}ms (±{self.frame_ms:g}ms, PSR {self.psr:.1f}, "
+            f"searched ±{self.max_offset_s:g}s)"
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "offset_ms": self.offset_ms,
+            "psr": round(self.psr, 2),
+            "frame_ms": self.frame_ms,
+            "max_offset_s": self.max_offset_s,
+            "reliable": self.reliable,
+        }
+
+
+def onset_envelope(
+    pcm: np.ndarray, sr: int, frame_ms: float = COARSE_FRAME_MS
+) -> np.ndarray:
+    """Half-wave rectified log-energy difference per frame, zero-mean."""
+    hop = max(1, int(round(sr * frame_ms / 1000.0)))
+    n_frames = len(pcm) // hop
+    if n_frames < 2:
+        return np.zeros(0, dtype=np.float64)
+    frames = np.asarray(pcm[: n_frames * hop], dtype=np.float64).reshape(
+        n_frames, hop
+    )
+    log_energy = np.log10(np.mean(frames * frames, axis=1) + 1e-10)
+    onset = np.maximum(np.diff(log_energy), 0.0)
+    onset -= onset.mean()
+    std = onset.std()
+    return onset / std if std > 0 else onset
+
+
+def coarse_offset(
+    ref_pcm: np.ndarray,
+    tgt_pcm: np.ndarray,
+    sr: int,
+    max_offset_s: float,
+    frame_ms: float = COARSE_FRAME_MS,
+) -> CoarseAlignment | None:
+    """Coarse delay of ``tgt_pcm`` against ``ref_pcm``; None if too short."""
+    ref_env = onset_envelope(ref_pcm, sr, frame_ms)
+    tgt_env = onset_envelope(tgt_pcm, sr, frame_ms)
+    shorter = min(len(ref_env), len(tgt_env))
+    if shorter < 2:
+        return None
+
+    n = len(ref_env) + len(tgt_env) - 1
+    n_fft = 1 << (n - 1).bit_length()
+    corr = np.fft.irfft(
+        np.fft.rfft(ref_env, n_fft) * np.conj(np.fft.rfft(tgt_env, n_fft)), n_fft
+    )
+
+    # corr[k] pairs ref[i + k] with tgt[i]; negative lags wrap to the end
+    max_lag = int(max_offset_s * 1000.0 / frame_ms)
+    lags = np.arange(
+        -min(max_lag, len(tgt_env) - 1), min(max_lag, len(ref_env) - 1) + 1
+    )
+    overlap = np.minimum(len(tgt_env), len(ref_env) - lags) - np.maximum(-lags, 0)
+    usable = overlap >= max(2, int(shorter * _MIN_OVERLAP))
+    if not usable.any():
+        return None
+    lags = lags[usable]
+    values = corr[lags % n_fft] / overlap[usable]
+
+    peak = int(np.argmax(values))
+    # Sidelobes: everything more than a few frames from the peak
+    near = np.arange(max(0, peak - 5), min(len(values), peak + 6))
+    sidelobes = np.delete(values, near)
+    if len(sidelobes) < 2 or sidelobes.std() == 0:
+        psr = 0.0
+    else:
+        psr = float((values[peak] - sidelobes.mean()) / sidelobes.std())
+
+    return CoarseAlignment(
+        offset_ms=float(lags[peak] * frame_ms),
+        psr=psr,
+        frame_ms=frame_ms,
+        max_offset_s=max_offset_s,
+    )
+
+
+def shift_target(tgt_pcm: np.ndarray, offset_ms: float, sr: int) -> np.ndarray:
+    """``tgt_pcm`` delayed by ``offset_ms`` (silence-padded or head-trimmed)."""
+    samples = int(round(offset_ms * sr / 1000.0))
+    if samples > 0:
+        return np.concatenate([np.zeros(samples, dtype=tgt_pcm.dtype), tgt_pcm])
+    return tgt_pcm[-samples:]
+
+
+def add_offset(results: list[ChunkResult], offset_ms: float) -> list[ChunkResult]:
+    """Window results on a shifted target, as delays of the original."""
+    return [
+        dataclasses.replace(
+            r,
+            delay_ms=int(round(r.raw_delay_ms + offset_ms)),
+            raw_delay_ms=r.raw_delay_ms + offset_ms,
+        )
+        for r in results
+    ]
@@ -4,12 +4,102 @@ Peak confidence normalization for correlation results.
 
 Shared by multiple correlation methods to convert raw correlation peaks
 into a comparable 0-100 confidence score.
+
+Every window's ``match_pct`` is compared against ``min_match_pct``. By
+default ("native") each method scores its peak its own way: SCC reports
+the peak as a fraction of the signals' energy, the GCC methods map their
+peak-to-sidelobe ratio. ``correlation_confidence_metric`` replaces that
+with one metric for all waveform methods (SCC, GCC-PHAT, GCC-SCOT,
+whitened); the feature-domain methods (onset, spectrogram) keep their own.
+How ``min_match_pct`` reads on each scale:
+
+- ``normalized_peak``: |peak| as a percentage of the largest value the
+  correlation could reach for this cross-spectrum (both windows carrying
+  the same content). 10% = a tenth of a perfect match. Cyclic or ambient
+  audio correlates broadly, so this can stay high at any lag.
+- ``peak_to_sidelobe``: (peak - mean sidelobe) / sidelobe std, with the
+  mainlobe excluded. PSR <= 10 (the noise floor of a 10 s window) maps to
+  0%, PSR >= 20 to 100%, linear in between: 50% = PSR 15.
+- ``peak_to_mean``: |peak| / mean |correlation|. Ratio <= 5 maps to 0%,
+  >= 25 to 100%: 10% = ratio 7, 50% = ratio 15. Unlike PSR it ignores how
+  much the sidelobes vary, so it sits between the other two.
 """
 
 from __future__ import annotations
 
+from typing import TYPE_CHECKING
+
 import numpy as np
 
+if TYPE_CHECKING:
+    from ...models.types import ConfidenceMetricStr
+
+PSR_FLOOR = 10.0  # PSR mapped to 0%
+PSR_FULL = 20.0  # PSR mapped to 100%
+PMR_FLOOR = 5.0  # Peak-to-mean ratio mapped to 0%
+PMR_FULL = 25.0  # Peak-to-mean ratio mapped to 100%
+SIDELOBE_EXCLUDE = 100  # Samples on each side of the peak left out of sidelobes
+
+
+def _scale(value: float, floor: float, full: float) -> float:
+    """Map ``value`` linearly from [floor, full] to [0, 100], clamped."""
+    return min(100.0, max(0.0, (value - floor) / (full - floor) * 100.0))
+
+
+def peak_to_sidelobe_ratio(
+    corr: np.ndarray, peak_idx: int, exclude_radius: int = SIDELOBE_EXCLUDE
+) -> float:
+    """(peak - mean sidelobe) / sidelobe std; 0 when it can't be measured."""
+    abs_corr = np.abs(corr)
+    mask = np.ones(len(abs_corr), dtype=bool)
+    mask[max(0, peak_idx - exclude_radius) : peak_idx + exclude_radius + 1] = False
+    sidelobes = abs_corr[mask]
+    if len(sidelobes) < 10:
+        return 0.0
+    std = float(np.std(sidelobes, ddof=1))
+    if std < 1e-12:
+        return 0.0
+    return (float(abs_corr[peak_idx]) - float(np.mean(sidelobes))) / std
+
+
+def psr_to_pct(psr: float) -> float:
+    return _scale(psr, PSR_FLOOR, PSR_FULL)
+
+
+def normalized_peak(corr: np.ndarray, peak_idx: int, peak_bound: float) -> float:
+    """|peak| as a percentage of ``peak_bound`` (the largest possible value)."""
+    if peak_bound <= 0.0:
+        return 0.0
+    return min(100.0, max(0.0, abs(float(corr[peak_idx])) / peak_bound * 100.0))
+
+
+def peak_to_mean(corr: np.ndarray, peak_idx: int) -> float:
+    abs_corr = np.abs(corr)
+    mean = float(np.mean(abs_corr))
+    if mean < 1e-12:
+        return 0.0
+    return _scale(float(abs_corr[peak_idx]) / mean, PMR_FLOOR, PMR_FULL)
+
+
+def score_confidence(
+    metric: ConfidenceMetricStr,
+    corr: np.ndarray,
+    peak_idx: int,
+    peak_bound: float,
+) -> float:
+    """0-100 confidence of the peak at ``peak_idx`` under ``metric``.
+
+    ``peak_bound`` is only used by ``normalized_peak``. "native" is not
+    scored here; the methods keep their own formula for it.
+    """
+    if metric == "normalized_peak":
+        return normalized_peak(corr, peak_idx, peak_bound)
+    if metric == "peak_to_sidelobe":
+        return psr_to_pct(peak_to_sidelobe_ratio(corr, peak_idx))
+    if metric == "peak_to_mean":
+        return peak_to_mean(corr, peak_idx)
+    raise ValueError(f"No shared scoring for confidence metric '{metric}'")
+
 
 def normalize_peak_confidence(
     correlation_array: np.ndarray, peak_idx: int | np.intp
@@ -0,0 +1,156 @@
+# vsg_core/analysis/correlation/curve.py
+"""
+Correlation curves (lag vs correlation) for plotting.
+
+The dense runner keeps only each window's peak. To let a frontend draw the
+whole curve (and judge how sharp and isolated the peak is), the curve of
+one representative window, or of every window, is recomputed afterwards
+from the audio still in memory and stored as ``CorrelationCurve``.
+
+The curve is the normalized cross-correlation (SCC) of the window, whatever
+method picked the delay: it is the view a user can read, and its peak sits
+at the delay the methods agree on. Lags use the same sign as
+``ChunkResult.raw_delay_ms``. Long curves are decimated to ``max_points``
+by keeping the largest-magnitude value of each block, so the peak survives.
+"""
+
+from __future__ import annotations
+
+import math
+import statistics
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any
+
+import numpy as np
+
+if TYPE_CHECKING:
+    from ..types import ChunkResult
+
+DEFAULT_MAX_POINTS = 2000
+
+
+@dataclass(frozen=True, slots=True)
+class CorrelationCurve:
+    """Correlation of one window over lag."""
+
+    start_s: float  # Window centre (as ChunkResult.start_s)
+    delay_ms: float  # Delay the correlation method reported
+    match_pct: float
+    accepted: bool
+    representative: bool  # The window chosen to stand for the source
+    lags_ms: np.ndarray
+    values: np.ndarray  # Normalized correlation, -1..1
+
+    def points(self) -> list[tuple[float, float]]:
+        """(lag_ms, value) pairs in ascending lag order."""
+        return list(zip(self.lags_ms.tolist(), self.values.tolist(), strict=True))
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "start_s": round(self.start_s, 3),
+            "delay_ms": round(self.delay_ms, 3),
+            "match_pct": round(self.match_pct, 3),
+            "accepted": self.accepted,
+            "representative": self.representative,
+            "points": [
+                [round(lag, 3), round(value, 5)] for lag, value in self.points()
+            ],
+        }
+
+
+def correlation_curve(
+    ref: np.ndarray,
+    tgt: np.ndarray,
+    sr: int,
+    max_points: int = DEFAULT_MAX_POINTS,
+) -> tuple[np.ndarray, np.ndarray]:
+    """Normalized cross-correlation of two windows as (lags_ms, values)."""
+    ref = np.asarray(ref, dtype=np.float64)
+    tgt = np.asarray(tgt, dtype=np.float64)
+    ref = (ref - ref.mean()) / (ref.std() + 1e-9)
+    tgt = (tgt - tgt.mean()) / (tgt.std() + 1e-9)
+
+    n = len(ref) + len(tgt) - 1
+    n_fft = 1 << (n - 1).bit_length()
+    corr = np.fft.irfft(
+        np.fft.rfft(ref, n_fft) * np.conj(np.fft.rfft(tgt, n_fft)), n_fft
+    )
+    corr /= math.sqrt(len(ref) * len(tgt))
+
+    # Circular index -> signed lag (as extract_peak); keep only real overlaps
+    max_lag = min(len(ref), len(tgt)) - 1
+    lags = np.arange(-max_lag, max_lag + 1)
+    values = corr[lags % n_fft]
+
+    if max_points > 0 and len(values) > max_points:
+        block = math.ceil(len(values) / max_points)
+        pad = (-len(values)) % block
+        padded = np.pad(np.abs(values), (0, pad), constant_values=-1.0)
+        picks = padded.reshape(-1, block).argmax(axis=1)
+        picks += np.arange(len(picks)) * block
+        lags, values = lags[picks], values[picks]
+
+    return lags / sr * 1000.0, values
+
+
+def representative_index(results: list[ChunkResult]) -> int | None:
+    """Index of the accepted window closest to the median delay.
+
+    Ties go to the higher match. Falls back to the best-matching window
+    when none was accepted.
+    """
+    if not results:
+        return None
+    accepted = [i for i, r in enumerate(results) if r.accepted]
+    if not accepted:
+        return max(range(len(results)), key=lambda i: results[i].match_pct)
+    median = statistics.median(results[i].raw_delay_ms for i in accepted)
+    return min(
+        accepted,
+        key=lambda i: (abs(results[i].raw_delay_ms - median), -results[i].match_pct),
+    )
+
+
+def collect_curves(
+    ref_pcm: np.ndarray,
+    tgt_pcm: np.ndarray,
+    sr: int,
+    window_s: float,
+    results: list[ChunkResult],
+    all_windows: bool = False,
+    max_points: int = DEFAULT_MAX_POINTS,
+) -> list[CorrelationCurve]:
+    """Curves for the representative window, or for every window.
+
+    ``ref_pcm``/``tgt_pcm`` are the (filtered) buffers the runner
+    correlated; a ``WindowedPcm`` works too, since only the runner's own
+    window slices are read.
+    """
+    rep = representative_index(results)
+    if rep is None:
+        return []
+    window_samples = int(round(window_s * sr))
+    chosen = range(len(results)) if all_windows else [rep]
+
+    curves = []
+    for i in chosen:
+        r = results[i]
+        pos = int(round(r.start_s * sr - window_samples / 2))
+        lags_ms, values = correlation_curve(
+            ref_pcm[pos : pos + window_samples],
+            tgt_pcm[pos : pos + window_samples],
+            sr,
+            max_points,
+        )
+        curves.append(
+            CorrelationCurve(
+                start_s=r.start_s,
+                delay_ms=r.raw_delay_ms,
+                match_pct=r.match_pct,
+                accepted=r.accepted,
+                representative=i == rep,
+                lags_ms=lags_ms,
+                values=values,
+            )
+        )
+    return curves
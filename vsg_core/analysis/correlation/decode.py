@@ -15,7 +15,10 @@ from typing import TYPE_CHECKING
 import numpy as np
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
+
     from vsg_core.io.runner import CommandRunner
+    from vsg_core.models.types import DownmixModeStr
 
 # --- Language Normalization (private to decode) ---
 
@@ -115,6 +118,78 @@ def get_audio_stream_info(
         return None, None
 
 
+# --- Downmix ---
+
+# Single channel picked by each non-summing downmix (ffmpeg pan names)
+_PAN_CHANNEL: dict[str, str] = {"none": "c0", "left_only": "FL", "center_only": "FC"}
+
+# Layouts with a front-centre channel besides mono and 5+ channel layouts
+_FC_LAYOUTS = ("3.0", "3.1", "4.0", "4.1")
+
+
+def downmix_args(downmix: DownmixModeStr) -> list[str]:
+    """ffmpeg output options that reduce the decoded stream to one channel.
+
+    "mono_sum" is ffmpeg's own ``-ac 1`` mix (the long-standing behaviour);
+    the others keep a single channel untouched, which sidesteps phase
+    differences between channels. "none" keeps the first channel whatever
+    its position.
+    """
+    if downmix == "mono_sum":
+        return ["-ac", "1"]
+    return ["-af", f"pan=mono|c0={_PAN_CHANNEL[downmix]}"]
+
+
+def resolve_downmix(
+    downmix: DownmixModeStr,
+    file_path: str,
+    stream_index: int,
+    runner: CommandRunner,
+    tool_paths: dict[str, str | None],
+) -> tuple[DownmixModeStr, str]:
+    """
+    ``downmix``, or "mono_sum" when the stream has no channel for it.
+
+    Returns:
+        (effective downmix, channel layout as reported by ffprobe)
+    """
+    if downmix in ("mono_sum", "none"):
+        return downmix, ""
+    out = runner.run(
+        [
+            "ffprobe",
+            "-v",
+            "error",
+            "-select_streams",
+            f"a:{stream_index}",
+            "-show_entries",
+            "stream=channels,channel_layout",
+            "-of",
+            "json",
+            str(file_path),
+        ],
+        tool_paths,
+    )
+    try:
+        stream = (json.loads(out or "{}").get("streams") or [{}])[0]
+        channels = int(stream.get("channels") or 0)
+    except (json.JSONDecodeError, TypeError, ValueError):
+        return "mono_sum", ""
+    layout = str(stream.get("channel_layout") or "")
+    if not layout:
+        # Unknown layout: ffmpeg can't resolve channel names
+        return "mono_sum", f"{channels} channels"
+    if downmix == "left_only":
+        has_channel = layout != "mono"
+    else:
+        has_channel = (
+            layout == "mono"
+            or channels >= 5
+            or (layout.startswith(_FC_LAYOUTS) and "(back)" not in layout)
+        )
+    return (downmix if has_channel else "mono_sum"), layout
+
+
 # --- Audio Decoding ---
 
 # Default sample rate for all correlation work
@@ -128,6 +203,7 @@ def decode_audio(
     use_soxr: bool,
     runner: CommandRunner,
     tool_paths: dict[str, str | None],
+    downmix: DownmixModeStr = "mono_sum",
 ) -> np.ndarray:
     """
     Decode one audio stream to a mono float32 NumPy array.
@@ -139,6 +215,8 @@ def decode_audio(
         use_soxr: Use high-quality soxr resampler.
         runner: CommandRunner for executing ffmpeg.
         tool_paths: Tool path dictionary.
+        downmix: How the channels become one (see ``downmix_args``); pass
+            a value from ``resolve_downmix``.
 
     Returns:
         1-D float32 NumPy array of audio samples.
@@ -160,7 +238,7 @@ def decode_audio(
     if use_soxr:
         cmd.extend(["-resampler", "soxr"])
 
-    cmd.extend(["-ac", "1", "-ar", str(sr), "-f", "f32le", "-"])
+    cmd.extend([*downmix_args(downmix), "-ar", str(sr), "-f", "f32le", "-"])
 
     pcm_bytes = runner.run(cmd, tool_paths, is_binary=True)
     if not pcm_bytes or not isinstance(pcm_bytes, bytes):
@@ -181,6 +259,13 @@ def decode_audio(
         except UnicodeDecodeError:
             pass  # Good - binary data as expected
 
+    return _to_float32(pcm_bytes, Path(file_path).name, log)
+
+
+def _to_float32(
+    pcm_bytes: bytes, name: str, log: Callable[[str], None] | None
+) -> np.ndarray:
+    """Raw f32le bytes from ffmpeg -> owned float32 array."""
     # Ensure buffer size is a multiple of element size (4 bytes for float32)
     element_size = np.dtype(np.float32).itemsize
     aligned_size = (len(pcm_bytes) // element_size) * element_size
@@ -189,7 +274,7 @@ def decode_audio(
         if log:
             log(
                 f"[BUFFER ALIGNMENT] Trimmed {trimmed_bytes} bytes from "
-                f"{Path(file_path).name} (likely Opus/other codec)"
+                f"{name} (likely Opus/other codec)"
             )
         pcm_bytes = pcm_bytes[:aligned_size]
 
@@ -197,3 +282,113 @@ def decode_audio(
     # np.frombuffer() creates a view that can become invalid if the underlying
     # buffer is garbage collected. Using .copy() ensures we own the memory.
     return np.frombuffer(pcm_bytes, dtype=np.float32).copy()
+
+
+# --- Windowed Decoding ---
+
+# Extra audio decoded before (and after) each window. Decoders need a few
+# frames to settle after a seek, and filters have a start-up transient;
+# both land in the guard, which is trimmed off.
+WINDOW_GUARD_S = 0.5
+
+
+def probe_audio_timing(
+    file_path: str,
+    stream_index: int,
+    runner: CommandRunner,
+    tool_paths: dict[str, str | None],
+) -> tuple[float, float] | None:
+    """
+    Duration of an audio stream and its start relative to the file start.
+
+    ``decode_audio`` output begins at the stream's first sample while
+    ``-ss`` seeks relative to the file start, so windowed decoding adds the
+    start offset to every seek.
+
+    Returns:
+        (duration_s, start_offset_s) or None if ffprobe fails.
+    """
+    out = runner.run(
+        [
+            "ffprobe",
+            "-v",
+            "error",
+            "-select_streams",
+            f"a:{stream_index}",
+            "-show_entries",
+            "stream=start_time,duration:format=start_time,duration",
+            "-of",
+            "json",
+            str(file_path),
+        ],
+        tool_paths,
+    )
+    if not out or not isinstance(out, str):
+        return None
+    try:
+        info = json.loads(out)
+        fmt = info.get("format", {})
+        stream = (info.get("streams") or [{}])[0]
+        file_start = float(fmt.get("start_time") or 0.0)
+        offset = float(stream.get("start_time") or file_start) - file_start
+        # Matroska usually only has a container duration
+        duration = float(stream.get("duration") or fmt["duration"])
+    except (json.JSONDecodeError, KeyError, ValueError):
+        return None
+    return duration - offset, offset
+
+
+def decode_window(
+    file_path: str,
+    stream_index: int,
+    start_s: float,
+    duration_s: float,
+    sr: int,
+    use_soxr: bool,
+    runner: CommandRunner,
+    tool_paths: dict[str, str | None],
+    start_offset_s: float = 0.0,
+    guard_s: float = WINDOW_GUARD_S,
+    downmix: DownmixModeStr = "mono_sum",
+) -> tuple[np.ndarray, int]:
+    """
+    Decode ``duration_s`` of one audio stream starting at ``start_s``.
+
+    ``start_s`` is in ``decode_audio`` coordinates (0 = first sample of the
+    stream). The decode starts ``guard_s`` early and runs ``guard_s`` past
+    the end, so the caller can filter the whole segment and then cut the
+    window out of the settled middle.
+
+    Returns:
+        (segment, lead) where ``segment[lead:lead + duration]`` is the
+        window. ``lead`` is shorter than the guard near the file start.
+
+    Raises:
+        RuntimeError: If ffmpeg decode fails.
+    """
+    seek_s = max(0.0, start_offset_s + start_s - guard_s)
+    lead_s = start_offset_s + start_s - seek_s
+    cmd: list[str] = [
+        "ffmpeg",
+        "-nostdin",
+        "-v",
+        "error",
+        "-ss",
+        f"{seek_s:.6f}",
+        "-i",
+        str(file_path),
+        "-map",
+        f"0:a:{stream_index}",
+        "-t",
+        f"{lead_s + duration_s + guard_s:.6f}",
+    ]
+    if use_soxr:
+        cmd.extend(["-resampler", "soxr"])
+    cmd.extend([*downmix_args(downmix), "-ar", str(sr), "-f", "f32le", "-"])
+
+    pcm_bytes = runner.run(cmd, tool_paths, is_binary=True)
+    if not pcm_bytes or not isinstance(pcm_bytes, bytes):
+        raise RuntimeError(
+            f"ffmpeg decode failed for {Path(file_path).name} at {start_s:.3f}s"
+        )
+    return _to_float32(pcm_bytes, Path(file_path).name, None), int(round(lead_s * sr))
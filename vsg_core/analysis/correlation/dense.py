@@ -16,19 +16,35 @@ detection, delay selection, audit trail).
 
 from __future__ import annotations
 
+import math
 import time
 from collections import Counter
+from dataclasses import dataclass, replace
 from typing import TYPE_CHECKING
 
 import numpy as np
 
-from ..types import ChunkResult
+from ..types import (
+    ChunkResult,
+    RejectReason,
+    count_rejections,
+    describe_rejections,
+)
+from .fft_plan import plan_stats, reset_plan_stats
+from .gpu_backend import gpu_in_use
+from .lag_window import out_of_range_delay
 
 if TYPE_CHECKING:
     from collections.abc import Callable
 
+    from ...models.settings import AppSettings
     from .registry import CorrelationMethod
 
+    # (index, start sample, ref window, tgt window, result or None)
+    WindowCallback = Callable[
+        [int, int, np.ndarray, np.ndarray, ChunkResult | None], None
+    ]
+
 
 # ── Silence Detection ─────────────────────────────────────────────────────
 
@@ -41,9 +57,258 @@ def _rms_db(samples: np.ndarray) -> float:
     return 20.0 * np.log10(rms)
 
 
+def _window_energy_db(
+    pcm: np.ndarray, start: int, end: int, block: int, window_blocks: int
+) -> np.ndarray:
+    """Mean-power dB of every block-aligned window in [start, end).
+
+    Entry ``i`` is the energy of the window starting at ``start + i*block``
+    spanning ``window_blocks`` blocks.
+    """
+    n_blocks = (end - start) // block
+    blocks = pcm[start : start + n_blocks * block].astype(np.float64)
+    power = np.mean(blocks.reshape(n_blocks, block) ** 2, axis=1)
+    csum = np.concatenate(([0.0], np.cumsum(power)))
+    window_power = (csum[window_blocks:] - csum[:-window_blocks]) / window_blocks
+    return 10.0 * np.log10(np.maximum(window_power, 1e-24))
+
+
+def _place_avoiding_silence(
+    ref_pcm: np.ndarray,
+    tgt_pcm: np.ndarray,
+    positions: list[int],
+    scan_start: int,
+    scan_end: int,
+    window_samples: int,
+    hop_samples: int,
+    min_energy_db: float,
+    sr: int,
+    log: Callable[[str], None],
+) -> list[int]:
+    """
+    Nudge window starts off low-energy regions.
+
+    Each window whose energy (the quieter of ref/tgt) is below
+    ``min_energy_db`` moves to the nearest sufficiently energetic start
+    within half a hop, so it never crosses into a neighbour's slot. If the
+    whole scan range is quiet, the uniform placement is returned unchanged.
+    Where silence is dense two windows can land on the same start; each
+    start is kept once, and a moved window always ends inside the range.
+    """
+    block = max(1, min(hop_samples // 4, sr // 4))
+    window_blocks = max(1, window_samples // block)
+    if scan_end - scan_start < window_blocks * block:
+        return positions
+
+    energy = np.minimum(
+        _window_energy_db(ref_pcm, scan_start, scan_end, block, window_blocks),
+        _window_energy_db(tgt_pcm, scan_start, scan_end, block, window_blocks),
+    )
+    loud = energy >= min_energy_db
+    if not loud.any():
+        log(
+            f"  [Silence Avoidance] Whole scan range is below "
+            f"{min_energy_db:.0f} dB — disabled for this source "
+            f"(uniform placement)."
+        )
+        return positions
+
+    loud_idx = np.flatnonzero(loud)
+    max_shift_blocks = max(1, (hop_samples // 2) // block)
+    last_start = max(scan_start, scan_end - window_samples)
+    placed: list[int] = []
+    nudged = 0
+    for pos in positions:
+        idx = min((pos - scan_start) // block, len(energy) - 1)
+        if loud[idx]:
+            placed.append(pos)
+            continue
+        # Nearest loud block-aligned start (searchsorted gives neighbours)
+        j = int(np.searchsorted(loud_idx, idx))
+        candidates = [loud_idx[k] for k in (j - 1, j) if 0 <= k < len(loud_idx)]
+        best = min(candidates, key=lambda c: abs(int(c) - idx))
+        if abs(int(best) - idx) <= max_shift_blocks:
+            placed.append(min(scan_start + int(best) * block, last_start))
+            nudged += 1
+        else:
+            placed.append(pos)
+
+    unique = sorted(set(placed))
+    log(
+        f"  [Silence Avoidance] Nudged {nudged}/{len(positions)} windows "
+        f"off regions below {min_energy_db:.0f} dB"
+    )
+    if len(unique) < len(placed):
+        log(
+            f"  [Silence Avoidance] {len(placed) - len(unique)} window(s) "
+            f"landed on an already used start and were dropped"
+        )
+    return unique
+
+
 # ── Dense Correlation Runner ──────────────────────────────────────────────
 
 
+def resolve_scan_range(
+    length: int,
+    sr: int,
+    start_pct: float,
+    end_pct: float,
+    start_ms: float = 0,
+    end_ms: float = 0,
+) -> tuple[int, int, list[str]]:
+    """
+    Scan range in samples.
+
+    ``start_ms``/``end_ms`` (0 = unset) take precedence over the
+    percentages. Times outside the file are clamped to it; if the range is
+    then empty the percentages are used instead. Returns (scan_start,
+    scan_end, warnings) with one message per adjustment.
+    """
+    duration_ms = length / sr * 1000.0
+    warnings: list[str] = []
+    start = duration_ms * start_pct / 100.0
+    end = duration_ms * end_pct / 100.0
+    if start_ms:
+        start = start_ms
+        if start_ms > duration_ms:
+            start = duration_ms
+            warnings.append(
+                f"Scan start {start_ms / 1000:.3f}s is past the end of the "
+                f"audio ({duration_ms / 1000:.3f}s) — clamped."
+            )
+    if end_ms:
+        end = end_ms
+        if end_ms > duration_ms:
+            end = duration_ms
+            warnings.append(
+                f"Scan end {end_ms / 1000:.3f}s is past the end of the "
+                f"audio ({duration_ms / 1000:.3f}s) — clamped."
+            )
+    if (start_ms or end_ms) and start >= end:
+        warnings.append(
+            f"Scan range {start / 1000:.3f}s-{end / 1000:.3f}s is empty — "
+            f"using {start_pct:g}%-{end_pct:g}% instead."
+        )
+        start = duration_ms * start_pct / 100.0
+        end = duration_ms * end_pct / 100.0
+
+    scan_start = int(round(start / 1000.0 * sr))
+    scan_end = min(int(round(end / 1000.0 * sr)), length)
+    return scan_start, scan_end, warnings
+
+
+@dataclass(frozen=True, slots=True)
+class ChunkPlacement:
+    """Which of the hop-spaced windows in the scan range get correlated.
+
+    ``uniform`` keeps them all. ``endpoints`` keeps ``start_chunks``
+    windows from the beginning of the range and ``end_chunks`` from the
+    end, spaced a window length apart so they don't overlap — the widest
+    lever arm for a drift slope from few windows.
+
+    ``min_spacing_s`` keeps window centres at least that far apart (every
+    n-th hop position), so long windows on a short file don't measure the
+    same audio over and over. When the range can't hold the requested
+    windows at that spacing, fewer are used; endpoint windows are never
+    closer than a window length either way. 0 = no minimum.
+
+    ``excluded_s`` lists (start, end) ranges in seconds that no window may
+    overlap, e.g. intro/outro chapters (chapter_exclusion.py); they are
+    dropped before the strategy picks its windows. If that would leave no
+    window at all, the ranges are ignored.
+    """
+
+    strategy: str = "uniform"
+    start_chunks: int = 5
+    end_chunks: int = 5
+    min_spacing_s: float = 0.0
+    excluded_s: tuple[tuple[float, float], ...] = ()
+
+    def __post_init__(self) -> None:
+        if self.strategy not in ("uniform", "endpoints"):
+            raise ValueError(f"Unknown chunk strategy: {self.strategy!r}")
+        if self.strategy == "endpoints" and self.start_chunks + self.end_chunks < 2:
+            raise ValueError("Endpoint placement needs at least two windows")
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> ChunkPlacement:
+        return cls(
+            strategy=settings.chunk_strategy,
+            start_chunks=max(0, settings.endpoint_chunks_start),
+            end_chunks=max(0, settings.endpoint_chunks_end),
+            min_spacing_s=max(0.0, settings.min_chunk_spacing_s),
+        )
+
+    def describe(self) -> str:
+        spacing = (
+            f", centres ≥{self.min_spacing_s:g}s apart" if self.min_spacing_s else ""
+        )
+        if self.excluded_s:
+            spacing += f", skipping {len(self.excluded_s)} excluded range(s)"
+        if self.strategy == "uniform":
+            return f"uniform{spacing}"
+        return (
+            f"endpoints ({self.start_chunks} start + {self.end_chunks} end{spacing})"
+        )
+
+    def select(
+        self,
+        positions: list[int],
+        window_samples: int,
+        hop_samples: int,
+        sr: int,
+    ) -> list[int]:
+        """The subset of ``positions`` (ascending window starts) to use."""
+        kept = [
+            pos
+            for pos in positions
+            if not any(
+                pos < end * sr and pos + window_samples > start * sr
+                for start, end in self.excluded_s
+            )
+        ]
+        positions = kept or positions
+        spacing = int(round(self.min_spacing_s * sr))
+        hop = max(hop_samples, 1)
+        if self.strategy == "uniform":
+            return positions[:: max(1, math.ceil(spacing / hop))]
+        stride = max(1, math.ceil(max(window_samples, spacing) / hop))
+        spaced = positions[::stride]
+        if len(spaced) <= self.start_chunks + self.end_chunks:
+            return spaced
+        tail = spaced[len(spaced) - self.end_chunks :] if self.end_chunks else []
+        return spaced[: self.start_chunks] + tail
+
+
+def window_positions(
+    length: int,
+    sr: int,
+    window_s: float,
+    hop_s: float,
+    start_pct: float,
+    end_pct: float,
+    start_ms: float = 0,
+    end_ms: float = 0,
+    placement: ChunkPlacement | None = None,
+) -> tuple[list[int], int, int]:
+    """
+    Start sample of every window in the scan range.
+
+    Returns (positions, scan_start, scan_end) in samples. Windowed decoding
+    uses this to decode exactly the windows the runner will read.
+    """
+    window_samples = int(round(window_s * sr))
+    hop_samples = int(round(hop_s * sr))
+    scan_start, scan_end, _ = resolve_scan_range(
+        length, sr, start_pct, end_pct, start_ms, end_ms
+    )
+    positions = list(range(scan_start, scan_end - window_samples + 1, hop_samples))
+    if placement is not None:
+        positions = placement.select(positions, window_samples, hop_samples, sr)
+    return positions, scan_start, scan_end
+
+
 def run_dense_correlation(
     ref_pcm: np.ndarray,
     tgt_pcm: np.ndarray,
@@ -56,9 +321,16 @@ def run_dense_correlation(
     outlier_threshold_ms: float = 50.0,
     start_pct: float = 5.0,
     end_pct: float = 95.0,
+    start_ms: float = 0,
+    end_ms: float = 0,
     log: Callable[[str], None] | None = None,
     dbscan_epsilon_ms: float = 20.0,
     dbscan_min_samples_pct: float = 1.5,
+    avoid_silence: bool = False,
+    min_chunk_energy_db: float = -45.0,
+    on_window: WindowCallback | None = None,
+    placement: ChunkPlacement | None = None,
+    progress: Callable[[float], None] | None = None,
 ) -> list[ChunkResult]:
     """
     Run dense sliding window correlation over the full file.
@@ -76,9 +348,22 @@ def run_dense_correlation(
             is considered an outlier. Used in summary logging.
         start_pct: Start of scan range as percentage of duration (0-100).
         end_pct: End of scan range as percentage of duration (0-100).
+        start_ms: Absolute scan start; overrides ``start_pct`` (0 = unset).
+        end_ms: Absolute scan end; overrides ``end_pct`` (0 = unset).
         log: Logging callback.
         dbscan_epsilon_ms: DBSCAN clustering tolerance for summary log.
         dbscan_min_samples_pct: DBSCAN min samples as % of windows for summary log.
+        avoid_silence: Nudge windows off low-energy regions before correlating.
+        min_chunk_energy_db: Energy a window needs to count as non-silent
+            for silence avoidance; rejected windows below it are reported
+            as low energy.
+        on_window: Called with (index, start sample, ref window, tgt window,
+            result or None for silence) for every window, e.g. to dump the
+            audio for debugging.
+        placement: Which windows of the scan range to correlate; None or
+            uniform means every hop.
+        progress: Called with the fraction of windows done (0-1) after
+            every window.
 
     Returns:
         list[ChunkResult] — one per non-silence window, compatible with
@@ -94,72 +379,133 @@ def run_dense_correlation(
     min_len = min(len(ref_pcm), len(tgt_pcm))
     duration_s = min_len / sr
 
-    # Apply scan range
-    scan_start = int(round(duration_s * (start_pct / 100.0) * sr))
-    scan_end = int(round(duration_s * (end_pct / 100.0) * sr))
-    scan_end = min(scan_end, min_len)
-
-    # Calculate total window positions
-    total_positions = max(0, (scan_end - scan_start - window_samples) // hop_samples + 1)
+    positions, scan_start, scan_end = window_positions(
+        min_len, sr, window_s, hop_s, start_pct, end_pct, start_ms, end_ms, placement
+    )
+    total_positions = len(positions)
 
     log(
         f"[Dense Correlation] {method.name}"
     )
+    for warning in resolve_scan_range(
+        min_len, sr, start_pct, end_pct, start_ms, end_ms
+    )[2]:
+        log(f"  [WARNING] {warning}")
+    range_desc = f"{start_pct:.0f}%-{end_pct:.0f}%"
+    if start_ms or end_ms:
+        range_desc = "absolute"
     log(
         f"  Window: {window_s}s, Hop: {hop_s}s, "
-        f"Range: {start_pct:.0f}%-{end_pct:.0f}% "
+        f"Range: {range_desc} "
         f"({scan_start / sr:.1f}s - {scan_end / sr:.1f}s)"
     )
+    if placement is not None and (
+        placement.strategy != "uniform"
+        or placement.min_spacing_s
+        or placement.excluded_s
+    ):
+        log(f"  Placement: {placement.describe()}")
+    if placement is not None and placement.min_spacing_s:
+        unspaced, _, _ = window_positions(
+            min_len,
+            sr,
+            window_s,
+            hop_s,
+            start_pct,
+            end_pct,
+            start_ms,
+            end_ms,
+            replace(placement, min_spacing_s=0.0),
+        )
+        if len(unspaced) > total_positions:
+            log(
+                f"  Min spacing {placement.min_spacing_s:g}s: "
+                f"{len(unspaced)} windows reduced to {total_positions}"
+            )
     log(f"  Total windows: {total_positions}")
 
+    if avoid_silence and positions:
+        positions = _place_avoiding_silence(
+            ref_pcm,
+            tgt_pcm,
+            positions,
+            scan_start,
+            scan_end,
+            window_samples,
+            hop_samples,
+            min_chunk_energy_db,
+            sr,
+            log,
+        )
+
     results: list[ChunkResult] = []
     silence_count = 0
 
+    reset_plan_stats()
     t0 = time.perf_counter()
     last_report = t0
 
-    pos = scan_start
-    window_idx = 0
-
-    while pos + window_samples <= scan_end:
-        center_s = (pos + window_samples / 2) / sr
-
-        ref_win = ref_pcm[pos : pos + window_samples]
-        tgt_win = tgt_pcm[pos : pos + window_samples]
-
-        ref_db = _rms_db(ref_win)
-        tgt_db = _rms_db(tgt_win)
-
-        if ref_db < silence_threshold_db or tgt_db < silence_threshold_db:
-            silence_count += 1
-        else:
-            # Run correlation method (handles numpy→torch→numpy internally)
-            raw_ms, confidence = method.find_delay(ref_win, tgt_win, sr)
-            accepted = confidence >= min_match
-
-            results.append(
-                ChunkResult(
+    # Keeps another job's cleanup_gpu from releasing the cached plans
+    with gpu_in_use():
+        window_idx = 0
+
+        for pos in positions:
+            center_s = (pos + window_samples / 2) / sr
+
+            ref_win = ref_pcm[pos : pos + window_samples]
+            tgt_win = tgt_pcm[pos : pos + window_samples]
+
+            ref_db = _rms_db(ref_win)
+            tgt_db = _rms_db(tgt_win)
+
+            result = None
+            if ref_db < silence_threshold_db or tgt_db < silence_threshold_db:
+                silence_count += 1
+            else:
+                # Run correlation method (handles numpy→torch→numpy internally)
+                raw_ms, confidence = method.find_delay(ref_win, tgt_win, sr)
+                accepted = confidence >= min_match
+                reason = None
+                if not accepted:
+                    reason = _reject_reason(
+                        method,
+                        ref_win,
+                        tgt_win,
+                        sr,
+                        confidence,
+                        min(ref_db, tgt_db),
+                        min_chunk_energy_db,
+                    )
+
+                result = ChunkResult(
                     delay_ms=int(round(raw_ms)),
                     raw_delay_ms=raw_ms,
                     match_pct=confidence,
                     start_s=center_s,
                     accepted=accepted,
+                    reject_reason=reason,
                 )
-            )
-
-        pos += hop_samples
-        window_idx += 1
-
-        # Progress reporting every 5 seconds
-        now = time.perf_counter()
-        if now - last_report > 5.0:
-            done = window_idx
-            pct = done / total_positions * 100 if total_positions > 0 else 100
-            elapsed = now - t0
-            rate = done / elapsed if elapsed > 0 else 0
-            eta = (total_positions - done) / rate if rate > 0 else 0
-            log(f"  [{pct:5.1f}%] {done}/{total_positions} ({rate:.0f}/s, ETA {eta:.0f}s)")
-            last_report = now
+                results.append(result)
+            if on_window is not None:
+                on_window(window_idx, pos, ref_win, tgt_win, result)
+
+            window_idx += 1
+            if progress is not None:
+                progress(window_idx / total_positions)
+
+            # Progress reporting every 5 seconds
+            now = time.perf_counter()
+            if now - last_report > 5.0:
+                done = window_idx
+                pct = done / total_positions * 100 if total_positions > 0 else 100
+                elapsed = now - t0
+                rate = done / elapsed if elapsed > 0 else 0
+                eta = (total_positions - done) / rate if rate > 0 else 0
+                log(
+                    f"  [{pct:5.1f}%] {done}/{total_positions} "
+                    f"({rate:.0f}/s, ETA {eta:.0f}s)"
+                )
+                last_report = now
 
     elapsed = time.perf_counter() - t0
     active_count = len(results)
@@ -169,6 +515,12 @@ def run_dense_correlation(
         f"{active_count + silence_count} windows in {elapsed:.1f}s "
         f"({(active_count + silence_count) / max(elapsed, 0.001):.0f} windows/s)"
     )
+    stats = plan_stats()
+    if stats.built or stats.reused:
+        log(f"  {stats.describe()}")
+    rejections = count_rejections(results, silence_count)
+    if rejections:
+        log(f"  Rejected: {describe_rejections(rejections)}")
 
     # ── Summary ──
     _log_dense_summary(results, silence_count, method.name, outlier_threshold_ms,
@@ -178,9 +530,73 @@ def run_dense_correlation(
     return results
 
 
+def run_dense_from_settings(
+    ref_pcm: np.ndarray,
+    tgt_pcm: np.ndarray,
+    sr: int,
+    method: CorrelationMethod,
+    settings: AppSettings,
+    min_match: float,
+    log: Callable[[str], None] | None = None,
+    placement: ChunkPlacement | None = None,
+    on_window: WindowCallback | None = None,
+    progress: Callable[[float], None] | None = None,
+) -> list[ChunkResult]:
+    """``run_dense_correlation`` with window, scan range and detection
+    parameters taken from ``settings``.
+
+    ``placement`` defaults to ``ChunkPlacement.from_settings(settings)``.
+    New dense settings are wired up here, once, for every caller.
+    """
+    return run_dense_correlation(
+        ref_pcm=ref_pcm,
+        tgt_pcm=tgt_pcm,
+        sr=sr,
+        method=method,
+        window_s=settings.dense_window_s,
+        hop_s=settings.dense_hop_s,
+        min_match=min_match,
+        silence_threshold_db=settings.dense_silence_threshold_db,
+        outlier_threshold_ms=settings.dense_outlier_threshold_ms,
+        start_pct=settings.scan_start_percentage,
+        end_pct=settings.scan_end_percentage,
+        start_ms=settings.scan_start_ms,
+        end_ms=settings.scan_end_ms,
+        log=log,
+        dbscan_epsilon_ms=settings.detection_dbscan_epsilon_ms,
+        dbscan_min_samples_pct=settings.detection_dbscan_min_samples_pct,
+        avoid_silence=settings.avoid_silence,
+        min_chunk_energy_db=settings.min_chunk_energy_db,
+        on_window=on_window,
+        placement=placement or ChunkPlacement.from_settings(settings),
+        progress=progress,
+    )
+
+
 # ── Helpers ───────────────────────────────────────────────────────────────
 
 
+def _reject_reason(
+    method: CorrelationMethod,
+    ref_win: np.ndarray,
+    tgt_win: np.ndarray,
+    sr: int,
+    confidence: float,
+    energy_db: float,
+    min_energy_db: float,
+) -> RejectReason:
+    """Why a correlated window fell below ``min_match``."""
+    # Bounded methods answer a peak beyond the bound with confidence 0
+    if (
+        confidence <= 0
+        and out_of_range_delay(method, ref_win, tgt_win, sr) is not None
+    ):
+        return RejectReason.OUT_OF_RANGE
+    if energy_db < min_energy_db:
+        return RejectReason.LOW_ENERGY
+    return RejectReason.BELOW_MIN_MATCH
+
+
 def _fmt_time(seconds: float) -> str:
     """Format seconds as M:SS or H:MM:SS."""
     s = int(round(seconds))
@@ -0,0 +1,219 @@
+# vsg_core/analysis/correlation/dtw.py
+"""
+Dynamic time warping alignment of two audio tracks.
+
+Unlike the correlation methods, which collapse each window to a single
+offset, DTW returns the full warping path between the reference and the
+target, so non-linear timing (tempo drift, small edits) becomes visible
+and can be corrected piecewise.
+
+Features are per-frame log band energies (mean-removed and L2-normalized,
+so gain differences between masters do not matter); the local cost is the
+cosine distance. Full DTW over a movie is infeasible, so the search is
+limited to a Sakoe-Chiba band of ``band_ms`` around the expected path
+(the diagonal shifted by the correlation delay). Only one cost row and a
+byte-sized backtrack pointer per cell are kept: a 24-minute scan at 50ms
+frames with a ±5s band is ~29k x 201 cells (~6 MB).
+
+Delay sign follows the rest of the analysis: ``delay = ref_time -
+tgt_time`` is the delay to apply to the target.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+import numpy as np
+
+DTW_FRAME_MS = 50.0
+_N_BANDS = 16
+_F_MIN = 100.0
+_F_MAX = 8000.0
+
+# Backtrack pointers
+_DIAG, _VERT, _HORZ, _START = 0, 1, 2, 3
+
+
+@dataclass(frozen=True, slots=True)
+class DtwResult:
+    """Warping path and summary of a DTW alignment."""
+
+    path: list[tuple[float, float]]  # (ref_s, tgt_s) pairs, ascending
+    offset_ms: float  # Median of ref - tgt over the path
+    slope: float  # Least-squares d(tgt)/d(ref); 1.0 = no stretch
+    mean_cost: float  # Mean cosine distance along the path (0 = identical)
+    band_ms: float
+    frame_ms: float
+
+    @property
+    def stretch_ppm(self) -> float:
+        return (self.slope - 1.0) * 1e6
+
+    def delay_curve(self, step_s: float) -> list[tuple[float, float]]:
+        """(ref_s, delay_ms) sampled every ``step_s`` along the path.
+
+        Suitable as input for piecewise (segmented) delay correction.
+        """
+        if not self.path:
+            return []
+        ref = np.array([p[0] for p in self.path])
+        delay = np.array([(p[0] - p[1]) * 1000.0 for p in self.path])
+        times = np.arange(ref[0], ref[-1] + 1e-9, step_s)
+        curve = np.interp(times, ref, delay)
+        return [(float(t), float(d)) for t, d in zip(times, curve)]
+
+
+def _band_edges(sr: int, n_fft: int) -> list[tuple[int, int]]:
+    freqs = np.geomspace(_F_MIN, min(_F_MAX, sr / 2.0), _N_BANDS + 1)
+    bins = np.clip(np.round(freqs * n_fft / sr).astype(int), 1, n_fft // 2)
+    return [(int(lo), max(int(hi), int(lo) + 1)) for lo, hi in zip(bins, bins[1:])]
+
+
+def dtw_features(
+    pcm: np.ndarray, sr: int, frame_ms: float = DTW_FRAME_MS
+) -> np.ndarray:
+    """Per-frame log band energies, shape ``(frames, bands)``, unit length."""
+    hop = max(1, int(round(sr * frame_ms / 1000.0)))
+    n_fft = 1 << (hop - 1).bit_length()
+    n_frames = max(0, (len(pcm) - n_fft) // hop + 1)
+    if n_frames == 0:
+        return np.zeros((0, _N_BANDS), dtype=np.float32)
+
+    window = np.hanning(n_fft).astype(np.float32)
+    edges = _band_edges(sr, n_fft)
+    feats = np.empty((n_frames, _N_BANDS), dtype=np.float32)
+    # Process in blocks to bound the temporary FFT buffer
+    block = 4096
+    for start in range(0, n_frames, block):
+        idx = np.arange(start, min(start + block, n_frames))[:, None] * hop
+        frames = pcm[idx + np.arange(n_fft)[None, :]] * window
+        power = np.abs(np.fft.rfft(frames, axis=1)) ** 2
+        for b, (lo, hi) in enumerate(edges):
+            feats[start : start + len(idx), b] = np.log10(
+                power[:, lo:hi].mean(axis=1) + 1e-10
+            )
+
+    feats -= feats.mean(axis=1, keepdims=True)
+    norms = np.linalg.norm(feats, axis=1, keepdims=True)
+    return feats / np.maximum(norms, 1e-9)
+
+
+def dtw_align(
+    ref_pcm: np.ndarray,
+    tgt_pcm: np.ndarray,
+    sr: int,
+    band_ms: float,
+    center_delay_ms: float = 0.0,
+    start_pct: float = 5.0,
+    end_pct: float = 95.0,
+    frame_ms: float = DTW_FRAME_MS,
+) -> DtwResult | None:
+    """
+    Align the target to the reference with band-limited DTW.
+
+    Args:
+        ref_pcm: Reference audio (mono float32).
+        tgt_pcm: Target audio (mono float32).
+        sr: Sample rate in Hz.
+        band_ms: Sakoe-Chiba half-width around the expected path.
+        center_delay_ms: Expected delay (e.g. from correlation); the band
+            is centred on ``tgt = ref - center_delay``.
+        start_pct: Start of the reference scan range (% of duration).
+        end_pct: End of the reference scan range (% of duration).
+        frame_ms: Feature frame length.
+
+    Returns:
+        DtwResult, or None if the scan range does not overlap the target.
+    """
+    ref_feats = dtw_features(ref_pcm, sr, frame_ms)
+    tgt_feats = dtw_features(tgt_pcm, sr, frame_ms)
+    n_ref, n_tgt = len(ref_feats), len(tgt_feats)
+    shift = int(round(center_delay_ms / frame_ms))  # ref frame - tgt frame
+    width = max(1, int(round(band_ms / frame_ms)))
+
+    # Reference rows: the scan range, clipped so the band centre stays
+    # inside the target
+    duration = min(n_ref, n_tgt)
+    i0 = max(int(duration * start_pct / 100.0), shift)
+    i1 = min(int(duration * end_pct / 100.0), n_tgt + shift, n_ref)
+    rows = i1 - i0
+    if rows < 2:
+        return None
+
+    cols = 2 * width + 1
+    ptr = np.full((rows, cols), _START, dtype=np.int8)
+    lows = np.empty(rows, dtype=np.int64)
+    prev_lo, prev = 0, np.empty(0)
+
+    for r in range(rows):
+        i = i0 + r
+        lo = max(0, i - shift - width)
+        hi = min(n_tgt - 1, i - shift + width)
+        lows[r] = lo
+        js = np.arange(lo, hi + 1)
+        local = 1.0 - tgt_feats[lo : hi + 1] @ ref_feats[i]
+
+        if r == 0:
+            arrive = local.copy()  # Open begin anywhere in the first row
+            from_ptr = np.full(len(js), _START, dtype=np.int8)
+        else:
+            v_idx = js - prev_lo
+            d_idx = v_idx - 1
+            vert = np.where(
+                (v_idx >= 0) & (v_idx < len(prev)),
+                prev[np.clip(v_idx, 0, len(prev) - 1)],
+                np.inf,
+            )
+            diag = np.where(
+                (d_idx >= 0) & (d_idx < len(prev)),
+                prev[np.clip(d_idx, 0, len(prev) - 1)],
+                np.inf,
+            )
+            from_ptr = np.where(diag <= vert, _DIAG, _VERT).astype(np.int8)
+            arrive = local + np.minimum(diag, vert)
+
+        # Horizontal steps within the row:
+        # D[j] = min(arrive[j], D[j-1] + local[j])
+        #      = C[j] + min_{k<=j}(arrive[k] - C[k]),  C = cumsum(local)
+        csum = np.cumsum(local)
+        row = csum + np.minimum.accumulate(arrive - csum)
+        horizontal = row < arrive - 1e-9
+        from_ptr[horizontal] = _HORZ
+
+        ptr[r, : len(js)] = from_ptr
+        prev_lo, prev = lo, row
+
+    # Open end anywhere in the last row
+    end_col = int(np.argmin(prev))
+    if not np.isfinite(prev[end_col]):
+        return None
+
+    path_idx: list[tuple[int, int]] = []
+    r, c = rows - 1, end_col
+    while True:
+        j = int(lows[r]) + c
+        path_idx.append((i0 + r, j))
+        step = ptr[r, c]
+        if step == _START:
+            break
+        if step == _HORZ:
+            c -= 1
+            continue
+        r -= 1
+        j_prev = j - 1 if step == _DIAG else j
+        c = j_prev - int(lows[r])
+    path_idx.reverse()
+
+    frame_s = frame_ms / 1000.0
+    ref_t = np.array([p[0] for p in path_idx], dtype=np.float64) * frame_s
+    tgt_t = np.array([p[1] for p in path_idx], dtype=np.float64) * frame_s
+    slope = float(np.polyfit(ref_t, tgt_t, 1)[0]) if ref_t[-1] > ref_t[0] else 1.0
+
+    return DtwResult(
+        path=list(zip(ref_t.tolist(), tgt_t.tolist())),
+        offset_ms=float(np.median(ref_t - tgt_t) * 1000.0),
+        slope=slope,
+        mean_cost=float(prev[end_col] / len(path_idx)),
+        band_ms=band_ms,
+        frame_ms=frame_ms,
+    )
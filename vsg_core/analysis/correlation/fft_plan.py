@@ -0,0 +1,128 @@
+# vsg_core/analysis/correlation/fft_plan.py
+"""
+FFT plans for the waveform correlation methods.
+
+Every dense window has the same length, so every window's correlation uses
+the same transform size. An ``FftPlan`` holds what only depends on that
+size (and the sample rate): the 300 Hz - 6 kHz band mask of the GCC
+methods, and the FFT library's own plan for the size, created up front by
+a warm-up transform (torch keeps those per size, e.g. in
+``torch.backends.cuda.cufft_plan_cache``). Plans are cached by (size,
+sample rate, path, device) until ``cleanup_gpu``, so 20 same-size windows
+plan once and the other 19 reuse it.
+
+Two paths (``correlation_fft_mode``):
+  real    — rfft/irfft: half the spectrum, the faster default for real audio
+  complex — full fft/ifft, keeping the real part; about twice the work,
+            for cross-checking the real path or backends with a weak rfft
+
+``plan_stats`` counts plans built and reused since ``reset_plan_stats``;
+dense correlation logs it with the time spent building them. The counters
+are per thread: concurrent jobs each correlate on their own thread, so one
+job's reset or lookups don't show up in another job's line.
+"""
+
+from __future__ import annotations
+
+import threading
+import time
+from dataclasses import dataclass, replace
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from ...models.types import FftModeStr
+
+
+@dataclass(frozen=True, slots=True, eq=False)
+class FftPlan:
+    """Transforms and band mask for one correlation size."""
+
+    n_fft: int
+    mode: FftModeStr
+    band: Any  # torch bool tensor over the spectrum bins (bandpass_mask)
+
+    @classmethod
+    def build(cls, n_fft: int, sr: int, mode: FftModeStr, device: Any) -> FftPlan:
+        import torch
+
+        from .gpu_correlation import bandpass_mask
+
+        band = bandpass_mask(n_fft, sr, device=device, one_sided=(mode == "real"))
+        plan = cls(n_fft, mode, band)
+        # Warm-up so the library plans this size now, not in the first window
+        plan.inverse(plan.forward(torch.zeros(n_fft, device=device)))
+        return plan
+
+    def forward(self, x: Any) -> Any:
+        """Spectrum of ``x`` zero-padded to ``n_fft``."""
+        import torch
+
+        if self.mode == "complex":
+            return torch.fft.fft(x, n=self.n_fft)
+        return torch.fft.rfft(x, n=self.n_fft)
+
+    def inverse(self, spectrum: Any) -> Any:
+        """Real signal (length ``n_fft``) of a cross-spectrum."""
+        import torch
+
+        if self.mode == "complex":
+            return torch.fft.ifft(spectrum, n=self.n_fft).real
+        return torch.fft.irfft(spectrum, n=self.n_fft)
+
+
+@dataclass(slots=True)
+class PlanStats:
+    """Plans built and reused since the last ``reset_plan_stats``."""
+
+    built: int = 0
+    reused: int = 0
+    build_s: float = 0.0
+
+    def describe(self) -> str:
+        return (
+            f"FFT plans: {self.built} built in {self.build_s * 1000:.1f} ms, "
+            f"reused {self.reused}x"
+        )
+
+
+_local = threading.local()  # .stats: this thread's PlanStats
+
+
+def _stats() -> PlanStats:
+    stats = getattr(_local, "stats", None)
+    if stats is None:
+        stats = _local.stats = PlanStats()
+    return stats
+
+
+def get_fft_plan(n_fft: int, sr: int, mode: FftModeStr = "real") -> FftPlan:
+    """The cached plan for this size, built on first use."""
+    from .gpu_backend import get_cached, get_device
+
+    device = get_device()
+    key = ("fft_plan", n_fft, sr, mode, str(device))
+    t0 = time.perf_counter()
+    built = False
+
+    def build() -> FftPlan:
+        nonlocal built
+        built = True
+        return FftPlan.build(n_fft, sr, mode, device)
+
+    plan = get_cached(key, build)
+    stats = _stats()
+    if built:
+        stats.built += 1
+        stats.build_s += time.perf_counter() - t0
+    else:
+        stats.reused += 1
+    return plan
+
+
+def plan_stats() -> PlanStats:
+    """A copy of this thread's counters."""
+    return replace(_stats())
+
+
+def reset_plan_stats() -> None:
+    _local.stats = PlanStats()
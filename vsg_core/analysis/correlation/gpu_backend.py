@@ -11,8 +11,13 @@ Usage:
 
     device = get_device()
     ref_gpu = to_torch(ref_chunk, device)
-    # ... do GPU work ...
+    with gpu_in_use():
+        # ... do GPU work ...
     cleanup_gpu()  # call after job finishes
+
+Jobs of a batch run concurrently and share the cache. It is guarded by a
+lock, and ``cleanup_gpu`` leaves it (and device memory) alone while any
+job is still inside ``gpu_in_use``; the last job to finish releases it.
 """
 
 from __future__ import annotations
@@ -20,7 +25,12 @@ from __future__ import annotations
 import gc
 import logging
 import os
-from typing import Any
+import threading
+from contextlib import contextmanager
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from collections.abc import Callable, Iterator
 
 logger = logging.getLogger(__name__)
 
@@ -34,6 +44,8 @@ os.environ.setdefault("HIP_VISIBLE_DEVICES", "0")
 
 _device: Any = None  # torch.device, lazily initialized
 _transform_cache: dict[tuple, Any] = {}
+_lock = threading.RLock()  # Guards the three values here
+_in_use = 0  # Correlation runs currently inside gpu_in_use()
 
 
 # ── Device Management ──────────────────────────────────────────────────────
@@ -47,20 +59,21 @@ def get_device() -> Any:
     Caches the result for the process lifetime.
     """
     global _device
-    if _device is not None:
-        return _device
+    with _lock:
+        if _device is not None:
+            return _device
 
-    import torch
+        import torch
 
-    if torch.cuda.is_available():
-        _device = torch.device("cuda")
-        gpu_name = torch.cuda.get_device_name(0)
-        logger.info("GPU correlation backend: %s", gpu_name)
-    else:
-        _device = torch.device("cpu")
-        logger.info("GPU correlation backend: CPU fallback (no CUDA)")
+        if torch.cuda.is_available():
+            _device = torch.device("cuda")
+            gpu_name = torch.cuda.get_device_name(0)
+            logger.info("GPU correlation backend: %s", gpu_name)
+        else:
+            _device = torch.device("cpu")
+            logger.info("GPU correlation backend: CPU fallback (no CUDA)")
 
-    return _device
+        return _device
 
 
 def to_torch(arr: Any, device: Any | None = None) -> Any:
@@ -94,18 +107,16 @@ def get_spectrogram_transform(
 
     Creates on first call with these parameters, reuses afterwards.
     """
-    key = ("spectrogram", n_fft, hop_length, power)
-    if key not in _transform_cache:
+    def build() -> Any:
         import torchaudio
 
-        device = get_device()
-        _transform_cache[key] = torchaudio.transforms.Spectrogram(
+        return torchaudio.transforms.Spectrogram(
             n_fft=n_fft,
             hop_length=hop_length,
             power=power,
-        ).to(device)
+        ).to(get_device())
 
-    return _transform_cache[key]
+    return get_cached(("spectrogram", n_fft, hop_length, power), build)
 
 
 def get_mel_spectrogram_transform(
@@ -120,50 +131,81 @@ def get_mel_spectrogram_transform(
 
     Creates on first call with these parameters, reuses afterwards.
     """
-    key = ("melspectrogram", sample_rate, n_fft, hop_length, n_mels, power)
-    if key not in _transform_cache:
+    def build() -> Any:
         import torchaudio
 
-        device = get_device()
-        _transform_cache[key] = torchaudio.transforms.MelSpectrogram(
+        return torchaudio.transforms.MelSpectrogram(
             sample_rate=sample_rate,
             n_fft=n_fft,
             hop_length=hop_length,
             n_mels=n_mels,
             power=power,
-        ).to(device)
+        ).to(get_device())
+
+    key = ("melspectrogram", sample_rate, n_fft, hop_length, n_mels, power)
+    return get_cached(key, build)
 
-    return _transform_cache[key]
+
+def get_cached(key: tuple, build: Callable[[], Any]) -> Any:
+    """
+    Get a cached GPU resource (e.g. an FFT plan), calling ``build`` on a miss.
+
+    Released by ``cleanup_gpu`` like the transforms above. Built under
+    the lock, so concurrent jobs asking for the same key build it once.
+    """
+    with _lock:
+        if key not in _transform_cache:
+            _transform_cache[key] = build()
+        return _transform_cache[key]
 
 
 # ── Cleanup ────────────────────────────────────────────────────────────────
 
 
-def cleanup_gpu() -> None:
+@contextmanager
+def gpu_in_use() -> Iterator[None]:
+    """Marks a correlation run using the cache; ``cleanup_gpu`` waits for it."""
+    global _in_use
+    with _lock:
+        _in_use += 1
+    try:
+        yield
+    finally:
+        with _lock:
+            _in_use -= 1
+
+
+def cleanup_gpu() -> bool:
     """
-    Release all cached GPU resources.
+    Release all cached GPU resources, unless another job still uses them.
 
     Call this after each job's correlation finishes to prevent
     GPU memory accumulation across jobs. Works with both CUDA
     and ROCm (HIP) backends — PyTorch maps cuda API to HIP.
+
+    Returns whether anything was released: while another job is inside
+    ``gpu_in_use`` the cache is kept, and that job's own call releases it.
     """
-    global _transform_cache
+    with _lock:
+        if _in_use:
+            return False
 
-    # Clear cached torchaudio transforms (hold GPU memory)
-    _transform_cache.clear()
+        # Clear cached torchaudio transforms and FFT plans (hold GPU memory)
+        _transform_cache.clear()
 
-    # GC first so Python drops tensor references before we free GPU memory
-    gc.collect()
+        # GC first so Python drops tensor references before we free GPU memory
+        gc.collect()
 
-    try:
-        import torch
+        try:
+            import torch
 
-        if torch.cuda.is_available():
-            torch.cuda.synchronize()
-            torch.cuda.empty_cache()
-            # Reset peak memory tracking for monitoring
-            torch.cuda.reset_peak_memory_stats()
-    except ImportError:
-        pass
-
-    gc.collect()
+            if torch.cuda.is_available():
+                torch.cuda.synchronize()
+                torch.cuda.empty_cache()
+                # Reset peak memory tracking for monitoring
+                torch.cuda.reset_peak_memory_stats()
+        except ImportError:
+            pass
+
+        gc.collect()
+        return True
@@ -9,8 +9,17 @@ confidence.py and the peak extraction logic in each method.
 
 from __future__ import annotations
 
+from typing import TYPE_CHECKING
+
 import torch
 
+from .confidence import psr_to_pct, score_confidence
+from .lag_window import circular_to_lag
+from .peak_interp import DEFAULT_SINC_TAPS, refine_peak
+
+if TYPE_CHECKING:
+    from ...models.types import ConfidenceMetricStr, PeakInterpStr
+
 
 def bandpass_mask(
     n_fft: int,
@@ -18,9 +27,10 @@ def bandpass_mask(
     lo_hz: float = 300.0,
     hi_hz: float = 6000.0,
     device: torch.device | str | None = None,
+    one_sided: bool = True,
 ) -> torch.Tensor:
     """
-    Create a frequency-domain bandpass mask for rfft output.
+    Create a frequency-domain bandpass mask for rfft (or fft) output.
 
     Zeroes out bins below lo_hz and above hi_hz to remove frequencies
     with ambiguous phase that cause false peaks in phase-only methods
@@ -32,11 +42,16 @@ def bandpass_mask(
         lo_hz: Lower cutoff frequency (default 300 Hz).
         hi_hz: Upper cutoff frequency (default 6000 Hz).
         device: Torch device for the output tensor.
+        one_sided: False for full fft output (both signs of frequency).
 
     Returns:
-        Boolean tensor of shape (n_fft // 2 + 1,) — True for bins to keep.
+        Boolean tensor of shape (n_fft // 2 + 1,), or (n_fft,) when not
+        one-sided — True for bins to keep.
     """
-    freqs = torch.fft.rfftfreq(n_fft, 1.0 / sr, device=device)
+    if one_sided:
+        freqs = torch.fft.rfftfreq(n_fft, 1.0 / sr, device=device)
+    else:
+        freqs = torch.fft.fftfreq(n_fft, 1.0 / sr, device=device).abs()
     return (freqs >= lo_hz) & (freqs <= hi_hz)
 
 
@@ -45,47 +60,100 @@ def extract_peak(
     n_fft: int,
     sr: int,
     peak_fit: bool = False,
+    interp: PeakInterpStr = "none",
+    sinc_taps: int = DEFAULT_SINC_TAPS,
+    max_lag_samples: int = 0,
+    ref_len: int | None = None,
+    tgt_len: int | None = None,
 ) -> tuple[float, int]:
     """
     Extract delay and peak index from a waveform-domain correlation.
 
-    Searches the full lag range (no max_delay restriction) for the
-    strongest peak. Confidence is computed separately by each method
-    using the returned peak_index.
+    Searches every lag at which the windows overlap for the strongest peak,
+    or only lags within +/- ``max_lag_samples`` when set (see
+    ``peak_outside_window``). Confidence is computed separately by each
+    method using the returned peak_index.
 
     Args:
         corr: Correlation result from irfft (length n_fft).
         n_fft: FFT size used.
         sr: Sample rate in Hz.
-        peak_fit: If True, apply parabolic sub-sample interpolation.
+        peak_fit: Legacy switch for quadratic interpolation (used when
+            ``interp`` is "none").
+        interp: Sub-sample peak interpolation method.
+        sinc_taps: Samples on each side of the peak for sinc interpolation.
+        max_lag_samples: Largest |lag| searched; 0 = the full range.
+        ref_len, tgt_len: Window lengths in samples, for the signed lag of
+            a circular index (see ``lag_window.circular_to_lag``); None =
+            equal windows.
 
     Returns:
         (delay_ms, peak_index) — delay in ms (raw float) and the
         peak index in the correlation array for confidence scoring.
     """
     n = n_fft
-    abs_corr = torch.abs(corr)
+    abs_corr = _searchable(corr, n, ref_len, tgt_len)
+    if 0 < max_lag_samples < n // 2:
+        # Lags max_lag+1 .. n-max_lag-1 (circular index) are out of bounds
+        abs_corr[max_lag_samples + 1 : n - max_lag_samples] = 0
     k = torch.argmax(abs_corr).item()
 
     # Convert circular index to signed lag
-    lag_samples = float(k if k <= n // 2 else k - n)
-
-    # Parabolic (sub-sample) peak fitting
-    if peak_fit and 0 < k < len(abs_corr) - 1:
-        y1 = abs_corr[k - 1].item()
-        y2 = abs_corr[k].item()
-        y3 = abs_corr[k + 1].item()
-        denom = y1 - 2.0 * y2 + y3
-        if abs(denom) > 1e-12:
-            delta = 0.5 * (y1 - y3) / denom
-            if -1.0 < delta < 1.0:
-                lag_samples += delta
+    lag_samples = float(circular_to_lag(k, n, ref_len))
+
+    if interp == "none" and peak_fit:
+        interp = "quadratic"
+
+    # Sub-sample peak refinement. The correlation is circular, so the
+    # neighbourhood wraps around the array ends. Signed values (flipped so
+    # the peak is positive) keep the curve band-limited for sinc; taking
+    # abs() would fold the side lobes.
+    if interp != "none":
+        radius = max(1, sinc_taps) if interp == "sinc" else 1
+        idx = torch.arange(k - radius, k + radius + 1, device=corr.device) % n
+        neighbourhood = (corr[idx] * torch.sign(corr[k])).tolist()
+        lag_samples += refine_peak(neighbourhood, radius, interp, sinc_taps)
 
     delay_ms = lag_samples / float(sr) * 1000.0
 
     return delay_ms, k
 
 
+def _searchable(
+    corr: torch.Tensor, n_fft: int, ref_len: int | None, tgt_len: int | None
+) -> torch.Tensor:
+    """
+    |corr| with the circular indices that match no lag zeroed.
+
+    Zero-padding to ``linear_fft_size`` keeps the correlation linear: indices
+    0 .. ref_len-1 are lags 0 .. ref_len-1, the last tgt_len-1 indices are
+    lags -(tgt_len-1) .. -1, and the band between them (no overlap at all)
+    is zero for plain correlation but not after spectral weighting (PHAT,
+    SCOT, whitening), where it could otherwise win the argmax.
+    """
+    abs_corr = torch.abs(corr).clone()
+    if ref_len is not None and tgt_len is not None:
+        abs_corr[ref_len : max(ref_len, n_fft - tgt_len + 1)] = 0
+    return abs_corr
+
+
+def peak_outside_window(
+    corr: torch.Tensor,
+    n_fft: int,
+    max_lag_samples: int,
+    ref_len: int | None = None,
+    tgt_len: int | None = None,
+) -> bool:
+    """
+    True when the strongest peak over all lags lies beyond +/-
+    ``max_lag_samples``: the bounded peak is then a sidelobe, not the delay.
+    """
+    if not 0 < max_lag_samples < n_fft // 2:
+        return False
+    k = torch.argmax(_searchable(corr, n_fft, ref_len, tgt_len)).item()
+    return abs(circular_to_lag(k, n_fft, ref_len)) > max_lag_samples
+
+
 def scc_confidence(
     corr: torch.Tensor,
     peak_idx: int,
@@ -158,12 +226,30 @@ def psr_confidence(
     # Map PSR to 0-100 confidence scale
     # Noise floor is PSR ~8-10 for 10s windows at 48kHz.
     # PSR <= 10: noise (0%), PSR 15: borderline (50%), PSR >= 20: confident (100%)
-    if psr <= 10.0:
-        return 0.0
-    if psr >= 20.0:
-        return 100.0
-    # Linear interpolation between 10 and 20
-    return (psr - 10.0) / 10.0 * 100.0
+    return psr_to_pct(psr)
+
+
+def metric_confidence(
+    metric: ConfidenceMetricStr,
+    corr: torch.Tensor,
+    peak_idx: int,
+    spectrum: torch.Tensor,
+    n_fft: int,
+) -> float:
+    """
+    Confidence of a waveform-domain peak under a shared metric.
+
+    ``spectrum`` is the (weighted) cross-spectrum ``corr`` was computed
+    from. By the triangle inequality no lag of its irfft can exceed
+    (|G0| + 2 * sum|Gk| + |G_last|) / n_fft (sum|Gk| / n_fft for a full
+    fft spectrum), which is the bound ``normalized_peak`` measures against.
+    """
+    abs_spec = torch.abs(spectrum)
+    if abs_spec.shape[0] == n_fft:  # Full spectrum (complex FFT path)
+        bound = abs_spec.sum().item() / n_fft
+    else:
+        bound = (2.0 * abs_spec.sum() - abs_spec[0] - abs_spec[-1]).item() / n_fft
+    return score_confidence(metric, corr.detach().cpu().numpy(), peak_idx, bound)
 
 
 def extract_peak_feature(
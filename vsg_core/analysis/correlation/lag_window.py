@@ -0,0 +1,79 @@
+# vsg_core/analysis/correlation/lag_window.py
+"""
+Lag bookkeeping for the FFT correlations.
+
+FFT correlation is circular: a lag near the window length wraps around
+and reads as a lag of the opposite sign unless both windows are
+zero-padded to at least ``len(ref) + len(tgt) - 1`` samples
+(``linear_fft_size``). With that padding, ``circular_to_lag`` maps each
+index of the result back to its one signed lag, also for windows of
+different lengths (splitting the array at ``n_fft / 2`` only works for
+equal ones).
+
+Without a bound, SCC and the GCC methods take the peak from every lag the
+windows allow: up to the window length either way.
+``correlation_max_lag_ms`` narrows that to +/- the given delay when the
+offset is known to be small, dropping false peaks far from any plausible
+offset. Delays longer than the window need a
+larger analysis window, not a larger bound.
+
+A window whose true peak lies outside the bound is not answered with the
+best peak inside it (an unrelated sidelobe that looks like a valid small
+delay): its confidence is set to 0 so it is rejected as out of range
+(``out_of_range_delay`` tells those apart from plain low-match windows).
+
+Only SCC and the GCC methods take a bound (``max_lag_ms``, set by
+``configure_method``); the feature-domain methods (Onset, Spectrogram)
+have none.
+"""
+
+from __future__ import annotations
+
+from dataclasses import replace
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    import numpy as np
+
+    from .registry import CorrelationMethod
+
+
+def linear_fft_size(ref_len: int, tgt_len: int) -> int:
+    """Smallest power of two holding the full linear correlation."""
+    n = ref_len + tgt_len - 1
+    return 1 << (n - 1).bit_length()
+
+
+def circular_to_lag(k: int, n_fft: int, ref_len: int | None = None) -> int:
+    """
+    Signed lag (``ref[i + lag]`` pairs with ``tgt[i]``) of circular index
+    ``k`` of a linear correlation: indices below ``ref_len`` are positive
+    lags, the rest negative. ``ref_len`` None assumes equal windows.
+    """
+    split = n_fft // 2 + 1 if ref_len is None else ref_len
+    return k if k < split else k - n_fft
+
+
+def lag_limit_samples(max_lag_ms: float, sr: int) -> int:
+    """``max_lag_ms`` in samples; 0 means no bound."""
+    if max_lag_ms <= 0:
+        return 0
+    return max(1, round(max_lag_ms * sr / 1000.0))
+
+
+def out_of_range_delay(
+    method: CorrelationMethod, ref: np.ndarray, tgt: np.ndarray, sr: int
+) -> float | None:
+    """
+    For a window a bounded method rejected (confidence 0): the delay of its
+    peak over every lag when that lies beyond the bound, else None.
+
+    Costs one more correlation, so only call it for rejected windows.
+    """
+    max_lag_ms = getattr(method, "max_lag_ms", 0.0)
+    if max_lag_ms <= 0:
+        return None
+    delay_ms, _ = replace(  # type: ignore[type-var]
+        method, max_lag_ms=0.0
+    ).find_delay(ref, tgt, sr)
+    return delay_ms if abs(delay_ms) > max_lag_ms else None
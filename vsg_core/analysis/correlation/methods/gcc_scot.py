@@ -4,9 +4,19 @@
 from __future__ import annotations
 
 from dataclasses import dataclass
+from typing import TYPE_CHECKING
 
 import numpy as np
 
+from ..peak_interp import DEFAULT_SINC_TAPS
+
+if TYPE_CHECKING:
+    from ....models.types import (
+        ConfidenceMetricStr,
+        FftModeStr,
+        PeakInterpStr,
+    )
+
 
 @dataclass(frozen=True, slots=True)
 class GccScot:
@@ -19,6 +29,11 @@ class GccScot:
 
     name: str = "GCC-SCOT"
     config_key: str = "multi_corr_gcc_scot"
+    peak_interp: PeakInterpStr = "none"
+    sinc_taps: int = DEFAULT_SINC_TAPS
+    confidence_metric: ConfidenceMetricStr = "native"
+    max_lag_ms: float = 0.0  # 0 = every lag the window allows (lag_window.py)
+    fft_mode: FftModeStr = "real"  # rfft or full fft path (fft_plan.py)
 
     def find_delay(
         self,
@@ -28,22 +43,30 @@ class GccScot:
     ) -> tuple[float, float]:
         import torch
 
+        from ..fft_plan import get_fft_plan
         from ..gpu_backend import get_device, to_torch
-        from ..gpu_correlation import bandpass_mask, extract_peak, psr_confidence
+        from ..gpu_correlation import (
+            extract_peak,
+            metric_confidence,
+            peak_outside_window,
+            psr_confidence,
+        )
+        from ..lag_window import lag_limit_samples, linear_fft_size
 
         device = get_device()
         ref = to_torch(ref_chunk, device)
         tgt = to_torch(tgt_chunk, device)
 
-        n = ref.shape[0] + tgt.shape[0] - 1
-        n_fft = 1 << (n - 1).bit_length()
+        # Padded for a linear (not circular) correlation
+        n_fft = linear_fft_size(ref.shape[0], tgt.shape[0])
+        plan = get_fft_plan(n_fft, sr, self.fft_mode)
 
-        R = torch.fft.rfft(ref, n=n_fft)
-        T = torch.fft.rfft(tgt, n=n_fft)
+        R = plan.forward(ref)
+        T = plan.forward(tgt)
         G = R * torch.conj(T)
 
         # Bandpass 300Hz-6kHz: remove bins with ambiguous phase
-        bp = bandpass_mask(n_fft, sr, device=device)
+        bp = plan.band
         G[~bp] = 0
 
         # SCOT weighting: normalize by geometric mean of auto-spectra
@@ -53,9 +76,28 @@ class GccScot:
 
         G_scot = G / scot_weight
         G_scot[~bp] = 0  # Re-zero filtered bins after normalization
-        corr = torch.fft.irfft(G_scot, n=n_fft)
-
-        delay_ms, peak_idx = extract_peak(corr, n_fft, sr)
-        confidence = psr_confidence(corr, peak_idx)
+        corr = plan.inverse(G_scot)
+
+        max_lag = lag_limit_samples(self.max_lag_ms, sr)
+        delay_ms, peak_idx = extract_peak(
+            corr,
+            n_fft,
+            sr,
+            interp=self.peak_interp,
+            sinc_taps=self.sinc_taps,
+            max_lag_samples=max_lag,
+            ref_len=ref.shape[0],
+            tgt_len=tgt.shape[0],
+        )
+        if self.confidence_metric == "native":
+            confidence = psr_confidence(corr, peak_idx)
+        else:
+            confidence = metric_confidence(
+                self.confidence_metric, corr, peak_idx, G_scot, n_fft
+            )
+        if peak_outside_window(
+            corr, n_fft, max_lag, ref.shape[0], tgt.shape[0]
+        ):
+            confidence = 0.0  # Out of range: rejected, not a sidelobe's delay
 
         return delay_ms, confidence
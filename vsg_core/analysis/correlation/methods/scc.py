@@ -4,17 +4,32 @@
 from __future__ import annotations
 
 from dataclasses import dataclass
+from typing import TYPE_CHECKING
 
 import numpy as np
 
+from ..peak_interp import DEFAULT_SINC_TAPS
+
+if TYPE_CHECKING:
+    from ....models.types import (
+        ConfidenceMetricStr,
+        FftModeStr,
+        PeakInterpStr,
+    )
+
 
 @dataclass(frozen=True, slots=True)
 class Scc:
-    """Standard cross-correlation with optional parabolic peak fitting."""
+    """Standard cross-correlation with optional sub-sample peak interpolation."""
 
     name: str = "Standard Correlation (SCC)"
     config_key: str = "multi_corr_scc"
-    peak_fit: bool = False
+    peak_fit: bool = False  # Legacy: quadratic when peak_interp is "none"
+    peak_interp: PeakInterpStr = "none"
+    sinc_taps: int = DEFAULT_SINC_TAPS
+    confidence_metric: ConfidenceMetricStr = "native"
+    max_lag_ms: float = 0.0  # 0 = every lag the window allows (lag_window.py)
+    fft_mode: FftModeStr = "real"  # rfft or full fft path (fft_plan.py)
 
     def find_delay(
         self,
@@ -24,8 +39,15 @@ class Scc:
     ) -> tuple[float, float]:
         import torch
 
+        from ..fft_plan import get_fft_plan
         from ..gpu_backend import get_device, to_torch
-        from ..gpu_correlation import extract_peak, scc_confidence
+        from ..gpu_correlation import (
+            extract_peak,
+            metric_confidence,
+            peak_outside_window,
+            scc_confidence,
+        )
+        from ..lag_window import lag_limit_samples, linear_fft_size
 
         device = get_device()
         ref = to_torch(ref_chunk, device)
@@ -35,18 +57,36 @@ class Scc:
         ref_n = (ref - torch.mean(ref)) / (torch.std(ref) + 1e-9)
         tgt_n = (tgt - torch.mean(tgt)) / (torch.std(tgt) + 1e-9)
 
-        # Cross-correlation via FFT
-        n = ref_n.shape[0] + tgt_n.shape[0] - 1
-        n_fft = 1 << (n - 1).bit_length()
+        # Cross-correlation via FFT, padded to be linear (not circular)
+        n_fft = linear_fft_size(ref_n.shape[0], tgt_n.shape[0])
+        plan = get_fft_plan(n_fft, sr, self.fft_mode)
 
-        R = torch.fft.rfft(ref_n, n=n_fft)
-        T = torch.fft.rfft(tgt_n, n=n_fft)
+        R = plan.forward(ref_n)
+        T = plan.forward(tgt_n)
         G = R * torch.conj(T)
-        corr = torch.fft.irfft(G, n=n_fft)
+        corr = plan.inverse(G)
 
+        max_lag = lag_limit_samples(self.max_lag_ms, sr)
         delay_ms, peak_idx = extract_peak(
-            corr, n_fft, sr, peak_fit=self.peak_fit,
+            corr,
+            n_fft,
+            sr,
+            peak_fit=self.peak_fit,
+            interp=self.peak_interp,
+            sinc_taps=self.sinc_taps,
+            max_lag_samples=max_lag,
+            ref_len=ref_n.shape[0],
+            tgt_len=tgt_n.shape[0],
         )
-        confidence = scc_confidence(corr, peak_idx, ref_n, tgt_n)
+        if self.confidence_metric == "native":
+            confidence = scc_confidence(corr, peak_idx, ref_n, tgt_n)
+        else:
+            confidence = metric_confidence(
+                self.confidence_metric, corr, peak_idx, G, n_fft
+            )
+        if peak_outside_window(
+            corr, n_fft, max_lag, ref_n.shape[0], tgt_n.shape[0]
+        ):
+            confidence = 0.0  # Out of range: rejected, not a sidelobe's delay
 
         return delay_ms, confidence
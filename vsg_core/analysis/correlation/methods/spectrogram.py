@@ -4,9 +4,20 @@
 from __future__ import annotations
 
 from dataclasses import dataclass
+from typing import TYPE_CHECKING
 
 import numpy as np
 
+if TYPE_CHECKING:
+    from ....models.settings import AppSettings
+
+# Sane parameter bounds. The FFT size must be a power of two; more mel
+# bands than fft_size / 4 leaves some filters without a single FFT bin.
+FFT_SIZES = (256, 512, 1024, 2048, 4096, 8192, 16384)
+MIN_N_MELS = 8
+MAX_N_MELS = 256
+MIN_HOP_LENGTH = 32
+
 
 @dataclass(frozen=True, slots=True)
 class SpectrogramCorrelation:
@@ -16,11 +27,53 @@ class SpectrogramCorrelation:
     Captures both frequency and time structure, making it robust
     to some types of audio differences while maintaining time precision.
 
+    Each mel band's log-energy envelope is correlated separately and the
+    cross-spectra are summed before PHAT weighting, so a transient in a
+    few bands isn't smeared by averaging it with the rest.
+
+    The delay is found in whole spectrogram frames: the time resolution is
+    ``hop_length / sr`` (10.7 ms at the defaults and 48 kHz). A smaller hop
+    gives a proportionally finer result at a proportionally higher cost;
+    ``fft_size`` and ``n_mels`` trade frequency detail for speed.
+
     Uses torchaudio MelSpectrogram on GPU — no librosa dependency.
     """
 
     name: str = "Spectrogram Correlation"
     config_key: str = "multi_corr_spectrogram"
+    n_mels: int = 64
+    fft_size: int = 2048
+    hop_length: int = 512
+
+    def __post_init__(self) -> None:
+        if self.fft_size not in FFT_SIZES:
+            raise ValueError(
+                f"Spectrogram FFT size must be a power of two from "
+                f"{FFT_SIZES[0]} to {FFT_SIZES[-1]}, got {self.fft_size}"
+            )
+        if not MIN_HOP_LENGTH <= self.hop_length <= self.fft_size:
+            raise ValueError(
+                f"Spectrogram hop length must be {MIN_HOP_LENGTH}-{self.fft_size} "
+                f"samples (at most the FFT size), got {self.hop_length}"
+            )
+        max_mels = min(MAX_N_MELS, self.fft_size // 4)
+        if not MIN_N_MELS <= self.n_mels <= max_mels:
+            raise ValueError(
+                f"Spectrogram mel bands must be {MIN_N_MELS}-{max_mels} for an "
+                f"FFT size of {self.fft_size}, got {self.n_mels}"
+            )
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> SpectrogramCorrelation:
+        return cls(
+            n_mels=settings.spectrogram_n_mels,
+            fft_size=settings.spectrogram_fft_size,
+            hop_length=settings.spectrogram_hop_length,
+        )
+
+    def resolution_ms(self, sr: int) -> float:
+        """Time step of the detected delay (one spectrogram frame)."""
+        return self.hop_length / sr * 1000.0
 
     def find_delay(
         self,
@@ -33,17 +86,14 @@ class SpectrogramCorrelation:
         from ..gpu_backend import get_device, get_mel_spectrogram_transform, to_torch
         from ..gpu_correlation import extract_peak_feature
 
-        hop_length = 512
-        n_mels = 64
-
         device = get_device()
         ref = to_torch(ref_chunk, device)
         tgt = to_torch(tgt_chunk, device)
 
         # Compute mel spectrograms using cached torchaudio transform
         mel_transform = get_mel_spectrogram_transform(
-            sample_rate=sr, n_fft=2048, hop_length=hop_length,
-            n_mels=n_mels, power=2.0,
+            sample_rate=sr, n_fft=self.fft_size, hop_length=self.hop_length,
+            n_mels=self.n_mels, power=2.0,
         )
 
         ref_mel = mel_transform(ref)  # shape: (n_mels, n_frames)
@@ -53,31 +103,27 @@ class SpectrogramCorrelation:
         ref_db = 10.0 * torch.log10(ref_mel.clamp(min=1e-10))
         tgt_db = 10.0 * torch.log10(tgt_mel.clamp(min=1e-10))
 
-        # Normalize per-signal to max = 0 dB (like librosa's ref=np.max)
-        ref_db = ref_db - ref_db.max()
-        tgt_db = tgt_db - tgt_db.max()
-
-        # Average across mel bands → time-series
-        ref_flat = ref_db.mean(dim=0)
-        tgt_flat = tgt_db.mean(dim=0)
-
-        # Normalize (zero-mean, unit-variance)
-        ref_norm = (ref_flat - torch.mean(ref_flat)) / (torch.std(ref_flat) + 1e-9)
-        tgt_norm = (tgt_flat - torch.mean(tgt_flat)) / (torch.std(tgt_flat) + 1e-9)
+        # Normalize each band over time (zero-mean, unit-variance)
+        ref_norm = (ref_db - ref_db.mean(dim=1, keepdim=True)) / (
+            ref_db.std(dim=1, keepdim=True) + 1e-9
+        )
+        tgt_norm = (tgt_db - tgt_db.mean(dim=1, keepdim=True)) / (
+            tgt_db.std(dim=1, keepdim=True) + 1e-9
+        )
 
         # Feature-domain parameters
-        frame_sr = sr / hop_length  # ~93.75 Hz
+        frame_sr = sr / self.hop_length
         # No max_delay restriction for chunked mode — search the full range
-        n_frames = min(len(ref_norm), len(tgt_norm))
+        n_frames = min(ref_norm.shape[1], tgt_norm.shape[1])
         max_delay_frames = n_frames // 2
 
-        # GCC-PHAT on mel time-series
-        n = ref_norm.shape[0] + tgt_norm.shape[0] - 1
+        # GCC-PHAT on the summed per-band cross-spectra
+        n = ref_norm.shape[1] + tgt_norm.shape[1] - 1
         n_fft = 1 << (n - 1).bit_length()
 
-        R = torch.fft.rfft(ref_norm, n=n_fft)
-        T = torch.fft.rfft(tgt_norm, n=n_fft)
-        G = R * torch.conj(T)
+        R = torch.fft.rfft(ref_norm, n=n_fft, dim=1)
+        T = torch.fft.rfft(tgt_norm, n=n_fft, dim=1)
+        G = (R * torch.conj(T)).sum(dim=0)
         G_phat = G / (torch.abs(G) + 1e-9)
         corr = torch.fft.irfft(G_phat, n=n_fft)
 
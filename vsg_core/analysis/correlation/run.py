@@ -3,20 +3,52 @@
 Correlation method resolution.
 
 Provides _resolve_method() for modules that need to pick the correct
-correlation plugin based on settings (e.g. stepping correction QA checks).
+correlation plugin based on settings (e.g. stepping correction QA checks),
+and configure_method() to apply the waveform settings to a plugin.
 """
 
 from __future__ import annotations
 
+from dataclasses import fields, replace
 from typing import TYPE_CHECKING
 
 from .methods.scc import Scc
+from .methods.spectrogram import SpectrogramCorrelation
 from .registry import get_method
 
 if TYPE_CHECKING:
     from ...models.settings import AppSettings
     from .registry import CorrelationMethod
 
+# Method plugin field -> the setting it is configured from
+_METHOD_SETTINGS = {
+    "peak_interp": "peak_interpolation",
+    "sinc_taps": "peak_sinc_taps",
+    "confidence_metric": "correlation_confidence_metric",
+    "max_lag_ms": "correlation_max_lag_ms",
+    "fft_mode": "correlation_fft_mode",
+}
+
+
+def configure_method(
+    method: CorrelationMethod, settings: AppSettings
+) -> CorrelationMethod:
+    """Copy of a method plugin with every field above it has set from settings.
+
+    Feature-domain methods have none of them and are returned unchanged.
+    The legacy ``audio_peak_fit`` is not mapped here: it only ever applied
+    to SCC, which keeps it in its own ``peak_fit``.
+    """
+    names = {f.name for f in fields(method)}  # type: ignore[arg-type]
+    changes = {
+        name: getattr(settings, key)
+        for name, key in _METHOD_SETTINGS.items()
+        if name in names
+    }
+    if not changes:
+        return method
+    return replace(method, **changes)  # type: ignore[type-var]
+
 
 def _resolve_method(
     settings: AppSettings, *, source_separated: bool
@@ -28,5 +60,9 @@ def _resolve_method(
         else settings.correlation_method
     )
     if "Standard Correlation" in method_name or "SCC" in method_name:
-        return Scc(peak_fit=settings.audio_peak_fit)
-    return get_method(method_name)
+        method = Scc(peak_fit=settings.audio_peak_fit)
+    else:
+        method = get_method(method_name)
+    if isinstance(method, SpectrogramCorrelation):
+        return SpectrogramCorrelation.from_settings(settings)
+    return configure_method(method, settings)
@@ -0,0 +1,120 @@
+# vsg_core/analysis/correlation/windowed.py
+"""
+Windowed (seek-based) decoding for sparse window layouts.
+
+With a hop larger than the window, the dense runner only ever reads a
+fraction of each track, yet ``decode_audio`` holds the whole track in
+memory (~2 GB for 3 hours at 48 kHz). ``decode_windows`` instead seeks to
+each window with ``ffmpeg -ss`` and decodes only the window plus a guard
+on both sides.
+
+The result is a ``WindowedPcm``: it has the full track's ``len()`` and
+answers the exact ``pcm[pos:pos + window]`` slices the runner asks for, so
+``run_dense_correlation`` works on it unchanged. Anything that needs the
+whole signal (silence avoidance, source separation, DTW) can't use it.
+
+ffmpeg places the seek using packet timestamps, so a window lines up with
+the full decode to the sample only when those are exact (PCM, or frame
+durations that are whole milliseconds in Matroska such as AC3 or FLAC at
+48 kHz). Otherwise each window may be off by up to the timestamp
+rounding (0.5 ms in Matroska).
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING
+
+import numpy as np
+
+from .decode import WINDOW_GUARD_S, decode_window
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from vsg_core.io.runner import CommandRunner
+    from vsg_core.models.types import DownmixModeStr
+
+
+@dataclass(frozen=True, slots=True)
+class _Segment:
+    pcm: np.ndarray  # Window plus guard on both sides
+    lead: int  # Samples of guard before the window
+
+
+@dataclass(slots=True)
+class WindowedPcm:
+    """Decoded audio for a fixed set of windows, sliceable like the track."""
+
+    length: int  # Samples in the full track
+    window_samples: int
+    segments: dict[int, _Segment] = field(default_factory=dict)
+
+    def __len__(self) -> int:
+        return self.length
+
+    def __getitem__(self, key: slice) -> np.ndarray:
+        start = key.start or 0
+        stop = self.length if key.stop is None else key.stop
+        seg = self.segments.get(start)
+        if seg is None or stop - start > self.window_samples:
+            raise KeyError(f"Samples {start}:{stop} were not decoded")
+        out = seg.pcm[seg.lead : seg.lead + (stop - start)]
+        if len(out) < stop - start:
+            # Decoder stopped a few samples short at the end of the file
+            out = np.pad(out, (0, stop - start - len(out)))
+        return out
+
+    @property
+    def nbytes(self) -> int:
+        return sum(seg.pcm.nbytes for seg in self.segments.values())
+
+    def map(self, fn: Callable[[np.ndarray], np.ndarray]) -> WindowedPcm:
+        """Apply ``fn`` (e.g. a filter) to every guarded segment."""
+        return WindowedPcm(
+            length=self.length,
+            window_samples=self.window_samples,
+            segments={
+                pos: _Segment(fn(seg.pcm), seg.lead)
+                for pos, seg in self.segments.items()
+            },
+        )
+
+
+def decode_windows(
+    file_path: str,
+    stream_index: int,
+    positions: list[int],
+    window_samples: int,
+    length: int,
+    sr: int,
+    use_soxr: bool,
+    runner: CommandRunner,
+    tool_paths: dict[str, str | None],
+    start_offset_s: float = 0.0,
+    guard_s: float = WINDOW_GUARD_S,
+    downmix: DownmixModeStr = "mono_sum",
+) -> WindowedPcm:
+    """
+    Decode the windows starting at ``positions`` (samples) of one stream.
+
+    Raises:
+        RuntimeError: If ffmpeg fails for any window.
+    """
+    pcm = WindowedPcm(length=length, window_samples=window_samples)
+    for pos in positions:
+        segment, lead = decode_window(
+            file_path,
+            stream_index,
+            pos / sr,
+            window_samples / sr,
+            sr,
+            use_soxr,
+            runner,
+            tool_paths,
+            start_offset_s=start_offset_s,
+            guard_s=guard_s,
+            downmix=downmix,
+        )
+        pcm.segments[pos] = _Segment(segment, lead)
+    return pcm
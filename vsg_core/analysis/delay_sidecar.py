@@ -0,0 +1,144 @@
+# vsg_core/analysis/delay_sidecar.py
+"""
+Per-job delays stored in a sidecar file (``<Source 1>.delays.json``).
+
+A sidecar pins a job's delays so automated re-runs skip correlation and
+produce the same mux every time. Writing the analysis report also writes
+one to the output folder (``{job}.delays.json``, the job being named after
+Source 1); copied next to Source 1 it is picked up by the analysis step
+when ``analysis_use_sidecar`` is on.
+
+Layout:
+    {
+      "version": 1,
+      "job_name": "Episode 01",
+      "sources": {
+        "Source 2": {"file": "ep01.mkv", "delay_ms": -120,
+                     "raw_delay_ms": -120.412, "provenance": "analyzed"},
+        ...
+      }
+    }
+
+Delays are the per-source final delays before the global shift (as in the
+analysis report), so the shift is recomputed for the current sync mode.
+Only delays are stored: stepping correction and the per-chunk diagnostics
+need a real analysis.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from .report import AnalysisReport
+
+SIDECAR_VERSION = 1
+SIDECAR_SUFFIX = ".delays.json"
+
+
+def sidecar_path(source1_file: str | Path) -> Path:
+    """Where the sidecar of the job whose Source 1 is ``source1_file`` lives."""
+    path = Path(source1_file)
+    return path.with_name(f"{path.stem}{SIDECAR_SUFFIX}")
+
+
+@dataclass(frozen=True, slots=True)
+class SidecarDelay:
+    """One source's delay as stored in the sidecar."""
+
+    delay_ms: int
+    raw_delay_ms: float
+    provenance: str = "analyzed"  # How the delay was originally obtained
+    file: str = ""  # Source file name when the sidecar was written
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "file": self.file,
+            "delay_ms": self.delay_ms,
+            "raw_delay_ms": round(self.raw_delay_ms, 6),
+            "provenance": self.provenance,
+        }
+
+
+@dataclass(frozen=True, slots=True)
+class DelaySidecar:
+    """Delays of every source of one job, relative to Source 1."""
+
+    job_name: str
+    sources: dict[str, SidecarDelay] = field(default_factory=dict)
+
+    @classmethod
+    def from_report(
+        cls, report: AnalysisReport, source_files: dict[str, str]
+    ) -> DelaySidecar:
+        return cls(
+            job_name=report.job_name,
+            sources={
+                s.source_key: SidecarDelay(
+                    delay_ms=s.delay_ms,
+                    raw_delay_ms=s.raw_delay_ms,
+                    provenance=s.provenance,
+                    file=Path(source_files.get(s.source_key, "")).name,
+                )
+                for s in report.sources
+            },
+        )
+
+    @classmethod
+    def load(cls, path: Path) -> DelaySidecar:
+        """Read a sidecar. Raises ValueError when it is malformed."""
+        try:
+            data = json.loads(path.read_text(encoding="utf-8"))
+        except (OSError, ValueError) as e:
+            raise ValueError(f"Could not read delay sidecar {path.name}: {e}") from e
+        if not isinstance(data, dict) or data.get("version") != SIDECAR_VERSION:
+            raise ValueError(
+                f"Delay sidecar {path.name} is not a version {SIDECAR_VERSION} "
+                f"sidecar"
+            )
+        entries = data.get("sources") or {}
+        if not isinstance(entries, dict):
+            raise ValueError(f"Delay sidecar {path.name}: 'sources' is not an object")
+        sources: dict[str, SidecarDelay] = {}
+        for key, entry in entries.items():
+            try:
+                sources[key] = SidecarDelay(
+                    delay_ms=int(entry["delay_ms"]),
+                    raw_delay_ms=float(entry.get("raw_delay_ms", entry["delay_ms"])),
+                    provenance=str(entry.get("provenance", "analyzed")),
+                    file=str(entry.get("file", "")),
+                )
+            except (KeyError, TypeError, ValueError) as e:
+                raise ValueError(
+                    f"Delay sidecar {path.name}: invalid entry for {key}: {e}"
+                ) from e
+        return cls(job_name=str(data.get("job_name", "")), sources=sources)
+
+    def check_sources(self, source_keys: list[str]) -> None:
+        """Raise ValueError unless the sidecar covers exactly ``source_keys``."""
+        missing = sorted(set(source_keys) - set(self.sources))
+        extra = sorted(set(self.sources) - set(source_keys))
+        if missing or extra:
+            parts = []
+            if missing:
+                parts.append(f"missing {', '.join(missing)}")
+            if extra:
+                parts.append(f"has {', '.join(extra)} which the job doesn't")
+            raise ValueError(
+                f"Delay sidecar doesn't match the job's sources: {'; '.join(parts)}"
+            )
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "version": SIDECAR_VERSION,
+            "job_name": self.job_name,
+            "sources": {k: v.to_dict() for k, v in sorted(self.sources.items())},
+        }
+
+    def to_json(self, path: Path) -> Path:
+        """Write the sidecar as indented JSON. Returns the written path."""
+        path.write_text(json.dumps(self.to_dict(), indent=2), encoding="utf-8")
+        return path
@@ -0,0 +1,134 @@
+# vsg_core/analysis/multi_corr.py
+"""
+Per-method agreement report for multi-correlation analysis.
+
+When several correlation methods are run on the same audio pair, their
+selected delays should agree to within a few milliseconds. A large spread
+is a strong signal of a problematic source (different master, heavy
+processing, wrong track). The report records each method's delay and
+confidence, a consensus delay (median across methods), the spread
+(max - min) and the method that deviated most from the consensus.
+"""
+
+from __future__ import annotations
+
+import statistics
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from .types import ChunkResult
+
+
+@dataclass(frozen=True, slots=True)
+class MethodAgreement:
+    """Selected delay and confidence of one correlation method."""
+
+    method: str
+    delay_ms: float | None  # Median of accepted windows; None = none accepted
+    confidence: float  # Mean match % of accepted windows (0-100)
+    accepted: int
+    total: int
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "method": self.method,
+            "delay_ms": None if self.delay_ms is None else round(self.delay_ms, 3),
+            "confidence": round(self.confidence, 2),
+            "accepted": self.accepted,
+            "total": self.total,
+        }
+
+
+@dataclass(slots=True)
+class MultiCorrReport:
+    """Agreement between correlation methods for one source."""
+
+    source_key: str
+    threshold_ms: float
+    methods: list[MethodAgreement] = field(default_factory=list)
+
+    def add_method(self, method: str, chunks: list[ChunkResult]) -> MethodAgreement:
+        accepted = [c for c in chunks if c.accepted]
+        entry = MethodAgreement(
+            method=method,
+            delay_ms=(
+                statistics.median(c.raw_delay_ms for c in accepted)
+                if accepted
+                else None
+            ),
+            confidence=(
+                sum(c.match_pct for c in accepted) / len(accepted) if accepted else 0.0
+            ),
+            accepted=len(accepted),
+            total=len(chunks),
+        )
+        self.methods.append(entry)
+        return entry
+
+    def _delays(self) -> list[tuple[str, float]]:
+        return [(m.method, m.delay_ms) for m in self.methods if m.delay_ms is not None]
+
+    @property
+    def consensus_ms(self) -> float | None:
+        """Median of the per-method delays."""
+        delays = [d for _, d in self._delays()]
+        return statistics.median(delays) if delays else None
+
+    @property
+    def spread_ms(self) -> float:
+        """max - min of the per-method delays (0 with fewer than 2 methods)."""
+        delays = [d for _, d in self._delays()]
+        return max(delays) - min(delays) if len(delays) > 1 else 0.0
+
+    @property
+    def most_deviant(self) -> str | None:
+        """Method whose delay is furthest from the consensus."""
+        consensus = self.consensus_ms
+        delays = self._delays()
+        if consensus is None or len(delays) < 2:
+            return None
+        return max(delays, key=lambda md: abs(md[1] - consensus))[0]
+
+    @property
+    def disagrees(self) -> bool:
+        return self.spread_ms > self.threshold_ms
+
+    def log_table(self, log: Callable[[str], None]) -> None:
+        width = max([len(m.method) for m in self.methods] + [6])
+        log(f"  {'Method':<{width}} | {'Delay (ms)':>11} | {'Conf':>6} | Accepted")
+        log(f"  {'-' * width}-+-{'-' * 11}-+-{'-' * 6}-+-{'-' * 9}")
+        consensus = self.consensus_ms
+        for m in self.methods:
+            delay = "-" if m.delay_ms is None else f"{m.delay_ms:+.3f}"
+            log(
+                f"  {m.method:<{width}} | {delay:>11} | {m.confidence:5.1f}% | "
+                f"{m.accepted}/{m.total}"
+            )
+        if consensus is None:
+            log("  Consensus: n/a (no method accepted any windows)")
+            return
+        log(
+            f"  Consensus (median): {consensus:+.3f}ms | "
+            f"spread: {self.spread_ms:.3f}ms"
+        )
+        if self.disagrees:
+            log(
+                f"  [WARNING] Methods disagree by >{self.threshold_ms:g}ms "
+                f"(most deviant: {self.most_deviant}). "
+                "Check this source before trusting the delay."
+            )
+
+    def to_dict(self) -> dict[str, Any]:
+        consensus = self.consensus_ms
+        return {
+            "source": self.source_key,
+            "methods": [m.to_dict() for m in self.methods],
+            "consensus_ms": None if consensus is None else round(consensus, 3),
+            "spread_ms": round(self.spread_ms, 3),
+            "threshold_ms": self.threshold_ms,
+            "disagrees": self.disagrees,
+            "most_deviant": self.most_deviant,
+        }
@@ -0,0 +1,159 @@
+# vsg_core/analysis/pair.py
+"""
+Reference-free delay between two arbitrary sources.
+
+The pipeline always syncs to Source 1. ``correlate_pair`` runs the same
+dense correlation and delay selection on any two files, with neither one
+treated as canonical — e.g. to line two alternate fansubs up with each
+other.
+
+Sign convention (the same one the pipeline uses for Source 2 vs Source 1,
+with A in the role of Source 1):
+
+    delay_ms > 0  B's content is ahead of A's; B must be delayed by
+                  ``delay_ms`` to line up with A.
+    delay_ms < 0  B's content is behind A's; B must be moved earlier.
+
+Swapping the files negates the result (up to rounding). Only the audio is
+compared: container delays and the global shift are not applied.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+from ..errors import AnalysisUnreliable
+from .correlation.decode import (
+    DEFAULT_SR,
+    decode_audio,
+    get_audio_stream_info,
+    normalize_lang,
+    resolve_downmix,
+)
+from .correlation.dense import run_dense_from_settings
+from .correlation.run import _resolve_method
+from .delay_selection import calculate_delay
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    import numpy as np
+
+    from ..io.runner import CommandRunner
+    from ..models.settings import AppSettings
+
+
+@dataclass(frozen=True, slots=True)
+class PairDelay:
+    """Delay of source B relative to source A (see module docstring)."""
+
+    delay_ms: int
+    raw_delay_ms: float
+    method: str  # Correlation method that produced the delay
+    accepted_windows: int
+    total_windows: int
+
+    def describe(self) -> str:
+        return (
+            f"B is {self.delay_ms:+d}ms relative to A "
+            f"({self.raw_delay_ms:+.3f}ms raw, {self.method}, "
+            f"{self.accepted_windows}/{self.total_windows} windows accepted)"
+        )
+
+
+def correlate_pair_pcm(
+    pcm_a: np.ndarray,
+    pcm_b: np.ndarray,
+    sr: int,
+    settings: AppSettings,
+    log: Callable[[str], None],
+) -> PairDelay:
+    """Delay of ``pcm_b`` relative to ``pcm_a`` (mono float32 at ``sr``).
+
+    Raises RuntimeError when too few windows are accepted to pick a delay.
+    """
+    method = _resolve_method(settings, source_separated=False)
+    results = run_dense_from_settings(
+        ref_pcm=pcm_a,
+        tgt_pcm=pcm_b,
+        sr=sr,
+        method=method,
+        settings=settings,
+        min_match=float(settings.min_match_pct),
+        log=log,
+    )
+    calc = calculate_delay(
+        results=results,
+        settings=settings,
+        delay_mode=settings.delay_selection_mode,
+        log=log,
+        role_tag="Pair B",
+    )
+    if calc is None:
+        accepted = sum(1 for r in results if r.accepted)
+        raise AnalysisUnreliable(
+            f"Could not determine a reliable delay between the two sources "
+            f"({accepted}/{len(results)} windows accepted)."
+        )
+    return PairDelay(
+        delay_ms=calc.rounded_ms,
+        raw_delay_ms=calc.raw_ms,
+        method=method.name,
+        accepted_windows=calc.accepted_windows,
+        total_windows=calc.total_windows,
+    )
+
+
+def correlate_pair(
+    path_a: str,
+    path_b: str,
+    settings: AppSettings,
+    runner: CommandRunner,
+    tool_paths: dict[str, str | None],
+    *,
+    track_a: int | None = None,
+    track_b: int | None = None,
+) -> PairDelay:
+    """Delay of the file at ``path_b`` relative to the one at ``path_a``.
+
+    ``track_a``/``track_b`` are 0-based audio stream indices; when omitted
+    the stream is picked by the Analysis Language for other sources, as
+    for Source 2/3.
+    """
+    log = runner._log_message
+    lang = normalize_lang(settings.analysis_lang_others)
+
+    indices = []
+    for label, path, explicit in (("A", path_a, track_a), ("B", path_b, track_b)):
+        if explicit is None:
+            explicit, _ = get_audio_stream_info(path, lang, runner, tool_paths)
+        if explicit is None:
+            raise ValueError(f"No audio stream found in {label}: {path}")
+        indices.append(explicit)
+
+    log(
+        f"[Pair] Correlating B (stream {indices[1]}) against "
+        f"A (stream {indices[0]})"
+    )
+    pcms = []
+    for path, index in ((path_a, indices[0]), (path_b, indices[1])):
+        downmix, _ = resolve_downmix(
+            settings.analysis_downmix_mode, path, index, runner, tool_paths
+        )
+        pcms.append(
+            decode_audio(
+                path,
+                index,
+                DEFAULT_SR,
+                settings.use_soxr,
+                runner,
+                tool_paths,
+                downmix=downmix,
+            )
+        )
+    pcm_a, pcm_b = pcms
+
+    result = correlate_pair_pcm(pcm_a, pcm_b, DEFAULT_SR, settings, log)
+    log(f"[Pair] {result.describe()}")
+    return result
@@ -0,0 +1,121 @@
+# vsg_core/analysis/reliability.py
+"""
+Confidence gate for analysis results.
+
+A correlation can technically produce a delay while its windows barely
+agree; applying such a delay is worse than not syncing at all. The gate
+checks each source's accepted windows against two thresholds (both off by
+default) and, when either is missed, stops the job before anything is
+muxed. Depending on the configured action the job ends as "Needs Review"
+or "Failed", with the reason and the measured values in its result.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+from ..errors import AnalysisUnreliable
+
+if TYPE_CHECKING:
+    from ..models.settings import AppSettings
+    from ..models.types import UnreliableAnalysisActionStr
+    from .types import ChunkResult
+
+
+@dataclass(frozen=True, slots=True)
+class UnreliableSource:
+    """Measured values of a source that fell below the gate."""
+
+    source_key: str
+    confidence: float  # Mean match % of accepted windows (0-100)
+    accepted: int
+    total: int
+    reasons: tuple[str, ...]
+
+    @property
+    def accepted_fraction(self) -> float:
+        return self.accepted / self.total if self.total else 0.0
+
+    def describe(self) -> str:
+        return (
+            f"{self.source_key}: {'; '.join(self.reasons)} "
+            f"({self.accepted}/{self.total} windows accepted)"
+        )
+
+
+class AnalysisNeedsReview(AnalysisUnreliable):  # noqa: N818 - mirrors the job status
+    """Raised when a delay was found but is too unreliable to apply."""
+
+    def __init__(self, sources: list[UnreliableSource], delays: dict[str, int]):
+        self.sources = sources
+        self.delays = delays  # Measured (unapplied) delays, for the job result
+        super().__init__(
+            "Analysis result is unreliable — needs manual review. "
+            + " | ".join(s.describe() for s in sources)
+        )
+
+
+@dataclass(frozen=True, slots=True)
+class ReliabilityGate:
+    """Minimum confidence / accepted fraction for a usable delay (0 = off)."""
+
+    min_confidence: float = 0.0
+    min_accepted_fraction: float = 0.0
+    action: UnreliableAnalysisActionStr = "needs_review"
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> ReliabilityGate:
+        return cls(
+            min_confidence=settings.abort_below_confidence,
+            min_accepted_fraction=settings.abort_below_accepted_fraction,
+            action=settings.unreliable_analysis_action,
+        )
+
+    @property
+    def enabled(self) -> bool:
+        return self.min_confidence > 0 or self.min_accepted_fraction > 0
+
+    def check(
+        self, source_key: str, chunks: list[ChunkResult]
+    ) -> UnreliableSource | None:
+        """Return the measured values if the source misses a threshold."""
+        if not self.enabled:
+            return None
+        accepted = [c for c in chunks if c.accepted]
+        confidence = (
+            sum(c.match_pct for c in accepted) / len(accepted) if accepted else 0.0
+        )
+        fraction = len(accepted) / len(chunks) if chunks else 0.0
+
+        reasons = []
+        if self.min_confidence > 0 and confidence < self.min_confidence:
+            reasons.append(
+                f"confidence {confidence:.1f}% < {self.min_confidence:g}%"
+            )
+        if self.min_accepted_fraction > 0 and fraction < self.min_accepted_fraction:
+            reasons.append(
+                f"accepted fraction {fraction:.2f} < {self.min_accepted_fraction:g}"
+            )
+        if not reasons:
+            return None
+        return UnreliableSource(
+            source_key=source_key,
+            confidence=confidence,
+            accepted=len(accepted),
+            total=len(chunks),
+            reasons=tuple(reasons),
+        )
+
+    def enforce(
+        self, sources: list[UnreliableSource], delays: dict[str, int]
+    ) -> None:
+        """Stop the job for the given unreliable sources (no-op if empty)."""
+        if not sources:
+            return
+        if self.action == "fail":
+            raise AnalysisUnreliable(
+                "Analysis result is unreliable. "
+                + " | ".join(s.describe() for s in sources)
+            )
+        raise AnalysisNeedsReview(sources, delays)
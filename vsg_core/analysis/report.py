@@ -0,0 +1,193 @@
+# vsg_core/analysis/report.py
+"""Machine-readable analysis report (JSON + CSV).
+
+Collects per-source delay results and the full per-chunk table so an
+analysis can be inspected in a spreadsheet or diffed between runs without
+scraping the log.
+
+JSON layout:
+    {
+      "job_name": "...",
+      "global_shift_ms": 0,
+      "sources": [
+        {"source": "Source 2", "delay_ms": -120, ..., "chunks": [...]},
+        ...
+      ]
+    }
+
+CSV layout: one row per chunk, with a leading ``source`` column so reports
+from multi-source jobs (or several jobs) concatenate cleanly.
+"""
+
+from __future__ import annotations
+
+import csv
+import json
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any
+
+from .types import count_rejections
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from .correlation.coarse import CoarseAlignment
+    from .correlation.curve import CorrelationCurve
+    from .multi_corr import MultiCorrReport
+    from .timings import AnalysisTimings
+    from .track_consensus import TrackConsensusReport
+    from .types import ChunkResult
+
+CSV_COLUMNS = (
+    "source",
+    "start_s",
+    "raw_delay_ms",
+    "delay_ms",
+    "match_pct",
+    "accepted",
+    "reject_reason",
+)
+
+
+@dataclass(slots=True)
+class SourceAnalysisReport:
+    """Analysis outcome for one source relative to Source 1."""
+
+    source_key: str
+    delay_ms: int  # Final delay incl. container delay, before global shift
+    raw_delay_ms: float
+    confidence: float  # Mean match % of accepted chunks (0-100)
+    accepted_chunks: int
+    total_chunks: int
+    selection_mode: str
+    correlation_method: str = ""  # Method that produced the accepted delay
+    # "manual" when set by a per-source override, "sidecar" when read from
+    # the job's .delays.json
+    provenance: str = "analyzed"
+    chunks: list[ChunkResult] = field(default_factory=list)
+    multi_corr: MultiCorrReport | None = None
+    track_consensus: TrackConsensusReport | None = None
+    timings: AnalysisTimings | None = None
+    correlation_curves: list[CorrelationCurve] = field(default_factory=list)
+    coarse_prealign: CoarseAlignment | None = None
+
+    def to_dict(self) -> dict[str, Any]:
+        data = {
+            "source": self.source_key,
+            "delay_ms": self.delay_ms,
+            "raw_delay_ms": round(self.raw_delay_ms, 6),
+            "confidence": round(self.confidence, 2),
+            "accepted_chunks": self.accepted_chunks,
+            "total_chunks": self.total_chunks,
+            "selection_mode": self.selection_mode,
+            "correlation_method": self.correlation_method,
+            "provenance": self.provenance,
+            "chunks": [
+                {
+                    "start_s": round(c.start_s, 3),
+                    "raw_delay_ms": round(c.raw_delay_ms, 6),
+                    "delay_ms": c.delay_ms,
+                    "match_pct": round(c.match_pct, 3),
+                    "accepted": c.accepted,
+                    "reject_reason": c.reject_reason,
+                }
+                for c in self.chunks
+            ],
+        }
+        rejections = count_rejections(self.chunks)
+        if rejections:
+            data["rejections"] = {str(r): n for r, n in rejections.items()}
+        if self.coarse_prealign is not None:
+            data["coarse_prealign"] = self.coarse_prealign.to_dict()
+        if self.multi_corr is not None:
+            data["multi_correlation"] = self.multi_corr.to_dict()
+        if self.track_consensus is not None:
+            data["track_consensus"] = self.track_consensus.to_dict()
+        if self.timings is not None:
+            data["timings"] = self.timings.to_dict()
+        if self.correlation_curves:
+            data["correlation_curves"] = [
+                c.to_dict() for c in self.correlation_curves
+            ]
+        return data
+
+
+@dataclass(slots=True)
+class AnalysisReport:
+    """All per-source analysis results for one job."""
+
+    job_name: str
+    global_shift_ms: int = 0
+    sources: list[SourceAnalysisReport] = field(default_factory=list)
+
+    def add_source(
+        self,
+        source_key: str,
+        delay_ms: int,
+        raw_delay_ms: float,
+        selection_mode: str,
+        chunks: list[ChunkResult],
+        correlation_method: str = "",
+        multi_corr: MultiCorrReport | None = None,
+        provenance: str = "analyzed",
+        track_consensus: TrackConsensusReport | None = None,
+        timings: AnalysisTimings | None = None,
+        correlation_curves: list[CorrelationCurve] | None = None,
+        coarse_prealign: CoarseAlignment | None = None,
+    ) -> SourceAnalysisReport:
+        """Record a source from its chunk results. Confidence is derived here."""
+        accepted = [c for c in chunks if c.accepted]
+        confidence = (
+            sum(c.match_pct for c in accepted) / len(accepted) if accepted else 0.0
+        )
+        entry = SourceAnalysisReport(
+            source_key=source_key,
+            delay_ms=delay_ms,
+            raw_delay_ms=raw_delay_ms,
+            confidence=confidence,
+            accepted_chunks=len(accepted),
+            total_chunks=len(chunks),
+            selection_mode=selection_mode,
+            correlation_method=correlation_method,
+            chunks=list(chunks),
+            multi_corr=multi_corr,
+            track_consensus=track_consensus,
+            provenance=provenance,
+            timings=timings,
+            correlation_curves=list(correlation_curves or []),
+            coarse_prealign=coarse_prealign,
+        )
+        self.sources.append(entry)
+        return entry
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "job_name": self.job_name,
+            "global_shift_ms": self.global_shift_ms,
+            "sources": [s.to_dict() for s in self.sources],
+        }
+
+    def to_json(self, path: Path) -> Path:
+        """Write the report as indented JSON. Returns the written path."""
+        path.write_text(json.dumps(self.to_dict(), indent=2), encoding="utf-8")
+        return path
+
+    def to_csv(self, path: Path) -> Path:
+        """Write one row per chunk (with a source column). Returns the path."""
+        with path.open("w", newline="", encoding="utf-8") as f:
+            writer = csv.writer(f)
+            writer.writerow(CSV_COLUMNS)
+            for src in self.sources:
+                for c in src.chunks:
+                    writer.writerow(
+                        [
+                            src.source_key,
+                            f"{c.start_s:.3f}",
+                            f"{c.raw_delay_ms:.6f}",
+                            c.delay_ms,
+                            f"{c.match_pct:.3f}",
+                            int(c.accepted),
+                            c.reject_reason or "",
+                        ]
+                    )
+        return path
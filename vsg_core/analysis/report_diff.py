@@ -0,0 +1,221 @@
+# vsg_core/analysis/report_diff.py
+"""
+Compare two sets of analysis reports (regression check between runs).
+
+After changing analysis settings, re-analysing a season and diffing the new
+``{job}_analysis_report.json`` files against the previous ones shows which
+episodes' delays moved before anything is re-muxed. Reports are matched by
+job name and sources by their key; for each source the diff holds the delay
+delta, the confidence change and the accepted-chunk change.
+
+A source is flagged when its delay moved by more than ``threshold_ms``, or
+when it is missing from the current run (analysis failed or the job was
+dropped). Sources only present in the current run are listed but not
+flagged. Delays are compared before the global shift, so a shift change
+caused by another source doesn't show up as a move.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import TYPE_CHECKING, Any, Literal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterable
+
+    from .report import AnalysisReport
+
+SourceDiffStatus = Literal["same", "changed", "added", "removed"]
+
+REPORT_SUFFIX = "_analysis_report.json"
+
+
+def load_report_set(path: Path) -> list[dict[str, Any]]:
+    """Analysis reports in ``path`` (a report file or a folder of them)."""
+    files = sorted(path.glob(f"*{REPORT_SUFFIX}")) if path.is_dir() else [path]
+    return [json.loads(f.read_text(encoding="utf-8")) for f in files]
+
+
+@dataclass(frozen=True, slots=True)
+class SourceDiff:
+    """One source's analysis result in the previous and current run."""
+
+    job_name: str
+    source_key: str
+    prev: dict[str, Any] | None  # Source entry of the report; None = absent
+    curr: dict[str, Any] | None
+
+    @property
+    def status(self) -> SourceDiffStatus:
+        if self.prev is None:
+            return "added"
+        if self.curr is None:
+            return "removed"
+        same = (
+            self.delay_delta_ms == 0
+            and self.confidence_delta == 0
+            and self.accepted_delta == 0
+        )
+        return "same" if same else "changed"
+
+    def _delta(self, key: str) -> float | None:
+        if self.prev is None or self.curr is None:
+            return None
+        return self.curr[key] - self.prev[key]
+
+    @property
+    def delay_delta_ms(self) -> int | None:
+        delta = self._delta("delay_ms")
+        return None if delta is None else int(delta)
+
+    @property
+    def confidence_delta(self) -> float | None:
+        delta = self._delta("confidence")
+        return None if delta is None else round(delta, 2)
+
+    @property
+    def accepted_delta(self) -> int | None:
+        delta = self._delta("accepted_chunks")
+        return None if delta is None else int(delta)
+
+    def is_flagged(self, threshold_ms: float) -> bool:
+        if self.status == "removed":
+            return True
+        delta = self.delay_delta_ms
+        return delta is not None and abs(delta) > threshold_ms
+
+    def describe(self) -> str:
+        name = f"{self.job_name} / {self.source_key}"
+        if self.prev is None:
+            return f"{name}: new ({_summary(self.curr)})"
+        if self.curr is None:
+            return f"{name}: missing (was {_summary(self.prev)})"
+        return (
+            f"{name}: delay {self.prev['delay_ms']:+d} -> "
+            f"{self.curr['delay_ms']:+d}ms ({self.delay_delta_ms:+d}), "
+            f"confidence {self.prev['confidence']:.1f} -> "
+            f"{self.curr['confidence']:.1f}% ({self.confidence_delta:+.1f}), "
+            f"accepted {self.prev['accepted_chunks']}/{self.prev['total_chunks']} "
+            f"-> {self.curr['accepted_chunks']}/{self.curr['total_chunks']}"
+        )
+
+    def to_dict(self, threshold_ms: float) -> dict[str, Any]:
+        def side(entry: dict[str, Any] | None) -> dict[str, Any] | None:
+            if entry is None:
+                return None
+            return {
+                "delay_ms": entry["delay_ms"],
+                "confidence": entry["confidence"],
+                "accepted_chunks": entry["accepted_chunks"],
+                "total_chunks": entry["total_chunks"],
+            }
+
+        return {
+            "job_name": self.job_name,
+            "source": self.source_key,
+            "status": self.status,
+            "flagged": self.is_flagged(threshold_ms),
+            "delay_delta_ms": self.delay_delta_ms,
+            "confidence_delta": self.confidence_delta,
+            "accepted_delta": self.accepted_delta,
+            "previous": side(self.prev),
+            "current": side(self.curr),
+        }
+
+
+def _summary(entry: dict[str, Any] | None) -> str:
+    if entry is None:
+        return "-"
+    return (
+        f"{entry['delay_ms']:+d}ms, {entry['confidence']:.1f}%, "
+        f"{entry['accepted_chunks']}/{entry['total_chunks']} accepted"
+    )
+
+
+@dataclass(slots=True)
+class ReportDiff:
+    """Per-source differences between two runs of analysis reports."""
+
+    threshold_ms: float
+    sources: list[SourceDiff] = field(default_factory=list)
+
+    @property
+    def flagged(self) -> list[SourceDiff]:
+        return [s for s in self.sources if s.is_flagged(self.threshold_ms)]
+
+    @property
+    def changed(self) -> list[SourceDiff]:
+        return [s for s in self.sources if s.status != "same"]
+
+    def to_text(self) -> str:
+        lines = [
+            f"Compared {len(self.sources)} source(s): {len(self.changed)} changed, "
+            f"{len(self.flagged)} flagged (delay moved > {self.threshold_ms:g}ms "
+            f"or missing)."
+        ]
+        flagged = self.flagged
+        if flagged:
+            lines.append("")
+            lines.append("Flagged:")
+            lines.extend(f"  ! {s.describe()}" for s in flagged)
+        others = [s for s in self.changed if s not in flagged]
+        if others:
+            lines.append("")
+            lines.append("Changed:")
+            lines.extend(f"    {s.describe()}" for s in others)
+        return "\n".join(lines)
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "threshold_ms": self.threshold_ms,
+            "compared": len(self.sources),
+            "changed": len(self.changed),
+            "flagged": len(self.flagged),
+            "sources": [s.to_dict(self.threshold_ms) for s in self.sources],
+        }
+
+    def to_json(self, path: Path) -> Path:
+        """Write the diff as indented JSON. Returns the written path."""
+        path.write_text(json.dumps(self.to_dict(), indent=2), encoding="utf-8")
+        return path
+
+
+def _by_job(
+    reports: Iterable[AnalysisReport | dict[str, Any]],
+) -> dict[str, dict[str, dict[str, Any]]]:
+    jobs: dict[str, dict[str, dict[str, Any]]] = {}
+    for report in reports:
+        data = report if isinstance(report, dict) else report.to_dict()
+        jobs[data["job_name"]] = {s["source"]: s for s in data.get("sources", [])}
+    return jobs
+
+
+def diff_reports(
+    prev: Iterable[AnalysisReport | dict[str, Any]],
+    curr: Iterable[AnalysisReport | dict[str, Any]],
+    threshold_ms: float = 1.0,
+) -> ReportDiff:
+    """
+    Diff two report sets (``AnalysisReport`` objects or their JSON dicts).
+
+    Sources are listed job by job in the current run's order, followed by
+    jobs that only exist in the previous run.
+    """
+    prev_jobs = _by_job(prev)
+    curr_jobs = _by_job(curr)
+    diff = ReportDiff(threshold_ms=threshold_ms)
+    for job_name in [*curr_jobs, *(j for j in prev_jobs if j not in curr_jobs)]:
+        before = prev_jobs.get(job_name, {})
+        after = curr_jobs.get(job_name, {})
+        for source_key in [*after, *(s for s in before if s not in after)]:
+            diff.sources.append(
+                SourceDiff(
+                    job_name=job_name,
+                    source_key=source_key,
+                    prev=before.get(source_key),
+                    curr=after.get(source_key),
+                )
+            )
+    return diff
@@ -0,0 +1,85 @@
+# vsg_core/analysis/timings.py
+"""
+Wall-clock breakdown of one source's audio analysis.
+
+Splits the time spent on a source into decode, preprocessing (source
+separation + filtering), correlation per method and delay selection, so a
+slow job can be attributed (decode-bound vs FFT-bound) and a method that is
+much slower than the others stands out in multi-correlation runs. Timed
+with ``time.perf_counter`` around whole phases only; the overhead is a few
+calls per source.
+"""
+
+from __future__ import annotations
+
+import time
+from contextlib import contextmanager
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+
+@dataclass(slots=True)
+class AnalysisTimings:
+    """Seconds spent in each analysis phase for one source."""
+
+    decode_s: float = 0.0
+    preprocess_s: float = 0.0  # Source separation + filtering
+    correlation_s: dict[str, float] = field(default_factory=dict)  # Per method
+    selection_s: float = 0.0
+
+    @property
+    def correlation_total_s(self) -> float:
+        return sum(self.correlation_s.values())
+
+    @property
+    def total_s(self) -> float:
+        return (
+            self.decode_s
+            + self.preprocess_s
+            + self.correlation_total_s
+            + self.selection_s
+        )
+
+    def add_correlation(self, method: str, seconds: float) -> None:
+        """Accumulate correlation time for ``method`` (reruns add up)."""
+        self.correlation_s[method] = self.correlation_s.get(method, 0.0) + seconds
+
+    @contextmanager
+    def measure(self, phase: str) -> Iterator[None]:
+        """Add the elapsed time of the ``with`` body to ``<phase>_s``."""
+        t0 = time.perf_counter()
+        try:
+            yield
+        finally:
+            attr = f"{phase}_s"
+            setattr(self, attr, getattr(self, attr) + time.perf_counter() - t0)
+
+    @contextmanager
+    def measure_method(self, method: str) -> Iterator[None]:
+        """Add the elapsed time of the ``with`` body to ``method``."""
+        t0 = time.perf_counter()
+        try:
+            yield
+        finally:
+            self.add_correlation(method, time.perf_counter() - t0)
+
+    def describe(self) -> str:
+        methods = ", ".join(f"{m} {s:.2f}s" for m, s in self.correlation_s.items())
+        return (
+            f"decode {self.decode_s:.2f}s | preprocess {self.preprocess_s:.2f}s | "
+            f"correlation {self.correlation_total_s:.2f}s"
+            + (f" ({methods})" if methods else "")
+            + f" | selection {self.selection_s:.3f}s | total {self.total_s:.2f}s"
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "decode_s": round(self.decode_s, 4),
+            "preprocess_s": round(self.preprocess_s, 4),
+            "correlation_s": {m: round(s, 4) for m, s in self.correlation_s.items()},
+            "selection_s": round(self.selection_s, 4),
+            "total_s": round(self.total_s, 4),
+        }
@@ -0,0 +1,183 @@
+# vsg_core/analysis/track_consensus.py
+"""
+Confidence-weighted delay consensus across several audio tracks.
+
+A source often carries the same mix more than once (stereo and 5.1, lossy
+and lossless). Correlating each of them against the reference and
+combining the results is more robust than trusting a single track: a
+track that correlates poorly contributes little, and one that lands on a
+wrong peak is outvoted.
+
+Tracks that are genuinely different content (commentary, another dub)
+must not be averaged in. The majority is the track whose delay has the
+largest total confidence within ``threshold_ms`` of it; tracks further
+than that from the majority delay are excluded. The consensus is the
+confidence-weighted mean of the remaining tracks' delays.
+
+The consensus only replaces the selected track's delay when the included
+tracks hold a strict majority of the confidence (``has_majority``) and the
+selected track is one of them. A tie, or a selected track that disagrees
+with the others, keeps the selected track's delay.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from .types import ChunkResult
+
+
+@dataclass(frozen=True, slots=True)
+class TrackAgreement:
+    """Selected delay and confidence of one audio track."""
+
+    track_index: int  # 0-based index within the source's audio tracks
+    description: str
+    delay_ms: float | None  # Selected raw delay; None = analysis failed
+    confidence: float  # Mean match % of accepted windows (0-100)
+    accepted: int
+    total: int
+
+    @classmethod
+    def from_chunks(
+        cls,
+        track_index: int,
+        description: str,
+        delay_ms: float | None,
+        chunks: list[ChunkResult],
+    ) -> TrackAgreement:
+        accepted = [c for c in chunks if c.accepted]
+        return cls(
+            track_index=track_index,
+            description=description,
+            delay_ms=delay_ms,
+            confidence=(
+                sum(c.match_pct for c in accepted) / len(accepted) if accepted else 0.0
+            ),
+            accepted=len(accepted),
+            total=len(chunks),
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "track_index": self.track_index,
+            "description": self.description,
+            "delay_ms": None if self.delay_ms is None else round(self.delay_ms, 3),
+            "confidence": round(self.confidence, 2),
+            "accepted": self.accepted,
+            "total": self.total,
+        }
+
+
+@dataclass(slots=True)
+class TrackConsensusReport:
+    """Per-track delays of one source and the consensus between them."""
+
+    source_key: str
+    threshold_ms: float
+    tracks: list[TrackAgreement] = field(default_factory=list)
+
+    def _measured(self) -> list[tuple[TrackAgreement, float]]:
+        return [(t, t.delay_ms) for t in self.tracks if t.delay_ms is not None]
+
+    @property
+    def majority_ms(self) -> float | None:
+        """Delay with the most confidence within the threshold of it."""
+        measured = self._measured()
+        if not measured:
+            return None
+
+        def support(anchor: tuple[TrackAgreement, float]) -> tuple[float, float]:
+            total = sum(
+                t.confidence
+                for t, d in measured
+                if abs(d - anchor[1]) <= self.threshold_ms
+            )
+            return total, anchor[0].confidence
+
+        return max(measured, key=support)[1]
+
+    @property
+    def included(self) -> list[tuple[TrackAgreement, float]]:
+        """Tracks within the threshold of the majority, with their delays."""
+        majority = self.majority_ms
+        if majority is None:
+            return []
+        return [
+            (t, d)
+            for t, d in self._measured()
+            if abs(d - majority) <= self.threshold_ms
+        ]
+
+    @property
+    def has_majority(self) -> bool:
+        """True if the included tracks hold more than half of the confidence.
+
+        When no measured track has any confidence, each one counts once.
+        """
+        measured = self._measured()
+        included = self.included
+        total = sum(t.confidence for t, _ in measured)
+        if total <= 0:
+            return 2 * len(included) > len(measured)
+        return 2 * sum(t.confidence for t, _ in included) > total
+
+    @property
+    def excluded(self) -> list[TrackAgreement]:
+        included = {t.track_index for t, _ in self.included}
+        return [t for t in self.tracks if t.track_index not in included]
+
+    @property
+    def consensus_ms(self) -> float | None:
+        """Confidence-weighted mean delay of the included tracks."""
+        included = self.included
+        if not included:
+            return None
+        weight = sum(t.confidence for t, _ in included)
+        if weight <= 0:
+            return sum(d for _, d in included) / len(included)
+        return sum(d * t.confidence for t, d in included) / weight
+
+    def log_table(self, log: Callable[[str], None]) -> None:
+        width = max([len(t.description) for t in self.tracks] + [5])
+        included = {t.track_index for t, _ in self.included}
+        log(f"  {'Track':<{width}} | {'Delay (ms)':>11} | {'Conf':>6} | Accepted")
+        log(f"  {'-' * width}-+-{'-' * 11}-+-{'-' * 6}-+-{'-' * 9}")
+        for t in self.tracks:
+            delay = "-" if t.delay_ms is None else f"{t.delay_ms:+.3f}"
+            mark = "" if t.track_index in included else "  (excluded)"
+            log(
+                f"  {t.description:<{width}} | {delay:>11} | {t.confidence:5.1f}% | "
+                f"{t.accepted}/{t.total}{mark}"
+            )
+        consensus = self.consensus_ms
+        if consensus is None:
+            log("  Consensus: n/a (no track produced a delay)")
+            return
+        log(
+            f"  Consensus (confidence-weighted, {len(included)} track(s)): "
+            f"{consensus:+.3f}ms"
+        )
+        for t in self.excluded:
+            if t.delay_ms is not None:
+                log(
+                    f"  [WARNING] {t.description} disagrees with the majority by "
+                    f">{self.threshold_ms:g}ms (different content?); excluded."
+                )
+
+    def to_dict(self) -> dict[str, Any]:
+        consensus = self.consensus_ms
+        included = {t.track_index for t, _ in self.included}
+        return {
+            "source": self.source_key,
+            "tracks": [
+                {**t.to_dict(), "included": t.track_index in included}
+                for t in self.tracks
+            ],
+            "consensus_ms": None if consensus is None else round(consensus, 3),
+            "threshold_ms": self.threshold_ms,
+        }
@@ -194,3 +194,25 @@ def select_audio_track(
         channels=channels,
         formatted_name=format_track_details(selected_track, selected_index),
     )
+
+
+def consensus_candidates(
+    audio_tracks: list[dict[str, Any]], selected_index: int
+) -> list[int]:
+    """
+    Audio track indices to correlate for a multi-track consensus.
+
+    Every track in the same language as the selected one, selected track
+    first. Tracks of the same language that carry different content
+    (commentary) are left to the consensus to exclude.
+    """
+
+    def lang(track: dict[str, Any]) -> str:
+        return (track.get("properties", {}).get("language", "") or "und").lower()
+
+    wanted = lang(audio_tracks[selected_index])
+    return [selected_index] + [
+        idx
+        for idx, track in enumerate(audio_tracks)
+        if idx != selected_index and lang(track) == wanted
+    ]
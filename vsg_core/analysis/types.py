@@ -8,7 +8,27 @@ They are local to the analysis module (not shared across the codebase).
 
 from __future__ import annotations
 
+from collections import Counter
 from dataclasses import dataclass, field as dataclass_field
+from enum import StrEnum
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Iterable
+
+
+class RejectReason(StrEnum):
+    """Why a window did not count towards the delay."""
+
+    BELOW_MIN_MATCH = "below_min_match"  # Confidence under min_match_pct
+    OUT_OF_RANGE = "out_of_range"  # Peak beyond correlation_max_lag_ms
+    SILENCE = "silence"  # Below the silence threshold; not correlated
+    LOW_ENERGY = "low_energy"  # Quiet (under min_chunk_energy_db) and no match
+
+    @property
+    def label(self) -> str:
+        """Short text for logs ("below min match")."""
+        return self.value.replace("_", " ")
 
 
 @dataclass(frozen=True, slots=True)
@@ -20,6 +40,29 @@ class ChunkResult:
     match_pct: float  # Match quality / confidence score (0-100)
     start_s: float  # Chunk start position in seconds
     accepted: bool  # True if match_pct >= threshold
+    reject_reason: RejectReason | None = None  # Set when not accepted
+
+
+def count_rejections(
+    chunks: Iterable[ChunkResult], silence_windows: int = 0
+) -> dict[RejectReason, int]:
+    """
+    Rejected windows per reason, most common first. Silence windows are
+    not correlated (no ``ChunkResult``), so their count is passed in.
+    """
+    counts = Counter(
+        c.reject_reason or RejectReason.BELOW_MIN_MATCH
+        for c in chunks
+        if not c.accepted
+    )
+    if silence_windows:
+        counts[RejectReason.SILENCE] += silence_windows
+    return dict(counts.most_common())
+
+
+def describe_rejections(counts: dict[RejectReason, int]) -> str:
+    """e.g. "14 below min match, 3 out of range" ("none" when empty)."""
+    return ", ".join(f"{n} {reason.label}" for reason, n in counts.items()) or "none"
 
 
 @dataclass(frozen=True, slots=True)
@@ -18,6 +18,8 @@ from typing import TYPE_CHECKING
 
 import numpy as np
 
+from ..errors import ToolMissing
+
 if TYPE_CHECKING:
     from collections.abc import Callable
 
@@ -185,8 +187,10 @@ def _extract_frame_hashes(
         if proc.returncode != 0 and stderr_out:
             log(f"[VideoDiff] ffmpeg warning: {stderr_out[:200]}")
 
-    except FileNotFoundError:
-        raise RuntimeError("ffmpeg not found. Required for VideoDiff frame extraction.")
+    except FileNotFoundError as e:
+        raise ToolMissing(
+            "ffmpeg not found. Required for VideoDiff frame extraction.", "ffmpeg"
+        ) from e
 
     duration_s = len(hashes) / effective_fps if effective_fps > 0 else 0
     log(f"[VideoDiff] Extracted {len(hashes)} frames ({duration_s:.1f}s)")
@@ -257,6 +257,7 @@ class AuditTrail:
         selection_method: str,
         accepted_windows: int,
         total_windows: int,
+        correlation_method: str | None = None,
     ) -> None:
         """
         Record the delay calculation chain for a source.
@@ -272,6 +273,7 @@ class AuditTrail:
                     "selection_method": selection_method,
                     "accepted_windows": accepted_windows,
                     "total_windows": total_windows,
+                    "method": correlation_method,
                 },
                 "container_delay_ms": round(container_delay_ms, 6),
                 "before_global_shift": {
@@ -0,0 +1,85 @@
+# vsg_core/chapters/editions.py
+"""
+Matroska chapter editions.
+
+A chapter file holds one or more ``EditionEntry`` elements, each with its
+own list of ``ChapterAtom``s. Ordered editions (``EditionFlagOrdered``)
+describe a playback order of time ranges, which may overlap, repeat or
+point into other files via ``ChapterSegmentUID``; they must not be
+re-sorted, de-duplicated, snapped or given seamless ends. Processing
+therefore works per edition, and ordered editions only get their
+timestamps shifted (and renamed, when enabled).
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from lxml import etree as ET
+
+
+def _flag(edition: ET.Element, tag: str, nsmap: dict | None, prefix: str) -> bool:
+    node = edition.find(f"{prefix}{tag}", namespaces=nsmap)
+    return node is not None and (node.text or "").strip() == "1"
+
+
+@dataclass(frozen=True, slots=True)
+class Edition:
+    """One ``EditionEntry`` and its flags."""
+
+    element: ET.Element
+    index: int
+    uid: str | None
+    ordered: bool
+    hidden: bool
+    default: bool
+
+    def atoms(self, nsmap: dict | None, prefix: str) -> list[ET.Element]:
+        """Top-level chapter atoms of this edition, in document order."""
+        return self.element.findall(f"{prefix}ChapterAtom", namespaces=nsmap)
+
+    def linked_atoms(self, nsmap: dict | None, prefix: str) -> list[ET.Element]:
+        """Atoms that play a range of another file (``ChapterSegmentUID``)."""
+        return [
+            atom
+            for atom in self.element.iterfind(
+                f".//{prefix}ChapterAtom", namespaces=nsmap
+            )
+            if atom.find(f"{prefix}ChapterSegmentUID", namespaces=nsmap) is not None
+        ]
+
+    def describe(self) -> str:
+        flags = [
+            name
+            for name, on in (
+                ("ordered", self.ordered),
+                ("hidden", self.hidden),
+                ("default", self.default),
+            )
+            if on
+        ]
+        uid = f" uid={self.uid}" if self.uid else ""
+        return f"Edition {self.index + 1}{uid} [{', '.join(flags) or 'plain'}]"
+
+
+def parse_editions(root: ET.Element, nsmap: dict | None, prefix: str) -> list[Edition]:
+    """All ``EditionEntry`` elements of a chapter document."""
+    editions = []
+    for i, element in enumerate(
+        root.iterfind(f".//{prefix}EditionEntry", namespaces=nsmap)
+    ):
+        uid_node = element.find(f"{prefix}EditionUID", namespaces=nsmap)
+        uid = (uid_node.text or "").strip() if uid_node is not None else ""
+        editions.append(
+            Edition(
+                element=element,
+                index=i,
+                uid=uid or None,
+                ordered=_flag(element, "EditionFlagOrdered", nsmap, prefix),
+                hidden=_flag(element, "EditionFlagHidden", nsmap, prefix),
+                default=_flag(element, "EditionFlagDefault", nsmap, prefix),
+            )
+        )
+    return editions
@@ -7,7 +7,9 @@ from typing import TYPE_CHECKING, Any
 from lxml import etree as ET
 
 from ..io.runner import CommandRunner
+from .editions import parse_editions
 from .keyframes import probe_duration_ns, probe_keyframes_ns
+from .validate import ChapterTiming, repair, validate
 
 if TYPE_CHECKING:
     from vsg_core.models import AppSettings
@@ -100,7 +102,7 @@ def _normalize_and_dedupe_chapters(
 ):
     parent_map = {c: p for p in root.iter() for c in p}
 
-    all_atoms = root.xpath(f"//{prefix}ChapterAtom", namespaces=nsmap)
+    all_atoms = root.xpath(f".//{prefix}ChapterAtom", namespaces=nsmap)
     chapters = []
     for i, atom in enumerate(all_atoms):
         st_el = atom.find(f"{prefix}ChapterTimeStart", namespaces=nsmap)
@@ -183,6 +185,141 @@ def _normalize_and_dedupe_chapters(
             en_el.text = new_text
 
 
+def _shift_timestamps(
+    root: ET.Element,
+    delta_ns: int,
+    nsmap: dict,
+    prefix: str,
+    skip: list[ET.Element],
+) -> None:
+    """Shift start/end of every atom except the elements in ``skip``.
+
+    Compared by identity: lxml hands out a new proxy per lookup, so the id()
+    of an element that isn't held on to can be reused by another one.
+    """
+    for atom in root.xpath(f".//{prefix}ChapterAtom", namespaces=nsmap):
+        if any(atom is s for s in skip):
+            continue
+        for tag_name in ("ChapterTimeStart", "ChapterTimeEnd"):
+            node = atom.find(f"{prefix}{tag_name}", namespaces=nsmap)
+            if node is not None and node.text:
+                node.text = _fmt_ns(_parse_ns(node.text) + delta_ns)
+
+
+def _chapter_timings(
+    root: ET.Element, nsmap: dict, prefix: str
+) -> tuple[list[ET.Element], list[ChapterTiming]]:
+    """Atoms with a start time and their timings, in document order."""
+    atoms: list[ET.Element] = []
+    timings: list[ChapterTiming] = []
+    for atom in root.xpath(f".//{prefix}ChapterAtom", namespaces=nsmap):
+        st_el = atom.find(f"{prefix}ChapterTimeStart", namespaces=nsmap)
+        if st_el is None or not st_el.text:
+            continue
+        en_el = atom.find(f"{prefix}ChapterTimeEnd", namespaces=nsmap)
+        name_node = atom.find(f".//{prefix}ChapterString", namespaces=nsmap)
+        timings.append(
+            ChapterTiming(
+                name=name_node.text
+                if name_node is not None and name_node.text
+                else f"Chapter Atom {len(atoms) + 1}",
+                start_ns=_parse_ns(st_el.text),
+                end_ns=_parse_ns(en_el.text)
+                if en_el is not None and en_el.text
+                else None,
+                position=len(atoms),
+            )
+        )
+        atoms.append(atom)
+    return atoms, timings
+
+
+def read_chapter_timings(xml_content: str) -> list[ChapterTiming]:
+    """
+    Names and times of the chapters in an mkvextract chapter XML, for
+    callers that only read them (e.g. analysis skipping intro chapters).
+
+    Reads the first edition that isn't ordered (the whole document if it
+    has no editions). Unparsable XML gives an empty list.
+    """
+    if xml_content.startswith("\ufeff"):
+        xml_content = xml_content[1:]
+    try:
+        parser = ET.XMLParser(remove_blank_text=True, recover=True)
+        root = ET.fromstring(xml_content.encode("utf-8"), parser)
+    except ET.XMLSyntaxError:
+        return []
+    if root is None:
+        return []
+    nsmap, prefix = _get_xpath_and_nsmap(root)
+    editions = parse_editions(root, nsmap, prefix)
+    plain = [e for e in editions if not e.ordered]
+    if editions and not plain:
+        return []  # Only playback orders; no plain timeline to read
+    scope = plain[0].element if plain else root
+    try:
+        return _chapter_timings(scope, nsmap, prefix)[1]
+    except ValueError:  # Malformed timestamp
+        return []
+
+
+def _validate_and_repair_chapters(
+    root: ET.Element,
+    runner: CommandRunner,
+    nsmap: dict,
+    prefix: str,
+    file_duration_ns: int | None,
+) -> None:
+    """Log timestamp issues after shift/snap and fix the chapter order."""
+    atoms, timings = _chapter_timings(root, nsmap, prefix)
+    issues = validate(timings, file_duration_ns)
+    if not issues:
+        return
+    for issue in issues:
+        runner._log_message(f"[Chapters] [WARNING] {issue.describe()}")
+    if not any(i.kind in ("out_of_order", "duplicate_start") for i in issues):
+        return
+
+    repaired = repair(timings)
+    changed = 0
+    for after in repaired:
+        before = timings[after.position]
+        atom = atoms[after.position]
+        if after.start_ns != before.start_ns:
+            changed += 1
+            atom.find(f"{prefix}ChapterTimeStart", namespaces=nsmap).text = _fmt_ns(
+                after.start_ns
+            )
+            runner._log_message(
+                f"  - Nudged '{after.name}' start: "
+                f"{_fmt_ns_for_log(before.start_ns)} -> "
+                f"{_fmt_ns_for_log(after.start_ns)}"
+            )
+        en_el = atom.find(f"{prefix}ChapterTimeEnd", namespaces=nsmap)
+        if en_el is not None and after.end_ns is not None:
+            en_el.text = _fmt_ns(after.end_ns)
+
+    # Re-order sibling atoms to match the repaired start order
+    # By identity: ``atoms`` keeps these proxies alive, and lxml returns a
+    # live element's existing proxy, so ``is`` matches across lookups
+    order = [atoms[t.position] for t in repaired]
+    parents: list[ET.Element] = []
+    for atom in atoms:
+        parent = atom.getparent()
+        if not any(parent is p for p in parents):
+            parents.append(parent)
+    for parent in parents:
+        siblings = [a for a in order if a.getparent() is parent]
+        for child in siblings:
+            parent.remove(child)
+        for child in siblings:
+            parent.append(child)
+
+    runner._log_message(
+        f"[Chapters] Repaired chapter order ({changed} start(s) nudged)."
+    )
+
+
 def _extract_language_from_display(display_node: ET.Element, nsmap: dict, prefix: str):
     """Extract both language fields from a ChapterDisplay node, returning tuple (chapter_lang, ietf_lang)."""
     try:
@@ -323,6 +460,34 @@ def process_chapters(
         # Detect namespace and get the correct prefix for XPath queries
         nsmap, prefix = _get_xpath_and_nsmap(root)
 
+        # Editions are processed independently. Ordered editions describe a
+        # playback order (ranges may overlap or repeat), so they are only
+        # shifted and renamed; snap, repair and end normalization would
+        # corrupt them. Atoms linked to another file keep their timestamps.
+        editions = parse_editions(root, nsmap, prefix)
+        for edition in editions:
+            runner._log_message(
+                f"[Chapters] {edition.describe()}: "
+                f"{len(edition.atoms(nsmap, prefix))} chapter(s)."
+            )
+            if edition.ordered:
+                runner._log_message(
+                    f"[Chapters] [WARNING] Edition {edition.index + 1} uses "
+                    "ordered chapters; snapping, repair and end normalization "
+                    "are not supported for it. Passing it through with "
+                    "shifted timestamps only."
+                )
+        plain_scopes = [e.element for e in editions if not e.ordered]
+        if not editions:
+            plain_scopes = [root]
+        linked = [atom for e in editions for atom in e.linked_atoms(nsmap, prefix)]
+        if linked:
+            runner._log_message(
+                f"[Chapters] [WARNING] {len(linked)} chapter(s) reference "
+                "another file (ChapterSegmentUID); their timestamps are left "
+                "unmodified."
+            )
+
         # Donor mode "chapter 1 = file start" preservation: capture the
         # chronologically-first ChapterAtom and whether it was originally
         # at exactly 00:00:00. Used later to undo a positive shift that
@@ -353,10 +518,7 @@ def process_chapters(
                 f"{_fmt_delta_for_log(donor_offset_ns)} "
                 f"(donor \u2192 Source 1 video time)."
             )
-            for tag_name in ["ChapterTimeStart", "ChapterTimeEnd"]:
-                for node in root.xpath(f"//{prefix}{tag_name}", namespaces=nsmap):
-                    if node is not None and node.text:
-                        node.text = _fmt_ns(_parse_ns(node.text) + donor_offset_ns)
+            _shift_timestamps(root, donor_offset_ns, nsmap, prefix, linked)
 
         # Pin first-in-order chapter back to 0 if (a) it was originally
         # at 0 in the donor and (b) the donor offset has pushed it past 0.
@@ -391,9 +553,10 @@ def process_chapters(
         if settings.snap_chapters:
             keyframes_ns = probe_keyframes_ns(keyframe_source, runner, tool_paths)
             if keyframes_ns:
-                _snap_chapter_times_inplace(
-                    root, keyframes_ns, settings, runner, nsmap, prefix
-                )
+                for scope in plain_scopes:
+                    _snap_chapter_times_inplace(
+                        scope, keyframes_ns, settings, runner, nsmap, prefix
+                    )
             else:
                 runner._log_message(
                     "[Chapters] Snap skipped: could not load keyframes."
@@ -404,10 +567,7 @@ def process_chapters(
         shift_ns = shift_ms * 1_000_000
         if shift_ns != 0:
             runner._log_message(f"[Chapters] Shifting all timestamps by +{shift_ms}ms.")
-            for tag_name in ["ChapterTimeStart", "ChapterTimeEnd"]:
-                for node in root.xpath(f"//{prefix}{tag_name}", namespaces=nsmap):
-                    if node is not None and node.text:
-                        node.text = _fmt_ns(_parse_ns(node.text) + shift_ns)
+            _shift_timestamps(root, shift_ns, nsmap, prefix, linked)
 
         # Probe the final video's duration so the normalizer can clamp
         # the LAST chapter's ChapterTimeEnd to the actual end of file
@@ -421,18 +581,37 @@ def process_chapters(
                 f"[Chapters] Probed file duration: {_fmt_ns_for_log(file_duration_ns)}"
             )
 
+        # Shifting clamps negatives to 0 and snapping can merge starts:
+        # check and restore a strictly increasing order before normalizing
+        for scope in plain_scopes:
+            _validate_and_repair_chapters(
+                scope, runner, nsmap, prefix, file_duration_ns
+            )
+
         runner._log_message("[Chapters] Normalizing chapter data...")
-        _normalize_and_dedupe_chapters(
-            root, runner, nsmap, prefix, file_duration_ns=file_duration_ns
-        )
+        for scope in plain_scopes:
+            _normalize_and_dedupe_chapters(
+                scope, runner, nsmap, prefix, file_duration_ns=file_duration_ns
+            )
+            _, final_timings = _chapter_timings(scope, nsmap, prefix)
+            for issue in validate(final_timings, file_duration_ns):
+                runner._log_message(
+                    f"[Chapters] [WARNING] After repair: {issue.describe()}"
+                )
 
         if settings.rename_chapters:
             runner._log_message('[Chapters] Renaming chapters to "Chapter NN"...')
 
-            # Use consistent namespace-aware XPath query
-            final_chapter_atoms = root.xpath(f"//{prefix}ChapterAtom", namespaces=nsmap)
+            # Numbering restarts in every edition
+            final_chapter_atoms = [
+                (i, atom)
+                for scope in ([e.element for e in editions] or [root])
+                for i, atom in enumerate(
+                    scope.xpath(f".//{prefix}ChapterAtom", namespaces=nsmap), 1
+                )
+            ]
 
-            for i, atom in enumerate(final_chapter_atoms, 1):
+            for i, atom in final_chapter_atoms:
                 # Find the first ChapterDisplay and extract both language fields
                 original_lang = "und"  # Default fallback for ChapterLanguage
                 original_ietf = "und"  # Default fallback for ChapLanguageIETF
@@ -511,7 +690,7 @@ def _snap_chapter_times_inplace(
             return prev_kf if abs(ts_ns - prev_kf) <= abs(ts_ns - next_kf) else next_kf
 
     # Use consistent namespace-aware XPath query
-    chapter_atoms = root.xpath(f"//{prefix}ChapterAtom", namespaces=nsmap)
+    chapter_atoms = root.xpath(f".//{prefix}ChapterAtom", namespaces=nsmap)
     for i, atom in enumerate(chapter_atoms):
         tags_to_snap = (
             ["ChapterTimeStart"]
@@ -0,0 +1,130 @@
+# vsg_core/chapters/validate.py
+"""
+Chapter timestamp validation and repair.
+
+Shifting clamps negative timestamps to 0 and snapping can pull two
+chapters onto the same keyframe, so after processing two chapters may
+share a start or end up out of order. ``validate`` reports such problems;
+``repair`` restores a strictly increasing order by re-sorting and nudging
+colliding starts forward by a minimal epsilon.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, replace
+from typing import Literal
+
+# Smallest nudge that survives millisecond-precision players
+CHAPTER_EPSILON_NS = 1_000_000
+
+ChapterIssueKind = Literal[
+    "out_of_order", "duplicate_start", "zero_length", "beyond_duration"
+]
+
+
+@dataclass(frozen=True, slots=True)
+class ChapterTiming:
+    """Start/end of one chapter atom, in document order."""
+
+    name: str
+    start_ns: int
+    end_ns: int | None = None
+    position: int = 0  # Index in the source document; preserved by repair
+
+
+@dataclass(frozen=True, slots=True)
+class ChapterIssue:
+    kind: ChapterIssueKind
+    index: int  # Position in the validated list
+    name: str
+    start_ns: int
+    detail: str
+
+    def describe(self) -> str:
+        return f"{self.kind}: '{self.name}' ({self.detail})"
+
+
+def _ms(ns: int) -> str:
+    return f"{ns / 1_000_000:.3f}ms"
+
+
+def validate(
+    chapters: list[ChapterTiming], duration_ns: int | None = None
+) -> list[ChapterIssue]:
+    """Detect non-monotonic starts, zero-length chapters and starts past
+    the end of the file (when ``duration_ns`` is known)."""
+    issues: list[ChapterIssue] = []
+    for i, ch in enumerate(chapters):
+        if i > 0:
+            prev = chapters[i - 1]
+            if ch.start_ns == prev.start_ns:
+                issues.append(
+                    ChapterIssue(
+                        "duplicate_start",
+                        i,
+                        ch.name,
+                        ch.start_ns,
+                        f"same start as '{prev.name}' at {_ms(ch.start_ns)}",
+                    )
+                )
+            elif ch.start_ns < prev.start_ns:
+                issues.append(
+                    ChapterIssue(
+                        "out_of_order",
+                        i,
+                        ch.name,
+                        ch.start_ns,
+                        f"starts at {_ms(ch.start_ns)}, before '{prev.name}' "
+                        f"at {_ms(prev.start_ns)}",
+                    )
+                )
+        if ch.end_ns is not None and ch.end_ns <= ch.start_ns:
+            issues.append(
+                ChapterIssue(
+                    "zero_length",
+                    i,
+                    ch.name,
+                    ch.start_ns,
+                    f"end {_ms(ch.end_ns)} <= start {_ms(ch.start_ns)}",
+                )
+            )
+        if duration_ns is not None and ch.start_ns >= duration_ns:
+            issues.append(
+                ChapterIssue(
+                    "beyond_duration",
+                    i,
+                    ch.name,
+                    ch.start_ns,
+                    f"starts at {_ms(ch.start_ns)}, file ends at {_ms(duration_ns)}",
+                )
+            )
+    return issues
+
+
+def repair(
+    chapters: list[ChapterTiming], epsilon_ns: int = CHAPTER_EPSILON_NS
+) -> list[ChapterTiming]:
+    """Sort by start and nudge colliding starts forward by ``epsilon_ns``.
+
+    The sort is stable, so chapters that collided keep their document
+    order. Exact duplicates (same start and same name) are left as-is for
+    the de-duplication pass to remove. Ends are kept at least
+    ``epsilon_ns`` after the (possibly nudged) start.
+    """
+    ordered = sorted(chapters, key=lambda c: c.start_ns)
+    repaired: list[ChapterTiming] = []
+    seen: set[tuple[int, str]] = set()
+    last_start: int | None = None
+    for ch in ordered:
+        key = (ch.start_ns, ch.name)
+        if key in seen:
+            repaired.append(ch)
+            continue
+        seen.add(key)
+        if last_start is not None and ch.start_ns <= last_start:
+            ch = replace(ch, start_ns=last_start + epsilon_ns)
+        if ch.end_ns is not None and ch.end_ns < ch.start_ns + epsilon_ns:
+            ch = replace(ch, end_ns=ch.start_ns + epsilon_ns)
+        last_start = ch.start_ns
+        repaired.append(ch)
+    return repaired
@@ -0,0 +1,615 @@
+# vsg_core/cli.py
+"""
+Headless command line (``vsg-cli``): the GUI's job flow without Qt.
+
+    vsg-cli scan SOURCE1 [SOURCE2 ...]
+    vsg-cli analyze-only SOURCE1 SOURCE2 [SOURCE3 ...]
+    vsg-cli run SOURCE1 SOURCE2 [SOURCE3 ...] --layout LAYOUT.json
+    vsg-cli watch FOLDER1 FOLDER2 [...] --layout LAYOUT.json [--analyze-only]
+    vsg-cli sync-subs VIDEO SUBTITLE [--offset MS] [--source-video FILE]
+
+Sources are given in order (the first is Source 1, the reference). Files
+make one job; folders make a batch, paired by ``discover_jobs`` like the
+Add Job dialog does. Settings come from the app's settings.json or
+``--settings FILE``; ``--preset FILE`` (a JSON object of settings) and
+``--set KEY=VALUE`` are merged over them for this run only.
+
+``run`` needs a layout: a saved job layout (``<temp_root>/job_layouts/``)
+or a JSON list of layout items. It is applied to every job the way the job
+queue pastes layouts, matching tracks by ``layout_match_strategy``; a job
+whose files don't fit the layout is reported as failed and not run.
+
+Jobs go through preflight and the batch queue runner (``JobPipeline``,
+one job log per job, as in the GUI). Text mode prints progress and a
+summary; ``--json`` prints one JSON document with the results (for
+``scan``: the track listings) and nothing else on stdout. Exit code 0 when
+every job succeeded, 1 when any job failed or needs review, 2 for bad
+arguments, settings or layouts.
+
+``watch`` keeps running (until Ctrl+C or SIGTERM) and runs a job, one at a
+time, whenever every folder has a matching file that has finished copying
+(see ``vsg_core.watch``, which also covers how restarts avoid redoing
+jobs). Each finished job is a line of output; with ``--json``, one JSON
+result per line.
+
+``sync-subs`` retimes one subtitle file to VIDEO without a mux job (see
+``vsg_core.subtitle_sync``): by a given offset, or by the offset analysis
+measures against the video the subtitle was timed to. The result is
+written next to the subtitle as ``<name>.synced.<ext>`` unless ``-o``
+says otherwise.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import os
+import shutil
+import signal
+import sys
+import threading
+from dataclasses import asdict
+from pathlib import Path
+from typing import TYPE_CHECKING, Any, get_args
+
+from .models.types import SubtitleOutputFormatStr, SubtitleSyncModeStr
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from .extraction.tracks import SourceScan
+    from .models.jobs import PipelineResult
+    from .models.settings import AppSettings
+    from .models.types import LayoutMatchStrategyStr
+
+# Same environment as main.py, set before numpy/torch load (see main.py)
+_ENVIRONMENT = {
+    "OMP_NUM_THREADS": "1",
+    "OPENBLAS_NUM_THREADS": "1",
+    "MKL_NUM_THREADS": "1",
+    "VECLIB_MAXIMUM_THREADS": "1",
+    "NUMEXPR_NUM_THREADS": "1",
+    "HIP_VISIBLE_DEVICES": "0",
+}
+
+_SCAN_TOOLS = ("mkvmerge", "mkvextract", "ffmpeg", "ffprobe")
+
+
+class CliError(Exception):
+    """A problem with the arguments, settings or layout (exit code 2)."""
+
+
+def build_parser() -> argparse.ArgumentParser:
+    parser = argparse.ArgumentParser(
+        prog="vsg-cli", description="Video/Audio Sync & Merge without the GUI."
+    )
+    commands = parser.add_subparsers(dest="command", required=True)
+
+    options = argparse.ArgumentParser(add_help=False)
+    options.add_argument("--json", action="store_true", help="print results as JSON")
+    options.add_argument(
+        "--settings", type=Path, help="settings.json to use instead of the app's"
+    )
+    options.add_argument(
+        "--preset", type=Path, help="JSON object of settings merged over them"
+    )
+    options.add_argument(
+        "--set",
+        dest="overrides",
+        action="append",
+        default=[],
+        metavar="KEY=VALUE",
+        help="override one setting (VALUE is parsed as JSON, else a string)",
+    )
+    options.add_argument(
+        "-v", "--verbose", action="store_true", help="echo the job logs (to stderr)"
+    )
+
+    common = argparse.ArgumentParser(add_help=False, parents=[options])
+    common.add_argument(
+        "sources",
+        nargs="+",
+        help="Source 1 (reference), Source 2, ... (files or folders)",
+    )
+
+    commands.add_parser(
+        "scan", parents=[common], help="list the tracks of each source"
+    )
+    for name, help_text in (
+        ("analyze-only", "compute the delays without merging"),
+        ("run", "analyze and merge with a layout"),
+    ):
+        sub = commands.add_parser(name, parents=[common], help=help_text)
+        sub.add_argument("-o", "--output", help="output folder (default: settings)")
+        sub.add_argument(
+            "--layout",
+            type=Path,
+            required=name == "run",
+            help="saved job layout or JSON list of layout items",
+        )
+
+    from .watch import DEFAULT_POLL_S, DEFAULT_SETTLE_S
+
+    watch = commands.add_parser(
+        "watch",
+        parents=[common],
+        help="run jobs as matching files appear in the folders",
+    )
+    watch.add_argument("-o", "--output", help="output folder (default: settings)")
+    watch.add_argument(
+        "--layout", type=Path, help="saved job layout or JSON list of layout items"
+    )
+    watch.add_argument(
+        "--analyze-only",
+        action="store_true",
+        help="compute the delays without merging (no layout needed)",
+    )
+    watch.add_argument(
+        "--settle",
+        type=float,
+        default=DEFAULT_SETTLE_S,
+        metavar="SECONDS",
+        help="how long a file must stay unchanged before it is used "
+        "(default: %(default)g)",
+    )
+    watch.add_argument(
+        "--poll",
+        type=float,
+        default=DEFAULT_POLL_S,
+        metavar="SECONDS",
+        help="rescan interval (default: %(default)g)",
+    )
+
+    sync_subs = commands.add_parser(
+        "sync-subs",
+        parents=[options],
+        help="retime one subtitle file to a video, without muxing",
+    )
+    sync_subs.add_argument("video", help="reference video the subtitle should fit")
+    sync_subs.add_argument("subtitle", help="subtitle file (SRT, ASS, SSA)")
+    sync_subs.add_argument(
+        "--offset", type=float, metavar="MS", help="shift by this many ms"
+    )
+    sync_subs.add_argument(
+        "--source-video",
+        metavar="FILE",
+        help="video the subtitle is timed to (analyzed when --offset is not "
+        "given; needed by video-verified)",
+    )
+    sync_subs.add_argument(
+        "--mode",
+        choices=get_args(SubtitleSyncModeStr),
+        help="sync mode (default: settings)",
+    )
+    sync_subs.add_argument(
+        "-o", "--output", help="output file (default: <name>.synced.<ext>)"
+    )
+    sync_subs.add_argument(
+        "--format",
+        choices=get_args(SubtitleOutputFormatStr),
+        default="keep",
+        help="output format (default: %(default)s)",
+    )
+    return parser
+
+
+def source_map(paths: list[str]) -> dict[str, str]:
+    """Positional sources as {"Source 1": ..., "Source 2": ...}."""
+    return {f"Source {i}": str(p) for i, p in enumerate(paths, 1)}
+
+
+def parse_overrides(items: list[str]) -> dict[str, Any]:
+    """``--set KEY=VALUE`` arguments as a settings dict."""
+    overrides: dict[str, Any] = {}
+    for item in items:
+        key, sep, raw = item.partition("=")
+        if not sep or not key.strip():
+            raise CliError(f"--set expects KEY=VALUE, got '{item}'")
+        try:
+            overrides[key.strip()] = json.loads(raw)
+        except json.JSONDecodeError:
+            overrides[key.strip()] = raw
+    return overrides
+
+
+def _read_json(path: Path, what: str) -> Any:
+    try:
+        return json.loads(path.read_text(encoding="utf-8"))
+    except (OSError, ValueError) as e:
+        raise CliError(f"Could not read {what} {path}: {e}") from e
+
+
+def load_settings(args: argparse.Namespace) -> tuple[AppSettings, list[str]]:
+    """Effective settings for the run, and log lines for the overrides."""
+    from .config import AppConfig
+    from .models.overrides import apply_overrides, validate_overrides
+
+    if args.settings is not None and not args.settings.is_file():
+        raise CliError(f"Settings file not found: {args.settings}")
+    config = AppConfig(str(args.settings.resolve())) if args.settings else AppConfig()
+
+    overrides: dict[str, Any] = {}
+    if args.preset is not None:
+        preset = _read_json(args.preset, "preset")
+        if not isinstance(preset, dict):
+            raise CliError(f"Preset {args.preset} must be a JSON object of settings")
+        overrides.update(preset)
+    overrides.update(parse_overrides(args.overrides))
+    problems = validate_overrides(overrides)
+    if problems:
+        raise CliError("Invalid settings:\n  " + "\n  ".join(problems))
+    return apply_overrides(config.settings, overrides)
+
+
+def load_layout(path: Path) -> dict[str, Any]:
+    """
+    A layout file as saved job layout fields (``enhanced_layout``,
+    ``attachment_sources``, ``source_settings``, ``chapter_source``).
+    """
+    data = _read_json(path, "layout")
+    if isinstance(data, list):
+        data = {"enhanced_layout": data}
+    if not isinstance(data, dict) or not isinstance(data.get("enhanced_layout"), list):
+        raise CliError(
+            f"Layout {path} must be a saved job layout or a JSON list of tracks"
+        )
+    for i, item in enumerate(data["enhanced_layout"]):
+        if not isinstance(item, dict) or not {"source", "type", "id"} <= item.keys():
+            raise CliError(f"Layout item {i} needs 'source', 'type' and 'id'")
+    items = sorted(data["enhanced_layout"], key=lambda t: t.get("user_order_index", 0))
+    # Position of each track among its source's tracks of the same type, for
+    # matching by position (saved layouts already carry it)
+    positions: dict[tuple[str, str], int] = {}
+    for item in items:
+        key = (item["source"], item["type"])
+        item.setdefault("position_in_source_type", positions.get(key, 0))
+        positions[key] = positions.get(key, 0) + 1
+    return {
+        "enhanced_layout": items,
+        "attachment_sources": data.get("attachment_sources", []),
+        "source_settings": data.get("source_settings", {}),
+        "chapter_source": data.get("chapter_source") or "Source 1",
+    }
+
+
+def apply_layout(
+    job: dict[str, Any],
+    layout: dict[str, Any],
+    track_info: dict[str, list[dict]],
+    strategy: LayoutMatchStrategyStr,
+    log: Callable[[str], None] | None = None,
+) -> list[str]:
+    """
+    Sets the job's layout fields from ``layout``, re-pointed at the job's
+    files. Returns the mismatches instead (job untouched) when it doesn't fit.
+    """
+    from .job_layouts.manager import repoint_layout
+    from .job_layouts.validation import rebind_layout, validate_against
+    from .models.source_input import primary_sources
+
+    mismatches = validate_against(layout["enhanced_layout"], track_info, strategy)
+    if mismatches:
+        return [m.describe() for m in mismatches]
+    items = repoint_layout(layout["enhanced_layout"], primary_sources(job["sources"]))
+    job["manual_layout"] = rebind_layout(items, track_info, strategy, log)
+    job["attachment_sources"] = list(layout["attachment_sources"])
+    job["source_settings"] = dict(layout["source_settings"])
+    job["chapter_source"] = layout["chapter_source"]
+    return []
+
+
+class _Output:
+    """Where progress and logs go: stdout in text mode, stderr with --json."""
+
+    def __init__(self, as_json: bool, verbose: bool):
+        self.as_json = as_json
+        self.verbose = verbose
+        self._last_pct = -1
+
+    def info(self, msg: str) -> None:
+        print(msg, file=sys.stderr if self.as_json else sys.stdout, flush=True)
+
+    def log(self, msg: str) -> None:
+        if self.verbose:
+            print(msg, file=sys.stderr, flush=True)
+
+    def progress(self, index: int, job_frac: float, batch_frac: float) -> None:
+        pct = int(batch_frac * 100) // 10 * 10
+        if pct > self._last_pct:
+            self._last_pct = pct
+            if not self.as_json:
+                self.info(f"[Progress] {pct}%")
+
+
+def _scan(
+    sources: dict[str, str], settings: AppSettings, out: _Output
+) -> dict[str, SourceScan]:
+    from .extraction.tracks import scan_sources
+    from .io.runner import CommandRunner
+
+    runner = CommandRunner(settings, out.log)
+    tool_paths = {t: shutil.which(t) for t in _SCAN_TOOLS}
+    return scan_sources(sources, runner, tool_paths)
+
+
+def cmd_scan(args: argparse.Namespace, settings: AppSettings, out: _Output) -> int:
+    scans = _scan(source_map(args.sources), settings, out)
+    if args.json:
+        listing = {k: asdict(s) for k, s in scans.items()}
+        print(json.dumps(listing, indent=2, default=str))
+    else:
+        for key, scan in scans.items():
+            out.info(f"{key}: {scan.path}")
+            if scan.error:
+                out.info(f"  ERROR: {scan.error}")
+            for t in scan.tracks:
+                out.info(
+                    f"  [{t['type'][0].upper()}-{t['id']}] "
+                    f"{t.get('description') or t.get('codec_id', '')}"
+                )
+            if scan.needs_remux:
+                out.info("  (read with ffprobe; needs a remux to MKV to merge)")
+    return 1 if any(s.error for s in scans.values()) else 0
+
+
+def _prepare_job(
+    job: dict[str, Any], layout: dict[str, Any], settings: AppSettings, out: _Output
+) -> PipelineResult | None:
+    """Applies ``layout`` to the job; a failed result when it doesn't fit."""
+    from .errors import ErrorKind
+    from .models.jobs import PipelineResult
+    from .models.source_input import primary_path, primary_sources
+
+    name = Path(primary_path(job["sources"]["Source 1"])).name
+    scans = _scan(primary_sources(job["sources"]), settings, out)
+    track_info = {k: s.tracks for k, s in scans.items()}
+    problems = [f"{k}: {s.error}" for k, s in scans.items() if s.error]
+    problems = problems or apply_layout(
+        job, layout, track_info, settings.layout_match_strategy, out.log
+    )
+    if not problems:
+        return None
+    out.info(f"[CLI] {name}: the layout doesn't fit this job:")
+    for problem in problems:
+        out.info(f"  {problem}")
+    return PipelineResult(
+        status="Failed",
+        name=name,
+        error="Layout doesn't fit: " + "; ".join(problems),
+        error_kind=ErrorKind.INVALID_LAYOUT,
+    )
+
+
+def cmd_jobs(
+    args: argparse.Namespace, settings: AppSettings, out: _Output, and_merge: bool
+) -> int:
+    from .errors import ErrorKind
+    from .job_discovery import discover_jobs
+    from .models.jobs import PipelineResult
+    from .models.source_input import primary_path
+    from .orchestrator.preflight import preflight
+    from .queue_runner import run_queue
+
+    try:
+        jobs = discover_jobs(
+            source_map(args.sources),
+            strategy=settings.discovery_strategy,
+            episode_regex=settings.discovery_regex,
+        )
+    except (ValueError, OSError) as e:
+        raise CliError(str(e)) from e
+    if not jobs:
+        raise CliError("No jobs found for these sources")
+    output_dir = args.output or settings.output_folder
+    out.info(f"[CLI] {len(jobs)} job(s) -> {output_dir}")
+
+    results: list[PipelineResult | None] = [None] * len(jobs)
+    layout = load_layout(args.layout) if args.layout else None
+    if layout is not None:
+        for i, job in enumerate(jobs):
+            results[i] = _prepare_job(job, layout, settings, out)
+
+    runnable = [i for i, r in enumerate(results) if r is None]
+    issues = preflight([jobs[i] for i in runnable], settings, output_dir, and_merge)
+    for issue in issues:
+        out.info(issue.describe())
+    if any(i.is_error for i in issues):
+        out.info("[CLI] Preflight failed; no jobs were started.")
+        for i in runnable:
+            name = Path(primary_path(jobs[i]["sources"]["Source 1"])).name
+            results[i] = PipelineResult(
+                status="Failed",
+                name=name,
+                error="Preflight",
+                error_kind=ErrorKind.VALIDATION,
+            )
+        runnable = []
+
+    def on_finished(index: int, result: PipelineResult) -> None:
+        results[runnable[index]] = result
+        detail = result.error or result.output or ""
+        out.info(f"[CLI] {result.status}: {result.name} {detail}".rstrip())
+
+    if runnable:
+        run_queue(
+            jobs=[jobs[i] for i in runnable],
+            settings=settings,
+            and_merge=and_merge,
+            output_dir=output_dir,
+            max_concurrent=settings.batch_max_concurrent_jobs,
+            log_callback=out.log,
+            progress_callback=out.progress,
+            job_finished_callback=on_finished,
+            stop_on_error=settings.batch_stop_on_error,
+        )
+
+    final = [r for r in results if r is not None]
+    if args.json:
+        print(
+            json.dumps(
+                {"command": args.command, "results": [asdict(r) for r in final]},
+                indent=2,
+                default=str,
+            )
+        )
+    else:
+        counts: dict[str, int] = {}
+        for r in final:
+            counts[r.status] = counts.get(r.status, 0) + 1
+        out.info(
+            "[CLI] Done: " + ", ".join(f"{n} {status}" for status, n in counts.items())
+        )
+    ok = {"Merged", "Analyzed"}
+    return 0 if final and all(r.status in ok for r in final) else 1
+
+
+def cmd_watch(args: argparse.Namespace, settings: AppSettings, out: _Output) -> int:
+    from .errors import ErrorKind
+    from .extraction.tracks import video_height
+    from .io.runner import CommandRunner
+    from .models.jobs import PipelineResult
+    from .models.source_input import primary_path
+    from .mux.output_name import output_name_fields, render_output_name
+    from .orchestrator.preflight import preflight
+    from .queue_runner import run_queue
+    from .watch import Watcher
+
+    folders = source_map(args.sources)
+    if len(folders) < 2:
+        raise CliError("watch needs at least two folders")
+    for key, folder in folders.items():
+        if not Path(folder).is_dir():
+            raise CliError(f"{key} is not a folder: {folder}")
+    if args.layout is None and not args.analyze_only:
+        raise CliError("watch needs --layout (or --analyze-only)")
+    if args.settle < 0 or args.poll <= 0:
+        raise CliError("--settle must be >= 0 and --poll > 0")
+    layout = load_layout(args.layout) if args.layout else None
+    and_merge = not args.analyze_only
+    output_dir = args.output or settings.output_folder
+
+    def run_job(job: dict[str, Any]) -> PipelineResult:
+        name = Path(primary_path(job["sources"]["Source 1"])).name
+        if layout is not None and and_merge:
+            failed = _prepare_job(job, layout, settings, out)
+            if failed is not None:
+                return failed
+        issues = preflight([job], settings, output_dir, and_merge)
+        for issue in issues:
+            out.info(issue.describe())
+        if any(i.is_error for i in issues):
+            return PipelineResult(
+                status="Failed",
+                name=name,
+                error="Preflight",
+                error_kind=ErrorKind.VALIDATION,
+            )
+        results = run_queue(
+            jobs=[job],
+            settings=settings,
+            and_merge=and_merge,
+            output_dir=output_dir,
+            max_concurrent=1,
+            log_callback=out.log,
+            progress_callback=lambda *_: None,
+        )
+        result = results[0]
+        if args.json:
+            print(json.dumps(asdict(result), default=str), flush=True)
+        return result
+
+    def output_name(sources: dict[str, Any]) -> str:
+        """The name MuxStep gives the job's output."""
+        template = settings.output_template
+        if not template:
+            return Path(primary_path(sources["Source 1"])).name
+        height = None
+        if "{resolution}" in template:
+            runner = CommandRunner(settings, out.log)
+            tool_paths = {t: shutil.which(t) for t in _SCAN_TOOLS}
+            height = video_height(
+                primary_path(sources["Source 1"]), runner, tool_paths
+            )
+        return render_output_name(template, output_name_fields(sources, height))
+
+    watcher = Watcher(
+        folders,
+        output_dir,
+        run_job,
+        out.info,
+        strategy=settings.discovery_strategy,
+        episode_regex=settings.discovery_regex,
+        settle_s=args.settle,
+        poll_s=args.poll,
+        output_name=output_name,
+    )
+    stop = threading.Event()
+    signal.signal(signal.SIGTERM, lambda *_: stop.set())
+    try:
+        watcher.run(stop)
+    except KeyboardInterrupt:
+        out.info("[Watch] Interrupted.")
+    return 0
+
+
+def cmd_sync_subs(
+    args: argparse.Namespace, settings: AppSettings, out: _Output
+) -> int:
+    from .subtitle_sync import SubtitleSyncOptions, sync_subtitle
+
+    options = SubtitleSyncOptions(
+        offset_ms=args.offset,
+        source_video=args.source_video or "",
+        sync_mode=args.mode or "",
+        output_path=args.output or "",
+        output_format=args.format,
+    )
+    try:
+        result = sync_subtitle(args.video, args.subtitle, options, settings, out.log)
+    except (FileNotFoundError, ValueError) as e:
+        raise CliError(str(e)) from e
+    except RuntimeError as e:
+        out.info(f"[CLI] Failed: {e}")
+        if args.json:
+            print(json.dumps({"command": args.command, "error": str(e)}, indent=2))
+        return 1
+
+    if args.json:
+        print(
+            json.dumps(
+                {"command": args.command, "result": asdict(result)},
+                indent=2,
+                default=str,
+            )
+        )
+    else:
+        out.info(
+            f"[CLI] Synced: {result.output_path} ({result.events} events, "
+            f"{result.sync_mode}, {result.delay_ms:+.3f}ms)"
+        )
+    return 0
+
+
+def main(argv: list[str] | None = None) -> int:
+    for key, value in _ENVIRONMENT.items():
+        os.environ.setdefault(key, value)
+    args = build_parser().parse_args(argv)
+    out = _Output(args.json, args.verbose)
+    try:
+        settings, override_lines = load_settings(args)
+        for line in override_lines:
+            out.info(line)
+        if args.command == "scan":
+            return cmd_scan(args, settings, out)
+        if args.command == "watch":
+            return cmd_watch(args, settings, out)
+        if args.command == "sync-subs":
+            return cmd_sync_subs(args, settings, out)
+        return cmd_jobs(args, settings, out, and_merge=args.command == "run")
+    except CliError as e:
+        print(f"vsg-cli: error: {e}", file=sys.stderr)
+        return 2
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -13,7 +13,7 @@ import json
 from pathlib import Path
 from typing import TYPE_CHECKING
 
-from ...analysis.types import ChunkResult, ClusterDiagnostic
+from ...analysis.types import ChunkResult, ClusterDiagnostic, RejectReason
 from ...audit.trail import NumpyJSONEncoder
 from .types import SteppingData
 
@@ -46,6 +46,7 @@ def save_stepping_data(
                 "match_pct": r.match_pct,
                 "start_s": r.start_s,
                 "accepted": r.accepted,
+                "reject_reason": r.reject_reason,
             }
             for r in chunk_results
         ],
@@ -83,6 +84,9 @@ def load_stepping_data(path: str | Path) -> SteppingData:
             match_pct=w["match_pct"],
             start_s=w["start_s"],
             accepted=w["accepted"],
+            reject_reason=(
+                RejectReason(w["reject_reason"]) if w.get("reject_reason") else None
+            ),
         )
         for w in raw["windows"]
     ]
@@ -20,6 +20,7 @@ if TYPE_CHECKING:
 
     from ...io.runner import CommandRunner
     from ...models.settings import AppSettings
+    from ...orchestrator.steps.context import Context
 
 
 def verify_correction(
@@ -31,12 +32,14 @@ def verify_correction(
     tool_paths: dict[str, str | None],
     log: Callable[[str], None],
     skip_mode: bool = False,
+    ctx: Context | None = None,
 ) -> tuple[bool, dict[str, object]]:
     """Verify corrected audio matches reference at *base_delay_ms*.
 
     Uses dense sliding-window correlation (same as the main analysis)
     to produce hundreds of delay estimates, then checks that the median
-    is near base_delay_ms and the variance is low.
+    is near base_delay_ms and the variance is low. With ``ctx`` the
+    reference decode goes through the job's decoded-audio cache.
 
     Returns ``(passed, metadata_dict)``.
     """
@@ -46,6 +49,7 @@ def verify_correction(
         get_audio_stream_info,
         normalize_lang,
     )
+    from ...analysis.correlation.audio_cache import decode_for_job
     from ...analysis.correlation.dense import run_dense_correlation
     from ...analysis.correlation.filtering import apply_bandpass, apply_lowpass
     from ...analysis.correlation.run import _resolve_method
@@ -72,9 +76,12 @@ def verify_correction(
 
         # --- 2. Decode ---
         use_soxr = settings.use_soxr
-        ref_pcm = decode_audio(
-            ref_file_path, idx_ref, DEFAULT_SR, use_soxr, runner, tool_paths
-        )
+        if ctx is not None:
+            ref_pcm = decode_for_job(ctx, ref_file_path, idx_ref, DEFAULT_SR, runner)
+        else:
+            ref_pcm = decode_audio(
+                ref_file_path, idx_ref, DEFAULT_SR, use_soxr, runner, tool_paths
+            )
         tgt_pcm = decode_audio(
             corrected_path, idx_tgt, DEFAULT_SR, use_soxr, runner, tool_paths
         )
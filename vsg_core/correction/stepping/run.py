@@ -298,7 +298,7 @@ def run_stepping_correction(ctx: Context, runner: CommandRunner) -> Context:
                 continue
 
             # --- QA: Assemble a mono check track and verify ---
-            qa_path = ctx.temp_dir / f"qa_{source_key.replace(' ', '_')}.flac"
+            qa_path = ctx.work_dir.audio / f"qa_{source_key.replace(' ', '_')}.flac"
             qa_ok = assemble_corrected_audio(
                 edl=edl,
                 target_audio_path=analysis_path,
@@ -333,6 +333,7 @@ def run_stepping_correction(ctx: Context, runner: CommandRunner) -> Context:
                 runner=runner,
                 tool_paths=ctx.tool_paths,
                 log=log,
+                ctx=ctx,
             )
             if not passed:
                 log("[SteppingCorrection] QA check FAILED — skipping correction.")
@@ -410,7 +411,7 @@ def run_stepping_correction(ctx: Context, runner: CommandRunner) -> Context:
                 if target_item.extracted_path is None:
                     log("[ERROR] Skipping track with no extracted path.")
                     continue
-                corrected_path = ctx.temp_dir / (
+                corrected_path = ctx.work_dir.audio / (
                     f"corrected_{target_item.extracted_path.stem}.flac"
                 )
                 ok = assemble_corrected_audio(
@@ -530,10 +531,10 @@ def _run_splice_verification(
     """
     from ...analysis.correlation import (
         DEFAULT_SR,
-        decode_audio,
         get_audio_stream_info,
         normalize_lang,
     )
+    from ...analysis.correlation.audio_cache import decode_for_job
     from .verify_splices import verify_splice_points
 
     ref_lang = normalize_lang(settings.analysis_lang_source1)
@@ -548,9 +549,7 @@ def _run_splice_verification(
     )
 
     try:
-        ref_pcm = decode_audio(
-            ref_file_path, idx_ref, DEFAULT_SR, settings.use_soxr, runner, tool_paths
-        )
+        ref_pcm = decode_for_job(ctx, ref_file_path, idx_ref, DEFAULT_SR, runner)
     except Exception as exc:
         log(f"  [Verify] Source 1 decode failed: {exc} — skipping verification")
         return
@@ -686,7 +685,7 @@ def _find_analysis_track(
     try:
         internal = extract_tracks(
             source_path,
-            ctx.temp_dir,
+            ctx.work_dir.extracted,
             runner,
             ctx.tool_paths,
             role=f"{source_key}_internal",
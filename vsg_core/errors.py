@@ -0,0 +1,128 @@
+# vsg_core/errors.py
+"""
+Typed errors for the job pipeline.
+
+Steps used to fail with a bare ``RuntimeError`` whose message was the only
+way to tell a missing tool from an unreliable analysis. Every failure a
+caller may want to react to (retry, skip, point the user at a setting) now
+raises a ``VsgError`` subclass; its ``kind`` travels with the job result
+(``PipelineResult.error_kind``) into the batch report, the CLI's JSON and
+the UI.
+
+The messages are unchanged, and each class keeps the built-in base it
+replaces (``ToolMissing`` is still a ``FileNotFoundError``,
+``InvalidLayout`` a ``ValueError``, everything a ``RuntimeError``), so
+existing ``except`` clauses keep working.
+
+The orchestrator wraps step failures ("Analysis phase failed: ...") with
+``raise ... from e``; ``error_kind`` follows that chain to the original.
+"""
+
+from __future__ import annotations
+
+from enum import StrEnum
+from typing import TYPE_CHECKING, ClassVar
+
+if TYPE_CHECKING:
+    from .io.retry import CommandFailure
+
+
+class ErrorKind(StrEnum):
+    """What went wrong, independent of the message."""
+
+    TOOL_MISSING = "tool_missing"
+    PROCESS_FAILED = "process_failed"
+    ANALYSIS_UNRELIABLE = "analysis_unreliable"
+    IO_ERROR = "io_error"
+    INVALID_LAYOUT = "invalid_layout"
+    INVALID_SETTINGS = "invalid_settings"
+    VALIDATION = "validation"  # A step finished without its expected results
+    CANCELLED = "cancelled"
+    INTERNAL = "internal"  # Anything not classified (a bug, most likely)
+
+    @property
+    def label(self) -> str:
+        """Short text for the UI ("Tool missing")."""
+        return self.value.replace("_", " ").capitalize().replace("Io ", "I/O ")
+
+
+class VsgError(RuntimeError):
+    """Base of the pipeline's typed errors."""
+
+    kind: ClassVar[ErrorKind] = ErrorKind.INTERNAL
+
+
+class ToolMissing(VsgError, FileNotFoundError):
+    """A required external tool is not installed (or not in PATH)."""
+
+    kind = ErrorKind.TOOL_MISSING
+
+    def __init__(self, message: str, tool: str = ""):
+        super().__init__(message)
+        self.tool = tool
+
+
+class ProcessFailed(VsgError):
+    """An external tool ran and failed (or could not be started)."""
+
+    kind = ErrorKind.PROCESS_FAILED
+
+    def __init__(self, message: str, failure: CommandFailure | None = None):
+        super().__init__(message)
+        self.failure = failure
+
+    @property
+    def tool(self) -> str:
+        return self.failure.tool if self.failure else ""
+
+    @property
+    def code(self) -> int | None:
+        """Exit code; None when unknown or the process never started."""
+        return self.failure.returncode if self.failure else None
+
+    @property
+    def stderr(self) -> str:
+        """Tail of the tool's output."""
+        return self.failure.output if self.failure else ""
+
+
+class AnalysisUnreliable(VsgError):
+    """No delay, or one too unreliable to apply, could be determined."""
+
+    kind = ErrorKind.ANALYSIS_UNRELIABLE
+
+
+class IoError(VsgError, OSError):
+    """Files that should exist are missing, empty or unwritable."""
+
+    kind = ErrorKind.IO_ERROR
+
+
+class InvalidLayout(VsgError, ValueError):
+    """The job's track layout can't be applied to its sources."""
+
+    kind = ErrorKind.INVALID_LAYOUT
+
+
+class Cancelled(VsgError):
+    """The job was stopped or skipped before it finished."""
+
+    kind = ErrorKind.CANCELLED
+
+
+def error_kind(exc: BaseException) -> ErrorKind:
+    """
+    Kind of a failure: the first ``VsgError`` in the exception's cause
+    chain; a plain ``OSError`` counts as an I/O error.
+    """
+    seen: set[int] = set()
+    current: BaseException | None = exc
+    fallback = ErrorKind.INTERNAL
+    while current is not None and id(current) not in seen:
+        seen.add(id(current))
+        if isinstance(current, VsgError):
+            return current.kind
+        if isinstance(current, OSError) and fallback is ErrorKind.INTERNAL:
+            fallback = ErrorKind.IO_ERROR
+        current = current.__cause__ or current.__context__
+    return fallback
@@ -1,86 +1,165 @@
 # vsg_core/extraction/attachments.py
+from __future__ import annotations
+
+from collections.abc import Callable, Iterable
+from dataclasses import dataclass
 from pathlib import Path
 
 from ..io.runner import CommandRunner
 from .tracks import get_stream_info
 
 
+@dataclass(frozen=True, slots=True)
+class ExtractedAttachment:
+    """An attachment written to disk by mkvextract."""
+
+    id: int
+    file_name: str  # Name inside the source file
+    content_type: str
+    size: int
+    path: Path
+    source: str  # Source key ("Source 1", ...) or other role label
+    is_font: bool
+
+    def matches(self, selector: str | int) -> bool:
+        """True if ``selector`` is this attachment's id or file name."""
+        text = str(selector).strip()
+        return text == str(self.id) or text.casefold() == self.file_name.casefold()
+
+
 def extract_attachments(
-    mkv: str, temp_dir: Path, runner: CommandRunner, tool_paths: dict, role: str
-) -> list[str]:
+    mkv: str,
+    temp_dir: Path,
+    runner: CommandRunner,
+    tool_paths: dict,
+    role: str,
+    select: Iterable[str | int] | None = None,
+    fonts_only: bool = True,
+) -> list[ExtractedAttachment]:
+    """Extract attachments of ``mkv`` into ``temp_dir`` with mkvextract.
+
+    Args:
+        role: Label for the source; prefixes the extracted file names.
+        select: Only these attachments (ids or file names); None = all.
+        fonts_only: Skip attachments that are not fonts (cover art etc.).
+    """
     info = get_stream_info(mkv, runner, tool_paths)
     if not info:
         return []
 
-    files, specs = [], []
-    font_count = 0
+    selectors = list(select) if select is not None else None
+    extracted: list[ExtractedAttachment] = []
+    specs = []
     total_attachments = len((info or {}).get("attachments", []))
 
     for attachment in (info or {}).get("attachments", []):
-        mime_type = attachment.get("content_type", "").lower()
-        file_name = attachment.get("file_name", "").lower()
-
-        # Comprehensive font detection covering all common cases
-        is_font = (
-            # Standard font MIME types
-            mime_type.startswith(("font/", "application/font", "application/x-font"))
-            or
-            # TrueType fonts (multiple variations)
-            mime_type
-            in ["application/x-truetype-font", "application/truetype", "font/ttf"]
-            or
-            # OpenType fonts (multiple variations)
-            mime_type
-            in ["application/vnd.ms-opentype", "application/opentype", "font/otf"]
-            or
-            # WOFF fonts
-            mime_type in ["application/font-woff", "font/woff", "font/woff2"]
-            or
-            # PostScript fonts
-            mime_type in ["application/postscript", "application/x-font-type1"]
-            or
-            # Generic binary (some MKVs use this for fonts)
-            (
-                mime_type in ["application/octet-stream", "binary/octet-stream"]
-                and file_name.endswith((".ttf", ".otf", ".ttc", ".woff", ".woff2"))
-            )
-            or
-            # Any MIME with 'font' or 'truetype' or 'opentype' in it
-            any(x in mime_type for x in ["font", "truetype", "opentype"])
-            or
-            # File extension fallback (most reliable)
-            file_name.endswith(
-                (
-                    ".ttf",
-                    ".otf",
-                    ".ttc",
-                    ".woff",
-                    ".woff2",
-                    ".eot",
-                    ".fon",
-                    ".fnt",
-                    ".pfb",
-                    ".pfa",
-                )
-            )
+        is_font = _is_font(attachment)
+        if fonts_only and not is_font:
+            continue
+        out_path = temp_dir / f"{role}_att_{attachment['id']}_{attachment['file_name']}"
+        item = ExtractedAttachment(
+            id=int(attachment["id"]),
+            file_name=attachment.get("file_name", ""),
+            content_type=attachment.get("content_type", ""),
+            size=int(attachment.get("size") or 0),
+            path=out_path,
+            source=role,
+            is_font=is_font,
         )
+        if selectors is not None and not any(item.matches(s) for s in selectors):
+            continue
+        specs.append(f"{item.id}:{out_path}")
+        extracted.append(item)
 
-        if is_font:
-            font_count += 1
-            out_path = (
-                temp_dir / f"{role}_att_{attachment['id']}_{attachment['file_name']}"
-            )
-            specs.append(f"{attachment['id']}:{out_path}")
-            files.append(str(out_path))
-
+    kind = "font file(s)" if fonts_only else "attachment(s)"
     if specs:
         runner._log_message(
-            f"[Attachments] Found {total_attachments} attachments, extracting {font_count} font file(s)..."
+            f"[Attachments] Found {total_attachments} attachments, "
+            f"extracting {len(specs)} {kind}..."
+        )
+        runner.run_with_retry(
+            ["mkvextract", str(mkv), "attachments", *specs], tool_paths
+        )
+    elif selectors is not None:
+        runner._log_message(
+            f"[Attachments] Found {total_attachments} attachments, "
+            "but none matched the selection."
         )
-        runner.run(["mkvextract", str(mkv), "attachments", *specs], tool_paths)
     else:
         runner._log_message(
             f"[Attachments] Found {total_attachments} attachments, but none were identified as fonts."
         )
 
-    return files
+    return extracted
+
+
+def dedupe_attachments(
+    attachments: list[ExtractedAttachment], log: Callable[[str], None]
+) -> list[ExtractedAttachment]:
+    """Drop attachments with the same file name and size as an earlier one.
+
+    Callers pass the reference source first, so its copy is the one kept.
+    """
+    kept: dict[tuple[str, int], ExtractedAttachment] = {}
+    for att in attachments:
+        size = att.path.stat().st_size if att.path.exists() else att.size
+        key = (att.file_name.casefold(), size)
+        first = kept.get(key)
+        if first is not None:
+            log(
+                f"[Attachments] Skipping duplicate '{att.file_name}' from "
+                f"{att.source} (same as {first.source})."
+            )
+            continue
+        kept[key] = att
+    return list(kept.values())
+
+
+def _is_font(attachment: dict) -> bool:
+    mime_type = attachment.get("content_type", "").lower()
+    file_name = attachment.get("file_name", "").lower()
+
+    # Comprehensive font detection covering all common cases
+    return (
+        # Standard font MIME types
+        mime_type.startswith(("font/", "application/font", "application/x-font"))
+        or
+        # TrueType fonts (multiple variations)
+        mime_type
+        in ["application/x-truetype-font", "application/truetype", "font/ttf"]
+        or
+        # OpenType fonts (multiple variations)
+        mime_type
+        in ["application/vnd.ms-opentype", "application/opentype", "font/otf"]
+        or
+        # WOFF fonts
+        mime_type in ["application/font-woff", "font/woff", "font/woff2"]
+        or
+        # PostScript fonts
+        mime_type in ["application/postscript", "application/x-font-type1"]
+        or
+        # Generic binary (some MKVs use this for fonts)
+        (
+            mime_type in ["application/octet-stream", "binary/octet-stream"]
+            and file_name.endswith((".ttf", ".otf", ".ttc", ".woff", ".woff2"))
+        )
+        or
+        # Any MIME with 'font' or 'truetype' or 'opentype' in it
+        any(x in mime_type for x in ["font", "truetype", "opentype"])
+        or
+        # File extension fallback (most reliable)
+        file_name.endswith(
+            (
+                ".ttf",
+                ".otf",
+                ".ttc",
+                ".woff",
+                ".woff2",
+                ".eot",
+                ".fon",
+                ".fnt",
+                ".pfb",
+                ".pfa",
+            )
+        )
+    )
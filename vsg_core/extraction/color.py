@@ -0,0 +1,222 @@
+# vsg_core/extraction/color.py
+"""
+Video colorimetry and HDR10 metadata probing via ffprobe.
+
+Probing the source MKV reports the container (Matroska ``Colour``) flags,
+falling back to the bitstream VUI when the container has none. Probing an
+extracted elementary stream (.h264/.h265) reports the bitstream VUI only.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from ..io.runner import CommandRunner
+
+# ffprobe reports these when a flag is absent
+_UNSET = {"", "unknown", "unspecified", "reserved", "reserved0"}
+
+
+def _clean(value: object) -> str | None:
+    text = str(value or "").strip().lower()
+    return None if text in _UNSET else text
+
+
+@dataclass(frozen=True, slots=True)
+class ColorInfo:
+    """Colorimetry flags using ffprobe's names (None = not signalled)."""
+
+    primaries: str | None = None
+    transfer: str | None = None
+    matrix: str | None = None
+    range: str | None = None
+
+    def is_empty(self) -> bool:
+        return not any((self.primaries, self.transfer, self.matrix, self.range))
+
+    def describe(self) -> str:
+        return (
+            f"primaries={self.primaries or '-'}, transfer={self.transfer or '-'}, "
+            f"matrix={self.matrix or '-'}, range={self.range or '-'}"
+        )
+
+
+def probe_color(
+    path: str | Path,
+    runner: CommandRunner,
+    tool_paths: dict,
+    stream_index: int | None = None,
+) -> ColorInfo | None:
+    """Read colorimetry of a video stream (first video stream by default).
+
+    Returns None if ffprobe fails or the file has no video stream.
+    """
+    select = str(stream_index) if stream_index is not None else "v:0"
+    cmd = [
+        "ffprobe",
+        "-v",
+        "error",
+        "-select_streams",
+        select,
+        "-show_entries",
+        "stream=codec_type,color_space,color_transfer,color_primaries,color_range",
+        "-of",
+        "json",
+        str(path),
+    ]
+    out = runner.run(cmd, tool_paths)
+    if not out:
+        return None
+    try:
+        streams = json.loads(out).get("streams", [])
+    except (json.JSONDecodeError, AttributeError):
+        return None
+    stream = next((s for s in streams if s.get("codec_type") == "video"), None)
+    if stream is None:
+        return None
+    return ColorInfo(
+        primaries=_clean(stream.get("color_primaries")),
+        transfer=_clean(stream.get("color_transfer")),
+        matrix=_clean(stream.get("color_space")),
+        range=_clean(stream.get("color_range")),
+    )
+
+
+def _ratio(value: object) -> float | None:
+    """Parse ffprobe rationals like ``"35400/50000"`` (or plain numbers)."""
+    if value is None:
+        return None
+    text = str(value)
+    try:
+        if "/" in text:
+            num, den = text.split("/", 1)
+            return float(num) / float(den) if float(den) else None
+        return float(text)
+    except ValueError:
+        return None
+
+
+@dataclass(frozen=True, slots=True)
+class Hdr10Metadata:
+    """HDR10 static metadata (SMPTE ST 2086 mastering display + CTA-861.3)."""
+
+    # Mastering display: CIE 1931 xy chromaticities and luminance (cd/m²)
+    red: tuple[float, float] | None = None
+    green: tuple[float, float] | None = None
+    blue: tuple[float, float] | None = None
+    white_point: tuple[float, float] | None = None
+    min_luminance: float | None = None
+    max_luminance: float | None = None
+    # Content light level (cd/m²)
+    max_cll: int | None = None
+    max_fall: int | None = None
+
+    def has_mastering_display(self) -> bool:
+        return None not in (self.red, self.green, self.blue, self.white_point)
+
+    def mkvmerge_options(self) -> list[tuple[str, str]]:
+        """(option, value) pairs for mkvmerge, without the ``0:`` track prefix."""
+        opts: list[tuple[str, str]] = []
+        if self.has_mastering_display():
+            coords = (*self.red, *self.green, *self.blue)  # type: ignore[misc]
+            opts.append(
+                ("chromaticity-coordinates", ",".join(f"{c:.5f}" for c in coords))
+            )
+            wx, wy = self.white_point  # type: ignore[misc]
+            opts.append(("white-colour-coordinates", f"{wx:.5f},{wy:.5f}"))
+        if self.max_luminance is not None:
+            opts.append(("max-luminance", f"{self.max_luminance:g}"))
+        if self.min_luminance is not None:
+            opts.append(("min-luminance", f"{self.min_luminance:g}"))
+        if self.max_cll is not None:
+            opts.append(("max-content-light", str(self.max_cll)))
+        if self.max_fall is not None:
+            opts.append(("max-frame-light", str(self.max_fall)))
+        return opts
+
+    def describe(self) -> str:
+        parts = []
+        if self.max_luminance is not None:
+            parts.append(
+                f"mastering {self.min_luminance or 0:g}-{self.max_luminance:g} nits"
+            )
+        if self.max_cll is not None or self.max_fall is not None:
+            parts.append(f"MaxCLL={self.max_cll}, MaxFALL={self.max_fall}")
+        return ", ".join(parts) or "empty"
+
+
+def _parse_hdr10_side_data(side_data: list[dict]) -> Hdr10Metadata | None:
+    fields: dict[str, object] = {}
+    for sd in side_data:
+        kind = str(sd.get("side_data_type", "")).lower()
+        if kind.startswith("mastering display"):
+            points = {}
+            for name in ("red", "green", "blue", "white_point"):
+                x, y = _ratio(sd.get(f"{name}_x")), _ratio(sd.get(f"{name}_y"))
+                if x is not None and y is not None:
+                    points[name] = (x, y)
+            fields.update(points)
+            fields["min_luminance"] = _ratio(sd.get("min_luminance"))
+            fields["max_luminance"] = _ratio(sd.get("max_luminance"))
+        elif kind.startswith("content light level"):
+            if sd.get("max_content") is not None:
+                fields["max_cll"] = int(sd["max_content"])
+            if sd.get("max_average") is not None:
+                fields["max_fall"] = int(sd["max_average"])
+    if not fields:
+        return None
+    return Hdr10Metadata(**fields)  # type: ignore[arg-type]
+
+
+def probe_hdr10(
+    path: str | Path,
+    runner: CommandRunner,
+    tool_paths: dict,
+    stream_index: int | None = None,
+) -> Hdr10Metadata | None:
+    """Read HDR10 static metadata of a video stream.
+
+    Checks the stream (container) side data first, then the first decoded
+    frame's side data (bitstream SEI). Returns None for SDR content.
+    """
+    select = str(stream_index) if stream_index is not None else "v:0"
+    base = ["ffprobe", "-v", "error", "-select_streams", select, "-of", "json"]
+
+    out = runner.run(
+        [*base, "-show_entries", "stream=side_data_list", str(path)], tool_paths
+    )
+    if out:
+        try:
+            for stream in json.loads(out).get("streams", []):
+                meta = _parse_hdr10_side_data(stream.get("side_data_list", []))
+                if meta:
+                    return meta
+        except (json.JSONDecodeError, AttributeError, ValueError):
+            pass
+
+    out = runner.run(
+        [
+            *base,
+            "-read_intervals",
+            "%+#1",
+            "-show_entries",
+            "frame=side_data_list",
+            str(path),
+        ],
+        tool_paths,
+    )
+    if not out:
+        return None
+    try:
+        for frame in json.loads(out).get("frames", []):
+            meta = _parse_hdr10_side_data(frame.get("side_data_list", []))
+            if meta:
+                return meta
+    except (json.JSONDecodeError, AttributeError, ValueError):
+        return None
+    return None
@@ -0,0 +1,117 @@
+# vsg_core/extraction/concat.py
+"""
+Joining multi-part sources into one file (mkvmerge append).
+
+A source given as a list of parts is appended into a single Matroska file
+in the job's work dir before analysis (``mkvmerge -o joined.mkv part1 +
+part2 ...``). mkvmerge offsets each part's timestamps by the duration of
+the parts before it and appends their chapters the same way, so analysis,
+delays and chapters all work on one continuous timeline.
+
+Appending needs every part to carry the same tracks in the same order
+with the same codecs; parts that differ are rejected with the first
+difference named rather than producing a file with shifted tracks.
+"""
+
+from __future__ import annotations
+
+import re
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from vsg_core.errors import ProcessFailed
+from vsg_core.models.source_input import SourceInput
+
+from .tracks import get_stream_info
+
+if TYPE_CHECKING:
+    from vsg_core.io.runner import CommandRunner
+    from vsg_core.models.source_input import SourceValue
+
+
+def track_layout(info: dict[str, Any]) -> list[tuple[str, str]]:
+    """(type, codec) of every track, in file order."""
+    return [(t.get("type", ""), t.get("codec", "")) for t in info.get("tracks", [])]
+
+
+def check_parts_compatible(
+    source_key: str,
+    parts: list[tuple[str, dict[str, Any]]],
+) -> None:
+    """Raise ValueError unless every part has the first part's track layout."""
+    first_path, first_info = parts[0]
+    expected = track_layout(first_info)
+    for path, info in parts[1:]:
+        layout = track_layout(info)
+        if layout == expected:
+            continue
+        name, first = Path(path).name, Path(first_path).name
+        if len(layout) != len(expected):
+            detail = f"{len(layout)} tracks vs {len(expected)} in {first}"
+        else:
+            i = next(i for i, (a, b) in enumerate(zip(layout, expected)) if a != b)
+            detail = (
+                f"track {i} is {layout[i][0]} ({layout[i][1]}), "
+                f"{expected[i][0]} ({expected[i][1]}) in {first}"
+            )
+        raise ValueError(
+            f"{source_key}: part {name} can't be appended to {first}: {detail}."
+        )
+
+
+def join_parts(
+    source_key: str,
+    source: SourceInput,
+    dest_dir: Path,
+    runner: CommandRunner,
+    tool_paths: dict[str, str | None],
+) -> Path:
+    """Append ``source``'s parts into one file in ``dest_dir``."""
+    log = runner._log_message
+    parts: list[tuple[str, dict[str, Any]]] = []
+    for path in source.parts:
+        if not Path(path).is_file():
+            raise FileNotFoundError(f"{source_key}: part not found: {path}")
+        info = get_stream_info(path, runner, tool_paths)
+        if info is None:
+            raise ValueError(f"{source_key}: could not read part {path}")
+        parts.append((path, info))
+    check_parts_compatible(source_key, parts)
+
+    dest_dir.mkdir(parents=True, exist_ok=True)
+    slug = re.sub(r"\W+", "_", source_key).strip("_").lower()
+    out = dest_dir / f"{slug}_{Path(source.primary).stem}.mkv"
+    cmd = ["mkvmerge", "-o", str(out), source.parts[0]]
+    for path in source.parts[1:]:
+        cmd += ["+", path]
+    log(f"[Parts] Joining {len(source.parts)} parts of {source_key}:")
+    for i, path in enumerate(source.parts, 1):
+        log(f"[Parts]   {i}. {Path(path).name}")
+    # Exit 1 = warnings; the joined file is complete
+    if runner.run(cmd, tool_paths, ok_codes=(0, 1)) is None or not out.is_file():
+        raise ProcessFailed(
+            f"{source_key}: mkvmerge could not join the parts.", runner.last_failure
+        )
+    log(f"[Parts] {source_key} joined into {out.name}.")
+    return out
+
+
+def join_multipart_sources(
+    sources: dict[str, SourceValue],
+    dest_dir: Path,
+    runner: CommandRunner,
+    tool_paths: dict[str, str | None],
+) -> dict[str, str]:
+    """``sources`` with every multi-part source replaced by its joined file."""
+    joined: dict[str, str] = {}
+    for key, value in sources.items():
+        if not value:
+            joined[key] = value
+            continue
+        source = SourceInput.from_value(value)
+        joined[key] = (
+            str(join_parts(key, source, dest_dir, runner, tool_paths))
+            if source.is_parts
+            else source.primary
+        )
+    return joined
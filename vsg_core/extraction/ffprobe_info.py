@@ -0,0 +1,190 @@
+# vsg_core/extraction/ffprobe_info.py
+"""
+Container probing through ffprobe when mkvmerge can't read a file.
+
+mkvmerge doesn't open every container ffmpeg does (some transport streams,
+.mov variants, raw elementary streams). Analysis only needs to decode
+audio, which ffmpeg handles, so for those files the track listing is built
+from ``ffprobe -show_streams -show_format`` instead, in the shape of
+``mkvmerge -J`` output: ``tracks`` with ``id``/``type``/``codec`` and the
+``properties`` the rest of the code reads (``codec_id``, ``language``,
+``track_name``, ``audio_channels``, ``minimum_timestamp``, ...), plus
+``attachments``.
+
+Track ids count the video, audio and subtitle streams in file order, the
+way mkvmerge numbers them; data streams are skipped. The result carries
+``"probed_by": "ffprobe"`` and a container marked as not supported:
+mkvextract and mkvmerge will still refuse the file, so extraction and mux
+need it remuxed to MKV first (``needs_remux``).
+"""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from ..io.runner import CommandRunner
+
+PROBED_BY_FFPROBE = "ffprobe"
+
+# ffprobe codec_name -> Matroska codec id (what mkvmerge -J reports)
+_FFPROBE_CODEC_IDS = {
+    # Video
+    "h264": "V_MPEG4/ISO/AVC",
+    "hevc": "V_MPEGH/ISO/HEVC",
+    "mpeg1video": "V_MPEG1",
+    "mpeg2video": "V_MPEG2",
+    "vc1": "V_MS/VFW/FOURCC",
+    "vp8": "V_VP8",
+    "vp9": "V_VP9",
+    "av1": "V_AV1",
+    # Audio
+    "aac": "A_AAC",
+    "ac3": "A_AC3",
+    "eac3": "A_EAC3",
+    "dts": "A_DTS",
+    "truehd": "A_TRUEHD",
+    "flac": "A_FLAC",
+    "opus": "A_OPUS",
+    "vorbis": "A_VORBIS",
+    "mp2": "A_MPEG/L2",
+    "mp3": "A_MPEG/L3",
+    # Subtitles
+    "subrip": "S_TEXT/UTF8",
+    "mov_text": "S_TEXT/UTF8",
+    "ass": "S_TEXT/ASS",
+    "ssa": "S_TEXT/SSA",
+    "hdmv_pgs_subtitle": "S_HDMV/PGS",
+    "dvd_subtitle": "S_VOBSUB",
+}
+
+_TRACK_TYPES = {"video": "video", "audio": "audio", "subtitle": "subtitles"}
+
+
+def _codec_id(stream: dict[str, Any]) -> str:
+    name = stream.get("codec_name", "")
+    if name.startswith("pcm_"):
+        return "A_PCM/FLOAT/IEEE" if name.startswith("pcm_f") else "A_PCM/INT/LIT"
+    return _FFPROBE_CODEC_IDS.get(name, name.upper() or "N/A")
+
+
+def _int(value: Any) -> int | None:
+    try:
+        return int(value)
+    except (TypeError, ValueError):
+        return None
+
+
+def _properties(stream: dict[str, Any], track_type: str) -> dict[str, Any]:
+    tags = stream.get("tags") or {}
+    disposition = stream.get("disposition") or {}
+    props: dict[str, Any] = {
+        "codec_id": _codec_id(stream),
+        "language": tags.get("language") or "und",
+        "default_track": bool(disposition.get("default")),
+        "forced_track": bool(disposition.get("forced")),
+        "number": stream.get("index", 0) + 1,
+    }
+    if tags.get("title"):
+        props["track_name"] = tags["title"]
+    try:
+        start_s = float(stream.get("start_time", 0) or 0)
+    except ValueError:
+        start_s = 0.0
+    if start_s:
+        props["minimum_timestamp"] = round(start_s * 1_000_000_000)
+
+    if track_type == "video":
+        width, height = _int(stream.get("width")), _int(stream.get("height"))
+        if width and height:
+            props["pixel_dimensions"] = f"{width}x{height}"
+    elif track_type == "audio":
+        for key, value in (
+            ("audio_channels", _int(stream.get("channels"))),
+            ("audio_sampling_frequency", _int(stream.get("sample_rate"))),
+            ("audio_bits_per_sample", _int(stream.get("bits_per_raw_sample"))),
+        ):
+            if value:
+                props[key] = value
+    return props
+
+
+def info_from_ffprobe(data: dict[str, Any]) -> dict[str, Any]:
+    """``ffprobe -show_streams -show_format`` JSON as mkvmerge -J-style info."""
+    tracks: list[dict[str, Any]] = []
+    attachments: list[dict[str, Any]] = []
+    for stream in sorted(data.get("streams", []), key=lambda s: s.get("index", 0)):
+        codec_type = stream.get("codec_type", "")
+        if codec_type == "attachment":
+            tags = stream.get("tags") or {}
+            attachments.append(
+                {
+                    "id": len(attachments) + 1,
+                    "file_name": tags.get("filename", ""),
+                    "content_type": tags.get("mimetype", ""),
+                    "size": _int(stream.get("extradata_size")) or 0,
+                }
+            )
+            continue
+        track_type = _TRACK_TYPES.get(codec_type)
+        if track_type is None:
+            continue
+        tracks.append(
+            {
+                "id": len(tracks),
+                "type": track_type,
+                "codec": stream.get("codec_long_name", stream.get("codec_name", "")),
+                "properties": _properties(stream, track_type),
+                "ffprobe_info": stream,
+            }
+        )
+
+    fmt = data.get("format") or {}
+    container_props: dict[str, Any] = {}
+    try:
+        container_props["duration"] = round(float(fmt["duration"]) * 1_000_000_000)
+    except (KeyError, TypeError, ValueError):
+        pass
+    return {
+        "probed_by": PROBED_BY_FFPROBE,
+        "container": {
+            "recognized": True,
+            "supported": False,
+            "type": fmt.get("format_long_name") or fmt.get("format_name", ""),
+            "properties": container_props,
+        },
+        "tracks": tracks,
+        "attachments": attachments,
+    }
+
+
+def ffprobe_stream_info(
+    path: str, runner: CommandRunner, tool_paths: dict
+) -> dict[str, Any] | None:
+    """mkvmerge -J-style info of ``path`` from ffprobe; None if it fails too."""
+    cmd = [
+        "ffprobe",
+        "-v",
+        "error",
+        "-show_streams",
+        "-show_format",
+        "-of",
+        "json",
+        str(path),
+    ]
+    out = runner.run(cmd, tool_paths)
+    if not out or not isinstance(out, str):
+        return None
+    try:
+        data = json.loads(out)
+    except json.JSONDecodeError:
+        runner._log_message("[WARN] Failed to parse ffprobe JSON output.")
+        return None
+    info = info_from_ffprobe(data)
+    return info if info["tracks"] else None
+
+
+def needs_remux(info: dict[str, Any] | None) -> bool:
+    """True if ``info`` came from ffprobe: mkvmerge-based steps can't use it."""
+    return bool(info) and info.get("probed_by") == PROBED_BY_FFPROBE
@@ -0,0 +1,138 @@
+# vsg_core/extraction/frame_rate.py
+"""
+Variable frame rate (VFR) detection from packet timestamps.
+
+The container frame rate fields can't be trusted for this: a VFR WEB-DL
+in Matroska usually reports identical r_frame_rate and avg_frame_rate.
+``detect_vfr`` instead reads the video packet timestamps over a window,
+sorts them into presentation order and looks at the frame durations.
+
+Matroska stores timestamps in whole milliseconds, so a CFR 23.976 stream
+alternates between 41 and 42 ms frames. Durations within
+``JITTER_MS`` of the most common one count as the dominant rate; the
+stream is VFR when more than ``VFR_MIN_SHARE`` of the frames fall
+outside it.
+"""
+
+from __future__ import annotations
+
+from collections import Counter
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+    from pathlib import Path
+
+    from ..io.runner import CommandRunner
+
+JITTER_MS = 1.0  # Timestamp rounding (1 ms timescale) plus float noise
+VFR_MIN_SHARE = 0.01  # Off-rate frames above this share mean VFR
+MIN_FRAMES = 50  # Fewer sampled frames than this give no verdict
+
+# Common rates the dominant rate snaps to (within 0.05 %)
+_STANDARD_RATES = (
+    (24000, 1001),
+    (24, 1),
+    (25, 1),
+    (30000, 1001),
+    (30, 1),
+    (48, 1),
+    (50, 1),
+    (60000, 1001),
+    (60, 1),
+)
+
+
+@dataclass(frozen=True, slots=True)
+class FrameRateInfo:
+    """Frame rate of a video stream as seen in its timestamps."""
+
+    is_vfr: bool
+    fps: float  # Dominant rate
+    fps_fraction: tuple[int, int] | None  # Standard rate it matches, if any
+    frames: int  # Frames sampled
+    dominant_share: float  # Share of frames at the dominant rate (0-1)
+
+    def describe(self) -> str:
+        if not self.is_vfr:
+            return f"CFR {self.fps:.3f} fps ({self.frames} frames sampled)"
+        return (
+            f"VFR, dominant {self.fps:.3f} fps for "
+            f"{self.dominant_share * 100:.1f}% of {self.frames} frames sampled"
+        )
+
+
+def snap_standard_rate(fps: float) -> tuple[int, int] | None:
+    """The standard rate (num, den) ``fps`` is within 0.05 % of, if any."""
+    for num, den in _STANDARD_RATES:
+        if abs(fps - num / den) <= num / den * 0.0005:
+            return num, den
+    return None
+
+
+def classify_frame_timestamps(pts_ms: Sequence[float]) -> FrameRateInfo | None:
+    """CFR/VFR verdict from presentation timestamps (any order, in ms).
+
+    Returns None when there are too few frames to tell.
+    """
+    pts = sorted(set(pts_ms))
+    if len(pts) < MIN_FRAMES:
+        return None
+    durations = [b - a for a, b in zip(pts, pts[1:])]
+    mode_ms, _ = Counter(round(d) for d in durations).most_common(1)[0]
+    on_rate = [d for d in durations if abs(d - mode_ms) <= JITTER_MS]
+    mean_ms = sum(on_rate) / len(on_rate)
+    fps = 1000.0 / mean_ms if mean_ms > 0 else 0.0
+    fraction = snap_standard_rate(fps)
+    if fraction is not None:
+        fps = fraction[0] / fraction[1]
+    share = len(on_rate) / len(durations)
+    return FrameRateInfo(
+        is_vfr=1.0 - share > VFR_MIN_SHARE,
+        fps=fps,
+        fps_fraction=fraction,
+        frames=len(pts),
+        dominant_share=share,
+    )
+
+
+def detect_vfr(
+    path: str | Path,
+    runner: CommandRunner,
+    tool_paths: dict,
+    window_s: float = 300.0,
+) -> FrameRateInfo | None:
+    """Classify the first video stream of ``path`` as CFR or VFR.
+
+    Only the packet timestamps of the first ``window_s`` seconds are read
+    (no decoding). Returns None if ffprobe fails or too few frames are
+    found.
+    """
+    out = runner.run(
+        [
+            "ffprobe",
+            "-v",
+            "error",
+            "-select_streams",
+            "v:0",
+            "-read_intervals",
+            f"%+{window_s:g}",
+            "-show_entries",
+            "packet=pts_time",
+            "-of",
+            "csv=p=0",
+            str(path),
+        ],
+        tool_paths,
+    )
+    if not out or not isinstance(out, str):
+        return None
+    pts_ms = []
+    for line in out.splitlines():
+        value = line.strip().rstrip(",")
+        try:
+            pts_ms.append(float(value) * 1000.0)
+        except ValueError:
+            continue  # "N/A" or a stray ffprobe message
+    return classify_frame_timestamps(pts_ms)
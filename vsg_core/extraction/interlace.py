@@ -0,0 +1,148 @@
+# vsg_core/extraction/interlace.py
+"""
+Interlace detection from the picture itself (ffmpeg ``idet``).
+
+Container and codec flags only say how a stream was *encoded*: plenty of
+progressive Blu-ray and broadcast content is flagged interlaced (PAFF/MBAFF
+or a stray field_order), and a few interlaced encodes are flagged
+progressive. ``detect_interlaced`` decodes a few short windows and lets the
+``idet`` filter classify every frame as TFF, BFF, progressive or
+undetermined; its multi-frame counts are what ``InterlaceInfo`` holds.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from ..io.runner import CommandRunner
+
+INTERLACED_MIN_SHARE = 0.25  # Interlaced share of classified frames for a verdict
+WINDOW_FRAMES = 250  # Frames decoded per sampled window
+WINDOW_POSITIONS = (0.2, 0.5, 0.8)  # Window starts, as a fraction of duration
+
+_MULTI_FRAME = re.compile(
+    r"Multi frame detection:\s*TFF:\s*(\d+)\s*BFF:\s*(\d+)\s*"
+    r"Progressive:\s*(\d+)\s*Undetermined:\s*(\d+)"
+)
+
+
+@dataclass(frozen=True, slots=True)
+class InterlaceInfo:
+    """idet frame counts over the sampled windows."""
+
+    tff: int
+    bff: int
+    progressive: int
+    undetermined: int
+
+    @property
+    def classified(self) -> int:
+        return self.tff + self.bff + self.progressive
+
+    @property
+    def interlaced_share(self) -> float:
+        """Share of classified frames that are interlaced (0-1)."""
+        return (self.tff + self.bff) / self.classified if self.classified else 0.0
+
+    @property
+    def is_interlaced(self) -> bool:
+        return self.classified > 0 and self.interlaced_share >= INTERLACED_MIN_SHARE
+
+    @property
+    def field_order(self) -> str:
+        """Verdict: "tff", "bff", "progressive" or "undetermined"."""
+        if not self.classified:
+            return "undetermined"
+        if not self.is_interlaced:
+            return "progressive"
+        return "tff" if self.tff >= self.bff else "bff"
+
+    @property
+    def confidence(self) -> float:
+        """Share of classified frames that agree with the verdict (0-1)."""
+        if not self.classified:
+            return 0.0
+        if self.is_interlaced:
+            return max(self.tff, self.bff) / self.classified
+        return self.progressive / self.classified
+
+    def describe(self) -> str:
+        return (
+            f"{self.field_order} ({self.confidence * 100:.0f}% confidence; "
+            f"TFF {self.tff}, BFF {self.bff}, progressive {self.progressive}, "
+            f"undetermined {self.undetermined})"
+        )
+
+
+def parse_idet_output(text: str) -> InterlaceInfo | None:
+    """Sum the idet multi-frame counts found in ffmpeg's log output."""
+    counts = [0, 0, 0, 0]
+    found = False
+    for match in _MULTI_FRAME.finditer(text):
+        found = True
+        for i, value in enumerate(match.groups()):
+            counts[i] += int(value)
+    return InterlaceInfo(*counts) if found else None
+
+
+def detect_interlaced(
+    path: str | Path,
+    runner: CommandRunner,
+    tool_paths: dict,
+    duration_s: float | None = None,
+) -> InterlaceInfo | None:
+    """Classify the first video stream of ``path`` with ffmpeg ``idet``.
+
+    Decodes ``WINDOW_FRAMES`` frames at each of ``WINDOW_POSITIONS`` when
+    ``duration_s`` is known, else one window from the start. Returns None
+    if ffmpeg fails on every window.
+    """
+    if duration_s and duration_s > 0:
+        starts = [duration_s * pos for pos in WINDOW_POSITIONS]
+    else:
+        starts = [0.0]
+
+    total: InterlaceInfo | None = None
+    for start in starts:
+        out = runner.run(
+            [
+                "ffmpeg",
+                "-hide_banner",
+                "-nostats",
+                "-ss",
+                f"{start:.3f}",
+                "-i",
+                str(path),
+                "-map",
+                "0:v:0",
+                "-vf",
+                "idet",
+                "-frames:v",
+                str(WINDOW_FRAMES),
+                "-an",
+                "-f",
+                "null",
+                "-",
+            ],
+            tool_paths,
+        )
+        if not out or not isinstance(out, str):
+            continue
+        info = parse_idet_output(out)
+        if info is None:
+            continue
+        if total is None:
+            total = info
+        else:
+            total = InterlaceInfo(
+                tff=total.tff + info.tff,
+                bff=total.bff + info.bff,
+                progressive=total.progressive + info.progressive,
+                undetermined=total.undetermined + info.undetermined,
+            )
+    return total
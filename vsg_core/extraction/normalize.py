@@ -0,0 +1,99 @@
+# vsg_core/extraction/normalize.py
+"""
+Remuxing non-MKV sources to Matroska with ffmpeg (``normalize_to_mkv``).
+
+Extraction, chapters, attachments and the mux all go through mkvtoolnix,
+which reads Matroska best and some containers (certain .ts/.m2ts, .mov,
+raw streams) not at all. With normalization on, every source that isn't
+already Matroska is stream-copied into ``normalized/<stem>.mkv`` in the
+work dir before analysis, and the job runs on that file:
+
+    ffmpeg -i in.ts -map 0 -map -0:d? -c copy -max_interleave_delta 0 out.mkv
+
+All video, audio, subtitle and attachment streams are kept in their
+original order, so track ids match the ffprobe listing of the source (see
+``ffprobe_info``); data streams (which Matroska can't store) are dropped,
+and chapters and metadata come along. Packets are copied with their
+timestamps; ffmpeg only removes the offset common to every stream (e.g. a
+transport stream starting at 1.4s), so the streams keep their relative
+sync. ``-max_interleave_delta 0`` stops ffmpeg from flushing ahead of a
+sparse subtitle stream and reordering packets.
+
+A codec Matroska can't hold with stream copy fails the job, naming the
+stream ffmpeg refused.
+"""
+
+from __future__ import annotations
+
+import re
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from vsg_core.errors import ProcessFailed
+
+if TYPE_CHECKING:
+    from vsg_core.io.runner import CommandRunner
+
+MATROSKA_SUFFIXES = frozenset({".mkv", ".mka", ".mks", ".mk3d", ".webm"})
+
+# ffmpeg lines that name why the output could not be written
+_FFMPEG_ERROR_RE = re.compile(
+    r"(?i)(not supported|could not find tag|could not write header|"
+    r"invalid argument|error|unsupported)"
+)
+
+
+def is_matroska(path: str | Path) -> bool:
+    return Path(path).suffix.lower() in MATROSKA_SUFFIXES
+
+
+def remux_to_mkv(
+    source_key: str,
+    path: str,
+    dest_dir: Path,
+    runner: CommandRunner,
+    tool_paths: dict[str, str | None],
+) -> Path:
+    """Stream-copy ``path`` into ``dest_dir`` as MKV. Raises RuntimeError."""
+    dest_dir.mkdir(parents=True, exist_ok=True)
+    out = dest_dir / f"{Path(path).stem}.mkv"
+    if out.exists():
+        # Two sources with the same stem (ep01.mp4 / ep01.ts)
+        out = dest_dir / f"{Path(path).stem}.{source_key.replace(' ', '_')}.mkv"
+    cmd = [
+        "ffmpeg",
+        "-hide_banner",
+        "-nostdin",
+        "-y",
+        "-i",
+        str(path),
+        "-map",
+        "0",
+        "-map",
+        "-0:d?",
+        "-c",
+        "copy",
+        "-max_interleave_delta",
+        "0",
+        str(out),
+    ]
+    runner._log_message(f"[Normalize] Remuxing {source_key} ({Path(path).name}) to MKV")
+    if runner.run(cmd, tool_paths) is None or not out.is_file():
+        out.unlink(missing_ok=True)
+        failure = runner.last_failure
+        details = [
+            line.strip()
+            for line in (failure.output if failure else "").splitlines()
+            if _FFMPEG_ERROR_RE.search(line)
+        ]
+        reason = "; ".join(details[-3:]) or (
+            failure.describe() if failure else "ffmpeg failed"
+        )
+        raise ProcessFailed(
+            f"{source_key}: {Path(path).name} could not be remuxed to MKV with "
+            f"stream copy ({reason}). Remux it manually (e.g. with MKVToolNix "
+            "GUI) or turn off 'Remux non-MKV sources to MKV'.",
+            failure,
+        )
+    runner._log_message(f"[Normalize] {source_key} -> {out.name}")
+    return out
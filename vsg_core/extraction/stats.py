@@ -0,0 +1,161 @@
+# vsg_core/extraction/stats.py
+"""
+Per-track statistics of a muxed file, measured from its packets.
+
+mkvmerge can write statistics tags (duration, bitrate, bytes), but they
+are optional and come from the muxer itself. ``probe_track_stats`` reads
+every packet with ffprobe instead, so the numbers are an independent
+check of what actually ended up in the file.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from ..io.runner import CommandRunner
+
+# An audio track this much shorter than the video is flagged
+AUDIO_SHORT_RATIO = 0.95
+
+_TRACK_TYPES = {"video": "video", "audio": "audio", "subtitle": "subtitles"}
+
+
+@dataclass(frozen=True, slots=True)
+class TrackStats:
+    """Measured properties of one output track."""
+
+    index: int  # ffprobe stream index
+    type: str  # "video" | "audio" | "subtitles"
+    codec: str
+    language: str
+    duration_s: float
+    size_bytes: int
+    packets: int  # Frames for video/audio, events for subtitles
+
+    @property
+    def bitrate_kbps(self) -> float:
+        if self.duration_s <= 0:
+            return 0.0
+        return self.size_bytes * 8 / self.duration_s / 1000
+
+    def describe(self) -> str:
+        return (
+            f"#{self.index:<2} {self.type:<9} {self.codec:<12} {self.language:<4} "
+            f"{self.duration_s:>9.3f}s {self.bitrate_kbps:>9.1f} kb/s "
+            f"{self.size_bytes / (1024 * 1024):>9.2f} MiB {self.packets:>8} pkts"
+        )
+
+
+def _float(value: str) -> float | None:
+    try:
+        return float(value)
+    except ValueError:
+        return None  # "N/A"
+
+
+def probe_track_stats(
+    path: str | Path, runner: CommandRunner, tool_paths: dict
+) -> list[TrackStats]:
+    """Duration, size and packet count of every video/audio/subtitle track.
+
+    Returns an empty list if ffprobe fails.
+    """
+    out = runner.run(
+        [
+            "ffprobe",
+            "-v",
+            "error",
+            "-show_entries",
+            "stream=index,codec_type,codec_name:stream_tags=language",
+            "-of",
+            "json",
+            str(path),
+        ],
+        tool_paths,
+    )
+    if not out:
+        return []
+    try:
+        streams = json.loads(out).get("streams", [])
+    except json.JSONDecodeError:
+        return []
+
+    # One line per packet; binary mode keeps the dump out of the log
+    raw = runner.run(
+        [
+            "ffprobe",
+            "-v",
+            "error",
+            "-show_entries",
+            "packet=stream_index,pts_time,duration_time,size",
+            "-of",
+            "csv=p=0",
+            str(path),
+        ],
+        tool_paths,
+        is_binary=True,
+    )
+    if not raw:
+        return []
+
+    size: dict[int, int] = {}
+    count: dict[int, int] = {}
+    first: dict[int, float] = {}
+    last: dict[int, float] = {}
+    for line in raw.decode("utf-8", errors="replace").splitlines():
+        fields = line.split(",")
+        if len(fields) < 4 or not fields[0].isdigit():
+            continue
+        idx = int(fields[0])
+        count[idx] = count.get(idx, 0) + 1
+        if fields[3].isdigit():
+            size[idx] = size.get(idx, 0) + int(fields[3])
+        pts = _float(fields[1])
+        if pts is None:
+            continue
+        end = pts + (_float(fields[2]) or 0.0)
+        first[idx] = min(first.get(idx, pts), pts)
+        last[idx] = max(last.get(idx, end), end)
+
+    stats: list[TrackStats] = []
+    for stream in streams:
+        ttype = _TRACK_TYPES.get(stream.get("codec_type", ""))
+        if ttype is None:
+            continue  # attachments, data
+        idx = int(stream["index"])
+        stats.append(
+            TrackStats(
+                index=idx,
+                type=ttype,
+                codec=stream.get("codec_name", "unknown"),
+                language=(stream.get("tags") or {}).get("language", "und"),
+                duration_s=last.get(idx, 0.0) - first.get(idx, 0.0),
+                size_bytes=size.get(idx, 0),
+                packets=count.get(idx, 0),
+            )
+        )
+    return stats
+
+
+def find_anomalies(stats: list[TrackStats]) -> list[str]:
+    """Tracks that look wrong: empty, no subtitle events, short audio."""
+    anomalies: list[str] = []
+    video_s = max((s.duration_s for s in stats if s.type == "video"), default=0.0)
+    min_audio_s = video_s * AUDIO_SHORT_RATIO
+    for s in stats:
+        label = f"{s.type} track #{s.index} ({s.codec}, {s.language})"
+        if s.type == "subtitles" and s.packets == 0:
+            anomalies.append(f"{label} has no events.")
+        elif s.packets == 0 or s.size_bytes == 0:
+            anomalies.append(f"{label} is empty.")
+        elif s.type == "audio" and s.duration_s < min_audio_s:
+            anomalies.append(
+                f"{label} lasts {s.duration_s:.1f}s but the video lasts "
+                f"{video_s:.1f}s."
+            )
+    return anomalies
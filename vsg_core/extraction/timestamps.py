@@ -0,0 +1,75 @@
+# vsg_core/extraction/timestamps.py
+"""
+Per-frame video timestamps (mkvmerge timestamps format v2).
+
+``mkvextract timestamps_v2`` writes one presentation time in milliseconds
+per frame, in presentation order, after a ``# timestamp format v2``
+header. Unlike an fps value this is exact for VFR and pulldown content.
+"""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from .tracks import get_stream_info
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from ..io.runner import CommandRunner
+
+TIMESTAMPS_V2_HEADER = "# timestamp format v2"
+
+
+def parse_timestamps_v2(text: str) -> list[float]:
+    """Frame times (ms) from the contents of a timestamps-v2 file."""
+    lines = text.splitlines()
+    if not lines or lines[0].strip().lower() != TIMESTAMPS_V2_HEADER:
+        raise ValueError("Not a timestamps v2 file (missing header)")
+    times = []
+    for line in lines[1:]:
+        line = line.strip()
+        if not line or line.startswith("#"):
+            continue
+        times.append(float(line))
+    return sorted(times)
+
+
+def extract_timestamps(
+    path: str | Path,
+    out_path: Path,
+    runner: CommandRunner,
+    tool_paths: dict,
+    track_id: int | None = None,
+) -> list[float] | None:
+    """Frame times (ms) of a video track, cached in ``out_path``.
+
+    ``track_id`` is the mkvmerge track ID; the first video track is used
+    when omitted. An existing ``out_path`` is reused as-is, so callers
+    must key it by the source file. Returns None if extraction fails.
+    """
+    if not out_path.exists():
+        if track_id is None:
+            info = get_stream_info(str(path), runner, tool_paths) or {}
+            track_id = next(
+                (t["id"] for t in info.get("tracks", []) if t.get("type") == "video"),
+                None,
+            )
+            if track_id is None:
+                return None
+        out_path.parent.mkdir(parents=True, exist_ok=True)
+        out = runner.run(
+            ["mkvextract", str(path), "timestamps_v2", f"{track_id}:{out_path}"],
+            tool_paths,
+        )
+        if out is None:
+            out_path.unlink(missing_ok=True)  # Don't cache a partial file
+            return None
+        if not out_path.exists():
+            return None
+    try:
+        times = parse_timestamps_v2(out_path.read_text(encoding="utf-8"))
+    except (OSError, ValueError) as e:
+        runner._log_message(f"[Timestamps] WARNING: {out_path.name}: {e}")
+        return None
+    return times or None
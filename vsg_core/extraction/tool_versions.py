@@ -0,0 +1,156 @@
+# vsg_core/extraction/tool_versions.py
+"""
+Versions of the external tools, checked against known minimums.
+
+Behaviour differs between MKVToolNix and FFmpeg releases, and a bug report
+without versions is hard to act on. ``tool_versions`` runs each tool's
+version switch once per process, parses the version and flags tools that
+are missing or older than what the pipeline relies on. The result is
+logged at startup and at the top of every job log, and the UI can list it.
+
+Versions that cannot be parsed (e.g. FFmpeg git builds, "N-112233-g...")
+are reported as found but unknown; they never produce a warning.
+"""
+
+from __future__ import annotations
+
+import re
+import shutil
+import subprocess
+from dataclasses import dataclass
+from functools import cache
+
+# "ffmpeg version 6.1.1-...", "ffmpeg version n6.1", "mkvmerge v80.0 (...)",
+# "dovi_tool 2.1.2"
+_VERSION = re.compile(r"(?:version\s+[nv]?|\bv|^\S+\s+)(\d+)\.(\d+)(?:\.(\d+))?")
+
+# (tool, version switch, minimum or None, required)
+_TOOLS: tuple[tuple[str, str, tuple[int, ...] | None, bool], ...] = (
+    # -J identification and JSON option files (@opts.json)
+    ("mkvmerge", "--version", (9, 0), True),
+    # "mkvextract <file> tracks ..." argument order
+    ("mkvextract", "--version", (17, 0), True),
+    ("mkvpropedit", "--version", (9, 0), True),
+    ("ffmpeg", "-version", None, True),
+    ("ffprobe", "-version", None, True),
+    ("dovi_tool", "--version", None, False),
+)
+
+_MKVTOOLNIX_HINT = (
+    "Install MKVToolNix (https://mkvtoolnix.download) and put it on PATH."
+)
+_FFMPEG_HINT = "Install FFmpeg (https://ffmpeg.org/download.html) and put it on PATH."
+INSTALL_HINTS = {
+    "mkvmerge": _MKVTOOLNIX_HINT,
+    "mkvextract": _MKVTOOLNIX_HINT,
+    "mkvpropedit": _MKVTOOLNIX_HINT,
+    "ffmpeg": _FFMPEG_HINT,
+    "ffprobe": _FFMPEG_HINT,
+    "dovi_tool": "Only needed for Dolby Vision RPU injection "
+    "(https://github.com/quietvoid/dovi_tool).",
+}
+
+
+def _fmt(version: tuple[int, ...]) -> str:
+    return ".".join(str(v) for v in version)
+
+
+@dataclass(frozen=True, slots=True)
+class ToolStatus:
+    """One tool: where it is, which version, and whether that is enough."""
+
+    name: str
+    path: str | None
+    version: tuple[int, ...] | None  # None if missing or unparseable
+    version_text: str  # First line of the version output
+    minimum: tuple[int, ...] | None
+    required: bool
+
+    @property
+    def too_old(self) -> bool:
+        return (
+            self.minimum is not None
+            and self.version is not None
+            and self.version < self.minimum
+        )
+
+    @property
+    def problem(self) -> str | None:
+        """Actionable message when the tool is missing or too old."""
+        hint = INSTALL_HINTS.get(self.name, "")
+        if self.path is None:
+            if not self.required:
+                return None
+            return f"{self.name} not found. {hint}".strip()
+        if self.too_old and self.version is not None and self.minimum is not None:
+            return (
+                f"{self.name} {_fmt(self.version)} is older than the required "
+                f"{_fmt(self.minimum)}. {hint}"
+            ).strip()
+        return None
+
+    def describe(self) -> str:
+        if self.path is None:
+            return f"{self.name}: not found" + ("" if self.required else " (optional)")
+        version = _fmt(self.version) if self.version else "unknown version"
+        return f"{self.name}: {version} ({self.path})"
+
+
+@dataclass(frozen=True, slots=True)
+class ToolVersions:
+    """Status of every known external tool."""
+
+    tools: tuple[ToolStatus, ...]
+
+    def get(self, name: str) -> ToolStatus | None:
+        return next((t for t in self.tools if t.name == name), None)
+
+    @property
+    def problems(self) -> list[str]:
+        return [p for t in self.tools if (p := t.problem)]
+
+    def log_lines(self) -> list[str]:
+        lines = [f"[Tools] {t.describe()}" for t in self.tools]
+        lines += [f"[Tools] [WARNING] {p}" for p in self.problems]
+        return lines
+
+
+def parse_version(text: str) -> tuple[int, ...] | None:
+    """First dotted version number in ``text`` ("v80.0", "6.1.1", ...)."""
+    match = _VERSION.search(text)
+    if match is None:
+        return None
+    return tuple(int(g) for g in match.groups() if g is not None)
+
+
+def _probe(name: str, switch: str) -> tuple[str | None, str]:
+    path = shutil.which(name)
+    if path is None:
+        return None, ""
+    try:
+        proc = subprocess.run(
+            [path, switch], capture_output=True, text=True, timeout=10, check=False
+        )
+    except (OSError, subprocess.SubprocessError):
+        return path, ""
+    lines = (proc.stdout or proc.stderr).strip().splitlines()
+    return path, lines[0].strip() if lines else ""
+
+
+@cache
+def tool_versions() -> ToolVersions:
+    """Probe every known tool once per process (cached)."""
+    statuses = []
+    for name, switch, minimum, required in _TOOLS:
+        path, first_line = _probe(name, switch)
+        statuses.append(
+            ToolStatus(
+                name=name,
+                path=path,
+                version=parse_version(first_line) if first_line else None,
+                version_text=first_line,
+                minimum=minimum,
+                required=required,
+            )
+        )
+    return ToolVersions(tuple(statuses))
@@ -1,10 +1,14 @@
 # vsg_core/extraction/tracks.py
 import json
 import re
+from concurrent.futures import ThreadPoolExecutor
+from dataclasses import dataclass, field
 from pathlib import Path
 from typing import Any
 
+from ..errors import IoError, ProcessFailed
 from ..io.runner import CommandRunner
+from .ffprobe_info import ffprobe_stream_info, needs_remux
 
 # --- Mappings and Helpers for Detailed Track Info ---
 
@@ -168,6 +172,39 @@ def _build_track_description(track: dict) -> str:
     return f"{base_info} | {', '.join(details)}" if details else base_info
 
 
+def _parse_timestamp_ms(value: Any) -> int | None:
+    """Matroska DURATION tag ("00:23:40.123000000") or seconds, in ms."""
+    if value in (None, ""):
+        return None
+    text = str(value).strip()
+    try:
+        if ":" not in text:
+            return round(float(text) * 1000)
+        hours, minutes, seconds = text.split(":")
+        return round((int(hours) * 3600 + int(minutes) * 60 + float(seconds)) * 1000)
+    except ValueError:
+        return None
+
+
+def _track_duration_ms(track: dict) -> int | None:
+    """
+    Track duration from mkvmerge's statistics tag, else ffprobe (stream
+    duration or DURATION tag); None when the file doesn't say.
+    """
+    props = track.get("properties", {}) or {}
+    duration = _parse_timestamp_ms(props.get("tag_duration"))
+    if duration is not None:
+        return duration
+    ffprobe_info = track.get("ffprobe_info", {}) or {}
+    duration = _parse_timestamp_ms(ffprobe_info.get("duration"))
+    if duration is not None:
+        return duration
+    for key, value in (ffprobe_info.get("tags") or {}).items():
+        if key.upper().startswith("DURATION"):
+            return _parse_timestamp_ms(value)
+    return None
+
+
 def _pcm_codec_from_bit_depth(bit_depth):
     try:
         bd = int(bit_depth) if bit_depth is not None else 16
@@ -183,53 +220,82 @@ def _pcm_codec_from_bit_depth(bit_depth):
 
 
 def get_stream_info(
-    mkv_path: str, runner: CommandRunner, tool_paths: dict
+    mkv_path: str,
+    runner: CommandRunner,
+    tool_paths: dict,
+    ffprobe_fallback: bool = False,
 ) -> dict[str, Any] | None:
+    """
+    mkvmerge -J of ``mkv_path``. With ``ffprobe_fallback``, a file mkvmerge
+    can't read is probed with ffprobe instead (see ``ffprobe_info``); such
+    info is marked ``needs_remux``.
+    """
     out = runner.run(["mkvmerge", "-J", str(mkv_path)], tool_paths)
-    if not out or not isinstance(out, str):
-        return None
-    try:
-        return json.loads(out)
-    except json.JSONDecodeError:
-        runner._log_message("[ERROR] Failed to parse mkvmerge -J JSON output.")
-        return None
+    info = None
+    if out and isinstance(out, str):
+        try:
+            info = json.loads(out)
+        except json.JSONDecodeError:
+            runner._log_message("[ERROR] Failed to parse mkvmerge -J JSON output.")
+    container = (info or {}).get("container", {})
+    readable = container.get("recognized", True) and container.get("supported", True)
+    if (info is None or not readable) and ffprobe_fallback:
+        info = ffprobe_stream_info(mkv_path, runner, tool_paths)
+        if info is not None:
+            runner._log_message(
+                f"[Probe] mkvmerge can't read {Path(mkv_path).name}; using ffprobe. "
+                "It must be remuxed to MKV before extraction or merging."
+            )
+    return info
 
 
-def get_stream_info_with_delays(
+def video_height(
     mkv_path: str, runner: CommandRunner, tool_paths: dict
+) -> int | None:
+    """Pixel height of the first video track, or None when unknown."""
+    info = get_stream_info(mkv_path, runner, tool_paths)
+    for track in (info or {}).get("tracks", []):
+        if track.get("type") != "video":
+            continue
+        dims = track.get("properties", {}).get("pixel_dimensions", "")
+        _, _, height = dims.partition("x")
+        if height.isdigit():
+            return int(height)
+    return None
+
+
+def get_stream_info_with_delays(
+    mkv_path: str,
+    runner: CommandRunner,
+    tool_paths: dict,
+    ffprobe_fallback: bool = False,
 ) -> dict[str, Any] | None:
     """Get stream info including container delays from mkvmerge -J output."""
-    out = runner.run(["mkvmerge", "-J", str(mkv_path)], tool_paths)
-    if not out or not isinstance(out, str):
+    info = get_stream_info(mkv_path, runner, tool_paths, ffprobe_fallback)
+    if info is None:
         return None
-    try:
-        info = json.loads(out)
-
-        # Extract container delays for each track
-        for track in info.get("tracks", []):
-            props = track.get("properties", {})
-            track_type = track.get("type", "")
-
-            # ONLY read container delays for audio and video tracks
-            # Subtitles don't have meaningful container delays in MKV
-            if track_type in ["audio", "video"]:
-                min_timestamp = props.get("minimum_timestamp", 0)
-
-                if min_timestamp:
-                    # Use round() for proper rounding of negative values
-                    # int() truncates toward zero: int(-1001.825) = -1001 (wrong)
-                    # round() rounds to nearest: round(-1001.825) = -1002 (correct)
-                    track["container_delay_ms"] = round(min_timestamp / 1_000_000)
-                else:
-                    track["container_delay_ms"] = 0
+    # Extract container delays for each track
+    for track in info.get("tracks", []):
+        props = track.get("properties", {})
+        track_type = track.get("type", "")
+
+        # ONLY read container delays for audio and video tracks
+        # Subtitles don't have meaningful container delays in MKV
+        if track_type in ["audio", "video"]:
+            min_timestamp = props.get("minimum_timestamp", 0)
+
+            if min_timestamp:
+                # Use round() for proper rounding of negative values
+                # int() truncates toward zero: int(-1001.825) = -1001 (wrong)
+                # round() rounds to nearest: round(-1001.825) = -1002 (correct)
+                track["container_delay_ms"] = round(min_timestamp / 1_000_000)
             else:
-                # Explicitly set subtitle delays to 0
                 track["container_delay_ms"] = 0
+        else:
+            # Explicitly set subtitle delays to 0
+            track["container_delay_ms"] = 0
 
-        return info
-    except json.JSONDecodeError:
-        runner._log_message("[ERROR] Failed to parse mkvmerge -J JSON output.")
-        return None
+    return info
 
 
 def _get_detailed_stream_info(
@@ -337,6 +403,7 @@ def extract_tracks(
             "path": str(out_path),
             "codec_id": codec,
             "source": role,
+            "audio_channels": props.get("audio_channels"),
         }
         tracks_to_extract.append(record)
 
@@ -362,9 +429,12 @@ def extract_tracks(
         runner._log_message(
             f"[{role}] Extracting {len(specs)} track(s) with mkvextract..."
         )
-        result = runner.run(["mkvextract", str(mkv), "tracks", *specs], tool_paths)
+        result = runner.run_with_retry(
+            ["mkvextract", str(mkv), "tracks", *specs], tool_paths
+        )
 
         if result is None:
+            failure = runner.last_failure
             runner._log_message(f"[{role}] [ERROR] mkvextract command failed!")
 
             # Check which tracks succeeded/failed
@@ -447,7 +517,7 @@ def extract_tracks(
             error_msg += "  5. Check log file for detailed mkvextract error messages\n"
             error_msg += f"{'=' * 80}\n"
 
-            raise RuntimeError(error_msg)
+            raise ProcessFailed(error_msg, failure)
 
         runner._log_message(f"[{role}] ✓ Successfully extracted {len(specs)} track(s)")
 
@@ -495,7 +565,7 @@ def extract_tracks(
             error_msg += "  • Antivirus interference\n"
             error_msg += "  • Disk I/O errors\n"
             error_msg += f"{'=' * 80}\n"
-            raise RuntimeError(error_msg)
+            raise IoError(error_msg)
 
     # Handle A_MS/ACM audio with ffmpeg
     for job in ffmpeg_jobs:
@@ -521,7 +591,7 @@ def extract_tracks(
             "copy",
             job["out"],
         ]
-        if runner.run(copy_cmd, tool_paths) is None:
+        if runner.run_with_retry(copy_cmd, tool_paths) is None:
             runner._log_message(
                 f"[{role}] Stream copy refused. Falling back to PCM ({job['pcm']})..."
             )
@@ -542,7 +612,7 @@ def extract_tracks(
                 job["pcm"],
                 job["out"],
             ]
-            if runner.run(pcm_cmd, tool_paths) is None:
+            if runner.run_with_retry(pcm_cmd, tool_paths) is None:
                 error_msg = f"\n{'=' * 80}\n"
                 error_msg += "A_MS/ACM AUDIO EXTRACTION FAILED\n"
                 error_msg += f"{'=' * 80}\n"
@@ -563,7 +633,7 @@ def extract_tracks(
                 )
                 error_msg += "  3. Consider remuxing the source file\n"
                 error_msg += f"{'=' * 80}\n"
-                raise RuntimeError(error_msg)
+                raise ProcessFailed(error_msg, runner.last_failure)
 
             runner._log_message(f"[{role}] ✓ Converted to {job['pcm']}")
         else:
@@ -572,63 +642,135 @@ def extract_tracks(
     return tracks_to_extract
 
 
-def get_track_info_for_dialog(
-    sources: dict[str, str], runner: CommandRunner, tool_paths: dict
-) -> dict[str, list[dict]]:
-    all_tracks: dict[str, list[dict]] = {key: [] for key in sources}
-    for source_key, filepath in sources.items():
-        if not filepath or not Path(filepath).exists():
-            continue
+@dataclass(slots=True)
+class SourceScan:
+    """Track listing of one source, or why it could not be read."""
 
-        mkvmerge_info = get_stream_info(filepath, runner, tool_paths)
-        if not mkvmerge_info or "tracks" not in mkvmerge_info:
-            continue
+    source_key: str
+    path: str
+    tracks: list[dict] = field(default_factory=list)
+    error: str | None = None
+    # Read through ffprobe: usable for analysis, needs a remux to MKV to merge
+    needs_remux: bool = False
+
+
+def scan_source(
+    source_key: str, filepath: str, runner: CommandRunner, tool_paths: dict
+) -> SourceScan:
+    """
+    List the tracks of one source (mkvmerge -J plus ffprobe details, or
+    ffprobe alone when mkvmerge can't read the file).
+    """
+    scan = SourceScan(source_key=source_key, path=filepath)
+    if not filepath:
+        return scan
+    if not Path(filepath).exists():
+        scan.error = "File not found"
+        return scan
+
+    mkvmerge_info = get_stream_info(
+        filepath, runner, tool_paths, ffprobe_fallback=True
+    )
+    if not mkvmerge_info or "tracks" not in mkvmerge_info:
+        scan.error = "Neither mkvmerge nor ffprobe could read the file"
+        return scan
+    scan.needs_remux = needs_remux(mkvmerge_info)
+
+    # ffprobe-built info already carries the ffprobe details
+    ffprobe_details = (
+        {}
+        if scan.needs_remux
+        else _get_detailed_stream_info(filepath, runner, tool_paths)
+    )
+
+    type_counters = {"video": 0, "audio": 0, "subtitles": 0}
+    ffprobe_streams_by_type = {
+        "video": sorted(
+            [s for s in ffprobe_details.values() if s.get("codec_type") == "video"],
+            key=lambda s: s["index"],
+        ),
+        "audio": sorted(
+            [s for s in ffprobe_details.values() if s.get("codec_type") == "audio"],
+            key=lambda s: s["index"],
+        ),
+        "subtitles": sorted(
+            [s for s in ffprobe_details.values() if s.get("codec_type") == "subtitle"],
+            key=lambda s: s["index"],
+        ),
+    }
+
+    for track in mkvmerge_info.get("tracks", []):
+        track_type = track["type"]
+        type_index = type_counters.get(track_type, 0)
+
+        if "ffprobe_info" not in track and type_index < len(
+            ffprobe_streams_by_type.get(track_type, [])
+        ):
+            track["ffprobe_info"] = ffprobe_streams_by_type[track_type][type_index]
 
-        ffprobe_details = _get_detailed_stream_info(filepath, runner, tool_paths)
-
-        type_counters = {"video": 0, "audio": 0, "subtitles": 0}
-        ffprobe_streams_by_type = {
-            "video": sorted(
-                [s for s in ffprobe_details.values() if s.get("codec_type") == "video"],
-                key=lambda s: s["index"],
-            ),
-            "audio": sorted(
-                [s for s in ffprobe_details.values() if s.get("codec_type") == "audio"],
-                key=lambda s: s["index"],
-            ),
-            "subtitles": sorted(
-                [
-                    s
-                    for s in ffprobe_details.values()
-                    if s.get("codec_type") == "subtitle"
-                ],
-                key=lambda s: s["index"],
-            ),
+        type_counters[track_type] = type_index + 1
+
+        props = track.get("properties", {}) or {}
+        record = {
+            "source": source_key,
+            "original_path": filepath,
+            "id": track["id"],
+            "type": track["type"],
+            "codec_id": props.get("codec_id", "N/A"),
+            "lang": props.get("language", "und"),
+            "name": props.get("track_name", ""),
+            "forced": bool(props.get("forced_track", False)),
+            "audio_channels": props.get("audio_channels", "")
+            if track["type"] == "audio"
+            else "",
+            "duration_ms": _track_duration_ms(track),
+            "description": _build_track_description(track),
         }
+        scan.tracks.append(record)
+
+    return scan
+
+
+def scan_sources(
+    sources: dict[str, str], runner: CommandRunner, tool_paths: dict
+) -> dict[str, SourceScan]:
+    """Scan all sources in parallel; results keep the order of ``sources``.
+
+    A source that fails comes back with ``error`` set instead of raising,
+    so the others can still be configured.
 
-        for track in mkvmerge_info.get("tracks", []):
-            track_type = track["type"]
-            type_index = type_counters.get(track_type, 0)
-
-            if type_index < len(ffprobe_streams_by_type.get(track_type, [])):
-                track["ffprobe_info"] = ffprobe_streams_by_type[track_type][type_index]
-
-            type_counters[track_type] = type_index + 1
-
-            props = track.get("properties", {}) or {}
-            record = {
-                "source": source_key,
-                "original_path": filepath,
-                "id": track["id"],
-                "type": track["type"],
-                "codec_id": props.get("codec_id", "N/A"),
-                "lang": props.get("language", "und"),
-                "name": props.get("track_name", ""),
-                "audio_channels": props.get("audio_channels", "")
-                if track["type"] == "audio"
-                else "",
-                "description": _build_track_description(track),
-            }
-            all_tracks[source_key].append(record)
-
-    return all_tracks
+    Each worker gets its own runner logging into a buffer: ``runner``'s
+    callback may touch the GUI, and ``last_failure``/``last_returncode``
+    are per-run state. The buffers are flushed here, on the calling
+    thread, one source after the other.
+    """
+    if not sources:
+        return {}
+
+    def scan(item: tuple[str, str]) -> tuple[SourceScan, list[str]]:
+        source_key, filepath = item
+        lines: list[str] = []
+        worker = CommandRunner(runner.settings, lines.append)
+        try:
+            result = scan_source(source_key, filepath, worker, tool_paths)
+        except Exception as e:
+            result = SourceScan(source_key=source_key, path=filepath, error=str(e))
+        return result, lines
+
+    with ThreadPoolExecutor(
+        max_workers=len(sources), thread_name_prefix="vsg_scan"
+    ) as pool:
+        results = list(pool.map(scan, sources.items()))
+    for _, lines in results:
+        for line in lines:
+            runner.log(line)
+    return {s.source_key: s for s, _ in results}
+
+
+def get_track_info_for_dialog(
+    sources: dict[str, str], runner: CommandRunner, tool_paths: dict
+) -> dict[str, list[dict]]:
+    return {
+        key: scan.tracks
+        for key, scan in scan_sources(sources, runner, tool_paths).items()
+    }
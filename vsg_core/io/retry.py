@@ -0,0 +1,64 @@
+# vsg_core/io/retry.py
+"""
+Transient-failure detection for external tool runs.
+
+Long batches occasionally see mkvmerge/mkvextract/ffmpeg fail for reasons
+that go away on their own: a file briefly locked by an indexer or antivirus,
+a network share hiccup, a process killed by a signal. Those are worth
+retrying after a short pause; a bad argument or a missing codec fails the
+same way every time and is not.
+
+``is_transient`` decides from the exit code and the captured output. A
+deterministic pattern always wins, so an output mentioning both ("Invalid
+argument" next to "I/O error") is not retried.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+
+RETRY_BASE_DELAY_S = 2.0  # First pause; doubles on every further attempt
+RETRY_MAX_DELAY_S = 30.0
+
+_TRANSIENT = re.compile(
+    r"resource temporarily unavailable|device or resource busy|text file busy"
+    r"|input/output error|i/o error|stale file handle|interrupted system call"
+    r"|connection (?:reset|timed out)|broken pipe|sharing violation"
+    r"|being used by another process|is locked",
+    re.IGNORECASE,
+)
+_DETERMINISTIC = re.compile(
+    r"unrecognized option|invalid argument|option not found|unknown encoder"
+    r"|unknown decoder|decoder .* not found|encoder .* not found"
+    r"|no such file or directory|invalid data found|not a valid|unsupported"
+    r"|does not contain|could not be parsed|syntax error|no space left",
+    re.IGNORECASE,
+)
+
+
+@dataclass(frozen=True, slots=True)
+class CommandFailure:
+    """Exit code and output tail of a failed command."""
+
+    tool: str
+    returncode: int | None  # None when the process could not be started
+    output: str  # Last lines of stdout/stderr (or the launch error)
+
+    def describe(self) -> str:
+        code = "not started" if self.returncode is None else f"exit {self.returncode}"
+        return f"{self.tool} ({code})"
+
+
+def is_transient(failure: CommandFailure) -> bool:
+    """True when the failure looks like it could pass on a retry."""
+    if _DETERMINISTIC.search(failure.output):
+        return False
+    if failure.returncode is not None and failure.returncode < 0:
+        return True  # Killed by a signal, not a verdict on the input
+    return bool(_TRANSIENT.search(failure.output))
+
+
+def backoff_delay(attempt: int) -> float:
+    """Pause before retry ``attempt`` (1-based): 2s, 4s, 8s, ... capped."""
+    return min(RETRY_BASE_DELAY_S * 2 ** (attempt - 1), RETRY_MAX_DELAY_S)
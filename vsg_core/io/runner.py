@@ -7,10 +7,13 @@ from __future__ import annotations
 
 import shlex
 import subprocess
+import time
 from collections.abc import Callable
 from datetime import datetime
 from typing import TYPE_CHECKING
 
+from .retry import CommandFailure, backoff_delay, is_transient
+
 if TYPE_CHECKING:
     from vsg_core.models import AppSettings
 
@@ -28,6 +31,10 @@ except ImportError:
         return os.environ.copy()
 
 
+_FAILURE_TAIL_LINES = 40  # Output lines kept in CommandRunner.last_failure
+_RETRY_LOG_CHARS = 2000  # Captured output shown with each retry notice
+
+
 class CommandRunner:
     """Executes external commands and streams output."""
 
@@ -35,6 +42,8 @@ class CommandRunner:
         self.settings = settings
         self.log = log_callback
         self.abs_paths = {}
+        self.last_failure: CommandFailure | None = None  # Set when run() fails
+        self.last_returncode: int | None = None  # Exit code of the last run()
 
     def _log_message(self, message: str):
         """Formats and sends a message to the log callback."""
@@ -48,13 +57,17 @@ class CommandRunner:
         tool_paths: dict,
         is_binary: bool = False,
         input_data: bytes | None = None,
+        ok_codes: tuple[int, ...] = (0,),
     ) -> str | bytes | None:
         """
         Executes a command and handles logging based on configuration.
         Can optionally pass binary `input_data` to the process's stdin.
         Returns captured stdout as a string, or bytes if is_binary=True.
-        Returns None on failure.
+        Returns None on failure, i.e. an exit code not in ``ok_codes``
+        (mkvmerge exits 1 for warnings).
         """
+        self.last_failure = None
+        self.last_returncode = None
         if not cmd:
             return None
 
@@ -104,6 +117,7 @@ class CommandRunner:
             # so the text-mode communicate() receives None as expected.
             stdout_data, stderr_data = proc.communicate(input=input_data)  # type: ignore[arg-type]
             rc = proc.returncode or 0
+            self.last_returncode = rc
 
             # For binary mode, log any stderr separately (don't mix with binary data)
             if is_binary and stderr_data:
@@ -145,7 +159,12 @@ class CommandRunner:
                 for line in out_buf_list:
                     self._log_message(line.rstrip("\n"))
 
-            if rc != 0:
+            if rc not in ok_codes:
+                if is_binary:
+                    failure_text = (stderr_data or b"").decode("utf-8", "replace")
+                else:
+                    failure_text = "".join(out_buf_list[-_FAILURE_TAIL_LINES:])
+                self.last_failure = CommandFailure(tool_name, rc, failure_text)
                 self._log_message(f"[!] Command failed with exit code {rc}")
                 if compact and not is_binary and err_tail > 0 and tail_buffer:
                     error_lines = list(tail_buffer)[-err_tail:]
@@ -164,5 +183,39 @@ class CommandRunner:
 
             return stdout_data if is_binary else "".join(out_buf_list)
         except Exception as e:
+            self.last_failure = CommandFailure(tool_name, None, str(e))
             self._log_message(f"[!] Failed to execute command: {e}")
             return None
+
+    def run_with_retry(
+        self,
+        cmd: list[str],
+        tool_paths: dict,
+        is_binary: bool = False,
+        input_data: bytes | None = None,
+        retries: int | None = None,
+        ok_codes: tuple[int, ...] = (0,),
+    ) -> str | bytes | None:
+        """
+        ``run`` that retries failures which look transient (see
+        ``vsg_core.io.retry``) up to ``retries`` times (default: the
+        ``command_retries`` setting), with exponential backoff.
+        Deterministic failures return None straight away.
+        """
+        retries = self.settings.command_retries if retries is None else retries
+        for attempt in range(retries + 1):
+            out = self.run(cmd, tool_paths, is_binary, input_data, ok_codes)
+            failure = self.last_failure
+            if out is not None or failure is None or attempt == retries:
+                return out
+            if not is_transient(failure):
+                return None
+            delay = backoff_delay(attempt + 1)
+            tail = failure.output.strip()[-_RETRY_LOG_CHARS:]
+            self._log_message(
+                f"[Retry] {failure.describe()} looks transient; retrying in "
+                f"{delay:g}s (attempt {attempt + 2}/{retries + 1})."
+                + (f"\n[captured output]\n{tail}" if tail else "")
+            )
+            time.sleep(delay)
+        return None
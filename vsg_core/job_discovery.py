@@ -10,27 +10,336 @@ Discovers and creates processing jobs from source file paths. Supports two modes
 2. Batch Folder Mode: Source 1 is a folder. Scans for video files (.mkv, .mp4, .m4v)
    and creates multiple jobs by matching filenames across all source folders.
 
+In batch mode, files are paired using a DiscoveryStrategyStr:
+
+- "exact":      identical filename (legacy behavior)
+- "normalized": exact first, then the extracted episode key (S01E05, - 05,
+                EP05, #5, OVA ...), then names with release noise stripped
+                ([group], (year), 1080p, x264, BD, ...) with a fuzzy fallback
+- "regex":      exact first, then a user regex capturing the episode number
+
+A source may also be a list of files (a multi-part source, e.g. a DVD rip
+split by disc), which is kept as a list in the job and joined before
+analysis. Multi-part sources are only supported in single file mode.
+
 Returns a list of job dictionaries, each containing a 'sources' dict mapping
-source names to file paths.
+source names to file paths (or lists of parts), plus 'match_confidence'
+(source -> 0.0-1.0) for every non-reference source that was paired, so the
+UI can flag weak matches.
 """
 
 from __future__ import annotations
 
+import difflib
+import re
+from dataclasses import dataclass
 from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from vsg_core.models.source_input import SourceInput
+
+if TYPE_CHECKING:
+    from vsg_core.models.source_input import SourceValue
+    from vsg_core.models.types import DiscoveryStrategyStr
+
+VIDEO_EXTENSIONS = (".mkv", ".mp4", ".m4v")
+
+# Fuzzy matches below this similarity are not paired at all
+_FUZZY_MIN_RATIO = 0.6
+
+# Bracketed chunks: [Group], (2019), {tags}
+_BRACKETED = re.compile(r"\[[^\]]*\]|\([^)]*\)|\{[^}]*\}")
+
+# Dotted noise that would otherwise survive splitting on "." (AAC2.0, H.264)
+_DOTTED_NOISE = re.compile(
+    r"\b(?:aac|ddp?|e?ac3|dts|flac|opus|truehd)?\d\.\d\b|\bh\.26[45]\b"
+)
+
+# Release noise tokens removed by the "normalized" strategy
+_NOISE_TOKENS = frozenset(
+    {
+        "480p", "576p", "720p", "1080p", "1080i", "2160p", "4k", "uhd",
+        "x264", "x265", "h264", "h265", "hevc", "avc", "av1", "xvid",
+        "10bit", "8bit", "hi10p", "hdr", "hdr10", "dv", "dovi", "sdr",
+        "bd", "bdrip", "bluray", "blu-ray", "brrip", "remux", "dvd", "dvdrip",
+        "web", "webdl", "web-dl", "webrip", "hdtv", "cr", "amzn", "nf", "dsnp",
+        "aac", "ac3", "eac3", "ddp", "dd", "flac", "opus", "dts", "truehd",
+        "atmos", "dual", "audio", "multi", "subs",
+        "proper", "repack", "v2", "v3", "uncensored",
+    }
+)  # fmt: skip
+
+
+def normalize_name(filename: str) -> str:
+    """
+    Reduces a release filename to its episode/title core.
+
+    "[Group] Show Name - 05 (1080p BD x264) [ABCD1234].mkv" -> "show name 05"
+    """
+    stem = Path(filename).stem.lower()
+    stem = _BRACKETED.sub(" ", stem)
+    stem = _DOTTED_NOISE.sub(" ", stem)
+    tokens = re.split(r"[\s_.]+", stem)
+    core = [
+        t.strip("-. ")
+        for t in tokens
+        if t.strip("-. ") and t.strip("-. ") not in _NOISE_TOKENS
+    ]
+    return " ".join(core)
+
 
+@dataclass(frozen=True, slots=True)
+class EpisodeKey:
+    """Normalized episode identity extracted from a release filename.
 
-def discover_jobs(sources: dict[str, str]) -> list[dict[str, dict[str, str]]]:
+    ``season`` is None for absolute numbering ("Show - 05"); it compares
+    equal to season 1 so absolute and S01Exx names pair up. Specials
+    (S00, OVA/OAD/SP) are kept apart from regular episodes.
+    """
+
+    episode: int
+    season: int | None = None
+    end_episode: int | None = None  # Double episodes: "05-06", "S01E05E06"
+    special: bool = False
+
+    def match_key(self) -> tuple[int, int, int | None, bool]:
+        season = self.season if self.season is not None else (0 if self.special else 1)
+        return (season, self.episode, self.end_episode, self.special)
+
+
+# Ordered most specific first; every pattern runs on a bracket-free stem.
+_SXXEXX = re.compile(
+    r"\bs(\d{1,2})[\s._-]?e(\d{1,4})(?:(?:-?e|-)(\d{1,4}))?(?!\d)", re.IGNORECASE
+)
+_NXNN = re.compile(r"\b(\d{1,2})x(\d{2,3})(?:-(\d{2,3}))?\b", re.IGNORECASE)
+_SPECIAL = re.compile(r"\b(?:ova|oad|sp|special)[\s._-]*(\d{1,3})?\b", re.IGNORECASE)
+_EP_WORD = re.compile(
+    r"\b(?:ep|e|episode)[\s._-]?(\d{1,4})(?:-(\d{1,4}))?(?!\d)", re.IGNORECASE
+)
+_HASH = re.compile(r"#(\d{1,4})\b")
+_DASH_ABS = re.compile(r"\s-\s(\d{1,4})(?:-(\d{1,4}))?(?:v\d)?(?:\s|$)")
+_BARE_NUM = re.compile(r"(?<![\d.])(\d{1,4})(?:-(\d{1,4}))?(?:v\d)?(?![\d.])")
+_YEAR = re.compile(r"^(19|20)\d{2}$")
+_SCENE_GROUP = re.compile(r"-[^\s_.-]+$")  # "...x264-GRP"
+
+
+def _end(value: str | None) -> int | None:
+    return int(value) if value else None
+
+
+def _special_match(stem: str) -> re.Match[str] | None:
+    """First OVA/OAD/SP/Special marker that really names a special.
+
+    Without a number the word only counts at the end of the title (nothing
+    but release noise after it), so "Special Forces - 05" stays episode 5.
+    """
+    for m in _SPECIAL.finditer(stem):
+        if m.group(1):
+            return m
+        rest = _SCENE_GROUP.sub("", stem[m.end() :])
+        tokens = (t.strip("-. ").lower() for t in re.split(r"[\s_.]+", rest))
+        if all(not t or t in _NOISE_TOKENS for t in tokens):
+            return m
+    return None
+
+
+def extract_episode(filename: str) -> EpisodeKey | None:
+    """
+    Recognizes common episode numbering schemes in a release filename.
+
+    Handles S01E05, 1x05, EP05/E05/Episode 5, #5, "Show - 05", bare absolute
+    numbers, double episodes (05-06, S01E05E06) and specials (S00E01, OVA 2).
+    Returns None when no episode number can be found.
+    """
+    stem = _BRACKETED.sub(" ", Path(filename).stem)
+    stem = _DOTTED_NOISE.sub(" ", stem)
+
+    if m := _SXXEXX.search(stem):
+        season = int(m.group(1))
+        return EpisodeKey(
+            episode=int(m.group(2)),
+            season=season,
+            end_episode=_end(m.group(3)),
+            special=season == 0,
+        )
+    if m := _NXNN.search(stem):
+        season = int(m.group(1))
+        return EpisodeKey(
+            episode=int(m.group(2)),
+            season=season,
+            end_episode=_end(m.group(3)),
+            special=season == 0,
+        )
+    if m := _special_match(stem):
+        return EpisodeKey(episode=int(m.group(1) or 1), season=0, special=True)
+    if m := _EP_WORD.search(stem):
+        return EpisodeKey(episode=int(m.group(1)), end_episode=_end(m.group(2)))
+    if m := _HASH.search(stem):
+        return EpisodeKey(episode=int(m.group(1)))
+    if m := _DASH_ABS.search(stem):
+        return EpisodeKey(episode=int(m.group(1)), end_episode=_end(m.group(2)))
+
+    # Bare absolute number: last numeric token that isn't noise or a year
+    core = normalize_name(filename)
+    for m in reversed(list(_BARE_NUM.finditer(core))):
+        if _YEAR.match(m.group(1)):
+            continue
+        return EpisodeKey(episode=int(m.group(1)), end_episode=_end(m.group(2)))
+    return None
+
+
+_LEADING_GROUP = re.compile(r"^\s*\[([^\]]+)\]")
+
+
+def release_group(filename: str) -> str:
+    """Release group tag at the start of a filename ("[Group] Show - 05")."""
+    match = _LEADING_GROUP.match(Path(filename).stem)
+    return match.group(1).strip() if match else ""
+
+
+def release_title(filename: str) -> str:
+    """
+    Show title of a release filename, without group, episode or noise tags.
+
+    "[Group] Show Name - 05 (1080p BD x264).mkv" -> "Show Name"
+    """
+    stem = _BRACKETED.sub(" ", Path(filename).stem)
+    stem = _DOTTED_NOISE.sub(" ", stem)
+    cut = len(stem)
+    matches = [p.search(stem) for p in (_SXXEXX, _NXNN, _EP_WORD, _HASH, _DASH_ABS)]
+    for m in (*matches, _special_match(stem)):
+        if m:
+            cut = min(cut, m.start())
+    words = []
+    for token in re.split(r"[\s_.]+", stem[:cut]):
+        if token.lower().strip("-") in _NOISE_TOKENS:
+            break
+        words.append(token)
+    return " ".join(words).strip(" -")
+
+
+def _regex_key(filename: str, pattern: re.Pattern[str]) -> str | None:
+    match = pattern.search(Path(filename).stem)
+    if not match:
+        return None
+    value = match.group(1) if match.groups() else match.group(0)
+    return str(int(value)) if value.isdigit() else value.lower()
+
+
+def _list_videos(folder: Path) -> list[Path]:
+    return sorted(
+        f
+        for f in folder.iterdir()
+        if f.is_file() and f.suffix.lower() in VIDEO_EXTENSIONS
+    )
+
+
+def _match_file(
+    ref_file: Path,
+    candidates: list[Path],
+    strategy: DiscoveryStrategyStr,
+    pattern: re.Pattern[str] | None,
+) -> tuple[Path | None, float]:
+    """Finds the candidate matching ref_file. Returns (file, confidence)."""
+    by_name = {c.name: c for c in candidates}
+    if ref_file.name in by_name:
+        return by_name[ref_file.name], 1.0
+    if strategy == "exact":
+        return None, 0.0
+
+    if strategy == "regex":
+        if pattern is None:
+            return None, 0.0
+        ref_key = _regex_key(ref_file.name, pattern)
+        if ref_key is None:
+            return None, 0.0
+        hits = [c for c in candidates if _regex_key(c.name, pattern) == ref_key]
+        if len(hits) == 1:
+            return hits[0], 1.0
+        if hits:
+            # Ambiguous: several candidates share the key, pick the closest name
+            best = max(
+                hits,
+                key=lambda c: difflib.SequenceMatcher(
+                    None, normalize_name(ref_file.name), normalize_name(c.name)
+                ).ratio(),
+            )
+            return best, 0.5
+        return None, 0.0
+
+    # "normalized": group by episode key when the reference has one
+    ref_episode = extract_episode(ref_file.name)
+    if ref_episode is not None:
+        ref_key = ref_episode.match_key()
+        hits = []
+        for c in candidates:
+            ep = extract_episode(c.name)
+            if ep is not None and ep.match_key() == ref_key:
+                hits.append(c)
+        if len(hits) == 1:
+            return hits[0], 0.9
+        if hits:
+            # Same episode in several files (e.g. two seasons) - closest title
+            ref_core = normalize_name(ref_file.name)
+            best = max(
+                hits,
+                key=lambda c: difflib.SequenceMatcher(
+                    None, ref_core, normalize_name(c.name)
+                ).ratio(),
+            )
+            return best, 0.5
+        # A file of another episode is never the right pair, however close
+        # the rest of its name is
+        return None, 0.0
+
+    ref_core = normalize_name(ref_file.name)
+    exact_core = [c for c in candidates if normalize_name(c.name) == ref_core]
+    if len(exact_core) == 1:
+        return exact_core[0], 0.95
+    pool = exact_core or candidates
+    best_file: Path | None = None
+    best_ratio = 0.0
+    for c in pool:
+        core = normalize_name(c.name)
+        ratio = difflib.SequenceMatcher(None, ref_core, core).ratio()
+        if ratio > best_ratio:
+            best_file, best_ratio = c, ratio
+    if best_file is None or best_ratio < _FUZZY_MIN_RATIO:
+        return None, 0.0
+    if len(exact_core) > 1:
+        # Several identical cores - we can't tell them apart
+        return best_file, 0.5
+    return best_file, round(best_ratio * 0.9, 3)
+
+
+def discover_jobs(
+    sources: dict[str, SourceValue],
+    strategy: DiscoveryStrategyStr = "exact",
+    episode_regex: str = "",
+) -> list[dict[str, Any]]:
     """
     Discovers jobs based on a dictionary of source paths.
     'Source 1' is the reference for filename matching.
-    Returns a list of job dictionaries, each with a 'sources' key.
+    Returns a list of job dictionaries, each with a 'sources' key and a
+    'match_confidence' key ({source_key: 0.0-1.0}).
 
     NEW: Supports single-source (Source 1 only) for remux-only mode.
+
+    Raises:
+        ValueError: Invalid paths, or an invalid regex for the regex strategy
     """
     source1_path_str = sources.get("Source 1")
     if not source1_path_str:
         raise ValueError("Source 1 (Reference) path cannot be empty.")
 
+    multipart = {
+        key: SourceInput.from_value(value)
+        for key, value in sources.items()
+        if value and not isinstance(value, str)
+    }
+    if multipart:
+        return [_multipart_job(sources, multipart)]
+
     source1_path = Path(source1_path_str)
     if not source1_path.exists():
         raise FileNotFoundError(f"Source 1 path does not exist: {source1_path}")
@@ -48,7 +357,8 @@ def discover_jobs(sources: dict[str, str]) -> list[dict[str, dict[str, str]]]:
 
         # CHANGE: Always return the job, even with only Source 1
         # This enables remux-only mode for processing a single file
-        return [{"sources": job_sources}]
+        confidence = {k: 1.0 for k in job_sources if k != "Source 1"}
+        return [{"sources": job_sources, "match_confidence": confidence}]
 
     # --- Batch (Folder) Mode ---
     if source1_path.is_dir():
@@ -58,23 +368,73 @@ def discover_jobs(sources: dict[str, str]) -> list[dict[str, dict[str, str]]]:
                     "If Source 1 is a folder, all other sources must also be folders or empty."
                 )
 
+        pattern: re.Pattern[str] | None = None
+        if strategy == "regex":
+            if not episode_regex:
+                raise ValueError("Regex discovery needs an episode pattern.")
+            try:
+                pattern = re.compile(episode_regex, re.IGNORECASE)
+            except re.error as e:
+                raise ValueError(f"Invalid discovery regex: {e}") from e
+
+        candidates = {
+            key: _list_videos(path) if path.is_dir() else []
+            for key, path in other_source_paths.items()
+        }
+
         jobs = []
-        for ref_file in sorted(
-            ref_file
-            for ref_file in source1_path.iterdir()
-            if ref_file.is_file()
-            and ref_file.suffix.lower() in [".mkv", ".mp4", ".m4v"]
-        ):
+        for ref_file in _list_videos(source1_path):
             job_sources = {"Source 1": str(ref_file)}
-            for key, path in other_source_paths.items():
-                match_file = path / ref_file.name
-                if match_file.is_file():
+            confidence: dict[str, float] = {}
+            for key in other_source_paths:
+                match_file, score = _match_file(
+                    ref_file, candidates[key], strategy, pattern
+                )
+                if match_file is not None:
                     job_sources[key] = str(match_file)
+                    confidence[key] = score
 
             # CHANGE: Allow single-source batch jobs (remux-only mode)
             # Always include the job, even if no matching files in other sources
-            jobs.append({"sources": job_sources})
+            jobs.append({"sources": job_sources, "match_confidence": confidence})
 
         return jobs
 
     raise ValueError("Source 1 path is not a valid file or directory.")
+
+
+def _multipart_job(
+    sources: dict[str, SourceValue], multipart: dict[str, SourceInput]
+) -> dict[str, Any]:
+    """The single job of a set of sources where some are lists of parts."""
+    job_sources: dict[str, SourceValue] = {}
+    for key, value in sources.items():
+        if not value:
+            continue
+        source = multipart.get(key) or SourceInput.from_value(value)
+        for part in source.parts:
+            path = Path(part)
+            if path.is_dir():
+                raise ValueError(
+                    f"{key}: multi-part sources need single files, not folders "
+                    f"({part})."
+                )
+            if not path.is_file():
+                raise FileNotFoundError(f"{key} part does not exist: {part}")
+        job_sources[key] = source.to_value()
+    confidence = {k: 1.0 for k in job_sources if k != "Source 1"}
+    return {"sources": job_sources, "match_confidence": confidence}
+
+
+def low_confidence_jobs(
+    jobs: list[dict[str, Any]], threshold: float
+) -> list[tuple[dict[str, Any], dict[str, float]]]:
+    """Returns (job, {source: confidence}) for pairings below threshold."""
+    flagged = []
+    for job in jobs:
+        weak = {
+            k: v for k, v in job.get("match_confidence", {}).items() if v < threshold
+        }
+        if weak:
+            flagged.append((job, weak))
+    return flagged
@@ -1,5 +1,6 @@
 # vsg_core/job_layouts/__init__.py
 
 from .manager import JobLayoutManager
+from .queue_store import JobQueueStore
 
-__all__ = ["JobLayoutManager"]
+__all__ = ["JobLayoutManager", "JobQueueStore"]
@@ -0,0 +1,303 @@
+# vsg_core/job_layouts/auto_layout.py
+"""
+Generated starting layouts for the manual selection window.
+
+Most batches want the same shape: one source's tracks as they are in the
+file, plus the audio and/or subtitles in some language from the others
+("all of Source 1 plus Source 2's Japanese audio"). ``auto_layout`` builds
+that list from a job's track info so the user only has to tweak it.
+
+- Every track of ``order_source`` is kept, in file order. Video only ever
+  comes from Source 1 (as in the manual selection); when ``order_source``
+  is another source, Source 1's video goes first.
+- Tracks of ``track_types`` in one of ``languages`` are added from the
+  other sources (in source order), each right after the last track of the
+  same type, so audio stays with audio and subtitles with subtitles.
+  Languages compare by name, so "ja" also picks "jpn" tracks.
+
+Flags are not decided here: the tracks start out as if added by hand, and
+the manual selection's default rules and the mux's flag settings
+(``default_audio_lang`` and friends) apply as usual.
+
+``LayoutRuleSet`` is the declarative form for a whole series ("all video
+from Source 1; jpn + eng audio, preferring Source 1; eng full + eng forced
+subtitles"), kept in the ``layout_rules`` setting and applied to every job
+opened without a layout. A rule that finds nothing is logged and skipped.
+Forced subtitles are recognized by their flag or name and keep
+``is_forced_display``, which the flag policy then enforces.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any
+
+from ..mux.track_names import language_name
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+REFERENCE = "Source 1"
+
+
+@dataclass(frozen=True, slots=True)
+class AutoLayoutPolicy:
+    """How ``auto_layout`` picks and orders tracks."""
+
+    order_source: str = REFERENCE  # Kept whole, in its original order
+    languages: tuple[str, ...] = ()  # Added from the other sources
+    track_types: tuple[str, ...] = ("audio", "subtitles")
+
+    @staticmethod
+    def parse_languages(text: str) -> tuple[str, ...]:
+        """Comma-separated codes as a tuple ("jpn, eng" -> ("jpn", "eng"))."""
+        parts = (p.strip().lower() for p in text.replace(";", ",").split(","))
+        return tuple(dict.fromkeys(p for p in parts if p))
+
+    def describe(self) -> str:
+        added = ", ".join(self.languages) or "no"
+        kinds = " and ".join(self.track_types) or "tracks"
+        return (
+            f"{self.order_source} in original order + {added} {kinds} "
+            f"from the other sources"
+        )
+
+
+def _source_number(key: str) -> int:
+    return int(m.group()) if (m := re.search(r"\d+", key)) else 0
+
+
+def _matches_language(track: dict[str, Any], wanted: set[str]) -> bool:
+    name = language_name(track.get("lang") or "")
+    return bool(name) and name in wanted
+
+
+def _insert_after_type(out: list[dict[str, Any]], track: dict[str, Any]) -> None:
+    """Insert after the last track of the same type (end of list if none)."""
+    for i in range(len(out) - 1, -1, -1):
+        if out[i].get("type") == track.get("type"):
+            out.insert(i + 1, track)
+            return
+    out.append(track)
+
+
+def auto_layout(
+    track_info: dict[str, list[dict[str, Any]]], policy: AutoLayoutPolicy
+) -> list[dict[str, Any]]:
+    """
+    Track dicts (copies from ``track_info``) in the generated order, ready
+    for the manual selection's final list.
+    """
+    base = policy.order_source if policy.order_source in track_info else REFERENCE
+    out: list[dict[str, Any]] = []
+    if base != REFERENCE:
+        out.extend(
+            dict(t) for t in track_info.get(REFERENCE, []) if t.get("type") == "video"
+        )
+    out.extend(
+        dict(t)
+        for t in track_info.get(base, [])
+        if t.get("type") != "video" or base == REFERENCE
+    )
+
+    wanted = {language_name(lang) for lang in policy.languages} - {""}
+    if not wanted:
+        return out
+    for source in sorted(track_info, key=_source_number):
+        if source == base:
+            continue
+        for track in track_info[source]:
+            if track.get("type") in policy.track_types and _matches_language(
+                track, wanted
+            ):
+                _insert_after_type(out, dict(track))
+    return out
+
+
+# --- Rule sets ---
+
+TRACK_TYPES = ("video", "audio", "subtitles")
+_TYPE_ALIASES = {"subs": "subtitles", "subtitle": "subtitles"}
+
+
+def is_forced_track(track: dict[str, Any]) -> bool:
+    """Forced flag set, or "forced" in the track name."""
+    return bool(track.get("forced")) or "forced" in (track.get("name") or "").lower()
+
+
+@dataclass(frozen=True, slots=True)
+class LayoutRule:
+    """
+    One line of a ``LayoutRuleSet``: which tracks of a type to include.
+
+    Text form (``parse``/``describe``), one rule per line::
+
+        TYPE [LANGS|any] [forced|full] [all] [from SOURCE, ...]
+
+    e.g. ``audio jpn,eng`` or ``subtitles eng forced from Source 2``.
+    Without ``all`` each language gets one track, from the first source
+    (in ``sources`` order, else Source 1, Source 2, ...) that has one; with
+    ``all`` every matching track is included.
+    """
+
+    track_type: str
+    languages: tuple[str, ...] = ()  # () = any language
+    sources: tuple[str, ...] = ()  # Preference order; () = every source
+    forced: bool | None = None  # Subtitles: True = forced only, False = full only
+    include_all: bool = False
+
+    def __post_init__(self) -> None:
+        if self.track_type not in TRACK_TYPES:
+            raise ValueError(
+                f"Unknown track type '{self.track_type}' (use video, audio or "
+                "subtitles)"
+            )
+        if self.forced is not None and self.track_type != "subtitles":
+            raise ValueError("'forced'/'full' only apply to subtitles")
+
+    @classmethod
+    def parse(cls, line: str) -> LayoutRule:
+        text, _, source_text = line.partition(" from ")
+        tokens = text.split()
+        if not tokens:
+            raise ValueError("Empty rule")
+        track_type = _TYPE_ALIASES.get(tokens[0].lower(), tokens[0].lower())
+        languages: tuple[str, ...] = ()
+        forced: bool | None = None
+        include_all = False
+        for token in tokens[1:]:
+            word = token.lower()
+            if word == "forced":
+                forced = True
+            elif word == "full":
+                forced = False
+            elif word == "all":
+                include_all = True
+            elif word != "any":
+                languages = AutoLayoutPolicy.parse_languages(token)
+        sources = tuple(
+            s.strip().title() for s in source_text.split(",") if s.strip()
+        )
+        return cls(track_type, languages, sources, forced, include_all)
+
+    def describe(self) -> str:
+        parts = [self.track_type, ",".join(self.languages) or "any"]
+        if self.forced is not None:
+            parts.append("forced" if self.forced else "full")
+        if self.include_all:
+            parts.append("all")
+        if self.sources:
+            parts.append("from " + ", ".join(self.sources))
+        return " ".join(parts)
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "track_type": self.track_type,
+            "languages": list(self.languages),
+            "sources": list(self.sources),
+            "forced": self.forced,
+            "include_all": self.include_all,
+        }
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> LayoutRule:
+        return cls(
+            track_type=data["track_type"],
+            languages=tuple(data.get("languages") or ()),
+            sources=tuple(data.get("sources") or ()),
+            forced=data.get("forced"),
+            include_all=bool(data.get("include_all", False)),
+        )
+
+    def candidates(
+        self, track_info: dict[str, list[dict[str, Any]]]
+    ) -> list[dict[str, Any]]:
+        """Matching tracks, most preferred source first, then file order."""
+        sources = self.sources or tuple(sorted(track_info, key=_source_number))
+        out = []
+        for source in sources:
+            for track in track_info.get(source, []):
+                if track.get("type") != self.track_type:
+                    continue
+                if self.forced is not None and is_forced_track(track) != self.forced:
+                    continue
+                out.append(track)
+        return out
+
+
+@dataclass(frozen=True, slots=True)
+class LayoutRuleSet:
+    """Ordered rules resolved into a layout (see ``resolve``)."""
+
+    rules: tuple[LayoutRule, ...] = ()
+
+    @classmethod
+    def parse(cls, text: str) -> LayoutRuleSet:
+        """One rule per line; blank lines and ``#`` comments are skipped."""
+        rules = []
+        for number, raw in enumerate(text.splitlines(), 1):
+            line = raw.split("#", 1)[0].strip()
+            if not line:
+                continue
+            try:
+                rules.append(LayoutRule.parse(line))
+            except ValueError as e:
+                raise ValueError(f"Line {number}: {e}") from e
+        return cls(tuple(rules))
+
+    def describe(self) -> str:
+        return "\n".join(rule.describe() for rule in self.rules)
+
+    def to_list(self) -> list[dict[str, Any]]:
+        return [rule.to_dict() for rule in self.rules]
+
+    @classmethod
+    def from_list(cls, data: list[dict[str, Any]]) -> LayoutRuleSet:
+        return cls(tuple(LayoutRule.from_dict(d) for d in data))
+
+    def resolve(
+        self,
+        track_info: dict[str, list[dict[str, Any]]],
+        log: Callable[[str], None] | None = None,
+    ) -> list[dict[str, Any]]:
+        """
+        Layout items in rule order; a track matched by several rules is
+        included once, by the first. Subtitles get ``is_forced_display``
+        from being forced, for the flag policy to work with; defaults are
+        left to the manual selection and the mux's flag settings.
+        """
+        log = log or (lambda msg: None)
+        taken: set[tuple[str, Any]] = set()
+        out: list[dict[str, Any]] = []
+
+        def take(track: dict[str, Any]) -> None:
+            taken.add((track.get("source"), track.get("id")))
+            item = {**track, "is_default": False, "is_forced_display": False}
+            if track.get("type") == "subtitles":
+                item["is_forced_display"] = is_forced_track(track)
+            out.append(item)
+
+        for number, rule in enumerate(self.rules, 1):
+            candidates = [
+                t
+                for t in rule.candidates(track_info)
+                if (t.get("source"), t.get("id")) not in taken
+                and not (t.get("type") == "video" and t.get("source") != REFERENCE)
+            ]
+            label = f"[Layout Rules] Rule {number} ({rule.describe()})"
+            if not rule.languages:
+                chosen = candidates if rule.include_all else candidates[:1]
+                if not chosen:
+                    log(f"{label}: no matching track, skipped.")
+                for track in chosen:
+                    take(track)
+                continue
+            for lang in rule.languages:
+                wanted = {language_name(lang)}
+                matches = [t for t in candidates if _matches_language(t, wanted)]
+                if not matches:
+                    log(f"{label}: no '{lang}' track in any source, skipped.")
+                for track in matches if rule.include_all else matches[:1]:
+                    take(track)
+        return out
@@ -0,0 +1,125 @@
+# vsg_core/job_layouts/duplicates.py
+"""
+Detection of the same track picked from two sources.
+
+Sources of one release often carry identical tracks (both have the same
+Japanese audio), and adding both to the layout only bloats the output.
+``find_duplicate_tracks`` flags pairs of layout tracks from different
+sources that agree on language, codec, channel count (audio) and
+duration. It only reports: keeping both can be intentional (e.g. two
+subtitle tracks that differ in content but not in metadata), so nothing
+is removed.
+
+Metadata comes from the probed track info, so a layout saved before a
+field existed is still checked. When either track has no known duration
+the pair is still reported, saying so, since the rest already matches.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from itertools import combinations
+from typing import Any
+
+DUPLICATE_DURATION_TOLERANCE_MS = 1000
+CHECKED_TYPES = ("audio", "subtitles")
+
+
+def _fmt_duration(ms: int) -> str:
+    minutes, rest = divmod(ms, 60_000)
+    return f"{minutes}:{rest / 1000:04.1f}"
+
+
+@dataclass(frozen=True, slots=True)
+class DuplicateTracks:
+    """Two layout tracks from different sources that look identical."""
+
+    track_type: str
+    first: tuple[str, int]  # (source, track id)
+    second: tuple[str, int]
+    lang: str
+    codec_id: str
+    channels: int | None
+    duration_ms: int | None  # None: not known for at least one of them
+    duration_diff_ms: int | None
+
+    def describe(self) -> str:
+        details = [self.lang, self.codec_id]
+        if self.channels:
+            details.append(f"{self.channels} ch")
+        if self.duration_ms is None:
+            details.append("duration not known")
+        else:
+            duration = _fmt_duration(self.duration_ms)
+            if self.duration_diff_ms:
+                duration += f" (differ by {self.duration_diff_ms} ms)"
+            details.append(duration)
+        return (
+            f"{self.first[0]} {self.track_type} track {self.first[1]} and "
+            f"{self.second[0]} {self.track_type} track {self.second[1]} look "
+            f"identical: {', '.join(details)}"
+        )
+
+
+def _probed(
+    item: dict[str, Any], track_info: dict[str, list[dict[str, Any]]]
+) -> dict[str, Any]:
+    for track in track_info.get(item.get("source", ""), []):
+        if track.get("id") == item.get("id") and track.get("type") == item.get("type"):
+            return track
+    return item
+
+
+def _channels(track: dict[str, Any]) -> int | None:
+    try:
+        return int(track.get("audio_channels") or 0) or None
+    except (TypeError, ValueError):
+        return None
+
+
+def find_duplicate_tracks(
+    layout: list[dict[str, Any]],
+    track_info: dict[str, list[dict[str, Any]]],
+    tolerance_ms: int = DUPLICATE_DURATION_TOLERANCE_MS,
+) -> list[DuplicateTracks]:
+    """Pairs of layout tracks (different sources) that are likely the same."""
+    candidates = [
+        (item, _probed(item, track_info))
+        for item in layout
+        if item.get("type") in CHECKED_TYPES
+        and not item.get("is_generated")
+        and item.get("source") != "External"
+    ]
+    found = []
+    for (item_a, a), (item_b, b) in combinations(candidates, 2):
+        if item_a.get("source") == item_b.get("source"):
+            continue
+        if a.get("type") != b.get("type"):
+            continue
+        lang = (a.get("lang") or "und").lower()
+        if lang != (b.get("lang") or "und").lower():
+            continue
+        codec = a.get("codec_id") or ""
+        if codec.upper() != (b.get("codec_id") or "").upper():
+            continue
+        if _channels(a) != _channels(b):
+            continue
+        dur_a, dur_b = a.get("duration_ms"), b.get("duration_ms")
+        diff = None
+        if dur_a is not None and dur_b is not None:
+            diff = abs(dur_a - dur_b)
+            if diff > tolerance_ms:
+                continue
+        found.append(
+            DuplicateTracks(
+                track_type=a["type"],
+                first=(item_a["source"], item_a["id"]),
+                second=(item_b["source"], item_b["id"]),
+                lang=lang,
+                codec_id=codec,
+                channels=_channels(a),
+                duration_ms=dur_a if diff is not None else None,
+                duration_diff_ms=diff,
+            )
+        )
+    return found
@@ -10,12 +10,15 @@ Architecture:
 - EnhancedSignatureGenerator: Creates track and structure signatures for comparing files
 - LayoutPersistence: Handles JSON storage/loading of layout files in temp_root/job_layouts/
 - LayoutValidator: Ensures loaded layouts have required fields and valid data
+- JobQueueStore: Persists the queue itself (queue.json) for session restore
 - JobLayoutManager: Main API coordinating save, load, copy, and validation operations
 
 Key Features:
 - Track Signatures: Detect changes in codec, language, channels, or sample rate
 - Structure Signatures: Compare track counts/types to determine compatibility
 - Layout Copying: Reuse layouts between jobs if file structures match
+- Layout Duplication: Clone a layout onto other sources, flagging it for review
+  when its stored track ids don't fit the new files
 - Enhanced Layouts: Add positional metadata for robust track ordering
 
 Job IDs are MD5 hashes of source file names, ensuring consistency across runs.
@@ -23,18 +26,57 @@ Job IDs are MD5 hashes of source file names, ensuring consistency across runs.
 
 from __future__ import annotations
 
+import copy
 import hashlib
 from collections import defaultdict
 from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
+from ..models.source_input import SourceInput, primary_sources
 from .persistence import LayoutPersistence
+from .queue_store import JobQueueStore
 from .signature import EnhancedSignatureGenerator
-from .validation import LayoutValidator
+from .validation import (
+    LayoutMismatch,
+    LayoutValidator,
+    rebind_layout,
+    validate_against,
+)
 
 if TYPE_CHECKING:
     from collections.abc import Callable
 
+    from ..models.source_input import SourceValue
+    from ..models.types import LayoutMatchStrategyStr
+
+
+def repoint_layout(
+    layout_template: list[dict[str, Any]], target_sources: dict[str, str]
+) -> list[dict[str, Any]]:
+    """Creates a new layout with file paths updated for the target job."""
+    new_layout = []
+    for track_template in layout_template:
+        # Use deepcopy to properly copy nested dicts like style_patch and font_replacements
+        new_track = copy.deepcopy(track_template)
+        source_key = new_track.get("source")
+        if source_key in target_sources:
+            new_track["original_path"] = target_sources[source_key]
+
+        # Clear session-specific temp file path
+        new_track.pop("user_modified_path", None)
+
+        # Clear file-specific event indices inside filter_config
+        # forced_include/forced_exclude are event indices from the source file
+        # They must be recalculated for each target file (only style-based filter transfers)
+        filter_config = new_track.get("filter_config")
+        if filter_config:
+            filter_config.pop("kept_indices", None)
+            filter_config.pop("forced_include", None)
+            filter_config.pop("forced_exclude", None)
+
+        new_layout.append(new_track)
+    return new_layout
+
 
 class JobLayoutManager:
     """
@@ -50,12 +92,18 @@ class JobLayoutManager:
         self.signature_gen = EnhancedSignatureGenerator()
         self.persistence = LayoutPersistence(self.layouts_dir, self.log)
         self.validator = LayoutValidator()
+        self.queue_store = JobQueueStore(self.layouts_dir, self.log)
 
-    def generate_job_id(self, sources: dict[str, str]) -> str:
+    def generate_job_id(self, sources: dict[str, SourceValue]) -> str:
         """Generates a consistent and unique job ID from source file paths."""
+
+        def names(value: SourceValue) -> str:
+            # Multi-part sources as "part1.mkv+part2.mkv"; single files as before
+            return "+".join(Path(p).name for p in SourceInput.from_value(value).parts)
+
         sorted_sources = sorted(sources.items())
         source_string = "|".join(
-            f"{key}:{Path(path).name}" for key, path in sorted_sources if path
+            f"{key}:{names(path)}" for key, path in sorted_sources if path
         )
         return hashlib.md5(source_string.encode()).hexdigest()[:16]
 
@@ -168,6 +216,73 @@ class JobLayoutManager:
 
         return self.persistence.save_layout(target_job_id, target_layout_data)
 
+    def duplicate_layout(
+        self,
+        source_job_id: str,
+        target_job_id: str,
+        target_sources: dict[str, SourceValue],
+        target_track_info: dict[str, list[dict]],
+        strategy: LayoutMatchStrategyStr = "id_then_position",
+    ) -> list[str] | None:
+        """
+        Clones a job's layout onto a job with other sources.
+
+        The layout's stored track ids are checked against the target's
+        tracks; problems are returned (an empty list means the layout
+        applies as-is) and stored as ``needs_review`` in the new layout,
+        which stays until the job is reconfigured. ``strategy`` is how the
+        tracks are matched (see ``find_layout_track``). Returns None when
+        nothing was saved.
+        """
+        source_data = self.load_job_layout(source_job_id)
+        if not source_data:
+            self.log(
+                f"[LayoutManager] Cannot duplicate: Source layout {source_job_id} not found."
+            )
+            return None
+
+        layout = repoint_layout(
+            source_data["enhanced_layout"], primary_sources(target_sources)
+        )
+        problems = [
+            m.describe()
+            for m in self.validate_against(layout, target_track_info, strategy)
+        ]
+        layout = rebind_layout(layout, target_track_info, strategy, self.log)
+        target_layout_data = {
+            "job_id": target_job_id,
+            "sources": target_sources,
+            "enhanced_layout": layout,
+            "attachment_sources": source_data.get("attachment_sources", []),
+            "source_settings": source_data.get("source_settings", {}),
+            "chapter_source": source_data.get("chapter_source", "Source 1"),
+            "track_signature": self.signature_gen.generate_track_signature(
+                target_track_info
+            ),
+            "structure_signature": self.signature_gen.generate_structure_signature(
+                target_track_info
+            ),
+            "copied_from": source_job_id,
+        }
+        if problems:
+            target_layout_data["needs_review"] = problems
+        if not self.persistence.save_layout(target_job_id, target_layout_data):
+            return None
+        self.log(
+            f"[LayoutManager] Duplicated layout {source_job_id} -> {target_job_id}"
+            + (f" ({len(problems)} track(s) need review)" if problems else "")
+        )
+        return problems
+
+    def validate_against(
+        self,
+        layout: list[dict[str, Any]],
+        track_info: dict[str, list[dict]],
+        strategy: LayoutMatchStrategyStr = "id_then_position",
+    ) -> list[LayoutMismatch]:
+        """Layout tracks that have no counterpart in ``track_info`` (see validation)."""
+        return validate_against(layout, track_info, strategy)
+
     def _create_enhanced_layout(
         self, layout: list[dict[str, Any]]
     ) -> list[dict[str, Any]]:
@@ -190,6 +305,14 @@ class JobLayoutManager:
             enhanced.append(enhanced_track)
         return enhanced
 
+    def save_queue(self, jobs: list[dict[str, Any]]) -> bool:
+        """Persists the job queue so it can be restored after a restart."""
+        return self.queue_store.save(jobs, self)
+
+    def load_queue(self) -> list[dict[str, Any]]:
+        """Restores the saved job queue (see JobQueueStore.load_or_empty)."""
+        return self.queue_store.load_or_empty(self)
+
     def layout_exists(self, job_id: str) -> bool:
         return self.persistence.layout_exists(job_id)
 
@@ -0,0 +1,161 @@
+# vsg_core/job_layouts/queue_store.py
+"""
+Job queue persistence.
+
+Stores the job queue (order, sources, discovery confidence, per-job
+settings overrides) as ``queue.json`` next to the per-job layout files so
+a session can be restored after the app is closed mid-batch.
+
+On restore every entry is re-associated with its layout via the job ID:
+
+- Entries whose source files no longer exist get status "Unavailable".
+- Layouts whose stored source paths differ from the entry's sources are
+  flagged ``stale_layout`` (same filenames, different files) instead of
+  being applied silently.
+- So are entries whose source files changed since the queue was saved: each
+  file's size and modification time are stored with the entry, so a file
+  replaced under the same name is caught too.
+"""
+
+from __future__ import annotations
+
+import json
+from datetime import datetime
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from vsg_core.models.source_input import SourceInput
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from vsg_core.models.source_input import SourceValue
+
+    from .manager import JobLayoutManager
+
+QUEUE_FILENAME = "queue.json"
+QUEUE_VERSION = 1
+
+# Per-job keys worth persisting; everything else is rebuilt on load
+_PERSISTED_KEYS = ("sources", "match_confidence", "settings_overrides")
+
+
+def file_signature(path: str) -> dict[str, int] | None:
+    """Size and modification time of ``path`` (None if it is gone)."""
+    try:
+        st = Path(path).stat()
+    except OSError:
+        return None
+    return {"size": st.st_size, "mtime_ns": st.st_mtime_ns}
+
+
+def source_signatures(
+    sources: dict[str, SourceValue],
+) -> dict[str, list[dict[str, int] | None]]:
+    """``file_signature`` of every file (every part) of every source."""
+    return {
+        key: [file_signature(p) for p in SourceInput.from_value(value).parts]
+        for key, value in sources.items()
+        if value
+    }
+
+
+class JobQueueStore:
+    """Saves and restores the job queue alongside its layout files."""
+
+    def __init__(self, layouts_dir: Path, log_callback: Callable[[str], None]):
+        self.path = layouts_dir / QUEUE_FILENAME
+        self.log = log_callback
+
+    def save(
+        self, jobs: list[dict[str, Any]], layout_manager: JobLayoutManager
+    ) -> bool:
+        """Writes the queue atomically. An empty queue removes the file."""
+        try:
+            if not jobs:
+                self.clear()
+                return True
+            entries = []
+            for job in jobs:
+                entry = {k: job[k] for k in _PERSISTED_KEYS if k in job}
+                entry["job_id"] = layout_manager.generate_job_id(job["sources"])
+                entry["source_signatures"] = source_signatures(job["sources"])
+                entries.append(entry)
+            data = {
+                "version": QUEUE_VERSION,
+                "saved_timestamp": datetime.now().isoformat(),
+                "jobs": entries,
+            }
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            temp_file = self.path.with_suffix(".tmp")
+            temp_file.write_text(
+                json.dumps(data, indent=2, ensure_ascii=False), encoding="utf-8"
+            )
+            temp_file.replace(self.path)
+            return True
+        except Exception as e:
+            self.log(f"[QueueStore] Error saving job queue: {e}")
+            return False
+
+    def load_or_empty(self, layout_manager: JobLayoutManager) -> list[dict[str, Any]]:
+        """Restores the saved queue, or returns [] if there is none."""
+        if not self.path.exists():
+            return []
+        try:
+            data = json.loads(self.path.read_text(encoding="utf-8"))
+        except Exception as e:
+            self.log(f"[QueueStore] Could not read saved queue, starting empty: {e}")
+            return []
+
+        jobs: list[dict[str, Any]] = []
+        for entry in data.get("jobs", []):
+            sources = entry.get("sources") or {}
+            if "Source 1" not in sources:
+                continue
+            job: dict[str, Any] = {
+                "sources": sources,
+                "match_confidence": entry.get("match_confidence", {}),
+            }
+            if entry.get("settings_overrides"):
+                job["settings_overrides"] = entry["settings_overrides"]
+
+            signatures = source_signatures(sources)
+            missing = [k for k, sigs in signatures.items() if None in sigs]
+            if missing:
+                job["status"] = "Unavailable"
+                job["missing_sources"] = missing
+            else:
+                job["status"] = "Needs Configuration"
+
+            job_id = layout_manager.generate_job_id(sources)
+            saved_signatures = entry.get("source_signatures")
+            if entry.get("job_id") and entry["job_id"] != job_id:
+                job["stale_layout"] = True
+            elif (
+                not missing
+                and saved_signatures is not None
+                and saved_signatures != signatures
+            ):
+                # Same paths, but a file was replaced or modified
+                job["stale_layout"] = True
+            else:
+                layout = layout_manager.load_job_layout(job_id)
+                if layout and layout.get("sources") != sources:
+                    job["stale_layout"] = True
+
+            jobs.append(job)
+
+        unavailable = sum(1 for j in jobs if j["status"] == "Unavailable")
+        stale = sum(1 for j in jobs if j.get("stale_layout"))
+        self.log(
+            f"[QueueStore] Restored {len(jobs)} job(s) "
+            f"({unavailable} unavailable, {stale} with stale layouts)."
+        )
+        return jobs
+
+    def clear(self) -> None:
+        """Removes the saved queue file."""
+        try:
+            self.path.unlink(missing_ok=True)
+        except OSError as e:
+            self.log(f"[QueueStore] Error removing saved queue: {e}")
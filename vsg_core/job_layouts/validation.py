@@ -1,6 +1,14 @@
 # vsg_core/job_layouts/validation.py
 from __future__ import annotations
 
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from ..models.types import LayoutMatchStrategyStr
+
 
 class LayoutValidator:
     """Validates that loaded layout data is well-formed."""
@@ -31,3 +39,161 @@ class LayoutValidator:
                     return False, f"Layout item {i} missing required field: {field}"
 
         return True, "Valid"
+
+
+@dataclass(frozen=True, slots=True)
+class LayoutMismatch:
+    """A layout track with no counterpart in a job's files."""
+
+    source: str
+    track_type: str
+    track_id: Any
+    reason: str
+
+    def describe(self) -> str:
+        return f"{self.source} {self.track_type} track {self.track_id}: {self.reason}"
+
+
+def _mismatch(item: dict[str, Any], reason: str) -> LayoutMismatch:
+    return LayoutMismatch(
+        str(item.get("source")), str(item.get("type")), item.get("id"), reason
+    )
+
+
+def find_layout_track(
+    item: dict[str, Any],
+    tracks: list[dict],
+    strategy: LayoutMatchStrategyStr = "id_then_position",
+) -> tuple[dict | None, str]:
+    """
+    The track of ``tracks`` a layout item applies to, and how it was found
+    ("id" or "position"; "" when there is no match).
+
+    By id, the track must have the item's id and type; by position, it is
+    the track of the item's type at its ``position_in_source_type`` (the
+    n-th audio track, ...). ``strategy`` picks one or tries id first.
+    Generated tracks always match by the id of the track they are made
+    from.
+    """
+    if item.get("is_generated"):
+        track_id = item.get("source_track_id")
+        match = next((t for t in tracks if t.get("id") == track_id), None)
+        return match, "id" if match is not None else ""
+    if strategy != "position":
+        match = next((t for t in tracks if t.get("id") == item.get("id")), None)
+        if match is not None and match.get("type") == item.get("type"):
+            return match, "id"
+        if strategy == "id":
+            return None, ""
+    position = item.get("position_in_source_type")
+    same_type = [t for t in tracks if t.get("type") == item.get("type")]
+    if position is None or position >= len(same_type):
+        return None, ""
+    return same_type[position], "position"
+
+
+def validate_against(
+    enhanced_layout: list[dict[str, Any]],
+    track_info: dict[str, list[dict]],
+    strategy: LayoutMatchStrategyStr = "id_then_position",
+) -> list[LayoutMismatch]:
+    """
+    Checks a layout's tracks against the tracks of other files.
+
+    One mismatch per layout track without a counterpart of the same codec
+    in ``track_info`` (see ``find_layout_track``); external subtitles are
+    not checked. An empty list means the layout applies to those files.
+    """
+    moved = _moved_ids(enhanced_layout, track_info, strategy)
+    mismatches = []
+    for item in enhanced_layout:
+        source = item.get("source")
+        if source == "External":
+            continue
+
+        if source not in track_info:
+            mismatches.append(_mismatch(item, f"{source} is not part of the job"))
+            continue
+        match, _ = find_layout_track(
+            _follow_parent(item, moved), track_info[source], strategy
+        )
+        if match is None:
+            mismatches.append(_mismatch(item, "not found in the new file"))
+        elif item.get("codec_id") and match.get("codec_id") != item.get("codec_id"):
+            mismatches.append(
+                _mismatch(
+                    item,
+                    f"codec changed ({item.get('codec_id')} -> {match.get('codec_id')})",
+                )
+            )
+    return mismatches
+
+
+def rebind_layout(
+    enhanced_layout: list[dict[str, Any]],
+    track_info: dict[str, list[dict]],
+    strategy: LayoutMatchStrategyStr = "id_then_position",
+    log: Callable[[str], None] | None = None,
+) -> list[dict[str, Any]]:
+    """
+    Copy of a validated layout with each track's id set to its match's.
+    Generated tracks follow the track they are made from. ``log`` gets one
+    line per track saying how it was matched.
+    """
+    moved = _moved_ids(enhanced_layout, track_info, strategy)
+    rebound = []
+    for original in enhanced_layout:
+        item = _follow_parent(original, moved)
+        source = item.get("source")
+        if source != "External":
+            match, matched_by = find_layout_track(
+                item, track_info.get(source, []), strategy
+            )
+            if log:
+                log(_match_line(original, match, matched_by))
+            if match is not None and not item.get("is_generated"):
+                item["id"] = match.get("id")
+        rebound.append(item)
+    return rebound
+
+
+def _moved_ids(
+    enhanced_layout: list[dict[str, Any]],
+    track_info: dict[str, list[dict]],
+    strategy: LayoutMatchStrategyStr,
+) -> dict[tuple[Any, Any], Any]:
+    """(source, layout id) -> matched id of each non-generated layout track."""
+    moved = {}
+    for item in enhanced_layout:
+        source = item.get("source")
+        if source == "External" or item.get("is_generated"):
+            continue
+        match, _ = find_layout_track(item, track_info.get(source, []), strategy)
+        if match is not None:
+            moved[(source, item.get("id"))] = match.get("id")
+    return moved
+
+
+def _follow_parent(
+    item: dict[str, Any], moved: dict[tuple[Any, Any], Any]
+) -> dict[str, Any]:
+    """
+    Copy of ``item``; for a generated track, with ``source_track_id`` (and
+    the id, which is its parent's) moved along with its parent track.
+    """
+    item = dict(item)
+    parent = (item.get("source"), item.get("source_track_id"))
+    if item.get("is_generated") and parent in moved:
+        if item.get("id") == item.get("source_track_id"):
+            item["id"] = moved[parent]
+        item["source_track_id"] = moved[parent]
+    return item
+
+
+def _match_line(item: dict[str, Any], match: dict | None, matched_by: str) -> str:
+    ref = f"[Layout] {item.get('source')} {item.get('type')} track {item.get('id')}"
+    if match is None:
+        return f"{ref}: no match"
+    if item.get("is_generated"):
+        return f"{ref} (generated): from track {match.get('id')}, by id"
+    return f"{ref} -> track {match.get('id')} (by {matched_by})"
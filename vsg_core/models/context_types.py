@@ -40,6 +40,7 @@ class ManualLayoutItem(TypedDict, total=False):
     # Track flags
     is_default: bool
     is_forced_display: bool
+    manual_default: bool  # Default for this type was chosen by hand
     apply_track_name: bool
 
     # Processing options
@@ -60,6 +61,10 @@ class ManualLayoutItem(TypedDict, total=False):
     style_patch: StylePatch | None
     font_replacements: FontReplacements | None
 
+    # Audio re-encode (audio tracks; absent/None = lossless copy)
+    encode: AudioEncodeSettings | None
+    loudnorm: bool  # EBU R128 normalize (audio tracks; re-encodes)
+
     # Generated track fields (for tracks created by style filtering)
     is_generated: bool
     source_track_id: int | None  # ID of source track this was generated from
@@ -92,6 +97,7 @@ class SourceNSettings(TypedDict, total=False):
 
     correlation_source_track: int | None  # Audio track ID for correlation
     use_source_separation: bool  # Whether to use source separation for analysis
+    manual_delay_ms: int | None  # Explicit delay; skips analysis for this source
 
 
 # =============================================================================
@@ -285,6 +291,15 @@ class FontReplacement(TypedDict, total=False):
 FontReplacements = dict[str, FontReplacement]
 
 
+class AudioEncodeSettings(TypedDict, total=False):
+    """Re-encode target for an audio track (see ``vsg_core.mux.encode``)."""
+
+    codec: Required[str]  # "ac3", "eac3", "aac", "opus", "flac"
+    bitrate_kbps: int | None
+    channels: int | None
+    gain_db: float
+
+
 # =============================================================================
 # PlanItem Filter Types (PlanItem.filter_config)
 # =============================================================================
@@ -315,4 +330,6 @@ class VideoVerifiedResult(TypedDict, total=False):
 
     original_delay_ms: float
     corrected_delay_ms: float
-    details: dict[str, object]  # Frame matching details
+    # Frame matching details; "checkpoints" holds the per-checkpoint outcome
+    # (read it with video_verified.checkpoint_report)
+    details: dict[str, object]
@@ -31,6 +31,9 @@ def tracks_from_dialog_info(
                         codec_id=t.get("codec_id", "") or "",
                         lang=(t.get("lang") or "und"),
                         name=(t.get("name") or ""),
+                        audio_channels=int(t["audio_channels"])
+                        if t.get("audio_channels")
+                        else None,
                     ),
                 )
             )
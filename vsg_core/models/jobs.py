@@ -7,7 +7,11 @@ from typing import TYPE_CHECKING, Literal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from vsg_core.extraction.color import Hdr10Metadata
+    from vsg_core.extraction.stats import TrackStats
+    from vsg_core.mux.encode import EncodeSpec
     from vsg_core.postprocess.auditors import AuditIssue
+    from vsg_core.postprocess.checksum import OutputChecksum
 
     from .context_types import (
         FilterConfig,
@@ -17,6 +21,7 @@ if TYPE_CHECKING:
         SyncStabilityIssue,
     )
     from .media import Track
+    from .types import DelayRoundingStr
 
 
 @dataclass(frozen=True, slots=True)
@@ -37,6 +42,8 @@ class PlanItem:
     extracted_path: Path | None = None
     is_default: bool = False
     is_forced_display: bool = False
+    # The user picked this track type's default by hand in the layout
+    manual_default: bool = False
     apply_track_name: bool = False
     convert_to_ass: bool = False
     rescale: bool = False
@@ -55,12 +62,27 @@ class PlanItem:
     # container delay) instead of the correlation-based source delay, because
     # the correlation shift is already baked into the FLAC samples.
     is_pre_aligned: bool = False
+    # Re-encode this audio track with ffmpeg instead of copying it. Once
+    # AudioEncodeStep has run, the track delay is baked into the encoded
+    # file (``is_reencoded``) and mkvmerge must not apply it again.
+    encode: EncodeSpec | None = None
+    is_reencoded: bool = False
+    dialnorm_db: int | None = None  # (E-)AC3 dialnorm, probed when levelling
+    loudnorm: bool = False  # EBU R128 normalize (when loudnorm_enabled)
+    # pad_silence sync mode: the negative delay was trimmed from the samples
+    # (muxed at 0) and the track padded to the video's duration
+    silence_padded: bool = False
     correction_source: str | None = None
     perform_ocr: bool = False
     container_delay_ms: int = 0
     custom_lang: str = ""
     custom_name: str = ""  # NEW: Custom track name set by user
     aspect_ratio: str | None = None  # NEW: Store original aspect ratio (e.g., "109:60")
+    # mkvmerge --colour-* container flag overrides (option name -> code),
+    # filled by MuxStep when the colorimetry policy is enabled
+    colour_overrides: dict[str, int] = field(default_factory=dict)
+    # HDR10 static metadata carried onto the output video track
+    hdr10: Hdr10Metadata | None = None
     stepping_adjusted: bool = (
         False  # True if subtitle timestamps were adjusted for stepping corrections
     )
@@ -107,20 +129,31 @@ class MergePlan:
     delays: Delays
     chapters_xml: Path | None = None
     attachments: list[Path] = field(default_factory=list)
+    # MIME types forced with --attachment-mime-type (others are auto-detected)
+    attachment_mime_types: dict[Path, str] = field(default_factory=dict)
     subtitle_delays_ms: dict[str, float] = field(
         default_factory=dict
     )  # Subtitle-specific delays (e.g., from video-verified mode)
+    delay_rounding: DelayRoundingStr = "nearest"  # Whole-ms rounding of --sync
+    title: str | None = None  # Segment title (--title)
+    global_tags_xml: Path | None = None  # Tags file for --global-tags
+    # --no-global-tags/--no-track-tags for every input (mux/tags.py)
+    strip_input_tags: bool = False
+    uid_seed: int | None = None  # mkvmerge --deterministic seed (mux/uids.py)
+    reproducible: bool = False  # No date/statistics tags (mux/reproducible.py)
 
 
 @dataclass(frozen=True, slots=True)
 class PipelineResult:
     """Detailed result from pipeline.run_job() with all diagnostic info."""
 
-    status: Literal["Merged", "Analyzed", "Failed"]
+    status: Literal["Merged", "Analyzed", "Needs Review", "Failed"]
     name: str
-    output: str | None = None
+    output: str | None = None  # First part when the output was split
+    outputs: list[str] = field(default_factory=list)  # All written files
     delays: dict[str, int] | None = None
     error: str | None = None
+    error_kind: str | None = None  # vsg_core.errors.ErrorKind value when failed
     issues: int = 0
     audit_details: list[AuditIssue] = field(default_factory=list)
     stepping_sources: list[str] = field(default_factory=list)
@@ -128,3 +161,7 @@ class PipelineResult:
     stepping_detected_separated: list[str] = field(default_factory=list)
     stepping_quality_issues: list[SteppingQualityIssue] = field(default_factory=list)
     sync_stability_issues: list[SyncStabilityIssue] = field(default_factory=list)
+    track_stats: list[TrackStats] = field(default_factory=list)
+    mux_warnings: list[str] = field(default_factory=list)  # mkvmerge exit 1
+    checksums: list[OutputChecksum] = field(default_factory=list)  # One per output
+    temp_dir: str | None = None  # Work dir kept after a failure (keep_temp_on_failure)
@@ -10,6 +10,7 @@ class StreamProps:
     codec_id: str
     lang: str = "und"
     name: str = ""
+    audio_channels: int | None = None  # Audio tracks only
 
 
 @dataclass(frozen=True, slots=True)
@@ -0,0 +1,72 @@
+# vsg_core/models/overrides.py
+"""
+Per-job settings overrides.
+
+A queued job can carry ``settings_overrides``: a partial settings dict,
+e.g. ``{"scan_end_percentage": 95.0}``, that is merged over the global
+settings for that job only. Keys left out keep their global value, so a
+one-off tweak does not need a whole preset of its own.
+
+Overrides are validated like the settings file itself (type coercion,
+Literal values). Unknown keys are reported and ignored, so an override
+saved by a newer version does not break the job.
+"""
+
+from __future__ import annotations
+
+from typing import Any
+
+from pydantic import ValidationError
+
+from .settings import AppSettings
+
+
+def unknown_override_keys(overrides: dict[str, Any]) -> list[str]:
+    """Keys of ``overrides`` that are not settings."""
+    known = AppSettings.get_field_names()
+    return sorted(k for k in overrides if k not in known)
+
+
+def validate_overrides(overrides: dict[str, Any]) -> list[str]:
+    """Problems with ``overrides`` as messages; empty when it can be applied."""
+    problems = [f"Unknown setting '{k}'" for k in unknown_override_keys(overrides)]
+    try:
+        AppSettings.model_validate({**AppSettings.get_defaults(), **overrides})
+    except ValidationError as e:
+        problems += [
+            f"{'.'.join(str(p) for p in err['loc'])}: {err['msg']}"
+            for err in e.errors()
+        ]
+    return problems
+
+
+def apply_overrides(
+    settings: AppSettings, overrides: dict[str, Any] | None
+) -> tuple[AppSettings, list[str]]:
+    """
+    Effective settings for a job, and log lines describing the overrides.
+
+    ``settings`` itself is not modified; it is shared by every job of a
+    batch.
+
+    Raises:
+        ValueError: An override has a value the setting does not accept
+    """
+    if not overrides:
+        return settings, []
+    unknown = unknown_override_keys(overrides)
+    lines = [f"[Overrides] Ignored unknown setting '{k}'." for k in unknown]
+    known = {k: v for k, v in overrides.items() if k not in unknown}
+    try:
+        effective = AppSettings.model_validate({**settings.model_dump(), **known})
+    except ValidationError as e:
+        raise ValueError(f"Invalid settings override: {e}") from e
+
+    for key in sorted(known):
+        before = getattr(settings, key)
+        after = getattr(effective, key)
+        if before == after:
+            lines.append(f"[Overrides] {key}: {after!r} (same as global)")
+        else:
+            lines.append(f"[Overrides] {key}: {before!r} -> {after!r}")
+    return effective, lines
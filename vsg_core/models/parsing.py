@@ -0,0 +1,73 @@
+# vsg_core/models/parsing.py
+"""String → Literal parsers for settings that the UI round-trips as text.
+
+Combo boxes, old config files, and hand-edited JSON don't always hand back
+the exact canonical spelling of a Literal value ("audio correlation",
+"allow-negative", "gcc_phat", ...). These helpers map such spellings onto
+the canonical value, or return None when the string is unknown so the
+caller can keep the previous value and warn instead of silently defaulting.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import get_args
+
+from .types import AnalysisModeStr, CorrelationMethodStr, SyncModeStr
+
+_ANALYSIS_MODES: tuple[AnalysisModeStr, ...] = get_args(AnalysisModeStr)
+_CORRELATION_METHODS: tuple[CorrelationMethodStr, ...] = get_args(
+    CorrelationMethodStr
+)
+_SYNC_MODES: tuple[SyncModeStr, ...] = get_args(SyncModeStr)
+
+# Short names used by the correlation registry config keys and in logs
+_CORRELATION_ALIASES: dict[str, CorrelationMethodStr] = {
+    "scc": "Standard Correlation (SCC)",
+    "gccphat": "Phase Correlation (GCC-PHAT)",
+    "phat": "Phase Correlation (GCC-PHAT)",
+    "onset": "Onset Detection",
+    "scot": "GCC-SCOT",
+    "gccwhiten": "Whitened Cross-Correlation",
+    "whiten": "Whitened Cross-Correlation",
+    "spectrogram": "Spectrogram Correlation",
+}
+
+
+def _fold(value: str) -> str:
+    """Case- and punctuation-insensitive key ("GCC-SCOT" → "gccscot")."""
+    return re.sub(r"[^a-z0-9]", "", value.lower())
+
+
+def _match(value: object, choices: tuple[str, ...]) -> str | None:
+    if not isinstance(value, str):
+        return None
+    if value in choices:
+        return value
+    folded = _fold(value)
+    if not folded:
+        return None
+    for choice in choices:
+        if _fold(choice) == folded:
+            return choice
+    return None
+
+
+def parse_analysis_mode(value: object) -> AnalysisModeStr | None:
+    """Parse an analysis mode string, or None if unrecognised."""
+    return _match(value, _ANALYSIS_MODES)  # type: ignore[return-value]
+
+
+def parse_correlation_method(value: object) -> CorrelationMethodStr | None:
+    """Parse a correlation method display name or short key."""
+    matched = _match(value, _CORRELATION_METHODS)
+    if matched is not None:
+        return matched  # type: ignore[return-value]
+    if isinstance(value, str):
+        return _CORRELATION_ALIASES.get(_fold(value))
+    return None
+
+
+def parse_sync_mode(value: object) -> SyncModeStr | None:
+    """Parse a sync mode string, or None if unrecognised."""
+    return _match(value, _SYNC_MODES)  # type: ignore[return-value]
@@ -0,0 +1,44 @@
+# vsg_core/models/rounding.py
+"""
+Rounding of delays to whole milliseconds (``delay_rounding``).
+
+mkvmerge's ``--sync`` takes integer milliseconds, so every analysed delay
+is rounded once before mux. How exact halves and negative values are
+treated decides whether the result matches other tools to the last ms:
+
+    nearest         nearest integer; exact halves go to the even neighbour
+                    (Python's round): +0.5 -> 0, -0.5 -> 0, +1.5 -> +2,
+                    -1.5 -> -2, +2.5 -> +2, -2.5 -> -2. The default, and
+                    what every delay used before this setting existed.
+    toward_zero     drop the fraction (int()): +1.7 -> +1, -1.7 -> -1,
+                    +0.5 -> 0, -0.5 -> 0, -1.5 -> -1. A negative delay
+                    never grows in magnitude.
+    away_from_zero  nearest integer; exact halves go away from zero (C's
+                    round(), Rust's f64::round): +0.5 -> +1, -0.5 -> -1,
+                    +1.5 -> +2, -1.5 -> -2, +2.5 -> +3, -2.5 -> -3.
+
+Zero stays zero in every mode, and the sign of the input never flips.
+"""
+
+from __future__ import annotations
+
+import math
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from .types import DelayRoundingStr
+
+
+def round_delay_ms(delay_ms: float, mode: DelayRoundingStr = "nearest") -> int:
+    """``delay_ms`` as whole milliseconds, rounded per ``mode``."""
+    if mode == "toward_zero":
+        return int(delay_ms)
+    if mode == "away_from_zero":
+        magnitude = abs(delay_ms)
+        whole = math.floor(magnitude)
+        # Compare the fraction rather than floor(x + 0.5), which rounds
+        # 0.49999999999999994 up through float addition
+        if magnitude - whole >= 0.5:
+            whole += 1
+        return int(whole) if delay_ms >= 0 else -int(whole)
+    return round(delay_ms)
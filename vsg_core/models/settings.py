@@ -23,18 +23,32 @@ Settings are organized by category:
 
 from __future__ import annotations
 
+import re
 from typing import Any, ClassVar
 
-from pydantic import BaseModel, ConfigDict
+from pydantic import BaseModel, ConfigDict, field_validator
 
+from .parsing import parse_analysis_mode, parse_correlation_method, parse_sync_mode
 from .types import (  # noqa: TC001 - Pydantic needs these at runtime
     AnalysisModeStr,
+    ChunkStrategyStr,
+    ConfidenceMetricStr,
+    CorrelationCurveStr,
     CorrelationMethodSourceSepStr,
     CorrelationMethodStr,
+    DelayRoundingStr,
     DelaySelectionModeStr,
+    DiscoveryStrategyStr,
+    DownmixModeStr,
+    FftModeStr,
     FilteringMethodStr,
+    InterlaceDetectionStr,
+    LayoutMatchStrategyStr,
+    MkvmergeOptionsFileStr,
     OcrEngineStr,
     OcrOutputFormatStr,
+    OutputSplitModeStr,
+    PeakInterpStr,
     ResampleEngineStr,
     RubberbandTransientsStr,
     SnapModeStr,
@@ -48,6 +62,9 @@ from .types import (  # noqa: TC001 - Pydantic needs these at runtime
     SubtitleSyncModeStr,
     SyncModeStr,
     SyncStabilityOutlierModeStr,
+    TagPolicyStr,
+    UnreliableAnalysisActionStr,
+    VerifyMismatchActionStr,
     VideoVerifiedBackendStr,
     VideoVerifiedCrossCheckBackendStr,
 )
@@ -83,6 +100,9 @@ class AppSettings(BaseModel):
     # =========================================================================
     output_folder: str = _PATH_SENTINEL
     temp_root: str = _PATH_SENTINEL
+    keep_temp_on_failure: bool = False  # Keep a failed job's work dir for debugging
+    # Added to the preflight's temp space estimate (re-encoded audio, indexes)
+    temp_space_margin_pct: int = 25
     logs_folder: str = _PATH_SENTINEL
     videodiff_path: str = ""
     fonts_directory: str = ""
@@ -95,15 +115,60 @@ class AppSettings(BaseModel):
     # Analysis Settings
     # =========================================================================
     analysis_mode: AnalysisModeStr = "Audio Correlation"
+    # Remux non-MKV sources to MKV (ffmpeg stream copy) before analysis
+    normalize_to_mkv: bool = False
     analysis_lang_source1: str = ""
     analysis_lang_others: str = ""
+    # Channels correlated (falls back to mono_sum if the stream lacks them)
+    analysis_downmix_mode: DownmixModeStr = "mono_sum"
+    # Keep decoded audio in the work dir so repeated analysis decodes of the
+    # same stream (reference per source, stepping QA) run once
+    analysis_audio_cache: bool = False
     min_match_pct: float = 10.0
+    # Score min_match_pct is compared against ("native" = per method); see
+    # vsg_core/analysis/correlation/confidence.py for each scale
+    correlation_confidence_metric: ConfidenceMetricStr = "native"
+    # Largest |delay| the SCC/GCC peak search considers (0 = the whole
+    # window); windows whose peak lies beyond it are rejected (lag_window.py)
+    correlation_max_lag_ms: float = 0.0
+    # FFT path of the SCC/GCC methods; plans are cached per size (fft_plan.py)
+    correlation_fft_mode: FftModeStr = "real"
+    analysis_write_report: bool = False  # {job}_analysis_report.json/.csv in logs/
+    # Take delays from <Source 1>.delays.json when present instead of analyzing
+    analysis_use_sidecar: bool = False
+    analysis_dump_chunks: bool = False  # Write each correlated window as WAV
 
     # Dense sliding window correlation (GPU)
     dense_window_s: float = 10.0
     dense_hop_s: float = 2.0
     dense_silence_threshold_db: float = -60.0
     dense_outlier_threshold_ms: float = 50.0
+    avoid_silence: bool = False  # Nudge windows off quiet regions before correlating
+    min_chunk_energy_db: float = -45.0  # Window energy needed by avoid_silence
+    # Keep windows off Source 1 chapters whose names match one of the regexes
+    # (intro/OP, outro/ED, credits); see vsg_core/analysis/chapter_exclusion.py
+    analysis_exclude_chapters: bool = False
+    analysis_exclude_chapter_patterns: list[str] = [
+        r"^(intro|opening|op\d*)\b",
+        r"^(outro|ending|ed\d*)\b",
+        r"\bcredits\b",
+    ]
+    windowed_decode: bool = False  # Seek-decode only the windows (sparse layouts)
+    chunk_strategy: ChunkStrategyStr = "uniform"
+    endpoint_chunks_start: int = 5  # "endpoints": windows at the start of the range
+    endpoint_chunks_end: int = 5  # "endpoints": windows at the end of the range
+    # Minimum distance between window centres; fewer windows when the range
+    # is too short (0 = every hop)
+    min_chunk_spacing_s: float = 0.0
+    correlation_curves: CorrelationCurveStr = "off"  # Keep lag/value curves
+    correlation_curve_max_points: int = 2000  # Decimate longer curves to this
+    # DTW warping path (diagnostic; reveals non-linear timing)
+    dtw_enabled: bool = False
+    dtw_band_ms: float = 5000.0  # Sakoe-Chiba half-width around the delay
+    # Whole-file onset-envelope alignment before window correlation, for
+    # offsets larger than a window (e.g. an extra intro on one source)
+    coarse_prealign: bool = False
+    coarse_prealign_max_offset_s: float = 300.0  # Largest offset searched
     videodiff_error_min: float = 0.0
     videodiff_error_max: float = 100.0
     videodiff_sample_fps: float = 0
@@ -124,15 +189,63 @@ class AppSettings(BaseModel):
     # Muxing Settings
     # =========================================================================
     apply_dialog_norm_gain: bool = False
+    # Dialogue level (dBFS) for (E-)AC3 tracks once dialnorm is removed;
+    # 0 = only remove the attenuation (lossless), otherwise re-encode
+    dialog_norm_target_db: int = 0
+    # EBU R128 two-pass loudnorm on audio tracks marked ``loudnorm`` in the
+    # layout (re-encodes them)
+    loudnorm_enabled: bool = False
+    loudnorm_target_i: float = -23.0  # Integrated loudness (LUFS)
+    loudnorm_target_lra: float = 7.0  # Loudness range (LU)
+    loudnorm_target_tp: float = -1.0  # True peak (dBTP)
+    loudnorm_allow_lossless: bool = False  # Normalize lossless tracks (to FLAC)
     disable_track_statistics_tags: bool = False
     disable_header_compression: bool = True
     trim_audio_to_video_duration: bool = False
+    fix_colorimetry_flags: bool = False  # Normalize missing/wrong --colour-* flags
+    dovi_inject: bool = False  # Carry Source 1's Dolby Vision RPU (dovi_tool)
+    dovi_target_profile: int = 0  # 0 = keep source profile, 8 = convert to 8.1
+    subtitle_font_subsetting: bool = False  # Attach only used glyphs (fontTools)
+    fix_attachment_mime: bool = False  # Force font/image MIME types on attachments
+    enforce_track_flag_policy: bool = False  # Normalize default/forced flags
+    flag_policy_audio_lang: str = ""  # Preferred default audio ("" = first)
+    flag_policy_subtitle_lang: str = ""  # Preferred default/forced subtitle
+    # Make the first track in this language the default, whatever the
+    # source marked ("" = off); a default picked by hand in the layout wins
+    default_audio_lang: str = ""
+    default_subtitle_lang: str = ""
 
     # =========================================================================
     # Post-Mux Settings
     # =========================================================================
     post_mux_normalize_timestamps: bool = False
     post_mux_strip_tags: bool = False
+    # Copy across drives via <name>.part, renamed once complete (atomic_output.py)
+    atomic_output: bool = True
+    # Output filename template, e.g. "{title} - {episode} [Synced].mkv"
+    # ("" = Source 1's filename); see vsg_core/mux/output_name.py
+    output_template: str = ""
+    # Source 1's tags/title in the output; tracks are muxed from extracted
+    # streams, so "strip_all" is what earlier versions wrote
+    tag_policy: TagPolicyStr = "strip_all"
+    # Segment title template, same fields as output_template plus
+    # {source1_title} ("" = title follows tag_policy)
+    output_title: str = ""
+    # Same track UIDs on every run of a job (mkvmerge --deterministic)
+    deterministic_uids: bool = False
+    # Byte-identical output for identical inputs, settings and tool versions
+    # (implies deterministic_uids); see vsg_core/mux/reproducible.py
+    reproducible_output: bool = False
+    output_split_mode: OutputSplitModeStr = "none"
+    output_split_size_mb: int = 4000  # Max part size for "size"
+    output_split_duration_min: int = 60  # Part length for "duration"
+    output_split_chapters_every: int = 1  # New part every N chapters
+    verify_output: bool = False  # Probe the written file (VerifyStep)
+    checksum_output: bool = False  # SHA-256 of each output in the report
+    verify_delay_tolerance_ms: int = 1  # Allowed output vs planned delay gap
+    verify_delay_mismatch_action: VerifyMismatchActionStr = "warn"
+    command_retries: int = 0  # Retries of transient extract/merge tool failures
+    mkvmerge_options_file: MkvmergeOptionsFileStr = "always"
 
     # =========================================================================
     # Logging Settings
@@ -144,9 +257,28 @@ class AppSettings(BaseModel):
     log_progress_step: int = 20
     log_show_options_pretty: bool = False
     log_show_options_json: bool = False
+    log_json_lines: bool = False  # Also write {job}.jsonl structured log
     log_audio_drift: bool = True
     archive_logs: bool = True
 
+    # =========================================================================
+    # Batch Queue Settings
+    # =========================================================================
+    discovery_strategy: DiscoveryStrategyStr = "exact"
+    discovery_regex: str = ""  # Must capture the episode number in group 1
+    discovery_min_confidence: float = 0.8  # Pairings below this get flagged
+    persist_job_queue: bool = True  # Restore the queue + layouts on restart
+    # How reused/pasted layouts find their tracks in other files
+    layout_match_strategy: LayoutMatchStrategyStr = "id_then_position"
+    # Last choices of the manual selection's "Generate Layout" (auto_layout)
+    auto_layout_languages: str = ""  # Comma-separated, e.g. "jpn, eng"
+    auto_layout_audio: bool = True
+    auto_layout_subtitles: bool = True
+    # LayoutRuleSet.to_list(): fills the layout of jobs opened without one
+    layout_rules: list[dict[str, Any]] = []
+    batch_max_concurrent_jobs: int = 1  # 1 = run jobs one at a time
+    batch_stop_on_error: bool = False  # Skip remaining jobs after a failure
+
     # =========================================================================
     # Timing Sync Settings
     # =========================================================================
@@ -163,9 +295,24 @@ class AppSettings(BaseModel):
     # =========================================================================
     subtitle_sync_mode: SubtitleSyncModeStr = "time-based"
     time_based_use_raw_values: bool = False
+    # Bake the delay into events snapped to the target's frames, then mux
+    # with --sync 0 (operations/bake_shift.py); needs the target FPS
+    time_based_frame_accurate: bool = False
     time_based_bypass_subtitle_data: bool = True
     subtitle_rounding: SubtitleRoundingStr = "floor"
+    # Repair zero/negative durations and event order (operations/lint.py)
+    subtitle_lint_autofix: bool = False
     subtitle_target_fps: float = 0.0
+    # Framerate retime before sync (e.g. 25 -> 23.976); 0 = off
+    subtitle_retime_src_fps: float = 0.0
+    subtitle_retime_dst_fps: float = 0.0
+
+    # Linear-stretch anchors: the start anchor takes the analysed delay,
+    # the end anchor the configured one (times in source ms; both delays
+    # take the global shift). The end anchor must be set after the start.
+    linear_stretch_start_ms: float = 0.0
+    linear_stretch_end_ms: float = 0.0
+    linear_stretch_end_delay_ms: float = 0.0
 
     # =========================================================================
     # Video-Verified Sync Settings (sliding-window matcher)
@@ -178,6 +325,7 @@ class AppSettings(BaseModel):
     video_verified_backend: VideoVerifiedBackendStr = "isc"
     video_verified_cross_check_backend: VideoVerifiedCrossCheckBackendStr = "none"
     video_verified_cross_check_tolerance_frames: int = 0  # 0 = strict match
+    video_verified_interlace_detection: InterlaceDetectionStr = "metadata"
 
     # Sliding search geometry (shared across all backends)
     video_verified_window_seconds: int = 10
@@ -187,6 +335,8 @@ class AppSettings(BaseModel):
 
     # Backend-specific tunables
     video_verified_hash_size: int = 32  # pHash/dHash: 8/16/32/64 (1024-bit default)
+    # pHash/dHash: reuse a precomputed hash index of the whole Source 1 video
+    video_verified_hash_index: bool = False
     video_verified_ssim_input_size: int = 256  # SSIM: 128/256/384/512
 
     # Runtime
@@ -221,14 +371,22 @@ class AppSettings(BaseModel):
     correlation_method_source_separated: CorrelationMethodSourceSepStr = (
         "Phase Correlation (GCC-PHAT)"
     )
+    # Tried in order when the primary method accepts too few windows
+    fallback_methods: list[CorrelationMethodSourceSepStr] = ["Onset Detection"]
 
     # Delay Selection Settings
     delay_selection_mode: DelaySelectionModeStr = "Mode (Most Common)"
     delay_selection_mode_source_separated: DelaySelectionModeStr = "Mode (Clustered)"
     min_accepted_pct: float = 5.0
+    # Confidence gate: stop before mux when a delay is too unreliable (0 = off)
+    abort_below_confidence: float = 0.0  # Mean match % of accepted windows
+    abort_below_accepted_fraction: float = 0.0  # Accepted / total windows (0-1)
+    unreliable_analysis_action: UnreliableAnalysisActionStr = "needs_review"
     first_stable_early_pct: float = 15.0
     early_cluster_early_pct: float = 15.0
     early_cluster_min_presence_pct: float = 10.0
+    # How the selected delay is rounded to the whole ms mkvmerge applies
+    delay_rounding: DelayRoundingStr = "nearest"
 
     # Multi-Correlation Comparison
     multi_correlation_enabled: bool = False
@@ -238,6 +396,17 @@ class AppSettings(BaseModel):
     multi_corr_gcc_scot: bool = False
     multi_corr_gcc_whiten: bool = False
     multi_corr_spectrogram: bool = False
+    multi_corr_disagree_threshold_ms: float = 20.0  # Flag spread above this
+
+    # Multi-Track Consensus: correlate every same-language audio track of a
+    # source and combine the delays, weighted by confidence
+    multi_track_consensus: bool = False
+    multi_track_consensus_threshold_ms: float = 50.0  # Exclude tracks beyond this
+
+    # Spectrogram Correlation (resolution = hop / sample rate)
+    spectrogram_n_mels: int = 64
+    spectrogram_fft_size: int = 2048  # Power of two, 256-16384
+    spectrogram_hop_length: int = 512  # Samples, at most the FFT size
 
     # DSP & Filtering
     filter_bandpass_lowcut_hz: float = 300.0
@@ -246,9 +415,14 @@ class AppSettings(BaseModel):
     filter_lowpass_taps: int = 101
     scan_start_percentage: float = 0.0
     scan_end_percentage: float = 100.0
+    # Absolute scan range; each overrides its percentage when non-zero
+    scan_start_ms: int = 0
+    scan_end_ms: int = 0
     use_soxr: bool = False
     audio_decode_native: bool = False
     audio_peak_fit: bool = False
+    peak_interpolation: PeakInterpStr = "none"  # SCC: "none" defers to audio_peak_fit
+    peak_sinc_taps: int = 8  # Samples each side of the peak for sinc
     audio_bandlimit_hz: int = 0
 
     # Drift Detection Settings
@@ -343,6 +517,7 @@ class AppSettings(BaseModel):
     ocr_engine_vobsub: OcrEngineStr = "paddleocr-vl-native"
     ocr_engine_pgs: OcrEngineStr = "paddleocr-vl-native"
     ocr_language: str = "eng"
+    ocr_tesseract_path: str = "tesseract"  # Binary name or full path
     ocr_low_confidence_threshold: float = 60.0
     ocr_output_format: OcrOutputFormatStr = "ass"
 
@@ -368,6 +543,48 @@ class AppSettings(BaseModel):
     # =========================================================================
     PATH_SENTINEL: ClassVar[str] = _PATH_SENTINEL
 
+    # =========================================================================
+    # Enum-like string normalization
+    # =========================================================================
+    # The UI and older configs may hand back lowercased / re-punctuated
+    # spellings. Normalize the recognised ones; unknown strings pass through
+    # so Literal validation rejects them and the caller keeps the old value.
+    @field_validator("analysis_mode", mode="before")
+    @classmethod
+    def _parse_analysis_mode(cls, value: Any) -> Any:
+        return parse_analysis_mode(value) or value
+
+    @field_validator("correlation_method", mode="before")
+    @classmethod
+    def _parse_correlation_method(cls, value: Any) -> Any:
+        return parse_correlation_method(value) or value
+
+    @field_validator("fallback_methods", mode="before")
+    @classmethod
+    def _parse_fallback_methods(cls, value: Any) -> Any:
+        if isinstance(value, str):
+            value = [v for v in value.split(",") if v.strip()]
+        if isinstance(value, list):
+            return [parse_correlation_method(v) or v for v in value]
+        return value
+
+    @field_validator("analysis_exclude_chapter_patterns", mode="before")
+    @classmethod
+    def _parse_chapter_patterns(cls, value: Any) -> Any:
+        if isinstance(value, str):  # ";"-separated, e.g. from the command line
+            value = [v.strip() for v in value.split(";") if v.strip()]
+        for pattern in value if isinstance(value, list) else []:
+            try:
+                re.compile(pattern)
+            except re.error as e:
+                raise ValueError(f"Invalid chapter pattern {pattern!r}: {e}") from e
+        return value
+
+    @field_validator("sync_mode", mode="before")
+    @classmethod
+    def _parse_sync_mode(cls, value: Any) -> Any:
+        return parse_sync_mode(value) or value
+
     @classmethod
     def get_defaults(cls) -> dict[str, Any]:
         """Get all field defaults as a dictionary.
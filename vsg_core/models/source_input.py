@@ -0,0 +1,79 @@
+# vsg_core/models/source_input.py
+"""
+A job source given as one file or as an ordered list of parts.
+
+Old DVD rips and some broadcasts arrive split (part1.mkv, part2.mkv). In a
+job's ``sources`` dict a source is either a path or a list of paths; a
+list is joined into one file (mkvmerge append, see
+``vsg_core/extraction/concat.py``) before analysis, so everything after
+that sees a single file with continuous timestamps and chapters.
+
+Code that only needs a name or a file to scan for tracks uses the first
+part: the parts are required to have the same track layout.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from dataclasses import dataclass
+from pathlib import Path
+
+# Value of a job's ``sources`` entry: a path, or the paths of its parts
+SourceValue = str | list[str]
+
+# Separates the parts of a multi-part source typed into one text field
+PARTS_SEPARATOR = "|"
+
+
+@dataclass(frozen=True, slots=True)
+class SourceInput:
+    """One source: a single file or several parts in playback order."""
+
+    parts: tuple[str, ...]
+
+    @classmethod
+    def from_value(cls, value: str | Sequence[str]) -> SourceInput:
+        if isinstance(value, str):
+            return cls((value,))
+        parts = tuple(str(p) for p in value if p)
+        if not parts:
+            raise ValueError("A multi-part source needs at least one file.")
+        return cls(parts)
+
+    @property
+    def is_parts(self) -> bool:
+        return len(self.parts) > 1
+
+    @property
+    def primary(self) -> str:
+        """The only file, or the first part."""
+        return self.parts[0]
+
+    def to_value(self) -> SourceValue:
+        return list(self.parts) if self.is_parts else self.parts[0]
+
+    def describe(self) -> str:
+        name = Path(self.primary).name
+        if not self.is_parts:
+            return name
+        return f"{name} (+{len(self.parts) - 1} part(s))"
+
+
+def parse_source_text(text: str) -> SourceValue:
+    """A source typed as ``part1.mkv | part2.mkv`` (or a single path)."""
+    parts = [p.strip() for p in text.split(PARTS_SEPARATOR) if p.strip()]
+    if len(parts) > 1:
+        return parts
+    return parts[0] if parts else ""
+
+
+def primary_path(value: str | Sequence[str]) -> str:
+    """The file of a ``sources`` entry (first part of a multi-part source)."""
+    if not value:
+        return ""
+    return SourceInput.from_value(value).primary
+
+
+def primary_sources(sources: dict[str, SourceValue]) -> dict[str, str]:
+    """``sources`` with every multi-part entry reduced to its first part."""
+    return {key: primary_path(value) for key, value in sources.items()}
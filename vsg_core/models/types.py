@@ -20,6 +20,18 @@ AnalysisModeStr = Literal["Audio Correlation", "VideoDiff"]
 # Snap mode - determines how chapter timestamps snap to keyframes
 SnapModeStr = Literal["previous", "nearest"]
 
+# Job discovery - how files are paired across source folders
+#   exact      — identical filename (legacy behavior)
+#   normalized — compare after stripping [group], (year), 1080p, x264, ...
+#   regex      — user regex capturing an episode number
+DiscoveryStrategyStr = Literal["exact", "normalized", "regex"]
+
+# Layout reuse - how a saved layout's tracks are found in other files
+#   id_then_position — same track id, else the same type at the same position
+#   id               — same track id only
+#   position         — the n-th track of the type, whatever its id
+LayoutMatchStrategyStr = Literal["id_then_position", "id", "position"]
+
 # =========================================================================
 # Sync & Subtitle Settings
 # =========================================================================
@@ -27,6 +39,7 @@ SnapModeStr = Literal["previous", "nearest"]
 # How subtitle sync delay is calculated
 SubtitleSyncModeStr = Literal[
     "time-based",
+    "linear-stretch",
     "video-verified",
 ]
 
@@ -34,7 +47,7 @@ SubtitleSyncModeStr = Literal[
 SubtitleRoundingStr = Literal["floor", "round", "ceil"]
 
 # Sync timing direction
-SyncModeStr = Literal["positive_only", "allow_negative"]
+SyncModeStr = Literal["positive_only", "allow_negative", "pad_silence"]
 
 # =========================================================================
 # Video-Verified Sliding-Window Matcher
@@ -65,6 +78,10 @@ VideoVerifiedCrossCheckBackendStr = Literal[
     "ssim",
 ]
 
+# How video-verified decides a source is interlaced: "metadata" trusts the
+# container/codec flags; "auto" confirms with ffmpeg idet on sampled frames.
+InterlaceDetectionStr = Literal["metadata", "auto"]
+
 # =========================================================================
 # Audio Analysis Settings
 # =========================================================================
@@ -99,6 +116,48 @@ CorrelationMethodSourceSepStr = Literal[
     "Spectrogram Correlation",
 ]
 
+# Sub-sample peak interpolation for waveform correlation methods
+#   none      — integer-sample peak
+#   quadratic — parabola through the peak and its neighbours
+#   gaussian  — parabola through the log of those values (bell-shaped peaks)
+#   sinc      — windowed-sinc reconstruction around the peak
+PeakInterpStr = Literal["none", "quadratic", "gaussian", "sinc"]
+
+# How multichannel audio becomes the mono signal that is correlated
+#   mono_sum     — ffmpeg -ac 1 mix of all channels
+#   none         — first channel as decoded, no mixing
+#   left_only    — front left
+#   center_only  — front centre (dialogue on 5.1/7.1)
+DownmixModeStr = Literal["mono_sum", "none", "left_only", "center_only"]
+
+# Confidence score compared against min_match_pct (waveform methods)
+#   native            — each method's own score (SCC energy ratio, GCC PSR)
+#   normalized_peak   — peak / largest possible correlation value
+#   peak_to_sidelobe  — PSR: peak over the sidelobe mean, in sidelobe stds
+#   peak_to_mean      — peak / mean |correlation|
+ConfidenceMetricStr = Literal[
+    "native", "normalized_peak", "peak_to_sidelobe", "peak_to_mean"
+]
+
+# FFT path of the waveform correlation methods (fft_plan.py)
+#   real    — rfft/irfft over half the spectrum (fastest for real audio)
+#   complex — full fft/ifft, real part kept (about twice the work)
+FftModeStr = Literal["real", "complex"]
+
+# Where dense correlation windows go inside the scan range
+#   uniform   — every hop across the whole range
+#   endpoints — a few windows at the start and end only (quick drift slope)
+ChunkStrategyStr = Literal["uniform", "endpoints"]
+
+# Which windows keep their full correlation curve for plotting
+#   off            — peaks only
+#   representative — the accepted window closest to the median delay
+#   all            — every correlated window (representative one flagged)
+CorrelationCurveStr = Literal["off", "representative", "all"]
+
+# What to do when an analysis result falls below the confidence gate
+UnreliableAnalysisActionStr = Literal["needs_review", "fail"]
+
 # Delay selection strategy
 DelaySelectionModeStr = Literal[
     "Mode (Most Common)",
@@ -108,6 +167,12 @@ DelaySelectionModeStr = Literal[
     "Average",
 ]
 
+# Whole-ms rounding of final delays (see vsg_core/models/rounding.py)
+#   nearest        — halves to even: -0.5 -> 0, -1.5 -> -2 (Python round)
+#   toward_zero    — truncate: -1.7 -> -1
+#   away_from_zero — halves away from zero: -0.5 -> -1, +2.5 -> +3
+DelayRoundingStr = Literal["nearest", "toward_zero", "away_from_zero"]
+
 # =========================================================================
 # Stepping Correction Settings
 # =========================================================================
@@ -143,6 +208,30 @@ RubberbandTransientsStr = Literal["crisp", "mixed", "smooth"]
 # Outlier detection mode for sync stability
 SyncStabilityOutlierModeStr = Literal["any", "threshold"]
 
+# =========================================================================
+# Post-Mux Settings
+# =========================================================================
+
+# Output splitting (mkvmerge --split)
+OutputSplitModeStr = Literal["none", "size", "duration", "chapters"]
+
+# What of Source 1's container metadata the output keeps (mux/tags.py)
+#   keep               — global tags and segment title
+#   strip_all          — no tags, no title
+#   strip_except_title — no tags, segment title kept
+TagPolicyStr = Literal["keep", "strip_all", "strip_except_title"]
+
+# How mkvmerge receives its arguments
+#   always — JSON options file (mkvmerge @opts.json), never hits argv limits
+#   auto   — plain argv while it stays under the safe length, else the file
+MkvmergeOptionsFileStr = Literal["always", "auto"]
+
+# What to do when output delays don't match the plan (VerifyStep)
+VerifyMismatchActionStr = Literal["warn", "fail"]
+
+# Output format of subtitle-only sync ("keep" = same as the input)
+SubtitleOutputFormatStr = Literal["keep", "ass", "srt"]
+
 # =========================================================================
 # OCR Settings
 # =========================================================================
@@ -151,7 +240,10 @@ SyncStabilityOutlierModeStr = Literal["any", "threshold"]
 #   paddleocr-vl-native — official transformers runtime, frames as decoded
 #   paddleocr-vl-2x     — transformers + 2x Spotting upscale for DVD frames
 #   paddleocr-vl        — legacy llama.cpp/GGUF runtime
-OcrEngineStr = Literal["paddleocr-vl-native", "paddleocr-vl-2x", "paddleocr-vl"]
+#   tesseract           — external tesseract binary (CPU, no model download)
+OcrEngineStr = Literal[
+    "paddleocr-vl-native", "paddleocr-vl-2x", "paddleocr-vl", "tesseract"
+]
 
 # OCR output format
 OcrOutputFormatStr = Literal["ass", "srt"]
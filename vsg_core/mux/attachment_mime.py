@@ -0,0 +1,101 @@
+# vsg_core/mux/attachment_mime.py
+"""
+Attachment MIME-type correction.
+
+Releases often carry fonts tagged ``application/octet-stream``, and some
+players (VSFilter-based ones in particular) only load attachments whose
+MIME type says "font". ``fix_attachment_mime`` identifies fonts and images
+from their magic bytes rather than the extension (misnamed files are
+common) and returns a correction for every attachment whose declared type
+doesn't fit what the file is. Files that aren't recognized are left alone
+rather than guessed at.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Mapping
+    from pathlib import Path
+
+# (magic prefix, canonical MIME type). WebP is checked separately (RIFF).
+_SIGNATURES: tuple[tuple[bytes, str], ...] = (
+    (b"\x00\x01\x00\x00", "application/x-truetype-font"),  # TrueType
+    (b"true", "application/x-truetype-font"),  # Apple TrueType
+    (b"ttcf", "application/x-truetype-font"),  # TrueType/OpenType collection
+    (b"OTTO", "application/vnd.ms-opentype"),  # OpenType (CFF)
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+)
+
+# Declared types that already work for a kind of file; these are kept.
+_FONT_MIMES = frozenset(
+    {
+        "application/x-truetype-font",
+        "application/vnd.ms-opentype",
+        "application/x-font-ttf",
+        "application/x-font-otf",
+        "application/x-font",
+        "application/font-sfnt",
+        "font/ttf",
+        "font/otf",
+        "font/sfnt",
+        "font/collection",
+    }
+)
+_ACCEPTED = {
+    "application/x-truetype-font": _FONT_MIMES,
+    "application/vnd.ms-opentype": _FONT_MIMES,
+    "image/png": frozenset({"image/png"}),
+    "image/jpeg": frozenset({"image/jpeg", "image/jpg"}),
+    "image/gif": frozenset({"image/gif"}),
+    "image/webp": frozenset({"image/webp"}),
+}
+
+
+@dataclass(frozen=True, slots=True)
+class MimeCorrection:
+    """A MIME type to force on one attachment."""
+
+    path: Path
+    declared: str  # "" when the attachment had no declared type
+    mime: str
+
+    def describe(self) -> str:
+        return f"{self.path.name}: {self.declared or '(none)'} -> {self.mime}"
+
+
+def sniff_mime(path: Path) -> str | None:
+    """Canonical MIME type of a font or image file, or None if unknown."""
+    try:
+        with path.open("rb") as f:
+            head = f.read(12)
+    except OSError:
+        return None
+    if head[:4] == b"RIFF" and head[8:12] == b"WEBP":
+        return "image/webp"
+    for magic, mime in _SIGNATURES:
+        if head.startswith(magic):
+            return mime
+    return None
+
+
+def fix_attachment_mime(attachments: Mapping[Path, str]) -> list[MimeCorrection]:
+    """Corrections for ``attachments`` (path -> declared MIME type).
+
+    Attachments whose declared type already fits, or whose content isn't
+    recognized, get no correction.
+    """
+    corrections = []
+    for path, declared in attachments.items():
+        mime = sniff_mime(path)
+        if mime is None:
+            continue
+        if (declared or "").strip().lower() in _ACCEPTED[mime]:
+            continue
+        corrections.append(MimeCorrection(path=path, declared=declared, mime=mime))
+    return corrections
@@ -0,0 +1,183 @@
+# vsg_core/mux/color.py
+"""
+Colorimetry flag policy.
+
+Normalizes missing or wrong container colour flags so players don't show
+washed-out SDR or neon HDR: SDR is normalized to BT.709 / limited range and
+HDR10 to BT.2020 / PQ. Only mkvmerge ``--colour-*`` container flags are
+written, so the fix is lossless and the bitstream VUI is never touched.
+
+Values that are already valid for the detected content class are kept, so
+an SD source flagged SMPTE 170M is not "fixed" to BT.709.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Literal
+
+from vsg_core.extraction.color import ColorInfo
+
+if TYPE_CHECKING:
+    from vsg_core.models.settings import AppSettings
+
+ColorClass = Literal["sdr", "hdr10", "hlg"]
+
+# ffprobe name -> ISO/IEC 23091-2 code, as expected by mkvmerge
+PRIMARIES_CODES = {
+    "bt709": 1,
+    "bt470m": 4,
+    "bt470bg": 5,
+    "smpte170m": 6,
+    "smpte240m": 7,
+    "film": 8,
+    "bt2020": 9,
+}
+TRANSFER_CODES = {
+    "bt709": 1,
+    "bt470m": 4,
+    "bt470bg": 5,
+    "smpte170m": 6,
+    "smpte240m": 7,
+    "linear": 8,
+    "iec61966-2-1": 13,
+    "bt2020-10": 14,
+    "bt2020-12": 15,
+    "smpte2084": 16,
+    "arib-std-b67": 18,
+}
+MATRIX_CODES = {
+    "gbr": 0,
+    "bt709": 1,
+    "fcc": 4,
+    "bt470bg": 5,
+    "smpte170m": 6,
+    "smpte240m": 7,
+    "bt2020nc": 9,
+    "bt2020c": 10,
+}
+# mkvmerge --colour-range: 1 = broadcast (limited), 2 = full
+RANGE_CODES = {"tv": 1, "pc": 2}
+
+# field -> (mkvmerge option, code table)
+_FIELDS: dict[str, tuple[str, dict[str, int]]] = {
+    "primaries": ("colour-primaries", PRIMARIES_CODES),
+    "transfer": ("colour-transfer-characteristics", TRANSFER_CODES),
+    "matrix": ("colour-matrix-coefficients", MATRIX_CODES),
+    "range": ("colour-range", RANGE_CODES),
+}
+
+# BT.2020 SDR (wide gamut, non-PQ) is legitimate and kept as-is
+_SDR_PRIMARIES = {"bt709", "bt470m", "bt470bg", "smpte170m", "bt2020"}
+_SDR_TRANSFER = {
+    "bt709",
+    "bt470m",
+    "bt470bg",
+    "smpte170m",
+    "bt2020-10",
+    "bt2020-12",
+}
+_SDR_MATRIX = {"bt709", "bt470bg", "smpte170m", "fcc", "bt2020nc", "bt2020c"}
+_RANGES = {"tv", "pc"}
+
+# class -> field -> (accepted values, normalized value)
+_TARGETS: dict[ColorClass, dict[str, tuple[set[str], str]]] = {
+    "sdr": {
+        "primaries": (_SDR_PRIMARIES, "bt709"),
+        "transfer": (_SDR_TRANSFER, "bt709"),
+        "matrix": (_SDR_MATRIX, "bt709"),
+        "range": (_RANGES, "tv"),
+    },
+    "hdr10": {
+        "primaries": ({"bt2020"}, "bt2020"),
+        "transfer": ({"smpte2084"}, "smpte2084"),
+        "matrix": ({"bt2020nc", "bt2020c"}, "bt2020nc"),
+        "range": (_RANGES, "tv"),
+    },
+    "hlg": {
+        "primaries": ({"bt2020"}, "bt2020"),
+        "transfer": ({"arib-std-b67"}, "arib-std-b67"),
+        "matrix": ({"bt2020nc", "bt2020c"}, "bt2020nc"),
+        "range": (_RANGES, "tv"),
+    },
+}
+
+
+def classify(container: ColorInfo, bitstream: ColorInfo | None) -> ColorClass:
+    """Decide SDR / HDR10 / HLG, trusting the bitstream transfer first."""
+    transfers = [bitstream.transfer if bitstream else None, container.transfer]
+    for transfer in transfers:
+        if transfer == "smpte2084":
+            return "hdr10"
+        if transfer == "arib-std-b67":
+            return "hlg"
+        if transfer:
+            break
+    else:
+        # No transfer signalled anywhere: BT.2020 primaries imply HDR10
+        primaries = (bitstream.primaries if bitstream else None) or (
+            container.primaries
+        )
+        if primaries == "bt2020":
+            return "hdr10"
+    return "sdr"
+
+
+@dataclass(slots=True)
+class ColorFix:
+    """Result of applying the policy to one video track."""
+
+    color_class: ColorClass
+    before: ColorInfo
+    after: ColorInfo
+    # mkvmerge option (without "--") -> code, only for changed fields
+    overrides: dict[str, int] = field(default_factory=dict)
+    # Fields where container and bitstream both carry (different) values
+    disagreements: list[str] = field(default_factory=list)
+
+
+@dataclass(frozen=True, slots=True)
+class ColorPolicy:
+    """Container colour flag normalization (off by default)."""
+
+    enabled: bool = False
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> ColorPolicy:
+        return cls(enabled=settings.fix_colorimetry_flags)
+
+    def evaluate(self, container: ColorInfo, bitstream: ColorInfo | None) -> ColorFix:
+        """Compute the container flags to write.
+
+        A field is left alone when its container value is already valid for
+        the content class. A missing container value is filled from the
+        bitstream when that is valid, otherwise from the normalized target.
+        """
+        color_class = classify(container, bitstream)
+        targets = _TARGETS[color_class]
+        after: dict[str, str | None] = {}
+        overrides: dict[str, int] = {}
+        disagreements: list[str] = []
+
+        for name, (option, codes) in _FIELDS.items():
+            current = getattr(container, name)
+            from_stream = getattr(bitstream, name) if bitstream else None
+            if current and from_stream and current != from_stream:
+                disagreements.append(name)
+
+            accepted, normalized = targets[name]
+            if current in accepted:
+                after[name] = current
+                continue
+            value = from_stream if from_stream in accepted else normalized
+            after[name] = value
+            if value != current:
+                overrides[option] = codes[value]
+
+        return ColorFix(
+            color_class=color_class,
+            before=container,
+            after=ColorInfo(**after),
+            overrides=overrides,
+            disagreements=disagreements,
+        )
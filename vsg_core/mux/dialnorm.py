@@ -0,0 +1,142 @@
+# vsg_core/mux/dialnorm.py
+"""
+AC3/E-AC3 dialogue normalization.
+
+Every (E-)AC3 frame carries a ``dialnorm`` value: the dialogue level of the
+programme, -1 to -31 dBFS. Decoders attenuate playback by ``31 + dialnorm``
+dB so dialogue always lands at -31 dBFS, which makes such tracks quieter
+than other codecs. -31 is neutral (no attenuation).
+
+``read_dialnorm`` pulls the value out of the first audio packet (dumped by
+ffprobe, so any container works). ``dialnorm_gain_db`` gives the gain that
+brings dialogue to a chosen target once the attenuation is removed.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from ..io.runner import CommandRunner
+
+NEUTRAL_DIALNORM_DB = -31
+
+_SYNCWORD = b"\x0b\x77"
+_HEXDUMP_LINE = re.compile(r"^[0-9a-f]{8}:((?: [0-9a-f]{2,4})+)", re.IGNORECASE)
+
+
+class _Bits:
+    def __init__(self, data: bytes, byte_offset: int):
+        self._data = data
+        self._pos = byte_offset * 8
+
+    def read(self, n: int) -> int:
+        value = 0
+        for _ in range(n):
+            byte = self._data[self._pos >> 3]
+            value = (value << 1) | ((byte >> (7 - (self._pos & 7))) & 1)
+            self._pos += 1
+        return value
+
+
+def parse_dialnorm(frame: bytes) -> int | None:
+    """Dialnorm (dB, -1..-31) from the bit stream info of an (E-)AC3 frame.
+
+    Returns None if ``frame`` does not start with an AC3 syncword or is too
+    short to hold a complete header.
+    """
+    start = frame.find(_SYNCWORD)
+    if start < 0 or len(frame) - start < 8:
+        return None
+    bits = _Bits(frame, start + 2)
+    try:
+        # bsid sits at the same offset (bits 40-44) in both syntaxes
+        bsid = (frame[start + 5] >> 3) & 0x1F
+        if bsid <= 10:  # AC3
+            bits.read(16)  # crc1
+            bits.read(8)  # fscod, frmsizecod
+            bits.read(8)  # bsid, bsmod
+            acmod = bits.read(3)
+            if acmod & 1 and acmod != 1:
+                bits.read(2)  # cmixlev
+            if acmod & 4:
+                bits.read(2)  # surmixlev
+            if acmod == 2:
+                bits.read(2)  # dsurmod
+            bits.read(1)  # lfeon
+        elif bsid <= 16:  # E-AC3
+            bits.read(16)  # strmtyp, substreamid, frmsiz
+            bits.read(4)  # fscod + fscod2/numblkscod
+            bits.read(4)  # acmod, lfeon
+            bits.read(5)  # bsid
+        else:
+            return None
+        dialnorm = bits.read(5)
+    except IndexError:
+        return None
+    # 0 is reserved and must be treated as -31
+    return -(dialnorm or 31)
+
+
+def _packet_bytes(hexdump: str) -> bytes:
+    """Decode ffprobe's ``-show_data`` hexdump."""
+    out = bytearray()
+    for line in hexdump.splitlines():
+        m = _HEXDUMP_LINE.match(line.strip())
+        if m:
+            out += bytes.fromhex(m.group(1).replace(" ", ""))
+    return bytes(out)
+
+
+def read_dialnorm(
+    path: str | Path,
+    runner: CommandRunner,
+    tool_paths: dict,
+    stream_index: int | None = None,
+) -> int | None:
+    """Dialnorm (dB) of an AC3/E-AC3 stream (first audio stream by default).
+
+    Returns None for other codecs, or if the stream can't be read.
+    """
+    select = str(stream_index) if stream_index is not None else "a:0"
+    cmd = [
+        "ffprobe",
+        "-v",
+        "error",
+        "-select_streams",
+        select,
+        "-read_intervals",
+        "%+#1",
+        "-show_entries",
+        "stream=codec_name:packet=data",
+        "-show_data",
+        "-of",
+        "json",
+        str(path),
+    ]
+    out = runner.run(cmd, tool_paths)
+    if not out:
+        return None
+    try:
+        info = json.loads(out)
+    except json.JSONDecodeError:
+        return None
+    streams = info.get("streams") or [{}]
+    if streams[0].get("codec_name") not in ("ac3", "eac3"):
+        return None
+    packets = info.get("packets") or []
+    if not packets:
+        return None
+    return parse_dialnorm(_packet_bytes(packets[0].get("data", "")))
+
+
+def dialnorm_gain_db(dialnorm_db: int, target_db: int) -> int:
+    """Gain that moves dialogue from ``dialnorm_db`` to ``target_db``.
+
+    Applies once the decoder attenuation is gone (dialnorm set to neutral).
+    """
+    return target_db - dialnorm_db
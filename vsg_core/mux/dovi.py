@@ -0,0 +1,44 @@
+# vsg_core/mux/dovi.py
+"""
+Dolby Vision RPU handling configuration.
+
+The RPU (reference processing unit) carries the Dolby Vision dynamic
+metadata. It is read from Source 1 and injected into the output video
+track before muxing, which allows hybrid releases where the picture comes
+from one source and the DV metadata from another.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from vsg_core.models.settings import AppSettings
+
+# dovi_tool conversion modes, keyed by target profile.
+# Mode 2 converts any profile (5/7/8) RPU to Profile 8.1.
+DOVI_CONVERSION_MODES: dict[int, int] = {8: 2}
+
+
+@dataclass(frozen=True, slots=True)
+class DoviConfig:
+    """What to do with Source 1's Dolby Vision RPU."""
+
+    inject: bool = False
+    target_profile: int | None = None  # None = keep the source profile
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> DoviConfig:
+        target = settings.dovi_target_profile
+        return cls(
+            inject=settings.dovi_inject,
+            target_profile=target if target in DOVI_CONVERSION_MODES else None,
+        )
+
+    @property
+    def conversion_mode(self) -> int | None:
+        """dovi_tool ``-m`` value for the requested profile, if converting."""
+        if self.target_profile is None:
+            return None
+        return DOVI_CONVERSION_MODES.get(self.target_profile)
@@ -0,0 +1,114 @@
+# vsg_core/mux/encode.py
+"""
+Audio re-encode configuration.
+
+Tracks are copied losslessly by default. A PlanItem can instead carry an
+``EncodeSpec`` (e.g. DTS -> AC3 for a device, or a 5.1 -> 2.0 downmix), in
+which case ``AudioEncodeStep`` converts it with ffmpeg before mux.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Any
+
+
+@dataclass(frozen=True, slots=True)
+class AudioEncoder:
+    """An ffmpeg audio encoder we know how to drive."""
+
+    ffmpeg_name: str
+    max_channels: int
+    lossless: bool = False
+    default_bitrate_kbps: int | None = None
+
+
+# Codec name (as stored in layouts) -> ffmpeg encoder
+AUDIO_ENCODERS: dict[str, AudioEncoder] = {
+    "ac3": AudioEncoder("ac3", max_channels=6, default_bitrate_kbps=640),
+    "eac3": AudioEncoder("eac3", max_channels=8, default_bitrate_kbps=1024),
+    "aac": AudioEncoder("aac", max_channels=8, default_bitrate_kbps=256),
+    "opus": AudioEncoder("libopus", max_channels=8, default_bitrate_kbps=192),
+    "flac": AudioEncoder("flac", max_channels=8, lossless=True),
+}
+
+
+@dataclass(frozen=True, slots=True)
+class EncodeSpec:
+    """Target format for re-encoding one audio track."""
+
+    codec: str
+    bitrate_kbps: int | None = None  # None = encoder default (ignored if lossless)
+    channels: int | None = None  # None = keep source layout
+    gain_db: float = 0.0  # Volume change applied while encoding
+    # Extra ffmpeg audio filters, run after the gain (e.g. loudnorm). Set
+    # per job, never stored in layouts.
+    filters: tuple[str, ...] = ()
+
+    def __post_init__(self) -> None:
+        encoder = AUDIO_ENCODERS.get(self.codec)
+        if encoder is None:
+            raise ValueError(
+                f"Unsupported audio codec '{self.codec}' "
+                f"(supported: {', '.join(AUDIO_ENCODERS)})"
+            )
+        if self.channels is not None and not (
+            1 <= self.channels <= encoder.max_channels
+        ):
+            raise ValueError(
+                f"{self.codec} supports 1-{encoder.max_channels} channels, "
+                f"got {self.channels}"
+            )
+        if self.bitrate_kbps is not None and self.bitrate_kbps <= 0:
+            raise ValueError(f"Invalid bitrate: {self.bitrate_kbps} kbps")
+
+    @property
+    def encoder(self) -> AudioEncoder:
+        return AUDIO_ENCODERS[self.codec]
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> EncodeSpec:
+        """Build from a layout entry; raises ValueError if unsupported."""
+        bitrate = data.get("bitrate_kbps")
+        channels = data.get("channels")
+        return cls(
+            codec=str(data.get("codec", "")).lower(),
+            bitrate_kbps=int(bitrate) if bitrate else None,
+            channels=int(channels) if channels else None,
+            gain_db=float(data.get("gain_db") or 0.0),
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "codec": self.codec,
+            "bitrate_kbps": self.bitrate_kbps,
+            "channels": self.channels,
+            "gain_db": self.gain_db,
+        }
+
+    def ffmpeg_args(self) -> list[str]:
+        """Output codec options for ffmpeg."""
+        encoder = self.encoder
+        args = ["-c:a", encoder.ffmpeg_name]
+        bitrate = self.bitrate_kbps or encoder.default_bitrate_kbps
+        if bitrate and not encoder.lossless:
+            args += ["-b:a", f"{bitrate}k"]
+        if self.channels:
+            args += ["-ac", str(self.channels)]
+        chain = [f"volume={self.gain_db:g}dB"] if self.gain_db else []
+        chain += self.filters
+        if chain:
+            args += ["-af", ",".join(chain)]
+        return args
+
+    def describe(self) -> str:
+        parts = [self.codec.upper()]
+        bitrate = self.bitrate_kbps or self.encoder.default_bitrate_kbps
+        if bitrate and not self.encoder.lossless:
+            parts.append(f"{bitrate} kbps")
+        if self.channels:
+            parts.append(f"{self.channels} ch")
+        if self.gain_db:
+            parts.append(f"{self.gain_db:+g} dB")
+        parts += [f.split("=", 1)[0] for f in self.filters]
+        return ", ".join(parts)
@@ -0,0 +1,182 @@
+# vsg_core/mux/flags.py
+"""
+Default/forced track flag policy.
+
+Manual layouts set default/forced per track, which drifts across a batch.
+With the policy enabled the final plan is normalized to:
+
+- exactly one default audio track: the first in the preferred language,
+  falling back to the first audio track;
+- exactly one default subtitle track among the non-forced ones (preferred
+  language, then first);
+- at most one forced subtitle track: the first forced track in the
+  preferred language, falling back to the first forced track.
+
+Existing choices that already satisfy the rules are kept, and a default
+picked by hand in the layout (``manual_default``) beats the preferred
+language.
+
+``DefaultLanguage`` is the lighter, independent rule for batches whose
+sources mark defaults inconsistently: the first track in the preferred
+language becomes the default, overriding the source's flag, unless the
+user picked that type's default by hand in the layout.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from vsg_core.models.jobs import PlanItem
+    from vsg_core.models.settings import AppSettings
+
+
+def _lang(item: PlanItem) -> str:
+    return (item.custom_lang or item.track.props.lang or "und").lower()
+
+
+def _label(item: PlanItem) -> str:
+    tr = item.track
+    name = item.custom_name or tr.props.name
+    return f"{tr.source} {tr.type} track {tr.id} [{_lang(item)}]" + (
+        f" '{name}'" if name else ""
+    )
+
+
+@dataclass(frozen=True, slots=True)
+class FlagPolicy:
+    """Rules for the default and forced-display flags of the final tracks."""
+
+    enabled: bool = False
+    audio_lang: str = ""  # Preferred default audio language ("" = any)
+    subtitle_lang: str = ""  # Preferred default/forced subtitle language
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> FlagPolicy:
+        return cls(
+            enabled=settings.enforce_track_flag_policy,
+            audio_lang=settings.flag_policy_audio_lang.strip().lower(),
+            subtitle_lang=settings.flag_policy_subtitle_lang.strip().lower(),
+        )
+
+    def apply(self, items: list[PlanItem]) -> list[str]:
+        """Fix flag violations in place; returns one message per change."""
+        tracks = [it for it in items if not it.is_preserved]
+        changes: list[str] = []
+
+        def set_flag(item: PlanItem, attr: str, value: bool, why: str) -> None:
+            if getattr(item, attr) != value:
+                setattr(item, attr, value)
+                flag = "default" if attr == "is_default" else "forced"
+                action = "set" if value else "cleared"
+                changes.append(f"{_label(item)}: {action} {flag} ({why})")
+
+        # Preserved originals never carry flags
+        for item in items:
+            if item.is_preserved:
+                set_flag(item, "is_default", False, "preserved original")
+                set_flag(item, "is_forced_display", False, "preserved original")
+
+        audio = [it for it in tracks if it.track.type == "audio"]
+        subs = [it for it in tracks if it.track.type == "subtitles"]
+
+        # Forced: keep one, preferring the subtitle language
+        forced = [it for it in subs if it.is_forced_display]
+        keep_forced = self._pick(forced, self.subtitle_lang)
+        for item in forced:
+            if item is not keep_forced:
+                set_flag(item, "is_forced_display", False, "only one forced track")
+
+        # Forced subtitles are never default
+        for item in subs:
+            if item.is_forced_display:
+                set_flag(item, "is_default", False, "forced tracks are not default")
+
+        self._one_default(audio, self.audio_lang, set_flag)
+        regular = [it for it in subs if not it.is_forced_display]
+        self._one_default(regular, self.subtitle_lang, set_flag)
+        return changes
+
+    @staticmethod
+    def _pick(items: list[PlanItem], lang: str) -> PlanItem | None:
+        """First item in ``lang`` (if given), else the first item."""
+        if lang:
+            for item in items:
+                if _lang(item) == lang:
+                    return item
+        return items[0] if items else None
+
+    def _one_default(self, items: list[PlanItem], lang: str, set_flag) -> None:
+        if not items:
+            return
+        defaults = [it for it in items if it.is_default]
+        # Keep the user's default if it fits the language preference
+        keep = self._pick(defaults, lang) if defaults else None
+        manual = any(it.manual_default for it in items)
+        if keep is not None and lang and _lang(keep) != lang and not manual:
+            preferred = self._pick(items, lang)
+            if _lang(preferred) == lang:
+                keep = preferred
+        if keep is None:
+            keep = self._pick(items, lang)
+        for item in items:
+            if item is keep:
+                set_flag(item, "is_default", True, "policy default")
+            else:
+                set_flag(item, "is_default", False, "only one default")
+
+
+@dataclass(frozen=True, slots=True)
+class DefaultLanguage:
+    """Preferred language for the default audio/subtitle track."""
+
+    audio_lang: str = ""  # "" = leave audio defaults alone
+    subtitle_lang: str = ""  # "" = leave subtitle defaults alone
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> DefaultLanguage:
+        return cls(
+            audio_lang=settings.default_audio_lang.strip().lower(),
+            subtitle_lang=settings.default_subtitle_lang.strip().lower(),
+        )
+
+    @property
+    def enabled(self) -> bool:
+        return bool(self.audio_lang or self.subtitle_lang)
+
+    def apply(self, items: list[PlanItem]) -> tuple[list[str], list[str]]:
+        """Set the defaults in place; returns (changes, warnings)."""
+        changes: list[str] = []
+        warnings: list[str] = []
+        tracks = [it for it in items if not it.is_preserved]
+        preferences = (("audio", self.audio_lang), ("subtitles", self.subtitle_lang))
+        for kind, lang in preferences:
+            if not lang:
+                continue
+            # Forced subtitles never become the default
+            of_kind = [
+                it
+                for it in tracks
+                if it.track.type == kind
+                and not (kind == "subtitles" and it.is_forced_display)
+            ]
+            if not of_kind:
+                continue
+            if any(it.manual_default for it in of_kind):
+                continue
+            preferred = next((it for it in of_kind if _lang(it) == lang), None)
+            if preferred is None:
+                warnings.append(
+                    f"No {kind} track in '{lang}'; keeping the existing default."
+                )
+                continue
+            for item in of_kind:
+                value = item is preferred
+                if item.is_default != value:
+                    item.is_default = value
+                    action = "set" if value else "cleared"
+                    changes.append(
+                        f"{_label(item)}: {action} default (preferred '{lang}')"
+                    )
+        return changes, warnings
@@ -0,0 +1,211 @@
+# vsg_core/mux/loudnorm.py
+"""
+EBU R128 loudness normalization (ffmpeg ``loudnorm``, two-pass).
+
+Pass one runs ``loudnorm`` in analysis mode over the whole track and reads
+the integrated loudness, loudness range, true peak and threshold it prints
+as JSON. Pass two is the re-encode itself: ``LoudnormSpec.apply_filter``
+feeds those measurements back so ``loudnorm`` can use a single linear gain
+instead of its dynamic mode. loudnorm works at 192 kHz internally, so the
+chain resamples back to the source rate.
+
+Normalizing always re-encodes, so tracks in a lossless codec are refused
+unless the user opts in (they are then encoded to FLAC).
+"""
+
+from __future__ import annotations
+
+import json
+import math
+from dataclasses import astuple, dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from vsg_core.io.runner import CommandRunner
+    from vsg_core.models.settings import AppSettings
+
+# Matroska codec IDs that are always lossless. DTS is only lossless with
+# the DTS-HD MA extension, which needs a probe (``is_lossless``).
+_LOSSLESS_CODEC_IDS = ("A_TRUEHD", "A_MLP", "A_PCM", "A_FLAC", "A_ALAC", "A_WAVPACK")
+
+
+@dataclass(frozen=True, slots=True)
+class LoudnessMeasurement:
+    """Loudness of a track as reported by the loudnorm analysis pass."""
+
+    integrated_lufs: float
+    range_lu: float
+    true_peak_dbtp: float
+    threshold_lufs: float
+    target_offset_lu: float
+
+    def describe(self) -> str:
+        return (
+            f"I={self.integrated_lufs:.1f} LUFS, LRA={self.range_lu:.1f} LU, "
+            f"TP={self.true_peak_dbtp:.1f} dBTP"
+        )
+
+
+@dataclass(frozen=True, slots=True)
+class LoudnormSpec:
+    """Loudness targets for normalization."""
+
+    integrated_lufs: float = -23.0  # EBU R128
+    range_lu: float = 7.0
+    true_peak_dbtp: float = -1.0
+
+    def __post_init__(self) -> None:
+        if not -70.0 <= self.integrated_lufs <= -5.0:
+            raise ValueError(
+                f"Integrated loudness must be -70..-5 LUFS, got {self.integrated_lufs}"
+            )
+        if not 1.0 <= self.range_lu <= 50.0:
+            raise ValueError(f"Loudness range must be 1..50 LU, got {self.range_lu}")
+        if not -9.0 <= self.true_peak_dbtp <= 0.0:
+            raise ValueError(
+                f"True peak must be -9..0 dBTP, got {self.true_peak_dbtp}"
+            )
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> LoudnormSpec:
+        return cls(
+            integrated_lufs=settings.loudnorm_target_i,
+            range_lu=settings.loudnorm_target_lra,
+            true_peak_dbtp=settings.loudnorm_target_tp,
+        )
+
+    def _targets(self) -> str:
+        return (
+            f"I={self.integrated_lufs:g}:LRA={self.range_lu:g}"
+            f":TP={self.true_peak_dbtp:g}"
+        )
+
+    def measure_filter(self) -> str:
+        """Filter for the analysis pass."""
+        return f"loudnorm={self._targets()}:print_format=json"
+
+    def apply_filter(
+        self, measured: LoudnessMeasurement, sample_rate: int | None
+    ) -> str:
+        """Filter chain for the encode pass, using the analysis results."""
+        return (
+            f"loudnorm={self._targets()}"
+            f":measured_I={measured.integrated_lufs:.2f}"
+            f":measured_LRA={measured.range_lu:.2f}"
+            f":measured_TP={measured.true_peak_dbtp:.2f}"
+            f":measured_thresh={measured.threshold_lufs:.2f}"
+            f":offset={measured.target_offset_lu:.2f}"
+            ":linear=true:print_format=none"
+            f",aresample={sample_rate or 48000}"
+        )
+
+    def describe(self) -> str:
+        return (
+            f"I={self.integrated_lufs:g} LUFS, LRA={self.range_lu:g} LU, "
+            f"TP={self.true_peak_dbtp:g} dBTP"
+        )
+
+
+def _probe_stream(
+    path: str | Path, runner: CommandRunner, tool_paths: dict
+) -> dict[str, str]:
+    out = runner.run(
+        [
+            "ffprobe",
+            "-v",
+            "error",
+            "-select_streams",
+            "a:0",
+            "-show_entries",
+            "stream=sample_rate,profile",
+            "-of",
+            "json",
+            str(path),
+        ],
+        tool_paths,
+    )
+    if not out:
+        return {}
+    try:
+        streams = json.loads(out).get("streams") or [{}]
+    except json.JSONDecodeError:
+        return {}
+    return streams[0]
+
+
+def is_lossless(
+    codec_id: str, path: str | Path, runner: CommandRunner, tool_paths: dict
+) -> bool:
+    """Whether the audio in ``path`` (Matroska ``codec_id``) is lossless."""
+    cid = (codec_id or "").upper()
+    if cid.startswith(_LOSSLESS_CODEC_IDS):
+        return True
+    if cid.startswith("A_DTS"):
+        profile = _probe_stream(path, runner, tool_paths).get("profile", "")
+        return "MA" in profile.upper()  # "DTS-HD MA", "DTS-HD MA + DTS:X"
+    return False
+
+
+def probe_sample_rate(
+    path: str | Path, runner: CommandRunner, tool_paths: dict
+) -> int | None:
+    rate = _probe_stream(path, runner, tool_paths).get("sample_rate")
+    return int(rate) if rate and str(rate).isdigit() else None
+
+
+def measure_loudness(
+    path: str | Path,
+    spec: LoudnormSpec,
+    runner: CommandRunner,
+    tool_paths: dict,
+    *,
+    pre_filters: tuple[str, ...] = (),
+) -> LoudnessMeasurement | None:
+    """Run the loudnorm analysis pass over the first audio stream.
+
+    ``pre_filters`` run before the analysis, so the measurement matches the
+    encode pass when other filters (trim/pad) precede loudnorm there.
+
+    Returns None if ffmpeg fails or prints no usable measurement (e.g. a
+    silent track, which loudnorm reports as -inf).
+    """
+    out = runner.run(
+        [
+            "ffmpeg",
+            "-hide_banner",
+            "-nostats",
+            "-nostdin",
+            "-i",
+            str(path),
+            "-map",
+            "0:a:0",
+            "-af",
+            ",".join((*pre_filters, spec.measure_filter())),
+            "-f",
+            "null",
+            "-",
+        ],
+        tool_paths,
+    )
+    if not out:
+        return None
+    # The JSON block is the last thing ffmpeg prints (stderr is merged)
+    start, end = out.rfind("{"), out.rfind("}")
+    if start < 0 or end < start:
+        return None
+    try:
+        data = json.loads(out[start : end + 1])
+        measurement = LoudnessMeasurement(
+            integrated_lufs=float(data["input_i"]),
+            range_lu=float(data["input_lra"]),
+            true_peak_dbtp=float(data["input_tp"]),
+            threshold_lufs=float(data["input_thresh"]),
+            target_offset_lu=float(data["target_offset"]),
+        )
+    except (json.JSONDecodeError, KeyError, ValueError):
+        return None
+    if not all(math.isfinite(v) for v in astuple(measurement)):
+        return None
+    return measurement
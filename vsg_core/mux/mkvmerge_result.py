@@ -0,0 +1,70 @@
+# vsg_core/mux/mkvmerge_result.py
+"""
+mkvmerge exit codes and messages.
+
+mkvmerge exits 0 on success, 1 when it finished but printed warnings, and
+2 on errors. Exit 1 still produces a complete file: the warnings are about
+things mkvmerge noticed and usually fixed on its own (non-monotonic
+timestamps, a missing codec private block, an unknown chapter language),
+so the job succeeds and the warnings are reported with its result. Exit
+2, or any other code, fails the job with the error lines as the reason.
+
+Messages are recognised by their "Warning:" / "Error:" prefixes; a
+message continues on the following indented lines. With a translated
+mkvmerge UI the prefixes differ and no messages are itemised, but the exit
+code still decides success.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+
+MKVMERGE_OK = 0
+MKVMERGE_WARNINGS = 1
+MKVMERGE_ERROR = 2
+
+_MESSAGE = re.compile(r"^\s*(?:\[\d\d:\d\d:\d\d\]\s*)?(Warning|Error):\s*(.*)$")
+
+
+@dataclass(frozen=True, slots=True)
+class MkvmergeOutcome:
+    """Parsed result of one mkvmerge run."""
+
+    returncode: int | None  # None when mkvmerge could not be started
+    warnings: tuple[str, ...] = ()
+    errors: tuple[str, ...] = ()
+
+    @property
+    def ok(self) -> bool:
+        """True when the output file is usable (exit 0 or 1)."""
+        return self.returncode in (MKVMERGE_OK, MKVMERGE_WARNINGS)
+
+    def describe(self) -> str:
+        if self.returncode is None:
+            return "mkvmerge could not be started"
+        if self.ok:
+            if not self.warnings:
+                return "mkvmerge finished"
+            return f"mkvmerge finished with {len(self.warnings)} warning(s)"
+        reason = "; ".join(self.errors) or f"exit code {self.returncode}"
+        return f"mkvmerge failed: {reason}"
+
+
+def parse_mkvmerge_output(returncode: int | None, output: str) -> MkvmergeOutcome:
+    """Warnings and errors printed by mkvmerge, with its exit code."""
+    warnings: list[str] = []
+    errors: list[str] = []
+    current: list[str] | None = None
+    for line in output.splitlines():
+        match = _MESSAGE.match(line)
+        if match:
+            current = warnings if match.group(1) == "Warning" else errors
+            current.append(match.group(2).strip())
+        elif current is not None and line[:1].isspace() and line.strip():
+            current[-1] = f"{current[-1]} {line.strip()}"
+        else:
+            current = None
+    return MkvmergeOutcome(
+        returncode=returncode, warnings=tuple(warnings), errors=tuple(errors)
+    )
@@ -2,12 +2,63 @@
 from typing import TYPE_CHECKING, Optional
 
 from ..models.jobs import MergePlan, PlanItem
+from ..models.rounding import round_delay_ms
 from ..models.settings import AppSettings
+from .dialnorm import NEUTRAL_DIALNORM_DB
+from .sync_targets import resolve_sync_key
+from .track_names import suggest_track_name
 
 if TYPE_CHECKING:
     from ..audit import AuditTrail
 
 
+def calculate_track_delay(plan: MergePlan, item: PlanItem) -> int:
+    """Final ``--sync`` delay (ms) mkvmerge applies to ``item``."""
+    return MkvmergeOptionsBuilder()._effective_delay_ms(plan, item)
+
+
+def final_track_order(plan: MergePlan) -> list[PlanItem]:
+    """Plan items in output track order (preserved originals after their type)."""
+    # Separate final tracks from preserved original tracks
+    final_items = [item for item in plan.items if not item.is_preserved]
+    preserved_audio = [
+        item
+        for item in plan.items
+        if item.is_preserved and item.track.type == "audio"
+    ]
+    preserved_subs = [
+        item
+        for item in plan.items
+        if item.is_preserved and item.track.type == "subtitles"
+    ]
+
+    # Insert preserved audio tracks after the last main audio track
+    if preserved_audio:
+        last_audio_idx = -1
+        for i, item in enumerate(final_items):
+            if item.track.type == "audio":
+                last_audio_idx = i
+        # Correctly insert the list of preserved items
+        if last_audio_idx != -1:
+            final_items[last_audio_idx + 1 : last_audio_idx + 1] = preserved_audio
+        else:
+            final_items.extend(preserved_audio)
+
+    # Insert preserved subtitle tracks after the last main subtitle track
+    if preserved_subs:
+        last_sub_idx = -1
+        for i, item in enumerate(final_items):
+            if item.track.type == "subtitles":
+                last_sub_idx = i
+        # Correctly insert the list of preserved items
+        if last_sub_idx != -1:
+            final_items[last_sub_idx + 1 : last_sub_idx + 1] = preserved_subs
+        else:
+            final_items.extend(preserved_subs)
+
+    return final_items
+
+
 class MkvmergeOptionsBuilder:
     def build(
         self,
@@ -19,45 +70,18 @@ class MkvmergeOptionsBuilder:
 
         if plan.chapters_xml:
             tokens += ["--chapters", str(plan.chapters_xml)]
-        if settings.disable_track_statistics_tags:
+        if settings.disable_track_statistics_tags or plan.reproducible:
             tokens += ["--disable-track-statistics-tags"]
+        if plan.reproducible:
+            tokens += ["--no-date"]
+        if plan.uid_seed is not None:
+            tokens += ["--deterministic", str(plan.uid_seed)]
+        if plan.title:
+            tokens += ["--title", plan.title]
+        if plan.global_tags_xml:
+            tokens += ["--global-tags", str(plan.global_tags_xml)]
 
-        # Separate final tracks from preserved original tracks
-        final_items = [item for item in plan.items if not item.is_preserved]
-        preserved_audio = [
-            item
-            for item in plan.items
-            if item.is_preserved and item.track.type == "audio"
-        ]
-        preserved_subs = [
-            item
-            for item in plan.items
-            if item.is_preserved and item.track.type == "subtitles"
-        ]
-
-        # Insert preserved audio tracks after the last main audio track
-        if preserved_audio:
-            last_audio_idx = -1
-            for i, item in enumerate(final_items):
-                if item.track.type == "audio":
-                    last_audio_idx = i
-            # Correctly insert the list of preserved items
-            if last_audio_idx != -1:
-                final_items[last_audio_idx + 1 : last_audio_idx + 1] = preserved_audio
-            else:
-                final_items.extend(preserved_audio)
-
-        # Insert preserved subtitle tracks after the last main subtitle track
-        if preserved_subs:
-            last_sub_idx = -1
-            for i, item in enumerate(final_items):
-                if item.track.type == "subtitles":
-                    last_sub_idx = i
-            # Correctly insert the list of preserved items
-            if last_sub_idx != -1:
-                final_items[last_sub_idx + 1 : last_sub_idx + 1] = preserved_subs
-            else:
-                final_items.extend(preserved_subs)
+        final_items = final_track_order(plan)
 
         default_audio_idx = self._first_index(
             final_items, kind="audio", predicate=lambda it: it.is_default
@@ -79,15 +103,23 @@ class MkvmergeOptionsBuilder:
             delay_ms = self._effective_delay_ms(plan, item)
 
             # Record delay calculation in audit trail
-            sync_key = item.sync_to if tr.source == "External" else tr.source
+            sync_key = resolve_sync_key(tr.source, item.sync_to)
             stepping_adj = item.stepping_adjusted
             frame_adj = item.frame_adjusted
 
             # Determine reason for delay value
-            if tr.source == "Source 1" and tr.type == "video":
+            if item.is_reencoded:
+                reason = "is_reencoded=True (delay baked in by ffmpeg)"
+            elif tr.source == "Source 1" and tr.type == "video":
                 reason = "global_shift_only (video defines timeline)"
             elif tr.source == "Source 1" and tr.type == "audio":
-                reason = f"container_delay({round(item.container_delay_ms)}ms) + global_shift({plan.delays.global_shift_ms}ms)"
+                container_ms = round_delay_ms(
+                    item.container_delay_ms, plan.delay_rounding
+                )
+                reason = (
+                    f"container_delay({container_ms}ms) + "
+                    f"global_shift({plan.delays.global_shift_ms}ms)"
+                )
             elif tr.type == "subtitles" and stepping_adj:
                 reason = "stepping_adjusted=True (delay embedded in subtitle file)"
             elif tr.type == "subtitles" and frame_adj:
@@ -128,11 +160,14 @@ class MkvmergeOptionsBuilder:
 
             tokens += ["--language", f"0:{lang_code}"]
 
-            # NEW: Use custom name if set, otherwise fall back to apply_track_name behavior
+            # Custom name wins; apply_track_name keeps the source name, or
+            # generates one when the source track has none
             if item.custom_name:
                 tokens += ["--track-name", f"0:{item.custom_name}"]
-            elif item.apply_track_name and (tr.props.name or "").strip():
-                tokens += ["--track-name", f"0:{tr.props.name}"]
+            elif item.apply_track_name:
+                name = (tr.props.name or "").strip() or suggest_track_name(tr)
+                if name:
+                    tokens += ["--track-name", f"0:{name}"]
 
             tokens += ["--sync", f"0:{delay_ms:+d}"]
             tokens += ["--default-track-flag", f"0:{'yes' if is_default else 'no'}"]
@@ -143,24 +178,41 @@ class MkvmergeOptionsBuilder:
             if settings.disable_header_compression:
                 tokens += ["--compression", "0:none"]
 
-            if settings.apply_dialog_norm_gain and tr.type == "audio":
-                cid = (tr.props.codec_id or "").upper()
-                if "AC3" in cid or "EAC3" in cid:
-                    tokens += ["--remove-dialog-normalization-gain", "0"]
+            # Dialnorm is probed by AudioEncodeStep; re-encoded tracks are
+            # already written with a neutral value
+            if (
+                settings.apply_dialog_norm_gain
+                and tr.type == "audio"
+                and not item.is_reencoded
+                and item.dialnorm_db not in (None, NEUTRAL_DIALNORM_DB)
+            ):
+                tokens += ["--remove-dialog-normalization-gain", "0"]
 
             # NEW: Preserve original aspect ratio for video tracks
             if tr.type == "video" and item.aspect_ratio:
                 tokens += ["--aspect-ratio", f"0:{item.aspect_ratio}"]
 
+            if tr.type == "video":
+                for option, code in item.colour_overrides.items():
+                    tokens += [f"--{option}", f"0:{code}"]
+                if item.hdr10:
+                    for option, value in item.hdr10.mkvmerge_options():
+                        tokens += [f"--{option}", f"0:{value}"]
+
             if not item.extracted_path:
                 raise ValueError(
                     f"Plan item at index {i} ('{tr.props.name}') missing extracted_path"
                 )
 
+            if plan.strip_input_tags:
+                tokens += ["--no-global-tags", "--no-track-tags"]
             tokens += ["(", str(item.extracted_path), ")"]
             order_entries.append(f"{i}:0")
 
         for att in plan.attachments or []:
+            mime = plan.attachment_mime_types.get(att)
+            if mime:
+                tokens += ["--attachment-mime-type", mime]
             tokens += ["--attach-file", str(att)]
 
         if order_entries:
@@ -207,12 +259,18 @@ class MkvmergeOptionsBuilder:
         """
         tr = item.track
 
+        # Re-encoded audio: the delay was applied with -itsoffset when the
+        # track went through ffmpeg, so it must not be applied twice.
+        if item.is_reencoded:
+            return 0
+
         # Source 1 AUDIO: Preserve individual container delays + add global shift
         if tr.source == "Source 1" and tr.type == "audio":
-            # Use round() for proper rounding of negative values
-            # int() truncates toward zero: int(-1001.825) = -1001 (wrong)
-            # round() rounds to nearest: round(-1001.825) = -1002 (correct)
-            container_delay = round(item.container_delay_ms)
+            # Rounded per plan.delay_rounding; the default rounds to nearest
+            # so negatives don't truncate: -1001.825 -> -1002, not -1001
+            container_delay = round_delay_ms(
+                item.container_delay_ms, plan.delay_rounding
+            )
             global_shift = plan.delays.global_shift_ms
             final_delay = container_delay + global_shift
             return final_delay
@@ -231,7 +289,7 @@ class MkvmergeOptionsBuilder:
         # _swap_corrected_track) so the corrected track lands at the same
         # container timeline as Source 1's audio.
         if item.is_pre_aligned:
-            return round(item.container_delay_ms)
+            return round_delay_ms(item.container_delay_ms, plan.delay_rounding)
 
         # All other tracks: Use the correlation delay from analysis
         # This includes:
@@ -254,7 +312,7 @@ class MkvmergeOptionsBuilder:
         if tr.type == "subtitles" and item.frame_adjusted:
             return 0
 
-        sync_key = item.sync_to if tr.source == "External" else tr.source
+        sync_key = resolve_sync_key(tr.source, item.sync_to)
         if sync_key is None:
             return 0
 
@@ -262,9 +320,9 @@ class MkvmergeOptionsBuilder:
         # (e.g., from video-verified mode). These are separate from audio delays.
         if tr.type == "subtitles" and sync_key in plan.subtitle_delays_ms:
             delay = plan.subtitle_delays_ms[sync_key]
-            return round(delay)
+            return round_delay_ms(delay, plan.delay_rounding)
 
         # DEFAULT: Use correlation delay from analysis (for audio and subtitles)
         delay = plan.delays.source_delays_ms.get(sync_key, 0)
-        # Use round() for proper rounding of negative values (safety for future refactoring)
-        return round(delay)
+        # Source delays are whole ms already; rounding is a safety net
+        return round_delay_ms(delay, plan.delay_rounding)
@@ -0,0 +1,142 @@
+# vsg_core/mux/output_name.py
+"""
+Output filenames from a template.
+
+By default the output keeps Source 1's filename. With ``output_template``
+set, the name is rendered from job fields instead, e.g.
+"{title} - {episode} [{source1_group}] [Synced].mkv":
+
+    {title}          show title from Source 1's filename
+    {episode}        episode number, two digits ("05", "05-06")
+    {season}         season number, two digits ("" for absolute numbering)
+    {source1}        Source 1 filename without extension
+    {sourceN_group}  release group tag of Source N ("[Group] Show - 05")
+    {resolution}     video height of Source 1 ("1080p")
+
+Rendering only looks at filenames and the probed resolution, so the same
+job always gets the same name. Placeholders that are not known are kept
+as written and logged once per process. Known fields without a value
+render empty; brackets left empty by that are dropped. Characters that are
+not allowed in filenames are replaced, and ``claim_output_path`` appends
+" (2)", " (3)", ... when the name is already taken. A claim lasts until
+the job releases it with ``release_output_path``, finished or failed.
+"""
+
+from __future__ import annotations
+
+import re
+import threading
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from vsg_core.job_discovery import extract_episode, release_group, release_title
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+_PLACEHOLDER = re.compile(r"\{(\w+)\}")
+_ILLEGAL = re.compile(r'[<>:"/\\|?*\x00-\x1f]')
+_EMPTY_BRACKETS = re.compile(r"\[\s*\]|\(\s*\)")
+
+_lock = threading.Lock()
+_warned_placeholders: set[str] = set()
+_claimed_paths: set[str] = set()
+
+
+def output_name_fields(
+    sources: dict[str, str], resolution_height: int | None = None
+) -> dict[str, str]:
+    """Template fields for a job, from its source filenames."""
+    source1 = Path(sources.get("Source 1", "")).name
+    key = extract_episode(source1)
+    episode = ""
+    season = ""
+    if key is not None:
+        episode = f"{key.episode:02d}"
+        if key.end_episode is not None:
+            episode += f"-{key.end_episode:02d}"
+        if key.season is not None:
+            season = f"{key.season:02d}"
+
+    fields = {
+        "title": release_title(source1),
+        "episode": episode,
+        "season": season,
+        "source1": Path(source1).stem,
+        "resolution": f"{resolution_height}p" if resolution_height else "",
+    }
+    for role, path in sources.items():
+        number = role.removeprefix("Source ").strip()
+        if number.isdigit():
+            fields[f"source{number}_group"] = release_group(Path(path).name)
+    return fields
+
+
+def sanitize_filename(name: str) -> str:
+    """Replace characters that are not allowed in filenames."""
+    name = _ILLEGAL.sub("_", name)
+    name = _EMPTY_BRACKETS.sub("", name)
+    name = re.sub(r"\s{2,}", " ", name)
+    # Windows does not allow names ending in a dot or space
+    return name.strip().rstrip(". ")
+
+
+def fill_template(
+    template: str,
+    fields: dict[str, str],
+    log: Callable[[str], None] | None = None,
+    label: str = "Output Name",
+) -> str:
+    """``template`` with ``fields`` filled in, nothing else changed."""
+
+    def replace(match: re.Match[str]) -> str:
+        key = match.group(1)
+        if key in fields:
+            return fields[key]
+        # sourceN_group of a source the job doesn't have: known, but empty
+        if re.fullmatch(r"source\d+_group", key):
+            return ""
+        with _lock:
+            first = key not in _warned_placeholders
+            _warned_placeholders.add(key)
+        if first and log:
+            log(f"[{label}] Unknown placeholder {{{key}}} left as written.")
+        return match.group(0)
+
+    return _PLACEHOLDER.sub(replace, template)
+
+
+def render_output_name(
+    template: str,
+    fields: dict[str, str],
+    log: Callable[[str], None] | None = None,
+) -> str:
+    """Filename for ``template`` with ``fields`` filled in (always ``.mkv``)."""
+    name = sanitize_filename(fill_template(template, fields, log))
+    stem = name[: -len(".mkv")] if name.lower().endswith(".mkv") else name
+    stem = stem.rstrip(". ") or fields.get("source1") or "output"
+    return f"{stem}.mkv"
+
+
+def claim_output_path(output_dir: Path, name: str) -> Path:
+    """
+    ``output_dir / name``, with a numeric suffix if that name is taken.
+
+    Taken means an existing file or a name already claimed by another job
+    of this process, so concurrent jobs never end up on the same path.
+    """
+    stem, suffix = Path(name).stem, Path(name).suffix
+    candidate = output_dir / name
+    n = 2
+    with _lock:
+        while candidate.exists() or str(candidate).lower() in _claimed_paths:
+            candidate = output_dir / f"{stem} ({n}){suffix}"
+            n += 1
+        _claimed_paths.add(str(candidate).lower())
+    return candidate
+
+
+def release_output_path(path: Path) -> None:
+    """Drop the claim ``claim_output_path`` took on ``path``."""
+    with _lock:
+        _claimed_paths.discard(str(path).lower())
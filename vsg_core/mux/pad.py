@@ -0,0 +1,61 @@
+# vsg_core/mux/pad.py
+"""
+Silence padding for the ``pad_silence`` sync mode.
+
+In ``allow_negative`` mode a negative audio delay goes to mkvmerge as a
+negative ``--sync``: mkvmerge drops whole frames before zero and the track
+keeps a small, codec-dependent start offset, which some players handle
+badly. ``pad_silence`` instead realizes the delay in the samples: the
+audio before zero is trimmed exactly and silence is appended at the end so
+the track still runs for the whole video. The track is muxed with a
+container delay of 0 and no other track moves.
+
+Leading silence can only push audio later, so it is never part of a
+negative delay; it is only the tail that gets padded.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+
+@dataclass(frozen=True, slots=True)
+class SilencePad:
+    """How one audio track is trimmed and padded to realize its delay."""
+
+    trim_ms: int  # Audio dropped from the head (the negative delay)
+    silence_s: float  # Silence appended at the end
+    duration_s: float  # Duration of the result (= the video's)
+
+    def ffmpeg_filter(self) -> str:
+        """Filter chain that applies the trim and padding."""
+        return (
+            f"atrim=start={self.trim_ms / 1000:.3f},asetpts=PTS-STARTPTS,"
+            f"apad=whole_dur={self.duration_s:.3f},"
+            f"atrim=duration={self.duration_s:.3f}"
+        )
+
+    def describe(self) -> str:
+        return (
+            f"trimmed {self.trim_ms}ms from the start, "
+            f"padded {self.silence_s:.3f}s of silence at the end"
+        )
+
+
+def plan_silence_pad(
+    delay_ms: int, audio_duration_s: float, video_duration_s: float
+) -> SilencePad:
+    """Trim/padding that puts a track with ``delay_ms`` (< 0) at zero.
+
+    The result always lasts ``video_duration_s``: audio still playing after
+    the video ends is cut, a shortfall is filled with silence.
+    """
+    if delay_ms >= 0:
+        raise ValueError(f"Silence padding is for negative delays, got {delay_ms}ms")
+    trim_ms = -delay_ms
+    remaining_s = max(audio_duration_s - trim_ms / 1000, 0.0)
+    return SilencePad(
+        trim_ms=trim_ms,
+        silence_s=max(video_duration_s - remaining_s, 0.0),
+        duration_s=video_duration_s,
+    )
@@ -0,0 +1,45 @@
+# vsg_core/mux/reproducible.py
+"""
+Reproducible output (``reproducible_output``).
+
+With it on, the same inputs, settings and tool versions give a
+byte-identical output file. What changes between runs otherwise, and
+what is done about it:
+
+    UIDs (segment, tracks, chapters, attachments)
+        random per run; seeded with ``--deterministic`` (see uids.py)
+    Muxing date (segment info DateUTC)
+        time of the run; left out with ``--no-date``, as mkvmerge can't
+        write a chosen date
+    Statistics tags (BPS, DURATION, ...)
+        record the writing date and application; not written
+        (``--disable-track-statistics-tags``)
+    Post-merge timestamp rebasing
+        FFmpeg's Matroska muxer has its own UIDs and version tag;
+        ``-fflags +bitexact`` keeps them fixed
+
+Not controllable through mkvmerge:
+
+    MuxingApp/WritingApp ("libebml ... + libmatroska ...",
+    "mkvmerge v...") are always those of the running mkvmerge; there is no
+    option to set them (``--engage no_variable_data`` is a developer switch
+    for mkvmerge's own tests, not used). The same output therefore needs the
+    same MKVToolNix version, and the same FFmpeg version when rebasing.
+"""
+
+from __future__ import annotations
+
+CONTROLLED = (
+    "UIDs seeded (--deterministic)",
+    "muxing date left out (--no-date)",
+    "statistics tags off",
+)
+NOT_CONTROLLED = ("muxing/writing application (tool versions)",)
+
+
+def describe() -> list[str]:
+    """Log lines: what is fixed and what still depends on the tools."""
+    return [
+        "Fixed: " + ", ".join(CONTROLLED) + ".",
+        "Not fixed: " + ", ".join(NOT_CONTROLLED) + ".",
+    ]
@@ -0,0 +1,123 @@
+# vsg_core/mux/split.py
+"""
+Output splitting (mkvmerge ``--split``).
+
+The output can be written as several parts: by maximum size, by
+duration, or at chapter boundaries (a new part every N chapters).
+mkvmerge applies ``--sync`` delays before it cuts, and it rebases the
+chapters into each part, so delays and chapters stay correct across
+split points. Parts are named ``<name>-001.mkv``, ``<name>-002.mkv``, ...
+
+Chapter-based splitting needs chapters in the output, so
+``count_chapters`` is used to check the chapters file before mux.
+"""
+
+from __future__ import annotations
+
+import re
+import xml.etree.ElementTree as ET
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from vsg_core.models.settings import AppSettings
+    from vsg_core.models.types import OutputSplitModeStr
+
+
+def _local(tag: str) -> str:
+    return tag.rsplit("}", 1)[-1]
+
+
+def count_chapters(chapters_xml: str | Path | None) -> int:
+    """Top-level chapter atoms in the first edition (0 if none/unreadable)."""
+    if not chapters_xml or not Path(chapters_xml).exists():
+        return 0
+    try:
+        root = ET.parse(chapters_xml).getroot()
+    except ET.ParseError:
+        return 0
+    for edition in root.iter():
+        if _local(edition.tag) == "EditionEntry":
+            return sum(1 for el in edition if _local(el.tag) == "ChapterAtom")
+    return 0
+
+
+def _fmt_duration(ms: int) -> str:
+    h, rem = divmod(ms, 3_600_000)
+    m, rem = divmod(rem, 60_000)
+    s, ms = divmod(rem, 1000)
+    return f"{h:02d}:{m:02d}:{s:02d}.{ms:03d}"
+
+
+@dataclass(frozen=True, slots=True)
+class SplitSpec:
+    """How the output is divided into parts.
+
+    ``value`` is the part size in bytes ("size"), the part length in ms
+    ("duration") or the number of chapters per part ("chapters").
+    """
+
+    mode: OutputSplitModeStr = "none"
+    value: int = 0
+
+    def __post_init__(self) -> None:
+        if self.mode != "none" and self.value <= 0:
+            raise ValueError(f"Split {self.mode} needs a positive value")
+
+    @property
+    def enabled(self) -> bool:
+        return self.mode != "none"
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> SplitSpec:
+        mode = settings.output_split_mode
+        if mode == "size":
+            return cls(mode, settings.output_split_size_mb * 1024 * 1024)
+        if mode == "duration":
+            return cls(mode, settings.output_split_duration_min * 60_000)
+        if mode == "chapters":
+            return cls(mode, settings.output_split_chapters_every)
+        return cls()
+
+    def mkvmerge_args(self, chapter_count: int = 0) -> list[str]:
+        """``--split`` tokens for mkvmerge.
+
+        For chapter splitting ``chapter_count`` is the number of chapters
+        in the output; no tokens are produced if it doesn't reach a second
+        part.
+        """
+        if self.mode == "size":
+            return ["--split", f"size:{self.value}"]
+        if self.mode == "duration":
+            return ["--split", f"duration:{_fmt_duration(self.value)}"]
+        if self.mode == "chapters":
+            if self.value == 1:
+                return ["--split", "chapters:all"] if chapter_count > 1 else []
+            # mkvmerge splits *before* the listed (1-based) chapter numbers
+            starts = range(self.value + 1, chapter_count + 1, self.value)
+            if not starts:
+                return []
+            return ["--split", "chapters:" + ",".join(str(n) for n in starts)]
+        return []
+
+    def describe(self) -> str:
+        if self.mode == "size":
+            return f"every {self.value / (1024 * 1024):g} MiB"
+        if self.mode == "duration":
+            return f"every {_fmt_duration(self.value)}"
+        if self.mode == "chapters":
+            return f"every {self.value} chapter(s)"
+        return "off"
+
+
+def find_parts(output_path: Path) -> list[Path]:
+    """Files mkvmerge wrote for ``output_path`` (one if not split)."""
+    # No glob: release names often contain "[...]"
+    pattern = re.compile(
+        re.escape(output_path.stem) + r"-\d{3,}" + re.escape(output_path.suffix)
+    )
+    parts = sorted(
+        p for p in output_path.parent.iterdir() if pattern.fullmatch(p.name)
+    )
+    return parts or [output_path]
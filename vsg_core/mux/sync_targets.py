@@ -0,0 +1,76 @@
+# vsg_core/mux/sync_targets.py
+"""
+Resolution and validation of ``sync_to`` (the source an external track
+takes its delay from).
+
+An external subtitle has no timing of its own in the job: mux, subtitle
+sync and the auditors all give it the delay of the source named by its
+``sync_to``. A key that isn't one of the job's sources (a typo, or a
+layout saved for a job that had more sources) used to fall through to a
+delay of 0 without a word, so every reference is resolved up front and
+a bad one fails the job, listing the offending tracks.
+
+A track syncs to a source and a source's delay comes from analysis, so a
+reference either lands on a source in one step or is broken. The only
+loop that can be written down is a track syncing to its own kind
+("External", which has no delay); it is reported as a self-reference.
+Tracks of a real source always use their own source's delay and their
+``sync_to`` is ignored.
+"""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from ..errors import InvalidLayout
+
+if TYPE_CHECKING:
+    from collections.abc import Collection, Iterable, Mapping
+
+EXTERNAL = "External"
+
+
+def resolve_sync_key(source: str, sync_to: str | None) -> str | None:
+    """Source whose delay a track of ``source`` uses (None = no delay)."""
+    return sync_to if source == EXTERNAL else source
+
+
+def _track_label(item: Mapping[str, Any]) -> str:
+    name = Path(item.get("original_path") or "").name
+    label = f"{item.get('source', '?')} {item.get('type', 'track')} {item.get('id')}"
+    return f"{label} ({name})" if name else label
+
+
+def sync_target_problems(
+    layout: Iterable[Mapping[str, Any]], source_keys: Collection[str]
+) -> list[str]:
+    """One message per layout track whose ``sync_to`` doesn't resolve."""
+    problems = []
+    for item in layout:
+        if item.get("source") != EXTERNAL or not item.get("sync_to"):
+            continue
+        target = item["sync_to"]
+        if target == EXTERNAL:
+            problems.append(
+                f"{_track_label(item)} syncs to itself ('{EXTERNAL}' has no delay)"
+            )
+        elif target not in source_keys:
+            problems.append(
+                f"{_track_label(item)} syncs to '{target}', which is not a source "
+                f"of this job"
+            )
+    return problems
+
+
+def check_sync_targets(
+    layout: Iterable[Mapping[str, Any]], source_keys: Collection[str]
+) -> None:
+    """Raise InvalidLayout listing every track whose ``sync_to`` doesn't resolve."""
+    problems = sync_target_problems(layout, source_keys)
+    if problems:
+        available = ", ".join(sorted(source_keys)) or "none"
+        raise InvalidLayout(
+            "Invalid 'Sync to Source' in the layout (job sources: "
+            f"{available}):\n  " + "\n  ".join(problems)
+        )
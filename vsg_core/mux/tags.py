@@ -0,0 +1,170 @@
+# vsg_core/mux/tags.py
+"""
+Tags and segment title of the output (``tag_policy``, ``output_title``).
+
+Tracks are muxed from extracted streams, so nothing of Source 1's
+container metadata reaches the output unless it is carried over:
+
+    keep               — Source 1's global tags (``--global-tags``) and its
+                         segment title (``--title``)
+    strip_all          — no tags and no title from any input
+    strip_except_title — no tags, but Source 1's title is kept
+
+The strip policies also pass ``--no-global-tags --no-track-tags`` for every
+input, so Matroska inputs never bring their own tags along. Track tags of
+Source 1 are never carried: they target the UIDs of Source 1's tracks,
+which the output's tracks don't have. Statistics tags written by mkvmerge
+are a separate setting (``disable_track_statistics_tags``).
+
+``output_title`` sets the title whatever the policy. It is a template with
+the fields of ``output_template`` (see output_name.py) plus
+``{source1_title}``, Source 1's own segment title.
+"""
+
+from __future__ import annotations
+
+import re
+import xml.etree.ElementTree as ET
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING
+
+from vsg_core.mux.output_name import fill_template
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from vsg_core.models.settings import AppSettings
+    from vsg_core.models.types import TagPolicyStr
+
+_EMPTY_BRACKETS = re.compile(r"\[\s*\]|\(\s*\)")
+# Targets that tie a tag to one track/edition/chapter/attachment
+_ELEMENT_TARGETS = {"TrackUID", "EditionUID", "ChapterUID", "AttachmentUID"}
+
+
+def _local(tag: str) -> str:
+    return tag.rsplit("}", 1)[-1]
+
+
+@dataclass(frozen=True, slots=True)
+class SourceTags:
+    """What Source 1 has: its segment title and its tags."""
+
+    title: str = ""
+    global_tag_names: tuple[str, ...] = ()
+    track_tag_count: int = 0
+    # <Tags> XML with only the global tags (None if there are none)
+    global_tags_xml: str | None = field(default=None, repr=False)
+
+    @classmethod
+    def parse(cls, title: str, tags_xml: str | None) -> SourceTags:
+        """From the segment title and ``mkvextract <file> tags`` output."""
+        if not tags_xml or not tags_xml.strip():
+            return cls(title)
+        try:
+            root = ET.fromstring(tags_xml)
+        except ET.ParseError:
+            return cls(title)
+
+        names: list[str] = []
+        track_tags = 0
+        for tag in list(root):
+            if _local(tag.tag) != "Tag":
+                continue
+            targets = [el for el in tag if _local(el.tag) == "Targets"]
+            if any(
+                _local(el.tag) in _ELEMENT_TARGETS
+                for target in targets
+                for el in target
+            ):
+                track_tags += 1
+                root.remove(tag)
+                continue
+            for simple in tag.iter():
+                if _local(simple.tag) != "SimpleTag":
+                    continue
+                for el in simple:
+                    if _local(el.tag) == "Name" and el.text:
+                        names.append(el.text.strip())
+
+        has_global = any(_local(tag.tag) == "Tag" for tag in root)
+        xml = ET.tostring(root, encoding="unicode") if has_global else None
+        return cls(title, tuple(names), track_tags, xml)
+
+    def describe(self) -> str:
+        parts = [f"title {self.title!r}" if self.title else "no title"]
+        if self.global_tag_names:
+            shown = ", ".join(self.global_tag_names[:5])
+            if len(self.global_tag_names) > 5:
+                shown += ", ..."
+            parts.append(f"{len(self.global_tag_names)} global tag(s) ({shown})")
+        else:
+            parts.append("no global tags")
+        if self.track_tag_count:
+            parts.append(f"{self.track_tag_count} track tag(s)")
+        return ", ".join(parts)
+
+
+def render_title(
+    template: str,
+    fields: dict[str, str],
+    log: Callable[[str], None] | None = None,
+) -> str:
+    """Segment title for ``template``; brackets left empty are dropped."""
+    title = fill_template(template, fields, log, label="Tags")
+    title = _EMPTY_BRACKETS.sub("", title)
+    return re.sub(r"\s{2,}", " ", title).strip()
+
+
+@dataclass(frozen=True, slots=True)
+class TagPolicy:
+    """What of Source 1's metadata the output keeps, and its title."""
+
+    mode: TagPolicyStr = "strip_all"
+    title_template: str = ""  # output_title ("" = title follows the policy)
+
+    @classmethod
+    def from_settings(cls, settings: AppSettings) -> TagPolicy:
+        return cls(settings.tag_policy, settings.output_title.strip())
+
+    @property
+    def keeps_tags(self) -> bool:
+        return self.mode == "keep"
+
+    @property
+    def keeps_title(self) -> bool:
+        return self.mode != "strip_all"
+
+    def title(
+        self,
+        source: SourceTags,
+        fields: dict[str, str],
+        log: Callable[[str], None] | None = None,
+    ) -> str | None:
+        """The output's segment title (None = no title)."""
+        if self.title_template:
+            fields = {**fields, "source1_title": source.title}
+            return render_title(self.title_template, fields, log) or None
+        if self.keeps_title:
+            return source.title or None
+        return None
+
+    def describe(self, source: SourceTags) -> list[str]:
+        """What is kept and stripped, one line each."""
+        lines = [f"Source 1 has {source.describe()}."]
+        if self.keeps_tags:
+            if source.global_tag_names:
+                lines.append("Keeping Source 1's global tags.")
+            if source.track_tag_count:
+                lines.append(
+                    "Track tags are not carried (tracks are muxed from "
+                    "extracted streams)."
+                )
+        else:
+            lines.append("Stripping tags from all inputs.")
+        if self.title_template:
+            lines.append("Title set by output_title.")
+        elif self.keeps_title:
+            lines.append("Keeping Source 1's title.")
+        elif source.title:
+            lines.append("Stripping the title.")
+        return lines
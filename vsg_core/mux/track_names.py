@@ -0,0 +1,141 @@
+# vsg_core/mux/track_names.py
+"""
+Generated track names.
+
+Tracks with an empty name get one built from their metadata when
+``apply_track_name`` is on, e.g. "English DTS-HD MA 5.1" or "Japanese
+ASS". A custom name from the UI always wins. The codec and channel maps
+are plain dicts so callers can override single entries.
+"""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from vsg_core.models.media import Track
+
+# ISO 639-2 (as stored by Matroska) and common 639-1 codes -> English name
+LANGUAGE_NAMES: dict[str, str] = {
+    "eng": "English",
+    "en": "English",
+    "jpn": "Japanese",
+    "ja": "Japanese",
+    "ger": "German",
+    "deu": "German",
+    "de": "German",
+    "fre": "French",
+    "fra": "French",
+    "fr": "French",
+    "spa": "Spanish",
+    "es": "Spanish",
+    "ita": "Italian",
+    "it": "Italian",
+    "por": "Portuguese",
+    "pt": "Portuguese",
+    "rus": "Russian",
+    "ru": "Russian",
+    "chi": "Chinese",
+    "zho": "Chinese",
+    "zh": "Chinese",
+    "kor": "Korean",
+    "ko": "Korean",
+    "ara": "Arabic",
+    "ar": "Arabic",
+    "hin": "Hindi",
+    "hi": "Hindi",
+    "pol": "Polish",
+    "pl": "Polish",
+    "dut": "Dutch",
+    "nld": "Dutch",
+    "nl": "Dutch",
+    "swe": "Swedish",
+    "sv": "Swedish",
+    "tur": "Turkish",
+    "tr": "Turkish",
+    "tha": "Thai",
+    "th": "Thai",
+    "vie": "Vietnamese",
+    "vi": "Vietnamese",
+    "ind": "Indonesian",
+    "id": "Indonesian",
+}
+
+# Matroska codec id -> short name used in generated track names
+CODEC_NAMES: dict[str, str] = {
+    "A_AC3": "AC-3",
+    "A_EAC3": "E-AC-3",
+    "A_DTS": "DTS",
+    "A_DTS/EXPRESS": "DTS Express",
+    "A_DTS/LOSSLESS": "DTS-HD MA",
+    "A_TRUEHD": "TrueHD",
+    "A_MLP": "MLP",
+    "A_FLAC": "FLAC",
+    "A_AAC": "AAC",
+    "A_OPUS": "Opus",
+    "A_VORBIS": "Vorbis",
+    "A_MPEG/L3": "MP3",
+    "A_MPEG/L2": "MP2",
+    "A_PCM/INT/LIT": "PCM",
+    "A_PCM/INT/BIG": "PCM",
+    "A_PCM/FLOAT/IEEE": "PCM",
+    "S_TEXT/ASS": "ASS",
+    "S_TEXT/SSA": "SSA",
+    "S_TEXT/UTF8": "SRT",
+    "S_TEXT/WEBVTT": "WebVTT",
+    "S_HDMV/PGS": "PGS",
+    "S_VOBSUB": "VobSub",
+    "V_MPEGH/ISO/HEVC": "HEVC",
+    "V_MPEG4/ISO/AVC": "AVC",
+    "V_AV1": "AV1",
+    "V_VP9": "VP9",
+}
+
+# Channel count -> layout
+CHANNEL_LAYOUTS: dict[int, str] = {
+    1: "Mono",
+    2: "Stereo",
+    3: "2.1",
+    6: "5.1",
+    7: "6.1",
+    8: "7.1",
+}
+
+
+def language_name(code: str, names: dict[str, str] | None = None) -> str:
+    """English display name of a language code ("" for und/unknown)."""
+    code = (code or "").strip().lower()
+    if not code or code in ("und", "zxx", "mis", "mul"):
+        return ""
+    table = LANGUAGE_NAMES if names is None else {**LANGUAGE_NAMES, **names}
+    # BCP 47 tags ("pt-BR") fall back to their primary language
+    return table.get(code) or table.get(code.split("-")[0], code)
+
+
+def suggest_track_name(
+    track: Track,
+    codec_names: dict[str, str] | None = None,
+    channel_layouts: dict[int, str] | None = None,
+    language_names: dict[str, str] | None = None,
+) -> str:
+    """Name like "English DTS-HD MA 5.1" from a track's metadata.
+
+    Parts that are unknown are left out, so an undetermined-language track
+    with an unmapped codec may yield an empty string.
+    """
+    codecs = CODEC_NAMES if codec_names is None else {**CODEC_NAMES, **codec_names}
+    layouts = (
+        CHANNEL_LAYOUTS
+        if channel_layouts is None
+        else {**CHANNEL_LAYOUTS, **channel_layouts}
+    )
+    props = track.props
+    codec_id = (props.codec_id or "").upper()
+    codec = codecs.get(codec_id, "")
+    if not codec and codec_id.startswith("A_PCM"):
+        codec = "PCM"
+
+    parts = [language_name(props.lang, language_names), codec]
+    if track.type == "audio" and props.audio_channels:
+        parts.append(layouts.get(props.audio_channels, f"{props.audio_channels}ch"))
+    return " ".join(p for p in parts if p)
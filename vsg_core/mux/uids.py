@@ -0,0 +1,43 @@
+# vsg_core/mux/uids.py
+"""
+Deterministic track UIDs (``deterministic_uids``).
+
+mkvmerge gives every track (and the segment, chapters and attachments) a
+random UID, so muxing the same job twice gives different UIDs. mkvmerge has
+no option to set a track's UID directly; ``--deterministic <seed>`` instead
+seeds the generator it draws all UIDs from. With the same mkvmerge version,
+inputs and options, the same seed gives the same UIDs.
+
+The seed is a hash of each output track's source filename, track id and
+type, in output order. Re-running a job gives the same seed and so the
+same UIDs, while a different file or layout gives different ones. Only
+filenames are hashed, not folders, so moving the sources doesn't change
+the UIDs.
+"""
+
+from __future__ import annotations
+
+import hashlib
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from vsg_core.models.jobs import PlanItem
+
+
+def track_key(item: PlanItem, sources: dict[str, str]) -> str:
+    """Identity of an output track: "<source filename>:<track id>:<type>"."""
+    track = item.track
+    name = Path(sources.get(track.source, track.source)).name
+    return f"{name}:{track.id}:{track.type}"
+
+
+def uid_seed(items: Sequence[PlanItem], sources: dict[str, str]) -> int:
+    """``--deterministic`` seed for these tracks (31-bit, never 0)."""
+    digest = hashlib.sha256()
+    for item in items:
+        digest.update(track_key(item, sources).encode("utf-8"))
+        digest.update(b"\0")
+    return int.from_bytes(digest.digest()[:4], "big") % (2**31 - 1) + 1
@@ -5,23 +5,27 @@ Enhanced pipeline with comprehensive validation at each step.
 
 from __future__ import annotations
 
-import time
 from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
+from vsg_core.analysis.reliability import AnalysisNeedsReview
 from vsg_core.audit import AuditTrail
 from vsg_core.io.runner import CommandRunner
 from vsg_core.orchestrator.steps import (
     AnalysisStep,
     AttachmentsStep,
     AudioCorrectionStep,
+    AudioEncodeStep,
     ChaptersStep,
     Context,
+    DoviStep,
     ExtractStep,
     MuxStep,
+    NormalizeStep,
     SubtitlesStep,
 )
 from vsg_core.orchestrator.validation import PipelineValidationError, StepValidator
+from vsg_core.orchestrator.work_dir import WorkDir
 
 if TYPE_CHECKING:
     from collections.abc import Callable
@@ -49,8 +53,11 @@ class Orchestrator:
         manual_layout: list[ManualLayoutItem],
         attachment_sources: list[str],
         source_settings: dict[str, dict[str, Any]] | None = None,
+        attachment_selection: dict[str, list[str]] | None = None,
         chapter_source: str = "Source 1",
         debug_paths=None,
+        temp_dir: Path | None = None,
+        source_inputs: dict[str, str] | None = None,
     ) -> Context:
         """
         Executes the pipeline steps with validation.
@@ -62,16 +69,25 @@ class Orchestrator:
                 to "Source 1" (existing behavior). Other source keys pull
                 chapters from a donor file. "None" suppresses chapters.
             debug_paths: DebugOutputPaths for this job
+            temp_dir: Work directory created by the caller (so it can clean
+                it up, or keep it, even if the run fails); a fresh one under
+                ``settings.temp_root`` when None
+            source_inputs: The user's files per source when ``sources``
+                points at joined multi-part files; names the job, its
+                output and sidecar (``sources`` when None)
         """
         source1_file = sources.get("Source 1")
         if not source1_file:
             raise ValueError("Job is missing Source 1 (Reference).")
+        source_inputs = dict(source_inputs or sources)
+        source1_input = source_inputs.get("Source 1") or source1_file
 
-        base_temp = (
-            Path(settings.temp_root) if settings.temp_root else Path.cwd() / "temp_work"
+        work_dir = (
+            WorkDir(temp_dir).ensure()
+            if temp_dir is not None
+            else WorkDir.create(settings.temp_root, source1_input)
         )
-        job_temp = base_temp / f"orch_{Path(source1_file).stem}_{int(time.time())}"
-        job_temp.mkdir(parents=True, exist_ok=True)
+        job_temp = work_dir.root
 
         # Cleanup old style editor temp files from previous sessions
         from vsg_core.config import cleanup_style_editor_temp_files
@@ -83,12 +99,12 @@ class Orchestrator:
         runner = CommandRunner(settings, log)
 
         # Create audit trail for debugging timing issues
-        job_name = Path(source1_file).stem
-        audit = AuditTrail(job_temp, job_name)
+        job_name = Path(source1_input).stem
+        audit = AuditTrail(work_dir.logs, job_name)
         log(f"[Audit] Trail created: {audit.get_path()}")
 
         # Record sources in audit
-        for src_key, src_path in sources.items():
+        for src_key, src_path in source_inputs.items():
             audit.record_source(src_key, src_path)
         audit.append_event(
             "milestone",
@@ -106,15 +122,30 @@ class Orchestrator:
             audit=audit,
             debug_paths=debug_paths,
             sources=sources,
+            source_inputs=source_inputs,
             and_merge=bool(and_merge),
             manual_layout=manual_layout or [],
             attachment_sources=attachment_sources,
+            attachment_selection=attachment_selection or {},
             source_settings=source_settings or {},
             chapter_source=chapter_source or "Source 1",
         )
 
+        from vsg_core.pipeline_components.progress_tracker import ProgressSlice
+
+        if settings.normalize_to_mkv:
+            log("--- Normalize Phase ---")
+            try:
+                ctx = NormalizeStep().run(ctx, runner)
+            except Exception as e:
+                log(f"[FATAL] Normalize phase failed: {e}")
+                raise RuntimeError(f"Normalize phase failed: {e}") from e
+
         log("--- Analysis Phase ---")
         progress(0.10)
+        ctx.analysis_progress = ProgressSlice(
+            progress, 0.10, 0.40 if and_merge else 1.0
+        )
         try:
             ctx = AnalysisStep().run(ctx, runner)
             StepValidator.validate_analysis(ctx)
@@ -122,6 +153,8 @@ class Orchestrator:
         except PipelineValidationError as e:
             log(f"[FATAL] Analysis validation failed: {e}")
             raise
+        except AnalysisNeedsReview:
+            raise
         except Exception as e:
             log(f"[FATAL] Analysis phase failed: {e}")
             raise RuntimeError(f"Analysis phase failed: {e}") from e
@@ -198,6 +231,30 @@ class Orchestrator:
             except Exception as e:
                 log(f"[WARNING] Audio trim phase had issues (non-fatal): {e}")
 
+        needs_encode = (
+            ctx.settings.apply_dialog_norm_gain
+            or ctx.settings.sync_mode == "pad_silence"
+            or any(
+                item.encode or (ctx.settings.loudnorm_enabled and item.loudnorm)
+                for item in ctx.extracted_items or []
+            )
+        )
+        if needs_encode:
+            log("--- Audio Re-encode Phase ---")
+            try:
+                ctx = AudioEncodeStep().run(ctx, runner)
+            except Exception as e:
+                log(f"[FATAL] Audio re-encode phase failed: {e}")
+                raise RuntimeError(f"Audio re-encode phase failed: {e}") from e
+
+        if ctx.settings.dovi_inject:
+            log("--- Dolby Vision Phase ---")
+            try:
+                ctx = DoviStep().run(ctx, runner)
+            except Exception as e:
+                log(f"[FATAL] Dolby Vision phase failed: {e}")
+                raise RuntimeError(f"Dolby Vision phase failed: {e}") from e
+
         log("--- Merge Planning Phase ---")
         progress(0.75)
         try:
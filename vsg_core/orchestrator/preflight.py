@@ -0,0 +1,370 @@
+# vsg_core/orchestrator/preflight.py
+"""
+Up-front checks for a batch, before any job runs.
+
+A batch that fails on its 12th job because a file was moved, or because the
+output folder is read-only, wastes the time spent on the first eleven.
+``preflight`` looks for the problems that can be detected without running
+a job (missing or unreadable sources, sources only ffprobe can read in a
+merge batch, multi-part sources whose parts can't be joined, layouts
+pointing at tracks or sync sources that do not exist, invalid settings
+overrides, an unwritable output folder, missing or outdated tools, too
+little free space in the temp folder) and returns all of them, so they can
+be fixed in one go.
+
+The temp space a merge job needs is estimated from the layout: the size of
+each selected track (mkvmerge's statistics tags, else the whole source),
+plus the joined copy of multi-part sources and the remuxed copy of non-MKV
+sources (``normalize_to_mkv``), plus ``temp_space_margin_pct`` for the
+re-encoded audio and indexes. Work dirs are removed when a job ends, so a
+batch needs room for its ``batch_max_concurrent_jobs`` largest jobs at once.
+
+Errors mean the job cannot succeed; warnings are worth reading but do not
+stop the batch.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import shutil
+import subprocess
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING, Any, Literal
+
+from ..extraction.concat import check_parts_compatible
+from ..extraction.ffprobe_info import info_from_ffprobe
+from ..extraction.normalize import is_matroska
+from ..extraction.tool_versions import tool_versions
+from ..models.overrides import unknown_override_keys, validate_overrides
+from ..models.source_input import SourceInput, primary_sources
+from ..mux.sync_targets import sync_target_problems
+
+if TYPE_CHECKING:
+    from ..models.settings import AppSettings
+
+PreflightSeverity = Literal["error", "warning"]
+
+_IDENTIFY_TIMEOUT_S = 60
+
+
+@dataclass(frozen=True, slots=True)
+class PreflightIssue:
+    """One problem found before running a job."""
+
+    severity: PreflightSeverity
+    message: str
+    job: str = ""  # Source 1 file name; empty for batch-wide issues
+
+    @property
+    def is_error(self) -> bool:
+        return self.severity == "error"
+
+    def describe(self) -> str:
+        where = f"{self.job}: " if self.job else ""
+        return f"[Preflight] [{self.severity.upper()}] {where}{self.message}"
+
+
+def _identify(mkvmerge: str, path: str) -> tuple[dict[str, Any] | None, str]:
+    """mkvmerge -J of ``path`` as (info, error)."""
+    try:
+        proc = subprocess.run(
+            [mkvmerge, "-J", path],
+            capture_output=True,
+            text=True,
+            timeout=_IDENTIFY_TIMEOUT_S,
+            check=False,
+        )
+        info = json.loads(proc.stdout)
+    except (OSError, subprocess.SubprocessError, json.JSONDecodeError) as e:
+        return None, str(e)
+    container = info.get("container", {})
+    if not container.get("recognized", True) or not container.get("supported", True):
+        errors = info.get("errors") or ["container not recognized"]
+        return None, "; ".join(errors)
+    return info, ""
+
+
+def _probe(ffprobe: str, path: str) -> dict[str, Any] | None:
+    """ffprobe-built stream info of ``path``; None if ffprobe can't read it."""
+    try:
+        proc = subprocess.run(
+            [ffprobe, "-v", "error", "-show_streams", "-show_format"]
+            + ["-of", "json", path],
+            capture_output=True,
+            text=True,
+            timeout=_IDENTIFY_TIMEOUT_S,
+            check=False,
+        )
+        info = info_from_ffprobe(json.loads(proc.stdout))
+    except (OSError, subprocess.SubprocessError, json.JSONDecodeError):
+        return None
+    return info if info["tracks"] else None
+
+
+def _nearest_existing(path: Path) -> Path:
+    while not path.exists() and path != path.parent:
+        path = path.parent
+    return path
+
+
+def _file_size(path: str) -> int:
+    try:
+        return Path(path).stat().st_size
+    except OSError:
+        return 0
+
+
+def _fmt_size(size: float) -> str:
+    if size >= 1024**3:
+        return f"{size / 1024**3:.1f} GiB"
+    return f"{size / 1024**2:.0f} MiB"
+
+
+def _track_bytes(info: dict[str, Any], track_id: Any) -> int | None:
+    """Size of a track from mkvmerge's statistics tags; None if not tagged."""
+    for track in info.get("tracks", []):
+        if track.get("id") == track_id:
+            value = (track.get("properties") or {}).get("tag_number_of_bytes")
+            try:
+                return int(value)
+            except (TypeError, ValueError):
+                return None
+    return None
+
+
+def _temp_bytes_needed(
+    job: dict[str, Any],
+    parts_by_source: dict[str, list[tuple[str, dict[str, Any]]]],
+    normalize: bool,
+) -> int:
+    """Estimated work dir size of a merge job, without the margin."""
+    total = 0
+    selected: dict[str, list[Any]] = {}
+    for item in job.get("manual_layout") or []:
+        if item.get("is_generated"):
+            continue
+        source = item.get("source", "")
+        if source == "External":
+            total += _file_size(item.get("original_path", ""))
+            continue
+        selected.setdefault(source, []).append(item.get("id"))
+    for source, parts in parts_by_source.items():
+        source_size = sum(_file_size(path) for path, _ in parts)
+        if len(parts) > 1:
+            total += source_size  # joined/
+        if normalize and not is_matroska(parts[0][0]):
+            total += source_size  # normalized/
+        tracks = 0
+        for track_id in selected.get(source, []):
+            sizes = [_track_bytes(info, track_id) for _, info in parts]
+            if None in sizes:
+                tracks = source_size
+                break
+            tracks += sum(s for s in sizes if s is not None)
+        total += min(tracks, source_size)
+    return total
+
+
+def _check_temp_space(
+    job_bytes: list[int], settings: AppSettings
+) -> list[PreflightIssue]:
+    concurrent = max(1, settings.batch_max_concurrent_jobs)
+    largest = sorted(job_bytes, reverse=True)[:concurrent]
+    needed = sum(largest) * (1 + settings.temp_space_margin_pct / 100)
+    if not needed:
+        return []
+    folder = Path(settings.temp_root) if settings.temp_root else Path.cwd()
+    try:
+        available = shutil.disk_usage(_nearest_existing(folder)).free
+    except OSError:
+        return []
+    if needed <= available:
+        return []
+    at_once = f"{len(largest)} job(s) at once, " if len(largest) > 1 else ""
+    return [
+        PreflightIssue(
+            "error",
+            f"Not enough free space for temp files in {folder}: about "
+            f"{_fmt_size(needed)} needed ({at_once}+"
+            f"{settings.temp_space_margin_pct}% margin), {_fmt_size(available)} "
+            "available. Free up space, pick another temp folder, or run fewer "
+            "jobs at once.",
+        )
+    ]
+
+
+def _check_tools() -> list[PreflightIssue]:
+    versions = tool_versions()
+    issues = []
+    for tool in versions.tools:
+        problem = tool.problem
+        if problem is None:
+            continue
+        severity: PreflightSeverity = "error" if tool.path is None else "warning"
+        issues.append(PreflightIssue(severity, problem))
+    return issues
+
+
+def _check_output_dir(output_dir: str) -> list[PreflightIssue]:
+    if not output_dir:
+        return [PreflightIssue("error", "No output folder set.")]
+    path = Path(output_dir)
+    if path.exists() and not path.is_dir():
+        return [PreflightIssue("error", f"Output path is not a folder: {path}")]
+    existing = _nearest_existing(path)
+    if not os.access(existing, os.W_OK):
+        return [
+            PreflightIssue(
+                "error",
+                f"Output folder is not writable: {existing}. "
+                "Pick another folder or fix its permissions.",
+            )
+        ]
+    return []
+
+
+def _check_job(
+    job: dict[str, Any],
+    mkvmerge: str | None,
+    ffprobe: str | None,
+    needs_mkv: bool,
+    normalize: bool,
+) -> tuple[list[PreflightIssue], int]:
+    """The job's issues and its estimated temp space (``_temp_bytes_needed``)."""
+    sources = primary_sources(job.get("sources", {}))
+    name = Path(sources.get("Source 1", "")).name
+    issues: list[PreflightIssue] = []
+
+    def add(severity: PreflightSeverity, message: str) -> None:
+        issues.append(PreflightIssue(severity, message, name))
+
+    if not sources.get("Source 1"):
+        add("error", "Job has no Source 1.")
+
+    track_ids: dict[str, set[int]] = {}
+    parts_by_source: dict[str, list[tuple[str, dict[str, Any]]]] = {}
+    for key, value in job.get("sources", {}).items():
+        if not value:
+            continue
+        parts: list[tuple[str, dict[str, Any]]] = []
+        for path in SourceInput.from_value(value).parts:
+            p = Path(path)
+            if not p.is_file():
+                add("error", f"{key} not found: {path}")
+                continue
+            if not os.access(p, os.R_OK):
+                add("error", f"{key} is not readable: {path}")
+                continue
+            if mkvmerge is None:
+                continue
+            info, error = _identify(mkvmerge, path)
+            if info is None and ffprobe is not None:
+                info = _probe(ffprobe, path)
+                if info is not None and needs_mkv:
+                    add(
+                        "error",
+                        f"{key} can only be read by ffprobe (mkvmerge: {error}); "
+                        f"remux it to MKV before merging, or turn on "
+                        f"'Remux non-MKV sources to MKV': {path}",
+                    )
+                    continue
+                if info is not None:
+                    add(
+                        "warning",
+                        f"{key} is read through ffprobe (mkvmerge: {error}); it "
+                        f"can be analyzed, and merged only once remuxed to MKV.",
+                    )
+            if info is None:
+                add("error", f"{key} could not be read by mkvmerge ({error}): {path}")
+                continue
+            parts.append((path, info))
+        if not parts:
+            continue
+        try:
+            check_parts_compatible(key, parts)
+        except ValueError as e:
+            add("error", str(e))
+            continue
+        parts_by_source[key] = parts
+        track_ids[key] = {t.get("id") for t in parts[0][1].get("tracks", [])}
+
+    for key in job.get("attachment_sources") or []:
+        if key not in sources:
+            add("warning", f"Attachment source {key} is not part of the job.")
+    overrides = job.get("settings_overrides") or {}
+    unknown = unknown_override_keys(overrides)
+    for key in unknown:
+        add("warning", f"Settings override '{key}' is not a setting; ignored.")
+    known = {k: v for k, v in overrides.items() if k not in unknown}
+    for problem in validate_overrides(known):
+        add("error", f"Settings override {problem}")
+    chapter_source = job.get("chapter_source") or "Source 1"
+    if chapter_source not in sources:
+        add("error", f"Chapter source {chapter_source} is not part of the job.")
+
+    for problem in sync_target_problems(job.get("manual_layout") or [], sources):
+        add("error", f"{problem}.")
+    for item in job.get("manual_layout") or []:
+        source = item.get("source", "")
+        if item.get("is_generated"):
+            continue
+        if source == "External":
+            original = item.get("original_path", "")
+            if original and not Path(original).is_file():
+                add("error", f"External subtitle not found: {original}")
+            continue
+        if source not in sources:
+            add(
+                "error",
+                f"Layout uses {source} track {item.get('id')}, "
+                f"but the job has no {source}.",
+            )
+            continue
+        ids = track_ids.get(source)
+        if ids is not None and item.get("id") not in ids:
+            add(
+                "error",
+                f"Layout uses {source} track {item.get('id')}, which does not "
+                f"exist in {Path(sources[source]).name}. Re-open the job's "
+                "layout to pick the tracks again.",
+            )
+    return issues, _temp_bytes_needed(job, parts_by_source, normalize)
+
+
+def preflight(
+    jobs: list[dict[str, Any]],
+    settings: AppSettings,
+    output_dir: str,
+    and_merge: bool,
+) -> list[PreflightIssue]:
+    """All problems detectable before running ``jobs``, batch-wide first."""
+    issues = _check_tools()
+    if and_merge:
+        issues += _check_output_dir(output_dir)
+    if settings.temp_root:
+        existing = _nearest_existing(Path(settings.temp_root))
+        if not os.access(existing, os.W_OK):
+            issues.append(
+                PreflightIssue(
+                    "error", f"Temp folder is not writable: {settings.temp_root}"
+                )
+            )
+    versions = tool_versions()
+    status = versions.get("mkvmerge")
+    mkvmerge = status.path if status is not None else None
+    status = versions.get("ffprobe")
+    ffprobe = status.path if status is not None else None
+    job_bytes = []
+    for job in jobs:
+        # Sources are remuxed to MKV up front with normalize_to_mkv
+        needs_mkv = and_merge and not settings.normalize_to_mkv
+        job_issues, needed = _check_job(
+            job, mkvmerge, ffprobe, needs_mkv, settings.normalize_to_mkv
+        )
+        issues += job_issues
+        job_bytes.append(needed)
+    if and_merge:
+        issues += _check_temp_space(job_bytes, settings)
+    return issues
@@ -2,19 +2,27 @@
 from .analysis_step import AnalysisStep
 from .attachments_step import AttachmentsStep
 from .audio_correction_step import AudioCorrectionStep
+from .audio_encode_step import AudioEncodeStep
 from .chapters_step import ChaptersStep
 from .context import Context
+from .dovi_step import DoviStep
 from .extract_step import ExtractStep
 from .mux_step import MuxStep
+from .normalize_step import NormalizeStep
 from .subtitles_step import SubtitlesStep
+from .verify_step import VerifyStep
 
 __all__ = [
     "AnalysisStep",
     "AttachmentsStep",
     "AudioCorrectionStep",
+    "AudioEncodeStep",
     "ChaptersStep",
     "Context",
+    "DoviStep",
     "ExtractStep",
     "MuxStep",
+    "NormalizeStep",
     "SubtitlesStep",
+    "VerifyStep",
 ]
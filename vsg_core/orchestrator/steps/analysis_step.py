@@ -15,9 +15,11 @@ NO business logic - delegates to vsg_core/analysis/ modules.
 
 from __future__ import annotations
 
+from dataclasses import replace
 from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
+from vsg_core.analysis.chapter_exclusion import read_excluded_ranges
 from vsg_core.analysis.container_delays import (
     calculate_delay_chain,
     find_actual_correlation_track_delay,
@@ -27,37 +29,78 @@ from vsg_core.analysis.correlation import (
     DEFAULT_SR,
     apply_bandpass,
     apply_lowpass,
-    decode_audio,
     get_audio_stream_info,
     get_method,
     list_methods,
     normalize_lang,
 )
+from vsg_core.analysis.correlation.audio_cache import decode_for_job
+from vsg_core.analysis.correlation.chunk_dump import ChunkDumper
+from vsg_core.analysis.correlation.coarse import (
+    MIN_COARSE_PSR,
+    add_offset,
+    coarse_offset,
+    shift_target,
+)
+from vsg_core.analysis.correlation.decode import (
+    WINDOW_GUARD_S,
+    probe_audio_timing,
+    resolve_downmix,
+)
+from vsg_core.analysis.correlation.dense import (
+    ChunkPlacement,
+    resolve_scan_range,
+    window_positions,
+)
 from vsg_core.analysis.correlation.methods.scc import Scc
+from vsg_core.analysis.correlation.methods.spectrogram import SpectrogramCorrelation
+from vsg_core.analysis.correlation.run import configure_method
+from vsg_core.analysis.correlation.windowed import WindowedPcm, decode_windows
 from vsg_core.analysis.delay_selection import (
     calculate_delay,
     find_first_stable_segment_delay,
 )
+from vsg_core.analysis.delay_sidecar import (
+    SIDECAR_SUFFIX,
+    DelaySidecar,
+    sidecar_path,
+)
 from vsg_core.analysis.drift_detection import diagnose_audio_issue
 from vsg_core.analysis.global_shift import (
     apply_global_shift_to_delays,
     calculate_global_shift,
 )
+from vsg_core.analysis.multi_corr import MultiCorrReport
+from vsg_core.analysis.reliability import ReliabilityGate
+from vsg_core.analysis.report import AnalysisReport
 from vsg_core.analysis.sync_stability import analyze_sync_stability
+from vsg_core.analysis.timings import AnalysisTimings
+from vsg_core.analysis.track_consensus import TrackAgreement, TrackConsensusReport
 from vsg_core.analysis.track_selection import (
+    consensus_candidates,
     format_track_details,
     select_audio_track,
 )
-from vsg_core.analysis.types import ChunkResult, DriftDiagnosis, SteppingDiagnosis
+from vsg_core.analysis.types import (
+    ChunkResult,
+    DriftDiagnosis,
+    SteppingDiagnosis,
+    count_rejections,
+    describe_rejections,
+)
+from vsg_core.errors import AnalysisUnreliable
 from vsg_core.extraction.tracks import get_stream_info
 from vsg_core.models.jobs import Delays
+from vsg_core.models.rounding import round_delay_ms
 
 if TYPE_CHECKING:
     from collections.abc import Callable
 
     import numpy as np
 
+    from vsg_core.analysis.correlation.dense import WindowCallback
     from vsg_core.analysis.correlation.registry import CorrelationMethod
+    from vsg_core.analysis.reliability import UnreliableSource
     from vsg_core.analysis.types import DiagnosisResult
     from vsg_core.io.runner import CommandRunner
     from vsg_core.models.context_types import (
@@ -65,7 +108,19 @@ if TYPE_CHECKING:
         SourceNSettings,
     )
     from vsg_core.models.settings import AppSettings
+    from vsg_core.models.types import DownmixModeStr
     from vsg_core.orchestrator.steps.context import Context
+    from vsg_core.pipeline_components.progress_tracker import ProgressSlice
+
+
+# DTW slope deviation worth a warning (0.1%)
+_DTW_STRETCH_WARN_PPM = 1000.0
+
+# Share of a source's progress slice reached after each sub-phase; the
+# correlation windows fill the span between the last two
+_DECODED_PROGRESS = 0.25
+_PREPROCESSED_PROGRESS = 0.30
+_CORRELATED_PROGRESS = 0.95
 
 
 def _should_use_source_separated_mode(
@@ -92,7 +147,8 @@ def _resolve_method(
     Resolve the correlation method to use based on settings.
 
     For SCC, creates a fresh instance with the peak_fit setting applied.
-    For all other methods, looks up the registered instance.
+    For all other methods, looks up the registered instance. Waveform methods
+    get the configured sub-sample peak interpolation.
     """
     method_name = (
         settings.correlation_method_source_separated
@@ -100,11 +156,24 @@ def _resolve_method(
         else settings.correlation_method
     )
 
+    return _method_by_name(method_name, settings)
+
+
+def _method_by_name(method_name: str, settings: AppSettings) -> CorrelationMethod:
+    """Look up a correlation method by display name, configured from settings."""
     # SCC is special: it has a configurable peak_fit parameter
     if "Standard Correlation" in method_name or "SCC" in method_name:
-        return Scc(peak_fit=settings.audio_peak_fit)
+        method: CorrelationMethod = Scc(peak_fit=settings.audio_peak_fit)
+    else:
+        method = get_method(method_name)
+    if isinstance(method, SpectrogramCorrelation):
+        return SpectrogramCorrelation.from_settings(settings)
+    return configure_method(method, settings)
 
-    return get_method(method_name)
+
+def _min_accepted_windows(total_windows: int, settings: AppSettings) -> int:
+    """Minimum accepted windows for a usable delay (mirrors calculate_delay)."""
+    return max(10, int(total_windows * settings.min_accepted_pct / 100.0))
 
 
 def _apply_source_separation(
@@ -155,23 +224,38 @@ def _apply_filtering(
     settings: AppSettings,
     log: Callable[[str], None],
 ) -> tuple[np.ndarray, np.ndarray]:
-    """Apply configured audio filtering. Returns (ref, tgt) arrays."""
+    """Apply configured audio filtering. Returns (ref, tgt) arrays.
+
+    Windowed audio is filtered per guarded segment, so the filter's
+    start-up transient falls in the guard.
+    """
     filtering_method = settings.filtering_method
 
+    def each(pcm, fn):
+        return pcm.map(fn) if isinstance(pcm, WindowedPcm) else fn(pcm)
+
     if filtering_method == "Dialogue Band-Pass Filter":
         log("Applying Dialogue Band-Pass filter...")
         lowcut = settings.filter_bandpass_lowcut_hz
         highcut = settings.filter_bandpass_highcut_hz
         order = settings.filter_bandpass_order
-        ref_pcm = apply_bandpass(ref_pcm, sr, lowcut, highcut, order, log)
-        tgt_pcm = apply_bandpass(tgt_pcm, sr, lowcut, highcut, order, log)
+
+        def bandpass(pcm):
+            return apply_bandpass(pcm, sr, lowcut, highcut, order, log)
+
+        ref_pcm = each(ref_pcm, bandpass)
+        tgt_pcm = each(tgt_pcm, bandpass)
     elif filtering_method == "Low-Pass Filter":
         cutoff = settings.audio_bandlimit_hz
         if cutoff > 0:
             log(f"Applying Low-Pass filter at {cutoff} Hz...")
             taps = settings.filter_lowpass_taps
-            ref_pcm = apply_lowpass(ref_pcm, sr, cutoff, taps, log)
-            tgt_pcm = apply_lowpass(tgt_pcm, sr, cutoff, taps, log)
+
+            def lowpass(pcm):
+                return apply_lowpass(pcm, sr, cutoff, taps, log)
+
+            ref_pcm = each(ref_pcm, lowpass)
+            tgt_pcm = each(tgt_pcm, lowpass)
 
     return ref_pcm, tgt_pcm
 
@@ -181,6 +265,9 @@ class AnalysisStep:
         source1_file = ctx.sources.get("Source 1")
         if not source1_file:
             raise ValueError("Context is missing Source 1 for analysis.")
+        # Joined or normalized files live in the work dir; the report and
+        # sidecar are named after (and found next to) the user's file
+        source1_input = ctx.source_inputs.get("Source 1") or source1_file
 
         log = runner._log_message
         settings = ctx.settings
@@ -202,6 +289,14 @@ class AnalysisStep:
             log("[SYNC MODE] Negative delays are ALLOWED (no global shift).")
             log("[SYNC MODE] Source 1 remains reference (delay = 0).")
             log("[SYNC MODE] Secondary sources can have negative delays.")
+        elif sync_mode == "pad_silence":
+            ctx.global_shift_is_required = False
+            log("[SYNC MODE] Negative delays are trimmed from the audio itself.")
+            log("[SYNC MODE] Source 1 remains reference (delay = 0).")
+            log(
+                "[SYNC MODE] Affected tracks are re-encoded, padded with silence "
+                "to the video's duration and muxed at delay 0."
+            )
         elif sync_mode == "positive_only":
             ctx.global_shift_is_required = has_secondary_audio
             if ctx.global_shift_is_required:
@@ -236,6 +331,8 @@ class AnalysisStep:
 
         source_delays: dict[str, int] = {}
         raw_source_delays: dict[str, float] = {}
+        ctx.analysis_report = AnalysisReport(job_name=Path(source1_input).stem)
+        self._placement = self._chunk_placement(ctx, runner, source1_file)
 
         # --- Step 1: Get Source 1's container delays ---
         log("--- Getting Source 1 Container Delays for Analysis ---")
@@ -255,7 +352,9 @@ class AnalysisStep:
             source1_video_container_delay = source1_container_info.video_delay_ms
 
             ref_lang = settings.analysis_lang_source1
-            source1_stream_info = get_stream_info(source1_file, runner, ctx.tool_paths)
+            source1_stream_info = get_stream_info(
+                source1_file, runner, ctx.tool_paths, ffprobe_fallback=True
+            )
 
             if source1_stream_info:
                 source1_audio_tracks = [
@@ -304,13 +403,74 @@ class AnalysisStep:
             log("\n--- Running Audio Correlation Analysis ---")
 
         stepping_sources: list[str] = []
+        unreliable_sources: list[UnreliableSource] = []
 
-        for source_key, source_file in sorted(ctx.sources.items()):
-            if source_key == "Source 1":
-                continue
+        from vsg_core.pipeline_components.progress_tracker import ProgressSlice
+
+        # Each analyzed source fills an equal share of the analysis bar
+        analysis_progress = ctx.analysis_progress or ProgressSlice(ctx.progress)
+        other_sources = [
+            (key, path)
+            for key, path in sorted(ctx.sources.items())
+            if key != "Source 1"
+        ]
+        sidecar = (
+            self._load_sidecar(source1_input, [k for k, _ in other_sources], log)
+            if settings.analysis_use_sidecar
+            else None
+        )
 
+        for i, (source_key, source_file) in enumerate(other_sources):
+            source_progress = analysis_progress.part(
+                i / len(other_sources), (i + 1) / len(other_sources)
+            )
             log(f"\n[Analyzing {source_key}]")
 
+            manual_delay = ctx.source_settings.get(source_key, {}).get(
+                "manual_delay_ms"
+            )
+            if manual_delay is not None:
+                log(
+                    f"[Manual Delay] Using {int(manual_delay):+d}ms for "
+                    f"{source_key} (set in source settings); skipping analysis."
+                )
+                self._apply_fixed_delay(
+                    ctx,
+                    source_key,
+                    int(manual_delay),
+                    float(manual_delay),
+                    "manual",
+                    source_delays,
+                    raw_source_delays,
+                )
+                source_progress(1.0)
+                continue
+
+            if sidecar is not None:
+                entry = sidecar.sources[source_key]
+                log(
+                    f"[Sidecar] Using {entry.delay_ms:+d}ms for {source_key} "
+                    f"(raw {entry.raw_delay_ms:+.3f}ms, {entry.provenance}); "
+                    f"skipping analysis."
+                )
+                if entry.file and entry.file != Path(source_file).name:
+                    log(
+                        f"[Sidecar] [WARNING] Sidecar was written for "
+                        f"{entry.file}, this job's {source_key} is "
+                        f"{Path(source_file).name}."
+                    )
+                self._apply_fixed_delay(
+                    ctx,
+                    source_key,
+                    entry.delay_ms,
+                    entry.raw_delay_ms,
+                    "sidecar",
+                    source_delays,
+                    raw_source_delays,
+                )
+                source_progress(1.0)
+                continue
+
             # =============================================================
             # VideoDiff mode: frame-based analysis (no audio tracks needed)
             # =============================================================
@@ -325,6 +485,7 @@ class AnalysisStep:
                     source_delays,
                     raw_source_delays,
                 )
+                source_progress(1.0)
                 continue
 
             # =============================================================
@@ -342,11 +503,21 @@ class AnalysisStep:
                 source_delays,
                 raw_source_delays,
                 stepping_sources,
+                unreliable_sources,
+                source_progress,
             )
+            source_progress(1.0)
 
         # Store stepping sources in context
         ctx.stepping_sources = stepping_sources
 
+        # Stop before a questionable delay reaches the mux
+        if unreliable_sources:
+            self._write_analysis_report(ctx, log)
+            ReliabilityGate.from_settings(settings).enforce(
+                unreliable_sources, dict(source_delays)
+            )
+
         # Initialize Source 1 with 0ms base delay
         source_delays["Source 1"] = 0
         raw_source_delays["Source 1"] = 0.0
@@ -434,18 +605,144 @@ class AnalysisStep:
                 "\n[INFO] Negative delays retained (allow_negative mode). "
                 "Secondary sources may have negative delays."
             )
+        elif sync_mode == "pad_silence" and shift.shift_ms == 0:
+            log(
+                "\n[INFO] Negative delays retained (pad_silence mode). "
+                "Affected audio tracks are trimmed and padded before mux."
+            )
         elif shift.shift_ms > 0:
             log(
                 f"\n[INFO] All delays shifted by +{shift.shift_ms}ms "
                 f"to eliminate negatives."
             )
 
+        ctx.analysis_report.global_shift_ms = shift.shift_ms
+        self._write_analysis_report(ctx, log)
+
         return ctx
 
+    def _write_analysis_report(self, ctx: Context, log: Callable[[str], None]) -> None:
+        """Write the JSON + CSV analysis report into the job's logs dir.
+
+        Only with ``analysis_write_report``. The delay sidecar goes to the
+        output folder instead: the work dir is removed after the job, and
+        the sidecar is meant to be copied next to Source 1.
+        """
+        report = ctx.analysis_report
+        if report is None or not ctx.settings.analysis_write_report:
+            return
+        logs_dir = ctx.work_dir.logs
+        out_dir = Path(ctx.output_dir)
+        try:
+            logs_dir.mkdir(parents=True, exist_ok=True)
+            out_dir.mkdir(parents=True, exist_ok=True)
+            stem = f"{report.job_name}_analysis_report"
+            json_path = report.to_json(logs_dir / f"{stem}.json")
+            csv_path = report.to_csv(logs_dir / f"{stem}.csv")
+            sidecar = DelaySidecar.from_report(report, ctx.source_inputs).to_json(
+                out_dir / f"{report.job_name}{SIDECAR_SUFFIX}"
+            )
+            log(
+                f"[Report] Analysis report written: {json_path.name}, "
+                f"{csv_path.name}, {sidecar.name}"
+            )
+        except OSError as e:
+            log(f"[WARNING] Could not write analysis report: {e}")
+
+    def _chunk_placement(
+        self, ctx: Context, runner: CommandRunner, source1_file: str
+    ) -> ChunkPlacement:
+        """Window placement for every source, minus excluded chapters."""
+        settings = ctx.settings
+        placement = ChunkPlacement.from_settings(settings)
+        if not settings.analysis_exclude_chapters:
+            return placement
+        log = runner._log_message
+        ranges = read_excluded_ranges(
+            source1_file,
+            settings.analysis_exclude_chapter_patterns,
+            runner,
+            ctx.tool_paths,
+        )
+        if not ranges:
+            log(
+                "[Chapter Exclusion] No Source 1 chapter matches the patterns; "
+                "using the scan range as configured."
+            )
+            return placement
+        for excluded in ranges:
+            log(f"[Chapter Exclusion] Skipping {excluded.describe()}")
+        return replace(
+            placement, excluded_s=tuple((r.start_s, r.end_s) for r in ranges)
+        )
+
     # -----------------------------------------------------------------
     # Private helpers - each handles one analysis path
     # -----------------------------------------------------------------
 
+    def _load_sidecar(
+        self,
+        source1_file: str,
+        source_keys: list[str],
+        log: Callable[[str], None],
+    ) -> DelaySidecar | None:
+        """The job's delay sidecar, or None when Source 1 has none.
+
+        A sidecar that is malformed or covers other sources than the job
+        fails the job rather than silently falling back to analysis.
+        """
+        path = sidecar_path(source1_file)
+        if not path.is_file():
+            log(f"[Sidecar] No {path.name} next to Source 1; analyzing.")
+            return None
+        sidecar = DelaySidecar.load(path)
+        sidecar.check_sources(source_keys)
+        log(f"[Sidecar] Delays for all sources come from {path.name}.")
+        return sidecar
+
+    def _apply_fixed_delay(
+        self,
+        ctx: Context,
+        source_key: str,
+        delay_ms: int,
+        raw_delay_ms: float,
+        provenance: str,
+        source_delays: dict[str, int],
+        raw_source_delays: dict[str, float],
+    ) -> None:
+        """Use a delay set by hand or read from a sidecar in place of analysis.
+
+        The value is taken as this source's final delay relative to Source 1
+        (container delays included), so it goes through the global shift
+        and ``calculate_track_delay`` exactly like an analyzed one.
+        """
+        selection = provenance.capitalize()
+        source_delays[source_key] = delay_ms
+        raw_source_delays[source_key] = raw_delay_ms
+
+        if ctx.analysis_report is not None:
+            ctx.analysis_report.add_source(
+                source_key=source_key,
+                delay_ms=delay_ms,
+                raw_delay_ms=raw_delay_ms,
+                selection_mode=selection,
+                chunks=[],
+                provenance=provenance,
+            )
+
+        if ctx.audit:
+            ctx.audit.record_delay_calculation(
+                source_key=source_key,
+                correlation_raw_ms=raw_delay_ms,
+                correlation_rounded_ms=delay_ms,
+                container_delay_ms=0.0,
+                final_raw_ms=raw_delay_ms,
+                final_rounded_ms=delay_ms,
+                selection_method=selection,
+                accepted_windows=0,
+                total_windows=0,
+            )
+
     def _run_videodiff_analysis(
         self,
         ctx: Context,
@@ -480,6 +777,7 @@ class AnalysisStep:
             actual_container_delay,
             log=log,
             source_key=source_key,
+            rounding=ctx.settings.delay_rounding,
         )
 
         log(
@@ -498,6 +796,15 @@ class AnalysisStep:
         source_delays[source_key] = final_delay_ms
         raw_source_delays[source_key] = final_delay_raw
 
+        if ctx.analysis_report is not None:
+            ctx.analysis_report.add_source(
+                source_key=source_key,
+                delay_ms=final_delay_ms,
+                raw_delay_ms=final_delay_raw,
+                selection_mode="VideoDiff",
+                chunks=[],
+            )
+
         if ctx.audit:
             ctx.audit.record_delay_calculation(
                 source_key=source_key,
@@ -524,6 +831,8 @@ class AnalysisStep:
         source_delays: dict[str, int],
         raw_source_delays: dict[str, float],
         stepping_sources: list[str],
+        unreliable_sources: list[UnreliableSource],
+        progress: ProgressSlice,
     ) -> None:
         """Handle audio correlation analysis for one source."""
         log = runner._log_message
@@ -532,6 +841,7 @@ class AnalysisStep:
         # --- Get per-source settings ---
         per_source_settings = ctx.source_settings.get(source_key, {})
         correlation_source_track = per_source_settings.get("correlation_source_track")
+        explicit_source_track = correlation_source_track is not None
         source1_settings = ctx.source_settings.get("Source 1", {})
         correlation_ref_track = source1_settings.get("correlation_ref_track")
 
@@ -578,7 +888,9 @@ class AnalysisStep:
             log(f"  Delay Mode: {effective_delay_mode}")
 
         # --- Get stream info and select target track ---
-        stream_info = get_stream_info(source_file, runner, ctx.tool_paths)
+        stream_info = get_stream_info(
+            source_file, runner, ctx.tool_paths, ffprobe_fallback=True
+        )
         if not stream_info:
             log(f"[WARN] Could not get stream info for {source_key}. Skipping.")
             return
@@ -617,7 +929,9 @@ class AnalysisStep:
         )
 
         # --- Decode, separate, filter, chunk, correlate ---
-        results = self._decode_and_correlate(
+        timings = AnalysisTimings()
+        ctx.analysis_timings[source_key] = timings
+        results, correlation_method = self._decode_and_correlate(
             ctx=ctx,
             runner=runner,
             source_key=source_key,
@@ -627,6 +941,8 @@ class AnalysisStep:
             correlation_source_track=correlation_source_track,
             tgt_lang=tgt_lang,
             use_source_separated_settings=use_source_separated_settings,
+            timings=timings,
+            progress=progress,
         )
 
         # --- Detect stepping BEFORE calculating mode delay ---
@@ -670,27 +986,27 @@ class AnalysisStep:
                 f"(first segment, stepping corrected)."
             )
         else:
-            delay_calc = calculate_delay(
-                results=results,
-                settings=settings,
-                delay_mode=effective_delay_mode,
-                log=log,
-                role_tag=source_key,
-            )
+            with timings.measure("selection"):
+                delay_calc = calculate_delay(
+                    results=results,
+                    settings=settings,
+                    delay_mode=effective_delay_mode,
+                    log=log,
+                    role_tag=source_key,
+                )
 
             if delay_calc is None:
                 accepted_count = len([r for r in results if r.accepted])
                 total_windows = len(results)
-                min_required = max(
-                    10, int(total_windows * settings.min_accepted_pct / 100.0)
-                )
+                min_required = _min_accepted_windows(total_windows, settings)
 
-                raise RuntimeError(
+                raise AnalysisUnreliable(
                     f"Analysis failed for {source_key}: Could not determine "
                     f"a reliable delay.\n"
                     f"  - Accepted windows: {accepted_count}\n"
                     f"  - Minimum required: {min_required} ({settings.min_accepted_pct:.0f}% of {total_windows})\n"
                     f"  - Total windows scanned: {total_windows}\n"
+                    f"  - Rejected: {describe_rejections(count_rejections(results))}\n"
                     f"  - Match threshold: {settings.min_match_pct}%\n"
                     f"\n"
                     f"Possible causes:\n"
@@ -709,6 +1025,39 @@ class AnalysisStep:
 
             correlation_delay_ms = delay_calc.rounded_ms
             correlation_delay_raw = delay_calc.raw_ms
+            if settings.delay_rounding != "nearest":
+                correlation_delay_ms = round_delay_ms(
+                    correlation_delay_raw, settings.delay_rounding
+                )
+
+            if settings.multi_track_consensus:
+                consensus_ms = self._run_track_consensus(
+                    ctx=ctx,
+                    runner=runner,
+                    source_key=source_key,
+                    source1_file=source1_file,
+                    source_file=source_file,
+                    correlation_ref_track=correlation_ref_track,
+                    explicit_track=explicit_source_track,
+                    audio_tracks=audio_tracks,
+                    primary=TrackAgreement.from_chunks(
+                        correlation_source_track,
+                        format_track_details(
+                            audio_tracks[correlation_source_track],
+                            correlation_source_track,
+                        ),
+                        correlation_delay_raw,
+                        results,
+                    ),
+                    use_source_separated_settings=use_source_separated_settings,
+                    effective_delay_mode=effective_delay_mode,
+                    timings=timings,
+                )
+                if consensus_ms is not None:
+                    correlation_delay_raw = consensus_ms
+                    correlation_delay_ms = round_delay_ms(
+                        consensus_ms, settings.delay_rounding
+                    )
 
         # --- Sync Stability Analysis ---
         stepping_clusters = None
@@ -745,11 +1094,34 @@ class AnalysisStep:
             actual_container_delay,
             log=log,
             source_key=source_key,
+            rounding=settings.delay_rounding,
         )
 
         source_delays[source_key] = final_delay_ms
         raw_source_delays[source_key] = final_delay_raw
 
+        unreliable = ReliabilityGate.from_settings(settings).check(source_key, results)
+        if unreliable:
+            log(f"[Reliability] [WARNING] {unreliable.describe()}")
+            unreliable_sources.append(unreliable)
+
+        log(f"[Timing] {source_key}: {timings.describe()}")
+
+        if ctx.analysis_report is not None:
+            ctx.analysis_report.add_source(
+                source_key=source_key,
+                delay_ms=final_delay_ms,
+                raw_delay_ms=final_delay_raw,
+                selection_mode=effective_delay_mode,
+                chunks=results,
+                correlation_method=correlation_method,
+                multi_corr=ctx.multi_corr_reports.get(source_key),
+                track_consensus=ctx.track_consensus_reports.get(source_key),
+                coarse_prealign=ctx.coarse_alignments.get(source_key),
+                timings=timings,
+                correlation_curves=ctx.correlation_curves.get(source_key),
+            )
+
         # === AUDIT ===
         if ctx.audit:
             accepted_count = len([r for r in results if r.accepted])
@@ -763,6 +1135,7 @@ class AnalysisStep:
                 selection_method=effective_delay_mode,
                 accepted_windows=accepted_count,
                 total_windows=len(results),
+                correlation_method=correlation_method,
             )
 
         # --- Handle drift detection flags ---
@@ -805,10 +1178,19 @@ class AnalysisStep:
         correlation_source_track: int | None,
         tgt_lang: str | None,
         use_source_separated_settings: bool,
-    ) -> list[ChunkResult]:
+        timings: AnalysisTimings,
+        progress: ProgressSlice,
+        secondary: bool = False,
+    ) -> tuple[list[ChunkResult], str]:
         """
         Decode audio, apply separation/filtering, and run dense sliding
         window correlation. Handles both single-method and multi-method paths.
+
+        Returns the chunk results and the name of the correlation method that
+        produced them (which may be a fallback method). Phase durations are
+        added to ``timings``; ``progress`` advances per correlated window.
+        ``secondary`` runs (extra tracks for the multi-track consensus) skip
+        the per-source extras: multi-correlation, chunk dumps, curves, DTW.
         """
         log = runner._log_message
         settings = ctx.settings
@@ -857,54 +1239,103 @@ class AnalysisStep:
         )
 
         # --- 2. Decode ---
-        use_soxr = settings.use_soxr
-        log(
-            f"[DECODE DEBUG] Decoding ref: -map 0:a:{idx_ref} "
-            f"from {Path(source1_file).name}"
-        )
-        ref_pcm = decode_audio(
-            source1_file, idx_ref, DEFAULT_SR, use_soxr, runner, ctx.tool_paths
-        )
-        log(
-            f"[DECODE DEBUG] Decoding tgt: -map 0:a:{idx_tgt} "
-            f"from {Path(source_file).name}"
-        )
-        tgt_pcm = decode_audio(
-            source_file, idx_tgt, DEFAULT_SR, use_soxr, runner, ctx.tool_paths
-        )
+        with timings.measure("decode"):
+            ref_downmix = self._resolve_downmix(
+                ctx, runner, "REF", source1_file, idx_ref
+            )
+            tgt_downmix = self._resolve_downmix(
+                ctx, runner, source_key.upper(), source_file, idx_tgt
+            )
+            windowed = self._decode_windowed(
+                ctx,
+                runner,
+                source1_file,
+                source_file,
+                idx_ref,
+                idx_tgt,
+                use_source_separated_settings,
+                ref_downmix,
+                tgt_downmix,
+            )
+            if windowed is not None:
+                ref_pcm, tgt_pcm = windowed
+            else:
+                log(
+                    f"[DECODE DEBUG] Decoding ref: -map 0:a:{idx_ref} "
+                    f"from {Path(source1_file).name}"
+                )
+                ref_pcm = decode_for_job(
+                    ctx, source1_file, idx_ref, DEFAULT_SR, runner, ref_downmix
+                )
+                log(
+                    f"[DECODE DEBUG] Decoding tgt: -map 0:a:{idx_tgt} "
+                    f"from {Path(source_file).name}"
+                )
+                tgt_pcm = decode_for_job(
+                    ctx, source_file, idx_tgt, DEFAULT_SR, runner, tgt_downmix
+                )
 
-        # Log audio stats
-        log(
-            f"[DECODE DEBUG] ref_pcm: shape={ref_pcm.shape}, "
-            f"min={ref_pcm.min():.6f}, max={ref_pcm.max():.6f}, "
-            f"std={ref_pcm.std():.6f}"
-        )
-        log(
-            f"[DECODE DEBUG] tgt_pcm: shape={tgt_pcm.shape}, "
-            f"min={tgt_pcm.min():.6f}, max={tgt_pcm.max():.6f}, "
-            f"std={tgt_pcm.std():.6f}"
-        )
+                # Log audio stats
+                log(
+                    f"[DECODE DEBUG] ref_pcm: shape={ref_pcm.shape}, "
+                    f"min={ref_pcm.min():.6f}, max={ref_pcm.max():.6f}, "
+                    f"std={ref_pcm.std():.6f}"
+                )
+                log(
+                    f"[DECODE DEBUG] tgt_pcm: shape={tgt_pcm.shape}, "
+                    f"min={tgt_pcm.min():.6f}, max={tgt_pcm.max():.6f}, "
+                    f"std={tgt_pcm.std():.6f}"
+                )
 
-        # --- 2b. Source Separation (Optional) ---
-        if use_source_separated_settings:
-            ref_pcm, tgt_pcm = _apply_source_separation(
-                ref_pcm, tgt_pcm, DEFAULT_SR, settings, log, source_key
+        progress(_DECODED_PROGRESS)
+
+        with timings.measure("preprocess"):
+            # --- 2b. Source Separation (Optional) ---
+            if use_source_separated_settings:
+                ref_pcm, tgt_pcm = _apply_source_separation(
+                    ref_pcm, tgt_pcm, DEFAULT_SR, settings, log, source_key
+                )
+
+            # --- 3. Filtering ---
+            ref_pcm, tgt_pcm = _apply_filtering(
+                ref_pcm, tgt_pcm, DEFAULT_SR, settings, log
             )
 
-        # --- 3. Filtering ---
-        ref_pcm, tgt_pcm = _apply_filtering(ref_pcm, tgt_pcm, DEFAULT_SR, settings, log)
+            # --- 3b. Coarse pre-alignment (Optional) ---
+            coarse_ms = 0.0
+            if settings.coarse_prealign:
+                coarse_ms = self._coarse_prealign(
+                    ctx, source_key, ref_pcm, tgt_pcm, log, secondary
+                )
+                if coarse_ms:
+                    tgt_pcm = shift_target(tgt_pcm, coarse_ms, DEFAULT_SR)
+        progress(_PREPROCESSED_PROGRESS)
 
         # --- 4 & 5. Correlate (dense sliding window) ---
         min_match = float(settings.min_match_pct)
+        correlation_progress = progress.part(
+            _PREPROCESSED_PROGRESS, _CORRELATED_PROGRESS
+        )
+
+        # Debug: dump the windows of the primary pass as WAV
+        dumper = None
+        if ctx.debug_paths and ctx.debug_paths.analysis_chunks_dir and not secondary:
+            dumper = ChunkDumper(
+                ctx.debug_paths.analysis_chunks_dir, source_key, DEFAULT_SR
+            )
 
-        from vsg_core.analysis.correlation.dense import run_dense_correlation
+        from vsg_core.analysis.correlation.dense import run_dense_from_settings
 
-        multi_corr_enabled = settings.multi_correlation_enabled and (
-            not ctx.and_merge
+        multi_corr_enabled = (
+            settings.multi_correlation_enabled and not ctx.and_merge and not secondary
         )
 
         if multi_corr_enabled:
-            results = self._run_dense_multi_correlation(
+            report = MultiCorrReport(
+                source_key=source_key,
+                threshold_ms=settings.multi_corr_disagree_threshold_ms,
+            )
+            results, used_method = self._run_dense_multi_correlation(
                 ref_pcm=ref_pcm,
                 tgt_pcm=tgt_pcm,
                 sr=DEFAULT_SR,
@@ -912,27 +1343,72 @@ class AnalysisStep:
                 use_source_separated=use_source_separated_settings,
                 min_match=min_match,
                 log=log,
+                timings=timings,
+                progress=correlation_progress,
+                report=report,
+                on_window=dumper,
             )
+            if report.methods:
+                ctx.multi_corr_reports[source_key] = report
         else:
             method = _resolve_method(
                 settings, source_separated=use_source_separated_settings
             )
-            results = run_dense_correlation(
+            used_method = method.name
+            with timings.measure_method(method.name):
+                results = run_dense_from_settings(
+                    ref_pcm=ref_pcm,
+                    tgt_pcm=tgt_pcm,
+                    sr=DEFAULT_SR,
+                    method=method,
+                    settings=settings,
+                    min_match=min_match,
+                    log=log,
+                    placement=self._placement,
+                    on_window=dumper,
+                    progress=correlation_progress,
+                )
+            results, used_method = self._run_fallback_methods(
                 ref_pcm=ref_pcm,
                 tgt_pcm=tgt_pcm,
-                sr=DEFAULT_SR,
-                method=method,
-                window_s=settings.dense_window_s,
-                hop_s=settings.dense_hop_s,
+                settings=settings,
                 min_match=min_match,
-                silence_threshold_db=settings.dense_silence_threshold_db,
-                outlier_threshold_ms=settings.dense_outlier_threshold_ms,
-                start_pct=settings.scan_start_percentage,
-                end_pct=settings.scan_end_percentage,
+                results=results,
+                used_method=used_method,
                 log=log,
-                dbscan_epsilon_ms=settings.detection_dbscan_epsilon_ms,
-                dbscan_min_samples_pct=settings.detection_dbscan_min_samples_pct,
+                timings=timings,
+            )
+
+        if dumper is not None:
+            index_path = dumper.close()
+            if index_path is not None:
+                log(
+                    f"[Chunk Dump] Wrote {source_key} windows to "
+                    f"{index_path.parent} (index: {index_path.name})"
+                )
+
+        if settings.correlation_curves != "off" and not secondary:
+            from vsg_core.analysis.correlation.curve import collect_curves
+
+            curves = collect_curves(
+                ref_pcm,
+                tgt_pcm,
+                DEFAULT_SR,
+                settings.dense_window_s,
+                results,
+                all_windows=settings.correlation_curves == "all",
+                max_points=settings.correlation_curve_max_points,
             )
+            if curves:
+                ctx.correlation_curves[source_key] = curves
+                log(
+                    f"[Correlation Curve] Kept {len(curves)} curve(s) for "
+                    f"{source_key}."
+                )
+
+        if settings.dtw_enabled and not secondary:
+            with timings.measure_method("dtw"):
+                self._run_dtw(ctx, source_key, ref_pcm, tgt_pcm, results, log)
 
         # Release audio arrays and GPU resources
         del ref_pcm
@@ -941,7 +1417,374 @@ class AnalysisStep:
 
         cleanup_gpu()
 
-        return results
+        if coarse_ms:
+            results = add_offset(results, coarse_ms)
+        return results, used_method
+
+    def _coarse_prealign(
+        self,
+        ctx: Context,
+        source_key: str,
+        ref_pcm: np.ndarray,
+        tgt_pcm: np.ndarray,
+        log: Callable[[str], None],
+        secondary: bool,
+    ) -> float:
+        """Coarse whole-file offset to shift the target by (0 = don't shift)."""
+        max_offset_s = ctx.settings.coarse_prealign_max_offset_s
+        alignment = coarse_offset(ref_pcm, tgt_pcm, DEFAULT_SR, max_offset_s)
+        if alignment is None:
+            log("[Coarse Align] Tracks too short for a coarse estimate; skipped.")
+            return 0.0
+        if not secondary:
+            ctx.coarse_alignments[source_key] = alignment
+        if not alignment.reliable:
+            log(
+                f"[Coarse Align] {source_key}: {alignment.describe()} is not "
+                f"distinct enough (PSR < {MIN_COARSE_PSR:g}); correlating "
+                f"without pre-alignment."
+            )
+            return 0.0
+        log(
+            f"[Coarse Align] {source_key}: coarse offset {alignment.describe()}; "
+            f"windows are correlated around it."
+        )
+        return alignment.offset_ms
+
+    def _run_track_consensus(
+        self,
+        ctx: Context,
+        runner: CommandRunner,
+        source_key: str,
+        source1_file: str,
+        source_file: str,
+        correlation_ref_track: int | None,
+        explicit_track: bool,
+        audio_tracks: list[dict[str, Any]],
+        primary: TrackAgreement,
+        use_source_separated_settings: bool,
+        effective_delay_mode: str,
+        timings: AnalysisTimings,
+    ) -> float | None:
+        """
+        Correlate the other same-language audio tracks of ``source_key`` and
+        return the confidence-weighted consensus delay, or None to keep the
+        primary track's delay (no strict majority, or the primary track is
+        not part of it).
+        """
+        from vsg_core.pipeline_components.progress_tracker import ProgressSlice
+
+        log = runner._log_message
+        settings = ctx.settings
+        if explicit_track:
+            log(
+                f"[Track Consensus] {source_key}: skipped, the correlation "
+                "track was chosen explicitly."
+            )
+            return None
+        candidates = consensus_candidates(audio_tracks, primary.track_index)
+        if len(candidates) < 2:
+            log(
+                f"[Track Consensus] {source_key}: only one candidate track, "
+                "nothing to combine."
+            )
+            return None
+
+        report = TrackConsensusReport(
+            source_key=source_key,
+            threshold_ms=settings.multi_track_consensus_threshold_ms,
+            tracks=[primary],
+        )
+        for idx in candidates[1:]:
+            description = format_track_details(audio_tracks[idx], idx)
+            log(f"[Track Consensus] {source_key}: correlating {description}")
+            try:
+                results, _ = self._decode_and_correlate(
+                    ctx=ctx,
+                    runner=runner,
+                    source_key=source_key,
+                    source1_file=source1_file,
+                    source_file=source_file,
+                    correlation_ref_track=correlation_ref_track,
+                    correlation_source_track=idx,
+                    tgt_lang=None,
+                    use_source_separated_settings=use_source_separated_settings,
+                    timings=timings,
+                    progress=ProgressSlice(lambda _f: None),
+                    secondary=True,
+                )
+            except (RuntimeError, ValueError) as e:
+                log(f"[Track Consensus] {description}: correlation failed ({e})")
+                report.tracks.append(TrackAgreement(idx, description, None, 0.0, 0, 0))
+                continue
+            delay_calc = calculate_delay(
+                results=results,
+                settings=settings,
+                delay_mode=effective_delay_mode,
+                log=log,
+                role_tag=f"{source_key} track {idx}",
+            )
+            report.tracks.append(
+                TrackAgreement.from_chunks(
+                    idx,
+                    description,
+                    delay_calc.raw_ms if delay_calc else None,
+                    results,
+                )
+            )
+
+        ctx.track_consensus_reports[source_key] = report
+        log(f"[Track Consensus] {source_key}:")
+        report.log_table(log)
+        consensus = report.consensus_ms
+        if consensus is None:
+            return None
+        if not report.has_majority:
+            log(
+                f"[Track Consensus] {source_key}: no group of tracks holds a "
+                "majority of the confidence; keeping the selected track's delay."
+            )
+            return None
+        if not any(t.track_index == primary.track_index for t, _ in report.included):
+            log(
+                f"[Track Consensus] [WARNING] {source_key}: the selected track "
+                "disagrees with the majority of the other tracks; keeping its "
+                "delay."
+            )
+            return None
+        return consensus
+
+    def _resolve_downmix(
+        self,
+        ctx: Context,
+        runner: CommandRunner,
+        label: str,
+        file_path: str,
+        stream_index: int,
+    ) -> DownmixModeStr:
+        """Effective ``analysis_downmix_mode`` for one stream, logged."""
+        requested = ctx.settings.analysis_downmix_mode
+        downmix, layout = resolve_downmix(
+            requested, file_path, stream_index, runner, ctx.tool_paths
+        )
+        layout_desc = f" ({layout})" if layout else ""
+        if downmix != requested:
+            runner._log_message(
+                f"[Downmix] {label}{layout_desc}: no channel for '{requested}', "
+                f"using mono_sum."
+            )
+        else:
+            runner._log_message(f"[Downmix] {label}{layout_desc}: {downmix}")
+        return downmix
+
+    def _decode_windowed(
+        self,
+        ctx: Context,
+        runner: CommandRunner,
+        source1_file: str,
+        source_file: str,
+        idx_ref: int,
+        idx_tgt: int,
+        use_source_separated: bool,
+        ref_downmix: DownmixModeStr = "mono_sum",
+        tgt_downmix: DownmixModeStr = "mono_sum",
+    ) -> tuple[WindowedPcm, WindowedPcm] | None:
+        """
+        Decode only the correlation windows (``windowed_decode``).
+
+        Returns None when the full tracks must be decoded instead: the
+        option is off, windows overlap (full decode is cheaper), or a
+        feature that needs the whole signal is enabled.
+        """
+        settings = ctx.settings
+        if not settings.windowed_decode:
+            return None
+        log = runner._log_message
+
+        blocker = None
+        if settings.dense_hop_s < settings.dense_window_s + 2 * WINDOW_GUARD_S:
+            blocker = "windows are too close together (hop ≤ window + guard)"
+        elif settings.avoid_silence:
+            blocker = "silence avoidance needs the full track"
+        elif use_source_separated:
+            blocker = "source separation needs the full track"
+        elif settings.dtw_enabled:
+            blocker = "DTW needs the full track"
+        elif settings.coarse_prealign:
+            blocker = "coarse pre-alignment needs the full track"
+        if blocker:
+            log(f"[Windowed Decode] Not used: {blocker}.")
+            return None
+
+        ref_timing = probe_audio_timing(source1_file, idx_ref, runner, ctx.tool_paths)
+        tgt_timing = probe_audio_timing(source_file, idx_tgt, runner, ctx.tool_paths)
+        if ref_timing is None or tgt_timing is None:
+            log("[Windowed Decode] Not used: could not probe stream durations.")
+            return None
+
+        sr = DEFAULT_SR
+        length = int(min(ref_timing[0], tgt_timing[0]) * sr)
+        window_samples = int(round(settings.dense_window_s * sr))
+        positions, _, _ = window_positions(
+            length,
+            sr,
+            settings.dense_window_s,
+            settings.dense_hop_s,
+            settings.scan_start_percentage,
+            settings.scan_end_percentage,
+            settings.scan_start_ms,
+            settings.scan_end_ms,
+            self._placement,
+        )
+        log(
+            f"[Windowed Decode] Decoding {len(positions)} windows of "
+            f"{settings.dense_window_s:g}s (±{WINDOW_GUARD_S:g}s guard) per "
+            f"track instead of {length / sr / 60:.1f} min."
+        )
+
+        def decode(
+            path: str, idx: int, offset_s: float, downmix: DownmixModeStr
+        ) -> WindowedPcm:
+            return decode_windows(
+                path,
+                idx,
+                positions,
+                window_samples,
+                length,
+                sr,
+                settings.use_soxr,
+                runner,
+                ctx.tool_paths,
+                start_offset_s=offset_s,
+                downmix=downmix,
+            )
+
+        ref_pcm = decode(source1_file, idx_ref, ref_timing[1], ref_downmix)
+        tgt_pcm = decode(source_file, idx_tgt, tgt_timing[1], tgt_downmix)
+        held_mib = (ref_pcm.nbytes + tgt_pcm.nbytes) / 2**20
+        log(
+            f"[Windowed Decode] Held {held_mib:.1f} MiB "
+            f"(full decode: {2 * length * 4 / 2**20:.1f} MiB)."
+        )
+        return ref_pcm, tgt_pcm
+
+    def _run_dtw(
+        self,
+        ctx: Context,
+        source_key: str,
+        ref_pcm: np.ndarray,
+        tgt_pcm: np.ndarray,
+        results: list[ChunkResult],
+        log: Callable[[str], None],
+    ) -> None:
+        """Band-limited DTW centred on the correlation delay (diagnostic)."""
+        from vsg_core.analysis.correlation.dtw import dtw_align
+
+        settings = ctx.settings
+        accepted = sorted(r.raw_delay_ms for r in results if r.accepted)
+        center = accepted[len(accepted) // 2] if accepted else 0.0
+        # Same scan range as the correlation (absolute times resolved)
+        length = min(len(ref_pcm), len(tgt_pcm))
+        scan_start, scan_end, _ = resolve_scan_range(
+            length,
+            DEFAULT_SR,
+            settings.scan_start_percentage,
+            settings.scan_end_percentage,
+            settings.scan_start_ms,
+            settings.scan_end_ms,
+        )
+        log(
+            f"[DTW] Aligning {source_key} (band ±{settings.dtw_band_ms:g}ms "
+            f"around {center:+.1f}ms)..."
+        )
+        result = dtw_align(
+            ref_pcm,
+            tgt_pcm,
+            DEFAULT_SR,
+            band_ms=settings.dtw_band_ms,
+            center_delay_ms=center,
+            start_pct=scan_start / length * 100.0,
+            end_pct=scan_end / length * 100.0,
+        )
+        if result is None:
+            log("[DTW] Scan range does not overlap the target — skipped.")
+            return
+        ctx.dtw_results[source_key] = result
+        log(
+            f"[DTW] Path length: {len(result.path)} | "
+            f"offset: {result.offset_ms:+.1f}ms | "
+            f"avg slope: {result.slope:.6f} ({result.stretch_ppm:+.0f} ppm) | "
+            f"mean cost: {result.mean_cost:.3f}"
+        )
+        if abs(result.stretch_ppm) > _DTW_STRETCH_WARN_PPM:
+            log(
+                "[DTW] [WARNING] Slope differs from 1.0 — the target runs at a "
+                "different speed (stretch or tempo change)."
+            )
+
+    def _run_fallback_methods(
+        self,
+        ref_pcm: np.ndarray,
+        tgt_pcm: np.ndarray,
+        settings: AppSettings,
+        min_match: float,
+        results: list[ChunkResult],
+        used_method: str,
+        log: Callable[[str], None],
+        timings: AnalysisTimings,
+    ) -> tuple[list[ChunkResult], str]:
+        """
+        Retry correlation with ``settings.fallback_methods`` (in order) when
+        the primary method accepted too few windows.
+
+        Reuses the already decoded/filtered audio. Returns the first result
+        set that meets the minimum, or the primary results if none do.
+        """
+        from vsg_core.analysis.correlation.dense import run_dense_from_settings
+        from vsg_core.analysis.correlation.gpu_backend import cleanup_gpu
+
+        required = _min_accepted_windows(len(results), settings)
+        accepted = sum(1 for r in results if r.accepted)
+        if accepted >= required or not settings.fallback_methods:
+            return results, used_method
+
+        tried = {used_method}
+        for name in settings.fallback_methods:
+            if name in tried:
+                continue
+            tried.add(name)
+            log(
+                f"[Fallback] '{used_method}' accepted {accepted}/{len(results)} "
+                f"windows (need {required}); retrying with '{name}' "
+                f"on the cached decoded audio."
+            )
+            cleanup_gpu()
+            with timings.measure_method(name):
+                fb_results = run_dense_from_settings(
+                    ref_pcm=ref_pcm,
+                    tgt_pcm=tgt_pcm,
+                    sr=DEFAULT_SR,
+                    method=_method_by_name(name, settings),
+                    settings=settings,
+                    min_match=min_match,
+                    log=log,
+                    placement=self._placement,
+                )
+            fb_accepted = sum(1 for r in fb_results if r.accepted)
+            fb_required = _min_accepted_windows(len(fb_results), settings)
+            if fb_accepted >= fb_required:
+                log(
+                    f"[Fallback] '{name}' accepted {fb_accepted}/{len(fb_results)} "
+                    f"windows — using it for the delay."
+                )
+                return fb_results, name
+            log(
+                f"[Fallback] '{name}' accepted only {fb_accepted}/"
+                f"{len(fb_results)} windows (need {fb_required})."
+            )
+
+        log(f"[Fallback] No fallback method succeeded; keeping '{used_method}'.")
+        return results, used_method
 
     def _run_dense_multi_correlation(
         self,
@@ -952,15 +1795,23 @@ class AnalysisStep:
         use_source_separated: bool,
         min_match: float,
         log: Callable[[str], None],
-    ) -> list[ChunkResult]:
+        timings: AnalysisTimings,
+        progress: ProgressSlice,
+        report: MultiCorrReport | None = None,
+        on_window: WindowCallback | None = None,
+    ) -> tuple[list[ChunkResult], str]:
         """
         Run multiple correlation methods using dense sliding window.
 
         Each enabled method gets its own dense correlation pass with
-        full summary logging. Returns the primary method's results
+        full summary logging. Per-method delays are collected into
+        ``report`` (agreement table). Returns the primary method's results
         for actual delay calculation.
+
+        ``on_window`` only sees the first method's pass (the audio is the
+        same for every method).
         """
-        from vsg_core.analysis.correlation.dense import run_dense_correlation
+        from vsg_core.analysis.correlation.dense import run_dense_from_settings
 
         # Find enabled methods
         enabled_methods: list[CorrelationMethod] = []
@@ -968,27 +1819,27 @@ class AnalysisStep:
             if getattr(settings, method.config_key, False):
                 if isinstance(method, Scc):
                     method = Scc(peak_fit=settings.audio_peak_fit)
-                enabled_methods.append(method)
+                elif isinstance(method, SpectrogramCorrelation):
+                    method = SpectrogramCorrelation.from_settings(settings)
+                enabled_methods.append(configure_method(method, settings))
 
         if not enabled_methods:
             log("[MULTI-CORRELATION] No methods enabled, falling back to single method")
             method = _resolve_method(settings, source_separated=use_source_separated)
-            return run_dense_correlation(
-                ref_pcm=ref_pcm,
-                tgt_pcm=tgt_pcm,
-                sr=sr,
-                method=method,
-                window_s=settings.dense_window_s,
-                hop_s=settings.dense_hop_s,
-                min_match=min_match,
-                silence_threshold_db=settings.dense_silence_threshold_db,
-                outlier_threshold_ms=settings.dense_outlier_threshold_ms,
-                start_pct=settings.scan_start_percentage,
-                end_pct=settings.scan_end_percentage,
-                log=log,
-                dbscan_epsilon_ms=settings.detection_dbscan_epsilon_ms,
-                dbscan_min_samples_pct=settings.detection_dbscan_min_samples_pct,
-            )
+            with timings.measure_method(method.name):
+                fallback_results = run_dense_from_settings(
+                    ref_pcm=ref_pcm,
+                    tgt_pcm=tgt_pcm,
+                    sr=sr,
+                    method=method,
+                    settings=settings,
+                    min_match=min_match,
+                    log=log,
+                    placement=self._placement,
+                    on_window=on_window,
+                    progress=progress,
+                )
+            return fallback_results, method.name
 
         log(
             f"\n[MULTI-CORRELATION] Running {len(enabled_methods)} methods "
@@ -997,27 +1848,26 @@ class AnalysisStep:
 
         all_results: dict[str, list[ChunkResult]] = {}
 
-        for method in enabled_methods:
+        for i, method in enumerate(enabled_methods):
             log(f"\n{'=' * 70}")
             log(f"  MULTI-CORRELATION: {method.name}")
             log(f"{'=' * 70}")
 
-            results = run_dense_correlation(
-                ref_pcm=ref_pcm,
-                tgt_pcm=tgt_pcm,
-                sr=sr,
-                method=method,
-                window_s=settings.dense_window_s,
-                hop_s=settings.dense_hop_s,
-                min_match=min_match,
-                silence_threshold_db=settings.dense_silence_threshold_db,
-                outlier_threshold_ms=settings.dense_outlier_threshold_ms,
-                start_pct=settings.scan_start_percentage,
-                end_pct=settings.scan_end_percentage,
-                log=log,
-                dbscan_epsilon_ms=settings.detection_dbscan_epsilon_ms,
-                dbscan_min_samples_pct=settings.detection_dbscan_min_samples_pct,
-            )
+            with timings.measure_method(method.name):
+                results = run_dense_from_settings(
+                    ref_pcm=ref_pcm,
+                    tgt_pcm=tgt_pcm,
+                    sr=sr,
+                    method=method,
+                    settings=settings,
+                    min_match=min_match,
+                    log=log,
+                    placement=self._placement,
+                    on_window=on_window if i == 0 else None,
+                    progress=progress.part(
+                        i / len(enabled_methods), (i + 1) / len(enabled_methods)
+                    ),
+                )
             all_results[method.name] = results
 
             # Free GPU memory between methods
@@ -1045,11 +1895,19 @@ class AnalysisStep:
                     f"std={std_d:.3f}ms | "
                     f"conf={avg_match:.1f}% | "
                     f"accepted={len(accepted)}/{len(method_results)} | "
-                    f"outliers={outliers}"
+                    f"outliers={outliers} | "
+                    f"time={timings.correlation_s.get(method_name, 0.0):.2f}s"
                 )
             else:
                 log(f"  {method_name}: NO ACCEPTED WINDOWS")
 
+        if report is not None:
+            for method_name, method_results in all_results.items():
+                report.add_method(method_name, method_results)
+            log(f"{'-' * 70}")
+            log("  METHOD AGREEMENT")
+            report.log_table(log)
+
         log(f"{'=' * 70}\n")
 
         # Use first method's results for actual processing
@@ -1058,7 +1916,7 @@ class AnalysisStep:
             f"[MULTI-CORRELATION] Using '{first_method_name}' results "
             f"for delay calculation"
         )
-        return all_results[first_method_name]
+        return all_results[first_method_name], first_method_name
 
     def _handle_stepping(
         self,
@@ -5,7 +5,11 @@ import shutil
 from pathlib import Path
 from typing import TYPE_CHECKING
 
-from vsg_core.extraction.attachments import extract_attachments
+from vsg_core.extraction.attachments import (
+    ExtractedAttachment,
+    dedupe_attachments,
+    extract_attachments,
+)
 
 if TYPE_CHECKING:
     from vsg_core.io.runner import CommandRunner
@@ -14,7 +18,9 @@ if TYPE_CHECKING:
 
 class AttachmentsStep:
     """
-    Extracts attachments from all sources specified by the user in the UI.
+    Extracts attachments from all sources specified by the user in the UI,
+    optionally limited to a chosen subset per source, and drops duplicates
+    (same file name and size) in favour of the reference source.
     Also handles copying replacement fonts from Font Manager.
     """
 
@@ -25,18 +31,30 @@ class AttachmentsStep:
             self._add_replacement_fonts(ctx, runner)
             return ctx
 
-        all_attachments: list[str] = []
-        for source_key in ctx.attachment_sources:
+        extracted: list[ExtractedAttachment] = []
+        # Reference source first, so its copy survives de-duplication
+        for source_key in sorted(ctx.attachment_sources, key=lambda k: k != "Source 1"):
             source_file = ctx.sources.get(source_key)
             if source_file:
                 runner._log_message(f"Extracting attachments from {source_key}...")
-                attachments_from_source = extract_attachments(
-                    str(source_file), ctx.temp_dir, runner, ctx.tool_paths, source_key
+                select = ctx.attachment_selection.get(source_key)
+                extracted.extend(
+                    extract_attachments(
+                        str(source_file),
+                        ctx.work_dir.extracted,
+                        runner,
+                        ctx.tool_paths,
+                        source_key,
+                        select=select,
+                        fonts_only=select is None,
+                    )
                 )
-                if attachments_from_source:
-                    all_attachments.extend(attachments_from_source)
 
-        ctx.attachments = all_attachments
+        extracted = dedupe_attachments(extracted, runner._log_message)
+        ctx.attachments = [str(att.path) for att in extracted]
+        ctx.attachment_mime_types = {
+            str(att.path): att.content_type for att in extracted
+        }
 
         # Add replacement fonts
         self._add_replacement_fonts(ctx, runner)
@@ -0,0 +1,289 @@
+# vsg_core/orchestrator/steps/audio_encode_step.py
+"""
+Optional pre-mux step: re-encodes audio tracks that carry an ``EncodeSpec``.
+
+Every other track is copied losslessly by mkvmerge. Selected tracks are run
+through ffmpeg into a Matroska audio file; the delay mkvmerge would have
+applied is baked in with ``-itsoffset`` (or by skipping leading audio for a
+negative delay, which is what mkvmerge does with a negative ``--sync``), and
+the item is marked ``is_reencoded`` so ``MuxStep`` adds no further delay.
+
+With ``apply_dialog_norm_gain`` this step also probes (E-)AC3 dialnorm.
+Tracks with a non-neutral value get their decoder attenuation removed by
+mkvmerge, or, when ``dialog_norm_target_db`` is set, are re-encoded with
+the gain that puts dialogue at that level.
+
+With ``loudnorm_enabled``, audio tracks marked ``loudnorm`` in the layout
+are measured (EBU R128, first loudnorm pass) and re-encoded with the
+measured values (second pass), in the track's own codec where we can
+encode it. Lossless tracks are skipped unless ``loudnorm_allow_lossless``
+or they are re-encoded anyway (layout or silence padding). Tracks loudnorm
+can't measure (e.g. silent ones) are left as they are. Padding runs first
+and the analysis pass goes through the trim/pad filters.
+
+In the ``pad_silence`` sync mode, audio tracks with a negative delay are
+re-encoded the same way (lossless ones to FLAC), with the delay trimmed
+from the samples and silence padded to the video's duration instead of an
+``-ss`` seek (see ``vsg_core.mux.pad``).
+"""
+
+from __future__ import annotations
+
+from dataclasses import replace
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from vsg_core.models.jobs import Delays, MergePlan
+from vsg_core.mux.dialnorm import NEUTRAL_DIALNORM_DB, dialnorm_gain_db, read_dialnorm
+from vsg_core.mux.encode import AUDIO_ENCODERS, EncodeSpec
+from vsg_core.mux.loudnorm import (
+    LoudnormSpec,
+    is_lossless,
+    measure_loudness,
+    probe_sample_rate,
+)
+from vsg_core.mux.options_builder import calculate_track_delay
+from vsg_core.mux.pad import plan_silence_pad
+from vsg_core.orchestrator.steps.audio_trim import (
+    probe_duration_s,
+    probe_video_duration_s,
+)
+
+if TYPE_CHECKING:
+    from vsg_core.io.runner import CommandRunner
+    from vsg_core.models.jobs import PlanItem
+    from vsg_core.orchestrator.steps.context import Context
+
+
+class AudioEncodeStep:
+    """Converts audio tracks marked for re-encode before they are muxed."""
+
+    def run(self, ctx: Context, runner: CommandRunner) -> Context:
+        items = ctx.extracted_items or []
+        audio = [it for it in items if it.track.type == "audio"]
+        if ctx.settings.apply_dialog_norm_gain:
+            self._level_dialnorm(ctx, runner, audio)
+
+        plan = MergePlan(
+            items=items,
+            delays=ctx.delays or Delays(),
+            subtitle_delays_ms=ctx.subtitle_delays_ms,
+            delay_rounding=ctx.settings.delay_rounding,
+        )
+        # Padding first: loudnorm measures the trimmed/padded timeline
+        if ctx.settings.sync_mode == "pad_silence":
+            self._pad_silence(ctx, runner, audio, plan)
+        if ctx.settings.loudnorm_enabled:
+            self._normalize_loudness(ctx, runner, audio)
+        if not any(it.encode for it in audio):
+            return ctx
+
+        for item in audio:
+            label = _label(item)
+            if item.encode is None:
+                runner._log_message(f"[AudioEncode] {label}: copied (lossless)")
+                continue
+            if item.extracted_path is None:
+                raise RuntimeError(f"{label} has no extracted file to re-encode")
+
+            delay_ms = calculate_track_delay(plan, item)
+            # Padded tracks already have their delay in the filter chain
+            seek_ms = 0 if item.silence_padded else delay_ms
+            out_path = _encode(
+                item, seek_ms, ctx.work_dir.audio, runner, ctx.tool_paths
+            )
+            if out_path is None:
+                raise RuntimeError(
+                    f"ffmpeg failed to re-encode {label} to {item.encode.describe()}"
+                )
+            runner._log_message(
+                f"[AudioEncode] {label}: re-encoded {item.track.props.codec_id} -> "
+                f"{item.encode.describe()} (delay {delay_ms:+d}ms baked in)"
+            )
+            item.extracted_path = out_path
+            item.is_reencoded = True
+
+        return ctx
+
+    def _level_dialnorm(
+        self, ctx: Context, runner: CommandRunner, audio: list[PlanItem]
+    ) -> None:
+        target = ctx.settings.dialog_norm_target_db
+        for item in audio:
+            cid = (item.track.props.codec_id or "").upper()
+            if "AC3" not in cid or item.extracted_path is None:
+                continue
+            label = _label(item)
+            dialnorm = read_dialnorm(item.extracted_path, runner, ctx.tool_paths)
+            if dialnorm is None:
+                runner._log_message(
+                    f"[DialNorm] {label}: no dialnorm found — left untouched."
+                )
+                continue
+            item.dialnorm_db = dialnorm
+            if dialnorm == NEUTRAL_DIALNORM_DB:
+                runner._log_message(
+                    f"[DialNorm] {label}: dialnorm {dialnorm} dB is neutral — "
+                    "left untouched."
+                )
+                continue
+
+            gain = dialnorm_gain_db(dialnorm, target) if target else 0
+            if gain == 0:
+                runner._log_message(
+                    f"[DialNorm] {label}: dialnorm {dialnorm} dB — removing the "
+                    f"{NEUTRAL_DIALNORM_DB - dialnorm:+d} dB decoder attenuation "
+                    "(lossless)."
+                )
+                continue
+            codec = "eac3" if "EAC3" in cid else "ac3"
+            encode = item.encode or EncodeSpec(codec)
+            item.encode = replace(encode, gain_db=encode.gain_db + gain)
+            runner._log_message(
+                f"[DialNorm] {label}: dialnorm {dialnorm} dB, target {target} dB — "
+                f"applying {gain:+d} dB gain ({item.encode.describe()})."
+            )
+
+    def _normalize_loudness(
+        self, ctx: Context, runner: CommandRunner, audio: list[PlanItem]
+    ) -> None:
+        spec = LoudnormSpec.from_settings(ctx.settings)
+        for item in audio:
+            if not item.loudnorm or item.extracted_path is None:
+                continue
+            label = _label(item)
+            cid = item.track.props.codec_id or ""
+            lossless = is_lossless(cid, item.extracted_path, runner, ctx.tool_paths)
+            # An EncodeSpec (layout or silence padding) re-encodes it anyway
+            implicit = item.encode is None
+            if lossless and implicit and not ctx.settings.loudnorm_allow_lossless:
+                runner._log_message(
+                    f"[Loudnorm] [WARNING] {label}: {cid} is lossless — not "
+                    "normalized (enable 'Allow lossless tracks' to encode to FLAC)."
+                )
+                continue
+
+            # Measured through the filters that run before loudnorm (trim/pad)
+            measured = measure_loudness(
+                item.extracted_path,
+                spec,
+                runner,
+                ctx.tool_paths,
+                pre_filters=item.encode.filters if item.encode else (),
+            )
+            if measured is None:
+                runner._log_message(
+                    f"[Loudnorm] [WARNING] {label}: loudness could not be measured "
+                    "(silent track?) — not normalized."
+                )
+                continue
+            rate = probe_sample_rate(item.extracted_path, runner, ctx.tool_paths)
+            encode = item.encode or EncodeSpec(_reencode_codec(cid, lossless))
+            if encode.gain_db:
+                runner._log_message(
+                    f"[Loudnorm] {label}: dropping the {encode.gain_db:+g} dB gain, "
+                    "loudnorm sets the level."
+                )
+            item.encode = replace(
+                encode,
+                gain_db=0.0,
+                filters=(*encode.filters, spec.apply_filter(measured, rate)),
+            )
+            runner._log_message(
+                f"[Loudnorm] {label}: measured {measured.describe()}; "
+                f"target {spec.describe()} ({item.encode.describe()})."
+            )
+
+    def _pad_silence(
+        self,
+        ctx: Context,
+        runner: CommandRunner,
+        audio: list[PlanItem],
+        plan: MergePlan,
+    ) -> None:
+        negative = [
+            (item, delay_ms)
+            for item in audio
+            if item.extracted_path is not None
+            and (delay_ms := calculate_track_delay(plan, item)) < 0
+        ]
+        if not negative:
+            runner._log_message(
+                "[PadSilence] No negative audio delays — nothing to pad."
+            )
+            return
+        video_s = probe_video_duration_s(ctx, runner)
+        if video_s is None:
+            raise RuntimeError("Could not probe the video duration for silence padding")
+
+        for item, delay_ms in negative:
+            label = _label(item)
+            audio_s = probe_duration_s(item.extracted_path, runner)
+            if audio_s is None:
+                raise RuntimeError(f"Could not probe the duration of {label}")
+            pad = plan_silence_pad(delay_ms, audio_s, video_s)
+            cid = item.track.props.codec_id or ""
+            encode = item.encode or EncodeSpec(
+                _reencode_codec(
+                    cid, is_lossless(cid, item.extracted_path, runner, ctx.tool_paths)
+                )
+            )
+            # Trim/pad first so later filters (loudnorm) see the final timeline
+            item.encode = replace(
+                encode, filters=(pad.ffmpeg_filter(), *encode.filters)
+            )
+            item.silence_padded = True
+            runner._log_message(
+                f"[PadSilence] {label}: delay {delay_ms:+d}ms — {pad.describe()} "
+                f"({audio_s:.3f}s -> {pad.duration_s:.3f}s, video {video_s:.3f}s)."
+            )
+
+
+def _reencode_codec(codec_id: str, lossless: bool) -> str:
+    """Encoder for a re-encoded track: FLAC if lossless, else the same codec.
+
+    Lossy codecs we can't encode (DTS core, MP3, Vorbis...) become E-AC3,
+    which keeps up to 7.1.
+    """
+    if lossless:
+        return "flac"
+    cid = codec_id.upper()
+    if cid.startswith("A_EAC3"):
+        return "eac3"
+    for codec in AUDIO_ENCODERS:
+        if cid.startswith(f"A_{codec.upper()}"):
+            return codec
+    return "eac3"
+
+
+def _label(item: PlanItem) -> str:
+    tr = item.track
+    return f"{tr.source} audio track {tr.id}" + (
+        f" '{tr.props.name}'" if tr.props.name else ""
+    )
+
+
+def _encode(
+    item: PlanItem,
+    delay_ms: int,
+    temp_dir: Path,
+    runner: CommandRunner,
+    tool_paths: dict,
+) -> Path | None:
+    """Encode the item's file into ``temp_dir`` with the delay applied.
+
+    Returns the encoded file, or None on failure.
+    """
+    src, spec = Path(item.extracted_path), item.encode
+    out_path = temp_dir / f"encoded_{src.stem}.mka"
+    cmd = ["ffmpeg", "-y", "-nostdin"]
+    if delay_ms > 0:
+        cmd += ["-itsoffset", f"{delay_ms / 1000:.3f}"]
+    elif delay_ms < 0:
+        cmd += ["-ss", f"{-delay_ms / 1000:.3f}"]
+    cmd += ["-i", str(src), "-map", "0:a:0", *spec.ffmpeg_args(), str(out_path)]
+
+    result = runner.run(cmd, tool_paths)
+    if result is None or not out_path.exists():
+        return None
+    return out_path
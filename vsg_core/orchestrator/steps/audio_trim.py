@@ -15,12 +15,15 @@ from __future__ import annotations
 import json
 from typing import TYPE_CHECKING
 
+from vsg_core.models.rounding import round_delay_ms
+
 if TYPE_CHECKING:
     from collections.abc import Callable
     from pathlib import Path
 
     from vsg_core.io.runner import CommandRunner
     from vsg_core.models.jobs import Delays, PlanItem
+    from vsg_core.models.types import DelayRoundingStr
     from vsg_core.orchestrator.steps.context import Context
 
 # Audio extending past video by less than this (seconds) is left alone.
@@ -53,19 +56,14 @@ def trim_audio_to_video(
         log("[AudioTrim] No video track found — skipping.")
         return ctx
 
-    # Extracted video is often a raw elementary stream (.h264/.hevc) with no
-    # container metadata, so ffprobe can't report its duration.  Fall back to
-    # probing the source MKV which always has a container duration.
-    video_dur_s = _probe_duration_s(video_item.extracted_path, runner)
-    if video_dur_s is None:
-        source_path = ctx.sources.get(video_item.track.source)
-        if source_path:
-            video_dur_s = _probe_duration_s(source_path, runner)
+    video_dur_s = probe_video_duration_s(ctx, runner)
     if video_dur_s is None:
         log("[AudioTrim] Could not probe video duration — skipping.")
         return ctx
 
-    video_delay_s = _effective_delay_s(video_item, delays, ctx.subtitle_delays_ms)
+    video_delay_s = _effective_delay_s(
+        video_item, delays, ctx.subtitle_delays_ms, ctx.settings.delay_rounding
+    )
     video_end_s = video_dur_s + video_delay_s
 
     log(
@@ -83,11 +81,13 @@ def trim_audio_to_video(
         if item.track.source == "Source 1":
             continue
 
-        audio_dur_s = _probe_duration_s(item.extracted_path, runner)
+        audio_dur_s = probe_duration_s(item.extracted_path, runner)
         if audio_dur_s is None:
             continue
 
-        audio_delay_s = _effective_delay_s(item, delays, ctx.subtitle_delays_ms)
+        audio_delay_s = _effective_delay_s(
+            item, delays, ctx.subtitle_delays_ms, ctx.settings.delay_rounding
+        )
         audio_end_s = audio_dur_s + audio_delay_s
         overhang_s = audio_end_s - video_end_s
 
@@ -108,7 +108,7 @@ def trim_audio_to_video(
         target_dur_s = min(target_dur_s, audio_dur_s)
 
         trimmed_path = _trim_audio(
-            item.extracted_path, target_dur_s, ctx.temp_dir, runner
+            item.extracted_path, target_dur_s, ctx.work_dir.audio, runner
         )
         if trimmed_path is None:
             log(
@@ -152,7 +152,23 @@ def _find_video_item(items: list[PlanItem]) -> PlanItem | None:
     return None
 
 
-def _probe_duration_s(path: Path | str, runner: CommandRunner) -> float | None:
+def probe_video_duration_s(ctx: Context, runner: CommandRunner) -> float | None:
+    """Duration of the output's video track, or None if it can't be probed."""
+    video_item = _find_video_item(ctx.extracted_items or [])
+    if video_item is None or video_item.extracted_path is None:
+        return None
+    # Extracted video is often a raw elementary stream (.h264/.hevc) with no
+    # container metadata, so ffprobe can't report its duration.  Fall back to
+    # probing the source MKV which always has a container duration.
+    duration_s = probe_duration_s(video_item.extracted_path, runner)
+    if duration_s is None:
+        source_path = ctx.sources.get(video_item.track.source)
+        if source_path:
+            duration_s = probe_duration_s(source_path, runner)
+    return duration_s
+
+
+def probe_duration_s(path: Path | str, runner: CommandRunner) -> float | None:
     """Probe the duration of a media file via ffprobe."""
     cmd = [
         "ffprobe",
@@ -181,6 +197,7 @@ def _effective_delay_s(
     item: PlanItem,
     delays: Delays,
     subtitle_delays_ms: dict[str, float],
+    rounding: DelayRoundingStr = "nearest",
 ) -> float:
     """Calculate the mkvmerge delay for a track, in seconds.
 
@@ -189,17 +206,23 @@ def _effective_delay_s(
     """
     tr = item.track
 
+    if item.is_reencoded:
+        return 0.0
+
     if tr.source == "Source 1" and tr.type == "video":
         return delays.global_shift_ms / 1000.0
 
     if tr.source == "Source 1" and tr.type == "audio":
-        return (round(item.container_delay_ms) + delays.global_shift_ms) / 1000.0
+        return (
+            round_delay_ms(item.container_delay_ms, rounding)
+            + delays.global_shift_ms
+        ) / 1000.0
 
     # Stepping-corrected tracks with pre-baked alignment: mkvmerge only applies
     # Source 1's audio-container delay (stashed in container_delay_ms) — the
     # correlation shift is already in the FLAC samples.  Mirror options_builder.
     if item.is_pre_aligned:
-        return round(item.container_delay_ms) / 1000.0
+        return round_delay_ms(item.container_delay_ms, rounding) / 1000.0
 
     # Subtitles with baked-in timing get 0 delay
     if tr.type == "subtitles" and (item.stepping_adjusted or item.frame_adjusted):
@@ -210,9 +233,11 @@ def _effective_delay_s(
         return 0.0
 
     if tr.type == "subtitles" and sync_key in subtitle_delays_ms:
-        return round(subtitle_delays_ms[sync_key]) / 1000.0
+        return round_delay_ms(subtitle_delays_ms[sync_key], rounding) / 1000.0
 
-    return round(delays.source_delays_ms.get(sync_key, 0)) / 1000.0
+    return (
+        round_delay_ms(delays.source_delays_ms.get(sync_key, 0), rounding) / 1000.0
+    )
 
 
 def _trim_audio(
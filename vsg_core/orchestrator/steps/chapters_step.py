@@ -260,7 +260,7 @@ class ChaptersStep:
         try:
             xml_path = process_chapters(
                 donor_file,
-                ctx.temp_dir,
+                ctx.work_dir.extracted,
                 runner,
                 ctx.tool_paths,
                 ctx.settings,
@@ -287,7 +287,7 @@ class ChaptersStep:
                 )
                 xml_path = process_chapters(
                     source1_file,
-                    ctx.temp_dir,
+                    ctx.work_dir.extracted,
                     runner,
                     ctx.tool_paths,
                     ctx.settings,
@@ -4,12 +4,23 @@ from __future__ import annotations
 from dataclasses import dataclass, field
 from typing import TYPE_CHECKING
 
+from vsg_core.orchestrator.work_dir import WorkDir
+
 if TYPE_CHECKING:
     from collections.abc import Callable
     from pathlib import Path
 
+    from vsg_core.analysis.correlation.audio_cache import DecodedAudioCache
+    from vsg_core.analysis.correlation.coarse import CoarseAlignment
+    from vsg_core.analysis.correlation.curve import CorrelationCurve
+    from vsg_core.analysis.correlation.dtw import DtwResult
+    from vsg_core.analysis.multi_corr import MultiCorrReport
+    from vsg_core.analysis.report import AnalysisReport
+    from vsg_core.analysis.timings import AnalysisTimings
+    from vsg_core.analysis.track_consensus import TrackConsensusReport
     from vsg_core.audit import AuditTrail
     from vsg_core.correction.stepping import AudioSegment
+    from vsg_core.extraction.stats import TrackStats
     from vsg_core.models.context_types import (
         ChapterSourceOutcome,
         DriftFlagsEntry,
@@ -23,12 +34,15 @@ if TYPE_CHECKING:
     )
     from vsg_core.models.jobs import Delays, PlanItem
     from vsg_core.models.settings import AppSettings
+    from vsg_core.pipeline_components.progress_tracker import ProgressSlice
+    from vsg_core.postprocess.auditors import AuditIssue
     from vsg_core.reporting import DebugOutputPaths
     from vsg_core.subtitles.frame_utils.frame_audit import FrameAuditResult
     from vsg_core.subtitles.operations.bitmap_audit import BitmapAuditResult
     from vsg_core.subtitles.operations.duration_audit import (
         SubtitleDurationAuditResult,
     )
+    from vsg_core.subtitles.operations.lint import SubtitleLintResult
 
 
 @dataclass(slots=True)
@@ -43,9 +57,18 @@ class Context:
     audit: AuditTrail | None = None  # Pipeline audit trail for debugging
     debug_paths: DebugOutputPaths | None = None  # Debug output paths for this job
     sources: dict[str, str] = field(default_factory=dict)
+    # The user's own file per source (first part of a multi-part source),
+    # before joining or normalizing: names the job, its output and sidecar
+    source_inputs: dict[str, str] = field(default_factory=dict)
+    # Sources NormalizeStep replaced by an MKV remux (key -> remuxed file);
+    # ``sources`` already points at these
+    normalized_sources: dict[str, str] = field(default_factory=dict)
     and_merge: bool = False
     manual_layout: list[ManualLayoutItem] = field(default_factory=list)
     attachment_sources: list[str] = field(default_factory=list)
+    # Optional per-source subset of attachments (ids or file names) to
+    # re-attach; sources without an entry contribute all of their fonts.
+    attachment_selection: dict[str, list[str]] = field(default_factory=dict)
 
     # Source key whose chapters get used in the final mux. Defaults to
     # "Source 1" (preserve existing behavior). Other values: "Source 2",
@@ -73,6 +96,8 @@ class Context:
     extracted_items: list[PlanItem] | None = None
     chapters_xml: str | None = None
     attachments: list[str] | None = None
+    # MIME type each attachment was declared with in its source (by path)
+    attachment_mime_types: dict[str, str] = field(default_factory=dict)
 
     # Stores flags for tracks that need segmented (stepping) correction
     # Key format: "{source}_{track_id}" e.g. "Source 2_1"
@@ -95,7 +120,8 @@ class Context:
     # A flag to determine if a global shift is necessary
     global_shift_is_required: bool = False
 
-    # Timing sync mode ('positive_only', 'allow_negative', or 'preserve_existing')
+    # Timing sync mode ('positive_only', 'allow_negative', 'pad_silence' or
+    # 'preserve_existing')
     sync_mode: str = "positive_only"
 
     # NEW: Track which sources had stepping detected (for final report)
@@ -143,12 +169,64 @@ class Context:
         default_factory=dict
     )
 
+    # Text subtitle event timing problems (zero durations, ...) found after
+    # sync. Rendered by SubtitleLintAuditor.
+    # Format: {"External_t0": SubtitleLintResult, ...}
+    subtitle_lint_results: dict[str, SubtitleLintResult] = field(default_factory=dict)
+
     # Cached video properties per source (detected on first access)
     # Used to gate video-verified frame matching: MPEG-2 and interlaced
     # sources skip frame matching and use audio correlation directly.
     # Format: {"Source 1": {fps, content_type, is_dvd, ...}, "Source 2": {...}}
     video_properties: dict[str, dict] = field(default_factory=dict)
 
+    # Machine-readable analysis report (per-source delays + chunk table).
+    # Populated by AnalysisStep; written to disk when reporting is enabled.
+    analysis_report: AnalysisReport | None = None
+
+    # Per-method agreement for each source when multi-correlation is run
+    multi_corr_reports: dict[str, MultiCorrReport] = field(default_factory=dict)
+
+    # Per-track delays and their consensus when multi-track consensus is on
+    track_consensus_reports: dict[str, TrackConsensusReport] = field(
+        default_factory=dict
+    )
+
+    # Share of the progress bar that AnalysisStep fills, set by the
+    # Orchestrator; each source gets an equal slice of it
+    analysis_progress: ProgressSlice | None = None
+
+    # Per-phase wall-clock time of each source's audio analysis
+    analysis_timings: dict[str, AnalysisTimings] = field(default_factory=dict)
+
+    # Correlation curves per source (when correlation_curves is not "off")
+    correlation_curves: dict[str, list[CorrelationCurve]] = field(
+        default_factory=dict
+    )
+
+    # DTW warping path per source (when dtw_enabled); input for piecewise
+    # timing correction
+    dtw_results: dict[str, DtwResult] = field(default_factory=dict)
+
+    # Whole-file coarse offset per source (when coarse_prealign is on)
+    coarse_alignments: dict[str, CoarseAlignment] = field(default_factory=dict)
+
     # Results/summaries
     out_file: str | None = None
     tokens: list[str] | None = None
+    # Output filename rendered from output_template by MuxStep (None = keep
+    # Source 1's name)
+    output_name: str | None = None
+
+    # Decoded PCM shared by analysis and stepping correction (created on
+    # first use when analysis_audio_cache is on)
+    audio_cache: DecodedAudioCache | None = None
+
+    # Filled by VerifyStep after mux (when verify_output is on)
+    track_stats: list[TrackStats] = field(default_factory=list)
+    verify_issues: list[AuditIssue] = field(default_factory=list)
+
+    @property
+    def work_dir(self) -> WorkDir:
+        """Layout of ``temp_dir`` (extracted/, audio/, indexes/, logs/)."""
+        return WorkDir(self.temp_dir)
@@ -0,0 +1,180 @@
+# vsg_core/orchestrator/steps/dovi_step.py
+"""
+Optional pre-mux step: Dolby Vision RPU extraction and injection.
+
+Extracts the RPU from Source 1 with dovi_tool (optionally converting it to
+Profile 8.1 via mode 2) and injects it into the output video track's
+extracted HEVC stream. Runs after extraction and before mux so that
+``MuxStep`` picks up the injected file through ``extracted_path``.
+
+Gated behind ``AppSettings.dovi_inject`` (off by default).
+"""
+
+from __future__ import annotations
+
+import re
+from typing import TYPE_CHECKING
+
+from vsg_core.errors import ProcessFailed, ToolMissing
+from vsg_core.mux.dovi import DoviConfig
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from vsg_core.io.runner import CommandRunner
+    from vsg_core.models.jobs import PlanItem
+    from vsg_core.orchestrator.steps.context import Context
+
+_HEVC_CODEC = "V_MPEGH/ISO/HEVC"
+_PROFILE_RE = re.compile(r"Profile:\s*(\d+(?:\.\d+)?)")
+
+
+class DoviStep:
+    """Carries Source 1's Dolby Vision RPU over to the output video track."""
+
+    def run(self, ctx: Context, runner: CommandRunner) -> Context:
+        config = DoviConfig.from_settings(ctx.settings)
+        if not config.inject:
+            return ctx
+
+        dovi_tool = ctx.tool_paths.get("dovi_tool")
+        if not dovi_tool:
+            raise ToolMissing(
+                "Dolby Vision injection is enabled but 'dovi_tool' was not found "
+                "in PATH. Install dovi_tool or disable the option.",
+                "dovi_tool",
+            )
+
+        video_item = _find_video_item(ctx.extracted_items or [])
+        if video_item is None or video_item.extracted_path is None:
+            runner._log_message("[DoVi] No extracted video track — skipping.")
+            return ctx
+        if _HEVC_CODEC not in video_item.track.props.codec_id.upper():
+            runner._log_message(
+                f"[DoVi] Output video is {video_item.track.props.codec_id}, "
+                "not HEVC — skipping."
+            )
+            return ctx
+
+        ref_file = ctx.sources.get("Source 1")
+        if not ref_file:
+            runner._log_message("[DoVi] No Source 1 file — skipping.")
+            return ctx
+
+        if video_item.track.source != "Source 1" and ctx.delays:
+            delay = ctx.delays.source_delays_ms.get(video_item.track.source, 0)
+            if delay:
+                runner._log_message(
+                    f"[DoVi] [WARNING] Video is from {video_item.track.source} "
+                    f"with a {delay:+d}ms delay; the RPU is applied frame-by-frame "
+                    "and may be misaligned."
+                )
+
+        rpu_path = ctx.work_dir.extracted / "dovi_source1_rpu.bin"
+        if not _extract_rpu(ref_file, rpu_path, config, ctx, runner):
+            runner._log_message(
+                "[DoVi] Source 1 has no Dolby Vision RPU — skipping injection."
+            )
+            return ctx
+
+        profile = _rpu_profile(rpu_path, ctx, runner)
+        runner._log_message(
+            f"[DoVi] Extracted RPU from Source 1 (profile {profile or 'unknown'})."
+        )
+
+        src = video_item.extracted_path
+        out_path = src.with_name(f"{src.stem}_dovi{src.suffix}")
+        cmd = [
+            "dovi_tool",
+            "inject-rpu",
+            "-i",
+            str(src),
+            "--rpu-in",
+            str(rpu_path),
+            "-o",
+            str(out_path),
+        ]
+        if runner.run(cmd, ctx.tool_paths) is None or not out_path.exists():
+            raise ProcessFailed("dovi_tool inject-rpu failed.", runner.last_failure)
+
+        video_item.extracted_path = out_path
+        runner._log_message(
+            f"[DoVi] Injected RPU into {video_item.track.source} video "
+            f"(track {video_item.track.id}); output profile "
+            f"{profile or 'unknown'}."
+        )
+        return ctx
+
+
+def _find_video_item(items: list[PlanItem]) -> PlanItem | None:
+    """Return the first non-preserved video PlanItem."""
+    for item in items:
+        if item.track.type == "video" and not item.is_preserved:
+            return item
+    return None
+
+
+def _extract_rpu(
+    ref_file: str,
+    rpu_path: Path,
+    config: DoviConfig,
+    ctx: Context,
+    runner: CommandRunner,
+) -> bool:
+    """Extract the RPU from Source 1's HEVC stream with ``dovi_tool``.
+
+    ffmpeg first copies the stream to an Annex B file next to the RPU
+    (dovi_tool reads raw HEVC only), then dovi_tool reads that file. Both
+    go through the runner, so they are logged and a failure is recorded
+    in ``runner.last_failure``. Raises ProcessFailed when ffmpeg can't
+    copy the stream; returns False when the stream carries no RPU
+    (dovi_tool fails or writes an empty file).
+    """
+    hevc_path = rpu_path.with_name("dovi_source1.hevc")
+    ffmpeg_cmd = [
+        "ffmpeg",
+        "-hide_banner",
+        "-nostdin",
+        "-y",
+        "-v",
+        "error",
+        "-i",
+        str(ref_file),
+        "-map",
+        "0:v:0",
+        "-c:v",
+        "copy",
+        "-bsf:v",
+        "hevc_mp4toannexb",
+        "-f",
+        "hevc",
+        str(hevc_path),
+    ]
+    dovi_cmd = ["dovi_tool"]
+    if config.conversion_mode is not None:
+        dovi_cmd += ["-m", str(config.conversion_mode)]
+    dovi_cmd += ["extract-rpu", "-i", str(hevc_path), "-o", str(rpu_path)]
+
+    try:
+        copied = runner.run_with_retry(ffmpeg_cmd, ctx.tool_paths)
+        if copied is None or not hevc_path.is_file():
+            raise ProcessFailed(
+                "Could not read Source 1's video stream for RPU extraction.",
+                runner.last_failure,
+            )
+        if runner.run(dovi_cmd, ctx.tool_paths) is None:
+            return False
+    finally:
+        hevc_path.unlink(missing_ok=True)
+    return rpu_path.exists() and rpu_path.stat().st_size > 0
+
+
+def _rpu_profile(rpu_path: Path, ctx: Context, runner: CommandRunner) -> str | None:
+    """Read the DV profile from ``dovi_tool info --summary``."""
+    out = runner.run(
+        ["dovi_tool", "info", "-i", str(rpu_path), "--summary"], ctx.tool_paths
+    )
+    if not isinstance(out, str):
+        return None
+    match = _PROFILE_RE.search(out)
+    return match.group(1) if match else None
@@ -8,6 +8,8 @@ from typing import TYPE_CHECKING, Any
 from vsg_core.extraction.tracks import extract_tracks, get_stream_info_with_delays
 from vsg_core.models.jobs import PlanItem
 from vsg_core.models.media import StreamProps, Track
+from vsg_core.mux.encode import EncodeSpec
+from vsg_core.mux.sync_targets import check_sync_targets
 
 if TYPE_CHECKING:
     from vsg_core.io.runner import CommandRunner
@@ -39,6 +41,10 @@ class ExtractStep:
         # Update the context with the filtered layout
         ctx.manual_layout = filtered_layout
 
+        # A 'Sync to Source' that doesn't resolve would silently give the
+        # track no delay
+        check_sync_targets(ctx.manual_layout, ctx.sources.keys())
+
         # --- Read container delays for all sources ---
         runner._log_message("--- Reading Container Delays from Source Files ---")
 
@@ -151,7 +157,7 @@ class ExtractStep:
                 runner._log_message(f"  [DEBUG] Track IDs: {track_ids_to_extract}")
                 extracted_for_source = extract_tracks(
                     str(source_path),
-                    ctx.temp_dir,
+                    ctx.work_dir.extracted,
                     runner,
                     ctx.tool_paths,
                     role=source_key,
@@ -170,7 +176,7 @@ class ExtractStep:
 
             if source == "External":
                 original_path = Path(sel["original_path"])
-                temp_path = ctx.temp_dir / original_path.name
+                temp_path = ctx.work_dir.extracted / original_path.name
                 shutil.copy(original_path, temp_path)
 
                 track_model = Track(
@@ -207,6 +213,7 @@ class ExtractStep:
                         codec_id=trk.get("codec_id", "") or "",
                         lang=trk.get("lang", "und") or "und",
                         name=trk.get("name", "") or "",
+                        audio_channels=trk.get("audio_channels") or None,
                     ),
                 )
 
@@ -231,6 +238,7 @@ class ExtractStep:
 
             plan_item.is_default = bool(sel.get("is_default", False))
             plan_item.is_forced_display = bool(sel.get("is_forced_display", False))
+            plan_item.manual_default = bool(sel.get("manual_default", False))
             plan_item.apply_track_name = bool(sel.get("apply_track_name", False))
             plan_item.perform_ocr = bool(sel.get("perform_ocr", False))
             plan_item.convert_to_ass = bool(sel.get("convert_to_ass", False))
@@ -255,6 +263,18 @@ class ExtractStep:
                 "custom_lang", ""
             )  # Preserve custom language
             plan_item.custom_name = sel.get("custom_name", "")  # Preserve custom name
+            encode = sel.get("encode")
+            if encode and plan_item.track.type == "audio":
+                try:
+                    plan_item.encode = EncodeSpec.from_dict(encode)
+                except ValueError as e:
+                    raise ValueError(
+                        f"Invalid re-encode settings for {source} audio track "
+                        f"{plan_item.track.id}: {e}"
+                    ) from e
+            plan_item.loudnorm = plan_item.track.type == "audio" and bool(
+                sel.get("loudnorm", False)
+            )
 
             # Generated track fields
             plan_item.is_generated = bool(sel.get("is_generated", False))
@@ -267,7 +287,7 @@ class ExtractStep:
         # --- Part 3: Process generated tracks (filter subtitle styles) ---
         runner._log_message("--- Processing Generated Tracks ---")
         failed_generated_tracks = self._process_generated_tracks(
-            items, runner, ctx.temp_dir
+            items, runner, ctx.work_dir.extracted
         )
 
         # Remove any generated tracks that failed processing (have no extracted_path)
@@ -1,11 +1,28 @@
 # vsg_core/orchestrator/steps/mux_step.py
 from __future__ import annotations
 
+from dataclasses import replace
 from pathlib import Path
 from typing import TYPE_CHECKING
 
+from vsg_core.extraction.color import probe_color, probe_hdr10
+from vsg_core.extraction.tracks import get_stream_info, video_height
 from vsg_core.models.jobs import Delays, MergePlan
-from vsg_core.mux.options_builder import MkvmergeOptionsBuilder
+from vsg_core.mux import reproducible
+from vsg_core.mux.attachment_mime import fix_attachment_mime
+from vsg_core.mux.color import ColorPolicy
+from vsg_core.mux.flags import DefaultLanguage, FlagPolicy
+from vsg_core.mux.options_builder import MkvmergeOptionsBuilder, final_track_order
+from vsg_core.mux.output_name import output_name_fields, render_output_name
+from vsg_core.mux.split import SplitSpec, count_chapters
+from vsg_core.mux.tags import SourceTags, TagPolicy
+from vsg_core.mux.uids import uid_seed
+from vsg_core.subtitles.ass_fonts import (
+    FontRef,
+    collect_fonts,
+    match_fonts,
+    subset_font,
+)
 
 if TYPE_CHECKING:
     from vsg_core.io.runner import CommandRunner
@@ -18,20 +35,323 @@ class MuxStep:
     """
 
     def run(self, ctx: Context, runner: CommandRunner) -> Context:
+        policy = ColorPolicy.from_settings(ctx.settings)
+        if policy.enabled:
+            self._apply_color_policy(ctx, runner, policy)
+        self._carry_hdr10(ctx, runner)
+        self._collect_fonts(ctx, runner)
+
+        default_lang = DefaultLanguage.from_settings(ctx.settings)
+        if default_lang.enabled:
+            changes, warnings = default_lang.apply(ctx.extracted_items or [])
+            for change in changes:
+                runner._log_message(f"[Default Lang] {change}")
+            for warning in warnings:
+                runner._log_message(f"[Default Lang] [WARNING] {warning}")
+
+        flag_policy = FlagPolicy.from_settings(ctx.settings)
+        if flag_policy.enabled:
+            changes = flag_policy.apply(ctx.extracted_items or [])
+            for change in changes:
+                runner._log_message(f"[Flags] {change}")
+            if not changes:
+                runner._log_message("[Flags] Track flags already follow the policy.")
+
         plan = MergePlan(
             items=ctx.extracted_items or [],
             delays=ctx.delays or Delays(),
             chapters_xml=Path(ctx.chapters_xml) if ctx.chapters_xml else None,
             attachments=[Path(a) for a in (ctx.attachments or [])],
             subtitle_delays_ms=ctx.subtitle_delays_ms,
+            delay_rounding=ctx.settings.delay_rounding,
         )
+        if ctx.settings.fix_attachment_mime:
+            mime_types = self._fix_attachment_mime(ctx, runner, plan.attachments)
+            plan = replace(plan, attachment_mime_types=mime_types)
+        plan = self._apply_tag_policy(ctx, runner, plan)
+        if ctx.settings.reproducible_output:
+            plan = replace(plan, reproducible=True)
+            for line in reproducible.describe():
+                runner._log_message(f"[Reproducible] {line}")
+        if ctx.settings.deterministic_uids or ctx.settings.reproducible_output:
+            seed = uid_seed(final_track_order(plan), ctx.sources)
+            plan = replace(plan, uid_seed=seed)
+            runner._log_message(
+                f"[UIDs] Deterministic UIDs (mkvmerge --deterministic {seed})."
+            )
 
         builder = MkvmergeOptionsBuilder()
         # FIX: The builder no longer needs the output path.
         # The --output flag will be added later by the JobPipeline.
         tokens = builder.build(plan, ctx.settings, audit=ctx.audit)
+        tokens += self._split_args(ctx, runner)
 
         # The pipeline will now determine the final output file
         ctx.out_file = None
         ctx.tokens = tokens
+        if ctx.settings.output_template:
+            ctx.output_name = self._render_output_name(ctx, runner)
         return ctx
+
+    def _render_output_name(self, ctx: Context, runner: CommandRunner) -> str:
+        """Output filename from ``output_template`` and the user's source files."""
+        template = ctx.settings.output_template
+        height = None
+        if "{resolution}" in template:
+            height = self._source1_height(ctx, runner)
+        fields = output_name_fields(ctx.source_inputs, height)
+        name = render_output_name(template, fields, runner._log_message)
+        runner._log_message(f"[Output Name] {template!r} -> {name}")
+        return name
+
+    def _apply_tag_policy(
+        self, ctx: Context, runner: CommandRunner, plan: MergePlan
+    ) -> MergePlan:
+        """``plan`` with title, global tags and tag stripping per ``tag_policy``."""
+        policy = TagPolicy.from_settings(ctx.settings)
+        source = self._source1_tags(ctx, runner)
+        for line in policy.describe(source):
+            runner._log_message(f"[Tags] {line}")
+
+        tags_path = None
+        if policy.keeps_tags and source.global_tags_xml:
+            tags_path = ctx.temp_dir / "source1_global_tags.xml"
+            tags_path.write_text(source.global_tags_xml, encoding="utf-8")
+
+        fields: dict[str, str] = {}
+        if policy.title_template:
+            height = None
+            if "{resolution}" in policy.title_template:
+                height = self._source1_height(ctx, runner)
+            fields = output_name_fields(ctx.source_inputs, height)
+        title = policy.title(source, fields, runner._log_message)
+        if policy.title_template:
+            runner._log_message(
+                f"[Tags] {policy.title_template!r} -> {title or '(no title)'!r}"
+            )
+        elif title:
+            runner._log_message(f"[Tags] Output title: {title!r}")
+        return replace(
+            plan,
+            title=title,
+            global_tags_xml=tags_path,
+            strip_input_tags=not policy.keeps_tags,
+        )
+
+    def _source1_tags(self, ctx: Context, runner: CommandRunner) -> SourceTags:
+        """Segment title and tags of Source 1 (empty if not Matroska)."""
+        source1 = ctx.sources["Source 1"]
+        info = get_stream_info(source1, runner, ctx.tool_paths) or {}
+        container = info.get("container", {})
+        if container.get("type") != "Matroska":
+            return SourceTags()
+        title = container.get("properties", {}).get("title", "")
+        tags_xml = runner.run(["mkvextract", str(source1), "tags", "-"], ctx.tool_paths)
+        return SourceTags.parse(title, tags_xml if isinstance(tags_xml, str) else None)
+
+    def _source1_height(self, ctx: Context, runner: CommandRunner) -> int | None:
+        return video_height(ctx.sources["Source 1"], runner, ctx.tool_paths)
+
+    def _fix_attachment_mime(
+        self, ctx: Context, runner: CommandRunner, attachments: list[Path]
+    ) -> dict[Path, str]:
+        """MIME types to force on attachments whose declared type is wrong."""
+        corrections = fix_attachment_mime(
+            {a: ctx.attachment_mime_types.get(str(a), "") for a in attachments}
+        )
+        for c in corrections:
+            runner._log_message(f"[Attachments] MIME type corrected: {c.describe()}")
+        return {c.path: c.mime for c in corrections}
+
+    def _split_args(self, ctx: Context, runner: CommandRunner) -> list[str]:
+        """``--split`` tokens; chapter splitting requires output chapters."""
+        spec = SplitSpec.from_settings(ctx.settings)
+        if not spec.enabled:
+            return []
+        chapter_count = 0
+        if spec.mode == "chapters":
+            chapter_count = count_chapters(ctx.chapters_xml)
+            if chapter_count == 0:
+                raise RuntimeError(
+                    "Chapter-based output splitting is enabled but the output "
+                    "has no chapters."
+                )
+        args = spec.mkvmerge_args(chapter_count)
+        if args:
+            runner._log_message(f"[Split] Splitting output {spec.describe()}.")
+        else:
+            runner._log_message(
+                f"[Split] Only {chapter_count} chapter(s) — output stays one file."
+            )
+        return args
+
+    def _apply_color_policy(
+        self, ctx: Context, runner: CommandRunner, policy: ColorPolicy
+    ) -> None:
+        """Probe each output video track and store container flag overrides."""
+        for item in ctx.extracted_items or []:
+            tr = item.track
+            if tr.type != "video" or item.is_preserved or tr.source == "External":
+                continue
+            source_path = ctx.sources.get(tr.source)
+            if not source_path:
+                continue
+            container = probe_color(source_path, runner, ctx.tool_paths, tr.id)
+            if container is None:
+                runner._log_message(
+                    f"[Color] Could not probe {tr.source} video track {tr.id} "
+                    "— leaving colour flags untouched."
+                )
+                continue
+            bitstream = None
+            if item.extracted_path:
+                bitstream = probe_color(item.extracted_path, runner, ctx.tool_paths)
+
+            fix = policy.evaluate(container, bitstream)
+            label = f"{tr.source} video track {tr.id}"
+            for name in fix.disagreements:
+                runner._log_message(
+                    f"[Color] [WARNING] {label}: container {name} "
+                    f"'{getattr(container, name)}' disagrees with bitstream "
+                    f"'{getattr(bitstream, name)}'."
+                )
+            if not fix.overrides:
+                runner._log_message(
+                    f"[Color] {label} ({fix.color_class.upper()}): flags already "
+                    f"correct ({container.describe()})."
+                )
+                continue
+            item.colour_overrides = fix.overrides
+            runner._log_message(
+                f"[Color] {label} ({fix.color_class.upper()}): "
+                f"before: {fix.before.describe()}"
+            )
+            runner._log_message(
+                f"[Color] {label} ({fix.color_class.upper()}): "
+                f"after:  {fix.after.describe()}"
+            )
+
+    def _carry_hdr10(self, ctx: Context, runner: CommandRunner) -> None:
+        """Re-attach HDR10 static metadata lost by extracting the video stream.
+
+        Only the video-bearing source's own metadata is carried; Source 1
+        (the reference) is probed just to log a disagreement. A video without
+        metadata (SDR content) is skipped silently.
+        """
+        ref_path = ctx.sources.get("Source 1")
+        ref_meta = None
+        ref_probed = False
+        for item in ctx.extracted_items or []:
+            tr = item.track
+            if tr.type != "video" or item.is_preserved or tr.source == "External":
+                continue
+            source_path = ctx.sources.get(tr.source)
+            if not source_path:
+                continue
+            meta = probe_hdr10(source_path, runner, ctx.tool_paths, tr.id)
+            if tr.source != "Source 1" and ref_path:
+                if not ref_probed:
+                    ref_meta = probe_hdr10(ref_path, runner, ctx.tool_paths)
+                    ref_probed = True
+                if meta and ref_meta and meta != ref_meta:
+                    runner._log_message(
+                        f"[HDR10] [WARNING] {tr.source} and Source 1 disagree: "
+                        f"{meta.describe()} vs {ref_meta.describe()}. "
+                        f"Using {tr.source} (video-bearing source)."
+                    )
+                elif meta is None and ref_meta:
+                    runner._log_message(
+                        f"[HDR10] [WARNING] Source 1 has HDR10 metadata but the "
+                        f"{tr.source} video has none; not carrying Source 1's."
+                    )
+            if meta is None:
+                continue
+            item.hdr10 = meta
+            runner._log_message(
+                f"[HDR10] {tr.source} video track {tr.id}: {meta.describe()}"
+            )
+
+    def _collect_fonts(self, ctx: Context, runner: CommandRunner) -> None:
+        """Check that every font used by the ASS tracks is attached.
+
+        Fonts are matched against the attachments gathered from the chosen
+        attachment sources (and Font Manager replacements). Referenced
+        fonts with no match are reported; with ``subtitle_font_subsetting``
+        each matched font is replaced by a copy holding only the glyphs the
+        scripts use.
+        """
+        from vsg_core.font_manager import FontScanner
+        from vsg_core.subtitles.data import SubtitleData
+
+        refs: dict[str, FontRef] = {}
+        for item in ctx.extracted_items or []:
+            path = item.extracted_path
+            if item.track.type != "subtitles" or not path:
+                continue
+            if Path(path).suffix.lower() not in (".ass", ".ssa"):
+                continue
+            try:
+                data = SubtitleData.from_file(path)
+            except Exception as e:
+                runner._log_message(
+                    f"[Fonts] [WARNING] Could not read {Path(path).name}: {e}"
+                )
+                continue
+            for ref in collect_fonts(data):
+                prev = refs.get(ref.key)
+                if prev is not None:
+                    ref = replace(
+                        prev,
+                        styles=prev.styles
+                        + tuple(s for s in ref.styles if s not in prev.styles),
+                        inline=prev.inline or ref.inline,
+                        chars=prev.chars | ref.chars,
+                    )
+                refs[ref.key] = ref
+        if not refs:
+            return
+
+        font_files = [
+            Path(a)
+            for a in ctx.attachments or []
+            if Path(a).suffix.lower() in FontScanner.FONT_EXTENSIONS
+        ]
+        matches, missing = match_fonts(list(refs.values()), font_files)
+        runner._log_message(
+            f"[Fonts] Subtitles use {len(refs)} font(s); "
+            f"{len(matches)} found in attachments."
+        )
+        for ref in missing:
+            used_by = ", ".join(ref.styles) if ref.styles else "inline \\fn"
+            runner._log_message(
+                f"[Fonts] [WARNING] Font '{ref.name}' ({used_by}) has no matching "
+                "attachment; players will substitute a system font."
+            )
+
+        if not ctx.settings.subtitle_font_subsetting or not matches:
+            return
+        chars_by_file: dict[Path, set[str]] = {}
+        for key, files in matches.items():
+            for f in files:
+                chars_by_file.setdefault(f, set()).update(refs[key].chars)
+
+        out_dir = ctx.temp_dir / "font_subsets"
+        subset_paths: dict[str, str] = {}
+        for font_file, chars in chars_by_file.items():
+            subset = subset_font(font_file, chars, out_dir)
+            if subset is None:
+                runner._log_message(
+                    f"[Fonts] Keeping full font {font_file.name} "
+                    "(format not subsettable or fontTools unavailable)."
+                )
+                continue
+            subset_paths[str(font_file)] = str(subset)
+            declared = ctx.attachment_mime_types.get(str(font_file))
+            if declared is not None:
+                ctx.attachment_mime_types[str(subset)] = declared
+            runner._log_message(
+                f"[Fonts] Subset {font_file.name}: {len(chars)} glyph(s), "
+                f"{font_file.stat().st_size // 1024} KiB -> "
+                f"{subset.stat().st_size // 1024} KiB"
+            )
+        ctx.attachments = [subset_paths.get(a, a) for a in ctx.attachments]
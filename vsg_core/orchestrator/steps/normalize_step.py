@@ -0,0 +1,52 @@
+# vsg_core/orchestrator/steps/normalize_step.py
+"""
+Optional first step: remux non-MKV sources to MKV.
+
+Runs before analysis so every later step (analysis, extraction, chapters,
+attachments, mux) reads Matroska. Each source that isn't already Matroska
+is stream-copied into the work dir's ``normalized/`` (see
+``vsg_core.extraction.normalize``); ``ctx.sources`` then points at the
+remuxed file and ``ctx.normalized_sources`` records which sources were
+replaced, by what.
+
+Gated behind ``AppSettings.normalize_to_mkv`` (off by default).
+"""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from vsg_core.extraction.normalize import is_matroska, remux_to_mkv
+
+if TYPE_CHECKING:
+    from vsg_core.io.runner import CommandRunner
+    from vsg_core.orchestrator.steps.context import Context
+
+
+class NormalizeStep:
+    """Replaces non-MKV sources by an MKV stream copy in the work dir."""
+
+    def run(self, ctx: Context, runner: CommandRunner) -> Context:
+        if not ctx.settings.normalize_to_mkv:
+            return ctx
+
+        for source_key, path in list(ctx.sources.items()):
+            if not path or is_matroska(path):
+                continue
+            out = remux_to_mkv(
+                source_key, path, ctx.work_dir.normalized, runner, ctx.tool_paths
+            )
+            ctx.sources[source_key] = str(out)
+            ctx.normalized_sources[source_key] = str(out)
+            if ctx.audit:
+                ctx.audit.record_source(source_key, str(out), normalized_from=path)
+
+        if not ctx.normalized_sources:
+            runner._log_message("[Normalize] All sources are already MKV.")
+        else:
+            names = ", ".join(
+                f"{k} ({Path(p).name})" for k, p in ctx.normalized_sources.items()
+            )
+            runner._log_message(f"[Normalize] Using remuxed MKV for {names}.")
+        return ctx
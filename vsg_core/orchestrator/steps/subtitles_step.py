@@ -187,6 +187,8 @@ class SubtitlesStep:
                 subtitle_sync_mode != "time-based"
             )  # Non-time-based modes need SubtitleData
             or use_raw_values  # Raw values mode applies delay in SubtitleData
+            or ctx.settings.time_based_frame_accurate  # Frame bake needs events
+            or ctx.settings.subtitle_retime_src_fps > 0  # Retime needs SubtitleData
             or (
                 item.track.source in ctx.stepping_edls
                 and ctx.settings.stepping_adjust_subtitles
@@ -0,0 +1,132 @@
+# vsg_core/orchestrator/steps/verify_step.py
+"""
+Optional post-mux step: verify the written file.
+
+Unlike the other steps this one runs after mkvmerge, on ``ctx.out_file``.
+It measures every output track (duration, codec, bitrate, size) with
+``probe_track_stats``, logs them as a table and flags tracks that look
+wrong. It then reads each track's container delay with ``mkvmerge -J``
+and compares it with the planned ``--sync`` delay, so regressions in the
+delay math (or mkvmerge quirks) don't go unnoticed. Results are stored on
+the context for the batch report.
+
+Gated behind ``AppSettings.verify_output`` (off by default).
+"""
+
+from __future__ import annotations
+
+from dataclasses import replace
+from typing import TYPE_CHECKING
+
+from vsg_core.extraction.stats import find_anomalies, probe_track_stats
+from vsg_core.extraction.tracks import get_stream_info_with_delays
+from vsg_core.models.jobs import Delays, MergePlan
+from vsg_core.mux.options_builder import calculate_track_delay, final_track_order
+from vsg_core.postprocess.auditors import AuditIssue
+
+if TYPE_CHECKING:
+    from vsg_core.io.runner import CommandRunner
+    from vsg_core.orchestrator.steps.context import Context
+
+
+class VerifyStep:
+    """Post-mux QC of the output file."""
+
+    def run(self, ctx: Context, runner: CommandRunner) -> Context:
+        if not ctx.settings.verify_output or not ctx.out_file:
+            return ctx
+
+        runner._log_message("--- Post-Merge: Verifying Output ---")
+        self._check_stats(ctx, runner)
+        self._check_delays(ctx, runner)
+        return ctx
+
+    def _check_stats(self, ctx: Context, runner: CommandRunner) -> None:
+        stats = probe_track_stats(ctx.out_file, runner, ctx.tool_paths)
+        if not stats:
+            runner._log_message(
+                "[Verify] [WARNING] Could not read track statistics from the output."
+            )
+            return
+
+        ctx.track_stats = stats
+        runner._log_message("[Verify] Output tracks:")
+        for track in stats:
+            runner._log_message(f"[Verify]   {track.describe()}")
+
+        for anomaly in find_anomalies(stats):
+            runner._log_message(f"[Verify] [WARNING] {anomaly}")
+            ctx.verify_issues.append(AuditIssue("Verify", "warning", anomaly))
+
+    def _check_delays(self, ctx: Context, runner: CommandRunner) -> None:
+        """Compare output container delays with the planned delays.
+
+        Only video/audio tracks with a non-negative planned delay can be
+        checked: subtitles carry no container delay, and mkvmerge drops
+        whatever a negative delay pushes before zero.
+        """
+        info = get_stream_info_with_delays(ctx.out_file, runner, ctx.tool_paths)
+        if not info:
+            runner._log_message(
+                "[Verify] [WARNING] Could not read track delays from the output."
+            )
+            return
+
+        plan = MergePlan(
+            items=ctx.extracted_items or [],
+            delays=ctx.delays or Delays(),
+            subtitle_delays_ms=ctx.subtitle_delays_ms,
+            delay_rounding=ctx.settings.delay_rounding,
+        )
+        items = final_track_order(plan)
+        tracks = info.get("tracks", [])
+        if len(tracks) != len(items):
+            runner._log_message(
+                f"[Verify] [WARNING] Output has {len(tracks)} tracks but "
+                f"{len(items)} were planned — skipping delay check."
+            )
+            return
+
+        tolerance = ctx.settings.verify_delay_tolerance_ms
+        rows: list[str] = []
+        mismatches = 0
+        for item, track in zip(items, tracks, strict=True):
+            tr = item.track
+            if tr.type == "subtitles":
+                continue
+            # Re-encoded tracks had the same delay baked in by ffmpeg, except
+            # padded ones which start at zero
+            expected = (
+                0
+                if item.silence_padded
+                else calculate_track_delay(plan, replace(item, is_reencoded=False))
+            )
+            if expected < 0:
+                continue
+            actual = track.get("container_delay_ms", 0)
+            ok = abs(actual - expected) <= tolerance
+            mismatches += not ok
+            rows.append(
+                f"[Verify]   {track.get('id', '?'):>2}  {tr.source:<10} "
+                f"{tr.type:<6} {expected:>+8}ms {actual:>+8}ms  "
+                + ("ok" if ok else "MISMATCH")
+            )
+
+        if not rows:
+            return
+        runner._log_message(
+            f"[Verify] Track delays (expected vs actual, ±{tolerance}ms):"
+        )
+        for row in rows:
+            runner._log_message(row)
+        if not mismatches:
+            return
+
+        message = (
+            f"{mismatches} track(s) have a container delay that differs from "
+            "the planned delay."
+        )
+        if ctx.settings.verify_delay_mismatch_action == "fail":
+            raise RuntimeError(f"Output verification failed: {message}")
+        runner._log_message(f"[Verify] [WARNING] {message}")
+        ctx.verify_issues.append(AuditIssue("Verify", "warning", message))
@@ -6,13 +6,14 @@ Ensures each step completed successfully before proceeding.
 
 from pathlib import Path
 
+from vsg_core.errors import ErrorKind, VsgError
 from vsg_core.orchestrator.steps.context import Context
 
 
-class PipelineValidationError(Exception):
+class PipelineValidationError(VsgError):
     """Raised when a pipeline step validation fails."""
 
-    pass
+    kind = ErrorKind.VALIDATION
 
 
 class StepValidator:
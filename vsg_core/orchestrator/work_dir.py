@@ -0,0 +1,76 @@
+# vsg_core/orchestrator/work_dir.py
+"""
+Per-job work directory and its layout.
+
+Every job gets its own directory under ``temp_root`` (or ``./temp_work``)
+with a fixed set of sub-directories, so a work dir that is kept after a
+failure (``keep_temp_on_failure``) can be browsed without guessing:
+
+    orch_<stem>_<time>_xxxx/
+        extracted/   tracks, attachments, chapters copied out of the sources
+        audio/       re-encoded, trimmed and stepping-corrected audio
+            decoded/ decoded PCM reused within the job (analysis_audio_cache)
+        indexes/     FFMS2 video indexes
+        logs/        audit trail, mkvmerge options
+        joined/      multi-part sources appended into one file (on demand)
+        normalized/  non-MKV sources remuxed to MKV (normalize_to_mkv)
+
+Anything else (OCR work files, font subsets, ...) stays at the top level.
+"""
+
+from __future__ import annotations
+
+import tempfile
+import time
+from dataclasses import dataclass
+from pathlib import Path
+
+SUBDIRS = ("extracted", "audio", "indexes", "logs")
+
+
+@dataclass(frozen=True, slots=True)
+class WorkDir:
+    """Paths of one job's work directory."""
+
+    root: Path
+
+    @property
+    def extracted(self) -> Path:
+        return self.root / "extracted"
+
+    @property
+    def audio(self) -> Path:
+        return self.root / "audio"
+
+    @property
+    def indexes(self) -> Path:
+        return self.root / "indexes"
+
+    @property
+    def logs(self) -> Path:
+        return self.root / "logs"
+
+    @property
+    def joined(self) -> Path:
+        return self.root / "joined"
+
+    @property
+    def normalized(self) -> Path:
+        return self.root / "normalized"
+
+    def ensure(self) -> WorkDir:
+        """Create the root and the layout sub-directories."""
+        for name in SUBDIRS:
+            (self.root / name).mkdir(parents=True, exist_ok=True)
+        return self
+
+    @classmethod
+    def create(cls, temp_root: str, source1_file: str) -> WorkDir:
+        """Make a fresh, uniquely named work dir for a job on ``source1_file``."""
+        base = Path(temp_root) if temp_root else Path.cwd() / "temp_work"
+        base.mkdir(parents=True, exist_ok=True)
+        # mkdtemp suffix keeps concurrent jobs with the same stem apart
+        root = tempfile.mkdtemp(
+            prefix=f"orch_{Path(source1_file).stem}_{int(time.time())}_", dir=base
+        )
+        return cls(Path(root)).ensure()
@@ -11,18 +11,30 @@ from collections.abc import Callable
 from pathlib import Path
 from typing import Any
 
+from .analysis.reliability import AnalysisNeedsReview
+from .errors import ErrorKind, ProcessFailed, ToolMissing, error_kind
+from .extraction.concat import join_multipart_sources
+from .extraction.tool_versions import tool_versions
 from .io.runner import CommandRunner
 from .models.context_types import ManualLayoutItem
 from .models.jobs import PipelineResult
+from .models.overrides import apply_overrides
 from .models.settings import AppSettings
+from .models.source_input import SourceValue, primary_path, primary_sources
+from .mux.output_name import claim_output_path, release_output_path
+from .mux.split import find_parts
+from .orchestrator.steps import VerifyStep
+from .orchestrator.work_dir import WorkDir
 from .pipeline_components import (
     LogManager,
     OutputWriter,
+    ProgressTracker,
     ResultAuditor,
     SyncExecutor,
     SyncPlanner,
     ToolValidator,
 )
+from .postprocess.checksum import sha256_file
 
 
 class JobPipeline:
@@ -38,6 +50,7 @@ class JobPipeline:
         config: AppSettings,
         log_callback: Callable[[str], None],
         progress_callback: Callable[[float], None],
+        status_callback: Callable[[str], None] | None = None,
     ):
         """
         Initializes the job pipeline.
@@ -46,68 +59,110 @@ class JobPipeline:
             config: AppSettings instance
             log_callback: Callback for GUI log messages
             progress_callback: Callback for progress updates
+            status_callback: Optional callback for "Phase… 45% (ETA 2m10s)"
+                status text derived from progress updates
         """
         self.settings = config
         self.gui_log_callback = log_callback
-        self.progress = progress_callback
+        self.progress_tracker = ProgressTracker(progress_callback, status_callback)
+        self.progress = self.progress_tracker
         self.tool_paths = {}
 
     def run_job(
         self,
-        sources: dict[str, str],
+        sources: dict[str, SourceValue],
         and_merge: bool,
         output_dir_str: str,
         manual_layout: list[ManualLayoutItem] | None = None,
         attachment_sources: list[str] | None = None,
         source_settings: dict[str, dict[str, Any]] | None = None,
+        attachment_selection: dict[str, list[str]] | None = None,
         chapter_source: str = "Source 1",
         debug_paths=None,
+        output_name: str | None = None,
+        settings_overrides: dict[str, Any] | None = None,
     ) -> PipelineResult:
         """
         Runs a complete sync job.
 
         Args:
-            sources: Dictionary mapping source names to file paths
+            sources: Dictionary mapping source names to file paths; a list
+                of paths is a multi-part source, joined before analysis
             and_merge: Whether to perform merge (vs. analyze-only)
             output_dir_str: Output directory path
             manual_layout: Manual layout configuration
             attachment_sources: List of attachment source paths
+            attachment_selection: Per-source attachment ids/file names to keep
+                (sources not listed keep all their fonts)
             source_settings: Per-source correlation settings, e.g.:
                 {'Source 1': {'correlation_ref_track': 0}, 'Source 2': {...}}
             debug_paths: DebugOutputPaths for this job (from DebugOutputManager)
+            output_name: Override for the output filename (default: Source 1
+                filename). Used by the batch queue to keep names unique.
+            settings_overrides: Partial settings merged over the global
+                settings for this job only, e.g. {'scan_end_percentage': 95.0}
 
         Returns:
             PipelineResult with status, delays, output path, and diagnostic info.
         """
         # --- 1. Input Validation ---
-        source1_file = sources.get("Source 1")
+        source1_file = primary_path(sources.get("Source 1", ""))
         if not source1_file:
             raise ValueError("Job is missing Source 1 (Reference).")
 
         output_dir = Path(output_dir_str)
         output_dir.mkdir(parents=True, exist_ok=True)
 
-        job_name = Path(source1_file).stem
+        output_filename = output_name or Path(source1_file).name
+        job_name = Path(output_filename).stem
+
+        # Resolved per job: self.settings is shared by the whole batch
+        override_error = ""
+        try:
+            settings, override_lines = apply_overrides(
+                self.settings, settings_overrides
+            )
+        except ValueError as e:
+            settings, override_lines = self.settings, []
+            override_error = str(e)
 
         # --- 2. Setup Logging ---
         logger, handler, log_to_all = LogManager.setup_job_log(
-            job_name, output_dir, self.gui_log_callback
+            job_name,
+            output_dir,
+            self.gui_log_callback,
+            json_lines=settings.log_json_lines,
+            phase_callback=self.progress_tracker.start_phase,
         )
 
-        runner = CommandRunner(self.settings, log_to_all)
+        runner = CommandRunner(settings, log_to_all)
 
         # --- 3. Validate Tools ---
         try:
             self.tool_paths = ToolValidator.validate_tools()
-        except FileNotFoundError as e:
+        except ToolMissing as e:
             log_to_all(f"[ERROR] {e}")
             return PipelineResult(
                 status="Failed",
                 name=Path(source1_file).name,
                 error=str(e),
+                error_kind=e.kind,
             )
 
         log_to_all(f"=== Starting Job: {Path(source1_file).name} ===")
+        for line in tool_versions().log_lines():
+            log_to_all(line)
+        if override_error:
+            log_to_all(f"[ERROR] {override_error}")
+            return PipelineResult(
+                status="Failed",
+                name=Path(source1_file).name,
+                error=override_error,
+                error_kind=ErrorKind.INVALID_SETTINGS,
+            )
+        for line in override_lines:
+            log_to_all(line)
+        self.progress_tracker.start_phase("Starting")
         self.progress(0.0)
 
         # --- 4. Validate Merge Requirements ---
@@ -118,27 +173,42 @@ class JobPipeline:
                 status="Failed",
                 name=Path(source1_file).name,
                 error=err_msg,
+                error_kind=ErrorKind.INVALID_LAYOUT,
             )
 
         ctx_temp_dir: Path | None = None
+        keep_temp = False
+        claimed_output: Path | None = None
 
         try:
+            # Created here rather than by the Orchestrator so it is known (and
+            # cleaned up or kept) even when planning fails part-way
+            work_dir = WorkDir.create(settings.temp_root, source1_file)
+            ctx_temp_dir = work_dir.root
+
+            # Multi-part sources are appended into one file each up front
+            joined_sources = join_multipart_sources(
+                sources, work_dir.joined, runner, self.tool_paths
+            )
+
             # --- 5. Plan Sync ---
             ctx = SyncPlanner.plan_sync(
-                settings=self.settings,
+                settings=settings,
                 tool_paths=self.tool_paths,
                 log_callback=log_to_all,
                 progress_callback=self.progress,
-                sources=sources,
+                sources=joined_sources,
                 and_merge=and_merge,
                 output_dir=str(output_dir),
                 manual_layout=manual_layout or [],
                 attachment_sources=attachment_sources or [],
+                attachment_selection=attachment_selection or {},
                 source_settings=source_settings or {},
                 chapter_source=chapter_source or "Source 1",
                 debug_paths=debug_paths,
+                temp_dir=ctx_temp_dir,
+                source_inputs=primary_sources(sources),
             )
-            ctx_temp_dir = ctx.temp_dir
 
             # --- 6. Return Early if Analysis Only ---
             if not and_merge:
@@ -161,9 +231,13 @@ class JobPipeline:
                 )
 
             # --- 8. Prepare Output Paths ---
-            final_output_path = OutputWriter.prepare_output_path(
-                output_dir, Path(source1_file).name
-            )
+            if ctx.output_name:
+                final_output_path = claim_output_path(output_dir, ctx.output_name)
+                claimed_output = final_output_path
+            else:
+                final_output_path = OutputWriter.prepare_output_path(
+                    output_dir, output_filename
+                )
             mkvmerge_output_path = ctx.temp_dir / f"temp_{final_output_path.name}"
 
             # --- 9. Add Output Flag to Tokens ---
@@ -172,36 +246,78 @@ class JobPipeline:
 
             # --- 10. Write mkvmerge Options ---
             opts_path = OutputWriter.write_mkvmerge_options(
-                ctx.tokens, ctx.temp_dir, self.settings, runner
+                ctx.tokens, ctx.work_dir.logs, settings, runner
             )
 
             # --- 11. Execute Merge ---
-            merge_ok = SyncExecutor.execute_merge(opts_path, self.tool_paths, runner)
-            if not merge_ok:
-                raise RuntimeError("mkvmerge execution failed.")
-
-            # --- 12. Finalize Output ---
-            SyncExecutor.finalize_output(
-                mkvmerge_output_path,
-                final_output_path,
-                self.settings,
-                self.tool_paths,
-                runner,
+            merge = SyncExecutor.execute_merge(
+                opts_path, self.tool_paths, runner, tokens=ctx.tokens
             )
+            if not merge.ok:
+                raise ProcessFailed(merge.describe(), runner.last_failure)
+            for warning in merge.warnings:
+                log_to_all(f"[Merge] [WARNING] {warning}")
+            if merge.warnings:
+                log_to_all(f"[Merge] {merge.describe()}; the output is complete.")
 
-            log_to_all(f"[SUCCESS] Output file created: {final_output_path}")
+            # --- 12. Finalize Output ---
+            # With --split mkvmerge writes <name>-001.mkv, -002.mkv, ...
+            temp_parts = find_parts(mkvmerge_output_path)
+            final_parts: list[Path] = []
+            for temp_part in temp_parts:
+                suffix = temp_part.name[len(mkvmerge_output_path.stem) :]
+                final_part = final_output_path.with_name(
+                    final_output_path.stem + suffix
+                )
+                SyncExecutor.finalize_output(
+                    temp_part,
+                    final_part,
+                    settings,
+                    self.tool_paths,
+                    runner,
+                )
+                final_parts.append(final_part)
+                log_to_all(f"[SUCCESS] Output file created: {final_part}")
 
             # --- 13. Audit Output ---
-            issues, audit_details = ResultAuditor.audit_output(
-                final_output_path, ctx, runner, log_to_all
-            )
+            if len(final_parts) == 1:
+                issues, audit_details = ResultAuditor.audit_output(
+                    final_parts[0], ctx, runner, log_to_all
+                )
+            else:
+                # Auditors compare against the whole plan (durations, track
+                # counts), which no single part matches
+                log_to_all(
+                    f"[Audit] Skipped: output was split into "
+                    f"{len(final_parts)} parts."
+                )
+                issues, audit_details = 0, []
+
+            # --- 13b. Verify Output (optional) ---
+            if settings.verify_output:
+                if len(final_parts) == 1:
+                    ctx.out_file = str(final_parts[0])
+                    ctx = VerifyStep().run(ctx, runner)
+                    issues += len(ctx.verify_issues)
+                    audit_details = [*audit_details, *ctx.verify_issues]
+                else:
+                    log_to_all("[Verify] Skipped: output was split into parts.")
+
+            # --- 13c. Checksums (optional) ---
+            checksums = []
+            if settings.checksum_output:
+                for part in final_parts:
+                    checksum = sha256_file(part)
+                    log_to_all(f"[Checksum] {checksum.describe()}")
+                    checksums.append(checksum)
 
             # --- 14. Success ---
             self.progress(1.0)
             return PipelineResult(
                 status="Merged",
                 name=Path(source1_file).name,
-                output=str(final_output_path),
+                output=str(final_parts[0]),
+                outputs=[str(p) for p in final_parts],
                 delays=ctx.delays.source_delays_ms if ctx.delays else {},
                 issues=issues,
                 audit_details=audit_details,
@@ -210,21 +326,49 @@ class JobPipeline:
                 stepping_detected_separated=ctx.stepping_detected_separated,
                 stepping_quality_issues=ctx.stepping_quality_issues,
                 sync_stability_issues=ctx.sync_stability_issues,
+                track_stats=ctx.track_stats,
+                mux_warnings=list(merge.warnings),
+                checksums=checksums,
+            )
+
+        except AnalysisNeedsReview as e:
+            log_to_all(f"[NEEDS REVIEW] {e}")
+            self.progress(1.0)
+            return PipelineResult(
+                status="Needs Review",
+                name=Path(source1_file).name,
+                delays=e.delays,
+                error=str(e),
+                error_kind=e.kind,
             )
 
         except Exception as e:
             log_to_all(f"[FATAL ERROR] Job failed: {e}")
+            keep_temp = (
+                settings.keep_temp_on_failure
+                and ctx_temp_dir is not None
+                and ctx_temp_dir.exists()
+            )
+            if keep_temp:
+                log_to_all(f"[Cleanup] Keeping work directory: {ctx_temp_dir}")
             return PipelineResult(
                 status="Failed",
                 name=Path(source1_file).name,
                 error=str(e),
+                error_kind=error_kind(e),
+                temp_dir=str(ctx_temp_dir) if keep_temp else None,
             )
 
         finally:
             # --- 15. Cleanup ---
-            if ctx_temp_dir and ctx_temp_dir.exists():
+            if ctx_temp_dir and not keep_temp and ctx_temp_dir.exists():
                 shutil.rmtree(ctx_temp_dir, ignore_errors=True)
 
+            # The output now exists on disk (or never will), so later jobs
+            # see it there rather than through the claim
+            if claimed_output is not None:
+                release_output_path(claimed_output)
+
             # Clear VFR cache after each job to release VideoTimestamps instances
             try:
                 from vsg_core.subtitles.frame_utils import clear_vfr_cache
@@ -7,6 +7,7 @@ Splits JobPipeline responsibilities into focused, testable components.
 
 from .log_manager import LogManager
 from .output_writer import OutputWriter
+from .progress_tracker import ProgressSlice, ProgressTracker
 from .result_auditor import ResultAuditor
 from .sync_executor import SyncExecutor
 from .sync_planner import SyncPlanner
@@ -15,6 +16,8 @@ from .tool_validator import ToolValidator
 __all__ = [
     "LogManager",
     "OutputWriter",
+    "ProgressSlice",
+    "ProgressTracker",
     "ResultAuditor",
     "SyncExecutor",
     "SyncPlanner",
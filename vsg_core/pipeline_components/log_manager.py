@@ -3,19 +3,88 @@
 Log management component.
 
 Handles logger setup, file handlers, and log output routing.
+
+Besides the human-readable ``{job}.log``, an optional JSON-lines sink
+(``{job}.jsonl``) receives the exact same events as structured records:
+``{"ts": ..., "level": ..., "phase": ..., "message": ...}``.
 """
 
+import json
 import logging
+import re
 from collections.abc import Callable
+from datetime import datetime
 from pathlib import Path
 
+# "[12:34:56] message" - prefix added by CommandRunner._log_message
+_TS_PREFIX = re.compile(r"^\[\d{2}:\d{2}:\d{2}\]\s*")
+# "--- Analysis Phase ---" / "--- Running Audio Correlation Analysis ---"
+_PHASE_LINE = re.compile(r"^-{3}\s*(.+?)\s*-{3}$")
+
+
+def parse_phase_header(message: str) -> str | None:
+    """Return the phase name if message is a ``--- X ---`` header."""
+    match = _PHASE_LINE.match(_TS_PREFIX.sub("", message.strip()))
+    return match.group(1) if match else None
+
+
+class JsonLinesFormatter(logging.Formatter):
+    """Formats log records as one JSON object per line.
+
+    Level is inferred from the repo's log tag conventions ([ERROR], [WARNING],
+    [SUCCESS], section banners, Progress lines). The current phase is taken
+    from the most recent ``--- X ---`` header and attached to every record.
+    """
+
+    def __init__(self) -> None:
+        super().__init__()
+        self.phase = ""
+
+    @staticmethod
+    def classify(message: str) -> str:
+        upper = message.upper()
+        if upper.startswith(("[FATAL", "[ERROR", "[!]")):
+            return "error"
+        if upper.startswith(("[WARN", "WARNING")):
+            return "warn"
+        if upper.startswith("[SUCCESS]"):
+            return "success"
+        if _PHASE_LINE.match(message):
+            return "phase"
+        if message.startswith("===") or set(message) == {"="}:
+            return "section"
+        if message.startswith("Progress:"):
+            return "progress"
+        return "info"
+
+    def format(self, record: logging.LogRecord) -> str:
+        message = _TS_PREFIX.sub("", record.getMessage().strip())
+        level = self.classify(message)
+        if level == "phase":
+            self.phase = parse_phase_header(message) or self.phase
+        return json.dumps(
+            {
+                "ts": datetime.fromtimestamp(record.created).isoformat(
+                    timespec="milliseconds"
+                ),
+                "level": level,
+                "phase": self.phase,
+                "message": message,
+            },
+            ensure_ascii=False,
+        )
+
 
 class LogManager:
     """Manages logging setup and cleanup for jobs."""
 
     @staticmethod
     def setup_job_log(
-        job_name: str, log_dir: Path, gui_log_callback: Callable[[str], None]
+        job_name: str,
+        log_dir: Path,
+        gui_log_callback: Callable[[str], None],
+        json_lines: bool = False,
+        phase_callback: Callable[[str], None] | None = None,
     ) -> tuple[logging.Logger, logging.FileHandler, Callable[[str], None]]:
         """
         Sets up logging for a job.
@@ -24,6 +93,8 @@ class LogManager:
             job_name: Name of the job (used for log filename and logger name)
             log_dir: Directory where log file will be created
             gui_log_callback: Callback to send log messages to GUI
+            json_lines: Also write structured records to {job_name}.jsonl
+            phase_callback: Called with the phase name on ``--- X ---`` headers
 
         Returns:
             Tuple of (logger, handler, log_to_all_function)
@@ -45,10 +116,22 @@ class LogManager:
         logger.addHandler(handler)
         logger.propagate = False
 
+        # Optional structured sink - same logger, so both see every event
+        if json_lines:
+            json_handler = logging.FileHandler(
+                log_dir / f"{job_name}.jsonl", mode="w", encoding="utf-8"
+            )
+            json_handler.setFormatter(JsonLinesFormatter())
+            logger.addHandler(json_handler)
+
         # Create unified log function
         def log_to_all(message: str):
             logger.info(message.strip())
             gui_log_callback(message)
+            if phase_callback:
+                phase = parse_phase_header(message)
+                if phase:
+                    phase_callback(phase)
 
         return logger, handler, log_to_all
 
@@ -57,9 +140,14 @@ class LogManager:
         """
         Cleans up logger and handler resources.
 
+        Flushes and closes every handler attached to the job logger (the
+        human log and, if enabled, the JSON-lines sink).
+
         Args:
             logger: Logger instance to clean up
             handler: File handler to close
         """
-        handler.close()
-        logger.removeHandler(handler)
+        for h in {handler, *logger.handlers}:
+            h.flush()
+            h.close()
+            logger.removeHandler(h)
@@ -3,6 +3,14 @@
 Output writer component.
 
 Handles writing mkvmerge options files and managing output paths.
+
+mkvmerge reads its arguments from a JSON options file (``mkvmerge
+@opts.json``), a JSON array of strings in UTF-8. Jobs with many tracks and
+attachments easily produce a command line longer than Windows allows
+(32767 characters), so the merge goes through that file unless
+``mkvmerge_options_file`` is "auto" and the arguments are short enough to
+pass directly. The file is written either way, so the exact arguments of
+every merge can be inspected in the job's work directory.
 """
 
 from __future__ import annotations
@@ -16,6 +24,25 @@ from ..io.runner import CommandRunner
 if TYPE_CHECKING:
     from vsg_core.models import AppSettings
 
+# Stay well below the Windows CreateProcess limit (32767 characters) and
+# Linux's per-argument and total limits
+ARGV_SAFE_CHARS = 24000
+ARGV_SAFE_COUNT = 2000
+
+
+def argv_fits(tokens: list[str]) -> bool:
+    """True when ``tokens`` can safely be passed as a plain command line."""
+    length = sum(len(t) + 3 for t in tokens)  # Quotes and separator
+    return len(tokens) <= ARGV_SAFE_COUNT and length <= ARGV_SAFE_CHARS
+
+
+def _is_utf8(token: str) -> bool:
+    try:
+        token.encode("utf-8")
+    except UnicodeEncodeError:
+        return False
+    return True
+
 
 class OutputWriter:
     """Writes output files and mkvmerge configuration."""
@@ -42,6 +69,9 @@ class OutputWriter:
         opts_path = temp_dir / "opts.json"
 
         try:
+            # Raw UTF-8, not \uXXXX escapes, so the file reads as written.
+            # Paths that are not valid Unicode (undecodable bytes on Linux)
+            # cannot be represented and fail here with a clear message.
             opts_path.write_text(
                 json.dumps(tokens, ensure_ascii=False), encoding="utf-8"
             )
@@ -63,6 +93,13 @@ class OutputWriter:
 
             return str(opts_path)
 
+        except UnicodeEncodeError as e:
+            bad = next((t for t in tokens if not _is_utf8(t)), "")
+            raise OSError(
+                "Failed to write mkvmerge options file: argument is not valid "
+                f"UTF-8 ({bad.encode('utf-8', 'replace').decode()}). Rename the "
+                "file to a name without undecodable characters."
+            ) from e
         except Exception as e:
             raise OSError(f"Failed to write mkvmerge options file: {e}")
 
@@ -0,0 +1,152 @@
+# vsg_core/pipeline_components/progress_tracker.py
+"""
+Progress tracking component.
+
+Wraps the job progress callback and derives a time estimate from the
+stream of progress updates. The rate is smoothed with an EWMA so a fast
+step following a slow one doesn't make the ETA jump wildly, and the
+estimator is reset at each phase boundary (the ``--- X Phase ---`` log
+headers emitted by the orchestrator).
+
+``ProgressSlice`` maps the 0-1 progress of a sub-task (one source's
+analysis, one correlation pass) onto its share of the parent bar.
+"""
+
+from __future__ import annotations
+
+import threading
+import time
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+
+def format_eta(seconds: float) -> str:
+    """Format seconds as a compact duration: 45s, 2m10s, 1h05m."""
+    total = max(0, int(round(seconds)))
+    if total < 60:
+        return f"{total}s"
+    minutes, secs = divmod(total, 60)
+    if minutes < 60:
+        return f"{minutes}m{secs:02d}s"
+    hours, minutes = divmod(minutes, 60)
+    return f"{hours}h{minutes:02d}m"
+
+
+class EtaEstimator:
+    """EWMA-smoothed rate estimator over (time, fraction) samples."""
+
+    def __init__(self, alpha: float = 0.3, min_elapsed_s: float = 1.0):
+        self.alpha = alpha
+        self.min_elapsed_s = min_elapsed_s
+        self.reset()
+
+    def reset(self) -> None:
+        """Forget all samples (call at phase boundaries)."""
+        self._start_time: float | None = None
+        self._last_time: float | None = None
+        self._last_fraction = 0.0
+        self._rate: float | None = None  # fraction per second
+
+    def update(self, fraction: float, now: float | None = None) -> float | None:
+        """Record a sample. Returns the estimated seconds remaining, or None."""
+        now = time.monotonic() if now is None else now
+        fraction = min(max(fraction, 0.0), 1.0)
+
+        if self._last_time is None:
+            self._start_time = now
+            self._last_time = now
+            self._last_fraction = fraction
+            return None
+
+        dt = now - self._last_time
+        dfrac = fraction - self._last_fraction
+        if dt > 0 and dfrac > 0:
+            inst_rate = dfrac / dt
+            self._rate = (
+                inst_rate
+                if self._rate is None
+                else self.alpha * inst_rate + (1 - self.alpha) * self._rate
+            )
+            self._last_time = now
+            self._last_fraction = fraction
+
+        if (
+            self._rate is None
+            or self._rate <= 0
+            or self._start_time is None
+            or now - self._start_time < self.min_elapsed_s
+        ):
+            return None
+        return (1.0 - fraction) / self._rate
+
+
+class ProgressTracker:
+    """Progress callback wrapper that also emits "Phase… 45% (ETA 2m10s)"."""
+
+    def __init__(
+        self,
+        progress_callback: Callable[[float], None],
+        status_callback: Callable[[str], None] | None = None,
+    ):
+        self.progress_callback = progress_callback
+        self.status_callback = status_callback
+        self.estimator = EtaEstimator()
+        self.phase = ""
+
+    def start_phase(self, name: str) -> None:
+        """Mark a phase boundary: reset the estimator and announce the phase."""
+        self.phase = name
+        self.estimator.reset()
+        if self.status_callback:
+            self.status_callback(f"{name}…")
+
+    def __call__(self, fraction: float) -> None:
+        self.progress_callback(fraction)
+        eta = self.estimator.update(fraction)
+        if self.status_callback is None:
+            return
+        label = self.phase or "Working"
+        text = f"{label}… {int(fraction * 100)}%"
+        if eta is not None and fraction < 1.0:
+            text += f" (ETA {format_eta(eta)})"
+        self.status_callback(text)
+
+
+class ProgressSlice:
+    """Progress callback for a sub-task, mapped onto ``[start, end]``.
+
+    Updates are clamped, never go backwards (a fallback re-run doesn't
+    rewind the bar) and are forwarded only when the parent value moved by
+    at least ``min_step``, so per-chunk updates don't flood the UI. Safe to
+    call from several worker threads.
+    """
+
+    def __init__(
+        self,
+        callback: Callable[[float], None],
+        start: float = 0.0,
+        end: float = 1.0,
+        min_step: float = 0.005,
+    ):
+        self.callback = callback
+        self.start = start
+        self.end = end
+        self.min_step = min_step
+        self._last = start
+        self._lock = threading.Lock()
+
+    def __call__(self, fraction: float) -> None:
+        value = self.start + (self.end - self.start) * min(max(fraction, 0.0), 1.0)
+        with self._lock:
+            if value <= self._last:
+                return
+            if value - self._last < self.min_step and fraction < 1.0:
+                return
+            self._last = value
+        self.callback(value)
+
+    def part(self, lo: float, hi: float) -> ProgressSlice:
+        """Sub-slice covering ``lo..hi`` (0-1) of this slice."""
+        return ProgressSlice(self, lo, hi, min_step=0.0)
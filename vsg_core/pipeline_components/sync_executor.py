@@ -7,12 +7,22 @@ Handles merge execution and post-processing finalization.
 
 from __future__ import annotations
 
-import shutil
 from pathlib import Path
 from typing import TYPE_CHECKING
 
 from ..io.runner import CommandRunner
-from ..postprocess import check_if_rebasing_is_needed, finalize_merged_file
+from ..mux.mkvmerge_result import (
+    MKVMERGE_OK,
+    MKVMERGE_WARNINGS,
+    MkvmergeOutcome,
+    parse_mkvmerge_output,
+)
+from ..postprocess import (
+    check_if_rebasing_is_needed,
+    finalize_merged_file,
+    place_output,
+)
+from .output_writer import argv_fits
 
 if TYPE_CHECKING:
     from vsg_core.models import AppSettings
@@ -23,21 +33,49 @@ class SyncExecutor:
 
     @staticmethod
     def execute_merge(
-        mkvmerge_options_path: str, tool_paths: dict[str, str], runner: CommandRunner
-    ) -> bool:
+        mkvmerge_options_path: str,
+        tool_paths: dict[str, str],
+        runner: CommandRunner,
+        tokens: list[str] | None = None,
+    ) -> MkvmergeOutcome:
         """
-        Executes mkvmerge with the provided options file.
+        Executes mkvmerge with the provided options file, retrying
+        transient failures (``command_retries``).
 
         Args:
             mkvmerge_options_path: Path to mkvmerge options JSON file
             tool_paths: Dictionary of tool paths
             runner: CommandRunner for execution
+            tokens: The arguments in the options file. With
+                ``mkvmerge_options_file`` "auto" they are passed as plain
+                argv when short enough.
 
         Returns:
-            True if merge succeeded, False otherwise
+            MkvmergeOutcome; ``ok`` also when mkvmerge only warned (exit 1)
         """
-        result = runner.run(["mkvmerge", f"@{mkvmerge_options_path}"], tool_paths)
-        return result is not None
+        if (
+            tokens is not None
+            and runner.settings.mkvmerge_options_file == "auto"
+            and argv_fits(tokens)
+        ):
+            cmd = ["mkvmerge", *tokens]
+        else:
+            if tokens is not None:
+                runner._log_message(
+                    f"[Merge] Passing {len(tokens)} arguments via options file "
+                    f"{Path(mkvmerge_options_path).name}."
+                )
+            cmd = ["mkvmerge", f"@{mkvmerge_options_path}"]
+        output = runner.run_with_retry(
+            cmd, tool_paths, ok_codes=(MKVMERGE_OK, MKVMERGE_WARNINGS)
+        )
+        if output is None:
+            failure = runner.last_failure
+            if failure is None:
+                return MkvmergeOutcome(returncode=None)
+            return parse_mkvmerge_output(failure.returncode, failure.output)
+        assert isinstance(output, str)
+        return parse_mkvmerge_output(runner.last_returncode, output)
 
     @staticmethod
     def finalize_output(
@@ -69,4 +107,9 @@ class SyncExecutor:
                 temp_output_path, final_output_path, runner, settings, tool_paths
             )
         else:
-            shutil.move(temp_output_path, final_output_path)
+            place_output(
+                temp_output_path,
+                final_output_path,
+                settings.atomic_output,
+                runner._log_message,
+            )
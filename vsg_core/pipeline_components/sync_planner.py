@@ -6,6 +6,7 @@ Wraps the Orchestrator to provide a cleaner interface for sync planning.
 """
 
 from collections.abc import Callable
+from pathlib import Path
 from typing import Any
 
 from ..models.context_types import ManualLayoutItem
@@ -28,8 +29,11 @@ class SyncPlanner:
         manual_layout: list[ManualLayoutItem],
         attachment_sources: list[str],
         source_settings: dict[str, dict[str, Any]] | None = None,
+        attachment_selection: dict[str, list[str]] | None = None,
         chapter_source: str = "Source 1",
         debug_paths=None,
+        temp_dir: Path | None = None,
+        source_inputs: dict[str, str] | None = None,
     ) -> Any:
         """
         Plans the sync operation by analyzing sources and preparing merge tokens.
@@ -46,9 +50,14 @@ class SyncPlanner:
             output_dir: Output directory path
             manual_layout: Manual layout configuration (typed as ManualLayoutItem)
             attachment_sources: List of attachment source paths
+            attachment_selection: Per-source attachment ids/file names to keep
+                (sources not listed keep all their fonts)
             source_settings: Per-source correlation settings, e.g.:
                 {'Source 1': {'correlation_ref_track': 0}, 'Source 2': {'correlation_source_track': 1, 'use_source_separation': True}}
             debug_paths: DebugOutputPaths for this job
+            temp_dir: Job work directory (created by the Orchestrator if None)
+            source_inputs: The user's files per source before multi-part
+                joining (``sources`` when None)
 
         Returns:
             Context object containing:
@@ -69,7 +78,10 @@ class SyncPlanner:
             output_dir=output_dir,
             manual_layout=manual_layout,
             attachment_sources=attachment_sources,
+            attachment_selection=attachment_selection or {},
             source_settings=source_settings or {},
             chapter_source=chapter_source or "Source 1",
             debug_paths=debug_paths,
+            temp_dir=temp_dir,
+            source_inputs=source_inputs,
         )
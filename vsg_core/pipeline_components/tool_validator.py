@@ -7,12 +7,15 @@ Validates that required external tools are available in the system PATH.
 
 import shutil
 
+from ..errors import ToolMissing
+from ..extraction.tool_versions import INSTALL_HINTS
+
 
 class ToolValidator:
     """Validates and locates required external tools."""
 
     REQUIRED_TOOLS = ["ffmpeg", "ffprobe", "mkvmerge", "mkvextract", "mkvpropedit"]
-    OPTIONAL_TOOLS = ["videodiff"]
+    OPTIONAL_TOOLS = ["videodiff", "dovi_tool"]
 
     @staticmethod
     def validate_tools() -> dict[str, str]:
@@ -23,7 +26,7 @@ class ToolValidator:
             Dict mapping tool names to their paths
 
         Raises:
-            FileNotFoundError: If any required tool is not found
+            ToolMissing: If any required tool is not found
         """
         tool_paths = {}
 
@@ -31,7 +34,11 @@ class ToolValidator:
         for tool in ToolValidator.REQUIRED_TOOLS:
             tool_paths[tool] = shutil.which(tool)
             if not tool_paths[tool]:
-                raise FileNotFoundError(f"Required tool '{tool}' not found in PATH.")
+                hint = INSTALL_HINTS.get(tool, "")
+                raise ToolMissing(
+                    f"Required tool '{tool}' not found in PATH. {hint}".strip(),
+                    tool,
+                )
 
         # Optional tools (don't fail if missing)
         for tool in ToolValidator.OPTIONAL_TOOLS:
@@ -1,5 +1,6 @@
 # vsg_core/postprocess/__init__.py
 
+from .atomic_output import place_output
 from .final_auditor import FinalAuditor
 from .finalizer import check_if_rebasing_is_needed, finalize_merged_file
 
@@ -7,4 +8,5 @@ __all__ = [
     "FinalAuditor",
     "check_if_rebasing_is_needed",
     "finalize_merged_file",
+    "place_output",
 ]
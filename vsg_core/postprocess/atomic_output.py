@@ -0,0 +1,62 @@
+# vsg_core/postprocess/atomic_output.py
+"""
+Moving the finished file into the output folder without ever leaving a
+truncated file under the final name.
+
+mkvmerge writes into the job's work dir; the result is then moved to the
+output folder. When both are on the same filesystem that is a rename,
+which is atomic. Across filesystems ``shutil.move`` copies straight into
+the final name, so a crash mid-copy leaves a partial file that looks
+complete. With ``atomic_output`` the copy goes to ``<name>.part`` next to
+the final file and is renamed once complete; on failure the ``.part`` is
+removed, and one left by a crash is recognizable by its name.
+"""
+
+from __future__ import annotations
+
+import errno
+import os
+import shutil
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+PART_SUFFIX = ".part"
+
+
+def part_path(final_path: Path) -> Path:
+    """``movie.mkv`` -> ``movie.mkv.part``."""
+    return final_path.with_name(final_path.name + PART_SUFFIX)
+
+
+def place_output(
+    temp_path: Path,
+    final_path: Path,
+    atomic: bool = True,
+    log: Callable[[str], None] | None = None,
+) -> None:
+    """Moves ``temp_path`` to ``final_path`` (see module docstring)."""
+    if not atomic:
+        shutil.move(str(temp_path), str(final_path))
+        return
+    try:
+        os.replace(temp_path, final_path)
+        return
+    except OSError as e:
+        if e.errno != errno.EXDEV:
+            raise
+    partial = part_path(final_path)
+    if log:
+        log(
+            f"[Finalize] Temp and output folders are on different drives; "
+            f"copying to {partial} first."
+        )
+    try:
+        shutil.copy2(temp_path, partial)
+        os.replace(partial, final_path)
+    except BaseException:
+        partial.unlink(missing_ok=True)
+        raise
+    temp_path.unlink(missing_ok=True)
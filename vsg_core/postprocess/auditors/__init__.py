@@ -23,6 +23,7 @@ from .stepping_separated import SteppingSeparatedAuditor
 from .subtitle_clamping import SubtitleClampingAuditor
 from .subtitle_duration import SubtitleDurationAuditor
 from .subtitle_formats import SubtitleFormatsAuditor
+from .subtitle_lint import SubtitleLintAuditor
 from .track_flags import TrackFlagsAuditor
 from .track_names import TrackNamesAuditor
 from .track_order import TrackOrderAuditor
@@ -53,6 +54,7 @@ __all__ = [
     "SubtitleClampingAuditor",
     "SubtitleDurationAuditor",
     "SubtitleFormatsAuditor",
+    "SubtitleLintAuditor",
     "TrackFlagsAuditor",
     "TrackNamesAuditor",
     "TrackOrderAuditor",
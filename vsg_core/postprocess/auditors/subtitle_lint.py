@@ -0,0 +1,46 @@
+# vsg_core/postprocess/auditors/subtitle_lint.py
+"""
+Renders the subtitle event sanity check in the final audit.
+
+The check runs during subtitle processing (``track_processor``, after
+sync) and stashes a ``SubtitleLintResult`` per track on
+``ctx.subtitle_lint_results``; this auditor only renders them. For
+external subtitle files the message says the problems are in the file
+itself, so they are not mistaken for sync problems.
+"""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from .base import BaseAuditor
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+class SubtitleLintAuditor(BaseAuditor):
+    """Renders per-track subtitle timing problems (zero durations, ...)."""
+
+    def run(
+        self,
+        final_mkv_path: Path,
+        final_mkvmerge_data: dict,
+        final_ffprobe_data: dict | None = None,
+    ) -> int:
+        results = self.ctx.subtitle_lint_results
+        if not results:
+            self.log("[INFO] No text-subtitle tracks checked — skipping.")
+            return 0
+
+        for result in results.values():
+            if not result.issues:
+                self.log(f"  {result.track_label}: OK")
+                continue
+            msg = f"{result.track_label}: {result.summary()}"
+            if result.is_external:
+                msg += " (in the subtitle file itself, not caused by sync)"
+            if result.events_fixed:
+                msg += f"; {result.events_fixed} event(s) fixed"
+            self._report(msg)
+        return len(self.issues)
@@ -1,6 +1,8 @@
 # vsg_core/postprocess/auditors/track_names.py
 from pathlib import Path
 
+from vsg_core.mux.track_names import suggest_track_name
+
 from .base import BaseAuditor
 
 
@@ -29,7 +31,9 @@ class TrackNamesAuditor(BaseAuditor):
             if item.custom_name:
                 expected_name = item.custom_name
             else:
-                expected_name = item.track.props.name or ""
+                expected_name = (
+                    item.track.props.name or ""
+                ).strip() or suggest_track_name(item.track)
 
             actual_name = final_tracks[i].get("properties", {}).get("track_name", "")
 
@@ -0,0 +1,43 @@
+# vsg_core/postprocess/checksum.py
+"""
+SHA-256 of the written output files (``checksum_output``).
+
+Recorded in the job result and the batch report, so a copy of the file
+can be verified later (after a transfer, or when archiving). The file is
+read in chunks, never whole; for a large remux this is a full extra read
+of the output, hence optional.
+"""
+
+from __future__ import annotations
+
+import hashlib
+from dataclasses import dataclass
+from pathlib import Path
+
+_CHUNK_BYTES = 1024 * 1024
+
+
+@dataclass(frozen=True, slots=True)
+class OutputChecksum:
+    """Size and SHA-256 of one output file."""
+
+    path: str
+    size_bytes: int
+    sha256: str
+
+    def describe(self) -> str:
+        return (
+            f"{Path(self.path).name}: {self.size_bytes:,} bytes "
+            f"({self.size_bytes / (1024 * 1024):.2f} MiB), SHA-256 {self.sha256}"
+        )
+
+
+def sha256_file(path: str | Path) -> OutputChecksum:
+    """Hashes ``path`` in 1 MiB chunks."""
+    digest = hashlib.sha256()
+    size = 0
+    with open(path, "rb") as f:
+        while chunk := f.read(_CHUNK_BYTES):
+            digest.update(chunk)
+            size += len(chunk)
+    return OutputChecksum(str(path), size, digest.hexdigest())
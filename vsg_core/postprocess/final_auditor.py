@@ -39,6 +39,7 @@ from .auditors import (
     SubtitleClampingAuditor,
     SubtitleDurationAuditor,
     SubtitleFormatsAuditor,
+    SubtitleLintAuditor,
     TrackFlagsAuditor,
     TrackNamesAuditor,
     TrackOrderAuditor,
@@ -214,6 +215,7 @@ class FinalAuditor:
             ("Audio Duration vs Video", AudioDurationAuditor, True),
             ("Subtitle Duration vs Video", SubtitleDurationAuditor, False),
             ("Subtitle Formats", SubtitleFormatsAuditor, False),
+            ("Subtitle Event Timing", SubtitleLintAuditor, False),
             (
                 "Sliding-Window Verification Confidence",
                 SlidingConfidenceAuditor,
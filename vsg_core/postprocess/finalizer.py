@@ -1,11 +1,11 @@
 # vsg_core/postprocess/finalizer.py
 from __future__ import annotations
 
-import shutil
 from pathlib import Path
 from typing import TYPE_CHECKING
 
 from ..io.runner import CommandRunner
+from .atomic_output import place_output
 from .chapter_backup import extract_chapters_xml, inject_chapters
 
 if TYPE_CHECKING:
@@ -84,7 +84,8 @@ def finalize_merged_file(
         "-map",
         "0",
         "-fflags",
-        "+genpts",
+        # bitexact: fixed UIDs/version tag (reproducible_output)
+        "+genpts+bitexact" if settings.reproducible_output else "+genpts",
         "-avoid_negative_ts",
         "make_zero",
         ffmpeg_temp_output,
@@ -95,7 +96,7 @@ def finalize_merged_file(
         log(
             "[WARNING] Timestamp normalization with FFmpeg failed. The original merged file will be used."
         )
-        shutil.move(ffmpeg_input, str(final_output_path))
+        place_output(Path(ffmpeg_input), final_output_path, settings.atomic_output, log)
         return
 
     # Replace the original with the normalized version
@@ -116,5 +117,5 @@ def finalize_merged_file(
         log("Stripped ENCODER tag successfully.")
 
     # Step 5: Move to final output location
-    shutil.move(str(temp_output_path), str(final_output_path))
+    place_output(temp_output_path, final_output_path, settings.atomic_output, log)
     log("[Finalize] Post-merge finalization complete.")
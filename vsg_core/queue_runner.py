@@ -0,0 +1,171 @@
+# vsg_core/queue_runner.py
+"""
+Batch-parallel job queue execution.
+
+Runs up to ``max_concurrent`` JobPipelines at once on a thread pool. Each
+job gets its own JobPipeline (and therefore its own job log, CommandRunner
+and orchestrator temp dir). Output filenames are de-duplicated up front so
+concurrent jobs never race for the same file in the shared output folder.
+
+A failing job does not affect the others unless ``stop_on_error`` is set,
+in which case jobs that have not started yet are skipped.
+"""
+
+from __future__ import annotations
+
+import threading
+from concurrent.futures import ThreadPoolExecutor, as_completed
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from .errors import ErrorKind, error_kind
+from .models.jobs import PipelineResult
+from .models.source_input import primary_path
+from .pipeline import JobPipeline
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from .models.settings import AppSettings
+
+
+def assign_output_names(jobs: list[dict[str, Any]]) -> list[str]:
+    """Return a unique output filename for each job (by Source 1 name).
+
+    The first job keeps its name; later jobs with the same name get a
+    " (2)", " (3)", ... suffix before the extension.
+    """
+    used: set[str] = set()
+    names: list[str] = []
+    for job in jobs:
+        original = Path(primary_path(job["sources"]["Source 1"])).name
+        candidate = original
+        n = 2
+        while candidate.lower() in used:
+            p = Path(original)
+            candidate = f"{p.stem} ({n}){p.suffix}"
+            n += 1
+        used.add(candidate.lower())
+        names.append(candidate)
+    return names
+
+
+def run_queue(
+    jobs: list[dict[str, Any]],
+    settings: AppSettings,
+    and_merge: bool,
+    output_dir: str,
+    max_concurrent: int,
+    log_callback: Callable[[str], None],
+    progress_callback: Callable[[int, float, float], None],
+    job_finished_callback: Callable[[int, PipelineResult], None] | None = None,
+    stop_on_error: bool = False,
+    debug_manager: Any = None,
+    cancel_event: threading.Event | None = None,
+) -> list[PipelineResult]:
+    """
+    Runs a batch of jobs concurrently.
+
+    Args:
+        jobs: Job dicts (sources, manual_layout, attachment_sources, ...)
+        settings: AppSettings shared (read-only) by all jobs
+        and_merge: Whether to merge or only analyze
+        output_dir: Shared output directory
+        max_concurrent: Maximum number of jobs running at once (>= 1)
+        log_callback: Receives log lines, prefixed with "[Job N]"
+        progress_callback: Called as (job_index, job_fraction, batch_fraction)
+        job_finished_callback: Called as (job_index, result) when a job ends
+        stop_on_error: Skip not-yet-started jobs after the first failure
+        debug_manager: Optional DebugOutputManager for per-job debug paths
+        cancel_event: When set, not-yet-started jobs are skipped
+
+    Returns:
+        PipelineResult per job, in the original job order
+    """
+    total = len(jobs)
+    if total == 0:
+        return []
+
+    output_names = assign_output_names(jobs)
+    fractions = [0.0] * total
+    lock = threading.Lock()
+    abort = cancel_event or threading.Event()
+    results: list[PipelineResult | None] = [None] * total
+
+    def _report(index: int, fraction: float) -> None:
+        with lock:
+            fractions[index] = fraction
+            overall = sum(fractions) / total
+        progress_callback(index, fraction, overall)
+
+    def _run_one(index: int) -> PipelineResult:
+        result = _run_job(index)
+        # Set here, on the job's own thread, so the next job a free worker
+        # picks up already sees it
+        if result.status == "Failed" and stop_on_error and not abort.is_set():
+            log_callback(
+                f"[Queue] Job {index + 1} failed; stopping remaining jobs "
+                f"(stop on error)."
+            )
+            abort.set()
+        return result
+
+    def _run_job(index: int) -> PipelineResult:
+        job = jobs[index]
+        sources = job["sources"]
+        name = output_names[index]
+        if abort.is_set():
+            log_callback(f"[Queue] Skipping job {index + 1}/{total}: {name}")
+            return PipelineResult(
+                status="Failed",
+                name=name,
+                error="Skipped",
+                error_kind=ErrorKind.CANCELLED,
+            )
+
+        debug_paths = None
+        if debug_manager:
+            from vsg_core.reporting import DebugPathResolver
+
+            debug_paths = debug_manager.register_job(
+                DebugPathResolver.sanitize_job_name(name)
+            )
+
+        pipeline = JobPipeline(
+            config=settings,
+            log_callback=lambda msg: log_callback(f"[Job {index + 1}] {msg}"),
+            progress_callback=lambda frac: _report(index, frac),
+        )
+        try:
+            return pipeline.run_job(
+                sources=sources,
+                and_merge=and_merge,
+                output_dir_str=output_dir,
+                manual_layout=job.get("manual_layout"),
+                attachment_sources=job.get("attachment_sources"),
+                attachment_selection=job.get("attachment_selection"),
+                source_settings=job.get("source_settings"),
+                chapter_source=job.get("chapter_source") or "Source 1",
+                debug_paths=debug_paths,
+                output_name=name,
+                settings_overrides=job.get("settings_overrides"),
+            )
+        except Exception as e:
+            return PipelineResult(
+                status="Failed", name=name, error=str(e), error_kind=error_kind(e)
+            )
+
+    workers = max(1, min(max_concurrent, total))
+    log_callback(f"[Queue] Running {total} job(s), up to {workers} at a time.")
+
+    with ThreadPoolExecutor(max_workers=workers, thread_name_prefix="vsg_job") as pool:
+        futures = {pool.submit(_run_one, i): i for i in range(total)}
+        for future in as_completed(futures):
+            index = futures[future]
+            result = future.result()
+            results[index] = result
+            _report(index, 1.0)
+            if job_finished_callback:
+                job_finished_callback(index, result)
+
+    return [r for r in results if r is not None]
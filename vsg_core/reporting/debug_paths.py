@@ -14,7 +14,8 @@ Path Structure:
               ├── ocr_debug/
               ├── frame_audit/
               ├── visual_verify/
-              └── sliding_verify/
+              ├── sliding_verify/
+              └── analysis_chunks/{job_name}/
 
     Batch Mode:
         output_folder/batch_name/
@@ -64,6 +65,7 @@ class DebugOutputPaths:
     visual_verify_dir: Path | None  # debug/visual_verify/
     sliding_verify_dir: Path | None  # debug/sliding_verify/
     bitmap_timing_dir: Path | None = None  # debug/bitmap_timing/
+    analysis_chunks_dir: Path | None = None  # debug/analysis_chunks/{job_name}/
 
     def should_create_debug_root(self) -> bool:
         """Check if any debug feature is enabled."""
@@ -74,6 +76,7 @@ class DebugOutputPaths:
                 self.visual_verify_dir,
                 self.sliding_verify_dir,
                 self.bitmap_timing_dir,
+                self.analysis_chunks_dir,
             ]
         )
 
@@ -90,6 +93,8 @@ class DebugOutputPaths:
             features.append("sliding_verify")
         if self.bitmap_timing_dir:
             features.append("bitmap_timing")
+        if self.analysis_chunks_dir:
+            features.append("analysis_chunks")
         return features
 
 
@@ -159,6 +164,12 @@ class DebugPathResolver:
         if bitmap_timing_enabled:
             bitmap_timing_dir = debug_root / "bitmap_timing"
 
+        # Resolve correlation chunk dump path (per job: files are named by
+        # source and chunk index only)
+        analysis_chunks_dir = None
+        if settings.analysis_dump_chunks:
+            analysis_chunks_dir = debug_root / "analysis_chunks" / job_name
+
         return DebugOutputPaths(
             output_dir=output_dir,
             job_name=job_name,
@@ -169,6 +180,7 @@ class DebugPathResolver:
             visual_verify_dir=visual_verify_dir,
             sliding_verify_dir=sliding_verify_dir,
             bitmap_timing_dir=bitmap_timing_dir,
+            analysis_chunks_dir=analysis_chunks_dir,
         )
 
     @staticmethod
@@ -123,9 +123,12 @@ class ReportWriter:
             "name": job_result.get("name", "Unknown"),
             "status": job_result.get("status", "Unknown"),
             "output_path": job_result.get("output"),
+            "output_paths": job_result.get("outputs", []),
             "completed_at": datetime.now().isoformat(),
             "delays": job_result.get("delays", {}),
             "error": job_result.get("error"),
+            "error_kind": job_result.get("error_kind"),
+            "temp_dir": job_result.get("temp_dir"),  # Kept work dir of a failed job
             # Stepping information
             "stepping": {
                 "applied_to": job_result.get("stepping_sources", []),
@@ -138,8 +141,14 @@ class ReportWriter:
                 "total_issues": job_result.get("issues", 0),
                 "details": job_result.get("audit_details", []),
             },
+            # Measured output tracks (VerifyStep)
+            "track_stats": job_result.get("track_stats", []),
             # Sync stability (correlation variance)
             "sync_stability": job_result.get("sync_stability_issues", []),
+            # Non-fatal mkvmerge warnings (exit code 1)
+            "mux_warnings": job_result.get("mux_warnings", []),
+            # Size + SHA-256 of each output file (checksum_output)
+            "checksums": job_result.get("checksums", []),
             # Validator issues (for future expansion)
             "validator_issues": job_result.get("validator_issues", []),
         }
@@ -165,6 +174,7 @@ class ReportWriter:
         successful = 0
         warnings = 0
         failed = 0
+        needs_review = 0
         total_issues = 0
         stepping_jobs = []
         stepping_disabled_jobs = []
@@ -176,7 +186,9 @@ class ReportWriter:
 
             if status == "Failed":
                 failed += 1
-            elif issues > 0:
+            elif status == "Needs Review":
+                needs_review += 1
+            elif issues > 0 or job.get("mux_warnings"):
                 warnings += 1
             else:
                 successful += 1
@@ -221,6 +233,7 @@ class ReportWriter:
             "successful": successful,
             "warnings": warnings,
             "failed": failed,
+            "needs_review": needs_review,
             "total_issues": total_issues,
             "stepping_jobs": stepping_jobs,
             "stepping_disabled_jobs": stepping_disabled_jobs,
@@ -320,12 +333,13 @@ class ReportWriter:
             job: A job entry from the report
 
         Returns:
-            Status string like "Success", "Warning (3 issues)", "Failed"
+            Status string like "Success", "Warning (3 issues)", "Failed",
+            "Needs Review"
         """
         status = job.get("status", "Unknown")
 
-        if status == "Failed":
-            return "Failed"
+        if status in ("Failed", "Needs Review"):
+            return status
 
         issues = job.get("audit_results", {}).get("total_issues", 0)
         if issues > 0:
@@ -0,0 +1,228 @@
+# vsg_core/subtitle_sync.py
+"""
+Subtitle-only sync: retime one subtitle file against a reference video.
+
+For the common quick task "I have a video and a subtitle, give me the
+subtitle in sync" without setting up a mux job. The offset is either given
+by hand or measured by the regular analysis (audio correlation, or
+VideoDiff) between the reference video and the video the subtitle was
+timed to. The chosen sync mode is then applied exactly as in a full job,
+through the same dispatcher, and the retimed subtitle is written as SRT or
+ASS. Nothing is extracted or muxed.
+
+The subtitle is timed against the reference video as it is on disk, so
+the global shift a mux job would add (to avoid negative delays) is not
+applied. Time-based sync always writes the delay into the events
+(``time_based_use_raw_values``), since there is no mkvmerge --sync to
+carry it.
+"""
+
+from __future__ import annotations
+
+import shutil
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from .extraction.tool_versions import tool_versions
+from .io.runner import CommandRunner
+from .models.jobs import Delays, PlanItem
+from .models.media import StreamProps, Track
+from .models.overrides import apply_overrides
+from .models.types import SubtitleOutputFormatStr
+from .orchestrator.work_dir import WorkDir
+from .pipeline_components import ToolValidator
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from .models.settings import AppSettings
+
+_CODEC_IDS = {".ass": "S_TEXT/ASS", ".ssa": "S_TEXT/SSA", ".srt": "S_TEXT/UTF8"}
+
+
+@dataclass(frozen=True, slots=True)
+class SubtitleSyncOptions:
+    """What to do with the subtitle; empty values fall back to settings."""
+
+    offset_ms: float | None = None  # Manual offset; None = analyze
+    source_video: str = ""  # Video the subtitle is timed to (analysis, VV)
+    sync_mode: str = ""  # "" = settings.subtitle_sync_mode
+    output_path: str = ""  # "" = "<name>.synced.<ext>" next to the subtitle
+    output_format: SubtitleOutputFormatStr = "keep"
+
+
+@dataclass(frozen=True, slots=True)
+class SubtitleSyncResult:
+    output_path: Path
+    delay_ms: float  # Offset the sync mode started from
+    sync_mode: str
+    events: int
+    summary: str
+
+
+def _output_path(subtitle: Path, options: SubtitleSyncOptions) -> Path:
+    suffix = subtitle.suffix.lower()
+    if options.output_format != "keep":
+        suffix = f".{options.output_format}"
+    if options.output_path:
+        return Path(options.output_path).with_suffix(suffix)
+    return subtitle.with_name(f"{subtitle.stem}.synced{suffix}")
+
+
+def _analyze_offset(
+    video: str,
+    source_video: str,
+    settings: AppSettings,
+    tool_paths: dict[str, str | None],
+    log: Callable[[str], None],
+    progress: Callable[[float], None],
+    work_dir: WorkDir,
+) -> float:
+    """Delay of ``source_video`` against ``video``, without the global shift."""
+    from .orchestrator.pipeline import Orchestrator
+
+    ctx = Orchestrator().run(
+        settings=settings,
+        tool_paths=tool_paths,
+        log=log,
+        progress=progress,
+        sources={"Source 1": video, "Source 2": source_video},
+        and_merge=False,
+        output_dir=str(work_dir.root),
+        manual_layout=[],
+        attachment_sources=[],
+        temp_dir=work_dir.root,
+    )
+    delays = ctx.delays
+    if delays is None or "Source 2" not in delays.raw_source_delays_ms:
+        raise RuntimeError("Analysis did not produce a delay for the subtitle.")
+    return delays.raw_source_delays_ms["Source 2"] - delays.raw_global_shift_ms
+
+
+def sync_subtitle(
+    video: str,
+    subtitle: str,
+    options: SubtitleSyncOptions,
+    settings: AppSettings,
+    log: Callable[[str], None],
+    progress: Callable[[float], None] | None = None,
+) -> SubtitleSyncResult:
+    """
+    Retime ``subtitle`` to ``video`` and write the result.
+
+    Raises:
+        FileNotFoundError: Missing input file or required tool
+        ValueError: Unsupported subtitle format, or no way to get an offset
+        RuntimeError: Analysis or sync failed
+    """
+    from .orchestrator.steps.context import Context
+    from .subtitles.data import SubtitleData
+    from .subtitles.sync_dispatcher import apply_sync_mode
+
+    progress = progress or (lambda _f: None)
+    subtitle_path = Path(subtitle)
+    for path in (video, subtitle, options.source_video):
+        if path and not Path(path).is_file():
+            raise FileNotFoundError(f"File not found: {path}")
+    if subtitle_path.suffix.lower() not in _CODEC_IDS:
+        raise ValueError(
+            f"Unsupported subtitle format '{subtitle_path.suffix}' (SRT, ASS, SSA)."
+        )
+    if options.offset_ms is None and not options.source_video:
+        raise ValueError("Give a manual offset or the subtitle's source video.")
+
+    sync_mode = options.sync_mode or settings.subtitle_sync_mode
+    if sync_mode == "video-verified" and not options.source_video:
+        raise ValueError("Video-verified sync needs the subtitle's source video.")
+    if not settings.time_based_use_raw_values:
+        # Time-based otherwise leaves the delay to mkvmerge --sync, and
+        # there is no mux here
+        settings, _ = apply_overrides(settings, {"time_based_use_raw_values": True})
+
+    tool_paths: dict[str, str | None] = dict(ToolValidator.validate_tools())
+    for line in tool_versions().log_lines():
+        log(line)
+    log(f"=== Subtitle Sync: {subtitle_path.name} -> {Path(video).name} ===")
+
+    work_dir = WorkDir.create(settings.temp_root, video)
+    failed = True
+    try:
+        if options.offset_ms is not None:
+            delay_ms = options.offset_ms
+            log(f"[Subtitle Sync] Manual offset: {delay_ms:+.3f}ms")
+        else:
+            log("--- Analysis Phase ---")
+            delay_ms = _analyze_offset(
+                video,
+                options.source_video,
+                settings,
+                tool_paths,
+                log,
+                lambda f: progress(f * 0.8),
+                work_dir,
+            )
+            log(f"[Subtitle Sync] Measured offset: {delay_ms:+.3f}ms")
+        progress(0.8)
+
+        runner = CommandRunner(settings, log)
+        sources = {"Source 1": video}
+        if options.source_video:
+            sources["Source 2"] = options.source_video
+        ctx = Context(
+            settings=settings,
+            tool_paths=tool_paths,
+            log=log,
+            progress=progress,
+            output_dir=str(_output_path(subtitle_path, options).parent),
+            temp_dir=work_dir.root,
+            sources=sources,
+            source_inputs=sources,
+            delays=Delays(raw_source_delays_ms={"Source 2": delay_ms}),
+        )
+        item = PlanItem(
+            track=Track(
+                source="Source 2",
+                id=0,
+                type="subtitles",
+                props=StreamProps(codec_id=_CODEC_IDS[subtitle_path.suffix.lower()]),
+            ),
+            extracted_path=subtitle_path,
+        )
+
+        subtitle_data = SubtitleData.from_file(subtitle_path)
+        result = apply_sync_mode(
+            item=item,
+            subtitle_data=subtitle_data,
+            ctx=ctx,
+            runner=runner,
+            source1_file=Path(video),
+            sync_mode=sync_mode,
+            scene_cache={},
+        )
+        if not result.success:
+            raise RuntimeError(f"Subtitle sync failed: {result.error or 'unknown'}")
+
+        output_path = _output_path(subtitle_path, options)
+        clock = result.details.get("frame_clock") if result.details else None
+        subtitle_data.save(
+            output_path, rounding=settings.subtitle_rounding, clock=clock
+        )
+        log(
+            f"[Subtitle Sync] Wrote {output_path} "
+            f"({len(subtitle_data.events)} events, {sync_mode})"
+        )
+        progress(1.0)
+        failed = False
+        return SubtitleSyncResult(
+            output_path=output_path,
+            delay_ms=delay_ms,
+            sync_mode=sync_mode,
+            events=len(subtitle_data.events),
+            summary=result.summary,
+        )
+    finally:
+        if failed and settings.keep_temp_on_failure:
+            log(f"[Subtitle Sync] Kept work directory: {work_dir.root}")
+        else:
+            shutil.rmtree(work_dir.root, ignore_errors=True)
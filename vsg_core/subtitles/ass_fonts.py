@@ -0,0 +1,181 @@
+# vsg_core/subtitles/ass_fonts.py
+"""
+Font collection for ASS/SSA subtitles.
+
+Renderers (libass) only find fonts that are attached to the Matroska file
+or installed on the player. ``collect_fonts`` lists every font a script
+actually uses — style ``Fontname`` fields plus inline ``\\fn`` overrides,
+following ``\\r`` resets — together with the characters drawn in it.
+``match_fonts`` resolves those names against font files the same way
+libass does (family or full name, case-insensitive), and ``subset_font``
+optionally strips a matched font down to the glyphs in use (fontTools).
+"""
+
+from __future__ import annotations
+
+import hashlib
+import re
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from .data import SubtitleData
+
+_OVERRIDE_BLOCK = re.compile(r"\{([^}]*)\}")
+_TAG = re.compile(r"\\(fn|r)([^\\]*)")
+
+# Font formats fontTools can subset in place
+_SUBSETTABLE = {".ttf", ".otf"}
+
+
+@dataclass(frozen=True, slots=True)
+class FontRef:
+    """A font referenced by a script, and what it is used for."""
+
+    name: str  # As written in the script, minus a vertical-text '@'
+    styles: tuple[str, ...]  # Styles whose Fontname is this font
+    inline: bool  # Referenced by at least one \fn override
+    chars: frozenset[str]  # Characters drawn in this font
+
+    @property
+    def key(self) -> str:
+        return _font_key(self.name)
+
+
+def _font_key(name: str) -> str:
+    return name.strip().lstrip("@").strip().casefold()
+
+
+def _visible_text(text: str) -> str:
+    return text.replace("\\N", "").replace("\\n", "").replace("\\h", " ")
+
+
+def collect_fonts(data: SubtitleData) -> list[FontRef]:
+    """Fonts used by the styles and events of ``data``, in first-use order.
+
+    Styles that no event uses still count (their font is requested when
+    the script is loaded). Comment events are ignored.
+    """
+    style_fonts = {name: style.fontname for name, style in data.styles.items()}
+    names: dict[str, str] = {}
+    styles: dict[str, list[str]] = {}
+    inline: set[str] = set()
+    chars: dict[str, set[str]] = {}
+
+    def use(name: str) -> str:
+        key = _font_key(name)
+        if key:
+            names.setdefault(key, name.strip().lstrip("@").strip())
+            chars.setdefault(key, set())
+        return key
+
+    for style_name, font in style_fonts.items():
+        key = use(font)
+        if key:
+            styles.setdefault(key, []).append(style_name)
+
+    for event in data.events:
+        if event.is_comment:
+            continue
+        base = style_fonts.get(event.style, style_fonts.get("Default", "Arial"))
+        current = use(base)
+        pos = 0
+        for block in _OVERRIDE_BLOCK.finditer(event.text):
+            if current:
+                chars[current].update(_visible_text(event.text[pos : block.start()]))
+            pos = block.end()
+            for tag, arg in _TAG.findall(block.group(1)):
+                arg = arg.strip()
+                if tag == "r":
+                    current = use(style_fonts.get(arg, base) if arg else base)
+                elif arg:
+                    current = use(arg)
+                    inline.add(current)
+                else:  # Bare \fn resets to the style font
+                    current = use(base)
+        if current:
+            chars[current].update(_visible_text(event.text[pos:]))
+
+    return [
+        FontRef(
+            name=name,
+            styles=tuple(styles.get(key, ())),
+            inline=key in inline,
+            chars=frozenset(chars[key]),
+        )
+        for key, name in names.items()
+    ]
+
+
+def _font_names(path: Path) -> set[str]:
+    """Names libass would match for a font file (family, full name, PS name)."""
+    from vsg_core.font_manager import FontInfo
+
+    info = FontInfo(path)
+    names = {info.family_name, info.full_name, info.postscript_name}
+    if not info.is_valid:
+        names.add(path.stem)
+    return {_font_key(n) for n in names if n}
+
+
+def match_fonts(
+    refs: list[FontRef], font_files: list[Path]
+) -> tuple[dict[str, list[Path]], list[FontRef]]:
+    """Match references against font files.
+
+    Returns:
+        ``(matches, missing)``: font key -> matching files (every face of a
+        family is kept, so bold/italic variants still resolve), and the
+        references with no matching file.
+    """
+    index: dict[str, list[Path]] = {}
+    for path in font_files:
+        for name in _font_names(path):
+            index.setdefault(name, []).append(path)
+
+    matches: dict[str, list[Path]] = {}
+    missing: list[FontRef] = []
+    for ref in refs:
+        files = index.get(ref.key)
+        if files:
+            matches[ref.key] = files
+        else:
+            missing.append(ref)
+    return matches, missing
+
+
+def subset_font(path: Path, chars: set[str], out_dir: Path) -> Path | None:
+    """Write a copy of ``path`` reduced to ``chars``.
+
+    Font names are kept so the subset still matches the script. The copy
+    keeps the file name (it becomes the attachment name) inside a folder
+    named after a hash of the source path, so same-named fonts from
+    different sources don't overwrite each other. Returns None (keep the
+    full font) for unsupported formats, when fontTools is missing, or when
+    subsetting fails.
+    """
+    if path.suffix.lower() not in _SUBSETTABLE or not chars:
+        return None
+    try:
+        from fontTools import subset
+    except ImportError:
+        return None
+
+    options = subset.Options()
+    options.name_IDs = ["*"]
+    options.name_languages = ["*"]
+    options.notdef_outline = True
+    options.layout_features = ["*"]
+    try:
+        font = subset.load_font(str(path), options)
+        subsetter = subset.Subsetter(options)
+        subsetter.populate(text="".join(sorted(chars)))
+        subsetter.subset(font)
+        key = hashlib.sha256(str(path.resolve()).encode("utf-8")).hexdigest()
+        out_path = out_dir / key[:12] / path.name
+        out_path.parent.mkdir(parents=True, exist_ok=True)
+        subset.save_font(font, str(out_path), options)
+    except Exception:
+        return None
+    return out_path
@@ -767,6 +767,8 @@ class SubtitleData:
 
     # ASS Script Info (preserved in order)
     script_info: OrderedDict = field(default_factory=OrderedDict)
+    # Raw [Script Info] lines (comments, blank lines and spacing kept verbatim)
+    script_info_lines: list[str] = field(default_factory=list)
 
     # Aegisub sections (preserved in order)
     aegisub_garbage: OrderedDict = field(default_factory=OrderedDict)
@@ -1143,6 +1145,44 @@ class SubtitleData:
 
         return apply_stepping(self, edl_segments, boundary_mode, runner)
 
+    def retime_fps(
+        self, src_fps: float, dst_fps: float, rounding: str = "round", runner=None
+    ) -> OperationResult:
+        """
+        Convert timing from one framerate to another (e.g. 25 -> 23.976).
+
+        Args:
+            src_fps: Framerate the subtitles are timed for
+            dst_fps: Framerate of the target video
+            rounding: Rounding mode ("floor", "round", "ceil")
+            runner: CommandRunner for logging
+
+        Returns:
+            OperationResult
+        """
+        from .operations.retime import retime_fps
+
+        return retime_fps(self, src_fps, dst_fps, rounding, runner)
+
+    def bake_shift(
+        self, delay_ms: float, fps: float, rounding: str = "floor", runner=None
+    ) -> OperationResult:
+        """
+        Bake a delay into the events, snapping each boundary to a frame.
+
+        Args:
+            delay_ms: Delay to apply
+            fps: Framerate of the target video
+            rounding: Rounding mode onto the frame grid ("floor", "round", "ceil")
+            runner: CommandRunner for logging
+
+        Returns:
+            OperationResult
+        """
+        from .operations.bake_shift import bake_shift
+
+        return bake_shift(self, delay_ms, fps, rounding, runner)
+
     def apply_style_patch(
         self, patches: dict[str, dict[str, Any]], runner=None
     ) -> OperationResult:
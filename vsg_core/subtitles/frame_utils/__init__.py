@@ -7,6 +7,8 @@ This package has been modularized for better maintainability:
 - video_properties.py: Video property detection (FPS, interlacing, resolution)
 - video_reader.py: Multi-backend video reader (VapourSynth, FFMS2, OpenCV, FFmpeg)
 - frame_hashing.py: Perceptual hash and frame comparison functions
+- hash_index.py: Precomputed per-frame hash index of a whole video
+- frame_table.py: Explicit per-frame grid from timestamps (VFR)
 - frame_audit.py: Frame alignment audit (centisecond rounding drift)
 - surgical_rounding.py: Surgical frame-aware rounding (floor→ceil when needed)
 - visual_verify.py: Visual frame verification (SSIM-based)
@@ -40,6 +42,7 @@ from .frame_hashing import (
     MultiMetricResult,
     compare_frames,
     compare_frames_multi,
+    compute_block_hash,
     compute_frame_hash,
     compute_hamming_distance,
     compute_mse,
@@ -47,6 +50,16 @@ from .frame_hashing import (
     compute_ssim,
 )
 
+# ============================================================================
+# Explicit frame grid from timestamps (VFR)
+# ============================================================================
+from .frame_table import FrameTable, load_frame_table
+
+# ============================================================================
+# Whole-video hash index
+# ============================================================================
+from .hash_index import HashIndex, build_hash_index, get_hash_index
+
 # ============================================================================
 # Surgical rounding (frame-aware fix)
 # ============================================================================
@@ -115,6 +128,8 @@ __all__ = [
     "FrameAuditResult",
     "FrameClock",
     "FrameShift",
+    "FrameTable",
+    "HashIndex",
     "MultiMetricResult",
     "RegionStats",
     "SampleResult",
@@ -123,10 +138,12 @@ __all__ = [
     "SurgicalRoundResult",
     "VideoReader",
     "VisualVerifyResult",
+    "build_hash_index",
     "clear_vfr_cache",
     "compare_frames",
     "compare_frames_multi",
     "compare_video_properties",
+    "compute_block_hash",
     "compute_frame_hash",
     "compute_hamming_distance",
     "compute_mse",
@@ -139,9 +156,11 @@ __all__ = [
     "frame_to_time_aegisub",
     "frame_to_time_floor",
     "frame_to_time_middle",
+    "get_hash_index",
     "get_vfr_timestamps",
     "get_video_duration_ms",
     "get_video_properties",
+    "load_frame_table",
     "run_frame_audit",
     "run_visual_verify",
     "surgical_round_batch",
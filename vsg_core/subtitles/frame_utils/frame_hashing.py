@@ -3,10 +3,17 @@
 Frame hashing and comparison functions for video sync verification.
 
 Contains:
-- Perceptual hash computation (phash, dhash, average_hash, whash)
+- Perceptual hash computation (phash, dhash, average_hash, whash, blockhash)
 - SSIM (Structural Similarity Index) comparison
 - MSE (Mean Squared Error) comparison
 - Unified frame comparison interface
+
+SSIM and MSE can run on downscaled frames (``downscale_width``, off by
+default). Structural similarity survives downscaling well, and comparing
+at ~256 px wide is far faster than at full resolution. There is no
+separate setting for it: callers on the video-verified path pass
+``settings.video_verified_ssim_input_size``, the size the GPU SSIM backend
+scores at, so CPU and GPU comparisons see the same detail.
 """
 
 from __future__ import annotations
@@ -48,11 +55,13 @@ def compute_perceptual_hash(
     - phash: Perceptual hash - best for heavy re-encoding, color grading
     - average_hash: Simple averaging - fast but less accurate
     - whash: Wavelet hash - very robust but slower
+    - blockhash: Block-mean hash - robust to scaling (partial matching)
 
     Args:
         image_data: PNG/JPEG image data as bytes
         runner: CommandRunner for logging
-        algorithm: Hash algorithm to use (dhash, phash, average_hash, whash)
+        algorithm: Hash algorithm to use (dhash, phash, average_hash, whash,
+            blockhash)
         hash_size: Hash size (4, 8, 16) - larger = more precise but less tolerant
 
     Returns:
@@ -71,6 +80,8 @@ def compute_perceptual_hash(
             hash_obj = imagehash.average_hash(img, hash_size=hash_size)
         elif algorithm == "whash":
             hash_obj = imagehash.whash(img, hash_size=hash_size)
+        elif algorithm == "blockhash":
+            hash_obj = compute_block_hash(img, hash_size=hash_size)
         else:  # dhash (default)
             hash_obj = imagehash.dhash(img, hash_size=hash_size)
 
@@ -99,7 +110,8 @@ def compute_frame_hash(
     Args:
         frame: PIL Image object
         hash_size: Hash size (8x8 = 64 bits, 16x16 = 256 bits)
-        method: Hash method ('phash', 'dhash', 'average_hash', 'whash')
+        method: Hash method ('phash', 'dhash', 'average_hash', 'whash',
+            'blockhash')
 
     Returns:
         ImageHash object, or None on failure
@@ -113,6 +125,8 @@ def compute_frame_hash(
             return imagehash.average_hash(frame, hash_size=hash_size)
         elif method == "whash":
             return imagehash.whash(frame, hash_size=hash_size)
+        elif method == "blockhash":
+            return compute_block_hash(frame, hash_size=hash_size)
         else:  # 'phash' or default
             return imagehash.phash(frame, hash_size=hash_size)
 
@@ -122,6 +136,39 @@ def compute_frame_hash(
         return None
 
 
+def compute_block_hash(frame: Image.Image, hash_size: int = 8) -> Any:
+    """
+    Block-mean hash (blockhash) of a frame.
+
+    The frame is split into ``hash_size × hash_size`` blocks and each
+    block's mean brightness is compared against the median of its
+    horizontal band (a quarter of the rows), so a bright sky doesn't set
+    every bit in the top half. Block means come from an area-average
+    resize, which makes the hash stable under scaling and mild re-encoding.
+
+    Args:
+        frame: PIL Image object
+        hash_size: Blocks per side (8 = 64 bits, 16 = 256 bits)
+
+    Returns:
+        ImageHash object (Hamming distance via subtraction, like the others)
+    """
+    import imagehash
+    import numpy as np
+    from PIL import Image as PILImage
+
+    gray = frame.convert("L").resize((hash_size, hash_size), PILImage.Resampling.BOX)
+    blocks = np.asarray(gray, dtype=np.float64)
+
+    bands = 4 if hash_size % 4 == 0 else 1
+    bits = np.zeros_like(blocks, dtype=bool)
+    band_rows = hash_size // bands
+    for b in range(bands):
+        band = blocks[b * band_rows : (b + 1) * band_rows]
+        bits[b * band_rows : (b + 1) * band_rows] = band > np.median(band)
+    return imagehash.ImageHash(bits)
+
+
 def compute_hamming_distance(hash1, hash2) -> int:
     """
     Compute Hamming distance between two perceptual hashes.
@@ -139,10 +186,33 @@ def compute_hamming_distance(hash1, hash2) -> int:
     return hash1 - hash2
 
 
+def _grayscale_pair(
+    frame1: Image.Image, frame2: Image.Image, downscale_width: int = 0
+) -> tuple[Image.Image, Image.Image]:
+    """Both frames as grayscale at a common size.
+
+    The size is frame1's, or ``downscale_width`` wide (aspect kept) when that
+    is set and smaller. LANCZOS keeps the downscale anti-aliased.
+    """
+    from PIL import Image as PILImage
+
+    width, height = frame1.size
+    if 0 < downscale_width < width:
+        height = max(1, round(height * downscale_width / width))
+        width = downscale_width
+    gray1, gray2 = frame1.convert("L"), frame2.convert("L")
+    if gray1.size != (width, height):
+        gray1 = gray1.resize((width, height), PILImage.Resampling.LANCZOS)
+    if gray2.size != (width, height):
+        gray2 = gray2.resize((width, height), PILImage.Resampling.LANCZOS)
+    return gray1, gray2
+
+
 def compute_ssim(
     frame1: Image.Image,
     frame2: Image.Image,
     use_global: bool = False,
+    downscale_width: int = 0,
 ) -> float:
     """
     Compute Structural Similarity Index (SSIM) between two frames.
@@ -158,6 +228,8 @@ def compute_ssim(
             pixel differences, which is important for deinterlaced content
             where bwdif interpolation creates local variations between
             different encodes even when the overall image is the same scene.
+        downscale_width: Compare at this width (aspect kept); 0 = frame1's
+            full resolution.
 
     Returns:
         SSIM value from 0.0 to 1.0. Higher = more similar.
@@ -166,17 +238,10 @@ def compute_ssim(
     try:
         import numpy as np
 
-        # Convert to grayscale numpy arrays
-        arr1 = np.array(frame1.convert("L"))
-        arr2 = np.array(frame2.convert("L"))
-
-        # Resize to match if needed
-        if arr1.shape != arr2.shape:
-            from PIL import Image as PILImage
-
-            # Resize frame2 to match frame1
-            frame2_resized = frame2.resize(frame1.size, PILImage.Resampling.LANCZOS)
-            arr2 = np.array(frame2_resized.convert("L"))
+        # Grayscale at a common (optionally downscaled) size
+        gray1, gray2 = _grayscale_pair(frame1, frame2, downscale_width)
+        arr1 = np.array(gray1)
+        arr2 = np.array(gray2)
 
         if not use_global:
             # Try scikit-image SSIM first (windowed, most accurate for CFR/progressive)
@@ -210,7 +275,9 @@ def compute_ssim(
         return 0.0
 
 
-def compute_mse(frame1: Image.Image, frame2: Image.Image) -> float:
+def compute_mse(
+    frame1: Image.Image, frame2: Image.Image, downscale_width: int = 0
+) -> float:
     """
     Compute Mean Squared Error between two frames.
 
@@ -219,6 +286,8 @@ def compute_mse(frame1: Image.Image, frame2: Image.Image) -> float:
     Args:
         frame1: First PIL Image object
         frame2: Second PIL Image object
+        downscale_width: Compare at this width (aspect kept); 0 = frame1's
+            full resolution.
 
     Returns:
         MSE value. Lower = more similar.
@@ -226,15 +295,9 @@ def compute_mse(frame1: Image.Image, frame2: Image.Image) -> float:
     try:
         import numpy as np
 
-        arr1 = np.array(frame1.convert("L"), dtype=np.float64)
-        arr2 = np.array(frame2.convert("L"), dtype=np.float64)
-
-        # Resize to match if needed
-        if arr1.shape != arr2.shape:
-            from PIL import Image as PILImage
-
-            frame2_resized = frame2.resize(frame1.size, PILImage.Resampling.LANCZOS)
-            arr2 = np.array(frame2_resized.convert("L"), dtype=np.float64)
+        gray1, gray2 = _grayscale_pair(frame1, frame2, downscale_width)
+        arr1 = np.array(gray1, dtype=np.float64)
+        arr2 = np.array(gray2, dtype=np.float64)
 
         mse = np.mean((arr1 - arr2) ** 2)
         return float(mse)
@@ -251,6 +314,7 @@ def compare_frames(
     hash_size: int = 8,
     threshold: int | None = None,
     use_global_ssim: bool = False,
+    downscale_width: int = 0,
 ) -> tuple:
     """
     Compare two frames using the specified method.
@@ -267,6 +331,8 @@ def compare_frames(
         use_global_ssim: If True, use global mean/variance SSIM instead of
             scikit-image windowed SSIM. Important for interlaced content where
             deinterlace interpolation causes local differences between encodes.
+        downscale_width: Width (px) SSIM/MSE compare at; 0 = full resolution.
+            Not used for hashing, which already works on a tiny thumbnail.
 
     Returns:
         Tuple of (distance, is_match):
@@ -279,7 +345,12 @@ def compare_frames(
     - mse: MSE / 100 capped at 100 (0=identical, <5=match, >10=different)
     """
     if method == "ssim":
-        ssim = compute_ssim(frame1, frame2, use_global=use_global_ssim)
+        ssim = compute_ssim(
+            frame1,
+            frame2,
+            use_global=use_global_ssim,
+            downscale_width=downscale_width,
+        )
         # Convert to distance (0 = identical, higher = more different)
         distance = (1.0 - ssim) * 100  # Scale to ~0-100 range
         max_dist = threshold if threshold is not None else 10
@@ -287,7 +358,7 @@ def compare_frames(
         return (distance, is_match)
 
     elif method == "mse":
-        mse = compute_mse(frame1, frame2)
+        mse = compute_mse(frame1, frame2, downscale_width=downscale_width)
         # Normalize to ~0-100 range (assuming 8-bit images)
         distance = min(mse / 100, 100)  # Cap at 100
         max_dist = threshold if threshold is not None else 5
@@ -316,6 +387,7 @@ def compare_frames_multi(
     ssim_threshold: int = 10,
     mse_threshold: int = 5,
     use_global_ssim: bool = False,
+    downscale_width: int = 0,
 ) -> MultiMetricResult:
     """
     Compare two frames using ALL metrics (phash, SSIM, MSE) in a single call.
@@ -332,6 +404,7 @@ def compare_frames_multi(
         ssim_threshold: Max SSIM distance for match (default 10 = SSIM > 0.90)
         mse_threshold: Max MSE distance for match (default 5 = MSE < 500)
         use_global_ssim: If True, use global SSIM (better for interlaced content)
+        downscale_width: Width (px) SSIM/MSE compare at; 0 = full resolution
 
     Returns:
         MultiMetricResult with all three metric distances and match booleans
@@ -349,14 +422,9 @@ def compare_frames_multi(
         pass
 
     # --- Convert to grayscale arrays ONCE for SSIM + MSE ---
-    arr1 = np.array(frame1.convert("L"), dtype=np.float64)
-    arr2 = np.array(frame2.convert("L"), dtype=np.float64)
-
-    if arr1.shape != arr2.shape:
-        from PIL import Image as PILImage
-
-        frame2_resized = frame2.resize(frame1.size, PILImage.Resampling.LANCZOS)
-        arr2 = np.array(frame2_resized.convert("L"), dtype=np.float64)
+    gray1, gray2 = _grayscale_pair(frame1, frame2, downscale_width)
+    arr1 = np.array(gray1, dtype=np.float64)
+    arr2 = np.array(gray2, dtype=np.float64)
 
     # --- MSE (trivial, reuse arrays) ---
     mse_val = float(np.mean((arr1 - arr2) ** 2))
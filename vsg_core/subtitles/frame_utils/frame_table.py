@@ -0,0 +1,115 @@
+# vsg_core/subtitles/frame_utils/frame_table.py
+"""
+Explicit frame grid from a timestamps table.
+
+``FrameClock`` derives every frame time from a constant fps, which is wrong
+for VFR and pulldown content. ``FrameTable`` holds the real presentation
+time of every frame (mkvmerge timestamps v2, see
+``vsg_core.extraction.timestamps``) and answers the same questions —
+``frame_ms``, ``frame_of``, ``is_on_frame`` — so it can stand in for a
+clock wherever the grid is only walked, not reasoned about as a rate.
+
+Past the last frame the table continues at the last frame's duration.
+"""
+
+from __future__ import annotations
+
+import math
+from bisect import bisect_left
+from dataclasses import dataclass
+from fractions import Fraction
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+    from pathlib import Path
+
+    from vsg_core.io.runner import CommandRunner
+
+
+@dataclass(frozen=True, slots=True)
+class FrameTable:
+    """Millisecond frame grid read from per-frame timestamps."""
+
+    times_ms: tuple[int, ...]  # Presentation time of each frame, ascending
+
+    def __post_init__(self) -> None:
+        if len(self.times_ms) < 2:
+            raise ValueError("A frame table needs at least two frames")
+
+    @classmethod
+    def from_timestamps(cls, times_ms: Sequence[float]) -> FrameTable:
+        return cls(tuple(sorted(round(t) for t in times_ms)))
+
+    def _last_duration(self) -> int:
+        return max(self.times_ms[-1] - self.times_ms[-2], 1)
+
+    def frame_ms(self, n: int) -> int:
+        """Presentation time (ms) of frame ``n``."""
+        if n < len(self.times_ms):
+            return self.times_ms[max(n, 0)]
+        extra = n - (len(self.times_ms) - 1)
+        return self.times_ms[-1] + extra * self._last_duration()
+
+    def frame_of(self, time_ms: float) -> int:
+        """Index of the first frame at or after ``time_ms``."""
+        if time_ms <= self.times_ms[-1]:
+            return bisect_left(self.times_ms, time_ms)
+        past = time_ms - self.times_ms[-1]
+        return len(self.times_ms) - 1 + math.ceil(past / self._last_duration())
+
+    def is_on_frame(self, time_ms: float) -> bool:
+        """True iff ``time_ms`` sits exactly on a frame (integer-ms match)."""
+        return self.frame_ms(self.frame_of(time_ms)) == round(time_ms)
+
+    @property
+    def fps(self) -> float:
+        """Average frame rate over the table."""
+        span_ms = self.times_ms[-1] - self.times_ms[0]
+        return (len(self.times_ms) - 1) * 1000.0 / span_ms if span_ms else 0.0
+
+    @property
+    def frame_duration_ms(self) -> float:
+        """Average frame period — for logging/reporting only."""
+        return 1000.0 / self.fps if self.fps else 0.0
+
+    def _nominal(self) -> Fraction:
+        from vsg_core.extraction.frame_rate import snap_standard_rate
+
+        standard = snap_standard_rate(self.fps)
+        if standard is not None:
+            return Fraction(*standard)
+        return Fraction(self.fps).limit_denominator(1001)
+
+    @property
+    def num(self) -> int:
+        """Nominal rate numerator (reporting), like ``FrameClock.num``."""
+        return self._nominal().numerator
+
+    @property
+    def den(self) -> int:
+        return self._nominal().denominator
+
+
+def load_frame_table(
+    video_path: str,
+    runner: CommandRunner,
+    tool_paths: dict,
+    temp_dir: Path | None,
+) -> FrameTable | None:
+    """Frame table of ``video_path``'s first video track, or None.
+
+    The timestamps file is cached next to the FFMS2 index, under the same
+    key, so repeated lookups in a job extract it once.
+    """
+    from vsg_core.extraction.timestamps import extract_timestamps
+
+    from .video_reader import _get_ffms2_cache_path
+
+    cache_path = _get_ffms2_cache_path(video_path, temp_dir).with_suffix(
+        ".timestamps_v2.txt"
+    )
+    times = extract_timestamps(video_path, cache_path, runner, tool_paths)
+    if not times or len(times) < 2:
+        return None
+    return FrameTable.from_timestamps(times)
@@ -0,0 +1,225 @@
+# vsg_core/subtitles/frame_utils/hash_index.py
+"""
+Precomputed perceptual-hash index for a whole reference video.
+
+Video-verified sync hashes the same reference (Source 1) frames around the
+same checkpoints on every run. With the pHash/dHash backends, a
+``HashIndex`` holds the hash of every frame of the reference so repeated
+runs (tweaking thresholds, re-running a batch) look the hashes up instead
+of decoding and hashing live.
+
+Hashes are stored as packed bits, one row per frame. ``descriptors`` turns
+rows back into the ±1/sqrt(dim) vectors the cosine-slide harness scores —
+for binary hashes cosine similarity is ``1 - 2 * hamming / dim``, so the
+lookup ranks exactly like a nearest-hamming search.
+
+The index file is keyed like the FFMS2 index (parent dir, name, size,
+mtime) plus the algorithm and hash size, so switching either one builds a
+new index. Unlike the FFMS2 index it is kept in the system temp dir rather
+than the job's, so it survives across jobs. The source size and mtime are
+also stored inside the file and checked on load.
+"""
+
+from __future__ import annotations
+
+import logging
+import os
+from dataclasses import dataclass
+from pathlib import Path
+from types import SimpleNamespace
+from typing import TYPE_CHECKING
+
+import numpy as np
+
+if TYPE_CHECKING:
+    from collections.abc import Callable, Sequence
+
+logger = logging.getLogger(__name__)
+
+# Backends whose descriptors are binary hashes (and can be indexed)
+HASH_INDEX_ALGORITHMS = ("phash", "dhash")
+
+_CHUNK_FRAMES = 2048  # Frames hashed per pass while building
+
+
+@dataclass(slots=True)
+class HashIndex:
+    """Per-frame perceptual hashes of one video."""
+
+    algo: str
+    hash_size: int
+    bits: np.ndarray  # uint8 [num_frames, ceil(hash_size**2 / 8)], packed
+    source_size: int
+    source_mtime_ns: int
+
+    @property
+    def num_frames(self) -> int:
+        return int(self.bits.shape[0])
+
+    @property
+    def dim(self) -> int:
+        return self.hash_size * self.hash_size
+
+    def matches_source(self, video_path: str | Path) -> bool:
+        """True if ``video_path`` is unchanged since the index was built."""
+        stat = os.stat(video_path)
+        return (stat.st_size, stat.st_mtime_ns) == (
+            self.source_size,
+            self.source_mtime_ns,
+        )
+
+    def unpacked(self, frame_nums: Sequence[int]) -> np.ndarray:
+        """0/1 hash bits for ``frame_nums`` (clamped to the video)."""
+        idx = np.clip(np.asarray(frame_nums), 0, self.num_frames - 1)
+        return np.unpackbits(self.bits[idx], axis=1, count=self.dim)
+
+    def descriptors(self, frame_nums: Sequence[int]) -> np.ndarray:
+        """Unit-norm ±1 descriptors, as the hash backends produce them."""
+        bits = self.unpacked(frame_nums).astype(np.float32)
+        return (bits * 2.0 - 1.0) / np.float32(self.dim**0.5)
+
+    def nearest(
+        self, hash_bits: np.ndarray, frame_nums: Sequence[int] | None = None
+    ) -> tuple[int, int]:
+        """Frame whose hash is closest to ``hash_bits`` (0/1, length dim).
+
+        Searches ``frame_nums`` or the whole video. Returns
+        ``(frame, hamming_distance)``.
+        """
+        candidates = (
+            np.arange(self.num_frames) if frame_nums is None else np.asarray(frame_nums)
+        )
+        query = np.packbits(np.asarray(hash_bits, dtype=np.uint8))
+        xor = np.bitwise_xor(self.bits[candidates], query)
+        distances = np.unpackbits(xor, axis=1).sum(axis=1)
+        best = int(np.argmin(distances))
+        return int(candidates[best]), int(distances[best])
+
+    def save(self, path: Path) -> None:
+        path.parent.mkdir(parents=True, exist_ok=True)
+        tmp = path.with_name(path.name + ".tmp")
+        with tmp.open("wb") as f:
+            np.savez(
+                f,
+                bits=self.bits,
+                algo=np.array(self.algo),
+                hash_size=np.array(self.hash_size),
+                source_size=np.array(self.source_size),
+                source_mtime_ns=np.array(self.source_mtime_ns),
+            )
+        tmp.replace(path)
+
+    @classmethod
+    def load(cls, path: Path) -> HashIndex:
+        with np.load(path) as data:
+            return cls(
+                algo=str(data["algo"]),
+                hash_size=int(data["hash_size"]),
+                bits=data["bits"],
+                source_size=int(data["source_size"]),
+                source_mtime_ns=int(data["source_mtime_ns"]),
+            )
+
+
+def hash_index_cache_path(video_path: str, algo: str, hash_size: int) -> Path:
+    """Where the index of ``video_path`` for ``algo``/``hash_size`` lives."""
+    from .video_reader import _get_ffms2_cache_path
+
+    ffindex = _get_ffms2_cache_path(video_path, None)
+    return ffindex.with_name(f"{ffindex.stem}.{algo}{hash_size}.hashidx.npz")
+
+
+def build_hash_index(
+    video_path: str,
+    algo: str,
+    hash_size: int,
+    *,
+    temp_dir: Path | None = None,
+    batch_size: int = 32,
+    log: Callable[[str], None] | None = None,
+) -> HashIndex:
+    """Hash every frame of ``video_path`` with the ``algo`` backend.
+
+    Decodes the whole video once (VapourSynth + FFMS2) and runs the same
+    GPU hash the sliding matcher uses, so indexed and live hashes agree.
+    """
+    if algo not in HASH_INDEX_ALGORITHMS:
+        raise ValueError(
+            f"Hash index supports {HASH_INDEX_ALGORITHMS}, got {algo!r}"
+        )
+    import torch
+    import vapoursynth as vs
+
+    from ..sync_mode_plugins.video_verified.backends import get_backend
+    from ..sync_mode_plugins.video_verified.sliding_core import open_clip
+
+    stat = os.stat(video_path)
+    _, rgb_clip, _ = open_clip(video_path, vs, temp_dir)
+    device = torch.device("cuda" if torch.cuda.is_available() else "cpu")
+    backend = get_backend(algo)
+    backend.load(device, SimpleNamespace(video_verified_hash_size=hash_size))
+    try:
+        rows = []
+        total = rgb_clip.num_frames
+        for start in range(0, total, _CHUNK_FRAMES):
+            frames = list(range(start, min(start + _CHUNK_FRAMES, total)))
+            desc = backend._extract_descriptors(rgb_clip, frames, device, batch_size)
+            rows.append(np.packbits(desc > 0, axis=1))
+            if log:
+                log(f"[HashIndex] Hashed {frames[-1] + 1}/{total} frames")
+    finally:
+        backend.cleanup()
+
+    return HashIndex(
+        algo=algo,
+        hash_size=hash_size,
+        bits=np.concatenate(rows, axis=0),
+        source_size=stat.st_size,
+        source_mtime_ns=stat.st_mtime_ns,
+    )
+
+
+def get_hash_index(
+    video_path: str,
+    algo: str,
+    hash_size: int,
+    *,
+    temp_dir: Path | None = None,
+    batch_size: int = 32,
+    log: Callable[[str], None] | None = None,
+) -> HashIndex:
+    """Cached index for ``video_path``, built (and saved) when missing/stale."""
+    cache_path = hash_index_cache_path(video_path, algo, hash_size)
+    if cache_path.exists():
+        try:
+            index = HashIndex.load(cache_path)
+            if (
+                index.algo == algo
+                and index.hash_size == hash_size
+                and index.matches_source(video_path)
+            ):
+                if log:
+                    log(f"[HashIndex] Using cached index: {cache_path.name}")
+                return index
+        except Exception as e:
+            logger.warning("Unreadable hash index %s: %s", cache_path, e)
+        cache_path.unlink(missing_ok=True)
+
+    if log:
+        log(
+            f"[HashIndex] Building {algo} ({hash_size * hash_size}-bit) index "
+            f"for {Path(video_path).name}..."
+        )
+    index = build_hash_index(
+        video_path,
+        algo,
+        hash_size,
+        temp_dir=temp_dir,
+        batch_size=batch_size,
+        log=log,
+    )
+    try:
+        index.save(cache_path)
+    except OSError as e:
+        logger.warning("Could not save hash index %s: %s", cache_path, e)
+    return index
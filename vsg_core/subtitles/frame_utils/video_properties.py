@@ -556,6 +556,19 @@ def detect_video_properties(video_path: str, runner) -> dict[str, Any]:
         props["content_type"] = content_type
         props["detection_confidence"] = detection_confidence
 
+        # ── True VFR (WEB-DL): the rate fields above don't show it ────
+        # Checked after classification so it can't turn a VFR H.264 stream
+        # into "soft telecine"; MPEG-2 is covered by MediaInfo.
+        if not props["is_vfr"] and not is_dvd_codec:
+            from vsg_core.extraction.frame_rate import detect_vfr
+
+            rate_info = detect_vfr(video_path, runner, {})
+            if rate_info is not None and rate_info.is_vfr:
+                props["is_vfr"] = True
+                runner._log_message(
+                    f"[VideoProps] Frame timestamps: {rate_info.describe()}"
+                )
+
         # ── Logging ──────────────────────────────────────────────────
         if props["is_vfr"]:
             orig = props.get("original_fps")
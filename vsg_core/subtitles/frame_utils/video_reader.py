@@ -25,7 +25,7 @@ def _get_ffms2_cache_path(video_path: str, temp_dir: Path | None) -> Path:
     Generate cache path for FFMS2 index in job's temp directory.
 
     Cache key: parent_dir + filename + size + mtime (unique per file path)
-    Location: {job_temp_dir}/ffindex/{cache_key}.ffindex
+    Location: {job_temp_dir}/indexes/{cache_key}.ffindex
 
     The index is created in the job's temp folder so it can be:
     1. Easily identified by filename and source
@@ -56,7 +56,7 @@ def _get_ffms2_cache_path(video_path: str, temp_dir: Path | None) -> Path:
 
     # ALWAYS use job's temp_dir for index storage (for cleanup)
     if temp_dir:
-        cache_dir = temp_dir / "ffindex"
+        cache_dir = temp_dir / "indexes"
         cache_dir.mkdir(parents=True, exist_ok=True)
     else:
         # Fallback: use system temp (but warn - won't be cleaned up)
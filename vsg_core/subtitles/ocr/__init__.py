@@ -91,6 +91,7 @@ def _build_ocr_settings(settings: AppSettings, lang: str) -> dict:
         "ocr_engine": get_val("ocr_engine", "paddleocr-vl"),
         "ocr_engine_vobsub": get_val("ocr_engine_vobsub", "paddleocr-vl-native"),
         "ocr_engine_pgs": get_val("ocr_engine_pgs", "paddleocr-vl-native"),
+        "ocr_tesseract_path": get_val("ocr_tesseract_path", "tesseract"),
         "ocr_low_confidence_threshold": get_val("ocr_low_confidence_threshold", 60.0),
         # Post-processing
         "ocr_cleanup_enabled": get_val("ocr_cleanup_enabled", True),
@@ -114,12 +115,15 @@ def check_ocr_available() -> tuple[bool, str]:
         Tuple of (is_available, message)
     """
     from .vlm_backends import is_model_available
+    from .vlm_backends.tesseract import find_tesseract
 
     available = [
         name
         for name in ("paddleocr-vl-native", "paddleocr-vl")
         if is_model_available(name)
     ]
+    if find_tesseract():
+        available.append("tesseract")
     if available:
         return True, f"OCR available: {', '.join(available)}"
     return False, (
@@ -229,6 +233,12 @@ def run_ocr_unified(
                 unknown_count = summary.get("unknown_word_count", 0)
                 if unknown_count > 0:
                     runner._log_message(f"[OCR] Unknown words: {unknown_count} unique")
+                low_conf = summary.get("low_confidence_count", 0)
+                if low_conf > 0:
+                    runner._log_message(
+                        f"[OCR] {low_conf} line(s) flagged for manual review "
+                        "(low confidence or unreadable)"
+                    )
 
             if result.report_path:
                 runner._log_message(f"[OCR] Report saved: {result.report_path.name}")
@@ -36,7 +36,17 @@ from .postprocess import OCRPostProcessor, create_postprocessor
 from .report import OCRReport, SubtitleOCRResult, create_report
 
 # VLM engine names that use the VLM pipeline (spotting + pixel verification)
-_VLM_ENGINES = {"qwen35-4b", "paddleocr-vl", "paddleocr-vl-native", "paddleocr-vl-2x"}
+_VLM_ENGINES = {
+    "qwen35-4b",
+    "paddleocr-vl",
+    "paddleocr-vl-native",
+    "paddleocr-vl-2x",
+    "tesseract",
+}
+
+# Text written for cues that have pixels but could not be read, so they stay
+# in the output (with their timing) and are flagged for manual review
+UNREADABLE_TEXT = "[UNREADABLE]"
 
 
 @dataclass(slots=True)
@@ -174,6 +184,17 @@ class OCRPipeline:
             is_vlm = backend_setting in _VLM_ENGINES
             self._log_progress(f"Using OCR engine: {backend_setting}", 0.02)
 
+            if backend_setting == "tesseract":
+                from .vlm_backends.tesseract import find_tesseract
+
+                binary = self.settings.get("ocr_tesseract_path", "tesseract")
+                if find_tesseract(binary) is None:
+                    result.error = (
+                        f"OCR tool '{binary}' is not installed; skipping OCR "
+                        "(the image-based track is kept as-is)"
+                    )
+                    return result
+
             # Step 1: Detect and create parser (raw for VLM)
             self._log_progress("Parsing subtitle file", 0.05)
             parser = SubtitleImageParser.detect_parser(input_path, raw=True)
@@ -841,7 +862,13 @@ class OCRPipeline:
         # ── Load model ────────────────────────────────────────────────
         self._log_progress(f"Loading model: {engine_name}", 0.10)
         load_start = time.time()
-        backend = get_vlm_backend(engine_name)
+        backend_kwargs = {}
+        if engine_name == "tesseract":
+            backend_kwargs = {
+                "binary": self.settings.get("ocr_tesseract_path", "tesseract"),
+                "language": self.config.language,
+            }
+        backend = get_vlm_backend(engine_name, **backend_kwargs)
         backend.load()
         self._vlm_backend = backend
         load_time = time.time() - load_start
@@ -915,18 +942,22 @@ class OCRPipeline:
                     ann = annotate_image(rgb, px_regions)
                     vlm_results = backend.ocr(ann, px_regions)
                     vl_lines = [
-                        {"text": vr.text, "bbox": vr.vl_bbox}
+                        {"text": vr.text, "bbox": vr.vl_bbox, "conf": vr.confidence}
                         for vr in vlm_results
                         if vr.text
                     ]
                 else:
                     vlm_results = backend.ocr(rgb, px_regions, raw_image=rgb)
                     vl_lines = [
-                        {"text": vr.text, "bbox": vr.vl_bbox}
+                        {"text": vr.text, "bbox": vr.vl_bbox, "conf": vr.confidence}
                         for vr in vlm_results
                         if vr.text
                     ]
 
+            # Backend-reported confidence (0-100); weakest line wins
+            line_confs = [vl["conf"] for vl in vl_lines if vl.get("conf") is not None]
+            sub_conf = min(line_confs) if line_confs else None
+
             # Sort lines by reading order: top→bottom, then left→right
             vl_lines.sort(
                 key=lambda vl: (
@@ -990,7 +1021,28 @@ class OCRPipeline:
                         logger.debug(f"Sub {sub_image.index}: OCR recovery failed: {e}")
 
                 if not recovered_text:
-                    # Recovery failed or not available — skip (stays paddle_empty)
+                    # Recovery failed or not available — keep the cue with a
+                    # placeholder so its timing survives and it gets reviewed
+                    ocr_results.append(
+                        OCRSubtitleResult(
+                            index=sub_image.index,
+                            start_ms=float(sub_image.start_ms),
+                            end_ms=float(sub_image.end_ms),
+                            text=UNREADABLE_TEXT,
+                            confidence=0.0,
+                            frame_width=frame_w,
+                            frame_height=frame_h,
+                            is_forced=sub_image.is_forced,
+                            zone="bot",
+                        )
+                    )
+                    report.add_low_confidence_line(
+                        text=UNREADABLE_TEXT,
+                        timestamp=sub_image.start_time,
+                        confidence=0.0,
+                        subtitle_index=sub_image.index,
+                        potential_issues=["image has pixels but no text was read"],
+                    )
                     if self.config.debug_output:
                         debugger.add_subtitle(
                             sub_image.index,
@@ -1342,12 +1394,23 @@ class OCRPipeline:
                         # Dominant = most common color across all lines
                         dominant_color = line_colors[0]
 
+                confidence = (
+                    sub_conf if sub_conf is not None else 90.0 * reg.confidence
+                )
+                if confidence < self.config.low_confidence_threshold:
+                    report.add_low_confidence_line(
+                        text=region_text,
+                        timestamp=sub_image.start_time,
+                        confidence=confidence,
+                        subtitle_index=sub_image.index,
+                    )
+
                 ocr_result = OCRSubtitleResult(
                     index=sub_image.index,
                     start_ms=float(sub_image.start_ms),
                     end_ms=float(sub_image.end_ms),
                     text=region_text,
-                    confidence=90.0 * reg.confidence,
+                    confidence=confidence,
                     raw_ocr_text=raw_region,
                     fixes_applied=all_fixes,
                     unknown_words=all_unknown,
@@ -1372,7 +1435,7 @@ class OCRPipeline:
                         timestamp_start=sub_image.start_time,
                         timestamp_end=sub_image.end_time,
                         text=region_text,
-                        confidence=90.0 * reg.confidence,
+                        confidence=confidence,
                         fixes_applied=all_fixes if isinstance(all_fixes, dict) else {},
                         unknown_words=all_unknown
                         if isinstance(all_unknown, list)
@@ -60,6 +60,7 @@ class VLMRegionResult:
     text: str
     raw_output: str = ""
     vl_bbox: tuple[int, int, int, int] | None = None  # VL-detected bbox (x1,y1,x2,y2)
+    confidence: float | None = None  # 0-100, for backends that report one
 
 
 @dataclass
@@ -250,3 +251,8 @@ try:
     from . import paddleocr_vl_torch
 except ImportError as e:
     logger.debug(f"PaddleOCR-VL torch backend not available: {e}")
+
+try:
+    from . import tesseract
+except ImportError as e:
+    logger.debug(f"Tesseract backend not available: {e}")
@@ -0,0 +1,128 @@
+# vsg_core/subtitles/ocr/vlm_backends/tesseract.py
+"""
+Tesseract backend — CPU OCR through the external ``tesseract`` binary.
+
+No model download or GPU needed, at the cost of accuracy on stylised
+subtitle fonts. Each detected region is cropped, converted to dark text on
+a white background (what Tesseract is trained on), upscaled and read as a
+uniform text block (``--psm 6``). Word confidences from the TSV output are
+averaged per region so weak lines can be flagged for manual review.
+
+The binary and language come from ``ocr_tesseract_path`` / ``ocr_language``.
+"""
+
+import logging
+import shutil
+import subprocess
+import tempfile
+from pathlib import Path
+
+import numpy as np
+from PIL import Image
+
+from ..region_detector import Region
+from . import VLMBackend, VLMRegionResult, register_vlm_backend
+
+logger = logging.getLogger(__name__)
+
+# Small subtitle glyphs read much better at ~3x
+_UPSCALE = 3
+_PAD = 10
+
+
+def find_tesseract(binary: str = "tesseract") -> str | None:
+    """Resolved path of the Tesseract binary, or None if not installed."""
+    return shutil.which(binary or "tesseract")
+
+
+def _parse_tsv(tsv: str) -> tuple[str, float | None]:
+    """Text (one output line per Tesseract line) and mean word confidence."""
+    lines: dict[tuple[str, str, str], list[str]] = {}
+    confs: list[float] = []
+    for row in tsv.splitlines()[1:]:
+        cols = row.split("\t")
+        if len(cols) < 12 or cols[0] != "5":  # Level 5 = word
+            continue
+        word = cols[11].strip()
+        if not word:
+            continue
+        lines.setdefault((cols[2], cols[3], cols[4]), []).append(word)
+        try:
+            conf = float(cols[10])
+        except ValueError:
+            continue
+        if conf >= 0:
+            confs.append(conf)
+    text = "\n".join(" ".join(words) for words in lines.values())
+    return text, (sum(confs) / len(confs) if confs else None)
+
+
+@register_vlm_backend("tesseract")
+class TesseractBackend(VLMBackend):
+    """Crop-based backend shelling out to ``tesseract``."""
+
+    uses_annotated = False
+
+    def __init__(self, binary: str = "tesseract", language: str = "eng", **kwargs):
+        self.binary = binary
+        self.language = language or "eng"
+        self._exe: str | None = None
+        self._tmp: tempfile.TemporaryDirectory | None = None
+
+    def load(self) -> None:
+        self._exe = find_tesseract(self.binary)
+        if self._exe is None:
+            raise FileNotFoundError(f"OCR tool not found: {self.binary}")
+        self._tmp = tempfile.TemporaryDirectory(prefix="vsg_tesseract_")
+        logger.info(f"Using Tesseract at {self._exe} (lang={self.language})")
+
+    def ocr(
+        self,
+        image: np.ndarray,
+        regions: list[Region],
+        raw_image: np.ndarray | None = None,
+    ) -> list[VLMRegionResult]:
+        source = raw_image if raw_image is not None else image
+        results = []
+        for region in regions:
+            crop = source[region.y1 : region.y2, region.x1 : region.x2]
+            text, conf, raw = self._read(crop)
+            results.append(
+                VLMRegionResult(
+                    region_id=region.region_id,
+                    text=text,
+                    raw_output=raw,
+                    vl_bbox=(region.x1, region.y1, region.x2, region.y2),
+                    confidence=conf,
+                )
+            )
+        return results
+
+    def _read(self, crop: np.ndarray) -> tuple[str, float | None, str]:
+        if crop.size == 0 or self._tmp is None:
+            return "", None, ""
+        gray = crop.max(axis=2) if crop.ndim == 3 else crop
+        # Light text on black -> black text on white, padded and upscaled
+        inverted = 255 - np.pad(gray, _PAD, constant_values=0)
+        img = Image.fromarray(inverted.astype(np.uint8), mode="L")
+        img = img.resize((img.width * _UPSCALE, img.height * _UPSCALE))
+        path = Path(self._tmp.name) / "region.png"
+        img.save(path)
+
+        proc = subprocess.run(
+            [self._exe, str(path), "stdout", "-l", self.language, "--psm", "6", "tsv"],
+            capture_output=True,
+            text=True,
+            encoding="utf-8",
+            errors="replace",
+        )
+        if proc.returncode != 0:
+            logger.debug(f"tesseract failed: {proc.stderr.strip()}")
+            return "", None, proc.stderr
+        text, conf = _parse_tsv(proc.stdout)
+        return text, conf, proc.stdout
+
+    def unload(self) -> None:
+        if self._tmp is not None:
+            self._tmp.cleanup()
+            self._tmp = None
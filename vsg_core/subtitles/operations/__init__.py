@@ -1,6 +1,8 @@
 # vsg_core/subtitles/operations/__init__.py
 """Subtitle operations (stepping, style patches, etc.)."""
 
+from .bake_shift import bake_shift
+from .retime import retime_fps
 from .stepping import apply_stepping
 from .style_ops import (
     apply_font_replacement,
@@ -17,4 +19,6 @@ __all__ = [
     "apply_stepping",
     "apply_style_filter",
     "apply_style_patch",
+    "bake_shift",
+    "retime_fps",
 ]
@@ -0,0 +1,131 @@
+# vsg_core/subtitles/operations/bake_shift.py
+"""
+Frame-accurate delay baked into SubtitleData.
+
+Instead of leaving the delay to mkvmerge ``--sync`` (which moves every
+event by the same, possibly mid-frame, amount), each event boundary is
+shifted and then snapped onto the target video's frame grid:
+
+    frame = floor/round/ceil((time_ms + delay_ms) / frame_duration)
+    time  = start of that frame (frame_to_time_floor)
+
+``floor`` keeps a boundary on the frame showing at the shifted time,
+``ceil`` moves it to the first frame at or after it, ``round`` to the
+nearest frame start. An event never collapses: an end that snaps onto
+its start frame is pushed one frame later. The result already contains
+the offset, so the track is muxed with delay 0 (``frame_adjusted``).
+"""
+
+from __future__ import annotations
+
+import math
+from datetime import datetime
+from typing import TYPE_CHECKING
+
+from ..frame_utils.timing import frame_to_time_floor
+
+if TYPE_CHECKING:
+    from ..data import OperationResult, SubtitleData
+
+
+def snap_frame(time_ms: float, fps: float, rounding: str = "floor") -> int:
+    """Frame index of ``time_ms`` on the ``fps`` grid, per ``rounding``."""
+    frames = time_ms * fps / 1000.0
+    mode = (rounding or "floor").lower()
+    if mode == "ceil":
+        return math.ceil(frames - 1e-6)
+    if mode == "round":
+        return math.floor(frames + 0.5)
+    return math.floor(frames + 1e-6)
+
+
+def bake_shift(
+    data: SubtitleData,
+    delay_ms: float,
+    fps: float,
+    rounding: str = "floor",
+    runner=None,
+) -> OperationResult:
+    """
+    Shift all non-comment events by ``delay_ms`` onto the ``fps`` frame grid.
+
+    Args:
+        data: SubtitleData to modify
+        delay_ms: Delay to bake in (ms, raw float)
+        fps: Framerate of the target video
+        rounding: Rounding mode onto the grid ("floor", "round", "ceil")
+        runner: CommandRunner for logging (optional)
+
+    Returns:
+        OperationResult with statistics
+    """
+    from ..data import OperationRecord, OperationResult, SyncEventData
+
+    def log(msg: str):
+        if runner:
+            runner._log_message(msg)
+
+    if fps <= 0:
+        return OperationResult(
+            success=False, operation="sync", error=f"Invalid framerate: {fps}"
+        )
+
+    log(
+        f"[BakeShift] {delay_ms:+.3f}ms onto the {fps:.3f}fps frame grid "
+        f"({rounding})"
+    )
+
+    events_shifted = 0
+    extended = 0
+    for event in data.events:
+        if event.is_comment:
+            continue
+        original_start, original_end = event.start_ms, event.end_ms
+        start_frame = snap_frame(original_start + delay_ms, fps, rounding)
+        end_frame = snap_frame(original_end + delay_ms, fps, rounding)
+        if end_frame <= start_frame and original_end > original_start:
+            end_frame = start_frame + 1
+            extended += 1
+        event.start_ms = frame_to_time_floor(start_frame, fps)
+        event.end_ms = frame_to_time_floor(end_frame, fps)
+        event.sync = SyncEventData(
+            original_start_ms=original_start,
+            original_end_ms=original_end,
+            start_adjustment_ms=event.start_ms - original_start,
+            end_adjustment_ms=event.end_ms - original_end,
+            snapped_to_frame=True,
+            target_frame_start=start_frame,
+            target_frame_end=end_frame,
+        )
+        events_shifted += 1
+
+    summary = f"Baked {delay_ms:+.1f}ms into {events_shifted} events on frame grid"
+    if extended:
+        summary += f" ({extended} kept one frame long)"
+    record = OperationRecord(
+        operation="sync",
+        timestamp=datetime.now(),
+        parameters={
+            "mode": "bake-shift",
+            "delay_ms": delay_ms,
+            "fps": fps,
+            "rounding": rounding,
+        },
+        events_affected=events_shifted,
+        summary=summary,
+    )
+    data.operations.append(record)
+    log(f"[BakeShift] {summary}")
+
+    return OperationResult(
+        success=True,
+        operation="sync",
+        events_affected=events_shifted,
+        summary=summary,
+        details={
+            "delay_ms": delay_ms,
+            "fps": fps,
+            "events_synced": events_shifted,
+            "events_extended": extended,
+        },
+    )
@@ -0,0 +1,132 @@
+# vsg_core/subtitles/operations/lint.py
+"""
+Sanity check of subtitle event timing, independent of sync.
+
+Subtitles from the web often carry broken events: zero-length lines, lines
+that end before they start, lines before 0, or a line hours after the rest
+(a stray timestamp typo). Players glitch on them, and after muxing they
+look like sync problems. ``lint_subtitles`` lists them; the pipeline runs
+it on each text track after retiming/sync and renders the result in the
+final audit (``SubtitleLintAuditor``), marking external files so it is
+clear the problem is in the file itself.
+
+Overlapping lines are not flagged: ASS uses them on purpose (signs,
+karaoke, two speakers at once).
+
+``fix_subtitle_timing`` is the opt-in repair (``subtitle_lint_autofix``):
+starts before 0 are clamped to 0, zero/negative durations are extended to
+``MIN_DURATION_MS`` (an end before the start is taken as swapped times),
+and events are sorted by start time when they are out of order. Large gaps
+are only reported; there is nothing safe to change.
+"""
+
+from __future__ import annotations
+
+from collections import Counter
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Literal
+
+if TYPE_CHECKING:
+    from vsg_core.subtitles.data import SubtitleEvent
+
+SubtitleIssueKind = Literal[
+    "negative_start", "zero_duration", "ends_before_start", "out_of_order", "large_gap"
+]
+
+# A gap between consecutive lines longer than this is flagged
+LARGE_GAP_MS = 10 * 60 * 1000.0
+# Duration given to zero-length lines by the fix (about two frames)
+MIN_DURATION_MS = 80.0
+
+_KIND_LABELS: dict[str, str] = {
+    "negative_start": "start before 0",
+    "zero_duration": "zero duration",
+    "ends_before_start": "end before start",
+    "out_of_order": "out of order",
+    "large_gap": "large gap",
+}
+
+
+@dataclass(frozen=True, slots=True)
+class SubtitleIssue:
+    """One problem event (``index`` into the event list)."""
+
+    kind: SubtitleIssueKind
+    index: int
+    start_ms: float
+    end_ms: float
+
+    def describe(self) -> str:
+        return (
+            f"Event {self.index + 1} ({self.start_ms / 1000:.3f}s -> "
+            f"{self.end_ms / 1000:.3f}s): {_KIND_LABELS[self.kind]}"
+        )
+
+
+@dataclass(frozen=True, slots=True)
+class SubtitleLintResult:
+    """Lint outcome of one track, for ``SubtitleLintAuditor``."""
+
+    track_label: str
+    is_external: bool
+    issues: tuple[SubtitleIssue, ...]
+    events_fixed: int = 0
+
+    def summary(self) -> str:
+        """Counts per kind, e.g. "3 zero duration, 1 end before start"."""
+        counts = Counter(i.kind for i in self.issues)
+        return ", ".join(f"{n} {_KIND_LABELS[kind]}" for kind, n in counts.items())
+
+
+def lint_subtitles(
+    events: list[SubtitleEvent], large_gap_ms: float = LARGE_GAP_MS
+) -> list[SubtitleIssue]:
+    """Timing problems of the non-comment events, in event order."""
+    issues = []
+    prev_start: float | None = None
+    prev_end: float | None = None
+    for index, event in enumerate(events):
+        if event.is_comment:
+            continue
+        kinds: list[SubtitleIssueKind] = []
+        if event.start_ms < 0:
+            kinds.append("negative_start")
+        if event.end_ms < event.start_ms:
+            kinds.append("ends_before_start")
+        elif event.end_ms == event.start_ms:
+            kinds.append("zero_duration")
+        if prev_start is not None and event.start_ms < prev_start:
+            kinds.append("out_of_order")
+        if prev_end is not None and event.start_ms - prev_end > large_gap_ms:
+            kinds.append("large_gap")
+        issues.extend(
+            SubtitleIssue(kind, index, event.start_ms, event.end_ms) for kind in kinds
+        )
+        prev_start = event.start_ms
+        prev_end = event.end_ms if prev_end is None else max(prev_end, event.end_ms)
+    return issues
+
+
+def fix_subtitle_timing(events: list[SubtitleEvent]) -> int:
+    """
+    Repairs the fixable problems in place (see module docstring).
+    Returns the number of events changed or moved.
+    """
+    changed: set[int] = set()
+    for index, event in enumerate(events):
+        if event.is_comment:
+            continue
+        before = (event.start_ms, event.end_ms)
+        if event.end_ms < event.start_ms:
+            event.start_ms, event.end_ms = event.end_ms, event.start_ms
+        if event.start_ms < 0:
+            event.start_ms = 0.0
+        if event.end_ms - event.start_ms <= 0:
+            event.end_ms = event.start_ms + MIN_DURATION_MS
+        if (event.start_ms, event.end_ms) != before:
+            changed.add(index)
+
+    order = sorted(range(len(events)), key=lambda i: events[i].start_ms)
+    changed.update(i for pos, i in enumerate(order) if pos != i)
+    events[:] = [events[i] for i in order]
+    return len(changed)
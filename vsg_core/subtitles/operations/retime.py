@@ -0,0 +1,113 @@
+# vsg_core/subtitles/operations/retime.py
+"""
+Framerate retime operation for SubtitleData.
+
+Converts subtitles timed for one framerate to another by scaling every
+timestamp by ``src_fps / dst_fps`` — the classic PAL (25fps) to film
+(23.976fps) fix, where the PAL master is sped up by ~4.3%.
+
+Each timestamp is split into a frame index and a sub-frame remainder with
+the shared frame-utility conversions, so frame N of the source lands
+exactly on frame N of the target; the remainder is scaled by the same
+ratio. Results are rounded to whole milliseconds with the given rounding
+mode, like the SRT writer.
+"""
+
+from __future__ import annotations
+
+import math
+from datetime import datetime
+from typing import TYPE_CHECKING
+
+from ..frame_utils.timing import frame_to_time_floor, time_to_frame_floor
+
+if TYPE_CHECKING:
+    from ..data import OperationResult, SubtitleData
+
+
+def _round_ms(ms: float, rounding: str) -> int:
+    mode = (rounding or "round").lower()
+    if mode == "ceil":
+        return int(math.ceil(ms - 1e-6))
+    if mode == "floor":
+        return int(math.floor(ms + 1e-6))
+    return int(round(ms))
+
+
+def retime_time_ms(time_ms: float, src_fps: float, dst_fps: float) -> float:
+    """Map one timestamp from the ``src_fps`` frame grid to ``dst_fps``."""
+    frame = time_to_frame_floor(time_ms, src_fps)
+    remainder = time_ms - frame_to_time_floor(frame, src_fps)
+    return frame_to_time_floor(frame, dst_fps) + remainder * (src_fps / dst_fps)
+
+
+def retime_fps(
+    data: SubtitleData,
+    src_fps: float,
+    dst_fps: float,
+    rounding: str = "round",
+    runner=None,
+) -> OperationResult:
+    """
+    Scale all event timestamps from ``src_fps`` to ``dst_fps``.
+
+    Args:
+        data: SubtitleData to modify
+        src_fps: Framerate the subtitles are timed for (e.g. 25.0)
+        dst_fps: Framerate of the target video (e.g. 24000/1001)
+        rounding: Rounding mode ("floor", "round", "ceil")
+        runner: CommandRunner for logging (optional)
+
+    Returns:
+        OperationResult with statistics
+    """
+    from ..data import OperationRecord, OperationResult
+
+    def log(msg: str):
+        if runner:
+            runner._log_message(msg)
+
+    if src_fps <= 0 or dst_fps <= 0:
+        return OperationResult(
+            success=False,
+            operation="retime",
+            error=f"Invalid framerates: {src_fps} -> {dst_fps}",
+        )
+
+    ratio = src_fps / dst_fps
+    log(f"[Retime] {src_fps:.3f}fps -> {dst_fps:.3f}fps (x{ratio:.6f})")
+
+    events_retimed = 0
+    for event in data.events:
+        event.start_ms = float(
+            _round_ms(retime_time_ms(event.start_ms, src_fps, dst_fps), rounding)
+        )
+        event.end_ms = float(
+            _round_ms(retime_time_ms(event.end_ms, src_fps, dst_fps), rounding)
+        )
+        events_retimed += 1
+
+    record = OperationRecord(
+        operation="retime",
+        timestamp=datetime.now(),
+        parameters={
+            "src_fps": src_fps,
+            "dst_fps": dst_fps,
+            "rounding": rounding,
+        },
+        events_affected=events_retimed,
+        summary=(
+            f"Retimed {events_retimed} events from {src_fps:.3f} "
+            f"to {dst_fps:.3f}fps"
+        ),
+    )
+    data.operations.append(record)
+    log(f"[Retime] {record.summary}")
+
+    return OperationResult(
+        success=True,
+        operation="retime",
+        events_affected=events_retimed,
+        summary=record.summary,
+        details={"ratio": ratio, "events_retimed": events_retimed},
+    )
@@ -64,7 +64,8 @@ def detect_encoding(path: Path) -> tuple[str, bool]:
         try:
             with open(path, encoding=encoding) as f:
                 f.read()
-            return (encoding, encoding == "utf-8-sig")
+            # No BOM was found above, so utf-8-sig is plain utf-8
+            return ("utf-8" if encoding == "utf-8-sig" else encoding, False)
         except (UnicodeDecodeError, LookupError):
             continue
 
@@ -157,6 +158,7 @@ def parse_ass_file(path: Path) -> SubtitleData:
 def _parse_script_info(data: SubtitleData, lines: list[str]) -> None:
     """Parse [Script Info] section."""
     comments_key = "__comments__"
+    data.script_info_lines = list(lines)
 
     for line in lines:
         stripped = line.strip()
@@ -31,14 +31,15 @@ def detect_encoding(path: Path) -> tuple[str, bool]:
 
     if raw.startswith(codecs.BOM_UTF8):
         return ("utf-8-sig", True)
-    if raw.startswith(codecs.BOM_UTF16_LE):
-        return ("utf-16-le", True)
+    if raw.startswith((codecs.BOM_UTF16_LE, codecs.BOM_UTF16_BE)):
+        return ("utf-16", True)  # Endianness from the BOM, which is consumed
 
     for encoding in ENCODINGS_TO_TRY:
         try:
             with open(path, encoding=encoding) as f:
                 f.read()
-            return (encoding, encoding == "utf-8-sig")
+            # No BOM was found above, so utf-8-sig is plain utf-8
+            return ("utf-8" if encoding == "utf-8-sig" else encoding, False)
         except (UnicodeDecodeError, LookupError):
             continue
 
@@ -58,8 +59,9 @@ def parse_srt_file(path: Path) -> SubtitleData:
     path = Path(path)
     encoding, has_bom = detect_encoding(path)
 
+    # Universal newlines fold CRLF/CR; a BOM can survive a fallback decode
     with open(path, encoding=encoding) as f:
-        content = f.read()
+        content = f.read().lstrip("\ufeff")
 
     data = SubtitleData(
         source_path=path,
@@ -18,6 +18,7 @@ if TYPE_CHECKING:
     from vsg_core.orchestrator.steps.context import Context
     from vsg_core.subtitles.data import OperationResult, SubtitleData
     from vsg_core.subtitles.frame_utils.frame_clock import FrameClock
+    from vsg_core.subtitles.frame_utils.frame_table import FrameTable
 
 from vsg_core.subtitles.sync_modes import get_sync_plugin
 
@@ -80,9 +81,10 @@ def apply_sync_mode(
         except Exception as e:
             runner._log_message(f"[Sync] WARNING: Could not detect FPS: {e}")
 
-    # Exact CFR frame clock for the target. None for VFR / MPEG-2 / interlaced,
-    # in which case rounding falls back to plain floor (no surgical, no audit) —
-    # the same effective behaviour those paths already have.
+    # Exact frame grid for the target: a CFR clock, or the frame timestamps
+    # table for VFR. None for MPEG-2 / interlaced, in which case rounding falls
+    # back to plain floor (no surgical, no audit) — the same effective
+    # behaviour those paths already have.
     target_clock = _detect_target_clock(ctx, target_video, runner)
 
     runner._log_message(f"[Sync] Mode: {sync_mode}")
@@ -339,7 +341,7 @@ def _run_frame_audit_if_enabled(
     """
     if clock is None:
         runner._log_message(
-            "[FrameAudit] Skipped: no exact CFR frame grid for target (VFR/MPEG-2)"
+            "[FrameAudit] Skipped: no exact frame grid for target (MPEG-2/interlaced)"
         )
         return
 
@@ -420,28 +422,42 @@ def _run_frame_audit_if_enabled(
 
 def _detect_target_clock(
     ctx: Context, target_video: Path | None, runner: CommandRunner
-) -> FrameClock | None:
-    """Exact CFR frame clock for the target (Source 1), or None for VFR/MPEG-2.
+) -> FrameClock | FrameTable | None:
+    """Exact frame grid for the target (Source 1), or None for MPEG-2.
 
     Reuses Source 1 properties cached by the video-verified preprocessing step
-    when available, else probes the target. None means "no trustworthy grid" —
-    callers fall back to plain rounding with no surgical pass and no audit.
+    when available, else probes the target. CFR targets get a
+    :class:`FrameClock`; VFR targets get a :class:`FrameTable` built from
+    their per-frame timestamps. None means "no trustworthy grid" — callers
+    fall back to plain rounding with no surgical pass and no audit.
     """
     if target_video is None:
         return None
     from vsg_core.subtitles.frame_utils import (
-        detect_frame_clock,
+        detect_video_properties,
         frame_clock_from_props,
+        load_frame_table,
     )
 
     props = ctx.video_properties.get("Source 1") if ctx.video_properties else None
-    if props:
-        return frame_clock_from_props(props)
     try:
-        return detect_frame_clock(str(target_video), runner)
+        if not props:
+            props = detect_video_properties(str(target_video), runner)
+        clock = frame_clock_from_props(props)
+        if clock is not None or not props.get("is_vfr"):
+            return clock
+        table = load_frame_table(
+            str(target_video), runner, ctx.tool_paths, ctx.temp_dir
+        )
     except Exception as e:
         runner._log_message(f"[Sync] WARNING: Could not build frame clock: {e}")
         return None
+    if table is not None:
+        runner._log_message(
+            f"[Sync] Target is VFR — using its frame timestamps "
+            f"({len(table.times_ms)} frames)"
+        )
+    return table
 
 
 def _build_track_label(item) -> str:
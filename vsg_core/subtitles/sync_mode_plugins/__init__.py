@@ -15,11 +15,13 @@ import importlib
 # Module mapping for lazy loading
 _MODULE_MAP = {
     "TimeBasedSync": ("time_based", "TimeBasedSync"),
+    "LinearStretchSync": ("linear_stretch", "LinearStretchSync"),
     "VideoVerifiedSync": ("video_verified", "VideoVerifiedSync"),
 }
 
 _SUBMODULES = [
     "time_based",
+    "linear_stretch",
     "video_verified",
 ]
 
@@ -48,6 +50,7 @@ def __getattr__(name):
 
 
 __all__ = [
+    "LinearStretchSync",
     "TimeBasedSync",
     "VideoVerifiedSync",
 ]
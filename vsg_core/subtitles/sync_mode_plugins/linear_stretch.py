@@ -0,0 +1,184 @@
+# vsg_core/subtitles/sync_mode_plugins/linear_stretch.py
+"""
+Linear-stretch sync plugin for SubtitleData.
+
+Maps every event through ``new = a * old + b``, fitted to two anchors
+(old_ms, new_ms). Useful when the subtitle source runs at a slightly
+different speed than the target video (e.g. 25 vs 23.976 fps masters), so
+a constant delay is right at one end of the file and wrong at the other.
+
+The first anchor's delay defaults to the analysed delay; the second is
+configured (``linear_stretch_end_ms`` / ``linear_stretch_end_delay_ms``).
+Both take the global shift on top. The end anchor has no usable default:
+until ``linear_stretch_end_ms`` is set after the start anchor the mode
+fails with a message saying so. Mapped times are rounded to whole
+milliseconds with ``subtitle_rounding``, like the framerate retime.
+"""
+
+from __future__ import annotations
+
+from datetime import datetime
+from typing import TYPE_CHECKING
+
+from ..operations.retime import _round_ms
+from ..sync_modes import SyncPlugin, register_sync_plugin
+
+if TYPE_CHECKING:
+    from ...models.settings import AppSettings
+    from ..data import OperationResult, SubtitleData
+
+# Slopes at or below this would collapse (or reverse) the timeline
+_MIN_SLOPE = 1e-3
+
+
+def stretch_from_anchors(
+    anchor_a: tuple[float, float], anchor_b: tuple[float, float]
+) -> tuple[float, float]:
+    """Return ``(a, b)`` of the affine map through two (old_ms, new_ms) anchors.
+
+    Raises:
+        ValueError: If the anchors share an old time or the slope is
+            degenerate (a <= 0.001).
+    """
+    (old_a, new_a), (old_b, new_b) = anchor_a, anchor_b
+    if abs(old_b - old_a) < 1e-9:
+        raise ValueError(f"Anchors share the same source time ({old_a:.3f}ms)")
+    a = (new_b - new_a) / (old_b - old_a)
+    if a <= _MIN_SLOPE:
+        raise ValueError(f"Degenerate stretch slope {a:.6f}")
+    return a, new_a - a * old_a
+
+
+@register_sync_plugin
+class LinearStretchSync(SyncPlugin):
+    """
+    Affine time mapping from two anchors (start and end delays).
+
+    Anchors can be passed as ``anchors=((old_ms, new_ms), (old_ms, new_ms))``;
+    otherwise they are built from the analysed delay and settings.
+    """
+
+    name = "linear-stretch"
+    description = "Affine time stretch between a start and an end anchor"
+
+    def apply(
+        self,
+        subtitle_data: SubtitleData,
+        total_delay_ms: float,
+        global_shift_ms: float,
+        target_fps: float | None = None,
+        source_video: str | None = None,
+        target_video: str | None = None,
+        runner=None,
+        settings: AppSettings | None = None,
+        **kwargs,
+    ) -> OperationResult:
+        from ...models.settings import AppSettings
+        from ..data import OperationRecord, OperationResult, SyncEventData
+
+        if settings is None:
+            settings = AppSettings()
+
+        def log(msg: str):
+            if runner:
+                runner._log_message(msg)
+
+        log("[LinearStretch] === Linear Stretch Sync ===")
+
+        anchors = kwargs.get("anchors")
+        if anchors is None:
+            start_ms = settings.linear_stretch_start_ms
+            end_ms = settings.linear_stretch_end_ms
+            if end_ms <= start_ms:
+                error = (
+                    f"End anchor ({end_ms:.3f}ms) must come after the start "
+                    f"anchor ({start_ms:.3f}ms); set linear_stretch_end_ms"
+                )
+                log(f"[LinearStretch] ERROR: {error}")
+                return OperationResult(success=False, operation="sync", error=error)
+            # total_delay_ms already includes the global shift; the
+            # configured end delay is relative to the source like the
+            # analysed delay, so it takes the shift too
+            end_delay_ms = settings.linear_stretch_end_delay_ms + global_shift_ms
+            anchors = (
+                (start_ms, start_ms + total_delay_ms),
+                (end_ms, end_ms + end_delay_ms),
+            )
+
+        log(
+            f"[LinearStretch] Anchors: {anchors[0][0]:.3f} -> {anchors[0][1]:.3f}ms, "
+            f"{anchors[1][0]:.3f} -> {anchors[1][1]:.3f}ms"
+        )
+        try:
+            a, b = stretch_from_anchors(anchors[0], anchors[1])
+        except ValueError as e:
+            log(f"[LinearStretch] ERROR: {e}")
+            return OperationResult(success=False, operation="sync", error=str(e))
+
+        log(
+            f"[LinearStretch] new = {a:.9f} * old {b:+.3f}ms "
+            f"({(a - 1.0) * 1e6:+.1f} ppm)"
+        )
+
+        rounding = settings.subtitle_rounding
+        events_synced = 0
+        clamped = 0
+        for event in subtitle_data.events:
+            if event.is_comment:
+                continue
+            original_start = event.start_ms
+            original_end = event.end_ms
+            new_start = float(_round_ms(a * original_start + b, rounding))
+            new_end = float(_round_ms(a * original_end + b, rounding))
+            if new_start < 0 or new_end < 0:
+                clamped += 1
+                new_start = max(0.0, new_start)
+                new_end = max(0.0, new_end)
+            event.start_ms = new_start
+            event.end_ms = new_end
+            event.sync = SyncEventData(
+                original_start_ms=original_start,
+                original_end_ms=original_end,
+                start_adjustment_ms=new_start - original_start,
+                end_adjustment_ms=new_end - original_end,
+                snapped_to_frame=False,
+            )
+            events_synced += 1
+
+        if clamped:
+            log(
+                f"[LinearStretch] [WARNING] {clamped} event(s) mapped before 0ms "
+                "were clamped to 0ms"
+            )
+
+        record = OperationRecord(
+            operation="sync",
+            timestamp=datetime.now(),
+            parameters={
+                "mode": "linear-stretch",
+                "anchors": [list(anchors[0]), list(anchors[1])],
+                "slope": a,
+                "offset_ms": b,
+                "rounding": rounding,
+            },
+            events_affected=events_synced,
+            summary=(
+                f"Stretched {events_synced} events "
+                f"(a={a:.9f}, b={b:+.1f}ms, {clamped} clamped)"
+            ),
+        )
+        subtitle_data.operations.append(record)
+        log(f"[LinearStretch] {record.summary}")
+
+        return OperationResult(
+            success=True,
+            operation="sync",
+            events_affected=events_synced,
+            summary=record.summary,
+            details={
+                "slope": a,
+                "offset_ms": b,
+                "events_synced": events_synced,
+                "events_clamped": clamped,
+            },
+        )
@@ -3,7 +3,9 @@
 Time-based sync plugin for SubtitleData.
 
 Simple delay application - applies raw delay to all events.
-Used when mkvmerge --sync is not handling the delay.
+Used when mkvmerge --sync is not handling the delay. In frame-accurate mode
+(``time_based_frame_accurate``) the delay is baked in with every boundary
+snapped to the target video's frame grid (operations/bake_shift.py).
 """
 
 from __future__ import annotations
@@ -49,6 +51,9 @@ class TimeBasedSync(SyncPlugin):
         returns success with no changes (mkvmerge handles sync).
 
         If True, applies raw delay to subtitle events.
+
+        With time_based_frame_accurate the delay is baked in on the frame grid
+        of target_fps; without a target FPS the other modes apply.
         """
         from ...models.settings import AppSettings
         from ..data import OperationRecord, OperationResult
@@ -62,6 +67,17 @@ class TimeBasedSync(SyncPlugin):
 
         use_raw_values = settings.time_based_use_raw_values
 
+        if settings.time_based_frame_accurate:
+            if target_fps and target_fps > 0:
+                log("[TimeBased] === Time-Based Sync (Frame-Accurate) ===")
+                return subtitle_data.bake_shift(
+                    total_delay_ms, target_fps, settings.subtitle_rounding, runner
+                )
+            log(
+                "[TimeBased] WARNING: Frame-accurate mode needs the target FPS, "
+                "which is unknown; using the plain delay instead"
+            )
+
         if not use_raw_values:
             # Default: mkvmerge --sync handles the delay
             log("[TimeBased] Using mkvmerge --sync mode (no subtitle modification)")
@@ -11,15 +11,21 @@ Public API:
       backends (ISC, SSCD mixup/large, pHash, dHash, SSIM). This is the
       single entrypoint for video-verified matching.
     - VideoVerifiedSync: SyncPlugin implementation for the subtitle pipeline.
+    - checkpoint_report(): Per-checkpoint outcome (CheckpointReport) from
+      the matcher's details dict.
 
 See ``backends/__init__.py`` for the backend registry and
 ``sliding_matcher.py`` for the orchestrator.
 """
 
+from .checkpoints import CheckpointReport, CheckpointResult, checkpoint_report
 from .plugin import VideoVerifiedSync
 from .sliding_matcher import calculate_sliding_offset
 
 __all__ = [
+    "CheckpointReport",
+    "CheckpointResult",
     "VideoVerifiedSync",
     "calculate_sliding_offset",
+    "checkpoint_report",
 ]
@@ -32,6 +32,8 @@ from .base import BackendResult
 if TYPE_CHECKING:
     import torch
 
+    from ....frame_utils.hash_index import HashIndex
+
 logger = logging.getLogger(__name__)
 
 
@@ -50,6 +52,9 @@ class DHashBackend:
         self._device: Any = None
         self._luma: Any = None
         self._hash_size: int = 32
+        # Precomputed hashes of the target (reference) video; when set,
+        # target descriptors are looked up instead of decoded and hashed.
+        self.reference_index: HashIndex | None = None
 
     def load(self, device: "torch.device", settings: Any) -> None:
         import torch
@@ -90,9 +95,12 @@ class DHashBackend:
         src_feats = self._extract_descriptors(
             src_rgb_clip, src_frame_nums, device, batch_size
         )
-        tgt_feats = self._extract_descriptors(
-            tgt_rgb_clip, tgt_frame_nums, device, batch_size
-        )
+        if self.reference_index is not None:
+            tgt_feats = self.reference_index.descriptors(tgt_frame_nums)
+        else:
+            tgt_feats = self._extract_descriptors(
+                tgt_rgb_clip, tgt_frame_nums, device, batch_size
+            )
         extract_time_s = time.perf_counter() - t_extract_start
 
         t_score_start = time.perf_counter()
@@ -181,5 +189,6 @@ class DHashBackend:
         return torch.cat(all_desc, dim=0).numpy()
 
     def cleanup(self) -> None:
+        self.reference_index = None
         self._luma = None
         self._device = None
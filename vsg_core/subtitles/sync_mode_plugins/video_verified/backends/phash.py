@@ -36,6 +36,8 @@ from .base import BackendResult
 if TYPE_CHECKING:
     import torch
 
+    from ....frame_utils.hash_index import HashIndex
+
 logger = logging.getLogger(__name__)
 
 
@@ -71,6 +73,9 @@ class PHashBackend:
         self._dct_matrix: Any = None  # [dct_size, dct_size]
         self._luma: Any = None  # [1, 3, 1, 1]
         self._hash_size: int = 32
+        # Precomputed hashes of the target (reference) video; when set,
+        # target descriptors are looked up instead of decoded and hashed.
+        self.reference_index: HashIndex | None = None
         self._dct_size: int = 32
 
     def load(self, device: "torch.device", settings: Any) -> None:
@@ -120,9 +125,12 @@ class PHashBackend:
         src_feats = self._extract_descriptors(
             src_rgb_clip, src_frame_nums, device, batch_size
         )
-        tgt_feats = self._extract_descriptors(
-            tgt_rgb_clip, tgt_frame_nums, device, batch_size
-        )
+        if self.reference_index is not None:
+            tgt_feats = self.reference_index.descriptors(tgt_frame_nums)
+        else:
+            tgt_feats = self._extract_descriptors(
+                tgt_rgb_clip, tgt_frame_nums, device, batch_size
+            )
         extract_time_s = time.perf_counter() - t_extract_start
 
         t_score_start = time.perf_counter()
@@ -218,6 +226,7 @@ class PHashBackend:
         return torch.cat(all_desc, dim=0).numpy()
 
     def cleanup(self) -> None:
+        self.reference_index = None
         self._dct_matrix = None
         self._luma = None
         self._device = None
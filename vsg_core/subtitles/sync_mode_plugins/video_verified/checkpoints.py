@@ -0,0 +1,176 @@
+# vsg_core/subtitles/sync_mode_plugins/video_verified/checkpoints.py
+"""
+Per-checkpoint outcome of video-verified matching.
+
+The sliding matcher tests N checkpoints (positions spread over 10%-90% of
+the source) and votes on the offset. ``CheckpointReport`` keeps what each
+checkpoint saw, so the answer to "is this sync trustworthy" comes with the
+data behind it:
+
+    expected_frame  target frame the audio correlation predicts
+    matched_frame   target frame the matcher found (None = skipped)
+    score           similarity at the match; for pHash/dHash also the
+                    Hamming distance (fraction of differing hash bits),
+                    for SSIM the mean SSIM
+    confirmed       the checkpoint found the offset that was applied
+
+Frames are target frame indices, as a player would seek to them. The
+report travels in the matcher's ``details["checkpoints"]`` (plain JSON, so
+it survives the subprocess), and ``checkpoint_report(details)`` reads it
+back from ``ctx.video_verified_sources[...]["details"]`` or a track's
+``video_verified_details``. ``strip()`` is the green/red row in text form.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Any
+
+_HASH_BACKENDS = {"phash", "dhash"}
+
+
+@dataclass(frozen=True, slots=True)
+class CheckpointResult:
+    """One checkpoint: where the audio said to look and what was found."""
+
+    position_pct: float
+    src_frame: int
+    expected_frame: int
+    matched_frame: int | None  # None = skipped (edge, backend error)
+    score: float | None = None
+    matches: int = 0
+    total: int = 0
+    confirmed: bool = False
+    skip_reason: str = ""
+
+    @property
+    def skipped(self) -> bool:
+        return self.matched_frame is None
+
+    @property
+    def offset_from_audio(self) -> int | None:
+        """Frames between the match and the audio prediction."""
+        if self.matched_frame is None:
+            return None
+        return self.matched_frame - self.expected_frame
+
+    def hamming(self, backend: str) -> float | None:
+        """Fraction of differing hash bits (pHash/dHash only)."""
+        if backend not in _HASH_BACKENDS or self.score is None:
+            return None
+        # Hash bits are scored as -1/+1, so cosine = 1 - 2 * hamming
+        return (1.0 - self.score) / 2.0
+
+    def describe(self, backend: str = "") -> str:
+        head = f"{self.position_pct:4.0f}% @{self.src_frame}f"
+        if self.skipped:
+            return f"{head}: skipped ({self.skip_reason or 'no match'})"
+        metric = f"score={self.score:.4f}"
+        hamming = self.hamming(backend)
+        if hamming is not None:
+            metric += f" hamming={hamming:.3f}"
+        elif backend == "ssim":
+            metric = f"SSIM={self.score:.4f}"
+        verdict = "confirmed" if self.confirmed else "disagrees"
+        return (
+            f"{head}: expected {self.expected_frame}f, matched "
+            f"{self.matched_frame}f ({self.offset_from_audio:+d}f) "
+            f"{metric} match={self.matches}/{self.total} — {verdict}"
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "position_pct": self.position_pct,
+            "src_frame": self.src_frame,
+            "expected_frame": self.expected_frame,
+            "matched_frame": self.matched_frame,
+            "score": self.score,
+            "matches": self.matches,
+            "total": self.total,
+            "confirmed": self.confirmed,
+            "skip_reason": self.skip_reason,
+        }
+
+    @classmethod
+    def from_dict(cls, d: dict[str, Any]) -> CheckpointResult:
+        return cls(
+            position_pct=float(d["position_pct"]),
+            src_frame=int(d["src_frame"]),
+            expected_frame=int(d["expected_frame"]),
+            matched_frame=(
+                int(d["matched_frame"]) if d.get("matched_frame") is not None else None
+            ),
+            score=float(d["score"]) if d.get("score") is not None else None,
+            matches=int(d.get("matches", 0)),
+            total=int(d.get("total", 0)),
+            confirmed=bool(d.get("confirmed", False)),
+            skip_reason=str(d.get("skip_reason", "")),
+        )
+
+
+@dataclass(frozen=True, slots=True)
+class CheckpointReport:
+    """All checkpoints of one source's matching run."""
+
+    backend: str
+    applied_offset_frames: int  # Consensus offset that was applied
+    audio_offset_frames: int  # Offset the audio correlation implied
+    checkpoints: tuple[CheckpointResult, ...] = ()
+
+    @property
+    def tested(self) -> list[CheckpointResult]:
+        return [c for c in self.checkpoints if not c.skipped]
+
+    @property
+    def confirmed_count(self) -> int:
+        return sum(1 for c in self.checkpoints if c.confirmed)
+
+    @property
+    def all_confirmed(self) -> bool:
+        tested = self.tested
+        return bool(tested) and all(c.confirmed for c in tested)
+
+    def strip(self) -> str:
+        """One mark per checkpoint: ✓ confirmed, ✗ disagrees, · skipped."""
+        return "".join(
+            "·" if c.skipped else ("✓" if c.confirmed else "✗")
+            for c in self.checkpoints
+        )
+
+    def describe(self) -> str:
+        return (
+            f"{self.confirmed_count}/{len(self.tested)} checkpoints confirmed "
+            f"{self.applied_offset_frames:+d}f [{self.strip()}]"
+        )
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "backend": self.backend,
+            "applied_offset_frames": self.applied_offset_frames,
+            "audio_offset_frames": self.audio_offset_frames,
+            "confirmed": self.confirmed_count,
+            "tested": len(self.tested),
+            "checkpoints": [c.to_dict() for c in self.checkpoints],
+        }
+
+    @classmethod
+    def from_dict(cls, d: dict[str, Any]) -> CheckpointReport:
+        return cls(
+            backend=str(d.get("backend", "")),
+            applied_offset_frames=int(d["applied_offset_frames"]),
+            audio_offset_frames=int(d["audio_offset_frames"]),
+            checkpoints=tuple(
+                CheckpointResult.from_dict(c) for c in d.get("checkpoints", [])
+            ),
+        )
+
+
+def checkpoint_report(details: dict[str, Any] | None) -> CheckpointReport | None:
+    """The report stored in matcher ``details`` (None for fallbacks)."""
+    data = (details or {}).get("checkpoints")
+    if not isinstance(data, dict):
+        return None
+    try:
+        return CheckpointReport.from_dict(data)
+    except (KeyError, TypeError, ValueError):
+        return None
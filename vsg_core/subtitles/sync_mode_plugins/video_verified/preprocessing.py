@@ -414,17 +414,36 @@ def run_per_source_preprocessing(
         source_props = detect_video_properties(str(source_video), runner)
         ctx.video_properties[source_key] = source_props
 
-        # Gate: skip frame matching for MPEG-2 (DVD) or interlaced content.
+        # Gate: skip frame matching for MPEG-2 (DVD), interlaced or VFR content.
         # MPEG-2 metadata is unreliable for distinguishing soft telecine,
         # hard telecine, and true interlaced — all use correlation for now.
         # Non-MPEG-2 interlaced (e.g., H.264 Blu-ray) is reliably detected
         # by ffprobe and also skips frame matching.
+        # VFR (either side) has no fixed frame duration to convert frame
+        # offsets with; frame matching would produce subtly wrong timings.
         codec = source_props.get("codec_name", "")
         is_mpeg2 = codec in ("mpeg2video", "mpeg1video")
         content_type = source_props.get("content_type", "unknown")
+        is_vfr = bool(source_props.get("is_vfr") or source1_props.get("is_vfr"))
+
+        # Auto interlace detection: the idet verdict on decoded frames
+        # overrides the flags, so a progressive encode flagged interlaced
+        # still gets frame matching (and a mis-flagged interlaced one skips).
+        if (
+            not is_mpeg2
+            and ctx.settings.video_verified_interlace_detection == "auto"
+        ):
+            content_type = _confirm_interlacing(
+                source_key, str(source_video), source_props, content_type, ctx, runner
+            )
 
-        if is_mpeg2 or content_type == "interlaced":
-            reason = "MPEG-2" if is_mpeg2 else "interlaced"
+        if is_mpeg2 or content_type == "interlaced" or is_vfr:
+            if is_mpeg2:
+                reason = "MPEG-2"
+            elif content_type == "interlaced":
+                reason = "interlaced"
+            else:
+                reason = "VFR"
             runner._log_message(
                 f"[VideoVerified] {source_key}: {reason} content detected "
                 f"(type={content_type}, codec={codec})"
@@ -712,3 +731,40 @@ def _run_visual_verify_if_enabled(
 
     except Exception as e:
         runner._log_message(f"[VisualVerify] WARNING: Verification failed - {e}")
+
+
+def _confirm_interlacing(
+    source_key: str,
+    source_video: str,
+    source_props: dict,
+    content_type: str,
+    ctx: Context,
+    runner: CommandRunner,
+) -> str:
+    """Content type after checking the flags against ffmpeg idet.
+
+    Returns ``content_type`` unchanged when idet gives no verdict.
+    """
+    from vsg_core.extraction.interlace import detect_interlaced
+
+    duration_ms = source_props.get("duration_ms") or 0.0
+    info = detect_interlaced(
+        source_video, runner, ctx.tool_paths, duration_s=duration_ms / 1000.0
+    )
+    if info is None or not info.classified:
+        runner._log_message(
+            f"[VideoVerified] {source_key}: idet gave no verdict, "
+            f"keeping flags (type={content_type})"
+        )
+        return content_type
+
+    runner._log_message(f"[VideoVerified] {source_key}: idet: {info.describe()}")
+    source_props["idet_field_order"] = info.field_order
+    detected = "interlaced" if info.is_interlaced else "progressive"
+    if content_type in ("interlaced", "progressive") and detected != content_type:
+        runner._log_message(
+            f"[VideoVerified] {source_key}: flagged {content_type}, "
+            f"idet says {detected} — treating as {detected}"
+        )
+        return detected
+    return content_type
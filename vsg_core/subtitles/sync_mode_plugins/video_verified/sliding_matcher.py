@@ -29,12 +29,14 @@ from __future__ import annotations
 
 import time
 from collections import Counter
+from dataclasses import replace
 from pathlib import Path
 from typing import Any, Callable
 
 import numpy as np
 
 from .backends import BackendResult, SlidingBackend, get_backend
+from .checkpoints import CheckpointReport, CheckpointResult
 from .sliding_core import compute_gradient, open_clip
 
 
@@ -295,6 +297,37 @@ def calculate_sliding_offset(
         }
     t_model = time.time() - t_model_start
 
+    # ─── REFERENCE HASH INDEX (pHash/dHash only) ────────────────
+    # Target hashes come from a precomputed whole-video index instead of
+    # being decoded and hashed at every position. Any failure just means
+    # hashing live, as without the index.
+    if getattr(settings, "video_verified_hash_index", False) and hasattr(
+        backend, "reference_index"
+    ):
+        from ...frame_utils.hash_index import get_hash_index  # noqa: PLC0415
+
+        hash_size = int(getattr(settings, "video_verified_hash_size", 32))
+        try:
+            index = get_hash_index(
+                target_video,
+                backend_name,
+                hash_size,
+                temp_dir=temp_dir,
+                batch_size=batch_size,
+                log=log,
+            )
+        except Exception as e:
+            log(f"[SlidingVerified] Hash index unavailable, hashing live: {e}")
+        else:
+            if index.num_frames == tgt_rgb.num_frames:
+                backend.reference_index = index
+                log(f"[SlidingVerified] Target hashes from index ({index.num_frames}f)")
+            else:
+                log(
+                    f"[SlidingVerified] Hash index has {index.num_frames}f, target "
+                    f"{tgt_rgb.num_frames}f — hashing live"
+                )
+
     # ─── SLIDING GEOMETRY ───────────────────────────────────────
     src_n_frames = int(window_sec * src_fps)
     slide_pad = int(slide_range_sec * tgt_fps)
@@ -313,6 +346,9 @@ def calculate_sliding_offset(
     # ─── PER-POSITION SLIDING ───────────────────────────────────
     results: list[dict[str, Any]] = []
     landscapes: list[dict[str, Any]] = []
+    # Per-checkpoint outcome (checkpoints.py); frames are target indices
+    audio_offset_frames = int(round(pure_correlation_ms / src_frame_dur_ms))
+    checkpoints: list[CheckpointResult] = []
     t_total_start = time.time()
 
     for i, pct in enumerate(positions_pct):
@@ -333,11 +369,14 @@ def calculate_sliding_offset(
         tgt_window_start = max(0, tgt_center - slide_pad)
         tgt_window_end = min(tgt_rgb.num_frames, tgt_center + src_n_frames + slide_pad)
         tgt_frames = list(range(tgt_window_start, tgt_window_end))
+        expected_frame = tgt_center + audio_offset_frames
+        skipped = CheckpointResult(pct, src_start, expected_frame, None)
 
         if len(tgt_frames) <= len(src_frames):
             log(
                 f"[SlidingVerified]   [{i + 1}/{num_positions}] {pct:.0f}% — SKIPPED (edge)"
             )
+            checkpoints.append(replace(skipped, skip_reason="edge"))
             continue
 
         # ─── Backend scoring ───────────────────────────────────
@@ -356,6 +395,7 @@ def calculate_sliding_offset(
                 f"[SlidingVerified]   [{i + 1}/{num_positions}] {pct:.0f}% — "
                 f"BACKEND ERROR: {e}"
             )
+            checkpoints.append(replace(skipped, skip_reason=f"backend error: {e}"))
             continue
 
         scores = bresult.scores
@@ -365,6 +405,7 @@ def calculate_sliding_offset(
             log(
                 f"[SlidingVerified]   [{i + 1}/{num_positions}] {pct:.0f}% — SKIPPED (no slides)"
             )
+            checkpoints.append(replace(skipped, skip_reason="no slides"))
             continue
 
         best_pos = int(np.argmax(scores))
@@ -397,6 +438,17 @@ def calculate_sliding_offset(
             "time_s": dt,
         }
         results.append(result)
+        checkpoints.append(
+            CheckpointResult(
+                position_pct=pct,
+                src_frame=src_start,
+                expected_frame=expected_frame,
+                matched_frame=tgt_window_start + best_pos,
+                score=float(scores[best_pos]),
+                matches=int(match_counts[best_pos]),
+                total=len(src_frames),
+            )
+        )
 
         landscape = {
             "position_pct": pct,
@@ -423,12 +475,16 @@ def calculate_sliding_offset(
 
     if not results:
         log("[SlidingVerified] No valid positions — falling back to audio correlation")
+        report = CheckpointReport(
+            backend_name, audio_offset_frames, audio_offset_frames, tuple(checkpoints)
+        )
         return total_delay_ms, {
             "reason": "fallback-no-valid-positions",
             "audio_correlation_ms": pure_correlation_ms,
             "video_offset_ms": pure_correlation_ms,
             "final_offset_ms": total_delay_ms,
             "backend": backend_name,
+            "checkpoints": report.to_dict(),
         }
 
     # ─── CONSENSUS ───────────────────────────────────────────────
@@ -438,6 +494,22 @@ def calculate_sliding_offset(
     consensus_frames = consensus[0]
     consensus_count = consensus[1]
     consensus_ms = consensus_frames * src_frame_dur_ms
+    # A checkpoint confirms the delay when it matched the consensus offset
+    report = CheckpointReport(
+        backend=backend_name,
+        applied_offset_frames=consensus_frames,
+        audio_offset_frames=audio_offset_frames,
+        checkpoints=tuple(
+            c
+            if c.skipped
+            else replace(
+                c,
+                confirmed=c.offset_from_audio + audio_offset_frames
+                == consensus_frames,
+            )
+            for c in checkpoints
+        ),
+    )
 
     # Confidence assessment
     consensus_ratio = consensus_count / len(results)
@@ -466,6 +538,10 @@ def calculate_sliding_offset(
     )
     log(f"[SlidingVerified] Mean gradient: {mean_gradient:.4f}/frame")
     log(f"[SlidingVerified] Confidence: {confidence}")
+    log(f"[SlidingVerified] Checkpoints: {report.describe()}")
+    if settings.video_verified_frame_audit:
+        for checkpoint in report.checkpoints:
+            log(f"[SlidingVerified]   Checkpoint {checkpoint.describe(backend_name)}")
     log(f"[SlidingVerified] Audio correlation: {pure_correlation_ms:+.3f}ms")
 
     diff_ms = consensus_ms - pure_correlation_ms
@@ -572,6 +648,7 @@ def calculate_sliding_offset(
         "target_fps": tgt_fps,
         "total_time_s": dt_total,
         "per_position_results": results,
+        "checkpoints": report.to_dict(),
         # PTS correction metadata — consumed by SlidingConfidenceAuditor
         "pts_correction_applied": pts_correction_applied,
         "src_start_pts_s": src_start_pts_s,
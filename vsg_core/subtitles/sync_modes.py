@@ -105,6 +105,7 @@ def _ensure_plugins_loaded():
 
         plugins_to_load = [
             "vsg_core.subtitles.sync_mode_plugins.time_based",
+            "vsg_core.subtitles.sync_mode_plugins.linear_stretch",
             "vsg_core.subtitles.sync_mode_plugins.video_verified",
         ]
         for module_name in plugins_to_load:
@@ -5,7 +5,7 @@ Subtitle track processing pipeline.
 Processes a single subtitle track through the unified SubtitleData flow:
 1. Load into SubtitleData (or use provided from OCR)
 2. Apply style filtering (if generated track)
-3. Apply stepping
+3. Apply stepping and framerate retime
 4. Apply sync mode
 5. Apply style operations (font, patch, rescale, size)
 6. Save JSON + ASS/SRT (single rounding point)
@@ -31,6 +31,11 @@ from vsg_core.subtitles.diagnostics import (
     read_raw_ass_timestamps,
 )
 from vsg_core.subtitles.operations.duration_audit import audit_subtitle_duration
+from vsg_core.subtitles.operations.lint import (
+    SubtitleLintResult,
+    fix_subtitle_timing,
+    lint_subtitles,
+)
 from vsg_core.subtitles.sync_dispatcher import apply_sync_mode
 
 
@@ -227,6 +232,20 @@ def process_subtitle_track(
             else:
                 runner._log_message(f"[SubtitleData] Stepping failed: {result.error}")
 
+    # ================================================================
+    # STEP 2b: Framerate Retime (if configured)
+    # ================================================================
+    retime_src = ctx.settings.subtitle_retime_src_fps
+    retime_dst = ctx.settings.subtitle_retime_dst_fps
+    if retime_src > 0 and retime_dst > 0 and abs(retime_src - retime_dst) > 1e-6:
+        result = subtitle_data.retime_fps(
+            retime_src, retime_dst, ctx.settings.subtitle_rounding, runner
+        )
+        if result.success:
+            runner._log_message(f"[SubtitleData] Retime: {result.summary}")
+        else:
+            runner._log_message(f"[SubtitleData] Retime failed: {result.error}")
+
     # ================================================================
     # STEP 3: Apply Sync Mode
     # ================================================================
@@ -303,6 +322,32 @@ def process_subtitle_track(
         )
     )
 
+    # ================================================================
+    # STEP 3c: Event timing sanity check (fix is opt-in)
+    # ================================================================
+    lint_issues = lint_subtitles(subtitle_data.events)
+    fixed = 0
+    if lint_issues:
+        runner._log_message(
+            f"[SubLint] {len(lint_issues)} event timing problem(s) in track "
+            f"{item.track.id}:"
+        )
+        for issue in lint_issues[:10]:
+            runner._log_message(f"[SubLint]   {issue.describe()}")
+        if len(lint_issues) > 10:
+            runner._log_message(f"[SubLint]   ... and {len(lint_issues) - 10} more")
+        if ctx.settings.subtitle_lint_autofix:
+            fixed = fix_subtitle_timing(subtitle_data.events)
+            runner._log_message(f"[SubLint] Fixed {fixed} event(s).")
+    ctx.subtitle_lint_results[f"{item.track.source}_t{item.track.id}"] = (
+        SubtitleLintResult(
+            track_label=f"{track_name} ({item.track.source})",
+            is_external=item.track.source == "External",
+            issues=tuple(lint_issues),
+            events_fixed=fixed,
+        )
+    )
+
     # ================================================================
     # STEP 4: Apply SRT to ASS Conversion (if needed)
     # ================================================================
@@ -14,6 +14,8 @@ from __future__ import annotations
 import math
 from typing import TYPE_CHECKING
 
+from ..data import SubtitleEvent, SubtitleStyle
+
 if TYPE_CHECKING:
     from pathlib import Path
 
@@ -194,23 +196,69 @@ def _write_script_info(data: SubtitleData, lines: list) -> None:
     has_script_type = any(k.lower() == "scripttype" for k in data.script_info)
     has_collisions = any(k.lower() == "collisions" for k in data.script_info)
 
-    for key, value in data.script_info.items():
-        if key == "__comments__":
-            # Write comments
-            for comment in value:
-                lines.append(comment)
-        else:
-            lines.append(f"{key}: {value}")
+    if data.script_info_lines:
+        _write_script_info_verbatim(data, lines)
+    else:
+        for key, value in data.script_info.items():
+            if key == "__comments__":
+                # Write comments
+                for comment in value:
+                    lines.append(comment)
+            else:
+                lines.append(f"{key}: {value}")
 
     # Add missing critical fields at end
     if not has_script_type:
         lines.append("ScriptType: v4.00+")
-    if not has_collisions:
+    if not has_collisions and not data.script_info_lines:
         lines.append("Collisions: Normal")
 
     lines.append("")
 
 
+def _write_script_info_verbatim(data: SubtitleData, lines: list) -> None:
+    """
+    Re-emit the parsed [Script Info] lines as-is.
+
+    Only keys whose value changed (e.g. PlayRes after a rescale) are
+    rewritten; removed keys are dropped and new keys appended.
+    """
+    written = set()
+    raw = list(data.script_info_lines)
+    while raw and not raw[-1].strip():
+        raw.pop()  # The section separator is added by the caller
+    for line in raw:
+        stripped = line.strip()
+        if not stripped or stripped.startswith(";") or ":" not in stripped:
+            lines.append(line)
+            continue
+        key, value = stripped.split(":", 1)
+        key = key.strip()
+        if key not in data.script_info or key in written:
+            continue
+        written.add(key)
+        current = data.script_info[key]
+        lines.append(line if current == value.strip() else f"{key}: {current}")
+
+    for key, value in data.script_info.items():
+        if key != "__comments__" and key not in written:
+            lines.append(f"{key}: {value}")
+
+
+def _original_values(
+    original_line: str | None, format_fields: list[str]
+) -> list[str] | None:
+    """Split a parsed ``Style:``/``Dialogue:``/``Comment:`` line like the parser."""
+    if not original_line or ":" not in original_line:
+        return None
+    body = original_line.split(":", 1)[1].strip()
+    text_idx = next(
+        (i for i, f in enumerate(format_fields) if f.strip().lower() == "text"),
+        None,
+    )
+    return body.split(",", text_idx) if text_idx is not None else body.split(",")
+
+
 def _write_styles(data: SubtitleData, lines: list, section_name: str) -> None:
     """Write [V4+ Styles] section."""
     lines.append(section_name)
@@ -219,9 +267,17 @@ def _write_styles(data: SubtitleData, lines: list, section_name: str) -> None:
     format_line = "Format: " + ", ".join(data.styles_format)
     lines.append(format_line)
 
-    # Style lines
+    # Style lines (unmodified styles keep their original line)
     for style in data.styles.values():
         values = style.to_format_values(data.styles_format)
+        original = _original_values(style._original_line, data.styles_format)
+        if original is not None:
+            parsed = SubtitleStyle.from_format_line(
+                data.styles_format, [v.strip() for v in original]
+            )
+            if parsed.to_format_values(data.styles_format) == values:
+                lines.append(style._original_line)
+                continue
         lines.append("Style: " + ",".join(values))
 
     lines.append("")
@@ -254,6 +310,26 @@ def _write_events(
         # Check if surgical rounding is available for this event
         surg = surgical_results.get(idx) if surgical_results else None
 
+        # Unmodified events keep their original fields; only timing changes
+        original = _unchanged_event_values(event, data.events_format)
+        if original is not None:
+            for i, field in enumerate(data.events_format):
+                field_lower = field.strip().lower()
+                if field_lower == "start":
+                    original[i] = (
+                        _format_ass_time_from_cs(surg.start.centisecond_ms)
+                        if surg is not None
+                        else _format_ass_time(event.start_ms, rounding_mode)
+                    )
+                elif field_lower == "end":
+                    original[i] = (
+                        _format_ass_time_from_cs(surg.end.centisecond_ms)
+                        if surg is not None
+                        else _format_ass_time(event.end_ms, rounding_mode)
+                    )
+            lines.append(f"{event_type}: " + ",".join(original))
+            continue
+
         # Build values
         values = []
         for field in data.events_format:
@@ -295,6 +371,21 @@ def _write_events(
     lines.append("")
 
 
+def _unchanged_event_values(
+    event: SubtitleEvent, format_fields: list[str]
+) -> list[str] | None:
+    """Original field values of an event whose non-timing fields are unchanged."""
+    original = _original_values(event._original_line, format_fields)
+    if original is None or len(original) != len(format_fields):
+        return None
+    if event._original_line.lower().startswith("comment:") != event.is_comment:
+        return None
+    parsed = SubtitleEvent.from_format_line(format_fields, original, event.is_comment)
+    if parsed.to_format_values(format_fields) != event.to_format_values(format_fields):
+        return None
+    return original
+
+
 def _write_fonts(data: SubtitleData, lines: list) -> None:
     """Write [Fonts] section."""
     if not data.fonts:
@@ -0,0 +1,229 @@
+# vsg_core/watch.py
+"""
+Watch mode: run jobs for files as they appear in the source folders.
+
+``Watcher`` pairs the files of the watched folders with ``discover_jobs``
+(Source 1's folder is the reference, as in a batch) and hands every job
+whose sources are all present and finished copying to ``run_job``, one at
+a time. It is the loop behind ``vsg-cli watch``; what a job does (layout,
+merge or analyze) is up to the callback.
+
+A file counts as finished once its size and modification time have not
+changed for ``settle_s`` seconds, so jobs don't start on a file that is
+still being copied or downloaded.
+
+The folders are rescanned every ``poll_s`` seconds (every second while
+a file is still settling); polling needs no extra package and also works
+on network shares, where filesystem notifications often never arrive.
+
+Progress is kept in ``vsg_watch_state.json`` in the output folder, written
+before and after every job. After a restart:
+
+- jobs recorded as finished are not run again, whatever their status
+  (a failed job is retried by deleting its entry or the state file);
+- a job recorded as running was interrupted and runs again;
+- a job missing from the state whose output file already exists is
+  skipped, so pointing the watcher at folders processed earlier doesn't
+  redo them. The output is looked up under the name the job would get
+  (``output_name``, e.g. rendered from ``output_template``), Source 1's
+  filename by default.
+"""
+
+from __future__ import annotations
+
+import json
+import threading
+import time
+from datetime import datetime
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from .job_discovery import discover_jobs
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from .models.jobs import PipelineResult
+    from .models.types import DiscoveryStrategyStr
+
+STATE_FILENAME = "vsg_watch_state.json"
+STATE_VERSION = 1
+DEFAULT_SETTLE_S = 30.0
+DEFAULT_POLL_S = 10.0
+
+
+def job_key(sources: dict[str, Any]) -> str:
+    """Stable identifier of a job in the state file."""
+    return "|".join(f"{k}={sources[k]}" for k in sorted(sources) if sources[k])
+
+
+class FileSettleTracker:
+    """Tells when files have stopped changing (size and mtime)."""
+
+    def __init__(
+        self, settle_s: float, clock: Callable[[], float] = time.monotonic
+    ):
+        self.settle_s = settle_s
+        self._clock = clock
+        # path -> ((size, mtime_ns), time that stat was first seen)
+        self._seen: dict[str, tuple[tuple[int, int], float]] = {}
+
+    def is_settled(self, path: str) -> bool:
+        try:
+            stat = Path(path).stat()
+        except OSError:
+            self._seen.pop(path, None)
+            return False
+        signature = (stat.st_size, stat.st_mtime_ns)
+        now = self._clock()
+        previous = self._seen.get(path)
+        if previous is None or previous[0] != signature:
+            self._seen[path] = (signature, now)
+            return False
+        return stat.st_size > 0 and now - previous[1] >= self.settle_s
+
+
+class WatchState:
+    """Per-job status persisted in the output folder (see module docstring)."""
+
+    RUNNING = "running"
+
+    def __init__(self, path: Path, log: Callable[[str], None]):
+        self.path = path
+        self.log = log
+        self.jobs: dict[str, dict[str, Any]] = {}
+        if path.exists():
+            try:
+                self.jobs = json.loads(path.read_text(encoding="utf-8"))["jobs"]
+            except (OSError, ValueError, KeyError, TypeError) as e:
+                log(f"[Watch] Could not read {path.name}, starting fresh: {e}")
+
+    def status(self, key: str) -> str | None:
+        entry = self.jobs.get(key)
+        return entry.get("status") if entry else None
+
+    def record(self, key: str, status: str, **details: Any) -> None:
+        self.jobs[key] = {
+            "status": status,
+            "updated": datetime.now().isoformat(timespec="seconds"),
+            **details,
+        }
+        self._save()
+
+    def _save(self) -> None:
+        data = {"version": STATE_VERSION, "jobs": self.jobs}
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            temp_file = self.path.with_suffix(".tmp")
+            temp_file.write_text(json.dumps(data, indent=2), encoding="utf-8")
+            temp_file.replace(self.path)
+        except OSError as e:
+            self.log(f"[Watch] Could not save {self.path.name}: {e}")
+
+
+class Watcher:
+    """Runs a job for each complete set of settled files in the folders."""
+
+    def __init__(
+        self,
+        folders: dict[str, str],
+        output_dir: str,
+        run_job: Callable[[dict[str, Any]], PipelineResult],
+        log: Callable[[str], None],
+        *,
+        strategy: DiscoveryStrategyStr = "exact",
+        episode_regex: str = "",
+        settle_s: float = DEFAULT_SETTLE_S,
+        poll_s: float = DEFAULT_POLL_S,
+        clock: Callable[[], float] = time.monotonic,
+        output_name: Callable[[dict[str, Any]], str] | None = None,
+    ):
+        self.folders = folders
+        self.output_dir = Path(output_dir)
+        self.run_job = run_job
+        self.log = log
+        self.strategy = strategy
+        self.episode_regex = episode_regex
+        self.poll_s = poll_s
+        self.settle = FileSettleTracker(settle_s, clock)
+        self.state = WatchState(self.output_dir / STATE_FILENAME, log)
+        self._reported: set[str] = set()
+        self._output_name = output_name or (
+            lambda sources: Path(sources["Source 1"]).name
+        )
+        self._output_names: dict[str, str] = {}  # job key -> expected name
+
+    def _note_once(self, key: str, msg: str) -> None:
+        if key not in self._reported:
+            self._reported.add(key)
+            self.log(msg)
+
+    def pending_jobs(self) -> tuple[list[dict[str, Any]], bool]:
+        """
+        (jobs ready to run, whether any job is still waiting for its files
+        to settle). Only jobs with a file from every watched folder count.
+        """
+        try:
+            jobs = discover_jobs(self.folders, self.strategy, self.episode_regex)
+        except (ValueError, OSError) as e:
+            self._note_once(f"error:{e}", f"[Watch] Cannot list the folders: {e}")
+            return [], False
+
+        ready, waiting = [], False
+        for job in jobs:
+            sources = job["sources"]
+            if len(sources) < len(self.folders):
+                continue  # The other files of the pair haven't appeared yet
+            key = job_key(sources)
+            status = self.state.status(key)
+            if status is not None and status != WatchState.RUNNING:
+                continue
+            # Check every file (no short-circuit) so all settle clocks start
+            settled = [self.settle.is_settled(p) for p in sources.values()]
+            if not all(settled):
+                waiting = True
+                continue
+            name = self._expected(key, sources) if status is None else ""
+            if name and (self.output_dir / name).exists():
+                self._note_once(
+                    key, f"[Watch] {name}: output already exists, skipping."
+                )
+                continue
+            ready.append(job)
+        return ready, waiting
+
+    def _expected(self, key: str, sources: dict[str, Any]) -> str:
+        """Output filename of a job, worked out once (it may probe Source 1)."""
+        if key not in self._output_names:
+            self._output_names[key] = self._output_name(sources)
+        return self._output_names[key]
+
+    def run_pending(self) -> bool:
+        """Runs every ready job once. Returns whether files are still settling."""
+        ready, waiting = self.pending_jobs()
+        for job in ready:
+            key = job_key(job["sources"])
+            name = Path(job["sources"]["Source 1"]).name
+            if self.state.status(key) == WatchState.RUNNING:
+                self.log(f"[Watch] {name}: interrupted last time, running again.")
+            self.log(f"[Watch] Starting {name}")
+            self.state.record(key, WatchState.RUNNING)
+            try:
+                result = self.run_job(job)
+                status, error = result.status, result.error
+            except Exception as e:
+                status, error = "Failed", str(e)
+            self.state.record(key, status, error=error)
+            self.log(f"[Watch] {name}: {status}" + (f" ({error})" if error else ""))
+        return waiting
+
+    def run(self, stop: threading.Event) -> None:
+        """Watches until ``stop`` is set (jobs in progress finish first)."""
+        names = ", ".join(f"{k}: {v}" for k, v in self.folders.items())
+        self.log(f"[Watch] Watching {names}; output to {self.output_dir}")
+        while not stop.is_set():
+            waiting = self.run_pending()
+            # Re-check settling files every second; otherwise wait for the
+            # next rescan
+            stop.wait(min(1.0, self.poll_s) if waiting else self.poll_s)
+        self.log("[Watch] Stopped.")
@@ -1,7 +1,10 @@
 # vsg_qt/add_job_dialog/ui.py
 from __future__ import annotations
 
+from pathlib import Path
+
 from PySide6.QtWidgets import (
+    QComboBox,
     QDialog,
     QDialogButtonBox,
     QFileDialog,
@@ -15,7 +18,13 @@ from PySide6.QtWidgets import (
     QWidget,
 )
 
-from vsg_core.job_discovery import discover_jobs
+from vsg_core.job_discovery import discover_jobs, low_confidence_jobs
+from vsg_core.models.source_input import (
+    PARTS_SEPARATOR,
+    SourceValue,
+    parse_source_text,
+    primary_path,
+)
 
 
 class SourceInputWidget(QWidget):
@@ -34,6 +43,11 @@ class SourceInputWidget(QWidget):
         )
         label = QLabel(label_text)
         self.line_edit = QLineEdit()
+        self.line_edit.setToolTip(
+            "A file or a folder. For a source split into parts, drop all parts\n"
+            f"at once or separate them with '{PARTS_SEPARATOR}' (in playback "
+            "order);\nthey are joined before analysis."
+        )
         browse_btn = QPushButton("Browse…")
 
         browse_btn.clicked.connect(self._browse_for_path)
@@ -50,9 +64,9 @@ class SourceInputWidget(QWidget):
 
     def dropEvent(self, event) -> None:
         if event.mimeData().hasUrls():
-            # Use the path of the first dropped file
-            path = event.mimeData().urls()[0].toLocalFile()
-            self.line_edit.setText(path)
+            # Several dropped files are the parts of one source
+            paths = [url.toLocalFile() for url in event.mimeData().urls()]
+            self.line_edit.setText(f" {PARTS_SEPARATOR} ".join(paths))
             event.acceptProposedAction()
         else:
             event.ignore()
@@ -72,10 +86,11 @@ class AddJobDialog(QDialog):
     A dialog for dynamically adding sources to discover jobs.
     """
 
-    def __init__(self, parent=None):
+    def __init__(self, parent=None, config=None):
         super().__init__(parent)
         self.setWindowTitle("Add Job(s) to Queue")
         self.setMinimumSize(700, 300)
+        self.config = config
 
         self.discovered_jobs: list[dict] = []
         self.source_widgets: list[SourceInputWidget] = []
@@ -101,6 +116,35 @@ class AddJobDialog(QDialog):
         add_source_btn.clicked.connect(self.add_source_input)
         layout.addWidget(add_source_btn)
 
+        # --- Folder matching strategy (batch mode only) ---
+        match_row = QHBoxLayout()
+        self.strategy_combo = QComboBox()
+        self.strategy_combo.addItem("Exact filename", "exact")
+        self.strategy_combo.addItem("Normalized (ignore tags/resolution)", "normalized")
+        self.strategy_combo.addItem("Regex episode number", "regex")
+        self.strategy_combo.setToolTip(
+            "How files are paired across source folders.\n"
+            "Exact: identical filenames only.\n"
+            "Normalized: strips [group], (year), 1080p, x264, BD... before comparing.\n"
+            "Regex: your pattern must capture the episode number in group 1."
+        )
+        self.regex_edit = QLineEdit()
+        self.regex_edit.setPlaceholderText(r"e.g. [Ee](\d+)  or  - (\d+)")
+        self.strategy_combo.currentIndexChanged.connect(self._update_regex_enabled)
+        match_row.addWidget(QLabel("Folder matching:"))
+        match_row.addWidget(self.strategy_combo, 2)
+        match_row.addWidget(self.regex_edit, 2)
+        layout.addLayout(match_row)
+
+        if self.config is not None:
+            index = self.strategy_combo.findData(
+                self.config.get("discovery_strategy", "exact")
+            )
+            if index >= 0:
+                self.strategy_combo.setCurrentIndex(index)
+            self.regex_edit.setText(self.config.get("discovery_regex", ""))
+        self._update_regex_enabled()
+
         dialog_btns = QDialogButtonBox(QDialogButtonBox.StandardButton.Ok | QDialogButtonBox.StandardButton.Cancel)
         ok_button = dialog_btns.button(QDialogButtonBox.StandardButton.Ok)
         ok_button.setText("Find & Add Jobs")
@@ -110,6 +154,9 @@ class AddJobDialog(QDialog):
 
         layout.addWidget(dialog_btns)
 
+    def _update_regex_enabled(self) -> None:
+        self.regex_edit.setEnabled(self.strategy_combo.currentData() == "regex")
+
     def add_source_input(self) -> None:
         """Adds a new SourceInputWidget to the dialog."""
         source_num = len(self.source_widgets) + 1
@@ -137,11 +184,11 @@ class AddJobDialog(QDialog):
 
     def find_and_accept(self) -> None:
         """Discover jobs from paths and accept the dialog if any are found."""
-        sources: dict[str, str] = {}
+        sources: dict[str, SourceValue] = {}
         for i, source_widget in enumerate(self.source_widgets):
-            path = source_widget.text().strip()
-            if path:
-                sources[f"Source {i + 1}"] = path
+            value = parse_source_text(source_widget.text())
+            if value:
+                sources[f"Source {i + 1}"] = value
 
         if "Source 1" not in sources:
             QMessageBox.warning(
@@ -150,7 +197,11 @@ class AddJobDialog(QDialog):
             return
 
         try:
-            self.discovered_jobs = discover_jobs(sources)
+            self.discovered_jobs = discover_jobs(
+                sources,
+                strategy=self.strategy_combo.currentData(),
+                episode_regex=self.regex_edit.text().strip(),
+            )
             if not self.discovered_jobs:
                 QMessageBox.information(
                     self,
@@ -159,9 +210,36 @@ class AddJobDialog(QDialog):
                 )
                 return
 
+            threshold = (
+                self.config.get("discovery_min_confidence", 0.8)
+                if self.config is not None
+                else 0.8
+            )
+            flagged = low_confidence_jobs(self.discovered_jobs, threshold)
+            if flagged and not self._confirm_low_confidence(flagged):
+                return
+
             self.accept()
         except (ValueError, FileNotFoundError) as e:
             QMessageBox.critical(self, "Error Discovering Jobs", str(e))
 
+    def _confirm_low_confidence(self, flagged) -> bool:
+        """Lists weak pairings and asks whether to add them anyway."""
+        lines = []
+        for job, weak in flagged[:15]:
+            ref_name = Path(primary_path(job["sources"]["Source 1"])).name
+            for key, score in weak.items():
+                other = Path(primary_path(job["sources"][key])).name
+                lines.append(f"{ref_name}  ↔  {other}  ({key}, {score:.0%})")
+        if len(flagged) > 15:
+            lines.append(f"... and {len(flagged) - 15} more job(s)")
+        reply = QMessageBox.question(
+            self,
+            "Low-Confidence Matches",
+            f"{len(flagged)} job(s) were paired with low confidence and should be "
+            "reviewed before running:\n\n" + "\n".join(lines) + "\n\nAdd them anyway?",
+        )
+        return reply == QMessageBox.StandardButton.Yes
+
     def get_discovered_jobs(self) -> list[dict]:
         return self.discovered_jobs
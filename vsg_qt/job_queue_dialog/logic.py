@@ -1,17 +1,28 @@
 # vsg_qt/job_queue_dialog/logic.py
 from __future__ import annotations
 
+import json
 import re
 import shutil
 from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
 from PySide6.QtCore import Qt
-from PySide6.QtWidgets import QHeaderView, QMessageBox, QTableWidgetItem
-
-from vsg_core.extraction.tracks import get_track_info_for_dialog
+from PySide6.QtWidgets import (
+    QFileDialog,
+    QHeaderView,
+    QInputDialog,
+    QMessageBox,
+    QTableWidgetItem,
+)
+
+from vsg_core.extraction.tracks import scan_sources
 from vsg_core.io.runner import CommandRunner
+from vsg_core.job_layouts.manager import repoint_layout
+from vsg_core.job_layouts.validation import rebind_layout
 from vsg_core.models.context_types import ManualLayoutItem
+from vsg_core.models.overrides import validate_overrides
+from vsg_core.models.source_input import SourceInput, primary_path, primary_sources
 from vsg_qt.add_job_dialog import AddJobDialog
 from vsg_qt.manual_selection_dialog import ManualSelectionDialog
 
@@ -40,18 +51,30 @@ class JobQueueLogic:
             t: shutil.which(t) for t in ["mkvmerge", "mkvextract", "ffmpeg", "ffprobe"]
         }
 
+        # Restore the queue saved by a previous session (if any)
+        self.persist = bool(self.v.config.get("persist_job_queue", True))
+        if self.persist:
+            self.jobs = self.layout_manager.load_queue()
+
+    def save_queue(self) -> None:
+        """Persists the current queue order and sources (if enabled)."""
+        if self.persist:
+            self.layout_manager.save_queue(self.jobs)
+
     def add_jobs(self, new_jobs: list[dict]) -> None:
         """Initializes and adds new jobs to the queue."""
         for job in new_jobs:
             job["status"] = "Needs Configuration"  # Set initial in-memory status
         new_jobs.sort(
-            key=lambda j: natural_sort_key(Path(j["sources"]["Source 1"]).name)
+            key=lambda j: natural_sort_key(
+                Path(primary_path(j["sources"]["Source 1"])).name
+            )
         )
         self.jobs.extend(new_jobs)
         self.populate_table()
 
     def add_jobs_from_dialog(self) -> None:
-        dialog = AddJobDialog(self.v)
+        dialog = AddJobDialog(self.v, config=self.v.config)
         if dialog.exec():
             self.add_jobs(dialog.get_discovered_jobs())
 
@@ -69,13 +92,19 @@ class JobQueueLogic:
         self.v.table.setRowCount(len(self.jobs))
         for row, job in enumerate(self.jobs):
             self._update_row(row, job)
+        self.save_queue()
 
     def _update_row(self, row: int, job: dict) -> None:
         """Updates a single row based on its on-disk and in-memory state."""
+        if job.get("status") == "Unavailable" or job.get("stale_layout"):
+            self._set_flagged_row(row, job)
+            return
+
         job_id = self.layout_manager.generate_job_id(job["sources"])
         status_text = (
             "Configured"
             if self.layout_manager.layout_exists(job_id)
+            and not job.get("paste_mismatches")
             else "Needs Configuration"
         )
 
@@ -85,7 +114,12 @@ class JobQueueLogic:
         if status_text == "Configured":
             # Only check if layout exists
             layout_data = self.layout_manager.load_job_layout(job_id)
-            if layout_data:
+            if layout_data and layout_data.get("needs_review"):
+                # Duplicated onto files whose tracks don't fit the layout
+                status_text = "Needs Review"
+                validation_warning = " ⚠️"
+                job["validation_issues"] = layout_data["needs_review"]
+            elif layout_data:
                 gen_issues = self._validate_generated_tracks(layout_data, job)
                 sync_exclusion_issues = self._validate_sync_exclusions(layout_data, job)
                 style_edit_issues = self._validate_style_edits(layout_data, job)
@@ -117,15 +151,59 @@ class JobQueueLogic:
         if validation_warning:
             # Add tooltip showing what's wrong
             issues_text = job.get("validation_issues", [])
+            heading = (
+                "Reconfigure this job; the copied layout doesn't fit its files:"
+                if status_text == "Needs Review"
+                else "Layout validation warnings:"
+            )
+            status_item.setToolTip(heading + "\n" + "\n".join(issues_text))
+        elif job.get("paste_mismatches"):
             status_item.setToolTip(
-                "Layout validation warnings:\n" + "\n".join(issues_text)
+                "The pasted layout doesn't fit this job's tracks:\n"
+                + "\n".join(job["paste_mismatches"])
             )
         self.v.table.setItem(row, 1, status_item)
 
-        source_names = [Path(p).name for p in job["sources"].values()]
-        item = QTableWidgetItem(" + ".join(source_names))
-        item.setToolTip("\n".join(job["sources"].values()))
-        self.v.table.setItem(row, 2, item)
+        self.v.table.setItem(row, 2, self._sources_item(job))
+
+    def _sources_item(self, job: dict) -> QTableWidgetItem:
+        inputs = [SourceInput.from_value(v) for v in job["sources"].values() if v]
+        overrides = job.get("settings_overrides") or {}
+        text = " + ".join(s.describe() for s in inputs)
+        tooltip = "\n".join(" + ".join(s.parts) for s in inputs)
+        if overrides:
+            text += f"  [{len(overrides)} override(s)]"
+            tooltip += "\n\nSettings overrides:\n" + "\n".join(
+                f"{k} = {v!r}" for k, v in overrides.items()
+            )
+        item = QTableWidgetItem(text)
+        item.setToolTip(tooltip)
+        return item
+
+    def _set_flagged_row(self, row: int, job: dict) -> None:
+        """Row for restored jobs that must not run as-is (missing/stale)."""
+        if job.get("status") == "Unavailable":
+            status_text = "Unavailable"
+            missing = job.get("missing_sources", [])
+            tooltip = "Source file(s) no longer exist:\n" + "\n".join(
+                f"{key}: {job['sources'].get(key, '')}" for key in missing
+            )
+        else:
+            status_text = "Stale Layout ⚠️"
+            job["status"] = status_text
+            tooltip = (
+                "The saved layout was made for different files with the same "
+                "names.\nReconfigure this job before running it."
+            )
+
+        order_item = QTableWidgetItem(str(row + 1))
+        order_item.setTextAlignment(Qt.AlignmentFlag.AlignCenter)
+        self.v.table.setItem(row, 0, order_item)
+        status_item = QTableWidgetItem(status_text)
+        status_item.setToolTip(tooltip)
+        self.v.table.setItem(row, 1, status_item)
+
+        self.v.table.setItem(row, 2, self._sources_item(job))
 
     def _validate_generated_tracks(self, layout_data: dict, job: dict) -> list[str]:
         """
@@ -161,7 +239,7 @@ class JobQueueLogic:
                 continue
 
             # Get the actual source file path from the job
-            source_file = job["sources"].get(source_key)
+            source_file = primary_path(job["sources"].get(source_key, ""))
             if not source_file:
                 issues.append(f"'{track_name}': Source '{source_key}' not found")
                 continue
@@ -332,7 +410,7 @@ class JobQueueLogic:
             # For non-OCR tracks, extract and validate against actual file
             track_id = track.get("id")
             source_key = track.get("source")
-            source_file = job["sources"].get(source_key)
+            source_file = primary_path(job["sources"].get(source_key, ""))
 
             if not source_file:
                 issues.append(f"'{track_name}': Source '{source_key}' not found")
@@ -444,7 +522,7 @@ class JobQueueLogic:
 
             # Get the actual subtitle file path
             source_key = track.get("source")
-            source_file = job["sources"].get(source_key)
+            source_file = primary_path(job["sources"].get(source_key, ""))
             if not source_file:
                 issues.append(f"'{track_name}': Source '{source_key}' not found")
                 continue
@@ -604,22 +682,39 @@ class JobQueueLogic:
     def _get_track_info_for_job(self, job: dict) -> dict | None:
         """Retrieves and caches track info for a job."""
         if "track_info" not in job or job["track_info"] is None:
-            try:
-                job["track_info"] = get_track_info_for_dialog(
-                    job["sources"], self.runner, self.tool_paths
+            # Parts share one track layout; the first part stands for all
+            scans = scan_sources(
+                primary_sources(job["sources"]), self.runner, self.tool_paths
+            )
+            failed = [s for s in scans.values() if s.error]
+            if failed:
+                details = "\n".join(
+                    f"{s.source_key} ({Path(s.path).name}): {s.error}" for s in failed
                 )
-            except Exception as e:
-                QMessageBox.critical(
+                QMessageBox.warning(
                     self.v,
                     "Error Analyzing Tracks",
-                    f"Could not analyze tracks for {Path(job['sources']['Source 1']).name}:\n{e}",
+                    f"Some sources could not be read; their tracks are not "
+                    f"available:\n{details}",
                 )
-                return None
+                if not any(s.tracks for s in scans.values()):
+                    return None
+            track_info = {key: s.tracks for key, s in scans.items()}
+            if failed:
+                return track_info  # Not cached: rescan once the files are fixed
+            job["track_info"] = track_info
         return job["track_info"]
 
     def configure_job_at_row(self, row: int) -> None:
         """Opens the ManualSelectionDialog and saves the result to disk."""
         job = self.jobs[row]
+        if job.get("status") == "Unavailable":
+            QMessageBox.warning(
+                self.v,
+                "Sources Unavailable",
+                "One or more source files for this job no longer exist.",
+            )
+            return
         job_id = self.layout_manager.generate_job_id(job["sources"])
         track_info = self._get_track_info_for_job(job)
         if not track_info:
@@ -671,6 +766,8 @@ class JobQueueLogic:
                     chapter_source=chapter_source,
                 )
                 if save_ok:
+                    job.pop("stale_layout", None)
+                    job.pop("paste_mismatches", None)
                     self._update_row(row, job)
                 else:
                     QMessageBox.critical(
@@ -679,6 +776,43 @@ class JobQueueLogic:
                         "Could not save the job layout. Check log for details.",
                     )
 
+    def edit_overrides_at_row(self, row: int) -> None:
+        """Edits the job's settings overrides as a JSON object."""
+        job = self.jobs[row]
+        current = job.get("settings_overrides") or {}
+        text = json.dumps(current, indent=2) if current else "{}"
+        while True:
+            text, ok = QInputDialog.getMultiLineText(
+                self.v,
+                "Settings Overrides",
+                "Settings used instead of the global ones for this job only,\n"
+                'e.g. {"scan_end_percentage": 95.0}. Use {} for none.',
+                text,
+            )
+            if not ok:
+                return
+            try:
+                overrides = json.loads(text or "{}")
+                if not isinstance(overrides, dict):
+                    raise ValueError("Overrides must be a JSON object.")
+            except ValueError as e:
+                QMessageBox.warning(self.v, "Invalid Overrides", str(e))
+                continue
+            problems = validate_overrides(overrides)
+            if problems:
+                QMessageBox.warning(
+                    self.v, "Invalid Overrides", "\n".join(problems)
+                )
+                continue
+            break
+
+        if overrides:
+            job["settings_overrides"] = overrides
+        else:
+            job.pop("settings_overrides", None)
+        self.v.table.setItem(row, 2, self._sources_item(job))
+        self.save_queue()
+
     def _convert_enhanced_to_dialog_format(
         self, enhanced_layout: list[ManualLayoutItem]
     ) -> list[ManualLayoutItem]:
@@ -724,26 +858,31 @@ class JobQueueLogic:
             )
             return
 
-        source_struct_sig = self._layout_clipboard["structure_signature"]
         updated_count = 0
+        rejected: list[str] = []
+        strategy = self.v.config.get("layout_match_strategy", "id_then_position")
 
-        for target_index in selected_indices:
+        for target_index in sorted(selected_indices):
             target_job = self.jobs[target_index]
             target_track_info = self._get_track_info_for_job(target_job)
             if not target_track_info:
                 continue
+            target_name = Path(primary_path(target_job["sources"]["Source 1"])).name
 
-            target_struct_sig = (
-                self.layout_manager.signature_gen.generate_structure_signature(
-                    target_track_info
-                )
+            # Tracks match by id and/or type + position (layout_match_strategy)
+            mismatches = self.layout_manager.validate_against(
+                self._layout_clipboard["enhanced_layout"], target_track_info, strategy
             )
-
-            if self.layout_manager.signature_gen.structures_are_compatible(
-                source_struct_sig, target_struct_sig
-            ):
-                new_layout = self._replace_paths_in_layout(
-                    self._layout_clipboard["enhanced_layout"], target_job["sources"]
+            if not mismatches:
+                self.v.log_callback(f"[Queue] Pasting layout to {target_name}:")
+                new_layout = rebind_layout(
+                    self._replace_paths_in_layout(
+                        self._layout_clipboard["enhanced_layout"],
+                        primary_sources(target_job["sources"]),
+                    ),
+                    target_track_info,
+                    strategy,
+                    self.v.log_callback,
                 )
 
                 target_job_id = self.layout_manager.generate_job_id(
@@ -761,54 +900,107 @@ class JobQueueLogic:
                     ),
                 )
                 if save_ok:
+                    target_job.pop("stale_layout", None)
+                    target_job.pop("paste_mismatches", None)
                     self._update_row(target_index, target_job)
                     updated_count += 1
             else:
+                reasons = [m.describe() for m in mismatches]
+                target_job["paste_mismatches"] = reasons
+                self._update_row(target_index, target_job)
+                rejected.append(f"{target_name}:\n  " + "\n  ".join(reasons))
                 self.v.log_callback(
-                    f"Skipped pasting to {Path(target_job['sources']['Source 1']).name}: Incompatible track structure."
+                    f"[Queue] Skipped pasting to {target_name}; the layout doesn't "
+                    "fit its tracks:\n  " + "\n  ".join(reasons)
                 )
 
-        if updated_count > 0:
+        if rejected:
+            QMessageBox.warning(
+                self.v,
+                "Paste Incomplete" if updated_count else "Paste Failed",
+                f"Pasted layout to {updated_count} job(s). These jobs need to be "
+                "configured manually; the layout doesn't fit their tracks:\n\n"
+                + "\n\n".join(rejected),
+            )
+        elif updated_count > 0:
             QMessageBox.information(
                 self.v,
                 "Paste Successful",
                 f"Successfully pasted layout to {updated_count} job(s).",
             )
-        else:
-            QMessageBox.warning(
-                self.v,
-                "Paste Failed",
-                "Could not paste layout to any selected jobs due to incompatible track structures.",
-            )
 
     def _replace_paths_in_layout(
         self, layout_template: list[ManualLayoutItem], target_sources: dict[str, str]
     ) -> list[ManualLayoutItem]:
         """Creates a new layout with file paths updated for the target job."""
-        import copy
-
-        new_layout = []
-        for track_template in layout_template:
-            # Use deepcopy to properly copy nested dicts like style_patch and font_replacements
-            new_track = copy.deepcopy(track_template)
-            source_key = new_track.get("source")
-            if source_key in target_sources:
-                new_track["original_path"] = target_sources[source_key]
-
-            # Clear session-specific temp file path
-            new_track.pop("user_modified_path", None)
-
-            # Clear file-specific event indices inside filter_config
-            # forced_include/forced_exclude are event indices from the source file
-            # They must be recalculated for each target file (only style-based filter transfers)
-            filter_config = new_track.get("filter_config")
-            if filter_config:
-                filter_config.pop("kept_indices", None)
-                filter_config.pop("forced_include", None)
-                filter_config.pop("forced_exclude", None)
-
-            new_layout.append(new_track)
-        return new_layout
+        return repoint_layout(layout_template, target_sources)
+
+    def duplicate_job_with_sources(self, row: int) -> None:
+        """
+        Adds a copy of the job at ``row`` (layout, overrides) that runs on
+        other files, picked per source. The copy is "Configured" when the
+        layout's tracks exist in the new files, "Needs Review" otherwise.
+        """
+        job = self.jobs[row]
+        new_sources: dict[str, str] = {}
+        for key, value in job["sources"].items():
+            if not value:
+                continue
+            old_path = primary_path(value)
+            path, _ = QFileDialog.getOpenFileName(
+                self.v,
+                f"Select the new file for {key}",
+                str(Path(old_path).parent),
+                "Video Files (*.mkv *.mp4 *.m2ts *.ts *.avi *.mov);;All Files (*)",
+            )
+            if not path:
+                return
+            new_sources[key] = path
+
+        new_job_id = self.layout_manager.generate_job_id(new_sources)
+        if any(
+            self.layout_manager.generate_job_id(j["sources"]) == new_job_id
+            for j in self.jobs
+        ):
+            QMessageBox.warning(
+                self.v,
+                "Duplicate Job",
+                "A job with these source files is already in the queue.",
+            )
+            return
+
+        new_job: dict[str, Any] = {"sources": new_sources}
+        if job.get("settings_overrides"):
+            new_job["settings_overrides"] = dict(job["settings_overrides"])
+        track_info = self._get_track_info_for_job(new_job)
+        if not track_info:
+            return
+
+        problems = self.layout_manager.duplicate_layout(
+            self.layout_manager.generate_job_id(job["sources"]),
+            new_job_id,
+            new_sources,
+            track_info,
+            self.v.config.get("layout_match_strategy", "id_then_position"),
+        )
+        if problems is None:
+            QMessageBox.critical(
+                self.v,
+                "Duplicate Failed",
+                "Could not copy the job layout. Check log for details.",
+            )
+            return
+
+        self.jobs.insert(row + 1, new_job)
+        self.populate_table()
+        name = Path(new_sources["Source 1"]).name
+        if problems:
+            self.v.log_callback(
+                f"[Queue] Duplicated job for {name}; the layout needs review:\n  "
+                + "\n  ".join(problems)
+            )
+        else:
+            self.v.log_callback(f"[Queue] Duplicated job for {name}.")
 
     def remove_selected_jobs(self) -> None:
         """Removes selected jobs and deletes their layout files."""
@@ -831,7 +1023,9 @@ class JobQueueLogic:
         for job in self.jobs:
             # Check if status starts with "Configured" (might have ⚠️ suffix)
             if not job.get("status", "").startswith("Configured"):
-                unconfigured_names.append(Path(job["sources"]["Source 1"]).name)
+                unconfigured_names.append(
+                    Path(primary_path(job["sources"]["Source 1"])).name
+                )
                 continue
 
             # Load from disk to ensure we have the definitive version
@@ -846,7 +1040,9 @@ class JobQueueLogic:
                 job["chapter_source"] = layout_data.get("chapter_source", "Source 1")
                 final_jobs.append(job)
             else:
-                unconfigured_names.append(Path(job["sources"]["Source 1"]).name)
+                unconfigured_names.append(
+                    Path(primary_path(job["sources"]["Source 1"])).name
+                )
 
         if unconfigured_names:
             QMessageBox.warning(
@@ -57,7 +57,7 @@ class JobQueueDialog(QDialog):
     def dropEvent(self, event) -> None:
         if event.mimeData().hasUrls():
             paths = [url.toLocalFile() for url in event.mimeData().urls()]
-            add_dialog = AddJobDialog(self)
+            add_dialog = AddJobDialog(self, config=self.config)
             add_dialog.populate_sources_from_paths(paths)
             if add_dialog.exec():
                 new_jobs = add_dialog.get_discovered_jobs()
@@ -156,12 +156,15 @@ class JobQueueDialog(QDialog):
 
         menu = QMenu()
         config_action = menu.addAction("Configure...")
+        overrides_action = menu.addAction("Settings Overrides...")
         remove_action = menu.addAction("Remove from Queue")
         menu.addSeparator()
         copy_action = menu.addAction("Copy Layout")
         paste_action = menu.addAction("Paste Layout")
+        duplicate_action = menu.addAction("Duplicate with Other Sources...")
 
         config_action.setEnabled(len(selected_rows) == 1)
+        overrides_action.setEnabled(len(selected_rows) == 1)
 
         # Enable "Copy" if a single, configured job is selected
         # Note: Status may be "Configured ⚠️" with warning, so use startswith
@@ -169,6 +172,7 @@ class JobQueueDialog(QDialog):
         source_job = self._logic.jobs[source_job_index]
         is_configured = source_job.get("status", "").startswith("Configured")
         copy_action.setEnabled(len(selected_rows) == 1 and is_configured)
+        duplicate_action.setEnabled(len(selected_rows) == 1 and is_configured)
 
         # Enable "Paste" if the clipboard has content
         paste_action.setEnabled(self._logic._layout_clipboard is not None)
@@ -177,12 +181,16 @@ class JobQueueDialog(QDialog):
 
         if action == config_action:
             self._logic.configure_job_at_row(source_job_index)
+        elif action == overrides_action:
+            self._logic.edit_overrides_at_row(source_job_index)
         elif action == remove_action:
             self._logic.remove_selected_jobs()
         elif action == copy_action:
             self._logic.copy_layout(source_job_index)
         elif action == paste_action:
             self._logic.paste_layout()
+        elif action == duplicate_action:
+            self._logic.duplicate_job_with_sources(source_job_index)
 
     def get_final_jobs(self) -> list[dict]:
         return self._logic.get_final_jobs()
@@ -8,8 +8,10 @@ from typing import TYPE_CHECKING
 from PySide6.QtCore import QThreadPool, QTimer
 from PySide6.QtWidgets import QMessageBox
 
+from vsg_core.extraction.tool_versions import ToolVersions, tool_versions
 from vsg_core.job_discovery import discover_jobs
 from vsg_core.job_layouts import JobLayoutManager
+from vsg_core.models.source_input import primary_path
 from vsg_core.reporting import DebugOutputManager, ReportWriter
 from vsg_qt.job_queue_dialog import JobQueueDialog
 from vsg_qt.options_dialog import OptionsDialog
@@ -34,6 +36,8 @@ class MainController:
         self.report_writer: ReportWriter | None = None
         self.debug_manager: DebugOutputManager | None = None
         self._job_counter: int = 0
+        # External tool versions, probed once after the window is shown
+        self.tool_versions: ToolVersions | None = None
 
     def open_options_dialog(self) -> None:
         dialog = OptionsDialog(self.config, self.v)
@@ -41,6 +45,14 @@ class MainController:
             self.config.save()
             self.append_log("Settings saved.")
 
+    def log_tool_versions(self) -> None:
+        self.tool_versions = tool_versions()
+        for line in self.tool_versions.log_lines():
+            self.append_log(line)
+        problems = self.tool_versions.problems
+        if problems:
+            self.update_status(problems[0])
+
     def apply_config_to_ui(self) -> None:
         v = self.v
         v.ref_input.setText(self.config.get("last_ref_path", ""))
@@ -98,13 +110,37 @@ class MainController:
                 self.append_log("Queue closed with no jobs to run.")
                 self.v.status_label.setText("Ready")
                 # No jobs were run, so we can clean up now.
-                self.layout_manager.cleanup_all()
+                self._cleanup_layouts()
         else:
             # User cancelled or closed the dialog, so clean up.
+            self._cleanup_layouts()
+
+    def _cleanup_layouts(self, finished_jobs: list[dict] | None = None) -> None:
+        """Deletes layout files unless the queue is persisted across sessions.
+
+        With queue persistence on, only the jobs that merged successfully are
+        dropped from the saved queue (and their layouts deleted); everything
+        else stays for the next session.
+        """
+        if not self.config.get("persist_job_queue", True):
             self.layout_manager.cleanup_all()
+            return
+        if not finished_jobs:
+            return
+        done_ids = {
+            self.layout_manager.generate_job_id(job["sources"]) for job in finished_jobs
+        }
+        remaining = [
+            job
+            for job in self.layout_manager.load_queue()
+            if self.layout_manager.generate_job_id(job["sources"]) not in done_ids
+        ]
+        for job_id in done_ids:
+            self.layout_manager.delete_layout(job_id)
+        self.layout_manager.save_queue(remaining)
 
     def _run_configured_jobs(self, final_jobs: list[dict]) -> None:
-        source1_path_str = final_jobs[0]["sources"]["Source 1"]
+        source1_path_str = primary_path(final_jobs[0]["sources"]["Source 1"])
         output_dir = self.config.get("output_folder")
         is_batch = len(final_jobs) > 1
         if is_batch:
@@ -126,7 +162,11 @@ class MainController:
         }
 
         try:
-            initial_jobs = discover_jobs(sources)
+            initial_jobs = discover_jobs(
+                sources,
+                strategy=self.config.get("discovery_strategy", "exact"),
+                episode_regex=self.config.get("discovery_regex", ""),
+            )
         except (ValueError, FileNotFoundError) as e:
             QMessageBox.warning(self.v, "Job Discovery Error", str(e))
             return
@@ -154,7 +194,7 @@ class MainController:
         logs_folder = Path(self.config.get("logs_folder"))
 
         # Determine batch name from first job's Source 1
-        source1_path = Path(jobs[0]["sources"]["Source 1"])
+        source1_path = Path(primary_path(jobs[0]["sources"]["Source 1"]))
         if is_batch:
             batch_name = source1_path.parent.name
         else:
@@ -234,6 +274,7 @@ class MainController:
         successful_jobs = summary.get("successful", 0)
         jobs_with_warnings = summary.get("warnings", 0)
         failed_jobs = summary.get("failed", 0)
+        needs_review_jobs = summary.get("needs_review", 0)
         stepping_jobs = summary.get("stepping_jobs", [])
         stepping_disabled_jobs = summary.get("stepping_disabled_jobs", [])
 
@@ -242,6 +283,8 @@ class MainController:
         summary_message += f"  - Successful jobs: {successful_jobs}\n"
         summary_message += f"  - Jobs with warnings: {jobs_with_warnings}\n"
         summary_message += f"  - Failed jobs: {failed_jobs}\n"
+        if needs_review_jobs:
+            summary_message += f"  - Jobs needing review: {needs_review_jobs}\n"
         if report_path:
             summary_message += f"\n  Report: {report_path}\n"
 
@@ -257,11 +300,17 @@ class MainController:
             stepping_jobs=stepping_jobs,
             stepping_disabled_jobs=stepping_disabled_jobs,
             report_path=report_path,
+            needs_review=needs_review_jobs,
         )
         dialog.exec()
 
         # FIX: Cleanup is now called here, after all jobs are finished.
-        self.layout_manager.cleanup_all()
+        merged_jobs = [
+            r["job_data_for_batch_check"]
+            for r in all_results
+            if r.get("status") == "Merged" and r.get("job_data_for_batch_check")
+        ]
+        self._cleanup_layouts(merged_jobs)
 
     def _archive_logs_for_batch(self, output_dir: Path) -> None:
         self.append_log(f"--- Archiving logs in {output_dir} ---")
@@ -287,4 +336,4 @@ class MainController:
             self.append_log("[SHUTDOWN] Cancelling background tasks...")
 
         self.save_ui_to_config()
-        self.layout_manager.cleanup_all()
+        self._cleanup_layouts()
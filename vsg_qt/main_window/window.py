@@ -1,7 +1,7 @@
 # vsg_qt/main_window/window.py
 from __future__ import annotations
 
-from PySide6.QtCore import Qt
+from PySide6.QtCore import Qt, QTimer
 from PySide6.QtWidgets import (
     QCheckBox,
     QGroupBox,
@@ -34,6 +34,7 @@ class MainWindow(QMainWindow):
 
         self._build_ui()
         self.controller.apply_config_to_ui()
+        QTimer.singleShot(0, self.controller.log_tool_versions)
 
     def _build_ui(self) -> None:
         # Quick Analysis Inputs
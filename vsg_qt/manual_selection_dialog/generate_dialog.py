@@ -0,0 +1,79 @@
+# vsg_qt/manual_selection_dialog/generate_dialog.py
+from __future__ import annotations
+
+from PySide6.QtWidgets import (
+    QCheckBox,
+    QComboBox,
+    QDialog,
+    QDialogButtonBox,
+    QFormLayout,
+    QHBoxLayout,
+    QLabel,
+    QLineEdit,
+    QVBoxLayout,
+)
+
+from vsg_core.job_layouts.auto_layout import AutoLayoutPolicy
+
+
+class GenerateLayoutDialog(QDialog):
+    """Asks for the auto_layout policy used by 'Generate Layout'."""
+
+    def __init__(
+        self,
+        sources: list[str],
+        languages: str = "",
+        audio: bool = True,
+        subtitles: bool = True,
+        parent=None,
+    ):
+        super().__init__(parent)
+        self.setWindowTitle("Generate Layout")
+
+        self.source_combo = QComboBox()
+        self.source_combo.addItems(sources)
+        self.languages_edit = QLineEdit(languages)
+        self.languages_edit.setPlaceholderText("e.g. jpn, eng")
+        self.audio_check = QCheckBox("Audio")
+        self.audio_check.setChecked(audio)
+        self.subs_check = QCheckBox("Subtitles")
+        self.subs_check.setChecked(subtitles)
+
+        types_row = QHBoxLayout()
+        types_row.addWidget(self.audio_check)
+        types_row.addWidget(self.subs_check)
+        types_row.addStretch()
+
+        form = QFormLayout()
+        form.addRow("Keep all tracks of:", self.source_combo)
+        form.addRow("Add from the other sources:", types_row)
+        form.addRow("In languages:", self.languages_edit)
+
+        note = QLabel(
+            "Replaces the final output list (Ctrl+Z to undo). Video always "
+            "comes from Source 1."
+        )
+        note.setWordWrap(True)
+
+        buttons = QDialogButtonBox(
+            QDialogButtonBox.StandardButton.Ok | QDialogButtonBox.StandardButton.Cancel
+        )
+        buttons.accepted.connect(self.accept)
+        buttons.rejected.connect(self.reject)
+
+        root = QVBoxLayout(self)
+        root.addLayout(form)
+        root.addWidget(note)
+        root.addWidget(buttons)
+
+    def policy(self) -> AutoLayoutPolicy:
+        types = []
+        if self.audio_check.isChecked():
+            types.append("audio")
+        if self.subs_check.isChecked():
+            types.append("subtitles")
+        return AutoLayoutPolicy(
+            order_source=self.source_combo.currentText(),
+            languages=AutoLayoutPolicy.parse_languages(self.languages_edit.text()),
+            track_types=tuple(types),
+        )
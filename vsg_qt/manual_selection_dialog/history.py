@@ -0,0 +1,77 @@
+# vsg_qt/manual_selection_dialog/history.py
+"""
+Undo/redo history for the manual selection's final track list.
+
+The history is a bounded list of layout snapshots (the same dicts
+``ManualLogic.build_layout_from_widgets`` produces) with a cursor on the
+current one. A snapshot is committed after every change to the list; undo
+and redo move the cursor and hand back the snapshot to rebuild the list
+from. Committing after an undo drops the states that could have been
+redone, and the oldest snapshot is discarded once ``limit`` undo steps are
+stored.
+
+No Qt in here, so the list widgets stay the only place that knows how a
+snapshot is turned back into rows.
+"""
+
+from __future__ import annotations
+
+import copy
+from typing import Any
+
+HISTORY_LIMIT = 50
+
+Snapshot = list[dict[str, Any]]
+
+
+class LayoutHistory:
+    """Bounded undo/redo stack of final-list snapshots."""
+
+    def __init__(self, limit: int = HISTORY_LIMIT):
+        self.limit = max(1, limit)
+        self._states: list[Snapshot] = []
+        self._index = -1
+
+    @property
+    def can_undo(self) -> bool:
+        return self._index > 0
+
+    @property
+    def can_redo(self) -> bool:
+        return 0 <= self._index < len(self._states) - 1
+
+    def reset(self, state: Snapshot) -> None:
+        """Forget all history; ``state`` becomes the only (current) state."""
+        self._states = [copy.deepcopy(state)]
+        self._index = 0
+
+    def clear(self) -> None:
+        self._states = []
+        self._index = -1
+
+    def commit(self, state: Snapshot) -> bool:
+        """Record ``state`` as the current one. False when nothing changed."""
+        if self._index >= 0 and self._states[self._index] == state:
+            return False
+        del self._states[self._index + 1 :]
+        self._states.append(copy.deepcopy(state))
+        # limit undo steps = limit + 1 states
+        overflow = len(self._states) - (self.limit + 1)
+        if overflow > 0:
+            del self._states[:overflow]
+        self._index = len(self._states) - 1
+        return True
+
+    def undo(self) -> Snapshot | None:
+        """The previous state, or None when there is nothing to undo."""
+        if not self.can_undo:
+            return None
+        self._index -= 1
+        return copy.deepcopy(self._states[self._index])
+
+    def redo(self) -> Snapshot | None:
+        """The next state, or None when there is nothing to redo."""
+        if not self.can_redo:
+            return None
+        self._index += 1
+        return copy.deepcopy(self._states[self._index])
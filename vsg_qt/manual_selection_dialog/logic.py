@@ -3,9 +3,15 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
+from vsg_core.job_layouts.auto_layout import LayoutRuleSet, auto_layout
+from vsg_core.job_layouts.duplicates import find_duplicate_tracks
+from vsg_core.job_layouts.validation import find_layout_track
 from vsg_core.models.context_types import ManualLayoutItem
 
+from .history import LayoutHistory
+
 if TYPE_CHECKING:
+    from vsg_core.job_layouts.auto_layout import AutoLayoutPolicy
     from vsg_qt.track_widget.ui import TrackWidget
 
     from .ui import ManualSelectionDialog
@@ -16,6 +22,9 @@ class ManualLogic:
 
     def __init__(self, view: ManualSelectionDialog):
         self.v = view
+        self.history = LayoutHistory()
+        # Set while the list is rebuilt, so the rebuild isn't recorded
+        self._restoring = False
 
     def is_blocked_video(self, track_data: dict) -> bool:
         """Video is only allowed from Source 1."""
@@ -28,18 +37,9 @@ class ManualLogic:
         if not layout:
             return
 
-        pools = {}
-        counters = {}
-        for src_key, track_list in self.v.track_info.items():
-            for t in track_list:
-                key = (src_key, t["type"], counters.get((src_key, t["type"]), 0))
-                pools[key] = t
-                counters[(src_key, t["type"])] = (
-                    counters.get((src_key, t["type"]), 0) + 1
-                )
-
+        strategy = self.v.config.get("layout_match_strategy", "id_then_position")
         realized_layout = []
-        counters.clear()
+        counters = {}
         for prev_item in layout:
             src, ttype = prev_item.get("source"), prev_item.get("type")
 
@@ -63,17 +63,137 @@ class ManualLogic:
             idx = counters.get((src, ttype), 0)
             counters[(src, ttype)] = idx + 1
 
-            match = pools.get((src, ttype, idx))
+            # By id and/or by n-th track of the type (layout_match_strategy)
+            item = {**prev_item, "position_in_source_type": idx}
+            match, matched_by = find_layout_track(
+                item, self.v.track_info.get(src, []), strategy
+            )
             if match:
+                if match.get("id") != prev_item.get("id"):
+                    self.v.log_callback(
+                        f"[Layout] {src} {ttype} track {prev_item.get('id')} -> "
+                        f"track {match.get('id')} (by {matched_by})"
+                    )
                 new_item = match.copy()
                 new_item.update(prev_item)
+                new_item["id"] = match["id"]
                 realized_layout.append(new_item)
+            else:
+                self.v.log_callback(
+                    f"[Layout] {src} {ttype} track {prev_item.get('id')}: no match "
+                    f"({strategy}), left out"
+                )
 
-        for track_data in realized_layout:
-            # CRITICAL FIX: Filter out video tracks from secondary sources
-            if self.is_blocked_video(track_data):
-                continue
-            self.v.final_list.add_track_widget(track_data, preset=True)
+        self._restoring = True
+        try:
+            for track_data in realized_layout:
+                # CRITICAL FIX: Filter out video tracks from secondary sources
+                if self.is_blocked_video(track_data):
+                    continue
+                self.v.final_list.add_track_widget(track_data, preset=True)
+        finally:
+            self._restoring = False
+
+    def apply_auto_layout(self, policy: AutoLayoutPolicy) -> int:
+        """
+        Replaces the final list with the generated layout (one undo step).
+        Returns the number of tracks added.
+        """
+        return self._replace_final_list(auto_layout(self.v.track_info, policy))
+
+    def apply_layout_rules(self, rules: LayoutRuleSet) -> int:
+        """Replaces the final list with the resolved rules (one undo step)."""
+        return self._replace_final_list(
+            rules.resolve(self.v.track_info, self.v.log_callback)
+        )
+
+    def layout_rules(self) -> LayoutRuleSet:
+        """The ``layout_rules`` setting (empty if unreadable)."""
+        try:
+            return LayoutRuleSet.from_list(self.v.config.get("layout_rules", []))
+        except (KeyError, TypeError, ValueError) as e:
+            self.v.log_callback(f"[Layout Rules] Ignoring invalid rules: {e}")
+            return LayoutRuleSet()
+
+    def _replace_final_list(self, tracks: list[dict]) -> int:
+        tracks = [t for t in tracks if not self.is_blocked_video(t)]
+        # Edits made inside a track's own dialogs are only picked up here
+        self.record_change()
+        fl = self.v.final_list
+        self._restoring = True
+        try:
+            fl.clear()
+            for track_data in tracks:
+                # Plain tracks start as if double-clicked; rule items carry flags
+                fl.add_track_widget(
+                    track_data, preset=("is_forced_display" in track_data)
+                )
+        finally:
+            self._restoring = False
+        self.record_change()
+        return len(tracks)
+
+    # --- Undo / redo ---
+
+    def _final_widgets(self) -> list[TrackWidget]:
+        fl = self.v.final_list
+        return [fl.itemWidget(fl.item(i)) for i in range(fl.count())]
+
+    def snapshot(self) -> list[ManualLayoutItem]:
+        return self.build_layout_from_widgets(self._final_widgets())
+
+    def reset_history(self) -> None:
+        """Makes the current final list the bottom of the undo history."""
+        self.history.reset(self.snapshot())
+        self.check_duplicates()
+
+    def record_change(self) -> None:
+        """Commits the final list to the undo history after an edit."""
+        if not self._restoring:
+            self.history.commit(self.snapshot())
+            self.check_duplicates()
+
+    def undo(self) -> None:
+        # Edits made inside a track's own dialogs are only picked up here
+        self.record_change()
+        state = self.history.undo()
+        if state is not None:
+            self._restore(state)
+
+    def redo(self) -> None:
+        self.record_change()
+        state = self.history.redo()
+        if state is not None:
+            self._restore(state)
+
+    def _restore(self, layout: list[ManualLayoutItem]) -> None:
+        """Rebuilds the final list from a snapshot, keeping the selected row."""
+        fl = self.v.final_list
+        row = fl.currentRow()
+        self._restoring = True
+        try:
+            fl.clear()
+            for track_data in layout:
+                fl.add_track_widget(track_data, preset=True)
+        finally:
+            self._restoring = False
+        if fl.count():
+            fl.setCurrentRow(min(max(row, 0), fl.count() - 1))
+        self.check_duplicates()
+
+    def check_duplicates(self) -> None:
+        """Warns (never removes) when the list has the same track twice."""
+        duplicates = find_duplicate_tracks(self.snapshot(), self.v.track_info)
+        label = self.v.duplicate_label
+        if not duplicates:
+            label.setVisible(False)
+            return
+        label.setText(
+            f"⚠ {len(duplicates)} pair(s) of tracks look identical across "
+            "sources (hover for details)."
+        )
+        label.setToolTip("\n".join(d.describe() for d in duplicates))
+        label.setVisible(True)
 
     def get_final_layout_and_attachments(self) -> tuple[list[ManualLayoutItem], list[str]]:
         """Builds the layout from widgets and gets selected attachment sources."""
@@ -10,7 +10,7 @@ from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
 from PySide6.QtCore import Qt
-from PySide6.QtGui import QStandardItem, QStandardItemModel
+from PySide6.QtGui import QKeySequence, QShortcut, QStandardItem, QStandardItemModel
 from PySide6.QtWidgets import (
     QCheckBox,
     QComboBox,
@@ -19,6 +19,7 @@ from PySide6.QtWidgets import (
     QFileDialog,
     QGroupBox,
     QHBoxLayout,
+    QInputDialog,
     QLabel,
     QMenu,
     QMessageBox,
@@ -32,11 +33,13 @@ from vsg_core.chapters.compat import is_donor_compatible, quick_probe
 from vsg_core.extraction.attachments import extract_attachments
 from vsg_core.extraction.tracks import extract_tracks
 from vsg_core.io.runner import CommandRunner
+from vsg_core.job_layouts.auto_layout import LayoutRuleSet
 from vsg_core.models.context_types import ManualLayoutItem
 from vsg_core.subtitles.data import SubtitleData
 from vsg_core.subtitles.style_engine import StyleEngine
 from vsg_qt.subtitle_editor import SubtitleEditorWindow
 
+from .generate_dialog import GenerateLayoutDialog
 from .logic import ManualLogic
 from .widgets import FinalList, SourceList
 
@@ -102,6 +105,13 @@ class ManualSelectionDialog(QDialog):
             self.info_label.setVisible(True)
             # FIX: Call the prepopulate method on the logic instance
             self._logic.prepopulate_from_layout(previous_layout)
+        elif (rules := self._logic.layout_rules()).rules:
+            count = self._logic.apply_layout_rules(rules)
+            self.info_label.setText(
+                f"✅ Pre-populated {count} track(s) from the layout rules."
+            )
+            self.info_label.setVisible(True)
+        self._logic.reset_history()
 
     def _build_ui(self, previous_attachment_sources: list[str] | None = None) -> None:
         root = QVBoxLayout(self)
@@ -168,6 +178,26 @@ class ManualSelectionDialog(QDialog):
         final_group = QGroupBox("Final Output (Drag to reorder)")
         final_layout = QVBoxLayout(final_group)
         final_layout.addWidget(self.final_list)
+        self.duplicate_label = QLabel()
+        self.duplicate_label.setVisible(False)
+        self.duplicate_label.setWordWrap(True)
+        self.duplicate_label.setStyleSheet("color: #E0A800; font-weight: bold;")
+        final_layout.addWidget(self.duplicate_label)
+        self.generate_layout_btn = QPushButton("Generate Layout...")
+        self.generate_layout_btn.setToolTip(
+            "Fill the list with one source's tracks in original order plus "
+            "audio/subtitles in chosen languages from the other sources"
+        )
+        self.layout_rules_btn = QPushButton("Layout Rules...")
+        self.layout_rules_btn.setToolTip(
+            "Edit the rules that fill the list for every job opened without a "
+            "layout, and apply them here"
+        )
+        generate_row = QHBoxLayout()
+        generate_row.addStretch()
+        generate_row.addWidget(self.layout_rules_btn)
+        generate_row.addWidget(self.generate_layout_btn)
+        final_layout.addLayout(generate_row)
 
         self.attachment_group = QGroupBox("Attachments")
         attachment_layout = QHBoxLayout(self.attachment_group)
@@ -208,6 +238,13 @@ class ManualSelectionDialog(QDialog):
             lw.itemDoubleClicked.connect(self._on_double_clicked_source)
         self.external_list.itemDoubleClicked.connect(self._on_double_clicked_source)
         self.add_external_btn.clicked.connect(self._add_external_subtitles)
+        self.generate_layout_btn.clicked.connect(self._generate_layout)
+        self.layout_rules_btn.clicked.connect(self._edit_layout_rules)
+        # Explicit keys rather than StandardKey.Redo, which is Ctrl+Y on
+        # Windows and would clash with the extra Ctrl+Y binding
+        QShortcut(QKeySequence("Ctrl+Z"), self, self._logic.undo)
+        QShortcut(QKeySequence("Ctrl+Shift+Z"), self, self._logic.redo)
+        QShortcut(QKeySequence("Ctrl+Y"), self, self._logic.redo)
 
     def _populate_sources(self) -> None:
         for src_key, widget in self.source_lists.items():
@@ -247,6 +284,7 @@ class ManualSelectionDialog(QDialog):
         self.manual_layout, self.attachment_sources = (
             self._logic.get_final_layout_and_attachments()
         )
+        self._logic.history.clear()
         # Resolve chapter source from combo userData
         combo = getattr(self, "chapter_source_combo", None)
         if combo is not None:
@@ -255,6 +293,55 @@ class ManualSelectionDialog(QDialog):
                 self.chapter_source = data
         super().accept()
 
+    def _generate_layout(self) -> None:
+        dialog = GenerateLayoutDialog(
+            self.available_sources,
+            languages=self.config.get("auto_layout_languages", ""),
+            audio=self.config.get("auto_layout_audio", True),
+            subtitles=self.config.get("auto_layout_subtitles", True),
+            parent=self,
+        )
+        if dialog.exec() != QDialog.DialogCode.Accepted:
+            return
+        policy = dialog.policy()
+        self.config.set("auto_layout_languages", ", ".join(policy.languages))
+        self.config.set("auto_layout_audio", "audio" in policy.track_types)
+        self.config.set("auto_layout_subtitles", "subtitles" in policy.track_types)
+        self.config.save()
+
+        count = self._logic.apply_auto_layout(policy)
+        self.info_label.setText(f"Generated {count} track(s): {policy.describe()}.")
+        self.info_label.setVisible(True)
+
+    def _edit_layout_rules(self) -> None:
+        text = self._logic.layout_rules().describe()
+        while True:
+            text, ok = QInputDialog.getMultiLineText(
+                self,
+                "Layout Rules",
+                "One rule per line, applied in order to every job opened "
+                "without a layout:\n"
+                "  TYPE [LANGS|any] [forced|full] [all] [from SOURCE, ...]\n"
+                "e.g. 'video any all from Source 1', 'audio jpn,eng', "
+                "'subtitles eng full', 'subtitles eng forced'.",
+                text,
+            )
+            if not ok:
+                return
+            try:
+                rules = LayoutRuleSet.parse(text)
+            except ValueError as e:
+                QMessageBox.warning(self, "Invalid Layout Rules", str(e))
+                continue
+            break
+
+        self.config.set("layout_rules", rules.to_list())
+        self.config.save()
+        if rules.rules:
+            count = self._logic.apply_layout_rules(rules)
+            self.info_label.setText(f"Applied the layout rules: {count} track(s).")
+            self.info_label.setVisible(True)
+
     def _build_chapter_source_combo(self) -> QComboBox:
         """
         Build the 'Chapters from:' dropdown.
@@ -358,6 +445,7 @@ class ManualSelectionDialog(QDialog):
             has_settings = bool(
                 current.get("correlation_source_track") is not None
                 or current.get("use_source_separation")
+                or current.get("manual_delay_ms") is not None
             )
 
         # Configure correlation settings action
@@ -460,7 +548,7 @@ class ManualSelectionDialog(QDialog):
         if event.key() == Qt.Key.Key_Delete:
             item = self.final_list.currentItem()
             if item:
-                self.final_list.takeItem(self.final_list.row(item))
+                self.final_list.remove_item(item)
                 event.accept()
                 return
         super().keyPressEvent(event)
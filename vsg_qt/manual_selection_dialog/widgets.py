@@ -96,6 +96,8 @@ class FinalList(QListWidget):
                     event.accept()
             return
         super().dropEvent(event)
+        # Drag-reordering within the list
+        self.dialog._logic.record_change()
 
     def add_track_widget(self, track_data: dict, preset=False) -> None:
         item = QListWidgetItem()
@@ -147,6 +149,7 @@ class FinalList(QListWidget):
         self.setItemWidget(item, widget)
         self.setCurrentItem(item)
         self.scrollToItem(item)
+        self.dialog._logic.record_change()
 
     def _show_context_menu(self, pos: QPoint) -> None:
         item = self.itemAt(pos)
@@ -209,7 +212,11 @@ class FinalList(QListWidget):
             widget.cb_forced.setChecked(not widget.cb_forced.isChecked())
             self._enforce_single_forced()
         elif act == act_del:
-            self.takeItem(self.row(item))
+            self.remove_item(item)
+
+    def remove_item(self, item: QListWidgetItem) -> None:
+        self.takeItem(self.row(item))
+        self.dialog._logic.record_change()
 
     def _move_by(self, delta: int) -> None:
         item = self.currentItem()
@@ -221,6 +228,7 @@ class FinalList(QListWidget):
             it = self.takeItem(row)
             self.insertItem(new_row, it)
             self.setCurrentItem(it)
+            self.dialog._logic.record_change()
 
     def _widgets_of_type(self, ttype: str):
         return [
@@ -232,15 +240,20 @@ class FinalList(QListWidget):
     def _enforce_single_default(self, sender_widget, prefer=False) -> None:
         if prefer:
             sender_widget.cb_default.setChecked(True)
+        # A hand-picked default beats the preferred default language
+        for w in self._widgets_of_type(sender_widget.track_type):
+            w.track_data["manual_default"] = True
         self.dialog._logic.normalize_single_default_for_type(
             self._widgets_of_type(sender_widget.track_type),
             sender_widget.track_type,
             force_default_if_none=False,
             prefer_widget=sender_widget,
         )
+        self.dialog._logic.record_change()
 
     def _enforce_single_forced(self) -> None:
         """Helper method to call the normalization logic for forced subtitles."""
         self.dialog._logic.normalize_forced_subtitles(
             self._widgets_of_type("subtitles")
         )
+        self.dialog._logic.record_change()
@@ -129,6 +129,9 @@ class OptionsLogic:
                 # Fallback to text matching for combos without custom data
                 widget.setCurrentText(str(value))
         elif isinstance(widget, QLineEdit):
+            # Lists (e.g. regex patterns) are edited ";"-separated
+            if isinstance(value, list):
+                value = "; ".join(str(v) for v in value)
             widget.setText(str(value))
         elif isinstance(widget, QWidget):
             _layout = widget.layout()
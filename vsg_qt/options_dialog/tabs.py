@@ -86,6 +86,23 @@ class StorageTab(QWidget):
         self.widgets["temp_root"].setToolTip(
             "The root directory for storing temporary files during processing (e.g., extracted tracks, logs)."
         )
+        self.widgets["keep_temp_on_failure"] = QCheckBox(
+            "Keep the work directory of failed jobs"
+        )
+        self.widgets["keep_temp_on_failure"].setToolTip(
+            "When a job fails, leave its work directory in place (extracted/,\n"
+            "audio/, indexes/, logs/) and report its path in the log, so the\n"
+            "intermediate files can be inspected. Successful jobs always clean up."
+        )
+        self.widgets["temp_space_margin_pct"] = QSpinBox()
+        self.widgets["temp_space_margin_pct"].setRange(0, 500)
+        self.widgets["temp_space_margin_pct"].setSuffix(" %")
+        self.widgets["temp_space_margin_pct"].setToolTip(
+            "Before a batch starts, the temp files it needs are estimated from\n"
+            "the selected tracks (for the jobs that run at once) and checked\n"
+            "against the free space of the temp folder. This margin is added\n"
+            "for re-encoded audio, indexes and other work files."
+        )
         self.widgets["logs_folder"] = _dir_input()
         self.widgets["logs_folder"].setToolTip(
             "Directory for batch report files. Reports are saved after each job completes for persistent tracking."
@@ -94,15 +111,22 @@ class StorageTab(QWidget):
         self.widgets["videodiff_path"].setToolTip(
             "Optional. The full path to the 'videodiff' executable if it's not in your system's PATH."
         )
+        self.widgets["ocr_tesseract_path"] = _file_input()
+        self.widgets["ocr_tesseract_path"].setToolTip(
+            "The 'tesseract' executable used by the Tesseract OCR engine. A bare name is looked up in your system's PATH. OCR is skipped with a log note if it cannot be found."
+        )
         self.widgets["ocr_custom_wordlist_path"] = _file_input()
         self.widgets["ocr_custom_wordlist_path"].setToolTip(
             "Path to custom wordlist file for OCR. Contains words to not flag as unknown (anime names, romaji, etc.). One word per line."
         )
         f.addRow("Output Directory:", self.widgets["output_folder"])
         f.addRow("Temporary Directory:", self.widgets["temp_root"])
+        f.addRow("", self.widgets["keep_temp_on_failure"])
+        f.addRow("Temp Space Margin:", self.widgets["temp_space_margin_pct"])
         f.addRow("Reports Directory:", self.widgets["logs_folder"])
         f.addRow("VideoDiff Path (optional):", self.widgets["videodiff_path"])
         f.addRow("OCR Custom Wordlist:", self.widgets["ocr_custom_wordlist_path"])
+        f.addRow("Tesseract Path:", self.widgets["ocr_tesseract_path"])
         main_layout.addWidget(paths_group)
 
         # Config Maintenance section
@@ -164,6 +188,9 @@ class OCRTab(QWidget):
         self.widgets["ocr_engine_vobsub"].addItem(
             "PaddleOCR-VL 1.5 — Legacy (llama.cpp)", "paddleocr-vl"
         )
+        self.widgets["ocr_engine_vobsub"].addItem(
+            "Tesseract (CPU, external binary)", "tesseract"
+        )
         self.widgets["ocr_engine_vobsub"].setToolTip(
             "Engine for VobSub (DVD) subtitle tracks.\n"
             "• Native: official runtime; fixes ellipsis/punctuation misreads "
@@ -181,6 +208,9 @@ class OCRTab(QWidget):
         self.widgets["ocr_engine_pgs"].addItem(
             "PaddleOCR-VL 1.5 — Legacy (llama.cpp)", "paddleocr-vl"
         )
+        self.widgets["ocr_engine_pgs"].addItem(
+            "Tesseract (CPU, external binary)", "tesseract"
+        )
         self.widgets["ocr_engine_pgs"].setToolTip(
             "Engine for PGS (Blu-ray) subtitle tracks.\n"
             "• Native: official runtime; output verified identical to Legacy "
@@ -538,6 +568,45 @@ class AnalysisTab(QWidget):
             "Model Directory:", self.widgets["source_separation_model_dir"]
         )
         prep_layout.addRow("", self.manage_models_btn)
+        downmix = QComboBox()
+        downmix.addItem("Mono sum (all channels)", "mono_sum")
+        downmix.addItem("First channel only", "none")
+        downmix.addItem("Front left only", "left_only")
+        downmix.addItem("Centre only (dialogue on 5.1)", "center_only")
+        downmix.setToolTip(
+            "How multichannel audio is reduced to mono before correlation.\n\n"
+            "Summing channels can partly cancel out when they differ in phase.\n"
+            "Centre only keeps the dialogue channel of 5.1/7.1 tracks.\n"
+            "Streams without the chosen channel fall back to the mono sum;\n"
+            "the choice is logged per stream."
+        )
+        self.widgets["analysis_downmix_mode"] = downmix
+        prep_layout.addRow("Channel Downmix:", downmix)
+        self.widgets["normalize_to_mkv"] = QCheckBox(
+            "Remux non-MKV sources to MKV first"
+        )
+        self.widgets["normalize_to_mkv"].setToolTip(
+            "Before analysis, stream-copy every source that isn't Matroska\n"
+            "(mp4, ts, m2ts, mov, ...) into an MKV in the job's work folder with\n"
+            "ffmpeg, and run the whole job on that file.\n\n"
+            "Needed for containers mkvmerge can't read. All streams, chapters\n"
+            "and their timing are kept; data streams are dropped. A codec that\n"
+            "can't be stored in MKV without re-encoding fails the job."
+        )
+        prep_layout.addRow(self.widgets["normalize_to_mkv"])
+        self.widgets["analysis_audio_cache"] = QCheckBox(
+            "Reuse decoded audio within a job"
+        )
+        self.widgets["analysis_audio_cache"].setToolTip(
+            "Store each decoded audio stream in the job's work folder and reuse\n"
+            "it instead of decoding again (Source 1's track for every other\n"
+            "source, multi-track consensus, stepping QA). Analysis only: audio\n"
+            "re-encoded for the output is read from the extracted track.\n\n"
+            "Needs about 11 MB of temp space per minute of audio; removed with\n"
+            "the work folder when the job finishes.\n\n"
+            "Default: Off"
+        )
+        prep_layout.addRow(self.widgets["analysis_audio_cache"])
         prep_layout.addRow("Audio Filtering:", self.widgets["filtering_method"])
         prep_layout.addRow(self.cutoff_container)
         main_layout.addWidget(prep_group)
@@ -624,6 +693,91 @@ class AnalysisTab(QWidget):
             "Higher values = also skip quiet passages.\n\n"
             "Default: -60 dB"
         )
+        self.widgets["avoid_silence"] = QCheckBox(
+            "Nudge windows off silent regions (silence-aware placement)"
+        )
+        self.widgets["avoid_silence"].setToolTip(
+            "Before correlating, moves each window that lands on a quiet region\n"
+            "(gaps, black frames without audio) to the nearest louder position\n"
+            "within half a hop, instead of wasting it.\n\n"
+            "If the whole scan range is quiet, uniform placement is used."
+        )
+        self.widgets["analysis_exclude_chapters"] = QCheckBox(
+            "Skip intro/outro chapters of Source 1"
+        )
+        self.widgets["analysis_exclude_chapters"].setToolTip(
+            "Keeps windows off Source 1 chapters whose names match the patterns\n"
+            "below (OP/ED, credits), which often differ between releases.\n\n"
+            "Without matching chapters the scan range is used as configured."
+        )
+        self.widgets["analysis_exclude_chapter_patterns"] = QLineEdit()
+        self.widgets["analysis_exclude_chapter_patterns"].setToolTip(
+            "Regular expressions, separated by ';', searched in each chapter\n"
+            "name (case-insensitive). A chapter matching any of them is skipped.\n\n"
+            "Default: ^(intro|opening|op\\d*)\\b; ^(outro|ending|ed\\d*)\\b; "
+            "\\bcredits\\b"
+        )
+        self.widgets["chunk_strategy"] = QComboBox()
+        self.widgets["chunk_strategy"].addItem("Uniform (whole range)", "uniform")
+        self.widgets["chunk_strategy"].addItem("Endpoints (start + end)", "endpoints")
+        self.widgets["chunk_strategy"].setToolTip(
+            "Where correlation windows go inside the scan range:\n\n"
+            "• Uniform (Default): a window every hop across the whole range\n"
+            "• Endpoints: only a few non-overlapping windows at the start and\n"
+            "  at the end of the range. Much faster, and the widest spread\n"
+            "  for estimating a linear drift — but stepping in the middle\n"
+            "  of the file is not seen.\n\n"
+            "The scan range and silence avoidance apply either way."
+        )
+        self.widgets["endpoint_chunks_start"] = QSpinBox()
+        self.widgets["endpoint_chunks_start"].setRange(0, 50)
+        self.widgets["endpoint_chunks_start"].setValue(5)
+        self.widgets["endpoint_chunks_start"].setToolTip(
+            "Endpoints placement: windows at the start of the scan range."
+        )
+        self.widgets["endpoint_chunks_end"] = QSpinBox()
+        self.widgets["endpoint_chunks_end"].setRange(0, 50)
+        self.widgets["endpoint_chunks_end"].setValue(5)
+        self.widgets["endpoint_chunks_end"].setToolTip(
+            "Endpoints placement: windows at the end of the scan range."
+        )
+        self.widgets["min_chunk_spacing_s"] = QDoubleSpinBox()
+        self.widgets["min_chunk_spacing_s"].setRange(0.0, 600.0)
+        self.widgets["min_chunk_spacing_s"].setDecimals(1)
+        self.widgets["min_chunk_spacing_s"].setSuffix(" s")
+        self.widgets["min_chunk_spacing_s"].setSpecialValueText("Off (every hop)")
+        self.widgets["min_chunk_spacing_s"].setToolTip(
+            "Minimum distance between the centres of two correlation windows.\n\n"
+            "With long windows on a short file, overlapping windows re-measure\n"
+            "the same audio and weigh it more than the rest. With a minimum\n"
+            "spacing only every n-th hop position is used; when the scan range\n"
+            "is too short for the requested windows, fewer are used and the\n"
+            "reduction is logged.\n\n"
+            "Default: Off"
+        )
+        self.widgets["windowed_decode"] = QCheckBox(
+            "Decode only the windows (seek per window, low memory)"
+        )
+        self.widgets["windowed_decode"].setToolTip(
+            "Instead of decoding both tracks completely, seeks to each window\n"
+            "and decodes just the window plus a 0.5 s guard on each side.\n"
+            "Cuts memory and time when the hop is much larger than the window\n"
+            "(sparse layouts on long files).\n\n"
+            "Ignored when windows overlap, or with silence-aware placement,\n"
+            "source separation or DTW (they need the whole track).\n"
+            "Sample-accurate when the container's packet timestamps are exact\n"
+            "(e.g. AC3/E-AC3/FLAC/Opus at 48 kHz); otherwise each window can be\n"
+            "off by up to the timestamp precision (0.5 ms in Matroska)."
+        )
+        self.widgets["min_chunk_energy_db"] = QDoubleSpinBox()
+        self.widgets["min_chunk_energy_db"].setRange(-120.0, 0.0)
+        self.widgets["min_chunk_energy_db"].setDecimals(1)
+        self.widgets["min_chunk_energy_db"].setSuffix(" dB")
+        self.widgets["min_chunk_energy_db"].setToolTip(
+            "Minimum window energy (quieter of the two sources) for\n"
+            "silence-aware placement.\n\n"
+            "Default: -45 dB"
+        )
         self.widgets["dense_outlier_threshold_ms"] = QDoubleSpinBox()
         self.widgets["dense_outlier_threshold_ms"].setRange(5.0, 500.0)
         self.widgets["dense_outlier_threshold_ms"].setDecimals(1)
@@ -643,8 +797,51 @@ class AnalysisTab(QWidget):
             "Windows below this threshold are rejected and excluded from delay selection.\n"
             "Higher values = stricter, fewer accepted windows.\n"
             "Lower values = more permissive, may include noisy results.\n\n"
+            "What the percentage means depends on the confidence metric below.\n\n"
             "Default: 10%"
         )
+        metric = QComboBox()
+        metric.addItem("Method default", "native")
+        metric.addItem("Normalized peak height", "normalized_peak")
+        metric.addItem("Peak-to-sidelobe ratio (PSR)", "peak_to_sidelobe")
+        metric.addItem("Peak-to-mean ratio", "peak_to_mean")
+        metric.setToolTip(
+            "How a window's match confidence is scored (SCC and GCC methods;\n"
+            "Onset and Spectrogram keep their own score).\n\n"
+            "Method default: SCC uses normalized peak height, GCC methods PSR.\n"
+            "Normalized peak: % of a perfect match. Stays high for cyclic or\n"
+            "ambient audio that correlates at many lags.\n"
+            "PSR: how far the peak stands out from the sidelobes; PSR 10 = 0%,\n"
+            "15 = 50%, 20 = 100%. The most discriminating for ambient audio.\n"
+            "Peak-to-mean: peak over mean correlation; 5 = 0%, 15 = 50%, 25 = 100%."
+        )
+        self.widgets["correlation_confidence_metric"] = metric
+        max_lag = QDoubleSpinBox()
+        max_lag.setRange(0.0, 600000.0)
+        max_lag.setDecimals(0)
+        max_lag.setSingleStep(100.0)
+        max_lag.setSuffix(" ms")
+        max_lag.setSpecialValueText("Whole window")
+        max_lag.setToolTip(
+            "Largest delay (either direction) the SCC and GCC methods look for.\n"
+            "Whole window: any delay up to the analysis window length.\n\n"
+            "Narrow it when the offset is known to be small: fewer false peaks\n"
+            "far from the real delay. A window whose best match lies beyond it is\n"
+            "rejected as out of range. To find delays longer than the window,\n"
+            "increase the window size instead."
+        )
+        self.widgets["correlation_max_lag_ms"] = max_lag
+        fft_mode = QComboBox()
+        fft_mode.addItem("Real FFT (faster)", "real")
+        fft_mode.addItem("Complex FFT", "complex")
+        fft_mode.setToolTip(
+            "Transform used by the SCC and GCC methods. Both give the same delays;\n"
+            "the real FFT only computes half the spectrum, so it does about half\n"
+            "the work. Complex FFT is there to cross-check it.\n\n"
+            "Either way the FFT plan for the window size is built once and reused\n"
+            "for every window; the analysis log shows how much planning that saved."
+        )
+        self.widgets["correlation_fft_mode"] = fft_mode
         self.widgets["delay_selection_mode"] = QComboBox()
         self.widgets["delay_selection_mode"].addItems(
             [
@@ -732,6 +929,55 @@ class AnalysisTab(QWidget):
             "• Excludes extreme outliers that poison averages\n\n"
             "Note: Sources without separation use the normal 'Delay Selection Method'."
         )
+        # Confidence gate
+        self.widgets["abort_below_confidence"] = QDoubleSpinBox()
+        self.widgets["abort_below_confidence"].setRange(0.0, 100.0)
+        self.widgets["abort_below_confidence"].setDecimals(1)
+        self.widgets["abort_below_confidence"].setSuffix(" %")
+        self.widgets["abort_below_confidence"].setSpecialValueText("Off")
+        self.widgets["abort_below_confidence"].setToolTip(
+            "Stop the job before muxing when the mean match confidence of the\n"
+            "accepted windows is below this value.\n\n"
+            "A delay this unreliable is usually worse than no sync at all.\n"
+            "The job result states the reason and the measured values.\n\n"
+            "Default: Off"
+        )
+        self.widgets["abort_below_accepted_fraction"] = QDoubleSpinBox()
+        self.widgets["abort_below_accepted_fraction"].setRange(0.0, 1.0)
+        self.widgets["abort_below_accepted_fraction"].setDecimals(2)
+        self.widgets["abort_below_accepted_fraction"].setSingleStep(0.05)
+        self.widgets["abort_below_accepted_fraction"].setSpecialValueText("Off")
+        self.widgets["abort_below_accepted_fraction"].setToolTip(
+            "Stop the job before muxing when fewer than this fraction of the\n"
+            "scanned windows were accepted (e.g. 0.25 = 25%).\n\n"
+            "Default: Off"
+        )
+        self.widgets["unreliable_analysis_action"] = QComboBox()
+        self.widgets["unreliable_analysis_action"].addItem(
+            "Mark as Needs Review", "needs_review"
+        )
+        self.widgets["unreliable_analysis_action"].addItem("Fail the job", "fail")
+        self.widgets["delay_rounding"] = QComboBox()
+        self.widgets["delay_rounding"].addItem("Nearest (halves to even)", "nearest")
+        self.widgets["delay_rounding"].addItem("Toward Zero", "toward_zero")
+        self.widgets["delay_rounding"].addItem(
+            "Away From Zero (halves away)", "away_from_zero"
+        )
+        self.widgets["delay_rounding"].setToolTip(
+            "How the selected delay is rounded to the whole milliseconds\n"
+            "mkvmerge applies (also used for container and subtitle delays).\n\n"
+            "• Nearest - exact halves go to the even neighbour:\n"
+            "  +0.5 → 0, -0.5 → 0, +1.5 → +2, -1.5 → -2. (Default)\n"
+            "• Toward Zero - the fraction is dropped: +1.7 → +1, -1.7 → -1.\n"
+            "• Away From Zero - exact halves round outward:\n"
+            "  +0.5 → +1, -0.5 → -1, +2.5 → +3, -2.5 → -3."
+        )
+        self.widgets["unreliable_analysis_action"].setToolTip(
+            "What happens when a source falls below either threshold above.\n\n"
+            "• Needs Review - no output is written; the batch report lists the\n"
+            "  job separately so it can be inspected manually.\n"
+            "• Fail - the job fails (honours 'stop batch on error')."
+        )
         core_layout.addRow("Correlation Method:", self.widgets["correlation_method"])
         core_layout.addRow(
             "Correlation (Source-Separated):",
@@ -742,12 +988,47 @@ class AnalysisTab(QWidget):
         core_layout.addRow(
             "Silence Threshold:", self.widgets["dense_silence_threshold_db"]
         )
+        core_layout.addRow("Window Placement:", self.widgets["chunk_strategy"])
+        endpoint_row = QHBoxLayout()
+        endpoint_row.addWidget(self.widgets["endpoint_chunks_start"])
+        endpoint_row.addWidget(QLabel("at start,"))
+        endpoint_row.addWidget(self.widgets["endpoint_chunks_end"])
+        endpoint_row.addWidget(QLabel("at end"))
+        endpoint_row.addStretch()
+        core_layout.addRow("Endpoint Windows:", endpoint_row)
+        core_layout.addRow("Min Window Spacing:", self.widgets["min_chunk_spacing_s"])
+        core_layout.addRow(self.widgets["windowed_decode"])
+        core_layout.addRow(self.widgets["avoid_silence"])
+        core_layout.addRow(self.widgets["analysis_exclude_chapters"])
+        core_layout.addRow(
+            "Excluded Chapters:", self.widgets["analysis_exclude_chapter_patterns"]
+        )
+        core_layout.addRow(
+            "Min Window Energy:", self.widgets["min_chunk_energy_db"]
+        )
         core_layout.addRow(
             "Outlier Threshold:", self.widgets["dense_outlier_threshold_ms"]
         )
         core_layout.addRow(
             "Minimum Match Confidence (%):", self.widgets["min_match_pct"]
         )
+        core_layout.addRow(
+            "Confidence Metric:", self.widgets["correlation_confidence_metric"]
+        )
+        core_layout.addRow(
+            "Max Delay Searched:", self.widgets["correlation_max_lag_ms"]
+        )
+        core_layout.addRow("FFT Path:", self.widgets["correlation_fft_mode"])
+        core_layout.addRow(
+            "Stop if Confidence Below:", self.widgets["abort_below_confidence"]
+        )
+        core_layout.addRow(
+            "Stop if Accepted Fraction Below:",
+            self.widgets["abort_below_accepted_fraction"],
+        )
+        core_layout.addRow(
+            "  ↳ Action:", self.widgets["unreliable_analysis_action"]
+        )
         core_layout.addRow(
             "Delay Selection Method:", self.widgets["delay_selection_mode"]
         )
@@ -764,6 +1045,7 @@ class AnalysisTab(QWidget):
         core_layout.addRow(
             "  ↳ Min Presence %:", self.widgets["early_cluster_min_presence_pct"]
         )
+        core_layout.addRow("Delay Rounding:", self.widgets["delay_rounding"])
         main_layout.addWidget(core_group)
 
         # --- Multi-Correlation Comparison (Analyze Only) ---
@@ -797,6 +1079,20 @@ class AnalysisTab(QWidget):
         methods_layout.addWidget(self.widgets["multi_corr_gcc_scot"])
         methods_layout.addWidget(self.widgets["multi_corr_gcc_whiten"])
         methods_layout.addWidget(self.widgets["multi_corr_spectrogram"])
+        self.widgets["multi_corr_disagree_threshold_ms"] = QDoubleSpinBox()
+        self.widgets["multi_corr_disagree_threshold_ms"].setRange(0.5, 1000.0)
+        self.widgets["multi_corr_disagree_threshold_ms"].setDecimals(1)
+        self.widgets["multi_corr_disagree_threshold_ms"].setSuffix(" ms")
+        self.widgets["multi_corr_disagree_threshold_ms"].setToolTip(
+            "Warn when the methods' selected delays differ by more than this\n"
+            "(spread = max − min across methods)."
+        )
+        threshold_form = QFormLayout()
+        threshold_form.addRow(
+            "Disagreement threshold:",
+            self.widgets["multi_corr_disagree_threshold_ms"],
+        )
+        methods_layout.addLayout(threshold_form)
         multi_corr_layout.addWidget(self.multi_corr_methods_container)
         main_layout.addWidget(multi_corr_group)
 
@@ -806,6 +1102,34 @@ class AnalysisTab(QWidget):
         )
         self._update_multi_corr_visibility(False)
 
+        # --- Multi-Track Consensus ---
+        consensus_group = QGroupBox("Multi-Track Consensus")
+        consensus_layout = QFormLayout(consensus_group)
+        self.widgets["multi_track_consensus"] = QCheckBox(
+            "Combine all same-language audio tracks"
+        )
+        self.widgets["multi_track_consensus"].setToolTip(
+            "Correlate every audio track of a source that has the same language as\n"
+            "the selected one (e.g. stereo and 5.1 of the same mix) and use the\n"
+            "confidence-weighted consensus of their delays.\n\n"
+            "Slower: each extra track is decoded and correlated. Skipped when the\n"
+            "correlation track is chosen explicitly for a source."
+        )
+        self.widgets["multi_track_consensus_threshold_ms"] = QDoubleSpinBox()
+        self.widgets["multi_track_consensus_threshold_ms"].setRange(1.0, 5000.0)
+        self.widgets["multi_track_consensus_threshold_ms"].setDecimals(1)
+        self.widgets["multi_track_consensus_threshold_ms"].setSuffix(" ms")
+        self.widgets["multi_track_consensus_threshold_ms"].setToolTip(
+            "Tracks whose delay differs from the majority by more than this are\n"
+            "left out of the consensus (commentary, a different dub)."
+        )
+        consensus_layout.addRow(self.widgets["multi_track_consensus"])
+        consensus_layout.addRow(
+            "Exclude tracks beyond:",
+            self.widgets["multi_track_consensus_threshold_ms"],
+        )
+        main_layout.addWidget(consensus_group)
+
         adv_filter_group = QGroupBox("Step 3: Advanced Filtering & Scan Controls")
         adv_filter_layout = QFormLayout(adv_filter_group)
         self.widgets["scan_start_percentage"] = QDoubleSpinBox()
@@ -820,6 +1144,25 @@ class AnalysisTab(QWidget):
         self.widgets["scan_end_percentage"].setToolTip(
             "Where to end the analysis scan, as a percentage of the file's total duration."
         )
+        self.widgets["scan_start_ms"] = QSpinBox()
+        self.widgets["scan_start_ms"].setRange(0, 24 * 3600 * 1000)
+        self.widgets["scan_start_ms"].setSingleStep(1000)
+        self.widgets["scan_start_ms"].setSuffix(" ms")
+        self.widgets["scan_start_ms"].setSpecialValueText("Off (use %)")
+        self.widgets["scan_start_ms"].setToolTip(
+            "Absolute scan start, e.g. 90000 to skip a 90 s intro.\n"
+            "Overrides the start percentage when set. Clamped (with a warning)\n"
+            "to the audio's duration; an empty range falls back to percentages."
+        )
+        self.widgets["scan_end_ms"] = QSpinBox()
+        self.widgets["scan_end_ms"].setRange(0, 24 * 3600 * 1000)
+        self.widgets["scan_end_ms"].setSingleStep(1000)
+        self.widgets["scan_end_ms"].setSuffix(" ms")
+        self.widgets["scan_end_ms"].setSpecialValueText("Off (use %)")
+        self.widgets["scan_end_ms"].setToolTip(
+            "Absolute scan end, e.g. to stop before the end credits.\n"
+            "Overrides the end percentage when set."
+        )
         self.widgets["filter_bandpass_lowcut_hz"] = QDoubleSpinBox()
         self.widgets["filter_bandpass_lowcut_hz"].setRange(20.0, 10000.0)
         self.widgets["filter_bandpass_lowcut_hz"].setSuffix(" Hz")
@@ -849,6 +1192,8 @@ class AnalysisTab(QWidget):
         adv_filter_layout.addRow(
             "Scan End Position:", self.widgets["scan_end_percentage"]
         )
+        adv_filter_layout.addRow("Scan Start Time:", self.widgets["scan_start_ms"])
+        adv_filter_layout.addRow("Scan End Time:", self.widgets["scan_end_ms"])
         adv_filter_layout.addRow(
             "Band-Pass Low Cutoff:", self.widgets["filter_bandpass_lowcut_hz"]
         )
@@ -890,7 +1235,9 @@ class AnalysisTab(QWidget):
         timing_mode_group = QGroupBox("Step 5: Timing Sync Mode")
         timing_mode_layout = QFormLayout(timing_mode_group)
         self.widgets["sync_mode"] = QComboBox()
-        self.widgets["sync_mode"].addItems(["positive_only", "allow_negative"])
+        self.widgets["sync_mode"].addItems(
+            ["positive_only", "allow_negative", "pad_silence"]
+        )
         self.widgets["sync_mode"].setToolTip(
             "Controls how timing delays are applied:\n\n"
             "• positive_only (Default): Shifts all tracks to eliminate negative delays.\n"
@@ -900,7 +1247,11 @@ class AnalysisTab(QWidget):
             "• allow_negative: Allows negative delays for secondary sources.\n"
             "  Source 1 remains the reference (delay = 0). Useful when merging early\n"
             "  releases (e.g., JPN Blu-ray + web audio) that will be remuxed later\n"
-            "  with a US Blu-ray in positive_only mode to add lossless audio."
+            "  with a US Blu-ray in positive_only mode to add lossless audio.\n\n"
+            "• pad_silence: Like allow_negative, but audio tracks with a negative\n"
+            "  delay are re-encoded (lossless to FLAC) with the delay trimmed from\n"
+            "  the audio and silence padded to the video's duration, so they are\n"
+            "  muxed at delay 0. Other tracks are not moved."
         )
         timing_mode_layout.addRow("Sync Mode:", self.widgets["sync_mode"])
         main_layout.addWidget(timing_mode_group)
@@ -917,13 +1268,108 @@ class AnalysisTab(QWidget):
         self.widgets["audio_peak_fit"].setToolTip(
             "For Standard Correlation (SCC), use parabolic interpolation to find a more precise, sub-sample peak.\nMay improve accuracy slightly."
         )
+        self.widgets["peak_interpolation"] = QComboBox()
+        self.widgets["peak_interpolation"].addItem("Default (see above)", "none")
+        self.widgets["peak_interpolation"].addItem("Quadratic", "quadratic")
+        self.widgets["peak_interpolation"].addItem("Gaussian", "gaussian")
+        self.widgets["peak_interpolation"].addItem("Windowed sinc", "sinc")
+        self.widgets["peak_interpolation"].setToolTip(
+            "Sub-sample peak interpolation for waveform methods\n"
+            "(SCC, GCC-PHAT, GCC-SCOT, Whitened).\n\n"
+            "Gaussian suits GCC-PHAT's sharp peaks; sinc is the most accurate\n"
+            "for band-limited audio. 'Default' leaves the GCC methods on the\n"
+            "integer sample; SCC uses quadratic when the peak fitting checkbox\n"
+            "is enabled."
+        )
+        self.widgets["peak_sinc_taps"] = QSpinBox()
+        self.widgets["peak_sinc_taps"].setRange(2, 64)
+        self.widgets["peak_sinc_taps"].setToolTip(
+            "Samples on each side of the peak used by sinc interpolation."
+        )
+        peak_form = QFormLayout()
+        peak_form.addRow("Peak interpolation:", self.widgets["peak_interpolation"])
+        peak_form.addRow("Sinc taps:", self.widgets["peak_sinc_taps"])
+        self.widgets["spectrogram_n_mels"] = QSpinBox()
+        self.widgets["spectrogram_n_mels"].setRange(8, 256)
+        self.widgets["spectrogram_n_mels"].setToolTip(
+            "Mel bands for Spectrogram Correlation. Each band is correlated\n"
+            "separately; more bands keep transients in narrow bands distinct.\n"
+            "At most FFT size / 4."
+        )
+        self.widgets["spectrogram_fft_size"] = QComboBox()
+        for size in (256, 512, 1024, 2048, 4096, 8192, 16384):
+            self.widgets["spectrogram_fft_size"].addItem(str(size), size)
+        self.widgets["spectrogram_fft_size"].setToolTip(
+            "Spectrogram Correlation FFT size. Larger = finer frequency detail\n"
+            "but longer frames, which smear transients in time."
+        )
+        self.widgets["spectrogram_hop_length"] = QSpinBox()
+        self.widgets["spectrogram_hop_length"].setRange(32, 16384)
+        self.widgets["spectrogram_hop_length"].setSingleStep(32)
+        self.widgets["spectrogram_hop_length"].setSuffix(" samples")
+        self.widgets["spectrogram_hop_length"].setToolTip(
+            "Spectrogram Correlation frame step (at most the FFT size).\n"
+            "The delay is found in whole frames, so the time resolution is\n"
+            "hop / 48000 s: 512 = 10.7 ms, 128 = 2.7 ms (4x slower)."
+        )
+        peak_form.addRow("Spectrogram mel bands:", self.widgets["spectrogram_n_mels"])
+        peak_form.addRow("Spectrogram FFT size:", self.widgets["spectrogram_fft_size"])
+        peak_form.addRow("Spectrogram hop:", self.widgets["spectrogram_hop_length"])
         self.widgets["log_audio_drift"] = QCheckBox("Log Audio Drift Metric")
         self.widgets["log_audio_drift"].setToolTip(
             "Calculate and log a metric that indicates potential audio drift or speed differences between sources."
         )
         adv_layout.addWidget(self.widgets["use_soxr"])
         adv_layout.addWidget(self.widgets["audio_peak_fit"])
+        adv_layout.addLayout(peak_form)
+        self.widgets["dtw_enabled"] = QCheckBox("Compute DTW Warping Path")
+        self.widgets["dtw_enabled"].setToolTip(
+            "After correlation, align the whole scan range with dynamic time\n"
+            "warping and log the path length and average slope.\n\n"
+            "A slope away from 1.0 points to a speed/tempo difference that a\n"
+            "single delay cannot fix. Adds a few seconds per source."
+        )
+        self.widgets["dtw_band_ms"] = QDoubleSpinBox()
+        self.widgets["dtw_band_ms"].setRange(500.0, 60000.0)
+        self.widgets["dtw_band_ms"].setDecimals(0)
+        self.widgets["dtw_band_ms"].setSingleStep(500.0)
+        self.widgets["dtw_band_ms"].setSuffix(" ms")
+        self.widgets["dtw_band_ms"].setToolTip(
+            "How far (±) the warping path may stray from the correlation delay.\n"
+            "Wider bands follow larger drifts but use more memory and time.\n\n"
+            "Default: 5000 ms"
+        )
+        dtw_form = QFormLayout()
+        dtw_form.addRow("DTW band:", self.widgets["dtw_band_ms"])
         adv_layout.addWidget(self.widgets["log_audio_drift"])
+        adv_layout.addWidget(self.widgets["dtw_enabled"])
+        adv_layout.addLayout(dtw_form)
+        self.widgets["coarse_prealign"] = QCheckBox(
+            "Coarse pre-alignment for large offsets"
+        )
+        self.widgets["coarse_prealign"].setToolTip(
+            "Before window correlation, match the onset envelopes of both whole\n"
+            "tracks to find a large offset (e.g. minutes of extra intro on one\n"
+            "source), then correlate the windows around it.\n\n"
+            "Use when every window is rejected because the sources are too far\n"
+            "apart. The coarse offset is logged and written to the analysis\n"
+            "report. Needs the full tracks (disables windowed decode)."
+        )
+        self.widgets["coarse_prealign_max_offset_s"] = QDoubleSpinBox()
+        self.widgets["coarse_prealign_max_offset_s"].setRange(10.0, 3600.0)
+        self.widgets["coarse_prealign_max_offset_s"].setDecimals(0)
+        self.widgets["coarse_prealign_max_offset_s"].setSingleStep(30.0)
+        self.widgets["coarse_prealign_max_offset_s"].setSuffix(" s")
+        self.widgets["coarse_prealign_max_offset_s"].setToolTip(
+            "Largest offset (±) the coarse pre-alignment searches.\n\n"
+            "Default: 300 s"
+        )
+        coarse_form = QFormLayout()
+        coarse_form.addRow(
+            "Max coarse offset:", self.widgets["coarse_prealign_max_offset_s"]
+        )
+        adv_layout.addWidget(self.widgets["coarse_prealign"])
+        adv_layout.addLayout(coarse_form)
         main_layout.addWidget(adv_group)
 
         self.widgets["filtering_method"].currentTextChanged.connect(
@@ -1712,12 +2158,15 @@ class SubtitleSyncTab(QWidget):
         self.widgets["subtitle_sync_mode"].addItems(
             [
                 "time-based",
+                "linear-stretch",
                 "video-verified",
             ]
         )
         self.widgets["subtitle_sync_mode"].setToolTip(
             "Subtitle synchronization method:\n\n"
             "• time-based: Simple delay via mkvmerge --sync (fastest)\n"
+            "• linear-stretch: Stretch timing between a start and an end anchor\n"
+            "  (subtitles from a source running at a slightly different speed)\n"
             "• video-verified: Audio correlation verified against video frames\n"
             "  (catches cases where audio is offset but subs should be 0ms)"
         )
@@ -1738,6 +2187,34 @@ class SubtitleSyncTab(QWidget):
             "• ceil: Round up - subtitles appear slightly later"
         )
         output_layout.addRow("Rounding:", self.widgets["subtitle_rounding"])
+
+        self.widgets["subtitle_lint_autofix"] = QCheckBox(
+            "Fix broken event timing (zero/negative durations, order)"
+        )
+        self.widgets["subtitle_lint_autofix"].setToolTip(
+            "Text subtitles are always checked after sync for zero-length lines,\n"
+            "lines ending before they start or before 0, lines out of order and\n"
+            "very large gaps; problems are logged and shown in the final audit.\n\n"
+            "When enabled, lines are also repaired: starts are clamped to 0, empty\n"
+            "or reversed lines get a short duration and lines are sorted by start."
+        )
+        output_layout.addRow("", self.widgets["subtitle_lint_autofix"])
+
+        for key, label in (
+            ("subtitle_retime_src_fps", "Retime from FPS:"),
+            ("subtitle_retime_dst_fps", "Retime to FPS:"),
+        ):
+            spin = QDoubleSpinBox()
+            spin.setRange(0.0, 240.0)
+            spin.setDecimals(3)
+            spin.setSpecialValueText("Off")
+            spin.setToolTip(
+                "Convert subtitle timing between framerates before sync\n"
+                "(e.g. 25 -> 23.976 for PAL subtitles on a film-speed video).\n"
+                "All timestamps are scaled by from/to. Off when either is 0."
+            )
+            self.widgets[key] = spin
+            output_layout.addRow(label, spin)
         main_layout.addWidget(output_group)
 
         # ===== TIME-BASED SETTINGS =====
@@ -1753,8 +2230,50 @@ class SubtitleSyncTab(QWidget):
             "• Checked: Modify subtitle timestamps directly in the file"
         )
         time_layout.addRow("", self.widgets["time_based_use_raw_values"])
+
+        self.widgets["time_based_frame_accurate"] = QCheckBox(
+            "Frame-accurate: snap shifted events to the target's frames"
+        )
+        self.widgets["time_based_frame_accurate"].setToolTip(
+            "Bake the delay into the subtitle file with every start and end\n"
+            "moved onto a frame of the target video (floor/round/ceil from\n"
+            "Rounding above), and mux with no extra delay. Needs the target FPS;\n"
+            "without it the delay is applied as above."
+        )
+        time_layout.addRow("", self.widgets["time_based_frame_accurate"])
         main_layout.addWidget(time_group)
 
+        # ===== LINEAR-STRETCH SETTINGS =====
+        stretch_group = QGroupBox("Linear-Stretch Settings")
+        stretch_layout = QFormLayout(stretch_group)
+
+        for key, label, tip in (
+            (
+                "linear_stretch_start_ms",
+                "Start anchor (ms):",
+                "Source time of the start anchor. The analysed delay applies here.",
+            ),
+            (
+                "linear_stretch_end_ms",
+                "End anchor (ms):",
+                "Source time of the end anchor (e.g. the last dialogue line).\n"
+                "Must be after the start anchor.",
+            ),
+            (
+                "linear_stretch_end_delay_ms",
+                "End anchor delay (ms):",
+                "Delay needed at the end anchor. Times in between are mapped\n"
+                "linearly: new = a * old + b.",
+            ),
+        ):
+            spin = QDoubleSpinBox()
+            spin.setRange(-36_000_000.0, 36_000_000.0)
+            spin.setDecimals(3)
+            spin.setToolTip(tip)
+            self.widgets[key] = spin
+            stretch_layout.addRow(label, spin)
+        main_layout.addWidget(stretch_group)
+
         # ===== VIDEO-VERIFIED SETTINGS =====
         vv_group = QGroupBox("Video-Verified Settings (Sliding-Window Matcher)")
         vv_layout = QFormLayout(vv_group)
@@ -1819,6 +2338,27 @@ class SubtitleSyncTab(QWidget):
             self.widgets["video_verified_cross_check_tolerance_frames"],
         )
 
+        self.widgets["video_verified_interlace_detection"] = QComboBox()
+        self.widgets["video_verified_interlace_detection"].addItem(
+            "Container Flags", "metadata"
+        )
+        self.widgets["video_verified_interlace_detection"].addItem(
+            "Auto (confirm with idet)", "auto"
+        )
+        self.widgets["video_verified_interlace_detection"].setToolTip(
+            "How a source is judged interlaced (interlaced sources skip frame\n"
+            "matching and use audio correlation):\n\n"
+            "• Container Flags (Default): trust ffprobe/MediaInfo flags\n"
+            "• Auto: also run ffmpeg idet on ~750 sampled frames; its verdict\n"
+            "  wins, so progressive video flagged interlaced still gets frame\n"
+            "  matching. Adds a few seconds per source.\n\n"
+            "MPEG-2 (DVD) sources always use audio correlation."
+        )
+        vv_layout.addRow(
+            "Interlace Detection:",
+            self.widgets["video_verified_interlace_detection"],
+        )
+
         # --- Backend-specific settings (conditionally enabled) ---
 
         self.widgets["video_verified_hash_size"] = QComboBox()
@@ -1842,6 +2382,22 @@ class SubtitleSyncTab(QWidget):
             self.widgets["video_verified_hash_size"],
         )
 
+        self.widgets["video_verified_hash_index"] = QCheckBox()
+        self.widgets["video_verified_hash_index"].setChecked(False)
+        self.widgets["video_verified_hash_index"].setToolTip(
+            "Precompute the hash of every Source 1 frame once and reuse it.\n\n"
+            "The first run hashes the whole reference video (slower); later\n"
+            "runs on the same file, backend and hash size look the target\n"
+            "hashes up instead of decoding them — useful when re-running a\n"
+            "job while tweaking settings. The index is rebuilt automatically\n"
+            "when the file changes.\n\n"
+            "Only active when Backend is pHash or dHash."
+        )
+        vv_layout.addRow(
+            "Reference Hash Index:",
+            self.widgets["video_verified_hash_index"],
+        )
+
         self.widgets["video_verified_ssim_input_size"] = QComboBox()
         for size in (128, 256, 384, 512):
             self.widgets["video_verified_ssim_input_size"].addItem(
@@ -2085,6 +2641,7 @@ class SubtitleSyncTab(QWidget):
         so the user only sees relevant knobs light up.
         """
         is_time_based = text == "time-based"
+        is_linear_stretch = text == "linear-stretch"
         is_video_verified = text == "video-verified"
 
         # Look up the currently-selected backend + cross-check backend.
@@ -2095,6 +2652,14 @@ class SubtitleSyncTab(QWidget):
 
         # Time-based specific
         self.widgets["time_based_use_raw_values"].setEnabled(is_time_based)
+        self.widgets["time_based_frame_accurate"].setEnabled(is_time_based)
+
+        for key in (
+            "linear_stretch_start_ms",
+            "linear_stretch_end_ms",
+            "linear_stretch_end_delay_ms",
+        ):
+            self.widgets[key].setEnabled(is_linear_stretch)
 
         # Shared video-verified toggles (always enabled in video-verified mode)
         for key in (
@@ -2104,6 +2669,7 @@ class SubtitleSyncTab(QWidget):
             "video_verified_slide_range_seconds",
             "video_verified_num_positions",
             "video_verified_batch_size",
+            "video_verified_interlace_detection",
             "video_verified_run_in_subprocess",
             "video_verified_debug_report",
             "video_verified_frame_audit",
@@ -2121,6 +2687,9 @@ class SubtitleSyncTab(QWidget):
         self.widgets["video_verified_hash_size"].setEnabled(
             is_video_verified and needs_hash
         )
+        self.widgets["video_verified_hash_index"].setEnabled(
+            is_video_verified and backend in ("phash", "dhash")
+        )
 
         # SSIM input size — only relevant when primary OR cross-check uses SSIM.
         needs_ssim = backend == "ssim" or cross == "ssim"
@@ -2182,7 +2751,17 @@ class MergeBehaviorTab(QWidget):
             "Remove dialog normalization gain (AC3/E-AC3)"
         )
         self.widgets["apply_dialog_norm_gain"].setToolTip(
-            "For AC3/E-AC3 audio tracks, remove the DialNorm metadata.\nThis can sometimes prevent players from lowering the volume."
+            "For AC3/E-AC3 audio tracks, read the DialNorm value and remove the\n"
+            "attenuation players apply for it. Tracks without DialNorm, with a\n"
+            "neutral value (-31 dB) or in other codecs are left untouched."
+        )
+        self.widgets["dialog_norm_target_db"] = QSpinBox()
+        self.widgets["dialog_norm_target_db"].setRange(-31, 0)
+        self.widgets["dialog_norm_target_db"].setSuffix(" dBFS")
+        self.widgets["dialog_norm_target_db"].setToolTip(
+            "Dialogue level for AC3/E-AC3 tracks once DialNorm is removed.\n"
+            "0 = only remove the attenuation (lossless). Any other value applies\n"
+            "the gain that brings dialogue to this level, which re-encodes the track."
         )
         self.widgets["disable_track_statistics_tags"] = QCheckBox(
             "Disable track statistics tags (for purist remuxes)"
@@ -2205,10 +2784,146 @@ class MergeBehaviorTab(QWidget):
             "Uses lossless stream copy — no re-encoding."
         )
         form1.addWidget(self.widgets["apply_dialog_norm_gain"])
+        form1.addRow(
+            "DialNorm target (0 = remove only):",
+            self.widgets["dialog_norm_target_db"],
+        )
         form1.addWidget(self.widgets["disable_track_statistics_tags"])
         form1.addWidget(self.widgets["disable_header_compression"])
+        self.widgets["fix_colorimetry_flags"] = QCheckBox(
+            "Fix missing/wrong colorimetry flags (container only, lossless)"
+        )
+        self.widgets["fix_colorimetry_flags"].setToolTip(
+            "Normalizes the video track's container colour flags:\n"
+            "SDR → BT.709 / limited range, HDR10 → BT.2020 / PQ.\n"
+            "Values that are already valid are kept; the bitstream is never touched."
+        )
+        self.widgets["subtitle_font_subsetting"] = QCheckBox(
+            "Subset attached fonts to the glyphs used by subtitles (requires fontTools)"
+        )
+        self.widgets["subtitle_font_subsetting"].setToolTip(
+            "Fonts referenced by ASS styles and \\fn tags are matched against the\n"
+            "attachments; each matched font is replaced by a copy that only holds\n"
+            "the characters the subtitles draw. Unmatched fonts are always logged."
+        )
         form1.addWidget(self.widgets["trim_audio_to_video_duration"])
+        form1.addWidget(self.widgets["fix_colorimetry_flags"])
+        self.widgets["fix_attachment_mime"] = QCheckBox(
+            "Correct attachment MIME types (fonts and images)"
+        )
+        self.widgets["fix_attachment_mime"].setToolTip(
+            "Fonts attached as application/octet-stream (or another wrong type)\n"
+            "are not loaded by some players. The type is detected from the file\n"
+            "contents; attachments that are already correct or not recognized\n"
+            "are left alone. Each correction is logged."
+        )
+        form1.addWidget(self.widgets["subtitle_font_subsetting"])
+        form1.addWidget(self.widgets["fix_attachment_mime"])
         main_layout.addWidget(general_group)
+        flags_group = QGroupBox("Track Flag Policy")
+        form_flags = QFormLayout(flags_group)
+        self.widgets["enforce_track_flag_policy"] = QCheckBox(
+            "Enforce one default audio/subtitle track and one forced subtitle track"
+        )
+        self.widgets["enforce_track_flag_policy"].setToolTip(
+            "Normalizes default/forced flags at mux time so batch output\n"
+            "is consistent:\n"
+            "• One default audio track (preferred language, else the first)\n"
+            "• One default subtitle track (preferred language, else the first);\n"
+            "  forced subtitles are never default\n"
+            "• One forced subtitle track (preferred language, else the first)\n"
+            "Every change is logged."
+        )
+        self.widgets["flag_policy_audio_lang"] = QLineEdit()
+        self.widgets["flag_policy_audio_lang"].setPlaceholderText(
+            "e.g. jpn (empty = first)"
+        )
+        self.widgets["flag_policy_subtitle_lang"] = QLineEdit()
+        self.widgets["flag_policy_subtitle_lang"].setPlaceholderText(
+            "e.g. eng (empty = first)"
+        )
+        form_flags.addRow(self.widgets["enforce_track_flag_policy"])
+        form_flags.addRow(
+            "Default audio language:", self.widgets["flag_policy_audio_lang"]
+        )
+        form_flags.addRow(
+            "Default/forced subtitle language:",
+            self.widgets["flag_policy_subtitle_lang"],
+        )
+        main_layout.addWidget(flags_group)
+        default_lang_group = QGroupBox("Preferred Default Language")
+        form_default_lang = QFormLayout(default_lang_group)
+        self.widgets["default_audio_lang"] = QLineEdit()
+        self.widgets["default_audio_lang"].setPlaceholderText("e.g. jpn (empty = off)")
+        self.widgets["default_subtitle_lang"] = QLineEdit()
+        self.widgets["default_subtitle_lang"].setPlaceholderText(
+            "e.g. eng (empty = off)"
+        )
+        for key in ("default_audio_lang", "default_subtitle_lang"):
+            self.widgets[key].setToolTip(
+                "The first track in this language (ISO 639-2 code) becomes the\n"
+                "default, whatever the source marked. A default you set by hand\n"
+                "in the layout always wins. If no track matches, the existing\n"
+                "default is kept and a warning is logged."
+            )
+        form_default_lang.addRow("Audio:", self.widgets["default_audio_lang"])
+        form_default_lang.addRow("Subtitles:", self.widgets["default_subtitle_lang"])
+        main_layout.addWidget(default_lang_group)
+        loudnorm_group = QGroupBox("Loudness Normalization (EBU R128)")
+        form_loud = QFormLayout(loudnorm_group)
+        self.widgets["loudnorm_enabled"] = QCheckBox(
+            "Normalize loudness of audio tracks marked in Track Settings"
+        )
+        self.widgets["loudnorm_enabled"].setToolTip(
+            "Two-pass FFmpeg loudnorm: the track is measured first, then\n"
+            "re-encoded with a single linear gain that reaches the targets.\n"
+            "Tracks are kept in their codec where possible (else E-AC3) and the\n"
+            "track delay is baked in. Measured and target loudness are logged."
+        )
+        self.widgets["loudnorm_target_i"] = QDoubleSpinBox()
+        self.widgets["loudnorm_target_i"].setRange(-70.0, -5.0)
+        self.widgets["loudnorm_target_i"].setDecimals(1)
+        self.widgets["loudnorm_target_i"].setSuffix(" LUFS")
+        self.widgets["loudnorm_target_lra"] = QDoubleSpinBox()
+        self.widgets["loudnorm_target_lra"].setRange(1.0, 50.0)
+        self.widgets["loudnorm_target_lra"].setDecimals(1)
+        self.widgets["loudnorm_target_lra"].setSuffix(" LU")
+        self.widgets["loudnorm_target_tp"] = QDoubleSpinBox()
+        self.widgets["loudnorm_target_tp"].setRange(-9.0, 0.0)
+        self.widgets["loudnorm_target_tp"].setDecimals(1)
+        self.widgets["loudnorm_target_tp"].setSuffix(" dBTP")
+        self.widgets["loudnorm_allow_lossless"] = QCheckBox(
+            "Allow lossless tracks (re-encoded to FLAC)"
+        )
+        self.widgets["loudnorm_allow_lossless"].setToolTip(
+            "TrueHD, DTS-HD MA, PCM, FLAC... are skipped with a warning unless\n"
+            "this is checked or the layout already re-encodes the track."
+        )
+        form_loud.addRow(self.widgets["loudnorm_enabled"])
+        form_loud.addRow("Integrated loudness:", self.widgets["loudnorm_target_i"])
+        form_loud.addRow("Loudness range:", self.widgets["loudnorm_target_lra"])
+        form_loud.addRow("True peak:", self.widgets["loudnorm_target_tp"])
+        form_loud.addRow(self.widgets["loudnorm_allow_lossless"])
+        main_layout.addWidget(loudnorm_group)
+        dovi_group = QGroupBox("Dolby Vision")
+        form_dovi = QFormLayout(dovi_group)
+        self.widgets["dovi_inject"] = QCheckBox(
+            "Inject Source 1 Dolby Vision RPU into output video (requires dovi_tool)"
+        )
+        self.widgets["dovi_inject"].setToolTip(
+            "Extracts the Dolby Vision RPU from Source 1 and injects it into the\n"
+            "output HEVC video track before muxing (hybrid releases).\n"
+            "Skipped automatically when Source 1 has no RPU."
+        )
+        self.widgets["dovi_target_profile"] = QComboBox()
+        self.widgets["dovi_target_profile"].addItem("Keep source profile", 0)
+        self.widgets["dovi_target_profile"].addItem("Convert to Profile 8.1", 8)
+        self.widgets["dovi_target_profile"].setToolTip(
+            "Profile 8.1 conversion uses dovi_tool mode 2 (works for P5/P7/P8)."
+        )
+        form_dovi.addRow(self.widgets["dovi_inject"])
+        form_dovi.addRow("Target profile:", self.widgets["dovi_target_profile"])
+        main_layout.addWidget(dovi_group)
         post_merge_group = QGroupBox("Post-Merge Finalization")
         form2 = QFormLayout(post_merge_group)
         self.widgets["post_mux_normalize_timestamps"] = QCheckBox(
@@ -2223,9 +2938,204 @@ class MergeBehaviorTab(QWidget):
         self.widgets["post_mux_strip_tags"].setToolTip(
             "If the timestamp normalization step is run, FFmpeg will add an 'ENCODER' tag to the file.\nThis option will run a quick update with mkvpropedit to remove that tag for a cleaner file."
         )
+        self.widgets["atomic_output"] = QCheckBox(
+            "Never leave partial files in the output folder"
+        )
+        self.widgets["atomic_output"].setToolTip(
+            "When the temp and output folders are on different drives, the merged\n"
+            "file is copied to '<name>.part' and renamed once complete, so a crash\n"
+            "or a full disk never leaves a truncated file under the final name.\n"
+            "A failed copy is removed."
+        )
+        self.widgets["deterministic_uids"] = QCheckBox(
+            "Keep track UIDs the same when a job is re-run"
+        )
+        self.widgets["deterministic_uids"].setToolTip(
+            "Seeds mkvmerge's UID generator (--deterministic) from the source\n"
+            "filenames, track ids and types, so muxing the same job again gives\n"
+            "the same track, chapter and segment UIDs. Needs the same mkvmerge\n"
+            "version and options. Off: mkvmerge picks random UIDs each run."
+        )
+        self.widgets["reproducible_output"] = QCheckBox(
+            "Reproducible output (same inputs give the same file)"
+        )
+        self.widgets["reproducible_output"].setToolTip(
+            "Seeds all UIDs like the option above, leaves out the muxing date\n"
+            "and statistics tags, and runs timestamp rebasing bit-exact, so the\n"
+            "same inputs and settings give a byte-identical file.\n"
+            "The file still records the mkvmerge version, so identical bytes\n"
+            "also need the same MKVToolNix (and FFmpeg) version."
+        )
+        self.widgets["output_template"] = QLineEdit()
+        self.widgets["output_template"].setPlaceholderText(
+            "Source 1 filename (e.g. {title} - {episode} [{source1_group}].mkv)"
+        )
+        self.widgets["output_template"].setToolTip(
+            "Name of the merged file. Leave empty to keep Source 1's filename.\n"
+            "Placeholders: {title}, {episode}, {season}, {source1},\n"
+            "{source1_group}, {source2_group}, ..., {resolution}.\n"
+            "Unknown placeholders are kept as written; characters not allowed\n"
+            "in filenames are replaced, and a taken name gets a (2), (3), ..."
+        )
+        self.widgets["tag_policy"] = QComboBox()
+        self.widgets["tag_policy"].addItem("Strip all", "strip_all")
+        self.widgets["tag_policy"].addItem(
+            "Strip tags, keep title", "strip_except_title"
+        )
+        self.widgets["tag_policy"].addItem("Keep Source 1's tags and title", "keep")
+        self.widgets["tag_policy"].setToolTip(
+            "Which of Source 1's container metadata the output keeps.\n"
+            "Keep carries its global tags and segment title; track tags are\n"
+            "never carried, they belong to Source 1's tracks. The strip options\n"
+            "also drop tags of any Matroska input. The log lists what was kept."
+        )
+        self.widgets["output_title"] = QLineEdit()
+        self.widgets["output_title"].setPlaceholderText(
+            "Per tag policy (e.g. {title} - {episode})"
+        )
+        self.widgets["output_title"].setToolTip(
+            "Segment title of the merged file, whatever the tag policy.\n"
+            "Same placeholders as the output filename, plus {source1_title}\n"
+            "(Source 1's own title). Leave empty to follow the tag policy."
+        )
+        self.widgets["output_split_mode"] = QComboBox()
+        self.widgets["output_split_mode"].addItem("Don't split", "none")
+        self.widgets["output_split_mode"].addItem("By size", "size")
+        self.widgets["output_split_mode"].addItem("By duration", "duration")
+        self.widgets["output_split_mode"].addItem("By chapters", "chapters")
+        self.widgets["output_split_mode"].setToolTip(
+            "Write the output as several parts (mkvmerge --split), named\n"
+            "<name>-001.mkv, <name>-002.mkv, ... Delays and chapters are applied\n"
+            "before splitting. Chapter splitting fails the job if the output\n"
+            "has no chapters. The output audit is skipped for split files."
+        )
+        split_size = QSpinBox()
+        split_size.setRange(1, 1_000_000)
+        split_size.setSuffix(" MiB")
+        self.widgets["output_split_size_mb"] = split_size
+        split_duration = QSpinBox()
+        split_duration.setRange(1, 1440)
+        split_duration.setSuffix(" min")
+        self.widgets["output_split_duration_min"] = split_duration
+        split_chapters = QSpinBox()
+        split_chapters.setRange(1, 999)
+        split_chapters.setToolTip("Start a new part every N chapters.")
+        self.widgets["output_split_chapters_every"] = split_chapters
+        self.widgets["verify_output"] = QCheckBox(
+            "Verify output tracks after merge (requires FFprobe)"
+        )
+        self.widgets["verify_output"].setToolTip(
+            "Reads every packet of the finished file and logs each track's duration,\n"
+            "codec, bitrate and size (also written to the batch report).\n"
+            "Flags empty tracks, subtitle tracks without events and audio tracks\n"
+            "much shorter than the video. Also checks that each video/audio track's\n"
+            "delay in the output matches the planned delay."
+        )
+        self.widgets["checksum_output"] = QCheckBox(
+            "Record SHA-256 checksums of output files"
+        )
+        self.widgets["checksum_output"].setToolTip(
+            "After the merge, hash each written file and record its size and\n"
+            "SHA-256 in the log and the batch report, to verify copies later.\n"
+            "Reads the whole output once more."
+        )
+        verify_tol = QSpinBox()
+        verify_tol.setRange(0, 1000)
+        verify_tol.setSuffix(" ms")
+        verify_tol.setToolTip(
+            "Allowed difference between the planned and the measured track delay."
+        )
+        self.widgets["verify_delay_tolerance_ms"] = verify_tol
+        self.widgets["verify_delay_mismatch_action"] = QComboBox()
+        self.widgets["verify_delay_mismatch_action"].addItem("Warn", "warn")
+        self.widgets["verify_delay_mismatch_action"].addItem("Fail the job", "fail")
         form2.addWidget(self.widgets["post_mux_normalize_timestamps"])
         form2.addWidget(self.widgets["post_mux_strip_tags"])
+        form2.addWidget(self.widgets["atomic_output"])
+        form2.addWidget(self.widgets["deterministic_uids"])
+        form2.addWidget(self.widgets["reproducible_output"])
+        form2.addWidget(self.widgets["verify_output"])
+        form2.addWidget(self.widgets["checksum_output"])
+        form2.addRow("Delay tolerance:", verify_tol)
+        form2.addRow(
+            "On delay mismatch:", self.widgets["verify_delay_mismatch_action"]
+        )
+        form2.addRow("Output filename:", self.widgets["output_template"])
+        form2.addRow("Tags:", self.widgets["tag_policy"])
+        form2.addRow("Output title:", self.widgets["output_title"])
+        form2.addRow("Split output:", self.widgets["output_split_mode"])
+        form2.addRow("Max part size:", split_size)
+        form2.addRow("Part duration:", split_duration)
+        form2.addRow("Chapters per part:", split_chapters)
+        self.widgets["mkvmerge_options_file"] = QComboBox()
+        self.widgets["mkvmerge_options_file"].addItem(
+            "Always use options file", "always"
+        )
+        self.widgets["mkvmerge_options_file"].addItem(
+            "Only for long command lines", "auto"
+        )
+        self.widgets["mkvmerge_options_file"].setToolTip(
+            "How mkvmerge receives its arguments. The JSON options file\n"
+            "(mkvmerge @opts.json) avoids command-line length limits on jobs\n"
+            "with many tracks or attachments. 'Only for long command lines'\n"
+            "passes short commands directly. The file is written either way."
+        )
+        form2.addRow("mkvmerge arguments:", self.widgets["mkvmerge_options_file"])
         main_layout.addWidget(post_merge_group)
+        batch_group = QGroupBox("Batch Queue")
+        form3 = QFormLayout(batch_group)
+        retries = QSpinBox()
+        retries.setRange(0, 10)
+        retries.setSpecialValueText("Off")
+        retries.setToolTip(
+            "Re-run a failed extraction or merge command up to this many times\n"
+            "when the failure looks transient (file briefly locked, I/O error,\n"
+            "process killed), waiting 2s, 4s, 8s, ... between attempts.\n"
+            "Deterministic errors (bad options, missing codec) are never retried."
+        )
+        self.widgets["command_retries"] = retries
+        concurrent = QSpinBox()
+        concurrent.setRange(1, 16)
+        concurrent.setToolTip(
+            "How many jobs of a batch to run at the same time.\n"
+            "1 = one after another (default). Higher values help CPU-heavy\n"
+            "batches but multiply memory/GPU use per running job."
+        )
+        self.widgets["batch_max_concurrent_jobs"] = concurrent
+        self.widgets["batch_stop_on_error"] = QCheckBox(
+            "Stop the batch when a job fails"
+        )
+        self.widgets["batch_stop_on_error"].setToolTip(
+            "Skip jobs that have not started yet once any job fails.\n"
+            "Jobs already running are allowed to finish."
+        )
+        self.widgets["persist_job_queue"] = QCheckBox(
+            "Remember the job queue between sessions"
+        )
+        self.widgets["persist_job_queue"].setToolTip(
+            "Keep queued jobs and their layouts when the queue or app is closed,\n"
+            "and restore them next time. Jobs whose files are gone are shown as\n"
+            "Unavailable; layouts saved for different files are flagged as stale."
+        )
+        self.widgets["layout_match_strategy"] = QComboBox()
+        self.widgets["layout_match_strategy"].addItem(
+            "Track ID, then position", "id_then_position"
+        )
+        self.widgets["layout_match_strategy"].addItem("Track ID only", "id")
+        self.widgets["layout_match_strategy"].addItem("Position only", "position")
+        self.widgets["layout_match_strategy"].setToolTip(
+            "How a pasted, duplicated or reused layout finds its tracks in\n"
+            "other files. 'Track ID, then position' uses the same track ID and\n"
+            "falls back to the same type at the same position (the second\n"
+            "audio track, ...) when IDs shifted between releases. Each track's\n"
+            "match is written to the log."
+        )
+        form3.addRow(self.widgets["persist_job_queue"])
+        form3.addRow("Match layout tracks by:", self.widgets["layout_match_strategy"])
+        form3.addRow("Concurrent Jobs:", concurrent)
+        form3.addRow(self.widgets["batch_stop_on_error"])
+        form3.addRow("Command Retries:", retries)
+        main_layout.addWidget(batch_group)
         main_layout.addStretch(1)
 
 
@@ -2272,12 +3182,76 @@ class LoggingTab(QWidget):
         self.widgets["log_show_options_json"].setToolTip(
             "Print the full mkvmerge command to the log in the raw JSON format that is passed to the tool."
         )
+        self.widgets["log_json_lines"] = QCheckBox(
+            "Write structured JSON-lines log (.jsonl)"
+        )
+        self.widgets["log_json_lines"].setToolTip(
+            "Also write <job>.jsonl next to the log, one {ts, level, phase, message}\n"
+            "record per line, for scripts and external tools."
+        )
+        self.widgets["analysis_write_report"] = QCheckBox(
+            "Write analysis report (JSON + CSV)"
+        )
+        self.widgets["analysis_write_report"].setToolTip(
+            "After analysis, write <job>_analysis_report.json and .csv into the\n"
+            "job's work dir (logs/, next to the audit trail).\n"
+            "Contains per-source delays, confidence and the full per-chunk table.\n"
+            "Also writes <job>.delays.json to the output folder, a delay sidecar\n"
+            "for later re-runs.\n"
+            "Also written when raw JSON option logging is enabled."
+        )
+        self.widgets["analysis_use_sidecar"] = QCheckBox(
+            "Use delay sidecar when present (skip analysis)"
+        )
+        self.widgets["analysis_use_sidecar"].setToolTip(
+            "When Source 1 has a <name>.delays.json next to it, take every\n"
+            "source's delay from it instead of running correlation.\n\n"
+            "The sidecar must list exactly the job's sources, otherwise the job\n"
+            "fails. Manual delays in source settings still take precedence.\n"
+            "Stepping correction is not run for sidecar delays."
+        )
+        self.widgets["analysis_dump_chunks"] = QCheckBox(
+            "Dump correlation windows as WAV (debug)"
+        )
+        self.widgets["analysis_dump_chunks"].setToolTip(
+            "Writes every window the correlator saw (decoded, resampled, filtered),\n"
+            "reference and target, to debug/analysis_chunks/<job>/ plus a CSV with\n"
+            "each window's delay, match and accepted/rejected/silence status.\n"
+            "Uses a lot of disk space on long files with a small hop."
+        )
+        self.widgets["correlation_curves"] = QComboBox()
+        self.widgets["correlation_curves"].addItem("Off", "off")
+        self.widgets["correlation_curves"].addItem(
+            "Representative window", "representative"
+        )
+        self.widgets["correlation_curves"].addItem("All windows", "all")
+        self.widgets["correlation_curves"].setToolTip(
+            "Keep the full correlation curve (lag vs correlation), not just the\n"
+            "peak, for plotting and judging how sharp the peak is.\n\n"
+            "• Representative: the accepted window closest to the median delay\n"
+            "• All windows: every correlated window (representative one flagged)\n\n"
+            "Curves are added to the analysis report JSON."
+        )
+        self.widgets["correlation_curve_max_points"] = QSpinBox()
+        self.widgets["correlation_curve_max_points"].setRange(0, 100000)
+        self.widgets["correlation_curve_max_points"].setSingleStep(500)
+        self.widgets["correlation_curve_max_points"].setSpecialValueText("No limit")
+        self.widgets["correlation_curve_max_points"].setToolTip(
+            "Longer curves are decimated to this many points, keeping the\n"
+            "largest value of each block so the peak is never lost."
+        )
         f.addRow(self.widgets["log_compact"])
         f.addRow(self.widgets["log_autoscroll"])
         f.addRow("Progress Step:", self.widgets["log_progress_step"])
         f.addRow("Error Tail:", self.widgets["log_error_tail"])
         f.addRow(self.widgets["log_show_options_pretty"])
         f.addRow(self.widgets["log_show_options_json"])
+        f.addRow(self.widgets["log_json_lines"])
+        f.addRow(self.widgets["analysis_write_report"])
+        f.addRow(self.widgets["analysis_use_sidecar"])
+        f.addRow(self.widgets["analysis_dump_chunks"])
+        f.addRow("Correlation Curves:", self.widgets["correlation_curves"])
+        f.addRow("Curve Points:", self.widgets["correlation_curve_max_points"])
         main_layout.addWidget(log_group)
 
         # --- Sync Stability (Correlation Variance Detection) ---
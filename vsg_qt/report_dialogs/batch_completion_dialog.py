@@ -39,6 +39,7 @@ class BatchCompletionDialog(QDialog):
         stepping_jobs: list[dict[str, Any]],
         stepping_disabled_jobs: list[dict[str, Any]],
         report_path: Path | None = None,
+        needs_review: int = 0,
     ):
         """
         Initialize the completion dialog.
@@ -52,6 +53,7 @@ class BatchCompletionDialog(QDialog):
             stepping_jobs: List of jobs that used stepping correction
             stepping_disabled_jobs: List of jobs with stepping detected but disabled
             report_path: Path to the JSON report file (for Show Report button)
+            needs_review: Number of jobs stopped by the analysis confidence gate
         """
         super().__init__(parent)
         self.report_path = report_path
@@ -62,6 +64,7 @@ class BatchCompletionDialog(QDialog):
             failed,
             stepping_jobs,
             stepping_disabled_jobs,
+            needs_review,
         )
 
     def _setup_ui(
@@ -72,6 +75,7 @@ class BatchCompletionDialog(QDialog):
         failed: int,
         stepping_jobs: list[dict[str, Any]],
         stepping_disabled_jobs: list[dict[str, Any]],
+        needs_review: int,
     ):
         """Set up the dialog UI."""
         # Determine dialog type based on results
@@ -79,7 +83,7 @@ class BatchCompletionDialog(QDialog):
             self.setWindowTitle("Batch Complete - Errors")
             icon_text = "X"
             icon_color = "#dc3545"  # Red
-        elif stepping_disabled_jobs:
+        elif stepping_disabled_jobs or needs_review > 0:
             self.setWindowTitle("Batch Complete - Review Required")
             icon_text = "!"
             icon_color = "#fd7e14"  # Orange
@@ -143,11 +147,15 @@ class BatchCompletionDialog(QDialog):
         stats_text.append(f"  Successful: {successful}")
         stats_text.append(f"  Warnings: {warnings}")
         stats_text.append(f"  Failed: {failed}")
+        if needs_review > 0:
+            stats_text.append(f"  Needs review: {needs_review}")
 
         for line in stats_text:
             lbl = QLabel(line)
             if "Failed" in line and failed > 0:
                 lbl.setStyleSheet("color: #dc3545;")  # Red
+            elif "Needs review" in line:
+                lbl.setStyleSheet("color: #fd7e14;")  # Orange
             elif "Warnings" in line and warnings > 0:
                 lbl.setStyleSheet("color: #ffc107;")  # Yellow
             elif "Successful" in line and successful > 0:
@@ -6,6 +6,7 @@ Shows a table of all jobs with their status, warnings, stepping info,
 and sync delays. Selecting a job shows detailed information in a panel below.
 """
 
+import html
 from pathlib import Path
 from typing import Any
 
@@ -27,6 +28,7 @@ from PySide6.QtWidgets import (
     QWidget,
 )
 
+from vsg_core.errors import ErrorKind
 from vsg_core.reporting import ReportWriter
 
 
@@ -118,6 +120,7 @@ class ReportViewer(QDialog):
         successful = summary.get("successful", 0)
         warnings = summary.get("warnings", 0)
         failed = summary.get("failed", 0)
+        needs_review = summary.get("needs_review", 0)
         total = self.report_data.get("total_jobs", 0)
 
         summary_text = f"Summary: {successful} successful"
@@ -125,6 +128,8 @@ class ReportViewer(QDialog):
             summary_text += f", {warnings} with warnings"
         if failed > 0:
             summary_text += f", {failed} failed"
+        if needs_review > 0:
+            summary_text += f", {needs_review} need review"
         summary_text += f" ({total} total)"
 
         summary_label = QLabel(summary_text)
@@ -218,6 +223,9 @@ class ReportViewer(QDialog):
             if status == "Failed":
                 status_item.setForeground(QColor("#dc3545"))  # Red
                 status_item.setBackground(QColor("#f8d7da"))
+            elif status == "Needs Review":
+                status_item.setForeground(QColor("#8a4b08"))  # Dark orange
+                status_item.setBackground(QColor("#ffe5d0"))
             elif "Warning" in status_text:
                 status_item.setForeground(QColor("#856404"))  # Dark yellow
                 status_item.setBackground(QColor("#fff3cd"))
@@ -294,7 +302,9 @@ class ReportViewer(QDialog):
         # Error (if failed)
         error = job.get("error")
         if error:
-            lines.append(f"<b style='color: #dc3545;'>Error:</b> {error}")
+            kind = job.get("error_kind")
+            label = f" ({ErrorKind(kind).label})" if kind in set(ErrorKind) else ""
+            lines.append(f"<b style='color: #dc3545;'>Error{label}:</b> {error}")
 
         lines.append("")
 
@@ -406,6 +416,24 @@ class ReportViewer(QDialog):
                 message = detail.get("message", "")
                 lines.append(f"  - [{auditor}] {message}")
 
+        mux_warnings = job.get("mux_warnings", [])
+        if mux_warnings:
+            lines.append("")
+            lines.append(f"<b>mkvmerge Warnings:</b> {len(mux_warnings)}")
+            for warning in mux_warnings:
+                lines.append(f"  - {html.escape(warning)}")
+
+        checksums = job.get("checksums", [])
+        if checksums:
+            lines.append("")
+            lines.append("<b>Checksums (SHA-256):</b>")
+            for entry in checksums:
+                name = html.escape(Path(entry.get("path", "")).name)
+                lines.append(
+                    f"  {name}: {entry.get('sha256', '')} "
+                    f"({entry.get('size_bytes', 0):,} bytes)"
+                )
+
         # Validator issues (for future)
         validator_issues = job.get("validator_issues", [])
         if validator_issues:
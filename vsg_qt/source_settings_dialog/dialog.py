@@ -13,6 +13,7 @@ from PySide6.QtWidgets import (
     QFormLayout,
     QGroupBox,
     QLabel,
+    QSpinBox,
     QVBoxLayout,
 )
 
@@ -24,6 +25,7 @@ class SourceSettingsDialog(QDialog):
     For Source 2/3:
     - Correlation source track: Which track from this source to use for correlation
     - Use source separation: Whether to apply source separation for this source
+    - Manual delay: An explicit delay that replaces analysis for this source
 
     For Source 1:
     - Reference track: Which Source 1 track to use as reference for all comparisons
@@ -128,6 +130,26 @@ class SourceSettingsDialog(QDialog):
 
             layout.addWidget(separation_group)
 
+            # --- Manual Delay Section (only for Source 2/3) ---
+            manual_group = QGroupBox("Manual Delay")
+            manual_layout = QFormLayout(manual_group)
+
+            self.manual_delay_cb = QCheckBox("Set delay manually (skip analysis)")
+            self.manual_delay_cb.setToolTip(
+                f"When enabled, {self.source_key} is not analyzed; the delay below is used\n"
+                "as its final delay relative to Source 1 (container delays included),\n"
+                "exactly as if correlation had found it. The global shift still applies."
+            )
+            self.manual_delay_spin = QSpinBox()
+            self.manual_delay_spin.setRange(-3_600_000, 3_600_000)
+            self.manual_delay_spin.setSuffix(" ms")
+            self.manual_delay_spin.setEnabled(False)
+            self.manual_delay_cb.toggled.connect(self.manual_delay_spin.setEnabled)
+            manual_layout.addRow(self.manual_delay_cb)
+            manual_layout.addRow("Delay:", self.manual_delay_spin)
+
+            layout.addWidget(manual_group)
+
         # --- Buttons ---
         layout.addStretch()
 
@@ -194,11 +216,17 @@ class SourceSettingsDialog(QDialog):
             use_sep = self.current_settings.get("use_source_separation", False)
             self.use_separation_cb.setChecked(use_sep)
 
+            manual_delay = self.current_settings.get("manual_delay_ms")
+            self.manual_delay_cb.setChecked(manual_delay is not None)
+            self.manual_delay_spin.setValue(manual_delay or 0)
+
     def _reset_to_defaults(self):
         """Reset all settings to defaults."""
         self.source_track_combo.setCurrentIndex(0)  # Auto
         if not self.is_source1:
             self.use_separation_cb.setChecked(False)
+            self.manual_delay_cb.setChecked(False)
+            self.manual_delay_spin.setValue(0)
 
     def get_settings(self) -> dict[str, Any]:
         """
@@ -211,6 +239,7 @@ class SourceSettingsDialog(QDialog):
             For Source 2/3:
             - 'correlation_source_track': int or None (Source 2/3 track index, None = auto)
             - 'use_source_separation': bool
+            - 'manual_delay_ms': int or None (None = analyze as usual)
         """
         if self.is_source1:
             return {"correlation_ref_track": self.source_track_combo.currentData()}
@@ -218,6 +247,11 @@ class SourceSettingsDialog(QDialog):
             return {
                 "correlation_source_track": self.source_track_combo.currentData(),
                 "use_source_separation": self.use_separation_cb.isChecked(),
+                "manual_delay_ms": (
+                    self.manual_delay_spin.value()
+                    if self.manual_delay_cb.isChecked()
+                    else None
+                ),
             }
 
     def has_non_default_settings(self) -> bool:
@@ -226,6 +260,8 @@ class SourceSettingsDialog(QDialog):
         if self.is_source1:
             return settings.get("correlation_ref_track") is not None
         else:
-            return settings.get("correlation_source_track") is not None or settings.get(
-                "use_source_separation", False
+            return (
+                settings.get("correlation_source_track") is not None
+                or settings.get("use_source_separation", False)
+                or settings.get("manual_delay_ms") is not None
             )
@@ -56,6 +56,7 @@ class TrackSettingsLogic:
 
         # Show subtitle group only for subtitles
         self.v.subtitle_group.setVisible(is_subs)
+        self.v.audio_group.setVisible(track_type == "audio")
 
         if is_subs:
             codec_upper = (codec_id or "").upper()
@@ -83,6 +84,7 @@ class TrackSettingsLogic:
         convert_to_ass: bool = False,
         rescale: bool = False,
         size_multiplier: float = 1.0,
+        loudnorm: bool = False,
         **kwargs,  # Accept and ignore any other arguments
     ) -> None:
         """Applies the starting values to the widgets."""
@@ -101,6 +103,9 @@ class TrackSettingsLogic:
         self.v.cb_rescale.setChecked(bool(rescale))
         self.v.size_multiplier.setValue(float(size_multiplier))
 
+        # Set audio options
+        self.v.cb_loudnorm.setChecked(bool(loudnorm))
+
     def read_values(self) -> dict:
         """Returns a dictionary of the current values from the widgets."""
         # Get selected language code (empty string means "keep original")
@@ -113,4 +118,5 @@ class TrackSettingsLogic:
             "convert_to_ass": self.v.cb_convert.isChecked(),
             "rescale": self.v.cb_rescale.isChecked(),
             "size_multiplier": self.v.size_multiplier.value(),
+            "loudnorm": self.v.cb_loudnorm.isChecked(),
         }
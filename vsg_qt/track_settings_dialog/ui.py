@@ -45,6 +45,12 @@ class TrackSettingsDialog(QDialog):
         self.size_multiplier.setPrefix("Size multiplier: ")
         self.size_multiplier.setSuffix("x")
 
+        # Audio-specific controls
+        self.cb_loudnorm = QCheckBox("Normalize loudness (EBU R128, re-encodes)")
+        self.cb_loudnorm.setToolTip(
+            "Only applied when loudness normalization is enabled in Settings."
+        )
+
         # Frame sync exclusions button (ASS/SSA only)
         self.sync_exclusion_btn = QPushButton("Configure Frame Sync Exclusions...")
         self.sync_exclusion_btn.clicked.connect(self._open_sync_exclusion_dialog)
@@ -77,6 +83,12 @@ class TrackSettingsDialog(QDialog):
         subtitle_layout.addWidget(self.sync_exclusion_btn)
         layout.addWidget(self.subtitle_group)
 
+        # Audio section (conditionally visible)
+        self.audio_group = QGroupBox("Audio Options")
+        audio_layout = QVBoxLayout(self.audio_group)
+        audio_layout.addWidget(self.cb_loudnorm)
+        layout.addWidget(self.audio_group)
+
         btns = QDialogButtonBox(QDialogButtonBox.StandardButton.Ok | QDialogButtonBox.StandardButton.Cancel)
         btns.accepted.connect(self.accept)
         btns.rejected.connect(self.reject)
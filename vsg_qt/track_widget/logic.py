@@ -111,6 +111,10 @@ class TrackWidgetLogic:
             if source_settings.get("use_source_separation"):
                 corr_parts.append("Source Separation")
 
+            manual_delay = source_settings.get("manual_delay_ms")
+            if manual_delay is not None:
+                corr_parts.append(f"Manual Delay {manual_delay:+d}ms")
+
             if corr_parts:
                 parts.append("🎯 " + ", ".join(corr_parts))
 
@@ -189,7 +193,9 @@ class TrackWidgetLogic:
         track_source = self.track_data.get("source", "")
         if self.track_data.get("type") == "audio" and track_source != "Source 1":
             source_settings = self.v.source_settings.get(track_source, {})
-            if source_settings.get(
+            if source_settings.get("manual_delay_ms") is not None:
+                badges.append("🎯 Manual Delay")
+            elif source_settings.get(
                 "correlation_source_track"
             ) is not None or source_settings.get("use_source_separation"):
                 badges.append("🎯 Correlation Settings")
@@ -217,6 +223,9 @@ class TrackWidgetLogic:
         if self.track_data.get("pasted_warnings"):
             badges.append("⚠️ Paste Warnings")
 
+        if self.track_data.get("loudnorm"):
+            badges.append("Loudnorm")
+
         # NEW: Add badge if language was customized
         original_lang = self.track_data.get("lang", "und")
         custom_lang = self.track_data.get("custom_lang", "")
@@ -250,6 +259,7 @@ class TrackWidgetLogic:
 
         config = {
             "is_default": self.v.cb_default.isChecked(),
+            "manual_default": self.track_data.get("manual_default", False),
             "apply_track_name": self.v.cb_name.isChecked(),
             "is_forced_display": self.v.cb_forced.isChecked() if is_subs else False,
             "perform_ocr": self.v.cb_ocr.isChecked() if is_subs else False,
@@ -272,6 +282,7 @@ class TrackWidgetLogic:
             "custom_name": self.track_data.get(
                 "custom_name", ""
             ),  # NEW: Include custom name
+            "loudnorm": self.track_data.get("loudnorm", False),
             # Generated track fields (clean structure)
             "is_generated": self.track_data.get("is_generated", False),
             "source_track_id": self.track_data.get("source_track_id"),
@@ -133,6 +133,12 @@ class TrackWidget(QWidget):
             elif "custom_name" in self.track_data:
                 del self.track_data["custom_name"]
 
+            # Loudness normalization flag (audio tracks)
+            if new_config.get("loudnorm"):
+                self.track_data["loudnorm"] = True
+            else:
+                self.track_data.pop("loudnorm", None)
+
             # NEW: Store sync exclusion config in track_data
             sync_exclusion_styles = new_config.get("sync_exclusion_styles", [])
             if sync_exclusion_styles:
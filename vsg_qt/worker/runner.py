@@ -5,7 +5,9 @@ from typing import Any
 
 from PySide6.QtCore import QRunnable, Slot
 
+from vsg_core.errors import ErrorKind, error_kind
 from vsg_core.models.settings import AppSettings
+from vsg_core.models.source_input import primary_path
 from vsg_core.pipeline import JobPipeline
 
 from .signals import WorkerSignals
@@ -28,6 +30,7 @@ class JobWorker(QRunnable):
         self.debug_manager = debug_manager
         self.signals = WorkerSignals()
         self.cancelled = False
+        self._status_prefix = ""
 
     def _safe_log(self, msg: str):
         """Safely emit log message, handling case where signals are deleted during GUI shutdown."""
@@ -56,6 +59,11 @@ class JobWorker(QRunnable):
             # Signals deleted during GUI cleanup - silently ignore
             pass
 
+    def _safe_job_status(self, msg: str):
+        """Emit pipeline phase/ETA status prefixed with the current job label."""
+        prefix = self._status_prefix
+        self._safe_status(f"{prefix} — {msg}" if prefix else msg)
+
     def _safe_finished_job(self, result: dict[str, Any]):
         """Safely emit job finished signal, handling case where signals are deleted during GUI shutdown."""
         try:
@@ -78,12 +86,54 @@ class JobWorker(QRunnable):
         """Request cancellation of this worker."""
         self.cancelled = True
 
+    def _preflight_passed(self) -> bool:
+        """Check the whole batch up front; on errors, fail every job unrun."""
+        from vsg_core.orchestrator.preflight import preflight
+
+        issues = preflight(self.jobs, self.config, self.output_dir, self.and_merge)
+        for issue in issues:
+            self._safe_log(issue.describe())
+        errors = [i for i in issues if i.is_error]
+        if not errors:
+            return True
+
+        self._safe_log(
+            f"[Preflight] {len(errors)} problem(s) must be fixed before this "
+            f"batch can run. No jobs were started."
+        )
+        self._safe_status("Preflight failed")
+        results: list[dict[str, Any]] = []
+        for job_data in self.jobs:
+            source1_file = primary_path(job_data.get("sources", {}).get("Source 1", ""))
+            job_data["ref_path_for_batch_check"] = source1_file
+            name = Path(source1_file).name
+            job_errors = [i.message for i in errors if i.job in ("", name)]
+            results.append(
+                {
+                    "status": "Failed",
+                    "error": "Preflight: " + "; ".join(job_errors or ["batch aborted"]),
+                    "error_kind": ErrorKind.VALIDATION,
+                    "name": name,
+                    "job_data_for_batch_check": job_data,
+                }
+            )
+        self._safe_finished_all(results)
+        return False
+
     @Slot()
     def run(self):
+        if not self._preflight_passed():
+            return
+
+        if self.config.batch_max_concurrent_jobs > 1 and len(self.jobs) > 1:
+            self._run_parallel()
+            return
+
         pipeline = JobPipeline(
             config=self.config,
             log_callback=self._safe_log,
             progress_callback=self._safe_progress,
+            status_callback=self._safe_job_status,
         )
 
         all_results: list[dict[str, Any]] = []
@@ -98,7 +148,7 @@ class JobWorker(QRunnable):
                 break
 
             sources = job_data.get("sources", {})
-            source1_file = sources.get("Source 1")
+            source1_file = primary_path(sources.get("Source 1", ""))
             if not source1_file:
                 self._safe_log(
                     f"[FATAL WORKER ERROR] Job {i} is missing 'Source 1'. Skipping."
@@ -117,9 +167,10 @@ class JobWorker(QRunnable):
                 debug_paths = self.debug_manager.register_job(job_name)
 
             try:
-                self._safe_status(
+                self._status_prefix = (
                     f"Processing {i}/{total_jobs}: {Path(source1_file).name}"
                 )
+                self._safe_status(self._status_prefix)
 
                 pipeline_result = pipeline.run_job(
                     sources=sources,
@@ -127,9 +178,11 @@ class JobWorker(QRunnable):
                     output_dir_str=self.output_dir,
                     manual_layout=job_data.get("manual_layout"),
                     attachment_sources=job_data.get("attachment_sources"),
+                    attachment_selection=job_data.get("attachment_selection"),
                     source_settings=job_data.get("source_settings"),
                     chapter_source=job_data.get("chapter_source") or "Source 1",
                     debug_paths=debug_paths,
+                    settings_overrides=job_data.get("settings_overrides"),
                 )
 
                 # Convert to dict for signal emission, add runner tracking data
@@ -143,6 +196,7 @@ class JobWorker(QRunnable):
                 error_result = {
                     "status": "Failed",
                     "error": str(e),
+                    "error_kind": error_kind(e),
                     "name": Path(source1_file).name,
                     "job_data_for_batch_check": job_data,
                 }
@@ -150,4 +204,57 @@ class JobWorker(QRunnable):
                 self._safe_finished_job(error_result)
                 all_results.append(error_result)
 
+            if all_results[-1].get("status") == "Failed" and (
+                self.config.batch_stop_on_error
+            ):
+                self._safe_log(
+                    f"[WORKER] Job {i} failed; stopping batch (stop on error)."
+                )
+                break
+
         self._safe_finished_all(all_results)
+
+    def _run_parallel(self):
+        """Run the batch through the thread-pool queue runner."""
+        import threading
+
+        from vsg_core.queue_runner import run_queue
+
+        cancel_event = threading.Event()
+        total_jobs = len(self.jobs)
+        all_results: list[dict[str, Any]] = [{} for _ in self.jobs]
+        done = 0
+
+        for job_data in self.jobs:
+            job_data["ref_path_for_batch_check"] = primary_path(
+                job_data["sources"].get("Source 1", "")
+            )
+
+        def on_progress(index: int, job_frac: float, batch_frac: float) -> None:
+            if self.cancelled:
+                cancel_event.set()
+            self._safe_progress(batch_frac)
+
+        def on_finished(index: int, pipeline_result) -> None:
+            nonlocal done
+            done += 1
+            result = asdict(pipeline_result)
+            result["job_data_for_batch_check"] = self.jobs[index]
+            all_results[index] = result
+            self._safe_status(f"Finished {done}/{total_jobs}: {pipeline_result.name}")
+            self._safe_finished_job(result)
+
+        run_queue(
+            jobs=self.jobs,
+            settings=self.config,
+            and_merge=self.and_merge,
+            output_dir=self.output_dir,
+            max_concurrent=self.config.batch_max_concurrent_jobs,
+            log_callback=self._safe_log,
+            progress_callback=on_progress,
+            job_finished_callback=on_finished,
+            stop_on_error=self.config.batch_stop_on_error,
+            debug_manager=self.debug_manager,
+            cancel_event=cancel_event,
+        )
+        self._safe_finished_all([r for r in all_results if r])